@@ -29,8 +29,30 @@
 //! When analytics is unavailable (pool is None), methods return
 //! `AnalyticsInfraError::analytics("analytics service unavailable")` which maps
 //! to HTTP 503 Service Unavailable.
+//!
+//! # Resource limits
+//!
+//! DuckDB defaults to 80% of system memory and one thread per core, which a
+//! single large query can exhaust. [`DuckDBService::with_config`] applies a
+//! [`DuckDbConfig`] to every pool connection via `PRAGMA` statements.
+//!
+//! # Read-only mode
+//!
+//! SQL is validated before it reaches DuckDB, but [`DuckDbConfig::read_only`]
+//! adds engine-level enforcement: [`DuckDbConfig::open_pool`] opens the
+//! database file with `access_mode = READ_ONLY`, so any write statement fails.
+//! Two limitations apply. An in-memory database cannot be opened read-only,
+//! so the flag requires a database path. Catalogs added later with `ATTACH`
+//! carry their own access mode and are not covered by the flag.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_duckdb::duckdb::{AccessMode, Config};
 
+use crate::columns::{ResultColumn, describe_columns};
 use crate::error::AnalyticsInfraError;
+use crate::statement_cache::StatementCache;
 
 /// Type alias for the DuckDB connection pool.
 ///
@@ -41,6 +63,136 @@ pub type DuckDbPool = async_duckdb::Pool;
 // Re-export duckdb types from async_duckdb for public API consumers.
 pub use async_duckdb::duckdb;
 
+/// Default memory limit applied to each DuckDB connection.
+pub const DEFAULT_MEMORY_LIMIT: &str = "2GB";
+
+/// Size units accepted by DuckDB's `memory_limit` setting.
+const MEMORY_UNITS: [&str; 9] = ["B", "KB", "MB", "GB", "TB", "KiB", "MiB", "GiB", "TiB"];
+
+/// Resource limits applied to every connection in the DuckDB pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuckDbConfig {
+    /// Maximum memory in DuckDB size syntax, e.g. `"2GB"` or `"512MiB"`.
+    pub memory_limit: String,
+    /// Worker threads; `None` keeps DuckDB's default of one per core.
+    pub threads: Option<usize>,
+    /// Directory for spilling intermediate results; `None` keeps DuckDB's default.
+    pub temp_directory: Option<PathBuf>,
+    /// Open the database file read-only, rejecting writes at the engine level.
+    pub read_only: bool,
+}
+
+impl Default for DuckDbConfig {
+    fn default() -> Self {
+        Self {
+            memory_limit: DEFAULT_MEMORY_LIMIT.to_string(),
+            threads: None,
+            temp_directory: None,
+            read_only: false,
+        }
+    }
+}
+
+impl DuckDbConfig {
+    /// Open a connection pool honoring [`read_only`](Self::read_only).
+    ///
+    /// Opens an in-memory database when `path` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsInfraError` if `read_only` is set without a path, or
+    /// if DuckDB fails to open the database.
+    pub async fn open_pool(
+        &self,
+        path: Option<&Path>,
+        num_conns: usize,
+    ) -> Result<DuckDbPool, AnalyticsInfraError> {
+        let flags: fn() -> duckdb::Result<Config> = if self.read_only {
+            read_only_flags
+        } else {
+            read_write_flags
+        };
+        let mut builder = async_duckdb::PoolBuilder::new()
+            .num_conns(num_conns)
+            .flagsfn(flags);
+
+        match path {
+            Some(path) => builder = builder.path(path),
+            None if self.read_only => {
+                return Err(AnalyticsInfraError::analytics(
+                    "read-only DuckDB requires a database path; in-memory databases cannot be opened read-only",
+                ));
+            }
+            None => {}
+        }
+
+        builder
+            .open()
+            .await
+            .map_err(|e| AnalyticsInfraError::analytics(format!("failed to open DuckDB pool: {e}")))
+    }
+
+    /// Validate the settings and render them as `PRAGMA` statements.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsInfraError` if the memory limit is not a positive
+    /// size with a DuckDB unit, the thread count is zero, or the temp
+    /// directory is not valid UTF-8 or contains a single quote.
+    pub fn pragmas(&self) -> Result<Vec<String>, AnalyticsInfraError> {
+        let memory_limit = self.memory_limit.trim();
+        if !is_valid_memory_limit(memory_limit) {
+            return Err(AnalyticsInfraError::analytics(format!(
+                "invalid DuckDB memory limit '{}': expected a positive size such as 2GB",
+                self.memory_limit
+            )));
+        }
+        let mut pragmas = vec![format!("PRAGMA memory_limit='{memory_limit}'")];
+
+        if let Some(threads) = self.threads {
+            if threads == 0 {
+                return Err(AnalyticsInfraError::analytics(
+                    "invalid DuckDB thread count: must be at least 1",
+                ));
+            }
+            pragmas.push(format!("PRAGMA threads={threads}"));
+        }
+
+        if let Some(ref dir) = self.temp_directory {
+            let dir = dir.to_str().filter(|d| !d.contains('\'')).ok_or_else(|| {
+                AnalyticsInfraError::analytics(format!(
+                    "invalid DuckDB temp directory '{}'",
+                    dir.display()
+                ))
+            })?;
+            pragmas.push(format!("PRAGMA temp_directory='{dir}'"));
+        }
+
+        Ok(pragmas)
+    }
+}
+
+/// Connection flags opening the database read-only.
+fn read_only_flags() -> duckdb::Result<Config> {
+    Config::default().access_mode(AccessMode::ReadOnly)
+}
+
+/// Connection flags opening the database read-write, creating it if needed.
+fn read_write_flags() -> duckdb::Result<Config> {
+    Config::default().access_mode(AccessMode::Automatic)
+}
+
+/// Whether `limit` is a positive number followed by a DuckDB size unit.
+fn is_valid_memory_limit(limit: &str) -> bool {
+    let split = limit
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(limit.len());
+    let (number, unit) = limit.split_at(split);
+    let positive = number.parse::<f64>().is_ok_and(|n| n > 0.0);
+    let unit = unit.trim();
+    positive && MEMORY_UNITS.iter().any(|u| u.eq_ignore_ascii_case(unit))
+}
+
 /// DuckDB analytics service wrapper.
 ///
 /// Provides a clean async interface for analytics queries, handling the
@@ -48,6 +200,7 @@ pub use async_duckdb::duckdb;
 #[derive(Clone)]
 pub struct DuckDBService {
     pool: Option<DuckDbPool>,
+    statements: Arc<StatementCache>,
 }
 
 impl DuckDBService {
@@ -56,7 +209,54 @@ impl DuckDBService {
     /// When `pool` is `None`, all query methods will return service unavailable errors.
     #[must_use]
     pub fn new(pool: Option<DuckDbPool>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            statements: Arc::new(StatementCache::default()),
+        }
+    }
+
+    /// Keep up to `capacity` prepared statements per connection.
+    #[must_use]
+    pub fn with_statement_cache_capacity(mut self, capacity: usize) -> Self {
+        self.statements = Arc::new(StatementCache::new(capacity));
+        self
+    }
+
+    /// Prepared-statement cache shared by clones of this service.
+    #[must_use]
+    pub fn statement_cache(&self) -> &StatementCache {
+        &self.statements
+    }
+
+    /// Create a DuckDBService whose pool connections all use `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsInfraError` if the config is invalid or a `PRAGMA`
+    /// fails on any connection.
+    pub async fn with_config(
+        pool: DuckDbPool,
+        config: &DuckDbConfig,
+    ) -> Result<Self, AnalyticsInfraError> {
+        let pragmas = config.pragmas()?;
+        let results = pool
+            .conn_for_each(move |conn| {
+                for pragma in &pragmas {
+                    conn.execute(pragma, [])?;
+                }
+                Ok(())
+            })
+            .await;
+
+        for (i, result) in results.into_iter().enumerate() {
+            result.map_err(|e| {
+                AnalyticsInfraError::analytics(format!(
+                    "failed to apply DuckDB settings on connection {i}: {e}"
+                ))
+            })?;
+        }
+
+        Ok(Self::new(Some(pool)))
     }
 
     /// Check if analytics is available.
@@ -100,6 +300,74 @@ impl DuckDBService {
             .map_err(|e| AnalyticsInfraError::analytics(e.to_string()))
     }
 
+    /// Execute `sql` through the prepared-statement cache.
+    ///
+    /// The closure receives the prepared statement to bind parameters and
+    /// read rows. Repeated calls with the same normalized SQL on the same
+    /// connection skip parsing and planning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsInfraError` if the service is unavailable, the SQL
+    /// fails to prepare, or the closure fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let count = service.query_prepared(
+    ///     "SELECT COUNT(*) FROM events WHERE aggregate_type = ?",
+    ///     |stmt| stmt.query_row(["Workspace"], |row| row.get::<_, i64>(0)),
+    /// ).await?;
+    /// ```
+    pub async fn query_prepared<F, T>(&self, sql: &str, func: F) -> Result<T, AnalyticsInfraError>
+    where
+        F: FnOnce(&mut duckdb::Statement<'_>) -> Result<T, duckdb::Error> + Send + 'static,
+        T: Send + 'static,
+    {
+        let sql = sql.to_string();
+        let statements = Arc::clone(&self.statements);
+        self.query(move |conn| {
+            let mut stmt = statements.prepare(conn, &sql)?;
+            func(&mut stmt)
+        })
+        .await
+    }
+
+    /// Read the column names and logical types `sql` would produce.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsInfraError` if the service is unavailable or the
+    /// query fails to plan.
+    pub async fn describe(&self, sql: &str) -> Result<Vec<ResultColumn>, AnalyticsInfraError> {
+        let sql = sql.to_string();
+        self.query(move |conn| describe_columns(conn, &sql)).await
+    }
+
+    /// Count the rows in `relation` with `SELECT COUNT(*)`.
+    ///
+    /// `relation` is a table or view name, optionally qualified with its
+    /// catalog and schema (`space.main.astronauts`). Every part must be a
+    /// valid SQL identifier, since the name is interpolated into the query.
+    ///
+    /// This scans the relation, so callers should only count on request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnalyticsInfraError` if the service is unavailable, the name
+    /// is not a valid (qualified) identifier, or the query fails.
+    pub async fn count_rows(&self, relation: &str) -> Result<u64, AnalyticsInfraError> {
+        if !relation.split('.').all(Self::is_valid_identifier) {
+            return Err(AnalyticsInfraError::analytics(format!(
+                "invalid relation name '{relation}': each part must be a valid SQL identifier"
+            )));
+        }
+
+        let sql = format!("SELECT COUNT(*) FROM {relation}");
+        self.query(move |conn| conn.query_row(&sql, [], |row| row.get::<_, u64>(0)))
+            .await
+    }
+
     /// Execute a query that may modify the database.
     ///
     /// The closure receives a mutable reference to a DuckDB connection.
@@ -490,6 +758,173 @@ mod tests {
         assert!(!DuckDBService::is_valid_identifier("test; SELECT * FROM"));
     }
 
+    #[test]
+    fn default_config_renders_memory_limit_only() {
+        let pragmas = DuckDbConfig::default().pragmas().unwrap();
+        assert_eq!(pragmas, vec!["PRAGMA memory_limit='2GB'".to_string()]);
+    }
+
+    #[test]
+    fn config_rejects_invalid_limits() {
+        let invalid = [
+            DuckDbConfig {
+                memory_limit: "lots".to_string(),
+                ..DuckDbConfig::default()
+            },
+            DuckDbConfig {
+                memory_limit: "0GB".to_string(),
+                ..DuckDbConfig::default()
+            },
+            DuckDbConfig {
+                memory_limit: "2GB'; DROP TABLE x; --".to_string(),
+                ..DuckDbConfig::default()
+            },
+            DuckDbConfig {
+                threads: Some(0),
+                ..DuckDbConfig::default()
+            },
+            DuckDbConfig {
+                temp_directory: Some(PathBuf::from("/tmp/it's")),
+                ..DuckDbConfig::default()
+            },
+        ];
+
+        for config in invalid {
+            assert!(config.pragmas().is_err(), "expected error for {config:?}");
+        }
+    }
+
+    #[tokio::test]
+    #[expect(clippy::expect_used, reason = "test assertions")]
+    async fn with_config_applies_settings_to_connections() {
+        let pool = create_test_pool(2).await;
+        let config = DuckDbConfig {
+            memory_limit: "512MiB".to_string(),
+            threads: Some(2),
+            ..DuckDbConfig::default()
+        };
+
+        let service = DuckDBService::with_config(pool.clone(), &config)
+            .await
+            .expect("with_config failed");
+
+        for _ in 0..2 {
+            let (memory_limit, threads): (String, String) = service
+                .query(|conn| {
+                    conn.query_row(
+                        "SELECT current_setting('memory_limit'), \
+                         current_setting('threads')::VARCHAR",
+                        [],
+                        |row| Ok((row.get(0)?, row.get(1)?)),
+                    )
+                })
+                .await
+                .expect("failed to read settings");
+
+            assert!(
+                memory_limit.starts_with("512"),
+                "unexpected memory_limit: {memory_limit}"
+            );
+            assert_eq!(threads, "2");
+        }
+
+        close_pool(pool).await;
+    }
+
+    #[tokio::test]
+    #[expect(clippy::expect_used, reason = "test assertions")]
+    async fn repeated_prepared_query_hits_statement_cache() {
+        let pool = create_test_pool(1).await;
+        let service = DuckDBService::new(Some(pool.clone()));
+
+        for sql in ["SELECT ? + 1", "  SELECT ?  +  1 ;"] {
+            let value: i64 = service
+                .query_prepared(sql, |stmt| stmt.query_row([41_i64], |row| row.get(0)))
+                .await
+                .expect("prepared query failed");
+            assert_eq!(value, 42);
+        }
+
+        let cache = service.statement_cache();
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        close_pool(pool).await;
+    }
+
+    #[tokio::test]
+    #[expect(clippy::expect_used, reason = "test assertions")]
+    async fn describe_populates_column_types() {
+        let pool = create_test_pool(1).await;
+        let service = DuckDBService::new(Some(pool.clone()));
+
+        let columns = service
+            .describe("SELECT 'a' AS label, 42 AS total, 1.5::DOUBLE AS ratio")
+            .await
+            .expect("describe failed");
+
+        assert_eq!(
+            columns,
+            vec![
+                ResultColumn::new("label", "VARCHAR"),
+                ResultColumn::new("total", "INTEGER"),
+                ResultColumn::new("ratio", "DOUBLE"),
+            ]
+        );
+
+        close_pool(pool).await;
+    }
+
+    #[tokio::test]
+    async fn read_only_requires_database_path() {
+        let config = DuckDbConfig {
+            read_only: true,
+            ..DuckDbConfig::default()
+        };
+
+        assert!(config.open_pool(None, 1).await.is_err());
+    }
+
+    #[tokio::test]
+    #[expect(clippy::expect_used, reason = "test assertions")]
+    async fn read_only_pool_rejects_writes() {
+        let path =
+            std::env::temp_dir().join(format!("ironstar-ro-{}.duckdb", uuid::Uuid::new_v4()));
+
+        let writable = DuckDbConfig::default()
+            .open_pool(Some(&path), 1)
+            .await
+            .expect("failed to open writable pool");
+        writable
+            .conn(|conn| conn.execute_batch("CREATE TABLE t (x INTEGER); INSERT INTO t VALUES (1)"))
+            .await
+            .expect("failed to seed database");
+        close_pool(writable).await;
+
+        let config = DuckDbConfig {
+            read_only: true,
+            ..DuckDbConfig::default()
+        };
+        let pool = config
+            .open_pool(Some(&path), 1)
+            .await
+            .expect("failed to open read-only pool");
+        let service = DuckDBService::new(Some(pool.clone()));
+
+        let count: i64 = service
+            .query(|conn| conn.query_row("SELECT count(*) FROM t", [], |row| row.get(0)))
+            .await
+            .expect("read should succeed");
+        assert_eq!(count, 1);
+
+        let write = service
+            .query(|conn| conn.execute("INSERT INTO t VALUES (2)", []))
+            .await;
+        assert!(write.is_err(), "write should fail on read-only connection");
+
+        close_pool(pool).await;
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[tokio::test]
     async fn attach_catalog_rejects_invalid_identifier() {
         // Create a pool so we can test identifier validation (happens before query)
@@ -512,4 +947,31 @@ mod tests {
 
         close_pool(pool).await;
     }
+
+    #[tokio::test]
+    #[expect(clippy::expect_used, reason = "test assertions")]
+    async fn count_rows_counts_table_rows() {
+        let pool = create_test_pool(1).await;
+        let service = DuckDBService::new(Some(pool.clone()));
+
+        service
+            .query_mut(|conn| {
+                conn.execute_batch("CREATE TABLE astronauts AS SELECT * FROM range(3) t(id)")
+            })
+            .await
+            .expect("create table");
+
+        let count = service.count_rows("main.astronauts").await.expect("count");
+        assert_eq!(count, 3);
+
+        let result = service.count_rows("astronauts; DROP TABLE x").await;
+        assert!(
+            result
+                .expect_err("injected name should be rejected")
+                .to_string()
+                .contains("invalid relation name")
+        );
+
+        close_pool(pool).await;
+    }
 }
@@ -16,11 +16,18 @@
 //! Use [`embedded_cache_key_prefix`](super::embedded_catalogs::embedded_cache_key_prefix)
 //! for embedded catalogs or construct prefixes manually for runtime sources.
 //!
+//! Transformed chart data is cached in a second layer under
+//! [`chart_cache_key`], which extends the query's key with a chart
+//! configuration hash and a dataset version.
+//! Redrawing a previously rendered chart then skips the transformation.
+//!
 //! # Invalidation
 //!
 //! Cache entries are invalidated by prefix using [`invalidate_for_aggregate`],
 //! which removes all entries whose keys start with a given prefix.
 //! This integrates with the Zenoh-based event-driven invalidation in 3gd.2.
+//! Chart keys start with their query's key, so the same events invalidate
+//! both layers.
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -154,6 +161,24 @@ pub fn cache_key(prefix: &str, params: &impl Hash) -> String {
     format!("{prefix}:{:x}", query_hash(params))
 }
 
+/// Compose a chart data cache key for a query's transformed result.
+///
+/// The key is `{query_key}:chart:{chart_config_hash:x}:{dataset_version}`.
+/// Because it starts with `query_key`, invalidating the query's prefix also
+/// drops every chart rendered from it.
+///
+/// ```rust,ignore
+/// let key = chart_cache_key(&result_ref, &chart_config, "2024-01-01T00:00:00Z");
+/// // "query_session:result:a1b2c3:chart:d4e5f6:2024-01-01T00:00:00Z"
+/// ```
+#[must_use]
+pub fn chart_cache_key(query_key: &str, chart_config: &impl Hash, dataset_version: &str) -> String {
+    format!(
+        "{query_key}:chart:{:x}:{dataset_version}",
+        query_hash(chart_config)
+    )
+}
+
 #[cfg(test)]
 #[expect(clippy::expect_used, reason = "test assertions")]
 mod tests {
@@ -192,6 +217,18 @@ mod tests {
         assert!(key.len() > "embedded:space:0.1.0:astronauts:".len());
     }
 
+    #[test]
+    fn chart_cache_key_extends_query_key() {
+        let query_key = cache_key("query_session:result", &"SELECT 1");
+        let bar = chart_cache_key(&query_key, &("bar", "x", "y"), "v1");
+        let line = chart_cache_key(&query_key, &("line", "x", "y"), "v1");
+
+        assert!(bar.starts_with(&format!("{query_key}:chart:")));
+        assert!(bar.ends_with(":v1"));
+        assert_ne!(bar, line);
+        assert_ne!(bar, chart_cache_key(&query_key, &("bar", "x", "y"), "v2"));
+    }
+
     #[tokio::test]
     async fn query_cached_returns_result_on_miss() {
         let pool = async_duckdb::PoolBuilder::new()
@@ -0,0 +1,164 @@
+//! Result column metadata read from DuckDB.
+//!
+//! Chart transformers and the data table need to know which columns are
+//! numeric, temporal, or textual. DuckDB reports a logical type name per
+//! column (`BIGINT`, `DECIMAL(18,3)`, `TIMESTAMP WITH TIME ZONE`, ...);
+//! [`ColumnType`] coarsens those into the handful of kinds presentation code
+//! branches on, while [`ResultColumn`] keeps the original name for display.
+//!
+//! [`describe_columns`] reads the schema of a query with `DESCRIBE`, which
+//! plans the query without producing rows.
+
+use async_duckdb::duckdb::{Connection, Result};
+use serde::{Deserialize, Serialize};
+
+/// Coarse logical type of a result column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnType {
+    /// Signed or unsigned integers of any width.
+    Integer,
+    /// Floating point and fixed-point decimal numbers.
+    Float,
+    /// Character strings.
+    Text,
+    /// Booleans.
+    Boolean,
+    /// Calendar dates without a time component.
+    Date,
+    /// Timestamps, with or without time zone.
+    Timestamp,
+    /// Any other type (lists, structs, blobs, intervals, ...).
+    #[default]
+    Other,
+}
+
+impl ColumnType {
+    /// Map a DuckDB logical type name to its coarse kind.
+    ///
+    /// Matching ignores case and type parameters, so `decimal(18,3)` is a
+    /// [`ColumnType::Float`].
+    #[must_use]
+    pub fn from_type_name(type_name: &str) -> Self {
+        let base = type_name
+            .split('(')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_uppercase();
+
+        match base.as_str() {
+            "TINYINT" | "SMALLINT" | "INTEGER" | "INT" | "BIGINT" | "HUGEINT" | "UTINYINT"
+            | "USMALLINT" | "UINTEGER" | "UBIGINT" | "UHUGEINT" => Self::Integer,
+            "FLOAT" | "REAL" | "DOUBLE" | "DECIMAL" | "NUMERIC" => Self::Float,
+            "VARCHAR" | "TEXT" | "STRING" | "CHAR" | "BPCHAR" => Self::Text,
+            "BOOLEAN" | "BOOL" => Self::Boolean,
+            "DATE" => Self::Date,
+            _ if base.starts_with("TIMESTAMP") || base == "DATETIME" => Self::Timestamp,
+            _ => Self::Other,
+        }
+    }
+
+    /// Whether values of this type can be plotted on a numeric axis.
+    #[must_use]
+    pub fn is_numeric(self) -> bool {
+        matches!(self, Self::Integer | Self::Float)
+    }
+}
+
+/// Name and type of one column in a query result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResultColumn {
+    /// Column name as returned by DuckDB.
+    pub name: String,
+    /// DuckDB logical type name, e.g. `"BIGINT"`.
+    pub data_type: String,
+    /// Coarse kind derived from `data_type`.
+    pub column_type: ColumnType,
+}
+
+impl ResultColumn {
+    /// Create a column, deriving its kind from the DuckDB type name.
+    #[must_use]
+    pub fn new(name: impl Into<String>, data_type: impl Into<String>) -> Self {
+        let data_type = data_type.into();
+        Self {
+            name: name.into(),
+            column_type: ColumnType::from_type_name(&data_type),
+            data_type,
+        }
+    }
+}
+
+/// Read the result schema of `sql` without executing it.
+///
+/// # Errors
+///
+/// Returns the DuckDB error if the query fails to plan.
+pub fn describe_columns(conn: &Connection, sql: &str) -> Result<Vec<ResultColumn>> {
+    let sql = sql.trim().trim_end_matches(';');
+    let mut stmt = conn.prepare(&format!("DESCRIBE {sql}"))?;
+    stmt.query_map([], |row| {
+        Ok(ResultColumn::new(
+            row.get::<_, String>("column_name")?,
+            row.get::<_, String>("column_type")?,
+        ))
+    })?
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_names_map_to_kinds() {
+        let cases = [
+            ("BIGINT", ColumnType::Integer),
+            ("utinyint", ColumnType::Integer),
+            ("DOUBLE", ColumnType::Float),
+            ("DECIMAL(18,3)", ColumnType::Float),
+            ("VARCHAR", ColumnType::Text),
+            ("BOOLEAN", ColumnType::Boolean),
+            ("DATE", ColumnType::Date),
+            ("TIMESTAMP WITH TIME ZONE", ColumnType::Timestamp),
+            ("TIMESTAMP_NS", ColumnType::Timestamp),
+            ("INTEGER[]", ColumnType::Other),
+            ("STRUCT(a INTEGER)", ColumnType::Other),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(ColumnType::from_type_name(name), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn describe_reports_mixed_column_types() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        let columns = describe_columns(
+            &conn,
+            "SELECT 1::BIGINT AS n, 2.5::DOUBLE AS x, 'a' AS s, true AS b, \
+             DATE '2024-01-15' AS d, TIMESTAMP '2024-01-15 10:30:00' AS t, [1, 2] AS l;",
+        )
+        .unwrap();
+
+        let kinds: Vec<(&str, ColumnType)> = columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.column_type))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("n", ColumnType::Integer),
+                ("x", ColumnType::Float),
+                ("s", ColumnType::Text),
+                ("b", ColumnType::Boolean),
+                ("d", ColumnType::Date),
+                ("t", ColumnType::Timestamp),
+                ("l", ColumnType::Other),
+            ]
+        );
+        assert_eq!(columns[0].data_type, "BIGINT");
+    }
+}
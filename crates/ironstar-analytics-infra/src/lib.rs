@@ -9,12 +9,20 @@ pub mod analytics;
 pub mod analytics_cache;
 pub mod cache_invalidation;
 pub mod cached_analytics;
+pub mod columns;
 pub mod embedded_catalogs;
 pub mod error;
+pub mod rows;
+pub mod statement_cache;
 
-pub use analytics::{AnalyticsState, DuckDBService, DuckDbPool};
+pub use analytics::{
+    AnalyticsState, DEFAULT_MEMORY_LIMIT, DuckDBService, DuckDbConfig, DuckDbPool,
+};
 pub use analytics_cache::AnalyticsCache;
 pub use cache_invalidation::{CacheInvalidationRegistry, spawn_cache_invalidation};
-pub use cached_analytics::{CachedAnalyticsService, cache_key, query_hash};
+pub use cached_analytics::{CachedAnalyticsService, cache_key, chart_cache_key, query_hash};
+pub use columns::{ColumnType, ResultColumn, describe_columns};
 pub use embedded_catalogs::{DuckLakeCatalogs, embedded_cache_key_prefix};
 pub use error::{AnalyticsInfraError, AnalyticsInfraErrorKind};
+pub use rows::{ResultRows, fetch_rows, value_to_json};
+pub use statement_cache::{DEFAULT_STATEMENT_CACHE_CAPACITY, StatementCache, normalize_sql};
@@ -0,0 +1,195 @@
+//! Query result rows read from DuckDB as JSON values.
+//!
+//! Completed queries cache their full result so the data table and chart
+//! can page and redraw it without running the query again. [`fetch_rows`]
+//! runs a query and returns a [`ResultRows`]: the columns from
+//! [`describe_columns`] and every row as JSON values in column order. Its
+//! serialized form matches the presentation layer's `QueryResult`, which is
+//! what readers of the cache deserialize.
+//!
+//! Scalars map to JSON numbers, strings, and booleans. Dates, times, and
+//! timestamps become ISO 8601 strings, lists and arrays become JSON arrays,
+//! and structs become objects. Values with no JSON counterpart (blobs and
+//! maps) read as `null`.
+
+use async_duckdb::duckdb::types::{TimeUnit, Value};
+use async_duckdb::duckdb::{Connection, Result};
+use chrono::{DateTime, Days, NaiveDate, NaiveTime};
+use serde::Serialize;
+
+use crate::columns::{ResultColumn, describe_columns};
+
+/// Columns and rows of a query result, ready to cache as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ResultRows {
+    /// Column names and types, in result order.
+    pub columns: Vec<ResultColumn>,
+    /// Row values, each in column order.
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+impl ResultRows {
+    /// Number of rows in the result.
+    #[must_use]
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+/// Run `sql` and read its columns and every row.
+///
+/// # Errors
+///
+/// Returns the DuckDB error if the query fails to plan or execute.
+pub fn fetch_rows(conn: &Connection, sql: &str) -> Result<ResultRows> {
+    let columns = describe_columns(conn, sql)?;
+    let mut stmt = conn.prepare(sql)?;
+    let mut rows = stmt.query([])?;
+    let mut values = Vec::new();
+    while let Some(row) = rows.next()? {
+        let cells = (0..columns.len())
+            .map(|index| row.get::<_, Value>(index).map(value_to_json))
+            .collect::<Result<Vec<_>>>()?;
+        values.push(cells);
+    }
+    Ok(ResultRows {
+        columns,
+        rows: values,
+    })
+}
+
+/// Convert one DuckDB value to JSON.
+#[must_use]
+pub fn value_to_json(value: Value) -> serde_json::Value {
+    use serde_json::Value as Json;
+
+    match value {
+        Value::Null | Value::Blob(_) | Value::Map(_) => Json::Null,
+        Value::Boolean(b) => b.into(),
+        Value::TinyInt(n) => n.into(),
+        Value::SmallInt(n) => n.into(),
+        Value::Int(n) => n.into(),
+        Value::BigInt(n) => n.into(),
+        Value::UTinyInt(n) => n.into(),
+        Value::USmallInt(n) => n.into(),
+        Value::UInt(n) => n.into(),
+        Value::UBigInt(n) => n.into(),
+        Value::HugeInt(n) => i64::try_from(n).map_or_else(|_| n.to_string().into(), Into::into),
+        Value::Float(n) => float_to_json(f64::from(n)),
+        Value::Double(n) => float_to_json(n),
+        Value::Decimal(d) => d
+            .to_string()
+            .parse::<f64>()
+            .map_or_else(|_| d.to_string().into(), float_to_json),
+        Value::Text(s) | Value::Enum(s) => s.into(),
+        Value::Date32(days) => date_from_days(days).map_or(Json::Null, |d| d.to_string().into()),
+        Value::Timestamp(unit, n) => DateTime::from_timestamp_micros(unit.to_micros(n))
+            .map_or(Json::Null, |ts| ts.to_rfc3339().into()),
+        Value::Time64(unit, n) => {
+            time_from_micros(unit, n).map_or(Json::Null, |t| t.to_string().into())
+        }
+        Value::Interval {
+            months,
+            days,
+            nanos,
+        } => serde_json::json!({ "months": months, "days": days, "nanos": nanos }),
+        Value::List(items) | Value::Array(items) => {
+            Json::Array(items.into_iter().map(value_to_json).collect())
+        }
+        Value::Struct(fields) => Json::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), value_to_json(value.clone())))
+                .collect(),
+        ),
+        Value::Union(inner) => value_to_json(*inner),
+    }
+}
+
+/// Finite floats as JSON numbers; NaN and infinities as `null`.
+fn float_to_json(n: f64) -> serde_json::Value {
+    serde_json::Number::from_f64(n).map_or(serde_json::Value::Null, serde_json::Value::Number)
+}
+
+/// The date `days` after the Unix epoch.
+fn date_from_days(days: i32) -> Option<NaiveDate> {
+    let epoch = DateTime::UNIX_EPOCH.date_naive();
+    let offset = Days::new(u64::from(days.unsigned_abs()));
+    if days >= 0 {
+        epoch.checked_add_days(offset)
+    } else {
+        epoch.checked_sub_days(offset)
+    }
+}
+
+/// The time of day `n` units after midnight.
+fn time_from_micros(unit: TimeUnit, n: i64) -> Option<NaiveTime> {
+    let micros = unit.to_micros(n);
+    let secs = u32::try_from(micros.div_euclid(1_000_000)).ok()?;
+    let nanos = u32::try_from(micros.rem_euclid(1_000_000).checked_mul(1000)?).ok()?;
+    NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalars_convert_to_json() {
+        let cases = [
+            (Value::Null, serde_json::json!(null)),
+            (Value::Boolean(true), serde_json::json!(true)),
+            (Value::BigInt(-7), serde_json::json!(-7)),
+            (Value::UBigInt(7), serde_json::json!(7)),
+            (Value::Double(1.5), serde_json::json!(1.5)),
+            (Value::Double(f64::NAN), serde_json::json!(null)),
+            (Value::Text("a".to_string()), serde_json::json!("a")),
+            (Value::Date32(19_737), serde_json::json!("2024-01-15")),
+            (Value::Date32(-1), serde_json::json!("1969-12-31")),
+            (
+                Value::Timestamp(TimeUnit::Second, 1_705_314_600),
+                serde_json::json!("2024-01-15T10:30:00+00:00"),
+            ),
+            (
+                Value::Time64(TimeUnit::Microsecond, 37_800_000_000),
+                serde_json::json!("10:30:00"),
+            ),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(value_to_json(value.clone()), expected, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn fetch_rows_reads_columns_and_rows_in_order() {
+        let conn = Connection::open_in_memory().unwrap();
+        let result = fetch_rows(
+            &conn,
+            "SELECT * FROM (VALUES ('a', 1), ('b', 2)) AS t(label, total)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.columns,
+            [
+                ResultColumn::new("label", "VARCHAR"),
+                ResultColumn::new("total", "INTEGER")
+            ]
+        );
+        assert_eq!(
+            result.rows,
+            [
+                vec![serde_json::json!("a"), serde_json::json!(1)],
+                vec![serde_json::json!("b"), serde_json::json!(2)],
+            ]
+        );
+        assert_eq!(result.row_count(), 2);
+    }
+
+    #[test]
+    fn lists_convert_element_wise() {
+        let list = Value::List(vec![Value::Int(1), Value::Null]);
+        assert_eq!(value_to_json(list), serde_json::json!([1, null]));
+    }
+}
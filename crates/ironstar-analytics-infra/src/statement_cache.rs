@@ -0,0 +1,329 @@
+//! Prepared-statement cache for repeated analytics queries.
+//!
+//! Dashboards re-run the same parameterized queries on every refresh, and
+//! DuckDB re-parses and re-plans SQL on each `prepare`. [`StatementCache`]
+//! routes preparation through each connection's `prepare_cached`, keyed by
+//! normalized SQL so formatting and comment differences share an entry, and
+//! bounds the per-connection cache with LRU eviction.
+//!
+//! The prepared statements themselves live inside each DuckDB connection and
+//! cannot be shared across connections. The cache mirrors every connection's
+//! LRU order so it can count hits and misses for observability and tests.
+//!
+//! Connections are told apart by [`connection_id`]. async-duckdb owns each
+//! pooled connection on a dedicated thread for the connection's whole life,
+//! so an id assigned once per thread is stable where the connection's
+//! address is not: a connection may move, and a freed address may be reused
+//! by another connection.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use async_duckdb::duckdb::{CachedStatement, Connection, Result};
+
+/// Default number of prepared statements kept per connection.
+pub const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 32;
+
+/// Normalize SQL for use as a cache key.
+///
+/// Drops `--` and `/* */` comments, collapses whitespace runs outside string
+/// literals and quoted identifiers to a single space, and strips surrounding
+/// whitespace and trailing semicolons. The result is what gets prepared, so
+/// it must mean the same as `sql`.
+#[must_use]
+pub fn normalize_sql(sql: &str) -> String {
+    let mut normalized = String::with_capacity(sql.len());
+    let mut pending_space = false;
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                pending_space = true;
+                continue;
+            }
+            '-' if chars.next_if_eq(&'-').is_some() => {
+                while chars.next_if(|&c| c != '\n' && c != '\r').is_some() {}
+                pending_space = true;
+                continue;
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                skip_block_comment(&mut chars);
+                pending_space = true;
+                continue;
+            }
+            _ => {}
+        }
+
+        if pending_space && !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        pending_space = false;
+        normalized.push(c);
+
+        match c {
+            '\'' => copy_quoted(&mut chars, &mut normalized, '\'', false),
+            '"' => copy_quoted(&mut chars, &mut normalized, '"', false),
+            // E'...' is an escape string, where a backslash escapes the quote.
+            'e' | 'E' if chars.peek() == Some(&'\'') && !ends_in_identifier(&normalized) => {
+                chars.next();
+                normalized.push('\'');
+                copy_quoted(&mut chars, &mut normalized, '\'', true);
+            }
+            '$' if !ends_in_identifier(&normalized) => {
+                copy_dollar_quoted(&mut chars, &mut normalized);
+            }
+            _ => {}
+        }
+    }
+
+    while normalized.ends_with(';') || normalized.ends_with(' ') {
+        normalized.pop();
+    }
+    normalized
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+/// Whether the character before the last one pushed continues an identifier,
+/// so that a trailing `e` is part of a word rather than an `E'...'` prefix.
+fn ends_in_identifier(normalized: &str) -> bool {
+    normalized
+        .chars()
+        .rev()
+        .nth(1)
+        .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Copy a dollar-quoted string (`$$...$$` or `$tag$...$tag$`) verbatim after
+/// its opening `$`. A positional parameter such as `$1` is left alone.
+fn copy_dollar_quoted(chars: &mut Chars<'_>, out: &mut String) {
+    let mut lookahead = chars.clone();
+    let mut tag = String::new();
+    while let Some(c) = lookahead
+        .next_if(|&c| c.is_alphabetic() || c == '_' || (!tag.is_empty() && c.is_ascii_digit()))
+    {
+        tag.push(c);
+    }
+    if lookahead.next_if_eq(&'$').is_none() {
+        return;
+    }
+
+    let delimiter = format!("${tag}$");
+    let content = out.len() + delimiter.len() - 1;
+    *chars = lookahead;
+    out.push_str(&tag);
+    out.push('$');
+    for c in chars.by_ref() {
+        out.push(c);
+        if out.len() >= content + delimiter.len() && out.ends_with(&delimiter) {
+            return;
+        }
+    }
+}
+
+/// Skip a block comment after its opening `/*`; block comments nest.
+fn skip_block_comment(chars: &mut Chars<'_>) {
+    let mut depth = 1_usize;
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.next_if_eq(&'*').is_some() => depth += 1,
+            '*' if chars.next_if_eq(&'/').is_some() => {
+                depth -= 1;
+                if depth == 0 {
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Copy a quoted literal or identifier verbatim after its opening `quote`,
+/// where a doubled quote stands for itself.
+fn copy_quoted(chars: &mut Chars<'_>, out: &mut String, quote: char, backslash_escapes: bool) {
+    while let Some(c) = chars.next() {
+        out.push(c);
+        if backslash_escapes && c == '\\' {
+            if let Some(escaped) = chars.next() {
+                out.push(escaped);
+            }
+        } else if c == quote {
+            match chars.next_if_eq(&quote) {
+                Some(doubled) => out.push(doubled),
+                None => return,
+            }
+        }
+    }
+}
+
+/// Source of connection ids, one per connection thread.
+static NEXT_CONNECTION_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static CONNECTION_ID: usize = NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Stable id of the connection owned by the current thread.
+fn connection_id() -> usize {
+    CONNECTION_ID.with(|id| *id)
+}
+
+/// LRU cache of prepared statements across the connections of a pool.
+#[derive(Debug)]
+pub struct StatementCache {
+    capacity: usize,
+    recent: Mutex<HashMap<usize, VecDeque<String>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Default for StatementCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_STATEMENT_CACHE_CAPACITY)
+    }
+}
+
+impl StatementCache {
+    /// Create a cache keeping up to `capacity` statements per connection.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            recent: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Maximum statements kept per connection.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Preparations served from a connection's cache.
+    #[must_use]
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Preparations that had to parse and plan the SQL.
+    #[must_use]
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Prepare `sql` on `conn`, reusing a cached statement when present.
+    pub(crate) fn prepare<'conn>(
+        &self,
+        conn: &'conn Connection,
+        sql: &str,
+    ) -> Result<CachedStatement<'conn>> {
+        let sql = normalize_sql(sql);
+        conn.set_prepared_statement_cache_capacity(self.capacity);
+        self.record(connection_id(), &sql);
+        conn.prepare_cached(&sql)
+    }
+
+    /// Record a use of `sql` on connection `conn_id`, returning whether it hit.
+    fn record(&self, conn_id: usize, sql: &str) -> bool {
+        let mut recent = self.recent.lock().unwrap_or_else(PoisonError::into_inner);
+        let entries = recent.entry(conn_id).or_default();
+
+        let hit = match entries.iter().position(|s| s == sql) {
+            Some(index) => {
+                if let Some(entry) = entries.remove(index) {
+                    entries.push_back(entry);
+                }
+                true
+            }
+            None => {
+                entries.push_back(sql.to_string());
+                while entries.len() > self.capacity {
+                    entries.pop_front();
+                }
+                false
+            }
+        };
+
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        hit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalization_collapses_whitespace_outside_literals() {
+        assert_eq!(
+            normalize_sql("  SELECT  *\n  FROM t\tWHERE s = 'a  b' ;  "),
+            "SELECT * FROM t WHERE s = 'a  b'"
+        );
+    }
+
+    #[test]
+    fn line_comment_does_not_swallow_the_following_clause() {
+        assert_eq!(
+            normalize_sql("SELECT a -- note\nFROM t WHERE x"),
+            "SELECT a FROM t WHERE x"
+        );
+        assert_eq!(
+            normalize_sql("SELECT a /* one /* nested */ two */\nFROM t"),
+            "SELECT a FROM t"
+        );
+    }
+
+    #[test]
+    fn normalization_keeps_quoted_identifiers_and_escape_strings_verbatim() {
+        assert_eq!(
+            normalize_sql("SELECT \"my  col\"  FROM t"),
+            "SELECT \"my  col\" FROM t"
+        );
+        assert_eq!(
+            normalize_sql("SELECT E'\\'  x' ,  'it''s  so'"),
+            "SELECT E'\\'  x' , 'it''s  so'"
+        );
+        assert_eq!(normalize_sql("SELECT ';' ;;"), "SELECT ';'");
+        assert_eq!(
+            normalize_sql("SELECT $q$a  -- b$q$,  $1"),
+            "SELECT $q$a  -- b$q$, $1"
+        );
+    }
+
+    #[test]
+    fn least_recently_used_statement_is_evicted() {
+        let cache = StatementCache::new(2);
+
+        assert!(!cache.record(0, "a"));
+        assert!(!cache.record(0, "b"));
+        assert!(cache.record(0, "a"));
+        assert!(!cache.record(0, "c"));
+        assert!(!cache.record(0, "b"));
+        assert!(cache.record(0, "c"));
+
+        assert_eq!((cache.hits(), cache.misses()), (2, 4));
+    }
+
+    #[test]
+    fn connections_cache_independently() {
+        let cache = StatementCache::new(2);
+
+        assert!(!cache.record(0, "a"));
+        assert!(!cache.record(1, "a"));
+        assert!(cache.record(1, "a"));
+    }
+
+    #[test]
+    fn connection_id_is_stable_per_thread_and_distinct_across_threads() {
+        let here = connection_id();
+        assert_eq!(connection_id(), here);
+
+        let there = std::thread::spawn(connection_id).join().unwrap();
+        assert_ne!(there, here);
+    }
+}
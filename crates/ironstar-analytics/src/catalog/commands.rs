@@ -17,7 +17,7 @@ use super::values::{CatalogMetadata, CatalogRef};
 /// DuckLake catalog via DuckDB and provides the metadata in the command.
 /// The typed holes from the Idris spec (`?metadata`, `?timestamp_refresh`)
 /// resolve at the effect boundary, not in the Decider.
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "domain/")]
 #[serde(tag = "type")]
 pub enum CatalogCommand {
@@ -62,3 +62,46 @@ impl DeciderType for CatalogCommand {
         "Catalog".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::values::DatasetInfo;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            CatalogCommand::SelectCatalog {
+                catalog_ref: CatalogRef::new("ducklake://hf/sciexp/fixtures").unwrap(),
+                selected_at: ts,
+            },
+            CatalogCommand::RefreshCatalogMetadata {
+                metadata: CatalogMetadata {
+                    datasets: vec![DatasetInfo {
+                        name: "fixtures".to_string(),
+                        table_count: 3,
+                        schema_version: "1".to_string(),
+                        row_count: None,
+                    }],
+                    last_refreshed: ts,
+                },
+                refreshed_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: CatalogCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
+}
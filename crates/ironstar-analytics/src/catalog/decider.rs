@@ -2,6 +2,13 @@
 //!
 //! The Decider manages the lifecycle of DuckLake catalog selections.
 //! It is a pure function with no side effects.
+//!
+//! # Metadata refresh
+//!
+//! `RefreshCatalogMetadata` carries the full metadata, but the emitted
+//! `CatalogMetadataRefreshed` event also records which datasets were added,
+//! removed, or changed so views can highlight them. A refresh whose datasets
+//! match the current metadata is idempotent and returns `Ok(vec![])`.
 
 use ironstar_core::Decider;
 use tracing::instrument;
@@ -10,7 +17,7 @@ use super::commands::CatalogCommand;
 use super::errors::CatalogError;
 use super::events::CatalogEvent;
 use super::state::CatalogState;
-use super::values::CatalogMetadata;
+use super::values::{CatalogMetadata, CatalogMetadataDiff};
 
 /// Type alias for the Catalog Decider.
 ///
@@ -72,17 +79,28 @@ fn decide(
             Err(CatalogError::no_catalog_selected())
         }
 
-        // RefreshCatalogMetadata from CatalogActive -> emit refresh
+        // RefreshCatalogMetadata from CatalogActive -> emit refresh with diff,
+        // idempotent when no dataset changed
         (
             CatalogCommand::RefreshCatalogMetadata {
                 metadata,
                 refreshed_at,
             },
-            CatalogState::CatalogActive { .. },
-        ) => Ok(vec![CatalogEvent::CatalogMetadataRefreshed {
-            metadata: metadata.clone(),
-            refreshed_at: *refreshed_at,
-        }]),
+            CatalogState::CatalogActive {
+                metadata: current, ..
+            },
+        ) => {
+            let diff = CatalogMetadataDiff::between(current, metadata);
+            if diff.is_empty() {
+                Ok(vec![])
+            } else {
+                Ok(vec![CatalogEvent::CatalogMetadataRefreshed {
+                    metadata: metadata.clone(),
+                    diff,
+                    refreshed_at: *refreshed_at,
+                }])
+            }
+        }
     };
     if let Ok(ref events) = result {
         tracing::debug!(event_count = events.len(), "decision complete");
@@ -146,6 +164,7 @@ mod tests {
                 name: "genomics".to_string(),
                 table_count: 5,
                 schema_version: "1.0.0".to_string(),
+                row_count: None,
             }],
             last_refreshed: ts,
         }
@@ -242,11 +261,88 @@ mod tests {
                 refreshed_at: ts,
             })
             .then(vec![CatalogEvent::CatalogMetadataRefreshed {
+                diff: CatalogMetadataDiff {
+                    added: meta.datasets.clone(),
+                    ..CatalogMetadataDiff::default()
+                },
                 metadata: meta,
                 refreshed_at: ts,
             }]);
     }
 
+    #[test]
+    fn refresh_records_added_and_removed_datasets() {
+        let r = sample_ref();
+        let ts = sample_time();
+        let old = sample_metadata(ts);
+        let imaging = DatasetInfo {
+            name: "imaging".to_string(),
+            table_count: 2,
+            schema_version: "1.0.0".to_string(),
+            row_count: None,
+        };
+        let new = CatalogMetadata {
+            datasets: vec![imaging.clone()],
+            last_refreshed: ts,
+        };
+
+        DeciderTestSpecification::default()
+            .for_decider(catalog_decider())
+            .given(vec![
+                CatalogEvent::CatalogSelected {
+                    catalog_ref: r,
+                    selected_at: ts,
+                },
+                CatalogEvent::CatalogMetadataRefreshed {
+                    metadata: old.clone(),
+                    diff: CatalogMetadataDiff::default(),
+                    refreshed_at: ts,
+                },
+            ])
+            .when(CatalogCommand::RefreshCatalogMetadata {
+                metadata: new.clone(),
+                refreshed_at: ts,
+            })
+            .then(vec![CatalogEvent::CatalogMetadataRefreshed {
+                metadata: new,
+                diff: CatalogMetadataDiff {
+                    added: vec![imaging],
+                    removed: old.datasets,
+                    changed: vec![],
+                },
+                refreshed_at: ts,
+            }]);
+    }
+
+    #[test]
+    fn refresh_without_changes_is_idempotent() {
+        let r = sample_ref();
+        let ts = sample_time();
+        let meta = sample_metadata(ts);
+
+        DeciderTestSpecification::default()
+            .for_decider(catalog_decider())
+            .given(vec![
+                CatalogEvent::CatalogSelected {
+                    catalog_ref: r,
+                    selected_at: ts,
+                },
+                CatalogEvent::CatalogMetadataRefreshed {
+                    metadata: meta.clone(),
+                    diff: CatalogMetadataDiff::default(),
+                    refreshed_at: ts,
+                },
+            ])
+            .when(CatalogCommand::RefreshCatalogMetadata {
+                metadata: CatalogMetadata {
+                    last_refreshed: ts + chrono::Duration::minutes(5),
+                    ..meta
+                },
+                refreshed_at: ts + chrono::Duration::minutes(5),
+            })
+            .then(vec![]);
+    }
+
     // ===== Evolve tests =====
 
     #[test]
@@ -285,6 +381,7 @@ mod tests {
             &initial,
             &CatalogEvent::CatalogMetadataRefreshed {
                 metadata: meta.clone(),
+                diff: CatalogMetadataDiff::default(),
                 refreshed_at: ts,
             },
         );
@@ -305,6 +402,7 @@ mod tests {
             &CatalogState::NoCatalogSelected,
             &CatalogEvent::CatalogMetadataRefreshed {
                 metadata: meta,
+                diff: CatalogMetadataDiff::default(),
                 refreshed_at: ts,
             },
         );
@@ -331,6 +429,10 @@ mod tests {
                 refreshed_at: ts,
             })
             .then(vec![CatalogEvent::CatalogMetadataRefreshed {
+                diff: CatalogMetadataDiff {
+                    added: meta.datasets.clone(),
+                    ..CatalogMetadataDiff::default()
+                },
                 metadata: meta,
                 refreshed_at: ts,
             }]);
@@ -351,6 +453,7 @@ mod tests {
                 },
                 CatalogEvent::CatalogMetadataRefreshed {
                     metadata: meta,
+                    diff: CatalogMetadataDiff::default(),
                     refreshed_at: ts,
                 },
             ])
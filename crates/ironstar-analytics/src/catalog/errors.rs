@@ -30,6 +30,9 @@ pub enum CatalogErrorKind {
     /// Catalog reference URI exceeds maximum length.
     RefTooLong { max: usize, actual: usize },
 
+    /// Catalog reference is neither a `ducklake:` URI nor a file path.
+    InvalidCatalogRef(String),
+
     /// Cannot change to a different catalog while one is active.
     CatalogAlreadyActive,
 
@@ -84,6 +87,11 @@ impl CatalogError {
         Self::new(CatalogErrorKind::RefTooLong { max, actual })
     }
 
+    /// Creates an `InvalidCatalogRef` error with the given reason.
+    pub fn invalid_catalog_ref(reason: impl Into<String>) -> Self {
+        Self::new(CatalogErrorKind::InvalidCatalogRef(reason.into()))
+    }
+
     /// Creates a `CatalogAlreadyActive` error.
     pub fn catalog_already_active() -> Self {
         Self::new(CatalogErrorKind::CatalogAlreadyActive)
@@ -105,6 +113,9 @@ impl fmt::Display for CatalogError {
                     "catalog reference cannot exceed {max} characters (got {actual})"
                 )
             }
+            CatalogErrorKind::InvalidCatalogRef(reason) => {
+                write!(f, "invalid catalog reference: {reason}")
+            }
             CatalogErrorKind::CatalogAlreadyActive => {
                 write!(f, "cannot change active catalog; deselect first")
             }
@@ -140,6 +151,10 @@ mod tests {
             CatalogError::empty_ref().to_string(),
             "catalog reference cannot be empty"
         );
+        assert_eq!(
+            CatalogError::invalid_catalog_ref("unsupported scheme").to_string(),
+            "invalid catalog reference: unsupported scheme"
+        );
         assert_eq!(
             CatalogError::catalog_already_active().to_string(),
             "cannot change active catalog; deselect first"
@@ -1,11 +1,11 @@
 //! Events emitted by the Catalog aggregate.
 
 use chrono::{DateTime, Utc};
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use super::values::{CatalogMetadata, CatalogRef};
+use super::values::{CatalogMetadata, CatalogMetadataDiff, CatalogRef};
 
 /// Events emitted by the Catalog aggregate.
 ///
@@ -22,12 +22,34 @@ pub enum CatalogEvent {
     },
 
     /// Catalog metadata was refreshed with new dataset information.
+    ///
+    /// `diff` records the datasets added, removed, or changed relative to
+    /// the previous metadata. Events stored before diffs were recorded
+    /// deserialize with an empty diff.
     CatalogMetadataRefreshed {
         metadata: CatalogMetadata,
+        #[serde(default)]
+        diff: CatalogMetadataDiff,
         refreshed_at: DateTime<Utc>,
     },
 }
 
+impl CatalogEvent {
+    /// Get the event type name for storage and routing.
+    ///
+    /// This matches the serde tag value and is used for:
+    /// - Event store `event_type` column
+    /// - SSE event names
+    /// - Metrics and logging
+    #[must_use]
+    pub fn event_type_str(&self) -> &'static str {
+        match self {
+            Self::CatalogSelected { .. } => "CatalogSelected",
+            Self::CatalogMetadataRefreshed { .. } => "CatalogMetadataRefreshed",
+        }
+    }
+}
+
 impl Identifier for CatalogEvent {
     fn identifier(&self) -> String {
         // Singleton aggregate pattern
@@ -37,11 +59,16 @@ impl Identifier for CatalogEvent {
 
 impl EventType for CatalogEvent {
     fn event_type(&self) -> String {
+        self.event_type_str().to_string()
+    }
+}
+
+impl Timestamped for CatalogEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
         match self {
-            Self::CatalogSelected { .. } => "CatalogSelected",
-            Self::CatalogMetadataRefreshed { .. } => "CatalogMetadataRefreshed",
+            Self::CatalogSelected { selected_at, .. } => *selected_at,
+            Self::CatalogMetadataRefreshed { refreshed_at, .. } => *refreshed_at,
         }
-        .to_string()
     }
 }
 
@@ -56,3 +83,45 @@ impl IsFinal for CatalogEvent {
         false // Catalog can always be updated, never terminal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn event_type_matches_serde_tag() {
+        let events = [
+            (
+                CatalogEvent::CatalogSelected {
+                    catalog_ref: CatalogRef::new("ducklake://hf/sciexp/fixtures").unwrap(),
+                    selected_at: sample_time(),
+                },
+                "CatalogSelected",
+            ),
+            (
+                CatalogEvent::CatalogMetadataRefreshed {
+                    metadata: CatalogMetadata {
+                        datasets: Vec::new(),
+                        last_refreshed: sample_time(),
+                    },
+                    diff: CatalogMetadataDiff::default(),
+                    refreshed_at: sample_time(),
+                },
+                "CatalogMetadataRefreshed",
+            ),
+        ];
+
+        for (event, expected_type) in events {
+            let json = serde_json::to_value(&event).unwrap();
+            assert_eq!(json["type"], expected_type);
+            assert_eq!(event.event_type_str(), expected_type);
+            assert_eq!(EventType::event_type(&event), expected_type);
+        }
+    }
+}
@@ -15,4 +15,7 @@ pub use decider::{CatalogDecider, catalog_decider};
 pub use errors::{CatalogError, CatalogErrorKind};
 pub use events::CatalogEvent;
 pub use state::CatalogState;
-pub use values::{CATALOG_REF_MAX_LENGTH, CatalogMetadata, CatalogRef, DatasetInfo};
+pub use values::{
+    CATALOG_REF_MAX_LENGTH, CatalogMetadata, CatalogMetadataDiff, CatalogRef, DUCKLAKE_SCHEME,
+    DatasetInfo,
+};
@@ -13,14 +13,22 @@ use super::errors::CatalogErrorKind;
 /// Maximum length for a catalog URI in characters.
 pub const CATALOG_REF_MAX_LENGTH: usize = 1024;
 
+/// URI scheme prefix for DuckLake catalog references.
+pub const DUCKLAKE_SCHEME: &str = "ducklake:";
+
 /// Reference to a DuckLake catalog.
 ///
 /// Guarantees:
 /// - Non-empty (at least one non-whitespace character)
 /// - At most [`CATALOG_REF_MAX_LENGTH`] characters
 /// - Trimmed of leading/trailing whitespace
+/// - Either a `ducklake:` URI with a non-empty target, or a file path to a
+///   catalog database (no `scheme://` prefix)
+/// - No control characters
 ///
-/// Example values: `"ducklake://hf/sciexp/fixtures"`, `"ducklake://local/analytics"`
+/// Example values: `"ducklake://hf/sciexp/fixtures"`,
+/// `"ducklake:hf://datasets/sciexp/fixtures/lakes/frozen/space.db"`,
+/// `"./data/catalog.ducklake"`
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "domain/", type = "string")]
 #[serde(try_from = "String", into = "String")]
@@ -33,6 +41,8 @@ impl CatalogRef {
     ///
     /// - [`CatalogError::EmptyRef`] if the trimmed URI is empty
     /// - [`CatalogError::RefTooLong`] if the URI exceeds [`CATALOG_REF_MAX_LENGTH`]
+    /// - [`CatalogError::InvalidCatalogRef`] if the URI has a scheme other than
+    ///   `ducklake:`, an empty `ducklake:` target, or control characters
     pub fn new(uri: impl Into<String>) -> Result<Self, CatalogError> {
         let uri = uri.into();
         let trimmed = uri.trim();
@@ -49,6 +59,27 @@ impl CatalogRef {
             ));
         }
 
+        if trimmed.chars().any(char::is_control) {
+            return Err(CatalogError::invalid_catalog_ref(
+                "contains control characters",
+            ));
+        }
+
+        match trimmed.strip_prefix(DUCKLAKE_SCHEME) {
+            Some(target) if target.trim().is_empty() => {
+                return Err(CatalogError::invalid_catalog_ref(
+                    "ducklake: URI has no target",
+                ));
+            }
+            Some(_) => {}
+            None if uri_scheme(trimmed).is_some() => {
+                return Err(CatalogError::invalid_catalog_ref(
+                    "unsupported scheme; expected ducklake: or a file path",
+                ));
+            }
+            None => {}
+        }
+
         Ok(Self(trimmed.to_string()))
     }
 
@@ -57,6 +88,27 @@ impl CatalogRef {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Whether the catalog is loaded over the network rather than from disk.
+    ///
+    /// True for `ducklake:` URIs whose target carries its own scheme, such as
+    /// `ducklake:hf://...` or `ducklake:s3://...`. Plain file paths and
+    /// `ducklake:` targets without a scheme are local.
+    #[must_use]
+    pub fn is_remote(&self) -> bool {
+        self.0
+            .strip_prefix(DUCKLAKE_SCHEME)
+            .is_some_and(|target| uri_scheme(target).is_some())
+    }
+}
+
+/// The scheme of a `scheme://...` URI, if `s` has one.
+fn uri_scheme(s: &str) -> Option<&str> {
+    let (scheme, _) = s.split_once("://")?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some(scheme)
 }
 
 impl std::fmt::Display for CatalogRef {
@@ -89,6 +141,13 @@ pub struct DatasetInfo {
     pub table_count: usize,
     /// Schema version string.
     pub schema_version: String,
+    /// Number of rows, when counted during refresh.
+    ///
+    /// Counting scans every dataset, so it only happens when a refresh opts
+    /// in. `None` means the count was not requested or could not be taken.
+    #[serde(default)]
+    #[ts(optional)]
+    pub row_count: Option<u64>,
 }
 
 /// Metadata about a DuckLake catalog.
@@ -105,6 +164,65 @@ pub struct CatalogMetadata {
     pub last_refreshed: chrono::DateTime<chrono::Utc>,
 }
 
+impl CatalogMetadata {
+    /// Datasets whose name contains `query`, ignoring case and surrounding
+    /// whitespace, in catalog order. A blank query matches every dataset.
+    pub fn datasets_matching(&self, query: &str) -> impl Iterator<Item = &DatasetInfo> {
+        let query = query.trim().to_lowercase();
+        self.datasets
+            .iter()
+            .filter(move |d| d.name.to_lowercase().contains(&query))
+    }
+}
+
+/// Dataset changes between two catalog metadata snapshots.
+///
+/// Datasets are matched by name. A dataset present in both snapshots whose
+/// table count, schema version, or row count differs is reported as changed, carrying its
+/// new details. The refresh timestamp is not compared.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "domain/")]
+pub struct CatalogMetadataDiff {
+    /// Datasets present only in the new metadata.
+    pub added: Vec<DatasetInfo>,
+    /// Datasets present only in the old metadata.
+    pub removed: Vec<DatasetInfo>,
+    /// Datasets present in both whose details changed, as they are now.
+    pub changed: Vec<DatasetInfo>,
+}
+
+impl CatalogMetadataDiff {
+    /// Compute the changes from `old` to `new`, in the order datasets appear.
+    #[must_use]
+    pub fn between(old: &CatalogMetadata, new: &CatalogMetadata) -> Self {
+        let find = |datasets: &[DatasetInfo], name: &str| {
+            datasets.iter().find(|d| d.name == name).cloned()
+        };
+
+        let mut diff = Self::default();
+        for dataset in &new.datasets {
+            match find(&old.datasets, &dataset.name) {
+                None => diff.added.push(dataset.clone()),
+                Some(previous) if previous != *dataset => diff.changed.push(dataset.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = old
+            .datasets
+            .iter()
+            .filter(|d| find(&new.datasets, &d.name).is_none())
+            .cloned()
+            .collect();
+        diff
+    }
+
+    /// Whether no dataset was added, removed, or changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,6 +276,48 @@ mod tests {
             assert!(CatalogRef::new(&max).is_ok());
         }
 
+        #[test]
+        fn accepts_ducklake_and_file_refs() {
+            for uri in [
+                "ducklake:my_catalog",
+                "ducklake://hf/sciexp/fixtures",
+                "ducklake:hf://datasets/sciexp/fixtures/lakes/frozen/space.db",
+                "./data/catalog.ducklake",
+                "/var/lib/ironstar/catalog.db",
+            ] {
+                assert!(CatalogRef::new(uri).is_ok(), "{uri}");
+            }
+        }
+
+        #[test]
+        fn rejects_unsupported_scheme() {
+            let result = CatalogRef::new("postgres://db/catalog");
+            assert!(matches!(
+                result.unwrap_err().kind(),
+                CatalogErrorKind::InvalidCatalogRef(_)
+            ));
+        }
+
+        #[test]
+        fn rejects_ducklake_without_target() {
+            let result = CatalogRef::new("ducklake:");
+            assert!(matches!(
+                result.unwrap_err().kind(),
+                CatalogErrorKind::InvalidCatalogRef(_)
+            ));
+        }
+
+        #[test]
+        fn remote_refs_have_a_target_scheme() {
+            let remote = CatalogRef::new("ducklake:hf://datasets/sciexp").unwrap();
+            let named = CatalogRef::new("ducklake:my_catalog").unwrap();
+            let file = CatalogRef::new("./data/catalog.ducklake").unwrap();
+
+            assert!(remote.is_remote());
+            assert!(!named.is_remote());
+            assert!(!file.is_remote());
+        }
+
         #[test]
         fn serde_roundtrip() {
             let original = CatalogRef::new("ducklake://test").unwrap();
@@ -173,4 +333,84 @@ mod tests {
             assert!(result.is_err());
         }
     }
+
+    mod metadata_diff {
+        use super::*;
+        use chrono::Utc;
+
+        fn dataset(name: &str, table_count: usize) -> DatasetInfo {
+            DatasetInfo {
+                name: name.to_string(),
+                table_count,
+                schema_version: "1.0".to_string(),
+                row_count: None,
+            }
+        }
+
+        fn metadata(datasets: Vec<DatasetInfo>) -> CatalogMetadata {
+            CatalogMetadata {
+                datasets,
+                last_refreshed: Utc::now(),
+            }
+        }
+
+        #[test]
+        fn reports_added_removed_and_changed_datasets() {
+            let old = metadata(vec![dataset("genomics", 5), dataset("proteomics", 3)]);
+            let new = metadata(vec![dataset("genomics", 6), dataset("imaging", 2)]);
+
+            let diff = CatalogMetadataDiff::between(&old, &new);
+
+            assert_eq!(diff.added, vec![dataset("imaging", 2)]);
+            assert_eq!(diff.removed, vec![dataset("proteomics", 3)]);
+            assert_eq!(diff.changed, vec![dataset("genomics", 6)]);
+        }
+
+        #[test]
+        fn identical_datasets_produce_empty_diff() {
+            let old = metadata(vec![dataset("genomics", 5)]);
+            let new = metadata(vec![dataset("genomics", 5)]);
+
+            assert!(CatalogMetadataDiff::between(&old, &new).is_empty());
+        }
+    }
+
+    mod datasets_matching {
+        use super::*;
+        use chrono::Utc;
+
+        fn metadata(names: &[&str]) -> CatalogMetadata {
+            CatalogMetadata {
+                datasets: names
+                    .iter()
+                    .map(|name| DatasetInfo {
+                        name: (*name).to_string(),
+                        table_count: 1,
+                        schema_version: "1.0".to_string(),
+                        row_count: None,
+                    })
+                    .collect(),
+                last_refreshed: Utc::now(),
+            }
+        }
+
+        fn names<'a>(datasets: impl Iterator<Item = &'a DatasetInfo>) -> Vec<&'a str> {
+            datasets.map(|d| d.name.as_str()).collect()
+        }
+
+        #[test]
+        fn matches_substring_ignoring_case() {
+            let catalog = metadata(&["Genomics", "proteomics", "imaging"]);
+            assert_eq!(
+                names(catalog.datasets_matching(" OMICS ")),
+                vec!["Genomics", "proteomics"]
+            );
+        }
+
+        #[test]
+        fn blank_query_matches_all() {
+            let catalog = metadata(&["genomics", "imaging"]);
+            assert_eq!(names(catalog.datasets_matching("")).len(), 2);
+        }
+    }
 }
@@ -2,13 +2,26 @@
 //!
 //! This module implements the Idris spec's `analyticsDecider = combine catalogDecider queryDecider`
 //! using fmodel-rust's `Decider::combine` method.
-
-use crate::catalog::{CatalogCommand, CatalogError, CatalogEvent, CatalogState, catalog_decider};
+//!
+//! # Event ordering
+//!
+//! Whenever one command yields events from both sub-deciders, every catalog
+//! event (`Sum::First`) precedes every query-session event (`Sum::Second`) in
+//! the emitted vec, and each side keeps the order its decider produced.
+//! Projections folding the stream can therefore rely on a query's catalog
+//! already being selected when they see it start.
+
+use crate::catalog::{
+    CatalogCommand, CatalogError, CatalogEvent, CatalogRef, CatalogState, catalog_decider,
+};
 use crate::query_session::{
     QuerySessionCommand, QuerySessionError, QuerySessionEvent, QuerySessionState,
     query_session_decider,
 };
-use ironstar_core::{Decider, Sum};
+use crate::values::{ChartConfig, DatasetRef, QueryId, SqlQuery};
+use chrono::{DateTime, Utc};
+use ironstar_core::{Decider, DeciderType, Sum};
+use ironstar_shared_kernel::UserId;
 use std::fmt;
 
 /// Unified error type for the combined Analytics Decider.
@@ -53,8 +66,47 @@ impl From<QuerySessionError> for CombinedDeciderError {
     }
 }
 
-/// Type alias for the combined Analytics command type.
-pub type AnalyticsCommand = Sum<CatalogCommand, QuerySessionCommand>;
+impl DeciderType for CombinedDeciderError {
+    /// Name of the sub-decider that rejected the command.
+    fn decider_type(&self) -> String {
+        match self {
+            Self::Catalog(_) => "Catalog".to_string(),
+            Self::QuerySession(_) => "QuerySession".to_string(),
+        }
+    }
+}
+
+/// Commands accepted by the combined Analytics Decider.
+///
+/// `First` and `Second` route to a single sub-decider, mirroring `Sum`.
+/// `SelectCatalogAndQuery` drives both in one decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnalyticsCommand {
+    /// A command for the Catalog Decider.
+    First(CatalogCommand),
+    /// A command for the QuerySession Decider.
+    Second(QuerySessionCommand),
+    /// Select a catalog and start a query against it.
+    ///
+    /// Emits `CatalogSelected` followed by `QueryStarted`. Fails without
+    /// emitting anything if either sub-decider rejects its part.
+    SelectCatalogAndQuery {
+        /// Reference to the catalog to select.
+        catalog_ref: CatalogRef,
+        /// Unique identifier for the query.
+        query_id: QueryId,
+        /// The SQL query to execute.
+        sql: SqlQuery,
+        /// Optional dataset reference (for remote data sources).
+        dataset_ref: Option<DatasetRef>,
+        /// Optional chart configuration for visualization.
+        chart_config: Option<ChartConfig>,
+        /// Timestamp of both the selection and the query start.
+        at: DateTime<Utc>,
+        /// User starting the query.
+        actor: UserId,
+    },
+}
 
 /// Type alias for the combined Analytics state type.
 pub type AnalyticsState = (CatalogState, QuerySessionState);
@@ -70,9 +122,16 @@ pub type AnalyticsDecider<'a> =
 ///
 /// Composes `catalog_decider` and `query_session_decider` using fmodel-rust's
 /// `Decider::combine`. Commands are routed to the appropriate sub-decider via
-/// `Sum::First` (Catalog) or `Sum::Second` (QuerySession).
+/// `AnalyticsCommand::First` (Catalog) or `AnalyticsCommand::Second`
+/// (QuerySession); `SelectCatalogAndQuery` is decided by both.
+///
+/// Emitted events follow the ordering described in the [module docs](self).
 pub fn analytics_decider<'a>() -> AnalyticsDecider<'a> {
-    catalog_decider()
+    let Decider {
+        decide,
+        evolve,
+        initial_state,
+    } = catalog_decider()
         .map_error(|e: &CatalogError| {
             CombinedDeciderError::Catalog(CatalogError::with_id(e.error_id(), e.kind().clone()))
         })
@@ -81,7 +140,52 @@ pub fn analytics_decider<'a>() -> AnalyticsDecider<'a> {
                 e.error_id(),
                 e.kind().clone(),
             ))
-        }))
+        }));
+
+    Decider {
+        decide: Box::new(move |command, state| {
+            let events = match command {
+                AnalyticsCommand::First(command) => decide(&Sum::First(command.clone()), state)?,
+                AnalyticsCommand::Second(command) => decide(&Sum::Second(command.clone()), state)?,
+                AnalyticsCommand::SelectCatalogAndQuery {
+                    catalog_ref,
+                    query_id,
+                    sql,
+                    dataset_ref,
+                    chart_config,
+                    at,
+                    actor,
+                } => {
+                    let select = CatalogCommand::SelectCatalog {
+                        catalog_ref: catalog_ref.clone(),
+                        selected_at: *at,
+                    };
+                    let start = QuerySessionCommand::StartQuery {
+                        query_id: *query_id,
+                        sql: sql.clone(),
+                        dataset_ref: dataset_ref.clone(),
+                        chart_config: chart_config.clone(),
+                        started_at: *at,
+                        actor: *actor,
+                    };
+                    // The sub-states are disjoint, so both parts are decided
+                    // against the same state.
+                    let mut events = decide(&Sum::First(select), state)?;
+                    events.extend(decide(&Sum::Second(start), state)?);
+                    events
+                }
+            };
+            Ok(catalog_events_first(events))
+        }),
+        evolve,
+        initial_state,
+    }
+}
+
+/// Stably move catalog events ahead of query-session events.
+fn catalog_events_first(mut events: Vec<AnalyticsEvent>) -> Vec<AnalyticsEvent> {
+    events.sort_by_key(|event| matches!(event, Sum::Second(_)));
+    events
 }
 
 #[cfg(test)]
@@ -91,6 +195,7 @@ mod tests {
     use crate::catalog::{CatalogMetadata, CatalogRef, DatasetInfo};
     use crate::values::{QueryId, SqlQuery};
     use chrono::Utc;
+    use ironstar_shared_kernel::UserId;
 
     fn sample_catalog_ref() -> CatalogRef {
         CatalogRef::try_from("ducklake:test".to_string()).expect("valid catalog ref")
@@ -102,6 +207,7 @@ mod tests {
                 name: "ds".to_string(),
                 table_count: 1,
                 schema_version: "1.0".to_string(),
+                row_count: None,
             }],
             last_refreshed: Utc::now(),
         }
@@ -145,6 +251,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: Utc::now(),
+            actor: UserId::system(),
         });
 
         let events = (decider.decide)(&command, &state);
@@ -184,6 +291,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: Utc::now(),
+            actor: UserId::system(),
         });
 
         let new_state = (decider.evolve)(&state, &event);
@@ -209,4 +317,92 @@ mod tests {
             CombinedDeciderError::Catalog(_)
         ));
     }
+
+    fn select_and_query(sql: &str) -> AnalyticsCommand {
+        AnalyticsCommand::SelectCatalogAndQuery {
+            catalog_ref: sample_catalog_ref(),
+            query_id: QueryId::new(),
+            sql: SqlQuery::try_from(sql.to_string()).expect("valid SQL"),
+            dataset_ref: None,
+            chart_config: None,
+            at: Utc::now(),
+            actor: UserId::system(),
+        }
+    }
+
+    #[test]
+    fn select_and_query_emits_catalog_events_first() {
+        let decider = analytics_decider();
+        let state = (decider.initial_state)();
+
+        let events = (decider.decide)(&select_and_query("SELECT 1"), &state)
+            .expect("select and query should succeed");
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0],
+            Sum::First(CatalogEvent::CatalogSelected { .. })
+        ));
+        assert!(matches!(
+            events[1],
+            Sum::Second(QuerySessionEvent::QueryStarted { .. })
+        ));
+    }
+
+    #[test]
+    fn select_and_query_reports_failing_sub_decider() {
+        let decider = analytics_decider();
+        let initial = (decider.initial_state)();
+
+        let selected = (decider.evolve)(
+            &initial,
+            &AnalyticsEvent::First(CatalogEvent::CatalogSelected {
+                catalog_ref: CatalogRef::try_from("ducklake:other".to_string())
+                    .expect("valid catalog ref"),
+                selected_at: Utc::now(),
+            }),
+        );
+        let error = (decider.decide)(&select_and_query("SELECT 1"), &selected)
+            .expect_err("another catalog is active");
+        assert!(matches!(error, CombinedDeciderError::Catalog(_)));
+        assert_eq!(error.decider_type(), "Catalog");
+
+        let pending = (decider.evolve)(
+            &initial,
+            &AnalyticsEvent::Second(QuerySessionEvent::QueryStarted {
+                query_id: QueryId::new(),
+                sql: SqlQuery::try_from("SELECT 1".to_string()).expect("valid SQL"),
+                dataset_ref: None,
+                chart_config: None,
+                started_at: Utc::now(),
+                actor: UserId::system(),
+            }),
+        );
+        let error = (decider.decide)(&select_and_query("SELECT 2"), &pending)
+            .expect_err("query already pending");
+        assert!(matches!(error, CombinedDeciderError::QuerySession(_)));
+        assert_eq!(error.decider_type(), "QuerySession");
+    }
+
+    #[test]
+    fn catalog_events_first_is_stable() {
+        let started = |n: &str| {
+            Sum::Second(QuerySessionEvent::QueryStarted {
+                query_id: QueryId::new(),
+                sql: SqlQuery::try_from(format!("SELECT {n}")).expect("valid SQL"),
+                dataset_ref: None,
+                chart_config: None,
+                started_at: Utc::now(),
+                actor: UserId::system(),
+            })
+        };
+        let selected = AnalyticsEvent::First(CatalogEvent::CatalogSelected {
+            catalog_ref: sample_catalog_ref(),
+            selected_at: Utc::now(),
+        });
+        let (a, b) = (started("1"), started("2"));
+
+        let ordered = catalog_events_first(vec![a.clone(), selected.clone(), b.clone()]);
+        assert_eq!(ordered, vec![selected, a, b]);
+    }
 }
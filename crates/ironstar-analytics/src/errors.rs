@@ -44,6 +44,9 @@ pub enum AnalyticsValidationErrorKind {
     /// SQL query exceeds maximum length.
     SqlTooLong { max: usize, actual: usize },
 
+    /// SQL query is not a single read-only statement.
+    NotReadOnly { reason: &'static str },
+
     /// Dataset reference cannot be empty.
     EmptyDatasetRef,
 
@@ -58,6 +61,9 @@ pub enum AnalyticsValidationErrorKind {
 
     /// Schema compatibility error (column not found, type mismatch, etc.).
     SchemaIncompatible { message: String },
+
+    /// Query and dataset reference disagree on whether a dataset is read.
+    DatasetReferenceMismatch { reason: &'static str },
 }
 
 impl AnalyticsValidationError {
@@ -97,6 +103,11 @@ impl AnalyticsValidationError {
         Self::new(AnalyticsValidationErrorKind::SqlTooLong { max, actual })
     }
 
+    /// Creates a `NotReadOnly` error.
+    pub fn not_read_only(reason: &'static str) -> Self {
+        Self::new(AnalyticsValidationErrorKind::NotReadOnly { reason })
+    }
+
     /// Creates an `EmptyDatasetRef` error.
     pub fn empty_dataset_ref() -> Self {
         Self::new(AnalyticsValidationErrorKind::EmptyDatasetRef)
@@ -123,6 +134,11 @@ impl AnalyticsValidationError {
             message: message.into(),
         })
     }
+
+    /// Creates a `DatasetReferenceMismatch` error.
+    pub fn dataset_reference_mismatch(reason: &'static str) -> Self {
+        Self::new(AnalyticsValidationErrorKind::DatasetReferenceMismatch { reason })
+    }
 }
 
 impl fmt::Display for AnalyticsValidationError {
@@ -132,6 +148,9 @@ impl fmt::Display for AnalyticsValidationError {
             AnalyticsValidationErrorKind::SqlTooLong { max, actual } => {
                 write!(f, "SQL query cannot exceed {max} characters (got {actual})")
             }
+            AnalyticsValidationErrorKind::NotReadOnly { reason } => {
+                write!(f, "SQL query is not read-only: {reason}")
+            }
             AnalyticsValidationErrorKind::EmptyDatasetRef => {
                 write!(f, "dataset reference cannot be empty")
             }
@@ -150,6 +169,9 @@ impl fmt::Display for AnalyticsValidationError {
             AnalyticsValidationErrorKind::SchemaIncompatible { message } => {
                 write!(f, "schema incompatible: {message}")
             }
+            AnalyticsValidationErrorKind::DatasetReferenceMismatch { reason } => {
+                write!(f, "dataset reference mismatch: {reason}")
+            }
         }
     }
 }
@@ -196,6 +218,9 @@ pub enum AnalyticsErrorKind {
     /// Resource exhausted (memory, connections, etc.).
     ResourceExhausted { resource: String },
 
+    /// Query returned no rows where at least one was required.
+    EmptyResult { query_id: Uuid },
+
     /// Validation error (wraps AnalyticsValidationError).
     Validation(AnalyticsValidationError),
 }
@@ -256,6 +281,11 @@ impl AnalyticsError {
         })
     }
 
+    /// Creates an `EmptyResult` error.
+    pub fn empty_result(query_id: Uuid) -> Self {
+        Self::new(AnalyticsErrorKind::EmptyResult { query_id })
+    }
+
     /// Creates a `Validation` error wrapping an `AnalyticsValidationError`.
     pub fn validation(err: AnalyticsValidationError) -> Self {
         Self::new(AnalyticsErrorKind::Validation(err))
@@ -280,6 +310,9 @@ impl fmt::Display for AnalyticsError {
             AnalyticsErrorKind::ResourceExhausted { resource } => {
                 write!(f, "resource exhausted: {resource}")
             }
+            AnalyticsErrorKind::EmptyResult { query_id } => {
+                write!(f, "query {query_id} returned no rows")
+            }
             AnalyticsErrorKind::Validation(err) => {
                 write!(f, "validation error: {err}")
             }
@@ -323,6 +356,11 @@ mod tests {
             AnalyticsValidationError::sql_too_long(1000, 1500).to_string(),
             "SQL query cannot exceed 1000 characters (got 1500)"
         );
+        assert_eq!(
+            AnalyticsValidationError::not_read_only("only a single statement is allowed")
+                .to_string(),
+            "SQL query is not read-only: only a single statement is allowed"
+        );
         assert_eq!(
             AnalyticsValidationError::invalid_chart_config("missing series").to_string(),
             "invalid chart configuration: missing series"
@@ -373,6 +411,11 @@ mod tests {
             "resource exhausted: memory"
         );
 
+        assert_eq!(
+            AnalyticsError::empty_result(query_id).to_string(),
+            format!("query {query_id} returned no rows")
+        );
+
         let validation_err = AnalyticsValidationError::empty_sql();
         let wrapped = AnalyticsError::validation(validation_err);
         assert_eq!(
@@ -9,6 +9,7 @@ pub mod catalog;
 pub mod combined;
 pub mod errors;
 pub mod query_session;
+mod sql_statement;
 pub mod values;
 pub mod views;
 pub mod workflow;
@@ -26,8 +27,8 @@ pub use errors::{
 
 // Re-export values
 pub use values::{
-    ChartConfig, ChartType, DATASET_REF_MAX_LENGTH, DatasetRef, QueryId, SQL_QUERY_MAX_LENGTH,
-    SqlQuery,
+    CancellationReason, ChartConfig, ChartType, DATASET_REF_MAX_LENGTH, DatasetRef, QueryId,
+    SQL_QUERY_MAX_LENGTH, SqlQuery,
 };
 
 // Re-export workflow types and functions
@@ -2,10 +2,11 @@
 
 use chrono::{DateTime, Utc};
 use ironstar_core::{DeciderType, Identifier};
+use ironstar_shared_kernel::UserId;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::values::{ChartConfig, DatasetRef, QueryId, SqlQuery};
+use crate::values::{CancellationReason, ChartConfig, DatasetRef, QueryId, SqlQuery};
 
 /// Commands for the QuerySession aggregate.
 ///
@@ -15,7 +16,7 @@ use crate::values::{ChartConfig, DatasetRef, QueryId, SqlQuery};
 ///
 /// All commands include timestamp fields for pure decision-making. Timestamps
 /// are injected by the application layer; the decider never calls `Utc::now()`.
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "domain/")]
 #[serde(tag = "type")]
 pub enum QuerySessionCommand {
@@ -33,6 +34,9 @@ pub enum QuerySessionCommand {
         chart_config: Option<ChartConfig>,
         /// Timestamp when the query was started (injected by application layer).
         started_at: DateTime<Utc>,
+        /// User starting the query.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
     },
 
     /// Mark query execution as started (called by application layer).
@@ -50,6 +54,8 @@ pub enum QuerySessionCommand {
         query_id: QueryId,
         /// Number of rows returned.
         row_count: usize,
+        /// Cache key under which the query result is stored.
+        result_ref: String,
         /// Execution duration in milliseconds.
         duration_ms: u64,
         /// Timestamp when the query completed (injected by application layer).
@@ -70,9 +76,9 @@ pub enum QuerySessionCommand {
     CancelQuery {
         /// Must match the current query ID.
         query_id: QueryId,
-        /// Optional reason for cancellation.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        reason: Option<String>,
+        /// Why the query is being cancelled.
+        #[serde(default)]
+        reason: CancellationReason,
         /// Timestamp when the query was cancelled (injected by application layer).
         cancelled_at: DateTime<Utc>,
     },
@@ -124,3 +130,68 @@ impl DeciderType for QuerySessionCommand {
         "QuerySession".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::ChartType;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            QuerySessionCommand::StartQuery {
+                query_id: QueryId::new(),
+                sql: SqlQuery::new("SELECT 1").unwrap(),
+                dataset_ref: Some(DatasetRef::new("hf://datasets/test/data").unwrap()),
+                chart_config: Some(ChartConfig::new(ChartType::Bar)),
+                started_at: ts,
+                actor: UserId::system(),
+            },
+            QuerySessionCommand::StartQuery {
+                query_id: QueryId::new(),
+                sql: SqlQuery::new("SELECT 2").unwrap(),
+                dataset_ref: None,
+                chart_config: None,
+                started_at: ts,
+                actor: UserId::system(),
+            },
+            QuerySessionCommand::BeginExecution {
+                query_id: QueryId::new(),
+                began_at: ts,
+            },
+            QuerySessionCommand::CompleteQuery {
+                query_id: QueryId::new(),
+                row_count: 42,
+                result_ref: "query_session:result:1".to_string(),
+                duration_ms: 150,
+                completed_at: ts,
+            },
+            QuerySessionCommand::FailQuery {
+                query_id: QueryId::new(),
+                error: "syntax error".to_string(),
+                failed_at: ts,
+            },
+            QuerySessionCommand::CancelQuery {
+                query_id: QueryId::new(),
+                reason: CancellationReason::UserCancelled,
+                cancelled_at: ts,
+            },
+            QuerySessionCommand::ResetSession { reset_at: ts },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: QuerySessionCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
+}
@@ -50,6 +50,7 @@ fn decide(
             dataset_ref,
             chart_config,
             started_at,
+            actor,
         } => {
             if state.is_idle() {
                 Ok(vec![QuerySessionEvent::QueryStarted {
@@ -58,6 +59,7 @@ fn decide(
                     dataset_ref: dataset_ref.clone(),
                     chart_config: chart_config.clone(),
                     started_at: *started_at,
+                    actor: *actor,
                 }])
             } else if state.is_in_progress() {
                 Err(QuerySessionError::query_already_in_progress())
@@ -92,6 +94,7 @@ fn decide(
         QuerySessionCommand::CompleteQuery {
             query_id,
             row_count,
+            result_ref,
             duration_ms,
             completed_at,
         } => match &state.status {
@@ -108,6 +111,7 @@ fn decide(
                 Ok(vec![QuerySessionEvent::QueryCompleted {
                     query_id: *query_id,
                     row_count: *row_count,
+                    result_ref: result_ref.clone(),
                     duration_ms: *duration_ms,
                     completed_at: *completed_at,
                 }])
@@ -214,6 +218,7 @@ fn evolve(state: &QuerySessionState, event: &QuerySessionEvent) -> QuerySessionS
             dataset_ref,
             chart_config,
             started_at,
+            actor,
         } => QuerySessionState {
             status: QuerySessionStatus::Pending {
                 query_id: *query_id,
@@ -221,6 +226,7 @@ fn evolve(state: &QuerySessionState, event: &QuerySessionEvent) -> QuerySessionS
                 dataset_ref: dataset_ref.clone(),
                 chart_config: chart_config.clone(),
                 started_at: *started_at,
+                started_by: *actor,
             },
             query_count: state.query_count,
         },
@@ -232,6 +238,7 @@ fn evolve(state: &QuerySessionState, event: &QuerySessionEvent) -> QuerySessionS
                 dataset_ref,
                 chart_config,
                 started_at,
+                started_by,
             } = &state.status
             {
                 QuerySessionState {
@@ -241,6 +248,7 @@ fn evolve(state: &QuerySessionState, event: &QuerySessionEvent) -> QuerySessionS
                         dataset_ref: dataset_ref.clone(),
                         chart_config: chart_config.clone(),
                         started_at: *started_at,
+                        started_by: *started_by,
                         began_at: *began_at,
                     },
                     query_count: state.query_count,
@@ -253,12 +261,14 @@ fn evolve(state: &QuerySessionState, event: &QuerySessionEvent) -> QuerySessionS
         QuerySessionEvent::QueryCompleted {
             query_id,
             row_count,
+            result_ref,
             duration_ms,
             completed_at,
         } => QuerySessionState {
             status: QuerySessionStatus::Completed {
                 query_id: *query_id,
                 row_count: *row_count,
+                result_ref: result_ref.clone(),
                 duration_ms: *duration_ms,
                 completed_at: *completed_at,
             },
@@ -303,8 +313,9 @@ mod tests {
     use super::*;
     use chrono::{DateTime, Utc};
     use ironstar_core::DeciderTestSpecification;
+    use ironstar_shared_kernel::UserId;
 
-    use crate::values::{QueryId, SqlQuery};
+    use crate::values::{CancellationReason, QueryId, SqlQuery};
 
     fn sample_query_id() -> QueryId {
         QueryId::from_uuid(uuid::Uuid::nil())
@@ -336,6 +347,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             })
             .then(vec![QuerySessionEvent::QueryStarted {
                 query_id,
@@ -343,6 +355,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }]);
     }
 
@@ -359,6 +372,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }])
             .when(QuerySessionCommand::StartQuery {
                 query_id: QueryId::new(), // Different ID
@@ -366,6 +380,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             })
             .then_error(QuerySessionError::query_already_in_progress());
     }
@@ -384,6 +399,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -392,6 +408,7 @@ mod tests {
                 QuerySessionEvent::QueryCompleted {
                     query_id,
                     row_count: 10,
+                    result_ref: "query_session:result:1".to_string(),
                     duration_ms: 100,
                     completed_at: ts,
                 },
@@ -402,6 +419,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             })
             .then_error(QuerySessionError::terminal_state("completed"));
     }
@@ -421,6 +439,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }])
             .when(QuerySessionCommand::BeginExecution {
                 query_id,
@@ -446,6 +465,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }])
             .when(QuerySessionCommand::BeginExecution {
                 query_id: wrong_id,
@@ -484,6 +504,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -493,12 +514,14 @@ mod tests {
             .when(QuerySessionCommand::CompleteQuery {
                 query_id,
                 row_count: 100,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 1500,
                 completed_at: ts,
             })
             .then(vec![QuerySessionEvent::QueryCompleted {
                 query_id,
                 row_count: 100,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 1500,
                 completed_at: ts,
             }]);
@@ -519,6 +542,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -528,6 +552,7 @@ mod tests {
             .when(QuerySessionCommand::CompleteQuery {
                 query_id: wrong_id,
                 row_count: 100,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 1500,
                 completed_at: ts,
             })
@@ -547,10 +572,12 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }])
             .when(QuerySessionCommand::CompleteQuery {
                 query_id,
                 row_count: 100,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 1500,
                 completed_at: ts,
             })
@@ -576,6 +603,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -609,6 +637,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -638,15 +667,16 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }])
             .when(QuerySessionCommand::CancelQuery {
                 query_id,
-                reason: Some("User cancelled".to_string()),
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             })
             .then(vec![QuerySessionEvent::QueryCancelled {
                 query_id,
-                reason: Some("User cancelled".to_string()),
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             }]);
     }
@@ -665,6 +695,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -673,12 +704,12 @@ mod tests {
             ])
             .when(QuerySessionCommand::CancelQuery {
                 query_id,
-                reason: None,
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             })
             .then(vec![QuerySessionEvent::QueryCancelled {
                 query_id,
-                reason: None,
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             }]);
     }
@@ -697,10 +728,11 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }])
             .when(QuerySessionCommand::CancelQuery {
                 query_id: wrong_id,
-                reason: None,
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             })
             .then_error(QuerySessionError::query_id_mismatch(query_id, wrong_id));
@@ -715,7 +747,7 @@ mod tests {
             .given(vec![])
             .when(QuerySessionCommand::CancelQuery {
                 query_id: sample_query_id(),
-                reason: None,
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             })
             .then_error(QuerySessionError::no_query_in_progress());
@@ -735,6 +767,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -743,13 +776,14 @@ mod tests {
                 QuerySessionEvent::QueryCompleted {
                     query_id,
                     row_count: 10,
+                    result_ref: "query_session:result:1".to_string(),
                     duration_ms: 100,
                     completed_at: ts,
                 },
             ])
             .when(QuerySessionCommand::CancelQuery {
                 query_id,
-                reason: None,
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             })
             .then_error(QuerySessionError::terminal_state("completed"));
@@ -771,6 +805,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -779,6 +814,7 @@ mod tests {
                 QuerySessionEvent::QueryCompleted {
                     query_id,
                     row_count: 10,
+                    result_ref: "query_session:result:1".to_string(),
                     duration_ms: 100,
                     completed_at: ts,
                 },
@@ -815,6 +851,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             },
             &state,
         )
@@ -836,6 +873,7 @@ mod tests {
             &QuerySessionCommand::CompleteQuery {
                 query_id,
                 row_count: 100,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 500,
                 completed_at: ts,
             },
@@ -866,6 +904,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -895,10 +934,11 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::QueryCancelled {
                     query_id,
-                    reason: None,
+                    reason: CancellationReason::UserCancelled,
                     cancelled_at: ts,
                 },
             ])
@@ -919,6 +959,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             }])
             .when(QuerySessionCommand::ResetSession { reset_at: ts })
             .then_error(QuerySessionError::invalid_transition(
@@ -941,6 +982,7 @@ mod tests {
                     dataset_ref: None,
                     chart_config: None,
                     started_at: ts,
+                    actor: UserId::system(),
                 },
                 QuerySessionEvent::ExecutionBegan {
                     query_id,
@@ -974,6 +1016,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             },
         );
         assert_eq!(state.query_count, 0);
@@ -994,6 +1037,7 @@ mod tests {
             &QuerySessionEvent::QueryCompleted {
                 query_id,
                 row_count: 10,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 100,
                 completed_at: ts,
             },
@@ -1014,6 +1058,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                started_by: UserId::system(),
                 began_at: ts,
             },
             query_count: 5,
@@ -1044,6 +1089,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                started_by: UserId::system(),
             },
             query_count: 3,
         };
@@ -1053,7 +1099,7 @@ mod tests {
             &state,
             &QuerySessionEvent::QueryCancelled {
                 query_id,
-                reason: None,
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: ts,
             },
         );
@@ -1070,6 +1116,7 @@ mod tests {
             status: QuerySessionStatus::Completed {
                 query_id,
                 row_count: 10,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 100,
                 completed_at: ts,
             },
@@ -1099,6 +1146,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             },
             &state,
         )
@@ -1146,6 +1194,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: ts,
+                actor: UserId::system(),
             },
             &state,
         )
@@ -1156,7 +1205,7 @@ mod tests {
         let events = decide(
             &QuerySessionCommand::CancelQuery {
                 query_id,
-                reason: Some("Changed my mind".to_string()),
+                reason: CancellationReason::Other("Changed my mind".to_string()),
                 cancelled_at: ts,
             },
             &state,
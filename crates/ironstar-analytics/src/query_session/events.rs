@@ -1,11 +1,12 @@
 //! Events emitted by the QuerySession aggregate.
 
 use chrono::{DateTime, Utc};
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
+use ironstar_shared_kernel::UserId;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::values::{ChartConfig, DatasetRef, QueryId, SqlQuery};
+use crate::values::{CancellationReason, ChartConfig, DatasetRef, QueryId, SqlQuery};
 
 /// Events emitted by the QuerySession aggregate.
 ///
@@ -28,6 +29,10 @@ pub enum QuerySessionEvent {
         #[serde(skip_serializing_if = "Option::is_none")]
         chart_config: Option<ChartConfig>,
         started_at: DateTime<Utc>,
+        /// Absent in events recorded before queries were attributed, which
+        /// read as started by the anonymous user.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
     },
 
     /// Query execution has begun (DuckDB task spawned).
@@ -40,6 +45,11 @@ pub enum QuerySessionEvent {
     QueryCompleted {
         query_id: QueryId,
         row_count: usize,
+        /// Cache key under which the query result is stored.
+        ///
+        /// Empty for queries completed before results were cached.
+        #[serde(default)]
+        result_ref: String,
         duration_ms: u64,
         completed_at: DateTime<Utc>,
     },
@@ -51,11 +61,13 @@ pub enum QuerySessionEvent {
         failed_at: DateTime<Utc>,
     },
 
-    /// Query was cancelled by user.
+    /// Query was cancelled.
     QueryCancelled {
         query_id: QueryId,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        reason: Option<String>,
+        /// Absent in events recorded before reasons were required, which
+        /// were all user cancellations.
+        #[serde(default)]
+        reason: CancellationReason,
         cancelled_at: DateTime<Utc>,
     },
 
@@ -66,7 +78,7 @@ pub enum QuerySessionEvent {
 impl QuerySessionEvent {
     /// Get the event type name for storage.
     #[must_use]
-    pub fn event_type(&self) -> &'static str {
+    pub fn event_type_str(&self) -> &'static str {
         match self {
             Self::QueryStarted { .. } => "QueryStarted",
             Self::ExecutionBegan { .. } => "ExecutionBegan",
@@ -100,15 +112,20 @@ impl Identifier for QuerySessionEvent {
 
 impl EventType for QuerySessionEvent {
     fn event_type(&self) -> String {
+        self.event_type_str().to_string()
+    }
+}
+
+impl Timestamped for QuerySessionEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
         match self {
-            Self::QueryStarted { .. } => "QueryStarted",
-            Self::ExecutionBegan { .. } => "ExecutionBegan",
-            Self::QueryCompleted { .. } => "QueryCompleted",
-            Self::QueryFailed { .. } => "QueryFailed",
-            Self::QueryCancelled { .. } => "QueryCancelled",
-            Self::SessionReset { .. } => "SessionReset",
+            Self::QueryStarted { started_at, .. } => *started_at,
+            Self::ExecutionBegan { began_at, .. } => *began_at,
+            Self::QueryCompleted { completed_at, .. } => *completed_at,
+            Self::QueryFailed { failed_at, .. } => *failed_at,
+            Self::QueryCancelled { cancelled_at, .. } => *cancelled_at,
+            Self::SessionReset { reset_at, .. } => *reset_at,
         }
-        .to_string()
     }
 }
 
@@ -123,3 +140,97 @@ impl IsFinal for QuerySessionEvent {
         false // Session can always be reset, never truly terminal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn event_type_matches_serde_tag() {
+        let query_id = QueryId::new();
+        let events = [
+            (
+                QuerySessionEvent::QueryStarted {
+                    query_id,
+                    sql: SqlQuery::new("SELECT 1").unwrap(),
+                    dataset_ref: None,
+                    chart_config: None,
+                    started_at: sample_time(),
+                    actor: UserId::system(),
+                },
+                "QueryStarted",
+            ),
+            (
+                QuerySessionEvent::ExecutionBegan {
+                    query_id,
+                    began_at: sample_time(),
+                },
+                "ExecutionBegan",
+            ),
+            (
+                QuerySessionEvent::QueryCompleted {
+                    query_id,
+                    row_count: 1,
+                    result_ref: "query_session:result:1".to_string(),
+                    duration_ms: 5,
+                    completed_at: sample_time(),
+                },
+                "QueryCompleted",
+            ),
+            (
+                QuerySessionEvent::QueryFailed {
+                    query_id,
+                    error: "boom".to_string(),
+                    failed_at: sample_time(),
+                },
+                "QueryFailed",
+            ),
+            (
+                QuerySessionEvent::QueryCancelled {
+                    query_id,
+                    reason: CancellationReason::UserCancelled,
+                    cancelled_at: sample_time(),
+                },
+                "QueryCancelled",
+            ),
+            (
+                QuerySessionEvent::SessionReset {
+                    reset_at: sample_time(),
+                },
+                "SessionReset",
+            ),
+        ];
+
+        for (event, expected_type) in events {
+            let json = serde_json::to_value(&event).unwrap();
+            assert_eq!(json["type"], expected_type);
+            assert_eq!(event.event_type_str(), expected_type);
+            assert_eq!(EventType::event_type(&event), expected_type);
+        }
+    }
+
+    #[test]
+    fn completed_event_stored_without_result_ref_deserializes() {
+        let json = r#"{
+            "type": "QueryCompleted",
+            "query_id": "00000000-0000-0000-0000-000000000000",
+            "row_count": 3,
+            "duration_ms": 5,
+            "completed_at": "2024-01-15T10:30:00Z"
+        }"#;
+
+        let event: QuerySessionEvent = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(
+            event,
+            QuerySessionEvent::QueryCompleted { ref result_ref, row_count: 3, .. }
+                if result_ref.is_empty()
+        ));
+    }
+}
@@ -1,9 +1,11 @@
 //! State types for the QuerySession aggregate.
 
 use chrono::{DateTime, Utc};
+use ironstar_core::IsFinal;
+use ironstar_shared_kernel::UserId;
 use serde::{Deserialize, Serialize};
 
-use crate::values::{ChartConfig, DatasetRef, QueryId, SqlQuery};
+use crate::values::{CancellationReason, ChartConfig, DatasetRef, QueryId, SqlQuery};
 
 /// Lifecycle status of a query session.
 ///
@@ -23,6 +25,7 @@ pub enum QuerySessionStatus {
         dataset_ref: Option<DatasetRef>,
         chart_config: Option<ChartConfig>,
         started_at: DateTime<Utc>,
+        started_by: UserId,
     },
 
     /// Query is currently executing in DuckDB.
@@ -32,6 +35,7 @@ pub enum QuerySessionStatus {
         dataset_ref: Option<DatasetRef>,
         chart_config: Option<ChartConfig>,
         started_at: DateTime<Utc>,
+        started_by: UserId,
         began_at: DateTime<Utc>,
     },
 
@@ -39,6 +43,8 @@ pub enum QuerySessionStatus {
     Completed {
         query_id: QueryId,
         row_count: usize,
+        /// Cache key under which the query result is stored.
+        result_ref: String,
         duration_ms: u64,
         completed_at: DateTime<Utc>,
     },
@@ -53,7 +59,7 @@ pub enum QuerySessionStatus {
     /// Query was cancelled.
     Cancelled {
         query_id: QueryId,
-        reason: Option<String>,
+        reason: CancellationReason,
         cancelled_at: DateTime<Utc>,
     },
 }
@@ -80,6 +86,19 @@ impl QuerySessionStatus {
         )
     }
 
+    /// The user who started the query in progress, if any.
+    #[must_use]
+    pub fn started_by(&self) -> Option<UserId> {
+        match self {
+            Self::Pending { started_by, .. } | Self::Executing { started_by, .. } => {
+                Some(*started_by)
+            }
+            Self::Idle | Self::Completed { .. } | Self::Failed { .. } | Self::Cancelled { .. } => {
+                None
+            }
+        }
+    }
+
     /// Get the current query ID if one exists.
     #[must_use]
     pub fn query_id(&self) -> Option<QueryId> {
@@ -144,3 +163,90 @@ impl QuerySessionState {
         self.status.query_id()
     }
 }
+
+/// Completed, failed, and cancelled statuses are final for the query they
+/// ran; the session itself stays open to the next `StartQuery`.
+impl IsFinal for QuerySessionStatus {
+    fn is_final(&self) -> bool {
+        self.is_terminal()
+    }
+}
+
+impl IsFinal for QuerySessionState {
+    fn is_final(&self) -> bool {
+        self.status.is_final()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn terminal_statuses_are_final() {
+        let query_id = QueryId::new();
+        let terminal = [
+            QuerySessionStatus::Completed {
+                query_id,
+                row_count: 1,
+                result_ref: "query_session:result:1".to_string(),
+                duration_ms: 5,
+                completed_at: sample_time(),
+            },
+            QuerySessionStatus::Failed {
+                query_id,
+                error: "boom".to_string(),
+                failed_at: sample_time(),
+            },
+            QuerySessionStatus::Cancelled {
+                query_id,
+                reason: CancellationReason::UserCancelled,
+                cancelled_at: sample_time(),
+            },
+        ];
+
+        for status in terminal {
+            assert!(status.is_final(), "{} should be final", status.state_name());
+        }
+    }
+
+    #[test]
+    fn idle_and_in_progress_statuses_are_not_final() {
+        let sql = SqlQuery::new("SELECT 1").unwrap();
+        let active = [
+            QuerySessionStatus::Idle,
+            QuerySessionStatus::Pending {
+                query_id: QueryId::new(),
+                sql: sql.clone(),
+                dataset_ref: None,
+                chart_config: None,
+                started_at: sample_time(),
+                started_by: UserId::system(),
+            },
+            QuerySessionStatus::Executing {
+                query_id: QueryId::new(),
+                sql,
+                dataset_ref: None,
+                chart_config: None,
+                started_at: sample_time(),
+                started_by: UserId::system(),
+                began_at: sample_time(),
+            },
+        ];
+
+        for status in active {
+            assert!(
+                !status.is_final(),
+                "{} should not be final",
+                status.state_name()
+            );
+        }
+        assert!(!QuerySessionState::default().is_final());
+    }
+}
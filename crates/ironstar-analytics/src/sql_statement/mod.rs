@@ -0,0 +1,265 @@
+//! Read-only statement check for [`SqlQuery`](crate::SqlQuery).
+//!
+//! Analytics queries run against shared datasets, so only statements that
+//! read are accepted. The check is lexical rather than a full parse: it
+//! tokenizes just enough of DuckDB's SQL dialect (comments, string literals,
+//! quoted identifiers, dollar quoting, parentheses, statement separators) to
+//! find a statement's leading keyword and any data-modifying statement nested
+//! inside it. The lexing rules follow DuckDB's Postgres-derived scanner so
+//! that text this check sees as a literal or comment is one to DuckDB too.
+//!
+//! A query is accepted when:
+//!
+//! - every string, quoted identifier, and block comment is terminated
+//! - it is a single statement (trailing semicolons are allowed)
+//! - its first keyword is one of [`READ_KEYWORDS`]
+//! - no [`DML_KEYWORDS`] appears outside parentheses or first inside a
+//!   parenthesized group, which rules out writes behind or inside a `WITH`
+//!
+//! The last rule also rejects an unquoted column named after one of those
+//! keywords. DuckDB's read-only access mode remains the enforcement layer
+//! behind this check.
+
+#[cfg(test)]
+mod proptests;
+
+/// Keywords a read-only statement may start with.
+const READ_KEYWORDS: &[&str] = &[
+    "SELECT",
+    "WITH",
+    "VALUES",
+    "FROM",
+    "TABLE",
+    "PIVOT",
+    "UNPIVOT",
+    "DESCRIBE",
+    "SHOW",
+    "SUMMARIZE",
+];
+
+/// Keywords of statements that can follow a `WITH` clause and write data.
+const DML_KEYWORDS: &[&str] = &["INSERT", "UPDATE", "DELETE", "MERGE"];
+
+const UNTERMINATED: &str = "unterminated string, quoted identifier, or comment";
+const MULTIPLE_STATEMENTS: &str = "only a single statement is allowed";
+const NOT_A_READ: &str = "query must start with SELECT, WITH, VALUES, FROM, TABLE, PIVOT, \
+                          UNPIVOT, DESCRIBE, SHOW, or SUMMARIZE";
+const DATA_MODIFYING: &str = "data-modifying statements are not allowed";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Word(&'a str),
+    Open,
+    Close,
+    Semicolon,
+}
+
+/// Check that `sql` is a single read-only statement.
+///
+/// Returns the reason for rejection on failure.
+pub(crate) fn check_read_only(sql: &str) -> Result<(), &'static str> {
+    let tokens = tokenize(sql)?;
+
+    let mut after_separator = tokens.iter().skip_while(|t| **t != Token::Semicolon);
+    if after_separator.any(|t| *t != Token::Semicolon) {
+        return Err(MULTIPLE_STATEMENTS);
+    }
+
+    let first = tokens.iter().find_map(|t| match t {
+        Token::Word(word) => Some(*word),
+        _ => None,
+    });
+    if !first.is_some_and(|word| is_one_of(word, READ_KEYWORDS)) {
+        return Err(NOT_A_READ);
+    }
+
+    let mut depth = 0_usize;
+    let mut group_start = false;
+    for token in &tokens {
+        match token {
+            Token::Open => {
+                depth += 1;
+                group_start = true;
+                continue;
+            }
+            Token::Close => depth = depth.saturating_sub(1),
+            Token::Word(word) if (depth == 0 || group_start) && is_one_of(word, DML_KEYWORDS) => {
+                return Err(DATA_MODIFYING);
+            }
+            Token::Word(_) | Token::Semicolon => {}
+        }
+        group_start = false;
+    }
+
+    Ok(())
+}
+
+fn is_one_of(word: &str, keywords: &[&str]) -> bool {
+    keywords
+        .iter()
+        .any(|keyword| word.eq_ignore_ascii_case(keyword))
+}
+
+/// Identifier start characters; DuckDB treats every non-ASCII character as one.
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+}
+
+fn is_ident_continue(c: char) -> bool {
+    is_ident_start(c) || c.is_ascii_digit() || c == '$'
+}
+
+/// Split `sql` into the tokens the check needs, skipping literals, comments,
+/// and punctuation other than parentheses and semicolons.
+fn tokenize(sql: &str) -> Result<Vec<Token<'_>>, &'static str> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            ';' => tokens.push(Token::Semicolon),
+            '-' if chars.next_if(|&(_, c)| c == '-').is_some() => {
+                while chars.next_if(|&(_, c)| c != '\n' && c != '\r').is_some() {}
+            }
+            '/' if chars.next_if(|&(_, c)| c == '*').is_some() => {
+                skip_block_comment(&mut chars)?;
+            }
+            '\'' => skip_quoted(&mut chars, '\'', false)?,
+            '"' => skip_quoted(&mut chars, '"', false)?,
+            '$' => {
+                let body = start + c.len_utf8();
+                if let Some(tag_len) = dollar_tag_len(sql.get(body..).unwrap_or_default()) {
+                    let delimiter = sql.get(start..body + tag_len + 1).unwrap_or_default();
+                    let content = body + tag_len + 1;
+                    let Some(offset) = sql.get(content..).and_then(|rest| rest.find(delimiter))
+                    else {
+                        return Err(UNTERMINATED);
+                    };
+                    let end = content + offset + delimiter.len();
+                    while chars.next_if(|&(i, _)| i < end).is_some() {}
+                }
+            }
+            c if c.is_ascii_digit() => {
+                while chars
+                    .next_if(|&(_, c)| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+                    .is_some()
+                {}
+            }
+            c if is_ident_start(c) => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) = chars.next_if(|&(_, c)| is_ident_continue(c)) {
+                    end = i + c.len_utf8();
+                }
+                let word = sql.get(start..end).unwrap_or_default();
+                // E'...' is an escape string, where a backslash escapes the quote.
+                if word.eq_ignore_ascii_case("e") && chars.next_if(|&(_, c)| c == '\'').is_some() {
+                    skip_quoted(&mut chars, '\'', true)?;
+                } else {
+                    tokens.push(Token::Word(word));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(tokens)
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::CharIndices<'a>>;
+
+/// Skip a block comment after its opening `/*`; block comments nest.
+fn skip_block_comment(chars: &mut Chars<'_>) -> Result<(), &'static str> {
+    let mut depth = 1_usize;
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '/' if chars.next_if(|&(_, c)| c == '*').is_some() => depth += 1,
+            '*' if chars.next_if(|&(_, c)| c == '/').is_some() => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(UNTERMINATED)
+}
+
+/// Skip a quoted literal after its opening `quote`, where a doubled quote
+/// stands for itself.
+fn skip_quoted(
+    chars: &mut Chars<'_>,
+    quote: char,
+    backslash_escapes: bool,
+) -> Result<(), &'static str> {
+    while let Some((_, c)) = chars.next() {
+        if backslash_escapes && c == '\\' {
+            chars.next();
+        } else if c == quote && chars.next_if(|&(_, c)| c == quote).is_none() {
+            return Ok(());
+        }
+    }
+    Err(UNTERMINATED)
+}
+
+/// Length of the tag when `rest`, following a `$`, opens a dollar-quoted
+/// string (`$$` or `$tag$`). A positional parameter such as `$1` is not one.
+fn dollar_tag_len(rest: &str) -> Option<usize> {
+    let tag_len = rest
+        .char_indices()
+        .find(|&(i, c)| {
+            if i == 0 {
+                !is_ident_start(c)
+            } else {
+                !(is_ident_start(c) || c.is_ascii_digit())
+            }
+        })
+        .map_or(rest.len(), |(i, _)| i);
+    rest.get(tag_len..)?.starts_with('$').then_some(tag_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_plain_select() {
+        assert_eq!(check_read_only("SELECT 1"), Ok(()));
+        assert_eq!(check_read_only("select * from t;;"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_write_behind_comment() {
+        assert_eq!(check_read_only("/* note */ DROP TABLE t"), Err(NOT_A_READ));
+    }
+
+    #[test]
+    fn rejects_second_statement() {
+        assert_eq!(
+            check_read_only("SELECT 1; DROP TABLE t"),
+            Err(MULTIPLE_STATEMENTS)
+        );
+    }
+
+    #[test]
+    fn rejects_write_after_with() {
+        assert_eq!(
+            check_read_only("WITH x AS (SELECT 1) INSERT INTO t SELECT * FROM x"),
+            Err(DATA_MODIFYING)
+        );
+    }
+
+    #[test]
+    fn rejects_unterminated_literal() {
+        assert_eq!(check_read_only("SELECT 'open"), Err(UNTERMINATED));
+    }
+
+    #[test]
+    fn positional_parameter_is_not_dollar_quote() {
+        assert_eq!(dollar_tag_len("1 AND x = 2"), None);
+        assert_eq!(dollar_tag_len("$ body $$"), Some(0));
+        assert_eq!(dollar_tag_len("tag$ body $tag$"), Some(3));
+    }
+}
@@ -0,0 +1,216 @@
+//! Fuzzing `SqlQuery::new_read_only` with property-based tests.
+//!
+//! Inputs are arbitrary strings, soups of SQL lexer fragments (quotes,
+//! comment markers, dollar quotes, separators, keywords), and mutations of a
+//! seed corpus of known bypass attempts. For every input:
+//!
+//! - validation never panics
+//! - a string whose first keyword, after any comments and parentheses, is a
+//!   write statement is rejected
+//! - an accepted query followed by a newline and a second statement is
+//!   rejected, so no accepted query leaves a literal or comment open
+//! - every seed is accepted or rejected as recorded in [`SEED_CORPUS`]
+
+use proptest::prelude::*;
+
+use crate::SqlQuery;
+
+/// Tricky inputs and whether they must be accepted.
+const SEED_CORPUS: &[(&str, bool)] = &[
+    // Writes hidden behind comments, parentheses, and case.
+    ("/* audit */ DROP TABLE t", false),
+    ("-- cleanup\nDROP TABLE t", false),
+    ("-- cleanup\rDROP TABLE t", false),
+    ("/* outer /* inner */ still comment */ DELETE FROM t", false),
+    ("((DELETE FROM t))", false),
+    ("dRoP TABLE t", false),
+    // A second statement smuggled past a literal or comment.
+    ("SELECT 1; DROP TABLE t", false),
+    ("SELECT 1 --\r; DROP TABLE t", false),
+    ("SELECT E'\\''; DROP TABLE t; --'", false),
+    ("SELECT a$$; DROP TABLE t; $$", false),
+    ("SELECT 1$$; DROP TABLE t; $$", true),
+    ("SELECT $$; DROP TABLE t; $$ AS s", true),
+    ("SELECT $q$ $$; DROP TABLE t; $q$ AS s", true),
+    ("SELECT 'DROP TABLE t; --' AS s", true),
+    ("SELECT 'it''s'; SELECT 2", false),
+    // Writes inside or after CTEs, including nested ones.
+    ("WITH x AS (SELECT 1) INSERT INTO t SELECT * FROM x", false),
+    (
+        "WITH d AS (DELETE FROM t RETURNING *) SELECT * FROM d",
+        false,
+    ),
+    (
+        "WITH a AS (WITH b AS (SELECT 1 AS n) SELECT n FROM b) UPDATE t SET n = 1",
+        false,
+    ),
+    (
+        "WITH a AS (WITH b AS (SELECT 1 AS n) SELECT n FROM b) SELECT * FROM a",
+        true,
+    ),
+    (
+        "WITH RECURSIVE r(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM r WHERE n < 3) \
+         SELECT * FROM r",
+        true,
+    ),
+    // Other statements that change state or reach outside the dataset.
+    ("ATTACH 'other.db' AS other", false),
+    ("COPY t TO 'out.csv'", false),
+    ("PRAGMA enable_profiling", false),
+    ("INSTALL httpfs", false),
+    ("SET memory_limit = '1GB'", false),
+    ("EXPLAIN ANALYZE DELETE FROM t", false),
+    ("CALL dbgen(sf = 1)", false),
+    // Unicode lookalikes and invisible characters are not keywords.
+    ("\u{FF24}\u{FF32}\u{FF2F}\u{FF30} TABLE t", false),
+    ("\u{FEFF}SELECT 1", false),
+    ("SELECT\u{00A0}1", false),
+    ("SELECT 'caf\u{00E9}' AS n\u{00E4}me FROM t", true),
+    // Unterminated literals and comments.
+    ("SELECT 'open", false),
+    ("SELECT \"open", false),
+    ("SELECT 1 /* open", false),
+    ("SELECT $tag$ open", false),
+    // Ordinary reads.
+    ("select * from t;;", true),
+    ("FROM dataset LIMIT 5", true),
+    ("(SELECT 1) UNION (SELECT 2)", true),
+    ("SELECT \"delete\" FROM t WHERE kind = 'update'", true),
+    ("SELECT * FROM t WHERE id = $1", true),
+    ("DESCRIBE dataset", true),
+    ("SUMMARIZE SELECT * FROM dataset", true),
+];
+
+const WRITE_KEYWORDS: &[&str] = &[
+    "INSERT",
+    "UPDATE",
+    "DELETE",
+    "MERGE",
+    "DROP",
+    "CREATE",
+    "ALTER",
+    "ATTACH",
+    "DETACH",
+    "COPY",
+    "EXPORT",
+    "IMPORT",
+    "INSTALL",
+    "LOAD",
+    "PRAGMA",
+    "SET",
+    "CALL",
+    "CHECKPOINT",
+    "VACUUM",
+    "TRUNCATE",
+];
+
+/// Fragments that steer the lexer between its states.
+const FRAGMENTS: &[&str] = &[
+    "SELECT", "WITH", "FROM", "AS", "DROP", "DELETE", "INSERT", "t", "x", "1", " ", "\n", "\r",
+    "\t", "(", ")", ";", "'", "''", "\"", "--", "/*", "*/", "$", "$$", "$q$", "$1", "E'", "\\",
+    ",", "*", "\u{00A0}", "\u{00E9}",
+];
+
+/// Text that may precede a statement's first keyword without being one.
+fn noise_strategy() -> impl Strategy<Value = String> {
+    let piece = prop_oneof![
+        Just(" ".to_string()),
+        Just("\n\t".to_string()),
+        Just("(".to_string()),
+        "[a-z ]{0,8}".prop_map(|text| format!("-- {text}\n")),
+        "[a-z ]{0,8}".prop_map(|text| format!("-- {text}\r")),
+        "[a-z ]{0,8}".prop_map(|text| format!("/* {text} */")),
+        "[a-z ]{0,8}".prop_map(|text| format!("/* /* {text} */ */")),
+    ];
+    prop::collection::vec(piece, 0..6).prop_map(|pieces| pieces.concat())
+}
+
+/// A write keyword in mixed case.
+fn write_keyword_strategy() -> impl Strategy<Value = String> {
+    (
+        prop::sample::select(WRITE_KEYWORDS),
+        prop::collection::vec(any::<bool>(), 16),
+    )
+        .prop_map(|(keyword, upper)| {
+            keyword
+                .chars()
+                .zip(upper)
+                .map(|(c, upper)| {
+                    if upper {
+                        c.to_ascii_uppercase()
+                    } else {
+                        c.to_ascii_lowercase()
+                    }
+                })
+                .collect()
+        })
+}
+
+fn fragment_soup_strategy() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::sample::select(FRAGMENTS), 0..40).prop_map(|parts| parts.concat())
+}
+
+/// A seed with a fragment soup spliced in at a character boundary.
+fn mutated_seed_strategy() -> impl Strategy<Value = String> {
+    (
+        prop::sample::select(SEED_CORPUS),
+        any::<prop::sample::Index>(),
+        fragment_soup_strategy(),
+    )
+        .prop_map(|((seed, _), at, soup)| {
+            let boundaries: Vec<usize> = seed
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([seed.len()])
+                .collect();
+            let at = boundaries[at.index(boundaries.len())];
+            format!("{}{soup}{}", &seed[..at], &seed[at..])
+        })
+}
+
+fn input_strategy() -> impl Strategy<Value = String> {
+    prop_oneof![
+        any::<String>(),
+        fragment_soup_strategy(),
+        mutated_seed_strategy(),
+    ]
+}
+
+#[test]
+fn seed_corpus_is_classified() {
+    for (sql, accepted) in SEED_CORPUS {
+        assert_eq!(
+            SqlQuery::new_read_only(*sql).is_ok(),
+            *accepted,
+            "{sql:?} should be {}",
+            if *accepted { "accepted" } else { "rejected" }
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2048))]
+
+    #[test]
+    fn validation_never_panics(sql in input_strategy()) {
+        let _ = SqlQuery::new_read_only(sql);
+    }
+
+    #[test]
+    fn leading_write_keyword_is_rejected(
+        noise in noise_strategy(),
+        keyword in write_keyword_strategy(),
+        rest in input_strategy(),
+    ) {
+        let sql = format!("{noise}{keyword} {rest}");
+        prop_assert!(SqlQuery::new_read_only(&sql).is_err(), "accepted {:?}", sql);
+    }
+
+    #[test]
+    fn accepted_query_cannot_hide_a_second_statement(sql in input_strategy()) {
+        if SqlQuery::new_read_only(&sql).is_ok() {
+            let smuggled = format!("{sql}\n; DROP TABLE t");
+            prop_assert!(SqlQuery::new_read_only(&smuggled).is_err(), "accepted {:?}", smuggled);
+        }
+    }
+}
@@ -40,7 +40,7 @@ impl QueryId {
     /// Generate a new random QueryId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a QueryId.
@@ -214,8 +214,14 @@ impl From<DatasetRef> for String {
 /// - At most [`SQL_QUERY_MAX_LENGTH`] characters
 /// - Trimmed of leading/trailing whitespace
 ///
-/// Note: This performs basic validation only. Full SQL parsing and
-/// authorization checks happen at the DuckDB execution layer.
+/// User input goes through [`SqlQuery::new_read_only`], which also requires
+/// a single statement that starts with a read keyword such as `SELECT` or
+/// `WITH` and contains no data-modifying statement. [`SqlQuery::new`] and
+/// deserialization skip that check, so stored events written before it
+/// still replay.
+///
+/// Note: The statement check is lexical, not a full SQL parse. DuckDB's
+/// read-only access mode still backs it at the execution layer.
 ///
 /// # Example
 ///
@@ -254,6 +260,21 @@ impl SqlQuery {
         Ok(Self(trimmed.to_string()))
     }
 
+    /// Create a new SqlQuery from user input, requiring a single read-only
+    /// statement on top of the checks in [`SqlQuery::new`].
+    ///
+    /// # Errors
+    ///
+    /// - Any error from [`SqlQuery::new`]
+    /// - [`AnalyticsValidationError::NotReadOnly`] if the query is not a single
+    ///   read-only statement
+    pub fn new_read_only(sql: impl Into<String>) -> Result<Self, AnalyticsValidationError> {
+        let query = Self::new(sql)?;
+        crate::sql_statement::check_read_only(query.as_str())
+            .map_err(AnalyticsValidationError::not_read_only)?;
+        Ok(query)
+    }
+
     /// Get the SQL query as a string slice.
     #[must_use]
     pub fn as_str(&self) -> &str {
@@ -561,6 +582,74 @@ impl ChartConfig {
     }
 }
 
+// ============================================================================
+// CancellationReason - Why a query was cancelled
+// ============================================================================
+
+/// Why a query was cancelled.
+///
+/// Serialized as a plain string: the known reasons use the snake_case codes
+/// below, and any other string is [`Other`](Self::Other). Cancellations
+/// recorded before reasons were classified stored free text, so those read
+/// back as `Other` with the original text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "domain/", type = "string")]
+#[serde(from = "String", into = "String")]
+pub enum CancellationReason {
+    /// The user asked for the query to stop.
+    #[default]
+    UserCancelled,
+    /// The client that started the query went away.
+    ClientDisconnected,
+    /// The query ran past its time limit.
+    Timeout,
+    /// A newer query replaced this one.
+    Superseded,
+    /// Any other reason, as free text.
+    Other(String),
+}
+
+impl CancellationReason {
+    /// The serialized form of this reason.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::UserCancelled => "user_cancelled",
+            Self::ClientDisconnected => "client_disconnected",
+            Self::Timeout => "timeout",
+            Self::Superseded => "superseded",
+            Self::Other(text) => text,
+        }
+    }
+}
+
+impl std::fmt::Display for CancellationReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for CancellationReason {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "user_cancelled" => Self::UserCancelled,
+            "client_disconnected" => Self::ClientDisconnected,
+            "timeout" => Self::Timeout,
+            "superseded" => Self::Superseded,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<CancellationReason> for String {
+    fn from(reason: CancellationReason) -> Self {
+        match reason {
+            CancellationReason::Other(text) => text,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -753,7 +842,7 @@ mod tests {
 
         #[test]
         fn accepts_max_length() {
-            let max_sql = "a".repeat(SQL_QUERY_MAX_LENGTH);
+            let max_sql = "SELECT ".to_string() + &"a".repeat(SQL_QUERY_MAX_LENGTH - 7);
             let result = SqlQuery::new(&max_sql);
             assert!(result.is_ok());
         }
@@ -766,6 +855,22 @@ mod tests {
             assert_eq!(original, parsed);
         }
 
+        #[test]
+        fn only_new_read_only_checks_the_statement() {
+            let sql = "PRAGMA version; SELECT 1";
+            assert!(matches!(
+                SqlQuery::new_read_only(sql).unwrap_err().kind(),
+                AnalyticsValidationErrorKind::NotReadOnly { .. }
+            ));
+
+            // Stored events written before the check must still replay.
+            assert!(SqlQuery::new(sql).is_ok());
+            let parsed: SqlQuery = serde_json::to_string(sql)
+                .and_then(|json| serde_json::from_str(&json))
+                .unwrap();
+            assert_eq!(parsed.as_str(), sql);
+        }
+
         #[test]
         fn serde_rejects_empty() {
             let json = r#""""#;
@@ -875,4 +980,41 @@ mod tests {
             assert_eq!(original, parsed);
         }
     }
+
+    mod cancellation_reason {
+        use super::*;
+
+        #[test]
+        fn known_reasons_serialize_as_codes() {
+            let cases = [
+                (CancellationReason::UserCancelled, "\"user_cancelled\""),
+                (
+                    CancellationReason::ClientDisconnected,
+                    "\"client_disconnected\"",
+                ),
+                (CancellationReason::Timeout, "\"timeout\""),
+                (CancellationReason::Superseded, "\"superseded\""),
+            ];
+            for (reason, json) in cases {
+                assert_eq!(serde_json::to_string(&reason).unwrap(), json);
+                assert_eq!(
+                    serde_json::from_str::<CancellationReason>(json).unwrap(),
+                    reason
+                );
+            }
+        }
+
+        #[test]
+        fn free_text_reads_as_other() {
+            let reason: CancellationReason = serde_json::from_str("\"User cancelled\"").unwrap();
+            assert_eq!(
+                reason,
+                CancellationReason::Other("User cancelled".to_string())
+            );
+            assert_eq!(
+                serde_json::to_string(&reason).unwrap(),
+                "\"User cancelled\""
+            );
+        }
+    }
 }
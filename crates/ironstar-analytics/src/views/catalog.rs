@@ -6,16 +6,18 @@
 
 use ironstar_core::View;
 
-use crate::catalog::{CatalogEvent, CatalogMetadata, CatalogRef};
+use crate::catalog::{CatalogEvent, CatalogMetadata, CatalogMetadataDiff, CatalogRef};
 
 /// State materialized by the Catalog View.
 ///
 /// Tracks the currently selected catalog reference and its metadata.
-/// When no catalog is selected, both fields are `None`.
+/// When no catalog is selected, all fields are `None`.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct CatalogViewState {
     pub catalog_ref: Option<CatalogRef>,
     pub metadata: Option<CatalogMetadata>,
+    /// Datasets changed by the most recent metadata refresh, for highlighting.
+    pub last_changes: Option<CatalogMetadataDiff>,
 }
 
 impl CatalogViewState {
@@ -55,10 +57,12 @@ fn evolve(state: &CatalogViewState, event: &CatalogEvent) -> CatalogViewState {
         CatalogEvent::CatalogSelected { catalog_ref, .. } => CatalogViewState {
             catalog_ref: Some(catalog_ref.clone()),
             metadata: None,
+            last_changes: None,
         },
-        CatalogEvent::CatalogMetadataRefreshed { metadata, .. } => CatalogViewState {
+        CatalogEvent::CatalogMetadataRefreshed { metadata, diff, .. } => CatalogViewState {
             catalog_ref: state.catalog_ref.clone(),
             metadata: Some(metadata.clone()),
+            last_changes: Some(diff.clone()),
         },
     }
 }
@@ -86,11 +90,13 @@ mod tests {
                     name: "genomics".to_string(),
                     table_count: 5,
                     schema_version: "1.0".to_string(),
+                    row_count: None,
                 },
                 DatasetInfo {
                     name: "proteomics".to_string(),
                     table_count: 3,
                     schema_version: "2.1".to_string(),
+                    row_count: None,
                 },
             ],
             last_refreshed: Utc::now(),
@@ -135,6 +141,7 @@ mod tests {
             },
             CatalogEvent::CatalogMetadataRefreshed {
                 metadata: metadata.clone(),
+                diff: CatalogMetadataDiff::default(),
                 refreshed_at: Utc::now(),
             },
         ];
@@ -161,6 +168,7 @@ mod tests {
             },
             CatalogEvent::CatalogMetadataRefreshed {
                 metadata,
+                diff: CatalogMetadataDiff::default(),
                 refreshed_at: Utc::now(),
             },
             CatalogEvent::CatalogSelected {
@@ -183,6 +191,7 @@ mod tests {
 
         let events = vec![CatalogEvent::CatalogMetadataRefreshed {
             metadata: metadata.clone(),
+            diff: CatalogMetadataDiff::default(),
             refreshed_at: Utc::now(),
         }];
 
@@ -202,10 +211,12 @@ mod tests {
         let existing = CatalogViewState {
             catalog_ref: Some(catalog_ref.clone()),
             metadata: None,
+            last_changes: None,
         };
 
         let new_events = vec![CatalogEvent::CatalogMetadataRefreshed {
             metadata: metadata.clone(),
+            diff: CatalogMetadataDiff::default(),
             refreshed_at: Utc::now(),
         }];
 
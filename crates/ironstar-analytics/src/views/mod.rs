@@ -5,5 +5,6 @@ pub mod query_session;
 
 pub use catalog::{CatalogView, CatalogViewState, catalog_view};
 pub use query_session::{
-    QueryHistoryEntry, QueryOutcome, QuerySessionView, QuerySessionViewState, query_session_view,
+    DEFAULT_MAX_HISTORY, QueryHistoryEntry, QueryOutcome, QuerySessionView, QuerySessionViewState,
+    query_session_view, query_session_view_with_max_history,
 };
@@ -1,19 +1,30 @@
 //! QuerySession View for read-side projections.
 //!
 //! The View materializes QuerySession events into queryable state tracking
-//! the current session status and complete query history.
+//! the current session status and recent query history.
+//!
+//! # History retention
+//!
+//! The history keeps at most `max_history` entries ([`DEFAULT_MAX_HISTORY`]
+//! unless configured via [`query_session_view_with_max_history`]); once the
+//! cap is exceeded the oldest entries are dropped. The outcome counters keep
+//! counting every finished query regardless of the cap.
 
 use chrono::{DateTime, Utc};
 use ironstar_core::View;
 
 use crate::query_session::{QuerySessionEvent, QuerySessionStatus};
-use crate::values::{ChartConfig, DatasetRef, QueryId, SqlQuery};
+use crate::values::{CancellationReason, ChartConfig, DatasetRef, QueryId, SqlQuery};
+
+/// Default number of history entries retained by the view.
+pub const DEFAULT_MAX_HISTORY: usize = 100;
 
 /// Outcome of a completed query lifecycle.
 #[derive(Debug, Clone, PartialEq)]
 pub enum QueryOutcome {
     Completed {
         row_count: usize,
+        result_ref: String,
         duration_ms: u64,
         completed_at: DateTime<Utc>,
     },
@@ -22,11 +33,23 @@ pub enum QueryOutcome {
         failed_at: DateTime<Utc>,
     },
     Cancelled {
-        reason: Option<String>,
+        reason: CancellationReason,
         cancelled_at: DateTime<Utc>,
     },
 }
 
+impl QueryOutcome {
+    /// When the query reached its terminal state.
+    #[must_use]
+    pub fn finished_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::Completed { completed_at, .. } => *completed_at,
+            Self::Failed { failed_at, .. } => *failed_at,
+            Self::Cancelled { cancelled_at, .. } => *cancelled_at,
+        }
+    }
+}
+
 /// A single entry in the query history.
 #[derive(Debug, Clone, PartialEq)]
 pub struct QueryHistoryEntry {
@@ -66,6 +89,23 @@ impl QuerySessionViewState {
     pub fn is_in_progress(&self) -> bool {
         self.status.is_in_progress()
     }
+
+    /// The history entry that finished most recently.
+    #[must_use]
+    pub fn most_recent(&self) -> Option<&QueryHistoryEntry> {
+        self.recent(1).into_iter().next()
+    }
+
+    /// Up to `n` history entries, most recently finished first.
+    ///
+    /// Entries finishing at the same instant keep reverse history order.
+    #[must_use]
+    pub fn recent(&self, n: usize) -> Vec<&QueryHistoryEntry> {
+        let mut entries: Vec<&QueryHistoryEntry> = self.query_history.iter().rev().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.outcome.finished_at()));
+        entries.truncate(n);
+        entries
+    }
 }
 
 /// Type alias for the QuerySession View.
@@ -73,14 +113,24 @@ pub type QuerySessionView<'a> = View<'a, QuerySessionViewState, QuerySessionEven
 
 /// Factory function creating a pure QuerySession View.
 pub fn query_session_view<'a>() -> QuerySessionView<'a> {
+    query_session_view_with_max_history(DEFAULT_MAX_HISTORY)
+}
+
+/// Factory function creating a QuerySession View retaining at most
+/// `max_history` history entries.
+pub fn query_session_view_with_max_history<'a>(max_history: usize) -> QuerySessionView<'a> {
     View {
-        evolve: Box::new(evolve),
+        evolve: Box::new(move |state, event| evolve(state, event, max_history)),
         initial_state: Box::new(QuerySessionViewState::default),
     }
 }
 
 /// Pure evolve function: (State, Event) -> State
-fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySessionViewState {
+fn evolve(
+    state: &QuerySessionViewState,
+    event: &QuerySessionEvent,
+    max_history: usize,
+) -> QuerySessionViewState {
     match event {
         QuerySessionEvent::QueryStarted {
             query_id,
@@ -88,6 +138,7 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
             dataset_ref,
             chart_config,
             started_at,
+            actor,
         } => QuerySessionViewState {
             status: QuerySessionStatus::Pending {
                 query_id: *query_id,
@@ -95,6 +146,7 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
                 dataset_ref: dataset_ref.clone(),
                 chart_config: chart_config.clone(),
                 started_at: *started_at,
+                started_by: *actor,
             },
             query_history: state.query_history.clone(),
             ..*state
@@ -106,6 +158,7 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
                 dataset_ref,
                 chart_config,
                 started_at,
+                started_by,
                 ..
             } = &state.status
             {
@@ -116,6 +169,7 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
                         dataset_ref: dataset_ref.clone(),
                         chart_config: chart_config.clone(),
                         started_at: *started_at,
+                        started_by: *started_by,
                         began_at: *began_at,
                     },
                     query_history: state.query_history.clone(),
@@ -129,6 +183,7 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
         QuerySessionEvent::QueryCompleted {
             query_id,
             row_count,
+            result_ref,
             duration_ms,
             completed_at,
         } => {
@@ -136,16 +191,18 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
             if let Some(entry) =
                 build_history_entry(state, *query_id, |_| QueryOutcome::Completed {
                     row_count: *row_count,
+                    result_ref: result_ref.clone(),
                     duration_ms: *duration_ms,
                     completed_at: *completed_at,
                 })
             {
-                history.push(entry);
+                push_history(&mut history, entry, max_history);
             }
             QuerySessionViewState {
                 status: QuerySessionStatus::Completed {
                     query_id: *query_id,
                     row_count: *row_count,
+                    result_ref: result_ref.clone(),
                     duration_ms: *duration_ms,
                     completed_at: *completed_at,
                 },
@@ -165,7 +222,7 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
                 error: error.clone(),
                 failed_at: *failed_at,
             }) {
-                history.push(entry);
+                push_history(&mut history, entry, max_history);
             }
             QuerySessionViewState {
                 status: QuerySessionStatus::Failed {
@@ -191,7 +248,7 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
                     cancelled_at: *cancelled_at,
                 })
             {
-                history.push(entry);
+                push_history(&mut history, entry, max_history);
             }
             QuerySessionViewState {
                 status: QuerySessionStatus::Cancelled {
@@ -213,6 +270,17 @@ fn evolve(state: &QuerySessionViewState, event: &QuerySessionEvent) -> QuerySess
     }
 }
 
+/// Append `entry`, dropping the oldest entries beyond `max_history`.
+fn push_history(
+    history: &mut Vec<QueryHistoryEntry>,
+    entry: QueryHistoryEntry,
+    max_history: usize,
+) {
+    history.push(entry);
+    let excess = history.len().saturating_sub(max_history);
+    history.drain(..excess);
+}
+
 /// Extract query metadata from the current session status to build a history entry.
 fn build_history_entry(
     state: &QuerySessionViewState,
@@ -249,6 +317,7 @@ fn build_history_entry(
 mod tests {
     use chrono::Utc;
     use ironstar_core::ViewStateComputation;
+    use ironstar_shared_kernel::UserId;
 
     use super::*;
 
@@ -283,6 +352,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: Utc::now(),
+            actor: UserId::system(),
         }];
 
         let state = view.compute_new_state(None, &as_refs(&events));
@@ -302,6 +372,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: Utc::now(),
+                actor: UserId::system(),
             },
             QuerySessionEvent::ExecutionBegan {
                 query_id: qid,
@@ -326,6 +397,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: Utc::now(),
+                actor: UserId::system(),
             },
             QuerySessionEvent::ExecutionBegan {
                 query_id: qid,
@@ -334,6 +406,7 @@ mod tests {
             QuerySessionEvent::QueryCompleted {
                 query_id: qid,
                 row_count: 42,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 150,
                 completed_at: Utc::now(),
             },
@@ -353,9 +426,10 @@ mod tests {
             entry.outcome,
             QueryOutcome::Completed {
                 row_count: 42,
+                ref result_ref,
                 duration_ms: 150,
                 ..
-            }
+            } if result_ref == "query_session:result:1"
         ));
     }
 
@@ -370,6 +444,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: Utc::now(),
+                actor: UserId::system(),
             },
             QuerySessionEvent::QueryFailed {
                 query_id: qid,
@@ -397,10 +472,11 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: Utc::now(),
+                actor: UserId::system(),
             },
             QuerySessionEvent::QueryCancelled {
                 query_id: qid,
-                reason: Some("user requested".to_string()),
+                reason: CancellationReason::UserCancelled,
                 cancelled_at: Utc::now(),
             },
         ];
@@ -412,6 +488,60 @@ mod tests {
         assert_eq!(state.cancelled_count, 1);
     }
 
+    #[test]
+    fn every_cancellation_reason_reaches_history() {
+        use crate::query_session::{QuerySessionCommand, query_session_decider};
+
+        let decider = query_session_decider();
+        let view = query_session_view();
+        let reasons = [
+            CancellationReason::UserCancelled,
+            CancellationReason::ClientDisconnected,
+            CancellationReason::Timeout,
+            CancellationReason::Superseded,
+            CancellationReason::Other("quota exceeded".to_string()),
+        ];
+
+        for reason in reasons {
+            let qid = sample_query_id();
+            let mut state = (decider.initial_state)();
+            let mut events = Vec::new();
+            for command in [
+                QuerySessionCommand::StartQuery {
+                    query_id: qid,
+                    sql: sample_sql(),
+                    dataset_ref: None,
+                    chart_config: None,
+                    started_at: Utc::now(),
+                    actor: UserId::system(),
+                },
+                QuerySessionCommand::CancelQuery {
+                    query_id: qid,
+                    reason: reason.clone(),
+                    cancelled_at: Utc::now(),
+                },
+            ] {
+                for event in (decider.decide)(&command, &state).unwrap() {
+                    // Each event also survives the trip through the store.
+                    let json = serde_json::to_string(&event).unwrap();
+                    let event: QuerySessionEvent = serde_json::from_str(&json).unwrap();
+                    state = (decider.evolve)(&state, &event);
+                    events.push(event);
+                }
+            }
+
+            assert!(matches!(
+                &state.status,
+                QuerySessionStatus::Cancelled { reason: r, .. } if *r == reason
+            ));
+            let view_state = view.compute_new_state(None, &as_refs(&events));
+            assert!(matches!(
+                &view_state.query_history[0].outcome,
+                QueryOutcome::Cancelled { reason: r, .. } if *r == reason
+            ));
+        }
+    }
+
     #[test]
     fn session_reset_preserves_history() {
         let view = query_session_view();
@@ -423,10 +553,12 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: Utc::now(),
+                actor: UserId::system(),
             },
             QuerySessionEvent::QueryCompleted {
                 query_id: qid,
                 row_count: 10,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 50,
                 completed_at: Utc::now(),
             },
@@ -454,10 +586,12 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: Utc::now(),
+                actor: UserId::system(),
             },
             QuerySessionEvent::QueryCompleted {
                 query_id: qid1,
                 row_count: 10,
+                result_ref: "query_session:result:1".to_string(),
                 duration_ms: 50,
                 completed_at: Utc::now(),
             },
@@ -470,6 +604,7 @@ mod tests {
                 dataset_ref: None,
                 chart_config: None,
                 started_at: Utc::now(),
+                actor: UserId::system(),
             },
             QuerySessionEvent::QueryFailed {
                 query_id: qid2,
@@ -487,4 +622,109 @@ mod tests {
         assert_eq!(state.query_history[0].query_id, qid1);
         assert_eq!(state.query_history[1].query_id, qid2);
     }
+
+    fn completed_queries(count: u128) -> Vec<QuerySessionEvent> {
+        (0..count)
+            .flat_map(|n| {
+                let query_id = QueryId::from_uuid(uuid::Uuid::from_u128(n));
+                [
+                    QuerySessionEvent::QueryStarted {
+                        query_id,
+                        sql: sample_sql(),
+                        dataset_ref: None,
+                        chart_config: None,
+                        started_at: Utc::now(),
+                        actor: UserId::system(),
+                    },
+                    QuerySessionEvent::QueryCompleted {
+                        query_id,
+                        row_count: 1,
+                        result_ref: format!("query_session:result:{n}"),
+                        duration_ms: 1,
+                        completed_at: Utc::now(),
+                    },
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn history_retains_newest_entries_up_to_default_cap() {
+        let view = query_session_view();
+        let events = completed_queries(150);
+
+        let state = view.compute_new_state(None, &as_refs(&events));
+
+        assert_eq!(state.query_history.len(), DEFAULT_MAX_HISTORY);
+        assert_eq!(state.completed_count, 150);
+        assert_eq!(
+            state.query_history.first().unwrap().query_id,
+            QueryId::from_uuid(uuid::Uuid::from_u128(50))
+        );
+        assert_eq!(
+            state.query_history.last().unwrap().query_id,
+            QueryId::from_uuid(uuid::Uuid::from_u128(149))
+        );
+    }
+
+    #[test]
+    fn history_cap_is_configurable() {
+        let view = query_session_view_with_max_history(3);
+        let events = completed_queries(5);
+
+        let state = view.compute_new_state(None, &as_refs(&events));
+
+        let ids: Vec<QueryId> = state.query_history.iter().map(|e| e.query_id).collect();
+        let expected: Vec<QueryId> = (2..5)
+            .map(|n| QueryId::from_uuid(uuid::Uuid::from_u128(n)))
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    fn history_entry(sql: &str, finished_at: &str) -> QueryHistoryEntry {
+        let finished_at = DateTime::parse_from_rfc3339(finished_at)
+            .unwrap()
+            .with_timezone(&Utc);
+        QueryHistoryEntry {
+            query_id: QueryId::new(),
+            sql: SqlQuery::new(sql).unwrap(),
+            dataset_ref: None,
+            chart_config: None,
+            started_at: finished_at,
+            outcome: QueryOutcome::Failed {
+                error: "boom".to_string(),
+                failed_at: finished_at,
+            },
+        }
+    }
+
+    fn three_entry_state() -> QuerySessionViewState {
+        QuerySessionViewState {
+            query_history: vec![
+                history_entry("SELECT 1", "2024-01-15T10:00:00Z"),
+                history_entry("SELECT 3", "2024-01-15T12:00:00Z"),
+                history_entry("SELECT 2", "2024-01-15T11:00:00Z"),
+            ],
+            ..QuerySessionViewState::default()
+        }
+    }
+
+    #[test]
+    fn most_recent_returns_latest_finished_entry() {
+        let state = three_entry_state();
+
+        assert_eq!(state.most_recent().unwrap().sql.as_str(), "SELECT 3");
+        assert!(QuerySessionViewState::default().most_recent().is_none());
+    }
+
+    #[test]
+    fn recent_orders_newest_first_and_limits() {
+        let state = three_entry_state();
+
+        let top_two: Vec<&str> = state.recent(2).iter().map(|e| e.sql.as_str()).collect();
+        assert_eq!(top_two, vec!["SELECT 3", "SELECT 2"]);
+
+        let all: Vec<&str> = state.recent(10).iter().map(|e| e.sql.as_str()).collect();
+        assert_eq!(all, vec!["SELECT 3", "SELECT 2", "SELECT 1"]);
+    }
 }
@@ -68,17 +68,40 @@ pub trait QueryExecutor {
 // Pure Validation Functions
 // ============================================================================
 
+/// Table name queries use to read from the referenced dataset.
+const DATASET_TABLE: &str = "dataset";
+
 /// Validates that all inputs are well-formed.
 ///
 /// This is a convenience function that combines individual validations.
 /// Note: DatasetRef and SqlQuery are already validated at construction time.
-/// This function performs additional cross-cutting validation.
+/// This function performs additional cross-cutting validation: a query
+/// reading `FROM dataset` needs a dataset reference, and a dataset reference
+/// is only accepted for a query that reads from it.
 pub fn validate_workflow_inputs(
-    _dataset: &DatasetRef,
-    _query: &SqlQuery,
+    dataset: Option<&DatasetRef>,
+    query: &SqlQuery,
     chart_config: Option<&ChartConfig>,
 ) -> Result<(), AnalyticsError> {
     // DatasetRef and SqlQuery are pre-validated by smart constructors.
+    match (reads_from_dataset(query), dataset.is_some()) {
+        (true, false) => {
+            return Err(AnalyticsError::validation(
+                AnalyticsValidationError::dataset_reference_mismatch(
+                    "query reads FROM dataset but no dataset reference was given",
+                ),
+            ));
+        }
+        (false, true) => {
+            return Err(AnalyticsError::validation(
+                AnalyticsValidationError::dataset_reference_mismatch(
+                    "dataset reference given but the query never reads FROM dataset",
+                ),
+            ));
+        }
+        _ => {}
+    }
+
     // Validate chart config if present.
     if let Some(config) = chart_config {
         config.validate().map_err(AnalyticsError::validation)?;
@@ -91,6 +114,20 @@ pub fn validate_workflow_inputs(
     Ok(())
 }
 
+/// Whether the query reads the `dataset` table via `FROM` or `JOIN`.
+fn reads_from_dataset(query: &SqlQuery) -> bool {
+    let tokens: Vec<&str> = query
+        .as_str()
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | '(' | ')' | ';'))
+        .filter(|token| !token.is_empty())
+        .collect();
+    tokens.windows(2).any(|pair| {
+        matches!(pair, [keyword, table]
+            if (keyword.eq_ignore_ascii_case("from") || keyword.eq_ignore_ascii_case("join"))
+                && table.trim_matches('"').eq_ignore_ascii_case(DATASET_TABLE))
+    })
+}
+
 /// Validates that query columns are compatible with chart configuration.
 ///
 /// Called after schema is loaded to verify column references.
@@ -231,6 +268,10 @@ pub struct WorkflowResult {
 ///
 /// This is the main entry point for analytics operations.
 /// Composes pure validation, async execution, and pure transformation.
+///
+/// With `require_nonempty` set, a query returning zero rows fails with
+/// `AnalyticsErrorKind::EmptyResult` instead of producing an empty chart,
+/// for workflows such as scheduled reports that must not pass silently.
 pub async fn execute_workflow<S, E>(
     schema_loader: &S,
     query_executor: &E,
@@ -238,13 +279,14 @@ pub async fn execute_workflow<S, E>(
     dataset: &DatasetRef,
     query: &SqlQuery,
     chart_config: Option<&ChartConfig>,
+    require_nonempty: bool,
 ) -> Result<WorkflowResult, AnalyticsError>
 where
     S: SchemaLoader,
     E: QueryExecutor,
 {
     // 1. Validate inputs (pure)
-    validate_workflow_inputs(dataset, query, chart_config)?;
+    validate_workflow_inputs(Some(dataset), query, chart_config)?;
 
     // 2. Load schema (async effect boundary)
     let schema = schema_loader.load_schema(dataset).await?;
@@ -256,6 +298,9 @@ where
 
     // 4. Execute query (async effect boundary)
     let result = query_executor.execute(dataset, query).await?;
+    if require_nonempty && result.row_count == 0 {
+        return Err(AnalyticsError::empty_result(query_id.into_inner()));
+    }
 
     // 5. Transform for chart (pure)
     let chart_data = match chart_config {
@@ -278,33 +323,71 @@ mod tests {
 
     mod validate_workflow_inputs {
         use super::*;
+        use crate::errors::{AnalyticsErrorKind, AnalyticsValidationErrorKind};
+
+        fn is_dataset_mismatch(err: &AnalyticsError) -> bool {
+            matches!(
+                err.kind(),
+                AnalyticsErrorKind::Validation(e)
+                    if matches!(e.kind(), AnalyticsValidationErrorKind::DatasetReferenceMismatch { .. })
+            )
+        }
 
         #[test]
         fn accepts_valid_inputs_without_chart() {
             let dataset = DatasetRef::new("./test.csv").unwrap();
-            let query = SqlQuery::new("SELECT * FROM test").unwrap();
-            assert!(validate_workflow_inputs(&dataset, &query, None).is_ok());
+            let query = SqlQuery::new("SELECT * FROM dataset").unwrap();
+            assert!(validate_workflow_inputs(Some(&dataset), &query, None).is_ok());
         }
 
         #[test]
         fn accepts_valid_inputs_with_valid_chart() {
             let dataset = DatasetRef::new("./test.csv").unwrap();
-            let query = SqlQuery::new("SELECT * FROM test").unwrap();
+            let query = SqlQuery::new("SELECT * FROM dataset").unwrap();
             let config = ChartConfig::new(ChartType::Line)
                 .with_x_axis("date")
                 .with_y_axis("value");
-            assert!(validate_workflow_inputs(&dataset, &query, Some(&config)).is_ok());
+            assert!(validate_workflow_inputs(Some(&dataset), &query, Some(&config)).is_ok());
         }
 
         #[test]
         fn rejects_invalid_chart_config() {
             let dataset = DatasetRef::new("./test.csv").unwrap();
-            let query = SqlQuery::new("SELECT * FROM test").unwrap();
+            let query = SqlQuery::new("SELECT * FROM dataset").unwrap();
             // Line chart requires both axes
             let config = ChartConfig::new(ChartType::Line).with_x_axis("date");
-            let result = validate_workflow_inputs(&dataset, &query, Some(&config));
+            let result = validate_workflow_inputs(Some(&dataset), &query, Some(&config));
             assert!(result.is_err());
         }
+
+        #[test]
+        fn accepts_query_without_dataset_when_no_ref() {
+            let query = SqlQuery::new("SELECT 1 AS one").unwrap();
+            assert!(validate_workflow_inputs(None, &query, None).is_ok());
+        }
+
+        #[test]
+        fn rejects_dataset_query_without_ref() {
+            let query = SqlQuery::new("select count(*) from Dataset where x > 1").unwrap();
+            let err = validate_workflow_inputs(None, &query, None).unwrap_err();
+            assert!(is_dataset_mismatch(&err));
+        }
+
+        #[test]
+        fn rejects_ref_without_dataset_query() {
+            let dataset = DatasetRef::new("./test.csv").unwrap();
+            let query = SqlQuery::new("SELECT * FROM other_table").unwrap();
+            let err = validate_workflow_inputs(Some(&dataset), &query, None).unwrap_err();
+            assert!(is_dataset_mismatch(&err));
+        }
+
+        #[test]
+        fn detects_dataset_in_join() {
+            let dataset = DatasetRef::new("./test.csv").unwrap();
+            let query =
+                SqlQuery::new("SELECT * FROM lookup l JOIN \"dataset\" d ON l.id = d.id").unwrap();
+            assert!(validate_workflow_inputs(Some(&dataset), &query, None).is_ok());
+        }
     }
 
     mod validate_schema_compatibility {
@@ -437,4 +520,83 @@ mod tests {
             assert_eq!(chart_data.title, Some("My Chart".to_string()));
         }
     }
+
+    mod execute_workflow {
+        use super::*;
+        use crate::errors::AnalyticsErrorKind;
+        use std::future::ready;
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        struct StaticSchema;
+
+        impl SchemaLoader for StaticSchema {
+            fn load_schema(
+                &self,
+                _dataset: &DatasetRef,
+            ) -> impl Future<Output = Result<DatasetSchema, AnalyticsError>> + Send {
+                let columns = HashMap::from([
+                    ("date".to_string(), "DATE".to_string()),
+                    ("value".to_string(), "DOUBLE".to_string()),
+                ]);
+                ready(Ok(DatasetSchema { columns }))
+            }
+        }
+
+        struct EmptyExecutor;
+
+        impl QueryExecutor for EmptyExecutor {
+            fn execute(
+                &self,
+                _dataset: &DatasetRef,
+                _query: &SqlQuery,
+            ) -> impl Future<Output = Result<QueryResult, AnalyticsError>> + Send {
+                ready(Ok(QueryResult {
+                    columns: vec!["date".to_string(), "value".to_string()],
+                    rows: vec![],
+                    row_count: 0,
+                    execution_time_ms: 1,
+                }))
+            }
+        }
+
+        /// Poll the workflow once; `None` if the stub effects did not resolve.
+        fn run_empty(require_nonempty: bool) -> Option<Result<WorkflowResult, AnalyticsError>> {
+            let dataset = DatasetRef::new("./test.csv").unwrap();
+            let query = SqlQuery::new("SELECT date, value FROM dataset").unwrap();
+            let config = ChartConfig::new(ChartType::Line)
+                .with_x_axis("date")
+                .with_y_axis("value");
+            let future = pin!(execute_workflow(
+                &StaticSchema,
+                &EmptyExecutor,
+                QueryId::new(),
+                &dataset,
+                &query,
+                Some(&config),
+                require_nonempty,
+            ));
+            match future.poll(&mut Context::from_waker(Waker::noop())) {
+                Poll::Ready(result) => Some(result),
+                Poll::Pending => None,
+            }
+        }
+
+        #[test]
+        fn empty_result_fails_when_required_nonempty() {
+            let err = run_empty(true).unwrap().unwrap_err();
+
+            assert!(matches!(err.kind(), AnalyticsErrorKind::EmptyResult { .. }));
+        }
+
+        #[test]
+        fn empty_result_yields_empty_chart_by_default() {
+            let result = run_empty(false).unwrap().unwrap();
+
+            assert_eq!(result.row_count, 0);
+            let chart_data = result.chart_data.unwrap();
+            assert!(chart_data.x_values.is_empty());
+            assert!(chart_data.y_values.is_empty());
+        }
+    }
 }
@@ -0,0 +1,365 @@
+//! In-memory aggregate root tracking state, version, and uncommitted events.
+//!
+//! `AggregateRoot` wraps a [`Decider`] with the mutable bookkeeping needed
+//! between command handling and persistence: the current folded state, the
+//! number of events applied so far, and the events produced since the last
+//! commit. The application layer drains those events with
+//! [`AggregateRoot::take_uncommitted`] and hands them to the event store.
+//!
+//! [`AggregateRoot::handle_expecting`] adds optimistic concurrency at the
+//! domain layer: the caller states the version it last observed, and the
+//! command is rejected with [`DomainErrorKind::VersionConflict`] before
+//! deciding when the root has moved on. [`AggregateRoot::expected_version`]
+//! reports the version the store should hold when the uncommitted events are
+//! appended.
+//!
+//! The wrapper performs no I/O. Loading history and persisting events remain
+//! the responsibility of the application layer; `EventSourcedAggregate` is
+//! still the preferred wiring when a repository is available.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let mut root = AggregateRoot::from_events(workspace_decider(), &history);
+//! root.handle(&command)?;
+//! let pending = root.take_uncommitted();
+//! repository.save(&pending).await?;
+//! ```
+
+use std::fmt;
+
+use crate::Decider;
+use crate::error::{DomainError, DomainErrorKind};
+
+/// Failure from [`AggregateRoot::handle_expecting`].
+#[derive(Debug)]
+pub enum AggregateRootError<Error> {
+    /// The caller's expected version does not match the root's version.
+    VersionConflict(DomainError),
+    /// The decider rejected the command.
+    Decider(Error),
+}
+
+impl<Error: fmt::Display> fmt::Display for AggregateRootError<Error> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VersionConflict(e) => write!(f, "{e}"),
+            Self::Decider(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<Error> std::error::Error for AggregateRootError<Error>
+where
+    Error: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::VersionConflict(e) => Some(e),
+            Self::Decider(e) => Some(e),
+        }
+    }
+}
+
+/// Event-sourced aggregate root over a pure [`Decider`].
+///
+/// Replayed history advances the version but is never considered uncommitted;
+/// only events produced by [`handle`](Self::handle) or supplied to
+/// [`apply_all`](Self::apply_all) are queued for persistence.
+pub struct AggregateRoot<'a, C, S, E, Error> {
+    decider: Decider<'a, C, S, E, Error>,
+    state: S,
+    version: u64,
+    expected_version: u64,
+    uncommitted: Vec<E>,
+}
+
+impl<'a, C, S, E, Error> AggregateRoot<'a, C, S, E, Error> {
+    /// Create an aggregate root in the decider's initial state at version 0.
+    #[must_use]
+    pub fn new(decider: Decider<'a, C, S, E, Error>) -> Self {
+        let state = (decider.initial_state)();
+        Self {
+            decider,
+            state,
+            version: 0,
+            expected_version: 0,
+            uncommitted: Vec::new(),
+        }
+    }
+
+    /// Rehydrate an aggregate root by folding previously persisted events.
+    ///
+    /// The replayed events advance the version but are not tracked as uncommitted.
+    #[must_use]
+    pub fn from_events(decider: Decider<'a, C, S, E, Error>, events: &[E]) -> Self {
+        let mut root = Self::new(decider);
+        for event in events {
+            root.evolve(event);
+        }
+        root.expected_version = root.version;
+        root
+    }
+
+    /// Current folded state.
+    #[must_use]
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Number of events applied, including uncommitted ones.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Version the store is expected to hold before the uncommitted events.
+    ///
+    /// Set when the root is loaded and advanced by
+    /// [`take_uncommitted`](Self::take_uncommitted); pass it to the store's
+    /// optimistic locking check when persisting.
+    #[must_use]
+    pub fn expected_version(&self) -> u64 {
+        self.expected_version
+    }
+
+    /// Events produced since the last [`take_uncommitted`](Self::take_uncommitted).
+    #[must_use]
+    pub fn uncommitted(&self) -> &[E] {
+        &self.uncommitted
+    }
+
+    /// Drain the uncommitted events for persistence.
+    ///
+    /// The queue is empty afterwards and the drained events are treated as
+    /// committed, so [`expected_version`](Self::expected_version) catches up
+    /// to [`version`](Self::version). The state is unaffected.
+    pub fn take_uncommitted(&mut self) -> Vec<E> {
+        self.expected_version = self.version;
+        std::mem::take(&mut self.uncommitted)
+    }
+
+    /// Apply new events to the state and queue them for persistence.
+    pub fn apply_all(&mut self, events: Vec<E>) {
+        for event in &events {
+            self.evolve(event);
+        }
+        self.uncommitted.extend(events);
+    }
+
+    fn evolve(&mut self, event: &E) {
+        self.state = (self.decider.evolve)(&self.state, event);
+        self.version += 1;
+    }
+}
+
+impl<C, S, E, Error> AggregateRoot<'_, C, S, E, Error>
+where
+    E: Clone,
+{
+    /// Decide on a command against the current state and apply the result.
+    ///
+    /// Returns the newly produced events, which are also queued as uncommitted.
+    /// On error the state, version, and uncommitted queue are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns the decider's error when the command is rejected.
+    pub fn handle(&mut self, command: &C) -> Result<Vec<E>, Error> {
+        let events = (self.decider.decide)(command, &self.state)?;
+        self.apply_all(events.clone());
+        Ok(events)
+    }
+
+    /// Handle a command only if the root is at `expected_version`.
+    ///
+    /// The version check happens before the decider runs, so a stale caller
+    /// never observes or mutates state derived from events it has not seen.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AggregateRootError::VersionConflict`] when the versions differ,
+    /// or [`AggregateRootError::Decider`] when the command is rejected.
+    pub fn handle_expecting(
+        &mut self,
+        command: &C,
+        expected_version: u64,
+    ) -> Result<Vec<E>, AggregateRootError<Error>> {
+        if expected_version != self.version {
+            return Err(AggregateRootError::VersionConflict(DomainError::new(
+                DomainErrorKind::VersionConflict {
+                    expected: i64::try_from(expected_version).unwrap_or(i64::MAX),
+                    actual: i64::try_from(self.version).unwrap_or(i64::MAX),
+                },
+            )));
+        }
+        self.handle(command).map_err(AggregateRootError::Decider)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum CounterEvent {
+        Incremented(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum CounterError {
+        Overflow,
+    }
+
+    fn counter_decider<'a>() -> Decider<'a, u32, u32, CounterEvent, CounterError> {
+        Decider {
+            decide: Box::new(|amount: &u32, total: &u32| {
+                if total.checked_add(*amount).is_none() {
+                    return Err(CounterError::Overflow);
+                }
+                if *amount == 0 {
+                    return Ok(vec![]);
+                }
+                Ok(vec![CounterEvent::Incremented(*amount)])
+            }),
+            evolve: Box::new(|total: &u32, event: &CounterEvent| match event {
+                CounterEvent::Incremented(amount) => total + amount,
+            }),
+            initial_state: Box::new(|| 0),
+        }
+    }
+
+    #[test]
+    fn handle_populates_uncommitted() {
+        let mut root = AggregateRoot::new(counter_decider());
+
+        let events = root.handle(&2).unwrap();
+        root.handle(&3).unwrap();
+
+        assert_eq!(events, vec![CounterEvent::Incremented(2)]);
+        assert_eq!(
+            root.uncommitted(),
+            &[CounterEvent::Incremented(2), CounterEvent::Incremented(3)]
+        );
+        assert_eq!(*root.state(), 5);
+        assert_eq!(root.version(), 2);
+    }
+
+    #[test]
+    fn take_uncommitted_drains_queue() {
+        let mut root = AggregateRoot::new(counter_decider());
+        root.handle(&4).unwrap();
+
+        let pending = root.take_uncommitted();
+
+        assert_eq!(pending, vec![CounterEvent::Incremented(4)]);
+        assert!(root.uncommitted().is_empty());
+        assert!(root.take_uncommitted().is_empty());
+        assert_eq!(*root.state(), 4);
+        assert_eq!(root.version(), 1);
+    }
+
+    #[test]
+    fn replayed_events_are_not_uncommitted() {
+        let history = vec![CounterEvent::Incremented(1), CounterEvent::Incremented(1)];
+
+        let root = AggregateRoot::from_events(counter_decider(), &history);
+
+        assert_eq!(*root.state(), 2);
+        assert_eq!(root.version(), 2);
+        assert!(root.uncommitted().is_empty());
+    }
+
+    #[test]
+    fn apply_all_queues_events() {
+        let mut root = AggregateRoot::new(counter_decider());
+
+        root.apply_all(vec![CounterEvent::Incremented(7)]);
+
+        assert_eq!(root.uncommitted(), &[CounterEvent::Incremented(7)]);
+        assert_eq!(*root.state(), 7);
+    }
+
+    #[test]
+    fn rejected_command_leaves_root_unchanged() {
+        let mut root =
+            AggregateRoot::from_events(counter_decider(), &[CounterEvent::Incremented(u32::MAX)]);
+
+        let result = root.handle(&1);
+
+        assert_eq!(result, Err(CounterError::Overflow));
+        assert!(root.uncommitted().is_empty());
+        assert_eq!(root.version(), 1);
+    }
+
+    #[test]
+    fn handle_expecting_matching_version_succeeds() {
+        let mut root =
+            AggregateRoot::from_events(counter_decider(), &[CounterEvent::Incremented(1)]);
+
+        let events = root.handle_expecting(&2, 1).unwrap();
+
+        assert_eq!(events, vec![CounterEvent::Incremented(2)]);
+        assert_eq!(root.version(), 2);
+        assert_eq!(root.expected_version(), 1);
+    }
+
+    #[test]
+    fn handle_expecting_stale_version_fails() {
+        let mut root =
+            AggregateRoot::from_events(counter_decider(), &[CounterEvent::Incremented(1)]);
+        root.handle(&1).unwrap();
+
+        let result = root.handle_expecting(&5, 1);
+
+        match result {
+            Err(AggregateRootError::VersionConflict(e)) => assert_eq!(
+                e.kind(),
+                &DomainErrorKind::VersionConflict {
+                    expected: 1,
+                    actual: 2
+                }
+            ),
+            other => panic!("expected version conflict, got {other:?}"),
+        }
+        assert_eq!(*root.state(), 2);
+        assert_eq!(root.uncommitted().len(), 1);
+    }
+
+    #[test]
+    fn handle_expecting_surfaces_decider_error() {
+        let mut root =
+            AggregateRoot::from_events(counter_decider(), &[CounterEvent::Incremented(u32::MAX)]);
+
+        let result = root.handle_expecting(&1, 1);
+
+        assert!(matches!(
+            result,
+            Err(AggregateRootError::Decider(CounterError::Overflow))
+        ));
+    }
+
+    #[test]
+    fn take_uncommitted_advances_expected_version() {
+        let mut root = AggregateRoot::new(counter_decider());
+        root.handle(&1).unwrap();
+        assert_eq!(root.expected_version(), 0);
+
+        root.take_uncommitted();
+
+        assert_eq!(root.expected_version(), 1);
+        assert!(root.handle_expecting(&1, 1).is_ok());
+    }
+
+    #[test]
+    fn idempotent_command_queues_nothing() {
+        let mut root = AggregateRoot::new(counter_decider());
+
+        let events = root.handle(&0).unwrap();
+
+        assert!(events.is_empty());
+        assert!(root.uncommitted().is_empty());
+        assert_eq!(root.version(), 0);
+    }
+}
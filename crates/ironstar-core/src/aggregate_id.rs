@@ -0,0 +1,264 @@
+//! Parsing of aggregate stream ids.
+//!
+//! Each aggregate derives its stream id from its identifier value object:
+//!
+//! | Aggregate | Stream id |
+//! |-----------|-----------|
+//! | WorkspacePreferences | `workspace_{uuid}/preferences` |
+//! | UserPreferences | `user_{uuid}/preferences` |
+//! | Dashboard | `dashboard_{uuid}` |
+//! | SavedQuery | `saved_query_{uuid}` |
+//! | Workspace, Todo, Session | `{uuid}` |
+//!
+//! [`AggregateId`] splits such an id back into its type prefix, instance
+//! UUID, and optional sub-path. The prefix is everything before the last
+//! underscore, so multi-word prefixes like `saved_query` parse whole.
+//!
+//! Singleton aggregates (Catalog, QuerySession) use fixed ids such as
+//! `default-catalog` that name no instance; they do not parse.
+
+use std::fmt;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+/// A stream id split into its components.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AggregateId {
+    aggregate_type: Option<String>,
+    instance_id: Uuid,
+    sub_path: Option<String>,
+}
+
+impl AggregateId {
+    /// Create an id with a type prefix, e.g. `workspace_{uuid}`.
+    #[must_use]
+    pub fn new(aggregate_type: impl Into<String>, instance_id: Uuid) -> Self {
+        Self {
+            aggregate_type: Some(aggregate_type.into()),
+            instance_id,
+            sub_path: None,
+        }
+    }
+
+    /// Create an id that is the bare instance UUID.
+    #[must_use]
+    pub fn bare(instance_id: Uuid) -> Self {
+        Self {
+            aggregate_type: None,
+            instance_id,
+            sub_path: None,
+        }
+    }
+
+    /// Append a sub-path, e.g. `preferences`.
+    #[must_use]
+    pub fn with_sub_path(mut self, sub_path: impl Into<String>) -> Self {
+        self.sub_path = Some(sub_path.into());
+        self
+    }
+
+    /// Parse a stream id into its components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AggregateIdError`] naming the malformed component.
+    pub fn parse(stream_id: &str) -> Result<Self, AggregateIdError> {
+        if stream_id.is_empty() {
+            return Err(AggregateIdError::Empty);
+        }
+
+        let (head, sub_path) = match stream_id.split_once('/') {
+            Some((head, sub_path)) => {
+                if sub_path.split('/').any(str::is_empty) {
+                    return Err(AggregateIdError::EmptySubPath);
+                }
+                (head, Some(sub_path.to_string()))
+            }
+            None => (stream_id, None),
+        };
+
+        let (aggregate_type, instance) = match head.rsplit_once('_') {
+            Some(("", _)) => return Err(AggregateIdError::EmptyAggregateType),
+            Some((aggregate_type, instance)) => (Some(aggregate_type.to_string()), instance),
+            None => (None, head),
+        };
+
+        // Only the canonical hyphenated form round-trips to the stored id.
+        let instance_id = Uuid::try_parse(instance)
+            .ok()
+            .filter(|id| id.hyphenated().to_string() == instance)
+            .ok_or_else(|| AggregateIdError::InvalidInstanceId {
+                value: instance.to_string(),
+            })?;
+
+        Ok(Self {
+            aggregate_type,
+            instance_id,
+            sub_path,
+        })
+    }
+
+    /// Type prefix, e.g. `"workspace"`, or `None` for bare UUID ids.
+    #[must_use]
+    pub fn aggregate_type(&self) -> Option<&str> {
+        self.aggregate_type.as_deref()
+    }
+
+    /// Instance UUID.
+    #[must_use]
+    pub fn instance_id(&self) -> Uuid {
+        self.instance_id
+    }
+
+    /// Sub-path after the first `/`, e.g. `"preferences"`.
+    #[must_use]
+    pub fn sub_path(&self) -> Option<&str> {
+        self.sub_path.as_deref()
+    }
+}
+
+impl fmt::Display for AggregateId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(aggregate_type) = &self.aggregate_type {
+            write!(f, "{aggregate_type}_")?;
+        }
+        write!(f, "{}", self.instance_id)?;
+        if let Some(sub_path) = &self.sub_path {
+            write!(f, "/{sub_path}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AggregateId {
+    type Err = AggregateIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Error parsing a stream id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AggregateIdError {
+    /// The stream id is empty.
+    Empty,
+    /// An underscore is present but no type prefix precedes it.
+    EmptyAggregateType,
+    /// The instance portion is not a hyphenated UUID.
+    InvalidInstanceId { value: String },
+    /// The sub-path has an empty segment.
+    EmptySubPath,
+}
+
+impl fmt::Display for AggregateIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "stream id is empty"),
+            Self::EmptyAggregateType => write!(f, "stream id has an empty aggregate type"),
+            Self::InvalidInstanceId { value } => {
+                write!(f, "stream id instance '{value}' is not a UUID")
+            }
+            Self::EmptySubPath => write!(f, "stream id has an empty sub-path segment"),
+        }
+    }
+}
+
+impl std::error::Error for AggregateIdError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    fn uuid() -> Uuid {
+        Uuid::parse_str(ID).unwrap()
+    }
+
+    fn assert_parses(stream_id: &str, expected: &AggregateId) {
+        let parsed = AggregateId::parse(stream_id).unwrap();
+        assert_eq!(&parsed, expected);
+        assert_eq!(parsed.to_string(), stream_id);
+    }
+
+    #[test]
+    fn parses_workspace_id() {
+        assert_parses(
+            &format!("workspace_{ID}"),
+            &AggregateId::new("workspace", uuid()),
+        );
+    }
+
+    #[test]
+    fn parses_workspace_preferences_id() {
+        let id = AggregateId::parse(&format!("workspace_{ID}/preferences")).unwrap();
+        assert_eq!(id.aggregate_type(), Some("workspace"));
+        assert_eq!(id.instance_id(), uuid());
+        assert_eq!(id.sub_path(), Some("preferences"));
+    }
+
+    #[test]
+    fn parses_user_preferences_id() {
+        assert_parses(
+            &format!("user_{ID}/preferences"),
+            &AggregateId::new("user", uuid()).with_sub_path("preferences"),
+        );
+    }
+
+    #[test]
+    fn parses_dashboard_id() {
+        assert_parses(
+            &format!("dashboard_{ID}"),
+            &AggregateId::new("dashboard", uuid()),
+        );
+    }
+
+    #[test]
+    fn parses_multi_word_prefix() {
+        assert_parses(
+            &format!("saved_query_{ID}"),
+            &AggregateId::new("saved_query", uuid()),
+        );
+    }
+
+    #[test]
+    fn parses_bare_uuid() {
+        let id = AggregateId::parse(ID).unwrap();
+        assert_eq!(id.aggregate_type(), None);
+        assert_eq!(id, AggregateId::bare(uuid()));
+    }
+
+    #[test]
+    fn rejects_malformed_ids() {
+        let invalid = |value: &str| AggregateIdError::InvalidInstanceId {
+            value: value.to_string(),
+        };
+        let cases = [
+            (String::new(), AggregateIdError::Empty),
+            (format!("_{ID}"), AggregateIdError::EmptyAggregateType),
+            ("default-catalog".to_string(), invalid("default-catalog")),
+            ("workspace_abc".to_string(), invalid("abc")),
+            (
+                format!("workspace_{}", ID.to_uppercase()),
+                invalid(&ID.to_uppercase()),
+            ),
+            (format!("workspace_{ID}/"), AggregateIdError::EmptySubPath),
+            (
+                format!("workspace_{ID}//preferences"),
+                AggregateIdError::EmptySubPath,
+            ),
+        ];
+
+        for (stream_id, expected) in cases {
+            assert_eq!(AggregateId::parse(&stream_id), Err(expected), "{stream_id}");
+        }
+    }
+
+    #[test]
+    fn from_str_parses() {
+        let id: AggregateId = format!("dashboard_{ID}").parse().unwrap();
+        assert_eq!(id.aggregate_type(), Some("dashboard"));
+    }
+}
@@ -0,0 +1,66 @@
+//! Injectable wall clock for command timestamps.
+//!
+//! Commands carry their own timestamps so deciders stay pure; the boundary
+//! that builds a command reads the time from a [`Clock`] rather than calling
+//! `Utc::now()` directly. Production wiring uses [`SystemClock`]; tests pass a
+//! [`FixedClock`] to assert exact timestamps on the resulting events.
+
+use chrono::{DateTime, Utc};
+
+/// Source of the current time.
+pub trait Clock: Send + Sync {
+    /// Current UTC time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Reads the system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Always returns the same instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    /// Create a clock frozen at `at`.
+    #[must_use]
+    pub fn new(at: DateTime<Utc>) -> Self {
+        Self(at)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_clock_returns_injected_time() {
+        let at = DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let clock = FixedClock::new(at);
+
+        assert_eq!(clock.now(), at);
+        assert_eq!(clock.now(), clock.now());
+    }
+
+    #[test]
+    fn system_clock_advances() {
+        let before = Utc::now();
+        let now = SystemClock.now();
+
+        assert!(now >= before);
+    }
+}
@@ -68,6 +68,8 @@ pub enum ValidationErrorKind {
         min_length: usize,
         actual_length: usize,
     },
+    /// Collection already holds its maximum number of items.
+    TooMany { field: String, max_count: usize },
 }
 
 impl ValidationError {
@@ -128,6 +130,9 @@ impl fmt::Display for ValidationError {
                 f,
                 "{field} is shorter than minimum length {min_length} (got {actual_length})"
             ),
+            ValidationErrorKind::TooMany { field, max_count } => {
+                write!(f, "{field} cannot hold more than {max_count} items")
+            }
         }
     }
 }
@@ -277,6 +282,12 @@ mod tests {
             err.to_string(),
             "description exceeds maximum length 100 (got 150)"
         );
+
+        let err = ValidationError::new(ValidationErrorKind::TooMany {
+            field: "charts".to_string(),
+            max_count: 50,
+        });
+        assert_eq!(err.to_string(), "charts cannot hold more than 50 items");
     }
 
     #[test]
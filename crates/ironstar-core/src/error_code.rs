@@ -24,6 +24,8 @@ pub enum ErrorCode {
     Unauthorized,
     /// Authenticated but not authorized for this operation.
     Forbidden,
+    /// Too many requests; the client should retry later.
+    RateLimited,
 
     // 5xx Server errors
     /// Unexpected server error.
@@ -45,6 +47,7 @@ impl ErrorCode {
             Self::Conflict => 409,
             Self::Unauthorized => 401,
             Self::Forbidden => 403,
+            Self::RateLimited => 429,
             Self::InternalError => 500,
             Self::DatabaseError => 500,
             Self::ServiceUnavailable => 503,
@@ -63,6 +66,7 @@ mod tests {
         assert_eq!(ErrorCode::Conflict.http_status(), 409);
         assert_eq!(ErrorCode::Unauthorized.http_status(), 401);
         assert_eq!(ErrorCode::Forbidden.http_status(), 403);
+        assert_eq!(ErrorCode::RateLimited.http_status(), 429);
         assert_eq!(ErrorCode::InternalError.http_status(), 500);
         assert_eq!(ErrorCode::DatabaseError.http_status(), 500);
         assert_eq!(ErrorCode::ServiceUnavailable.http_status(), 503);
@@ -78,5 +82,9 @@ mod tests {
             serde_json::to_string(&ErrorCode::DatabaseError).unwrap(),
             "\"DATABASE_ERROR\""
         );
+        assert_eq!(
+            serde_json::to_string(&ErrorCode::RateLimited).unwrap(),
+            "\"RATE_LIMITED\""
+        );
     }
 }
@@ -0,0 +1,161 @@
+//! Injectable UUID generation for identifier value objects.
+//!
+//! Identifier constructors such as `WorkspaceId::new()` draw their UUID from
+//! [`next_uuid`], which uses the thread's current [`IdSource`]. Production
+//! code never installs a source and gets random v4 UUIDs; tests install a
+//! [`SeededIdSource`] with [`with_id_source`] to make generated ids, and the
+//! events that carry them, predictable.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use ironstar_core::id::{SeededIdSource, with_id_source};
+//!
+//! let (a, b) = with_id_source(SeededIdSource::new(7), || (WorkspaceId::new(), WorkspaceId::new()));
+//! let (c, d) = with_id_source(SeededIdSource::new(7), || (WorkspaceId::new(), WorkspaceId::new()));
+//! assert_eq!((a, b), (c, d));
+//! ```
+
+use std::cell::RefCell;
+
+use uuid::{Builder, Uuid};
+
+/// Source of UUIDs for newly created identifiers.
+pub trait IdSource {
+    /// Produce the next UUID.
+    fn next_uuid(&mut self) -> Uuid;
+}
+
+/// Random v4 UUIDs; the default when no source is installed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomIdSource;
+
+impl IdSource for RandomIdSource {
+    fn next_uuid(&mut self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Deterministic v4-formatted UUIDs derived from a seed and a counter.
+///
+/// Two sources with the same seed yield the same sequence; different seeds
+/// never collide because the seed occupies bytes 0–5 and 10–11 of every
+/// UUID, clear of the version and variant bits in bytes 6 and 8. The counter
+/// fills the remaining 48 bits, so a source repeats after 2^48 UUIDs.
+#[derive(Debug, Clone)]
+pub struct SeededIdSource {
+    seed: u64,
+    counter: u64,
+}
+
+impl SeededIdSource {
+    /// Create a source whose first UUID corresponds to counter 0.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { seed, counter: 0 }
+    }
+}
+
+impl IdSource for SeededIdSource {
+    fn next_uuid(&mut self) -> Uuid {
+        let [s0, s1, s2, s3, s4, s5, s6, s7] = self.seed.to_be_bytes();
+        let [_, _, c2, c3, c4, c5, c6, c7] = self.counter.to_be_bytes();
+        let bytes = [s0, s1, s2, s3, s4, s5, 0, c2, 0, c3, s6, s7, c4, c5, c6, c7];
+        self.counter = self.counter.wrapping_add(1);
+        Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Box<dyn IdSource>>> = const { RefCell::new(None) };
+}
+
+/// Generate a UUID from the current thread's source.
+///
+/// Falls back to a random v4 UUID when no source is installed.
+#[must_use]
+pub fn next_uuid() -> Uuid {
+    CURRENT.with(|current| match current.borrow_mut().as_mut() {
+        Some(source) => source.next_uuid(),
+        None => Uuid::new_v4(),
+    })
+}
+
+/// Run `f` with `source` installed as the current thread's id source.
+///
+/// The previous source is restored afterwards, including when `f` panics,
+/// so nested and sequential calls compose.
+pub fn with_id_source<S, R>(source: S, f: impl FnOnce() -> R) -> R
+where
+    S: IdSource + 'static,
+{
+    struct Restore(Option<Box<dyn IdSource>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(Box::new(source)));
+    let _restore = Restore(previous);
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_differing_in_any_bit_do_not_collide() {
+        for other in [0x1000, 1 << 63, 1] {
+            let first: Vec<Uuid> = with_id_source(SeededIdSource::new(0), || {
+                (0..3).map(|_| next_uuid()).collect()
+            });
+            let second: Vec<Uuid> = with_id_source(SeededIdSource::new(other), || {
+                (0..3).map(|_| next_uuid()).collect()
+            });
+
+            assert!(
+                first.iter().all(|id| !second.contains(id)),
+                "seed {other:#x} collides with seed 0"
+            );
+        }
+    }
+
+    #[test]
+    fn seeded_source_is_deterministic() {
+        let first: Vec<Uuid> = with_id_source(SeededIdSource::new(42), || {
+            (0..3).map(|_| next_uuid()).collect()
+        });
+        let second: Vec<Uuid> = with_id_source(SeededIdSource::new(42), || {
+            (0..3).map(|_| next_uuid()).collect()
+        });
+
+        assert_eq!(first, second);
+        assert_ne!(first[0], first[1]);
+        assert_eq!(first[0].get_version_num(), 4);
+    }
+
+    #[test]
+    fn different_seeds_differ() {
+        let a = with_id_source(SeededIdSource::new(1), next_uuid);
+        let b = with_id_source(SeededIdSource::new(2), next_uuid);
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn previous_source_is_restored() {
+        let (outer_first, inner, outer_second) = with_id_source(SeededIdSource::new(1), || {
+            let outer_first = next_uuid();
+            let inner = with_id_source(SeededIdSource::new(1), next_uuid);
+            (outer_first, inner, next_uuid())
+        });
+
+        assert_eq!(outer_first, inner);
+        assert_ne!(outer_first, outer_second);
+        assert_ne!(next_uuid(), outer_first);
+    }
+}
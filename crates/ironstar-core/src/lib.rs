@@ -6,20 +6,31 @@
 //!
 //! Maps to `spec/Core/*` in the Idris2 specification.
 
+pub mod aggregate;
+pub mod aggregate_id;
+pub mod clock;
 pub mod error;
 pub mod error_code;
+pub mod id;
+pub mod testing;
 pub mod traits;
 pub mod values;
+pub mod view_invariants;
 
 // Re-export core domain traits
+pub use aggregate::{AggregateRoot, AggregateRootError};
+pub use aggregate_id::{AggregateId, AggregateIdError};
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use error::{DomainError, DomainErrorKind, ValidationError, ValidationErrorKind};
 pub use error_code::ErrorCode;
-pub use traits::{DeciderType, EventType, IsFinal};
+pub use id::{IdSource, RandomIdSource, SeededIdSource};
+pub use traits::{DeciderType, EventType, IsFinal, Timestamped};
 pub use values::BoundedString;
 pub use values::{
     DASHBOARD_TITLE_MAX_LENGTH, DASHBOARD_TITLE_MIN_LENGTH, DashboardTitle, GRID_HEIGHT_MIN,
     GRID_WIDTH_MIN, GridSize, TAB_TITLE_MAX_LENGTH, TAB_TITLE_MIN_LENGTH, TabTitle,
 };
+pub use view_invariants::{ViewInvariantError, ViewInvariants, debug_validated};
 
 // Re-export fmodel-rust core abstractions.
 //
@@ -34,6 +45,7 @@ pub use fmodel_rust::view::View;
 
 // Re-export fmodel-rust computation traits used by application layer
 pub use fmodel_rust::decider::EventComputation;
+pub use fmodel_rust::saga::ActionComputation;
 pub use fmodel_rust::view::ViewStateComputation;
 
 // Re-export fmodel-rust aggregate types used for event-sourced wiring
@@ -41,3 +53,4 @@ pub use fmodel_rust::aggregate::{EventRepository, EventSourcedAggregate};
 
 // Re-export fmodel-rust test specification for aggregate testing
 pub use fmodel_rust::specification::DeciderTestSpecification;
+pub use testing::{DeciderTestExt, LogCapture, LogLine, UPDATE_GOLDENS_ENV, assert_golden};
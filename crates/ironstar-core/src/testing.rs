@@ -0,0 +1,291 @@
+//! Test helpers layered over fmodel-rust's specification DSL.
+//!
+//! [`DeciderTestExt`] adds assertions that recur across the aggregate test
+//! suites and that the given-when-then DSL cannot express with a useful
+//! failure message.
+//!
+//! [`assert_golden`] compares serialized output against a checked-in golden
+//! file, so that a change to an event's wire format fails a test instead of
+//! silently breaking events already in the store. Set
+//! [`UPDATE_GOLDENS_ENV`] to rewrite the goldens after an intended change.
+//!
+//! [`LogCapture`] records the fields of every `tracing` event emitted while
+//! it is the default subscriber, for tests that assert on what was logged.
+
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+
+use crate::Decider;
+use fmodel_rust::decider::EventComputation;
+
+/// Convenience assertions for [`Decider`]s.
+pub trait DeciderTestExt<C, E> {
+    /// Assert that `command` is accepted after `given` and emits no events.
+    ///
+    /// Reads as the idempotency check it is, in place of `.then(vec![])`.
+    /// A failure names the command alongside the unexpected events, or the
+    /// error if the decider rejected the command outright.
+    fn assert_no_events(&self, given: Vec<E>, command: C);
+}
+
+impl<C, S, E, Error> DeciderTestExt<C, E> for Decider<'_, C, S, E, Error>
+where
+    C: Debug,
+    E: Debug,
+    Error: Debug,
+{
+    #[track_caller]
+    #[expect(clippy::panic, reason = "test assertion helper")]
+    fn assert_no_events(&self, given: Vec<E>, command: C) {
+        match self.compute_new_events(&given, &command) {
+            Ok(events) if events.is_empty() => {}
+            Ok(events) => panic!(
+                "expected no events for command {command:?}, but the decider emitted {events:?}"
+            ),
+            Err(error) => panic!(
+                "expected no events for command {command:?}, but the decider rejected it: {error:?}"
+            ),
+        }
+    }
+}
+
+/// Environment variable that makes [`assert_golden`] rewrite its golden file.
+pub const UPDATE_GOLDENS_ENV: &str = "IRONSTAR_UPDATE_GOLDENS";
+
+/// Assert that `actual` matches the golden file at `path`.
+///
+/// With [`UPDATE_GOLDENS_ENV`] set to a non-empty value the file is written
+/// from `actual` instead, creating parent directories as needed. A missing
+/// golden fails like a mismatch, naming the variable to set.
+///
+/// # Panics
+///
+/// Panics when the golden is missing or differs from `actual`, or when it
+/// cannot be rewritten.
+#[track_caller]
+pub fn assert_golden(path: impl AsRef<Path>, actual: &str) {
+    let update = std::env::var_os(UPDATE_GOLDENS_ENV).is_some_and(|value| !value.is_empty());
+    check_golden(path.as_ref(), actual, update);
+}
+
+#[track_caller]
+#[expect(clippy::panic, reason = "test assertion helper")]
+fn check_golden(path: &Path, actual: &str, update: bool) {
+    if update {
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(path, actual));
+        if let Err(error) = written {
+            panic!("failed to write golden {}: {error}", path.display());
+        }
+        return;
+    }
+
+    let Ok(expected) = std::fs::read_to_string(path) else {
+        panic!(
+            "golden {} is missing; rerun with {UPDATE_GOLDENS_ENV}=1 to create it",
+            path.display()
+        );
+    };
+    assert!(
+        expected == actual,
+        "output drifted from golden {}; if the change is intended, make sure \
+         stored data still deserializes (or add an upcaster), then rerun with \
+         {UPDATE_GOLDENS_ENV}=1\n--- golden\n{expected}\n--- actual\n{actual}",
+        path.display()
+    );
+}
+
+/// The fields of one logged event, in the order they were recorded.
+///
+/// The event's message is recorded under `message`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogLine(pub Vec<(String, String)>);
+
+impl LogLine {
+    /// The value of the field called `name`, if the event recorded one.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Visit for LogLine {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_string(), value.to_string()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+/// A `tracing` layer that keeps every event it sees as a [`LogLine`].
+///
+/// Clones share one buffer, so a test keeps a clone to read [`lines`]
+/// after installing the capture with [`set_default`].
+///
+/// [`lines`]: LogCapture::lines
+/// [`set_default`]: LogCapture::set_default
+#[derive(Debug, Clone, Default)]
+pub struct LogCapture(Arc<Mutex<Vec<LogLine>>>);
+
+impl LogCapture {
+    /// Install a subscriber with this capture as its only layer for the
+    /// current thread, until the returned guard drops.
+    #[must_use = "the capture is uninstalled when the guard drops"]
+    pub fn set_default(&self) -> tracing::subscriber::DefaultGuard {
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(self.clone()))
+    }
+
+    /// Every event captured so far.
+    #[must_use]
+    pub fn lines(&self) -> Vec<LogLine> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The captured events whose message is `message`.
+    #[must_use]
+    pub fn lines_with_message(&self, message: &str) -> Vec<LogLine> {
+        self.lines()
+            .into_iter()
+            .filter(|line| line.field("message") == Some(message))
+            .collect()
+    }
+}
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for LogCapture {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let mut line = LogLine::default();
+        event.record(&mut line);
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Decider;
+
+    fn toggle_decider<'a>() -> Decider<'a, bool, bool, bool, ()> {
+        Decider {
+            decide: Box::new(|target: &bool, current: &bool| {
+                if target == current {
+                    Ok(vec![])
+                } else {
+                    Ok(vec![*target])
+                }
+            }),
+            evolve: Box::new(|_: &bool, event: &bool| *event),
+            initial_state: Box::new(|| false),
+        }
+    }
+
+    #[test]
+    fn assert_no_events_passes_for_idempotent_command() {
+        toggle_decider().assert_no_events(vec![true], true);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "expected no events for command true, but the decider emitted [true]"
+    )]
+    fn assert_no_events_names_command_and_events_on_failure() {
+        toggle_decider().assert_no_events(vec![], true);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected no events for command false, but the decider rejected it")]
+    fn assert_no_events_reports_rejection() {
+        let decider: Decider<'_, bool, bool, bool, &str> = Decider {
+            decide: Box::new(|_: &bool, _: &bool| Err("rejected")),
+            evolve: Box::new(|_: &bool, event: &bool| *event),
+            initial_state: Box::new(|| false),
+        };
+        decider.assert_no_events(vec![], false);
+    }
+
+    fn golden_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("ironstar-golden-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn assert_golden_passes_for_matching_output() {
+        let path = golden_path("match.json");
+        std::fs::write(&path, "{}\n").unwrap();
+
+        check_golden(&path, "{}\n", false);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "drifted from golden")]
+    fn assert_golden_fails_on_drift() {
+        let path = golden_path("drift.json");
+        std::fs::write(&path, "{}\n").unwrap();
+
+        let result = std::panic::catch_unwind(|| check_golden(&path, "[]\n", false));
+        std::fs::remove_file(&path).unwrap();
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "IRONSTAR_UPDATE_GOLDENS=1")]
+    fn assert_golden_fails_when_missing() {
+        check_golden(&golden_path("missing.json"), "{}\n", false);
+    }
+
+    #[test]
+    fn check_golden_rewrites_when_updating() {
+        let dir = golden_path("update");
+        let path = dir.join("nested.json");
+
+        check_golden(&path, "[]\n", true);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[]\n");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn log_capture_records_fields_while_installed() {
+        let capture = LogCapture::default();
+        {
+            let _guard = capture.set_default();
+            tracing::info!(count = 2_i64, name = "a", "captured");
+            tracing::info!("other");
+        }
+        tracing::info!("after");
+
+        let lines = capture.lines_with_message("captured");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].field("count"), Some("2"));
+        assert_eq!(lines[0].field("name"), Some("a"));
+        assert_eq!(capture.lines().len(), 2);
+    }
+}
@@ -4,6 +4,9 @@
 //! - `EventType`: Event type discriminator for JSON schema evolution
 //! - `DeciderType`: Aggregate type name for polymorphic routing
 //! - `IsFinal`: Terminal state marker for aggregate lifecycle
+//! - `Timestamped`: Caller-supplied time at which an event occurred
+
+use chrono::{DateTime, Utc};
 
 pub use fmodel_rust::Identifier;
 
@@ -27,3 +30,11 @@ pub trait DeciderType {
 pub trait IsFinal {
     fn is_final(&self) -> bool;
 }
+
+/// Time at which an event occurred.
+///
+/// Returns the timestamp the command supplied when the event was decided,
+/// used to keep an aggregate's event stream in time order.
+pub trait Timestamped {
+    fn occurred_at(&self) -> DateTime<Utc>;
+}
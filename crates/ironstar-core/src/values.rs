@@ -280,6 +280,12 @@ pub struct GridSize {
 }
 
 impl GridSize {
+    /// Size given to a chart placed without an explicit size.
+    pub const DEFAULT_CHART: Self = Self {
+        width: 4,
+        height: 3,
+    };
+
     /// Create a new GridSize with validated dimensions.
     ///
     /// # Errors
@@ -0,0 +1,143 @@
+//! Runtime checks for the derived fields of read-model states.
+//!
+//! List views keep counts next to the entries they summarize so that
+//! rendering never recounts. Nothing in the type system ties the two
+//! together, so an evolve arm that updates one and not the other goes
+//! unnoticed until a page shows the wrong total.
+//!
+//! [`ViewInvariants`] lets a view state check its derived fields against its
+//! contents, and [`debug_validated`] runs that check on every folded state in
+//! debug builds, so an evolve bug fails the first test that exercises it.
+
+use std::fmt;
+
+/// A read-model state whose derived fields must agree with its contents.
+pub trait ViewInvariants {
+    /// Check the state's invariants, reporting the first one broken.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ViewInvariantError`] describing the broken invariant.
+    fn validate_invariants(&self) -> Result<(), ViewInvariantError>;
+}
+
+/// A broken view state invariant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViewInvariantError {
+    /// A count field disagrees with the entries it counts.
+    CountMismatch {
+        field: &'static str,
+        count: usize,
+        actual: usize,
+    },
+}
+
+impl ViewInvariantError {
+    /// Check that the count in `field` equals the `actual` number of entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ViewInvariantError::CountMismatch`] when they differ.
+    pub fn check_count(field: &'static str, count: usize, actual: usize) -> Result<(), Self> {
+        if count == actual {
+            Ok(())
+        } else {
+            Err(Self::CountMismatch {
+                field,
+                count,
+                actual,
+            })
+        }
+    }
+}
+
+impl fmt::Display for ViewInvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CountMismatch {
+                field,
+                count,
+                actual,
+            } => write!(
+                f,
+                "view field `{field}` is {count} but {actual} entries exist"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ViewInvariantError {}
+
+/// Return `state` unchanged, asserting its invariants in debug builds.
+///
+/// Wrap a view's evolve function with this so every folded state is
+/// checked; release builds skip the check.
+///
+/// # Panics
+///
+/// Panics in debug builds when `state` breaks an invariant.
+#[must_use]
+#[track_caller]
+pub fn debug_validated<S: ViewInvariants>(state: S) -> S {
+    if cfg!(debug_assertions) {
+        let checked = state.validate_invariants();
+        debug_assert!(checked.is_ok(), "view invariant broken: {checked:?}");
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Listing {
+        entries: Vec<u8>,
+        count: usize,
+    }
+
+    impl ViewInvariants for Listing {
+        fn validate_invariants(&self) -> Result<(), ViewInvariantError> {
+            ViewInvariantError::check_count("count", self.count, self.entries.len())
+        }
+    }
+
+    #[test]
+    fn matching_count_is_valid() {
+        let listing = Listing {
+            entries: vec![1, 2],
+            count: 2,
+        };
+        assert_eq!(listing.validate_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn mismatched_count_is_reported() {
+        let listing = Listing {
+            entries: vec![1],
+            count: 3,
+        };
+        let error = listing.validate_invariants().unwrap_err();
+        assert_eq!(
+            error,
+            ViewInvariantError::CountMismatch {
+                field: "count",
+                count: 3,
+                actual: 1,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "view field `count` is 3 but 1 entries exist"
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "view invariant broken")]
+    fn debug_validated_panics_on_broken_state() {
+        let _ = debug_validated(Listing {
+            entries: vec![],
+            count: 1,
+        });
+    }
+}
@@ -0,0 +1,377 @@
+//! Catch-up subscriptions: checkpointed replay followed by live delivery.
+//!
+//! A projection updater needs every event exactly once, in global order,
+//! across restarts. [`CatchUpSubscription`] provides that by combining three
+//! pieces:
+//!
+//! 1. **Subscribe first**: the Zenoh subscriber is declared before any replay,
+//!    the same subscribe-before-replay handoff the SSE feeds use, so nothing
+//!    published during catch-up is missed.
+//! 2. **Replay from the checkpoint**: events of the subscribed aggregate type
+//!    after the projection's saved checkpoint are read from the event store
+//!    and handed to the handler.
+//! 3. **Live as a wake-up signal**: each live sample triggers another read
+//!    from the store starting at the last processed sequence. Bus payloads do
+//!    not carry global sequences, so the store remains the source of truth
+//!    and an event that was both replayed and published is handled once.
+//!
+//! The checkpoint is saved after each processed batch.
+
+use std::sync::Arc;
+
+use ironstar_event_store::{
+    GlobalSequence, SqliteCheckpointStore, SqliteEventRepository, StoredEvent,
+};
+use serde::de::DeserializeOwned;
+use tracing::instrument;
+use zenoh::Session;
+
+use crate::error::EventBusError;
+use crate::key_expr::aggregate_type_pattern;
+
+/// Gapless, exactly-once event delivery for a named projection.
+pub struct CatchUpSubscription<C, E> {
+    repo: Arc<SqliteEventRepository<C, E>>,
+    checkpoints: SqliteCheckpointStore,
+    projection_name: String,
+    aggregate_type: String,
+    key_expr: String,
+}
+
+impl<C, E> CatchUpSubscription<C, E>
+where
+    E: DeserializeOwned + Clone,
+{
+    /// Create a subscription for `projection_name` to the events of
+    /// `aggregate_type`.
+    ///
+    /// Only events of `aggregate_type` are read from the store, since other
+    /// aggregates' events do not deserialize as `E`. The live wake-up listens
+    /// on `aggregate_type_pattern(aggregate_type)`.
+    #[must_use]
+    pub fn new(
+        repo: Arc<SqliteEventRepository<C, E>>,
+        checkpoints: SqliteCheckpointStore,
+        projection_name: impl Into<String>,
+        aggregate_type: impl Into<String>,
+    ) -> Self {
+        let aggregate_type = aggregate_type.into();
+        Self {
+            repo,
+            checkpoints,
+            projection_name: projection_name.into(),
+            key_expr: aggregate_type_pattern(&aggregate_type),
+            aggregate_type,
+        }
+    }
+
+    /// Catch up from the checkpoint, then process live events until the
+    /// subscriber closes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscriber cannot be declared or if reading
+    /// events or saving the checkpoint fails.
+    #[instrument(
+        name = "event_bus.catch_up",
+        skip(self, session, handler),
+        fields(projection = %self.projection_name, key_expr = %self.key_expr),
+    )]
+    pub async fn run<F>(&self, session: &Session, mut handler: F) -> Result<(), EventBusError>
+    where
+        F: FnMut(&StoredEvent<E>),
+    {
+        // Subscribe BEFORE replay so events appended during catch-up still wake us
+        let subscriber = session
+            .declare_subscriber(self.key_expr.as_str())
+            .await
+            .map_err(|e| EventBusError::event_bus(e.to_string()))?;
+
+        let checkpoint = self
+            .checkpoints
+            .load_checkpoint(&self.projection_name)
+            .await?
+            .unwrap_or_default();
+        let mut position = self.process_since(checkpoint, &mut handler).await?;
+        tracing::debug!(%position, "catch-up complete, switching to live");
+
+        while subscriber.recv_async().await.is_ok() {
+            position = self.process_since(position, &mut handler).await?;
+        }
+        Ok(())
+    }
+
+    /// Handle every stored event of the aggregate type after `position` and
+    /// advance the checkpoint.
+    async fn process_since<F>(
+        &self,
+        position: GlobalSequence,
+        handler: &mut F,
+    ) -> Result<GlobalSequence, EventBusError>
+    where
+        F: FnMut(&StoredEvent<E>),
+    {
+        let events = self
+            .repo
+            .query_type_since_sequence(&self.aggregate_type, position)
+            .await?;
+        let Some(last) = events.last().map(|stored| stored.sequence) else {
+            return Ok(position);
+        };
+        for stored in &events {
+            handler(stored);
+        }
+        self.checkpoints
+            .save_checkpoint(&self.projection_name, last)
+            .await?;
+        Ok(last)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::event_bus::{EventBus, ZenohEventBus, open_embedded_session};
+    use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+    use ironstar_event_store::{CHECKPOINTS_MIGRATION_SQL, EVENTS_MIGRATION_SQL};
+    use sqlx::SqlitePool;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct TestEvent {
+        id: String,
+        n: u32,
+    }
+
+    impl Identifier for TestEvent {
+        fn identifier(&self) -> String {
+            self.id.clone()
+        }
+    }
+
+    impl EventType for TestEvent {
+        fn event_type(&self) -> String {
+            "TestEvent".to_string()
+        }
+    }
+
+    impl DeciderType for TestEvent {
+        fn decider_type(&self) -> String {
+            "CatchUpTest".to_string()
+        }
+    }
+
+    impl IsFinal for TestEvent {
+        fn is_final(&self) -> bool {
+            false
+        }
+    }
+
+    struct TestCommand;
+
+    impl Identifier for TestCommand {
+        fn identifier(&self) -> String {
+            "agg-1".to_string()
+        }
+    }
+
+    impl DeciderType for TestCommand {
+        fn decider_type(&self) -> String {
+            "CatchUpTest".to_string()
+        }
+    }
+
+    fn event(n: u32) -> TestEvent {
+        TestEvent {
+            id: "agg-1".to_string(),
+            n,
+        }
+    }
+
+    /// An event of another aggregate type that does not deserialize as
+    /// `TestEvent`.
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct OtherEvent {
+        label: String,
+    }
+
+    impl Identifier for OtherEvent {
+        fn identifier(&self) -> String {
+            "other-1".to_string()
+        }
+    }
+
+    impl EventType for OtherEvent {
+        fn event_type(&self) -> String {
+            "OtherEvent".to_string()
+        }
+    }
+
+    impl DeciderType for OtherEvent {
+        fn decider_type(&self) -> String {
+            "OtherTest".to_string()
+        }
+    }
+
+    impl IsFinal for OtherEvent {
+        fn is_final(&self) -> bool {
+            false
+        }
+    }
+
+    async fn create_test_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool");
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("events migration");
+        sqlx::query(CHECKPOINTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("checkpoints migration");
+        pool
+    }
+
+    async fn append_and_publish(
+        repo: &SqliteEventRepository<TestCommand, TestEvent>,
+        bus: &ZenohEventBus,
+        n: u32,
+    ) {
+        let saved = repo
+            .save_with_command(&[event(n)], None)
+            .await
+            .expect("save");
+        for (e, _) in &saved {
+            bus.publish(e).await.expect("publish");
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn events_appended_during_catch_up_are_processed_once() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        let checkpoints = SqliteCheckpointStore::new(pool);
+        repo.append_batch("agg-1", (1..=3).map(event).collect())
+            .await
+            .expect("seed history");
+
+        let session = Arc::new(open_embedded_session().await.expect("session"));
+        let bus = ZenohEventBus::new(Arc::clone(&session));
+        let subscription = CatchUpSubscription::new(
+            Arc::clone(&repo),
+            checkpoints.clone(),
+            "counter",
+            "CatchUpTest",
+        );
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let task = tokio::spawn({
+            let session = Arc::clone(&session);
+            async move {
+                subscription
+                    .run(&session, |stored| {
+                        let _ = tx.send(stored.event.n);
+                    })
+                    .await
+            }
+        });
+
+        // Appended while the subscription is starting up / replaying
+        append_and_publish(&repo, &bus, 4).await;
+
+        let mut seen = Vec::new();
+        while seen.len() < 4 {
+            let n = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+                .await
+                .expect("event within timeout")
+                .expect("channel open");
+            seen.push(n);
+        }
+
+        // Appended after the switch to live
+        append_and_publish(&repo, &bus, 5).await;
+        let n = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("live event within timeout")
+            .expect("channel open");
+        seen.push(n);
+
+        // No duplicates arrive afterwards
+        let extra = tokio::time::timeout(Duration::from_millis(200), rx.recv()).await;
+        assert!(extra.is_err(), "unexpected duplicate delivery: {extra:?}");
+        assert_eq!(seen, vec![1, 2, 3, 4, 5]);
+
+        let latest = repo.latest_sequence().await.expect("latest");
+        assert_eq!(
+            checkpoints.load_checkpoint("counter").await.expect("load"),
+            latest
+        );
+
+        task.abort();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn other_aggregate_events_are_skipped() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        let other_repo: SqliteEventRepository<TestCommand, OtherEvent> =
+            SqliteEventRepository::new(pool.clone());
+        let checkpoints = SqliteCheckpointStore::new(pool);
+        repo.append_batch("agg-1", vec![event(1)])
+            .await
+            .expect("seed history");
+        let checkpoint = repo
+            .latest_sequence()
+            .await
+            .expect("latest")
+            .expect("seeded");
+        checkpoints
+            .save_checkpoint("counter", checkpoint)
+            .await
+            .expect("save checkpoint");
+
+        // Between the checkpoint and live
+        other_repo
+            .append_batch(
+                "other-1",
+                vec![OtherEvent {
+                    label: "not a TestEvent".to_string(),
+                }],
+            )
+            .await
+            .expect("other aggregate");
+
+        let session = Arc::new(open_embedded_session().await.expect("session"));
+        let bus = ZenohEventBus::new(Arc::clone(&session));
+        let subscription =
+            CatchUpSubscription::new(Arc::clone(&repo), checkpoints, "counter", "CatchUpTest");
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let task = tokio::spawn({
+            let session = Arc::clone(&session);
+            async move {
+                subscription
+                    .run(&session, |stored| {
+                        let _ = tx.send(stored.event.n);
+                    })
+                    .await
+            }
+        });
+
+        append_and_publish(&repo, &bus, 2).await;
+        let n = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("live event within timeout")
+            .expect("channel open");
+        assert_eq!(n, 2);
+        assert!(!task.is_finished(), "subscription stopped: {task:?}");
+
+        task.abort();
+    }
+}
@@ -9,6 +9,7 @@ use std::fmt;
 use uuid::Uuid;
 
 use ironstar_core::ErrorCode;
+use ironstar_event_store::EventStoreError;
 
 /// Event bus errors from Zenoh publish/subscribe operations with UUID tracking.
 #[derive(Debug)]
@@ -25,6 +26,8 @@ pub enum EventBusErrorKind {
     EventBus(String),
     /// JSON serialization failed.
     Serialization(serde_json::Error),
+    /// Event store read or checkpoint write failed during a subscription.
+    Store(EventStoreError),
 }
 
 impl EventBusError {
@@ -67,6 +70,7 @@ impl EventBusError {
         match &self.kind {
             EventBusErrorKind::EventBus(_) => ErrorCode::ServiceUnavailable,
             EventBusErrorKind::Serialization(_) => ErrorCode::InternalError,
+            EventBusErrorKind::Store(e) => e.error_code(),
         }
     }
 }
@@ -78,6 +82,7 @@ impl fmt::Display for EventBusError {
             EventBusErrorKind::Serialization(e) => {
                 write!(f, "event bus serialization error: {e}")
             }
+            EventBusErrorKind::Store(e) => write!(f, "event bus store error: {e}"),
         }
     }
 }
@@ -86,6 +91,7 @@ impl std::error::Error for EventBusError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self.kind {
             EventBusErrorKind::Serialization(e) => Some(e),
+            EventBusErrorKind::Store(e) => Some(e),
             _ => None,
         }
     }
@@ -97,6 +103,12 @@ impl From<serde_json::Error> for EventBusError {
     }
 }
 
+impl From<EventStoreError> for EventBusError {
+    fn from(e: EventStoreError) -> Self {
+        Self::new(EventBusErrorKind::Store(e))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
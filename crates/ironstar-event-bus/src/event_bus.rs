@@ -32,10 +32,12 @@
 
 use crate::error::EventBusError;
 use crate::key_expr::event_key_without_sequence;
+use crate::metrics::record_publish;
 use ironstar_core::{DeciderType, Identifier};
 use serde::Serialize;
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::warn;
 use zenoh::Session;
 
@@ -142,6 +144,16 @@ impl ZenohEventBus {
     pub fn session(&self) -> &Arc<Session> {
         &self.session
     }
+
+    /// Serialize `event` and put it on `key_expr`.
+    async fn put<E: Serialize>(&self, key_expr: &str, event: &E) -> Result<(), EventBusError> {
+        let payload = serde_json::to_vec(event)?;
+
+        self.session
+            .put(key_expr, payload)
+            .await
+            .map_err(|e| EventBusError::event_bus(e.to_string()))
+    }
 }
 
 impl EventBus for ZenohEventBus {
@@ -153,14 +165,10 @@ impl EventBus for ZenohEventBus {
         let aggregate_id = event.identifier();
         let key_expr = event_key_without_sequence(&aggregate_type, &aggregate_id);
 
-        let payload = serde_json::to_vec(event)?;
-
-        self.session
-            .put(&key_expr, payload)
-            .await
-            .map_err(|e| EventBusError::event_bus(e.to_string()))?;
-
-        Ok(())
+        let started = Instant::now();
+        let result = self.put(&key_expr, event).await;
+        record_publish(&key_expr, started.elapsed(), result.is_ok());
+        result
     }
 }
 
@@ -377,4 +385,74 @@ mod tests {
         // Should complete without panicking
         publish_events_fire_and_forget(&event_bus, &events).await;
     }
+
+    struct UnserializableEvent;
+
+    impl Serialize for UnserializableEvent {
+        fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("cannot serialize"))
+        }
+    }
+
+    impl Identifier for UnserializableEvent {
+        fn identifier(&self) -> String {
+            "broken".to_string()
+        }
+    }
+
+    impl DeciderType for UnserializableEvent {
+        fn decider_type(&self) -> String {
+            "Test".to_string()
+        }
+    }
+
+    // The local recorder is thread-scoped, so drive the publishes with
+    // `block_on`, which polls them on this thread.
+    #[test]
+    fn publish_records_duration_and_failures() {
+        let recorder = metrics_exporter_prometheus::PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .expect("runtime should build");
+
+        let (after_success, after_failure) = metrics::with_local_recorder(&recorder, || {
+            runtime.block_on(async {
+                let session = Arc::new(open_embedded_session().await.expect("session should open"));
+                let event_bus = ZenohEventBus::new(session);
+
+                let event = TestEvent {
+                    id: "ok".to_string(),
+                    data: "published".to_string(),
+                };
+                event_bus
+                    .publish(&event)
+                    .await
+                    .expect("publish should succeed");
+                let after_success = handle.render();
+
+                let result = event_bus.publish(&UnserializableEvent).await;
+                assert!(result.is_err());
+                (after_success, handle.render())
+            })
+        });
+
+        assert!(
+            after_success.contains(
+                r#"ironstar_event_publish_duration_seconds_count{aggregate_type="Test"} 1"#
+            )
+        );
+        assert!(!after_success.contains("ironstar_event_publish_failures_total{"));
+        assert!(
+            after_failure.contains(
+                r#"ironstar_event_publish_duration_seconds_count{aggregate_type="Test"} 2"#
+            )
+        );
+        assert!(
+            after_failure
+                .contains(r#"ironstar_event_publish_failures_total{aggregate_type="Test"} 1"#)
+        );
+    }
 }
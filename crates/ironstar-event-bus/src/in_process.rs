@@ -0,0 +1,251 @@
+//! In-process event bus backed by `tokio::sync::broadcast`.
+//!
+//! [`InProcessEventBus`] implements [`EventBus`] without a Zenoh session, so
+//! application-layer tests can assert what a command handler published. It
+//! mirrors the Zenoh shape: events are published as JSON payloads under
+//! `events/{aggregate_type}/{aggregate_id}`, and subscribers filter with the
+//! same wildcard patterns (`events/Todo/**`).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let bus = InProcessEventBus::new();
+//! let mut subscriber = bus.subscribe(aggregate_type_pattern("Workspace"));
+//!
+//! handle_workspace_command(repo, Some(&bus), command).await?;
+//!
+//! let published = subscriber.recv_async().await?;
+//! let event: WorkspaceEvent = published.decode()?;
+//! ```
+
+use ironstar_core::{DeciderType, Identifier};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::cache_dependency::matches_key_expression;
+use crate::error::EventBusError;
+use crate::event_bus::EventBus;
+use crate::key_expr::event_key_without_sequence;
+
+/// Default number of buffered events per subscriber before it lags.
+pub const DEFAULT_IN_PROCESS_CAPACITY: usize = 256;
+
+/// An event as delivered to in-process subscribers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedEvent {
+    /// Key expression the event was published under.
+    pub key_expr: String,
+    /// JSON-encoded event payload.
+    pub payload: Vec<u8>,
+}
+
+impl PublishedEvent {
+    /// Deserialize the payload into a domain event.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JSON error if the payload is not a valid `E`.
+    pub fn decode<E: DeserializeOwned>(&self) -> Result<E, serde_json::Error> {
+        serde_json::from_slice(&self.payload)
+    }
+}
+
+/// Event bus that broadcasts to subscribers in the same process.
+#[derive(Debug, Clone)]
+pub struct InProcessEventBus {
+    sender: broadcast::Sender<PublishedEvent>,
+}
+
+impl Default for InProcessEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InProcessEventBus {
+    /// Create a bus with [`DEFAULT_IN_PROCESS_CAPACITY`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_IN_PROCESS_CAPACITY)
+    }
+
+    /// Create a bus buffering up to `capacity` events per subscriber.
+    ///
+    /// A `capacity` of zero is raised to one.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    /// Subscribe to events whose key matches `key_expr`.
+    ///
+    /// Only events published after this call are delivered, matching Zenoh's
+    /// subscriber semantics.
+    #[must_use]
+    pub fn subscribe(&self, key_expr: impl Into<String>) -> InProcessSubscriber {
+        InProcessSubscriber {
+            pattern: key_expr.into(),
+            receiver: self.sender.subscribe(),
+        }
+    }
+}
+
+impl EventBus for InProcessEventBus {
+    async fn publish<E>(&self, event: &E) -> Result<(), EventBusError>
+    where
+        E: Identifier + DeciderType + Serialize + Sync,
+    {
+        let key_expr = event_key_without_sequence(&event.decider_type(), &event.identifier());
+        let payload = serde_json::to_vec(event)?;
+
+        // A send error only means nobody is subscribed, which is not a failure
+        let _ = self.sender.send(PublishedEvent { key_expr, payload });
+        Ok(())
+    }
+}
+
+/// Receiving half of an [`InProcessEventBus`] subscription.
+#[derive(Debug)]
+pub struct InProcessSubscriber {
+    pattern: String,
+    receiver: broadcast::Receiver<PublishedEvent>,
+}
+
+impl InProcessSubscriber {
+    /// Wait for the next event matching this subscriber's key expression.
+    ///
+    /// Events dropped because the subscriber lagged are logged and skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error once the bus and all its clones have been dropped.
+    pub async fn recv_async(&mut self) -> Result<PublishedEvent, EventBusError> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) if matches_key_expression(&self.pattern, &event.key_expr) => {
+                    return Ok(event);
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(
+                        skipped,
+                        pattern = %self.pattern,
+                        "in-process subscriber lagged"
+                    );
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    return Err(EventBusError::event_bus("in-process event bus closed"));
+                }
+            }
+        }
+    }
+
+    /// Key expression this subscriber filters on.
+    #[must_use]
+    pub fn key_expr(&self) -> &str {
+        &self.pattern
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::event_bus::publish_events_fire_and_forget;
+    use crate::key_expr::aggregate_type_pattern;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct TestEvent {
+        id: String,
+        data: String,
+    }
+
+    impl Identifier for TestEvent {
+        fn identifier(&self) -> String {
+            self.id.clone()
+        }
+    }
+
+    impl DeciderType for TestEvent {
+        fn decider_type(&self) -> String {
+            "Test".to_string()
+        }
+    }
+
+    fn event(id: &str) -> TestEvent {
+        TestEvent {
+            id: id.to_string(),
+            data: "hello".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn subscriber_receives_published_event() {
+        let bus = InProcessEventBus::new();
+        let mut subscriber = bus.subscribe(aggregate_type_pattern("Test"));
+
+        bus.publish(&event("test-123")).await.expect("publish");
+
+        let published = tokio::time::timeout(Duration::from_millis(100), subscriber.recv_async())
+            .await
+            .expect("receive within timeout")
+            .expect("bus open");
+        assert_eq!(published.key_expr, "events/Test/test-123");
+        assert_eq!(
+            published.decode::<TestEvent>().expect("decode"),
+            event("test-123")
+        );
+    }
+
+    #[tokio::test]
+    async fn subscriber_filters_by_key_expression() {
+        let bus = InProcessEventBus::new();
+        let mut subscriber = bus.subscribe(aggregate_type_pattern("Todo"));
+
+        bus.publish(&event("test-456")).await.expect("publish");
+
+        let result = tokio::time::timeout(Duration::from_millis(50), subscriber.recv_async()).await;
+        assert!(
+            result.is_err(),
+            "Test event should not reach a Todo subscriber"
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_without_subscribers_succeeds() {
+        let bus = InProcessEventBus::new();
+
+        assert!(bus.publish(&event("a")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn fire_and_forget_publishes_every_event() {
+        let bus = InProcessEventBus::new();
+        let mut subscriber = bus.subscribe(aggregate_type_pattern("Test"));
+        let saved = vec![
+            (event("a"), "v1".to_string()),
+            (event("b"), "v2".to_string()),
+        ];
+
+        publish_events_fire_and_forget(&bus, &saved).await;
+
+        let first = subscriber.recv_async().await.expect("first");
+        let second = subscriber.recv_async().await.expect("second");
+        assert_eq!(first.key_expr, "events/Test/a");
+        assert_eq!(second.key_expr, "events/Test/b");
+    }
+
+    #[tokio::test]
+    async fn recv_errors_after_bus_dropped() {
+        let bus = InProcessEventBus::new();
+        let mut subscriber = bus.subscribe(aggregate_type_pattern("Test"));
+
+        drop(bus);
+
+        assert!(subscriber.recv_async().await.is_err());
+    }
+}
@@ -27,7 +27,12 @@
 //!
 //! The [`EventKeyExpr`] type provides validated key expressions with
 //! construction from components and parsing back to extract metadata.
+//!
+//! Aggregate ids with a sub-path, such as `workspace_{uuid}/preferences`,
+//! span two segments. Parsing recognizes them with [`AggregateId`], so the
+//! sub-path is not mistaken for a sequence number.
 
+use ironstar_core::AggregateId;
 use std::fmt;
 use std::str::FromStr;
 use thiserror::Error;
@@ -145,6 +150,26 @@ impl EventKeyExpr {
         }
     }
 
+    /// Aggregate type name, e.g. `"Workspace"`.
+    #[must_use]
+    pub fn aggregate_type(&self) -> &str {
+        &self.aggregate_type
+    }
+
+    /// Aggregate instance id, or `None` when the id segment is a wildcard.
+    ///
+    /// Subscription patterns such as `events/Todo/**` parse with a wildcard
+    /// in the id position; they name no single instance, so callers mapping
+    /// keys to cache entries or SSE feeds get `None` rather than `"**"`.
+    #[must_use]
+    pub fn instance_id(&self) -> Option<&str> {
+        if self.aggregate_id.contains(SINGLE_WILD) {
+            None
+        } else {
+            Some(&self.aggregate_id)
+        }
+    }
+
     /// Convert to a Zenoh key expression string.
     #[must_use]
     pub fn to_key_expr(&self) -> String {
@@ -160,6 +185,11 @@ impl EventKeyExpr {
     /// - `events/{type}/{id}` (without sequence)
     /// - `events/{type}/{id}/{sequence}` (with sequence)
     ///
+    /// A wildcard in the sequence position, as in `events/{type}/{id}/**`,
+    /// parses as no sequence. An `{id}` with a sub-path, as in
+    /// `events/WorkspacePreferences/workspace_{uuid}/preferences/3`, parses
+    /// whole.
+    ///
     /// # Errors
     ///
     /// Returns `ParseError` if the key expression is malformed.
@@ -168,8 +198,16 @@ impl EventKeyExpr {
 
         // Destructure with pattern matching for safe access
         let (root, aggregate_type, aggregate_id, sequence_part) = match parts.as_slice() {
-            [root, agg_type, agg_id] => (*root, *agg_type, *agg_id, None),
-            [root, agg_type, agg_id, seq, ..] => (*root, *agg_type, *agg_id, Some(*seq)),
+            [root, agg_type, agg_id, sub_path, rest @ ..] if is_sub_path(agg_id, sub_path) => (
+                *root,
+                *agg_type,
+                format!("{agg_id}/{sub_path}"),
+                rest.first().copied(),
+            ),
+            [root, agg_type, agg_id] => (*root, *agg_type, (*agg_id).to_string(), None),
+            [root, agg_type, agg_id, seq, ..] => {
+                (*root, *agg_type, (*agg_id).to_string(), Some(*seq))
+            }
             _ => {
                 return Err(ParseError::TooFewSegments {
                     expected: 3,
@@ -196,25 +234,32 @@ impl EventKeyExpr {
 
         // Parse optional sequence
         let sequence = match sequence_part {
-            Some(seq) if !seq.is_empty() => {
-                Some(
-                    seq.parse::<u64>()
-                        .map_err(|_| ParseError::InvalidSequence {
-                            value: seq.to_string(),
-                        })?,
-                )
-            }
+            Some(seq) if !seq.is_empty() && !seq.contains(SINGLE_WILD) => Some(
+                seq.parse::<u64>()
+                    .map_err(|_| ParseError::InvalidSequence {
+                        value: seq.to_string(),
+                    })?,
+            ),
             _ => None,
         };
 
         Ok(Self {
             aggregate_type: aggregate_type.to_string(),
-            aggregate_id: aggregate_id.to_string(),
+            aggregate_id,
             sequence,
         })
     }
 }
 
+/// Whether `segment` continues the aggregate id `id` as its sub-path.
+///
+/// Sequence numbers and wildcards are never sub-paths.
+fn is_sub_path(id: &str, segment: &str) -> bool {
+    segment.parse::<u64>().is_err()
+        && !segment.contains(SINGLE_WILD)
+        && AggregateId::parse(&format!("{id}/{segment}")).is_ok()
+}
+
 impl fmt::Display for EventKeyExpr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_key_expr())
@@ -399,4 +444,53 @@ mod tests {
         let key = EventKeyExpr::parse("events/Todo/abc/0").unwrap();
         assert_eq!(key.sequence, Some(0));
     }
+
+    #[test]
+    fn event_key_expr_accessors_extract_components() {
+        let key = EventKeyExpr::parse("events/Workspace/workspace_abc-123/7").unwrap();
+
+        assert_eq!(key.aggregate_type(), "Workspace");
+        assert_eq!(key.instance_id(), Some("workspace_abc-123"));
+    }
+
+    #[test]
+    fn event_key_expr_wildcard_has_no_instance_id() {
+        let key = EventKeyExpr::parse(&aggregate_type_pattern("Dashboard")).unwrap();
+
+        assert_eq!(key.aggregate_type(), "Dashboard");
+        assert_eq!(key.instance_id(), None);
+    }
+
+    #[test]
+    fn event_key_expr_parse_sub_path_aggregate_id() {
+        let aggregate_id = "workspace_550e8400-e29b-41d4-a716-446655440000/preferences";
+
+        let key = EventKeyExpr::parse(&event_key("WorkspacePreferences", aggregate_id, 3)).unwrap();
+        assert_eq!(key.instance_id(), Some(aggregate_id));
+        assert_eq!(key.sequence, Some(3));
+
+        let key = EventKeyExpr::parse(&event_key_without_sequence(
+            "WorkspacePreferences",
+            aggregate_id,
+        ))
+        .unwrap();
+        assert_eq!(key.instance_id(), Some(aggregate_id));
+        assert_eq!(key.sequence, None);
+
+        let key = EventKeyExpr::parse(&aggregate_instance_pattern(
+            "WorkspacePreferences",
+            aggregate_id,
+        ))
+        .unwrap();
+        assert_eq!(key.instance_id(), Some(aggregate_id));
+        assert_eq!(key.sequence, None);
+    }
+
+    #[test]
+    fn event_key_expr_parse_instance_pattern() {
+        let key = EventKeyExpr::parse(&aggregate_instance_pattern("Todo", "abc")).unwrap();
+
+        assert_eq!(key.instance_id(), Some("abc"));
+        assert_eq!(key.sequence, None);
+    }
 }
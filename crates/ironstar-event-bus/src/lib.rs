@@ -5,22 +5,32 @@
 //! for invalidation via Zenoh subscriptions.
 
 pub mod cache_dependency;
+pub mod catch_up;
 pub mod error;
 pub mod event_bus;
+pub mod in_process;
 pub mod key_expr;
+pub mod metrics;
+pub mod replay;
 pub mod workspace;
 
 pub use cache_dependency::{CacheDependency, matches_key_expression};
+pub use catch_up::CatchUpSubscription;
 pub use error::{EventBusError, EventBusErrorKind};
 pub use event_bus::{
     EventBus, ZenohEventBus, open_embedded_session, publish_events_fire_and_forget,
     zenoh_embedded_config,
 };
+pub use in_process::{
+    DEFAULT_IN_PROCESS_CAPACITY, InProcessEventBus, InProcessSubscriber, PublishedEvent,
+};
 pub use key_expr::{
     ALL_EVENTS, DOUBLE_WILD, EVENTS_ROOT, EventKeyExpr, ParseError as KeyExprParseError,
     SINGLE_WILD, aggregate_instance_pattern, aggregate_type_pattern, event_key,
     event_key_without_sequence,
 };
+pub use metrics::{EVENT_PUBLISH_DURATION_SECONDS, EVENT_PUBLISH_FAILURES_TOTAL};
+pub use replay::ReplayMode;
 pub use workspace::{
     ALL_WORKSPACE_AGGREGATE_TYPES, DASHBOARD_TYPE, SAVED_QUERY_TYPE, USER_PREFERENCES_TYPE,
     WORKSPACE_TYPE, WorkspaceSubscriberFactory, ZenohSubscriber, dashboard_events_pattern,
@@ -0,0 +1,37 @@
+//! Event bus publish metrics.
+//!
+//! Publishing is fire-and-forget, so failures never surface to callers as
+//! command errors. These metrics make publish latency and failures visible
+//! on the `/metrics` endpoint instead. Both carry an `aggregate_type` label
+//! parsed from the event's key expression.
+
+use std::time::Duration;
+
+use crate::key_expr::EventKeyExpr;
+
+/// Publish duration histogram in seconds (labels: aggregate_type).
+pub const EVENT_PUBLISH_DURATION_SECONDS: &str = "ironstar_event_publish_duration_seconds";
+
+/// Failed publish counter (labels: aggregate_type).
+pub const EVENT_PUBLISH_FAILURES_TOTAL: &str = "ironstar_event_publish_failures_total";
+
+/// Label value used when a key expression cannot be parsed.
+const UNKNOWN_AGGREGATE_TYPE: &str = "unknown";
+
+/// Record the outcome of one publish to `key_expr`.
+///
+/// Every publish records its duration; failed publishes also increment the
+/// failure counter.
+pub(crate) fn record_publish(key_expr: &str, elapsed: Duration, succeeded: bool) {
+    let aggregate_type = EventKeyExpr::parse(key_expr).map_or_else(
+        |_| UNKNOWN_AGGREGATE_TYPE.to_string(),
+        |key| key.aggregate_type().to_string(),
+    );
+
+    metrics::histogram!(EVENT_PUBLISH_DURATION_SECONDS, "aggregate_type" => aggregate_type.clone())
+        .record(elapsed.as_secs_f64());
+    if !succeeded {
+        metrics::counter!(EVENT_PUBLISH_FAILURES_TOTAL, "aggregate_type" => aggregate_type)
+            .increment(1);
+    }
+}
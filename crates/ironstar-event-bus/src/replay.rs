@@ -0,0 +1,57 @@
+//! Live versus replay handling of stored events.
+//!
+//! Handlers that react to events (sagas, projection updaters) run in one of
+//! two modes:
+//!
+//! - **Live**: the event was just persisted. Anything the handler persists in
+//!   response is published to the event bus, so subscribers and other
+//!   reactors hear about it.
+//! - **Replay**: the event was read back from the store to rebuild state.
+//!   Whatever the handler produces was already published the first time
+//!   round, and publishing it again would wake every reactor subscribed to
+//!   it, which can loop back into the replay. Publishing is suppressed.
+//!
+//! Handlers take a [`ReplayMode`] and route their bus through
+//! [`ReplayMode::event_bus`] instead of branching on the mode themselves.
+
+/// Whether a handler is reacting to live events or replaying stored ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ReplayMode {
+    /// Reacting to newly persisted events; results are published.
+    #[default]
+    Live,
+    /// Replaying stored events during a rebuild; nothing is published.
+    Replay,
+}
+
+impl ReplayMode {
+    /// Whether events are being replayed from the store.
+    #[must_use]
+    pub const fn is_replay(self) -> bool {
+        matches!(self, Self::Replay)
+    }
+
+    /// The bus to publish to in this mode: `event_bus` when live, `None`
+    /// while replaying.
+    #[must_use]
+    pub fn event_bus<B>(self, event_bus: Option<&B>) -> Option<&B> {
+        match self {
+            Self::Live => event_bus,
+            Self::Replay => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_withholds_the_event_bus() {
+        let bus = "bus";
+
+        assert_eq!(ReplayMode::Live.event_bus(Some(&bus)), Some(&bus));
+        assert_eq!(ReplayMode::Replay.event_bus(Some(&bus)), None);
+        assert_eq!(ReplayMode::default(), ReplayMode::Live);
+    }
+}
@@ -0,0 +1,226 @@
+//! Projection checkpoint persistence.
+//!
+//! A checkpoint records the last global event sequence a named projection has
+//! processed. On restart the projection loads its checkpoint and replays only
+//! newer events via [`SqliteEventRepository::query_since_checkpoint`] instead
+//! of rebuilding from the start of the log.
+//!
+//! # Schema
+//!
+//! See `CHECKPOINTS_MIGRATION_SQL`. One row per projection; saving overwrites.
+
+use serde::de::DeserializeOwned;
+use sqlx::sqlite::SqlitePool;
+use tracing::instrument;
+
+use crate::error::EventStoreError;
+use crate::event_store::{SqliteEventRepository, StoredEvent};
+use crate::sequence::GlobalSequence;
+
+/// SQL for the `checkpoints` table.
+///
+/// Embedded directly so tests can create the schema without depending on
+/// the monolith's migrations directory.
+pub const CHECKPOINTS_MIGRATION_SQL: &str = include_str!("checkpoints_migration.sql");
+
+/// SQLite-backed store of per-projection checkpoints.
+#[derive(Debug, Clone)]
+pub struct SqliteCheckpointStore {
+    pool: SqlitePool,
+}
+
+impl SqliteCheckpointStore {
+    /// Create a checkpoint store with the given connection pool.
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Record that `projection_name` has processed events up to `sequence`.
+    ///
+    /// Overwrites any previous checkpoint for the projection.
+    #[instrument(name = "checkpoint.save", skip(self))]
+    pub async fn save_checkpoint(
+        &self,
+        projection_name: &str,
+        sequence: GlobalSequence,
+    ) -> Result<(), EventStoreError> {
+        sqlx::query(
+            r#"
+            INSERT INTO checkpoints (projection_name, sequence)
+            VALUES (?, ?)
+            ON CONFLICT(projection_name) DO UPDATE SET
+                sequence = excluded.sequence,
+                updated_at = datetime('now', 'utc')
+            "#,
+        )
+        .bind(projection_name)
+        .bind(sequence.to_sql())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Load the last processed sequence for `projection_name`.
+    ///
+    /// Returns `None` if the projection has never saved a checkpoint.
+    #[instrument(name = "checkpoint.load", skip(self))]
+    pub async fn load_checkpoint(
+        &self,
+        projection_name: &str,
+    ) -> Result<Option<GlobalSequence>, EventStoreError> {
+        let sequence: Option<u64> =
+            sqlx::query_scalar("SELECT sequence FROM checkpoints WHERE projection_name = ?")
+                .bind(projection_name)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(sequence.map(GlobalSequence::new))
+    }
+}
+
+impl<C, E> SqliteEventRepository<C, E>
+where
+    E: DeserializeOwned + Clone,
+{
+    /// Load the events a projection has not yet processed.
+    ///
+    /// Resumes after the projection's checkpoint, or replays the full log
+    /// when no checkpoint exists.
+    #[instrument(name = "event_store.query_since_checkpoint", skip(self, checkpoints))]
+    pub async fn query_since_checkpoint(
+        &self,
+        checkpoints: &SqliteCheckpointStore,
+        projection_name: &str,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        match checkpoints.load_checkpoint(projection_name).await? {
+            Some(sequence) => self.query_since_sequence(sequence).await,
+            None => self.query_all().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_store::EVENTS_MIGRATION_SQL;
+    use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+    struct TestEvent {
+        id: String,
+        n: u32,
+    }
+
+    impl Identifier for TestEvent {
+        fn identifier(&self) -> String {
+            self.id.clone()
+        }
+    }
+
+    impl EventType for TestEvent {
+        fn event_type(&self) -> String {
+            "TestEvent".to_string()
+        }
+    }
+
+    impl DeciderType for TestEvent {
+        fn decider_type(&self) -> String {
+            "Test".to_string()
+        }
+    }
+
+    impl IsFinal for TestEvent {
+        fn is_final(&self) -> bool {
+            false
+        }
+    }
+
+    struct TestCommand;
+
+    impl Identifier for TestCommand {
+        fn identifier(&self) -> String {
+            "agg-1".to_string()
+        }
+    }
+
+    impl DeciderType for TestCommand {
+        fn decider_type(&self) -> String {
+            "Test".to_string()
+        }
+    }
+
+    async fn create_test_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(CHECKPOINTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn load_missing_checkpoint_returns_none() {
+        let store = SqliteCheckpointStore::new(create_test_pool().await);
+
+        assert_eq!(store.load_checkpoint("todo_list").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn save_overwrites_previous_checkpoint() {
+        let store = SqliteCheckpointStore::new(create_test_pool().await);
+
+        let seq = GlobalSequence::new;
+        store.save_checkpoint("todo_list", seq(3)).await.unwrap();
+        store.save_checkpoint("todo_list", seq(7)).await.unwrap();
+        store
+            .save_checkpoint("workspace_list", seq(1))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.load_checkpoint("todo_list").await.unwrap(),
+            Some(seq(7))
+        );
+        assert_eq!(
+            store.load_checkpoint("workspace_list").await.unwrap(),
+            Some(seq(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn rebuild_resumes_after_checkpoint() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> =
+            SqliteEventRepository::new(pool.clone());
+        let store = SqliteCheckpointStore::new(pool);
+        let events: Vec<TestEvent> = (1..=5)
+            .map(|n| TestEvent {
+                id: "agg-1".to_string(),
+                n,
+            })
+            .collect();
+        let stored = repo.append_batch("agg-1", events).await.unwrap();
+
+        // First run: no checkpoint, process everything and record progress
+        let first = repo.query_since_checkpoint(&store, "sum").await.unwrap();
+        assert_eq!(first.len(), 5);
+        store
+            .save_checkpoint("sum", stored[2].sequence)
+            .await
+            .unwrap();
+
+        // Resume: only events after the checkpoint are processed
+        let resumed = repo.query_since_checkpoint(&store, "sum").await.unwrap();
+        let processed: Vec<u32> = resumed.iter().map(|s| s.event.n).collect();
+        assert_eq!(processed, vec![4, 5]);
+    }
+}
@@ -0,0 +1,8 @@
+-- Projection checkpoints: the last global event sequence each projection has processed.
+-- Projection updaters resume from here instead of replaying the full event log.
+
+CREATE TABLE IF NOT EXISTS checkpoints (
+    projection_name TEXT PRIMARY KEY,
+    sequence INTEGER NOT NULL CHECK (sequence >= 0),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'utc'))
+) STRICT;
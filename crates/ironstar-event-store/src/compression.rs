@@ -0,0 +1,160 @@
+//! Optional zstd compression of event payloads.
+//!
+//! Events such as `UiStateUpdated` can carry large JSON documents. When a
+//! repository is configured with [`PayloadCompression`], payloads at or above
+//! the threshold are stored zstd-compressed in the `payload_zstd` column with
+//! the row's `compressed` flag set; smaller payloads stay plain JSON so they
+//! remain queryable with SQLite's JSON functions.
+//!
+//! Reads decompress transparently based on the per-row flag, so a repository
+//! without compression configured still reads compressed rows written by one
+//! with it.
+//!
+//! # Schema
+//!
+//! See `EVENTS_COMPRESSION_MIGRATION_SQL`.
+
+use crate::error::{EventStoreError, EventStoreErrorKind};
+
+/// SQL adding the `compressed` flag and `payload_zstd` column to `events`.
+pub const EVENTS_COMPRESSION_MIGRATION_SQL: &str = include_str!("events_compression_migration.sql");
+
+/// Default minimum payload size, in bytes, that is compressed.
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 4096;
+
+/// Default zstd compression level.
+pub const DEFAULT_COMPRESSION_LEVEL: i32 = 3;
+
+/// JSON placeholder written to `payload` when the real payload is compressed.
+///
+/// The `payload` column must hold valid JSON, so compressed rows carry `null`.
+const COMPRESSED_PLACEHOLDER: &str = "null";
+
+/// Compression policy for an event repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadCompression {
+    threshold: usize,
+    level: i32,
+}
+
+impl Default for PayloadCompression {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_COMPRESSION_THRESHOLD,
+            level: DEFAULT_COMPRESSION_LEVEL,
+        }
+    }
+}
+
+impl PayloadCompression {
+    /// Compress payloads of at least `threshold` bytes at the default level.
+    #[must_use]
+    pub fn with_threshold(threshold: usize) -> Self {
+        Self {
+            threshold,
+            ..Self::default()
+        }
+    }
+
+    /// Set the zstd compression level.
+    #[must_use]
+    pub fn level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Minimum payload size, in bytes, that is compressed.
+    #[must_use]
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Encode a serialized payload for storage.
+    pub(crate) fn encode(&self, json: String) -> Result<EncodedPayload, EventStoreError> {
+        if json.len() < self.threshold {
+            return Ok(EncodedPayload::plain(json));
+        }
+        let compressed = zstd::encode_all(json.as_bytes(), self.level)
+            .map_err(|e| EventStoreError::new(EventStoreErrorKind::Compression(e)))?;
+        Ok(EncodedPayload {
+            payload: COMPRESSED_PLACEHOLDER.to_string(),
+            compressed: true,
+            payload_zstd: Some(compressed),
+        })
+    }
+}
+
+/// Column values for a payload as stored in the `events` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EncodedPayload {
+    pub(crate) payload: String,
+    pub(crate) compressed: bool,
+    pub(crate) payload_zstd: Option<Vec<u8>>,
+}
+
+impl EncodedPayload {
+    /// An uncompressed JSON payload.
+    pub(crate) fn plain(json: String) -> Self {
+        Self {
+            payload: json,
+            compressed: false,
+            payload_zstd: None,
+        }
+    }
+
+    /// Recover the JSON payload, decompressing if the row is flagged.
+    pub(crate) fn decode(self) -> Result<String, EventStoreError> {
+        if !self.compressed {
+            return Ok(self.payload);
+        }
+        let bytes = self.payload_zstd.ok_or_else(|| {
+            EventStoreError::database("compressed event row has no payload_zstd data")
+        })?;
+        let json = zstd::decode_all(bytes.as_slice())
+            .map_err(|e| EventStoreError::new(EventStoreErrorKind::Compression(e)))?;
+        String::from_utf8(json).map_err(|e| {
+            EventStoreError::new(EventStoreErrorKind::Compression(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                e,
+            )))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_payload_is_stored_plain() {
+        let compression = PayloadCompression::with_threshold(64);
+
+        let encoded = compression.encode(r#"{"a":1}"#.to_string()).unwrap();
+
+        assert_eq!(encoded, EncodedPayload::plain(r#"{"a":1}"#.to_string()));
+    }
+
+    #[test]
+    fn large_payload_round_trips_byte_identical() {
+        let compression = PayloadCompression::with_threshold(64);
+        let json = format!(r#"{{"state":"{}"}}"#, "x".repeat(10_000));
+
+        let encoded = compression.encode(json.clone()).unwrap();
+
+        assert!(encoded.compressed);
+        assert_eq!(encoded.payload, COMPRESSED_PLACEHOLDER);
+        assert!(encoded.payload_zstd.as_ref().unwrap().len() < json.len());
+        assert_eq!(encoded.decode().unwrap().as_bytes(), json.as_bytes());
+    }
+
+    #[test]
+    fn flagged_row_without_blob_is_an_error() {
+        let encoded = EncodedPayload {
+            payload: COMPRESSED_PLACEHOLDER.to_string(),
+            compressed: true,
+            payload_zstd: None,
+        };
+
+        assert!(encoded.decode().is_err());
+    }
+}
@@ -0,0 +1,80 @@
+//! Correlation and causation ids for persisted events.
+//!
+//! A command handled for a request starts a chain whose correlation id is the
+//! request id. Every event the chain leads to, including events written by
+//! sagas reacting to it, carries the same correlation id, so
+//! `load_by_correlation` can fetch the whole causal chain. The causation id
+//! names the command or event that directly caused an event.
+//!
+//! # Storage
+//!
+//! Correlation is stored in the `metadata` JSON column, as `correlation_id`
+//! and `caused_by`. A command that caused an event is recorded separately in
+//! `command_id`.
+
+/// Correlation context recorded with the events of one save.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Correlation {
+    /// The request that started the chain.
+    pub correlation_id: String,
+    /// The command or event that directly caused the events, if known.
+    pub causation_id: Option<String>,
+}
+
+impl Correlation {
+    /// Start a chain at `correlation_id`, typically the request id.
+    #[must_use]
+    pub fn new(correlation_id: impl Into<String>) -> Self {
+        Self {
+            correlation_id: correlation_id.into(),
+            causation_id: None,
+        }
+    }
+
+    /// The same chain, with events caused by `causation_id`.
+    #[must_use]
+    pub fn caused_by(&self, causation_id: impl Into<String>) -> Self {
+        Self {
+            correlation_id: self.correlation_id.clone(),
+            causation_id: Some(causation_id.into()),
+        }
+    }
+
+    /// JSON for the `metadata` column.
+    pub(crate) fn to_metadata(&self) -> String {
+        match &self.causation_id {
+            Some(caused_by) => serde_json::json!({
+                "correlation_id": self.correlation_id,
+                "caused_by": caused_by,
+            }),
+            None => serde_json::json!({ "correlation_id": self.correlation_id }),
+        }
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caused_by_keeps_the_correlation_id() {
+        let root = Correlation::new("request");
+        let child = root.caused_by("event");
+
+        assert_eq!(root.causation_id, None);
+        assert_eq!(child.correlation_id, "request");
+        assert_eq!(child.causation_id.as_deref(), Some("event"));
+    }
+
+    #[test]
+    fn metadata_omits_a_missing_causation_id() {
+        let root = Correlation::new("request");
+
+        assert_eq!(root.to_metadata(), r#"{"correlation_id":"request"}"#);
+        assert_eq!(
+            root.caused_by("event").to_metadata(),
+            r#"{"caused_by":"event","correlation_id":"request"}"#
+        );
+    }
+}
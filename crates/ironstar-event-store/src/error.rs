@@ -27,11 +27,15 @@ pub enum EventStoreErrorKind {
     DatabaseMessage(String),
     /// JSON serialization/deserialization failed.
     Serialization(serde_json::Error),
+    /// Payload compression or decompression failed.
+    Compression(std::io::Error),
     /// Optimistic locking conflict - concurrent modification detected.
     OptimisticLockingConflict {
         aggregate_type: String,
         aggregate_id: String,
     },
+    /// Stored aggregate type has no known event type to decode into.
+    UnknownAggregateType(String),
 }
 
 impl EventStoreError {
@@ -69,7 +73,9 @@ impl EventStoreError {
             EventStoreErrorKind::Database(_) | EventStoreErrorKind::DatabaseMessage(_) => {
                 ErrorCode::DatabaseError
             }
-            EventStoreErrorKind::Serialization(_) => ErrorCode::InternalError,
+            EventStoreErrorKind::Serialization(_)
+            | EventStoreErrorKind::Compression(_)
+            | EventStoreErrorKind::UnknownAggregateType(_) => ErrorCode::InternalError,
             EventStoreErrorKind::OptimisticLockingConflict { .. } => ErrorCode::Conflict,
         }
     }
@@ -91,6 +97,14 @@ impl EventStoreError {
             aggregate_id: aggregate_id.into(),
         })
     }
+
+    /// Create an unknown aggregate type error.
+    #[must_use]
+    pub fn unknown_aggregate_type(aggregate_type: impl Into<String>) -> Self {
+        Self::new(EventStoreErrorKind::UnknownAggregateType(
+            aggregate_type.into(),
+        ))
+    }
 }
 
 impl fmt::Display for EventStoreError {
@@ -103,6 +117,9 @@ impl fmt::Display for EventStoreError {
             EventStoreErrorKind::Serialization(e) => {
                 write!(f, "event store serialization error: {e}")
             }
+            EventStoreErrorKind::Compression(e) => {
+                write!(f, "event store compression error: {e}")
+            }
             EventStoreErrorKind::OptimisticLockingConflict {
                 aggregate_type,
                 aggregate_id,
@@ -112,6 +129,12 @@ impl fmt::Display for EventStoreError {
                     "optimistic locking conflict for {aggregate_type}/{aggregate_id}"
                 )
             }
+            EventStoreErrorKind::UnknownAggregateType(aggregate_type) => {
+                write!(
+                    f,
+                    "event store has no event type for aggregate {aggregate_type}"
+                )
+            }
         }
     }
 }
@@ -121,6 +144,7 @@ impl std::error::Error for EventStoreError {
         match &self.kind {
             EventStoreErrorKind::Database(e) => Some(e),
             EventStoreErrorKind::Serialization(e) => Some(e),
+            EventStoreErrorKind::Compression(e) => Some(e),
             _ => None,
         }
     }
@@ -9,13 +9,23 @@
 //! - Global monotonic `id` for SSE Last-Event-ID semantics
 //! - `previous_id` chain for optimistic locking (first event has NULL)
 //! - Triggers enforce immutability and chain integrity
+//! - Optional zstd compression of large payloads (see `compression`)
 //!
 //! # Extension methods
 //!
 //! Beyond the `EventRepository` trait, this module provides:
 //! - `query_all()` — projection rebuild on startup
 //! - `query_since_sequence(since)` — SSE reconnection via Last-Event-ID
+//! - `query_type_since_sequence(aggregate_type, since)` — catch-up of one
+//!   aggregate type
 //! - `earliest_sequence()` / `latest_sequence()` — stream bounds
+//! - `save_correlated(events, correlation)` / `load_by_correlation(id)` —
+//!   causal chains (see `correlation`)
+//! - `append_batch(stream_id, events)` — atomic multi-event append
+//! - `query_since_checkpoint(store, projection)` — resume a projection (see `checkpoint`)
+//!
+//! Global positions are [`GlobalSequence`]s and positions within a stream are
+//! [`StreamVersion`]s (see `sequence`), so the two cannot be interchanged.
 //!
 //! # Schema versioning
 //!
@@ -24,12 +34,15 @@
 //! current schema during deserialization. The version column enables routing
 //! events to appropriate upcasters without modifying stored data.
 
+use crate::compression::{EncodedPayload, PayloadCompression};
+use crate::correlation::Correlation;
 use crate::error::EventStoreError;
+use crate::sequence::{GlobalSequence, StreamVersion};
 use fmodel_rust::aggregate::EventRepository;
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::{AggregateId, AggregateIdError, DeciderType, EventType, Identifier, IsFinal};
 use serde::{Serialize, de::DeserializeOwned};
 use sqlx::Row;
-use sqlx::sqlite::SqlitePool;
+use sqlx::sqlite::{SqlitePool, SqliteRow};
 use std::marker::PhantomData;
 use tracing::instrument;
 use uuid::Uuid;
@@ -38,7 +51,9 @@ use uuid::Uuid;
 #[derive(Debug, Clone)]
 pub struct StoredEvent<E> {
     /// Global monotonic sequence (SSE Last-Event-ID)
-    pub sequence: i64,
+    pub sequence: GlobalSequence,
+    /// Position within the aggregate stream, starting at 1
+    pub stream_version: StreamVersion,
     /// Event UUID (version for optimistic locking)
     pub event_id: String,
     /// Aggregate type
@@ -53,12 +68,42 @@ pub struct StoredEvent<E> {
     pub event: E,
     /// Command that caused this event
     pub command_id: Option<String>,
+    /// Request that started the causal chain this event belongs to
+    pub correlation_id: Option<String>,
+    /// Command or event that directly caused this event
+    pub causation_id: Option<String>,
     /// Whether this event finalizes the aggregate
     pub is_final: bool,
     /// Event creation timestamp (ISO 8601)
     pub created_at: String,
 }
 
+impl<E> StoredEvent<E> {
+    /// Split the aggregate id into type prefix, instance UUID, and sub-path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AggregateIdError`] for ids outside the stream id
+    /// conventions, including singleton ids such as `default-catalog`.
+    pub fn parsed_aggregate_id(&self) -> Result<AggregateId, AggregateIdError> {
+        AggregateId::parse(&self.aggregate_id)
+    }
+
+    /// Correlation for events caused by this one, such as saga output.
+    ///
+    /// The children join this event's chain, or start a chain at this event
+    /// when it was saved without one.
+    #[must_use]
+    pub fn child_correlation(&self) -> Correlation {
+        Correlation::new(
+            self.correlation_id
+                .clone()
+                .unwrap_or_else(|| self.event_id.clone()),
+        )
+        .caused_by(&self.event_id)
+    }
+}
+
 /// SQLite event repository implementing fmodel-rust's EventRepository trait.
 ///
 /// Generic over command and event types to support multiple aggregates.
@@ -72,6 +117,7 @@ pub struct StoredEvent<E> {
 #[derive(Debug, Clone)]
 pub struct SqliteEventRepository<C, E> {
     pool: SqlitePool,
+    compression: Option<PayloadCompression>,
     _phantom: PhantomData<(C, E)>,
 }
 
@@ -81,10 +127,20 @@ impl<C, E> SqliteEventRepository<C, E> {
     pub fn new(pool: SqlitePool) -> Self {
         Self {
             pool,
+            compression: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Compress payloads written by this repository according to `compression`.
+    ///
+    /// Reads always decompress flagged rows, whether or not this is set.
+    #[must_use]
+    pub fn with_compression(mut self, compression: PayloadCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
     /// Get a reference to the connection pool.
     #[must_use]
     pub fn pool(&self) -> &SqlitePool {
@@ -115,7 +171,7 @@ where
     ) -> Result<Vec<(E, String)>, EventStoreError> {
         let rows = sqlx::query(
             r#"
-            SELECT event_id, payload
+            SELECT event_id, payload, compressed, payload_zstd
             FROM events
             WHERE aggregate_type = ?
             ORDER BY id
@@ -128,8 +184,7 @@ where
         let mut events = Vec::with_capacity(rows.len());
         for row in rows {
             let event_id: String = row.get("event_id");
-            let payload: String = row.get("payload");
-            let event: E = serde_json::from_str(&payload)?;
+            let event: E = decode_payload(&row)?;
             events.push((event, event_id));
         }
 
@@ -141,6 +196,53 @@ where
         Ok(events)
     }
 
+    /// Fetch the events of an aggregate type whose event type is one of
+    /// `event_types`.
+    ///
+    /// Read models that only depend on a few event types use this to skip
+    /// the rest of every stream; the lookup goes through the event type
+    /// index rather than scanning the aggregate type.
+    ///
+    /// Returns events ordered by global sequence (id), with each event
+    /// paired with its event_id (version).
+    #[instrument(
+        name = "event_store.fetch_by_event_types",
+        skip(self),
+        fields(aggregate_type = %aggregate_type, event_count),
+    )]
+    pub async fn fetch_events_by_event_types(
+        &self,
+        aggregate_type: &str,
+        event_types: &[&str],
+    ) -> Result<Vec<(E, String)>, EventStoreError> {
+        if event_types.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; event_types.len()].join(", ");
+        let sql = format!(
+            "SELECT event_id, payload, compressed, payload_zstd \
+             FROM events \
+             WHERE event_type IN ({placeholders}) AND aggregate_type = ? \
+             ORDER BY id"
+        );
+        let mut query = sqlx::query(&sql);
+        for event_type in event_types {
+            query = query.bind(*event_type);
+        }
+        let rows = query.bind(aggregate_type).fetch_all(&self.pool).await?;
+
+        let mut events = Vec::with_capacity(rows.len());
+        for row in rows {
+            let event_id: String = row.get("event_id");
+            let event: E = decode_payload(&row)?;
+            events.push((event, event_id));
+        }
+
+        tracing::Span::current().record("event_count", events.len());
+        tracing::debug!(event_count = events.len(), "fetched events by event types");
+        Ok(events)
+    }
+
     /// Fetch events by aggregate type and ID.
     ///
     /// This method provides direct access to events without requiring a command,
@@ -164,7 +266,7 @@ where
     ) -> Result<Vec<(E, String)>, EventStoreError> {
         let rows = sqlx::query(
             r#"
-            SELECT event_id, payload
+            SELECT event_id, payload, compressed, payload_zstd
             FROM events
             WHERE aggregate_type = ? AND aggregate_id = ?
             ORDER BY id
@@ -178,8 +280,7 @@ where
         let mut events = Vec::with_capacity(rows.len());
         for row in rows {
             let event_id: String = row.get("event_id");
-            let payload: String = row.get("payload");
-            let event: E = serde_json::from_str(&payload)?;
+            let event: E = decode_payload(&row)?;
             events.push((event, event_id));
         }
 
@@ -188,39 +289,68 @@ where
         Ok(events)
     }
 
+    /// Fetch the events of the listed aggregates of one aggregate type.
+    ///
+    /// Lets a reader that already knows which streams it needs load only
+    /// those, rather than every stream of the type.
+    ///
+    /// Returns events ordered by global sequence (id), with each event
+    /// paired with its event_id (version).
+    #[instrument(
+        name = "event_store.fetch_by_aggregates",
+        skip(self, aggregate_ids),
+        fields(
+            aggregate_type = %aggregate_type,
+            aggregate_count = aggregate_ids.len(),
+            event_count,
+        ),
+    )]
+    pub async fn fetch_events_by_aggregates(
+        &self,
+        aggregate_type: &str,
+        aggregate_ids: &[String],
+    ) -> Result<Vec<(E, String)>, EventStoreError> {
+        if aggregate_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; aggregate_ids.len()].join(", ");
+        let sql = format!(
+            "SELECT event_id, payload, compressed, payload_zstd \
+             FROM events \
+             WHERE aggregate_type = ? AND aggregate_id IN ({placeholders}) \
+             ORDER BY id"
+        );
+        let mut query = sqlx::query(&sql).bind(aggregate_type);
+        for aggregate_id in aggregate_ids {
+            query = query.bind(aggregate_id);
+        }
+        let rows = query.fetch_all(&self.pool).await?;
+
+        let mut events = Vec::with_capacity(rows.len());
+        for row in rows {
+            let event_id: String = row.get("event_id");
+            let event: E = decode_payload(&row)?;
+            events.push((event, event_id));
+        }
+
+        tracing::Span::current().record("event_count", events.len());
+        tracing::debug!(event_count = events.len(), "fetched events by aggregates");
+        Ok(events)
+    }
+
     /// Query all events across all aggregates, ordered by global sequence.
     ///
     /// Used for projection rebuild on application startup.
     #[instrument(name = "event_store.query_all", skip(self), fields(event_count))]
     pub async fn query_all(&self) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
-        let rows = sqlx::query(
-            r#"
-            SELECT id, event_id, aggregate_type, aggregate_id, event_type,
-                   schema_version, payload, command_id, final, created_at
-            FROM events
-            ORDER BY id
-            "#,
-        )
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = sqlx::query(&format!("{STORED_EVENT_SELECT} ORDER BY id"))
+            .fetch_all(&self.pool)
+            .await?;
 
-        let mut events = Vec::with_capacity(rows.len());
-        for row in rows {
-            let payload: String = row.get("payload");
-            let event: E = serde_json::from_str(&payload)?;
-            events.push(StoredEvent {
-                sequence: row.get("id"),
-                event_id: row.get("event_id"),
-                aggregate_type: row.get("aggregate_type"),
-                aggregate_id: row.get("aggregate_id"),
-                event_type: row.get("event_type"),
-                schema_version: row.get("schema_version"),
-                event,
-                command_id: row.get("command_id"),
-                is_final: row.get::<i64, _>("final") != 0,
-                created_at: row.get("created_at"),
-            });
-        }
+        let events = rows
+            .iter()
+            .map(stored_event)
+            .collect::<Result<Vec<_>, _>>()?;
 
         tracing::Span::current().record("event_count", events.len());
         tracing::debug!(
@@ -236,72 +366,125 @@ where
     #[instrument(
         name = "event_store.query_since",
         skip(self),
-        fields(since = since, event_count),
+        fields(since = %since, event_count),
     )]
     pub async fn query_since_sequence(
         &self,
-        since: i64,
+        since: GlobalSequence,
     ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
-        let rows = sqlx::query(
-            r#"
-            SELECT id, event_id, aggregate_type, aggregate_id, event_type,
-                   schema_version, payload, command_id, final, created_at
-            FROM events
-            WHERE id > ?
-            ORDER BY id
-            "#,
-        )
-        .bind(since)
+        let rows = sqlx::query(&format!("{STORED_EVENT_SELECT} WHERE id > ? ORDER BY id"))
+            .bind(since.to_sql())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let events = rows
+            .iter()
+            .map(stored_event)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        tracing::Span::current().record("event_count", events.len());
+        tracing::debug!(
+            event_count = events.len(),
+            since = %since,
+            "queried events since sequence for SSE reconnection"
+        );
+        Ok(events)
+    }
+
+    /// Query the events of one aggregate type since a global sequence
+    /// (exclusive).
+    ///
+    /// Used by catch-up subscriptions, which must not try to deserialize
+    /// other aggregates' events as `E`.
+    #[instrument(
+        name = "event_store.query_type_since",
+        skip(self),
+        fields(aggregate_type = %aggregate_type, since = %since, event_count),
+    )]
+    pub async fn query_type_since_sequence(
+        &self,
+        aggregate_type: &str,
+        since: GlobalSequence,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let rows = sqlx::query(&format!(
+            "{STORED_EVENT_SELECT} WHERE aggregate_type = ? AND id > ? ORDER BY id"
+        ))
+        .bind(aggregate_type)
+        .bind(since.to_sql())
         .fetch_all(&self.pool)
         .await?;
 
-        let mut events = Vec::with_capacity(rows.len());
-        for row in rows {
-            let payload: String = row.get("payload");
-            let event: E = serde_json::from_str(&payload)?;
-            events.push(StoredEvent {
-                sequence: row.get("id"),
-                event_id: row.get("event_id"),
-                aggregate_type: row.get("aggregate_type"),
-                aggregate_id: row.get("aggregate_id"),
-                event_type: row.get("event_type"),
-                schema_version: row.get("schema_version"),
-                event,
-                command_id: row.get("command_id"),
-                is_final: row.get::<i64, _>("final") != 0,
-                created_at: row.get("created_at"),
-            });
-        }
+        let events = rows
+            .iter()
+            .map(stored_event)
+            .collect::<Result<Vec<_>, _>>()?;
 
         tracing::Span::current().record("event_count", events.len());
         tracing::debug!(
             event_count = events.len(),
-            since = since,
-            "queried events since sequence for SSE reconnection"
+            "queried events of aggregate type since sequence"
         );
         Ok(events)
     }
 
+    /// Load the causal chain sharing `correlation_id`, ordered by global sequence.
+    ///
+    /// The event the chain started at is included even when it was saved
+    /// without a correlation id, since its children use its event id. A chain
+    /// can span aggregates, so every event in it must deserialize as `E`.
+    #[instrument(
+        name = "event_store.load_by_correlation",
+        skip(self),
+        fields(correlation_id = %correlation_id, event_count),
+    )]
+    pub async fn load_by_correlation(
+        &self,
+        correlation_id: &str,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let rows = sqlx::query(&format!(
+            "{STORED_EVENT_SELECT} \
+             WHERE json_extract(metadata, '$.correlation_id') = ? OR event_id = ? \
+             ORDER BY id"
+        ))
+        .bind(correlation_id)
+        .bind(correlation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let events = rows
+            .iter()
+            .map(stored_event)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        tracing::Span::current().record("event_count", events.len());
+        tracing::debug!(event_count = events.len(), "loaded events by correlation");
+        Ok(events)
+    }
+
     /// Get the earliest global sequence in the event store.
     ///
     /// Returns `None` if the event store is empty.
     #[instrument(name = "event_store.earliest_sequence", skip(self))]
-    pub async fn earliest_sequence(&self) -> Result<Option<i64>, EventStoreError> {
+    pub async fn earliest_sequence(&self) -> Result<Option<GlobalSequence>, EventStoreError> {
         let row = sqlx::query("SELECT MIN(id) as min_id FROM events")
             .fetch_one(&self.pool)
             .await?;
-        Ok(row.get("min_id"))
+        Ok(row
+            .try_get::<Option<u64>, _>("min_id")?
+            .map(GlobalSequence::new))
     }
 
     /// Get the latest global sequence in the event store.
     ///
     /// Returns `None` if the event store is empty.
     #[instrument(name = "event_store.latest_sequence", skip(self))]
-    pub async fn latest_sequence(&self) -> Result<Option<i64>, EventStoreError> {
+    pub async fn latest_sequence(&self) -> Result<Option<GlobalSequence>, EventStoreError> {
         let row = sqlx::query("SELECT MAX(id) as max_id FROM events")
             .fetch_one(&self.pool)
             .await?;
-        Ok(row.get("max_id"))
+        Ok(row
+            .try_get::<Option<u64>, _>("max_id")?
+            .map(GlobalSequence::new))
     }
 }
 
@@ -326,6 +509,8 @@ where
     /// If another transaction commits between our BEGIN IMMEDIATE and INSERT,
     /// the UNIQUE constraint on `previous_id` will fail. This is caught and
     /// translated to `OptimisticLockingConflict`.
+    ///
+    /// Each event is logged at debug level once the transaction commits.
     #[instrument(
         name = "event_store.append",
         skip(self, events, command_id),
@@ -335,6 +520,37 @@ where
         &self,
         events: &[E],
         command_id: Option<&str>,
+    ) -> Result<Vec<(E, String)>, EventStoreError> {
+        self.save_inner(events, command_id, None).await
+    }
+
+    /// Save events recording their correlation and causation ids.
+    ///
+    /// Behaves like `save_with_command()` otherwise. Handlers pass the request
+    /// id as the correlation id; sagas pass the triggering event's
+    /// [`StoredEvent::child_correlation`].
+    #[instrument(
+        name = "event_store.append_correlated",
+        skip(self, events, correlation),
+        fields(
+            event_count = events.len(),
+            correlation_id = %correlation.correlation_id,
+        ),
+    )]
+    pub async fn save_correlated(
+        &self,
+        events: &[E],
+        correlation: &Correlation,
+    ) -> Result<Vec<(E, String)>, EventStoreError> {
+        self.save_inner(events, None, Some(correlation)).await
+    }
+
+    /// Shared body of the save methods.
+    async fn save_inner(
+        &self,
+        events: &[E],
+        command_id: Option<&str>,
+        correlation: Option<&Correlation>,
     ) -> Result<Vec<(E, String)>, EventStoreError> {
         if events.is_empty() {
             return Ok(Vec::new());
@@ -344,84 +560,322 @@ where
         let mut tx = self.pool.begin().await?;
 
         let mut results = Vec::with_capacity(events.len());
+        let mut sequences = Vec::with_capacity(events.len());
 
         for event in events {
-            let event_id = Uuid::new_v4().to_string();
-            let aggregate_id = event.identifier();
-            let aggregate_type = event.decider_type();
-            let event_type = event.event_type();
-            let is_final = if event.is_final() { 1_i64 } else { 0_i64 };
-            let payload = serde_json::to_string(event)?;
-
-            // Fetch latest version within transaction
-            let previous_id: Option<String> = sqlx::query_scalar(
-                r#"
-                SELECT event_id
-                FROM events
-                WHERE aggregate_type = ? AND aggregate_id = ?
-                ORDER BY id DESC
-                LIMIT 1
-                "#,
-            )
-            .bind(&aggregate_type)
-            .bind(&aggregate_id)
-            .fetch_optional(&mut *tx)
-            .await?;
+            let inserted = self
+                .insert_event(&mut tx, event, command_id, correlation)
+                .await?;
+            sequences.push(inserted.sequence);
+            results.push((event.clone(), inserted.event_id));
+        }
+
+        tx.commit().await?;
+
+        for ((event, event_id), sequence) in results.iter().zip(sequences) {
+            log_persisted(event, event_id, sequence);
+        }
+        record_persisted(results.iter().map(|(event, _)| event));
+
+        tracing::debug!(
+            event_count = results.len(),
+            "persisted events to event store"
+        );
+        Ok(results)
+    }
+
+    /// Append a batch of events to a single stream in one transaction.
+    ///
+    /// Every event must belong to `stream_id`. Events are chained and assigned
+    /// contiguous global sequences in order; if any insert fails the whole
+    /// batch is rolled back and nothing is persisted. Prefer this over
+    /// repeated single-event saves for commands that emit several events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an event targets a different stream, if
+    /// serialization fails, or if any insert fails (including optimistic
+    /// locking conflicts).
+    #[instrument(
+        name = "event_store.append_batch",
+        skip(self, events),
+        fields(stream_id = %stream_id, event_count = events.len()),
+    )]
+    pub async fn append_batch(
+        &self,
+        stream_id: &str,
+        events: Vec<E>,
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        if let Some(stray) = events.iter().find(|e| e.identifier() != stream_id) {
+            return Err(EventStoreError::database(format!(
+                "event for stream {} cannot be appended to stream {stream_id}",
+                stray.identifier()
+            )));
+        }
+        if events.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Dropping the transaction on an early return rolls back every insert
+        let mut tx = self.pool.begin().await?;
+
+        let mut stored = Vec::with_capacity(events.len());
+        for event in events {
+            let inserted = self.insert_event(&mut tx, &event, None, None).await?;
+            stored.push(inserted.into_stored(event));
+        }
+
+        tx.commit().await?;
+
+        for s in &stored {
+            log_persisted(&s.event, &s.event_id, s.sequence);
+        }
+        record_persisted(stored.iter().map(|s| &s.event));
+
+        tracing::debug!(
+            event_count = stored.len(),
+            "appended event batch to event store"
+        );
+        Ok(stored)
+    }
+
+    /// Append events inside a transaction the caller owns.
+    ///
+    /// Repositories over the same pool can share one transaction this way,
+    /// so events of several aggregates, even of different types, commit or
+    /// roll back together. Events are chained as in `save_with_command()`.
+    /// Nothing is logged or counted here; once the transaction commits, pass
+    /// the result to [`Self::record_committed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or any insert fails
+    /// (including optimistic locking conflicts). The caller should then
+    /// drop the transaction to roll back.
+    #[instrument(
+        name = "event_store.append_in_transaction",
+        skip(self, tx, events),
+        fields(event_count = events.len()),
+    )]
+    pub async fn append_in_transaction(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        events: &[E],
+    ) -> Result<Vec<StoredEvent<E>>, EventStoreError> {
+        let mut stored = Vec::with_capacity(events.len());
+        for event in events {
+            let inserted = self.insert_event(tx, event, None, None).await?;
+            stored.push(inserted.into_stored(event.clone()));
+        }
+        Ok(stored)
+    }
+
+    /// Log and count events appended with [`Self::append_in_transaction`]
+    /// once their transaction has committed.
+    pub fn record_committed(&self, stored: &[StoredEvent<E>]) {
+        for s in stored {
+            log_persisted(&s.event, &s.event_id, s.sequence);
+        }
+        record_persisted(stored.iter().map(|s| &s.event));
+    }
+
+    /// Insert one event inside an open transaction, chaining it to the
+    /// aggregate's latest event.
+    async fn insert_event(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+        event: &E,
+        command_id: Option<&str>,
+        correlation: Option<&Correlation>,
+    ) -> Result<InsertedEvent, EventStoreError> {
+        let event_id = Uuid::new_v4().to_string();
+        let aggregate_id = event.identifier();
+        let aggregate_type = event.decider_type();
+        let event_type = event.event_type();
+        let is_final = if event.is_final() { 1_i64 } else { 0_i64 };
+        let json = serde_json::to_string(event)?;
+        let payload = match &self.compression {
+            Some(compression) => compression.encode(json)?,
+            None => EncodedPayload::plain(json),
+        };
+
+        // Fetch the stream's latest event within the transaction
+        let latest: Option<(String, u64)> = sqlx::query_as(
+            r#"
+            SELECT event_id, stream_version
+            FROM events
+            WHERE aggregate_type = ? AND aggregate_id = ?
+            ORDER BY stream_version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(&aggregate_type)
+        .bind(&aggregate_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+        let (previous_id, stream_version) = match latest {
+            Some((event_id, version)) => (Some(event_id), StreamVersion::new(version).next()),
+            None => (None, StreamVersion::ZERO.next()),
+        };
 
-            let result = sqlx::query(
+        let result =
+            sqlx::query(
                 r#"
-                INSERT INTO events (
-                    event_id, aggregate_type, aggregate_id, previous_id,
-                    event_type, payload, command_id, final
-                )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
-                "#,
+            INSERT INTO events (
+                event_id, aggregate_type, aggregate_id, previous_id,
+                event_type, payload, compressed, payload_zstd, command_id,
+                metadata, final, stream_version
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING id, schema_version, created_at
+            "#,
             )
             .bind(&event_id)
             .bind(&aggregate_type)
             .bind(&aggregate_id)
             .bind(&previous_id)
             .bind(&event_type)
-            .bind(&payload)
+            .bind(&payload.payload)
+            .bind(i64::from(payload.compressed))
+            .bind(&payload.payload_zstd)
             .bind(command_id)
+            .bind(correlation.map(Correlation::to_metadata))
             .bind(is_final)
-            .execute(&mut *tx)
+            .bind(i64::try_from(stream_version.get()).map_err(|_| {
+                EventStoreError::database(format!("stream {aggregate_id} is too long"))
+            })?)
+            .fetch_one(&mut **tx)
             .await;
 
-            // Translate UNIQUE constraint violation on previous_id to OptimisticLockingConflict
-            if let Err(sqlx::Error::Database(db_err)) = &result
-                && db_err.message().contains("UNIQUE constraint failed")
-                && db_err.message().contains("previous_id")
-            {
-                return Err(EventStoreError::optimistic_locking_conflict(
-                    &aggregate_type,
-                    &aggregate_id,
-                ));
-            }
-
-            result?;
-            results.push((event.clone(), event_id));
+        // Translate UNIQUE constraint violation on previous_id or
+        // stream_version to OptimisticLockingConflict
+        if let Err(sqlx::Error::Database(db_err)) = &result
+            && db_err.message().contains("UNIQUE constraint failed")
+            && (db_err.message().contains("previous_id")
+                || db_err.message().contains("stream_version"))
+        {
+            return Err(EventStoreError::optimistic_locking_conflict(
+                &aggregate_type,
+                &aggregate_id,
+            ));
         }
 
-        tx.commit().await?;
+        let row = result?;
+        Ok(InsertedEvent {
+            event_id,
+            sequence: GlobalSequence::new(row.try_get("id")?),
+            stream_version,
+            schema_version: row.get("schema_version"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
 
-        // Emit metrics for each distinct aggregate type in the batch.
-        // Typical batches contain events for a single aggregate, but the loop
-        // handles mixed batches correctly.
-        let mut counted: std::collections::HashMap<&str, u64> = std::collections::HashMap::new();
-        for (event, _version) in &results {
-            *counted.entry(leak_str(&event.decider_type())).or_default() += 1;
-        }
-        for (agg_type, count) in &counted {
-            metrics::counter!("events_persisted_total", "aggregate_type" => *agg_type)
-                .increment(*count);
+/// Columns of a [`StoredEvent`].
+const STORED_EVENT_SELECT: &str = r#"
+    SELECT id, event_id, aggregate_type, aggregate_id, event_type,
+           schema_version, payload, compressed, payload_zstd,
+           command_id, json_extract(metadata, '$.correlation_id') AS correlation_id,
+           json_extract(metadata, '$.caused_by') AS causation_id, final, created_at,
+           stream_version
+    FROM events
+"#;
+
+/// Build a [`StoredEvent`] from a row selected with [`STORED_EVENT_SELECT`].
+fn stored_event<E: DeserializeOwned>(row: &SqliteRow) -> Result<StoredEvent<E>, EventStoreError> {
+    Ok(StoredEvent {
+        sequence: GlobalSequence::new(row.try_get("id")?),
+        stream_version: StreamVersion::new(row.try_get("stream_version")?),
+        event_id: row.get("event_id"),
+        aggregate_type: row.get("aggregate_type"),
+        aggregate_id: row.get("aggregate_id"),
+        event_type: row.get("event_type"),
+        schema_version: row.get("schema_version"),
+        event: decode_payload(row)?,
+        command_id: row.get("command_id"),
+        correlation_id: row.get("correlation_id"),
+        causation_id: row.get("causation_id"),
+        is_final: row.get::<i64, _>("final") != 0,
+        created_at: row.get("created_at"),
+    })
+}
+
+/// Deserialize the event in a row selected with `payload, compressed, payload_zstd`.
+fn decode_payload<E: DeserializeOwned>(row: &SqliteRow) -> Result<E, EventStoreError> {
+    let json = EncodedPayload {
+        payload: row.get("payload"),
+        compressed: row.get::<i64, _>("compressed") != 0,
+        payload_zstd: row.get("payload_zstd"),
+    }
+    .decode()?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Columns assigned by the database when an event row is inserted.
+struct InsertedEvent {
+    event_id: String,
+    sequence: GlobalSequence,
+    stream_version: StreamVersion,
+    schema_version: i64,
+    created_at: String,
+}
+
+impl InsertedEvent {
+    /// The stored form of `event`, inserted without a command or
+    /// correlation id.
+    fn into_stored<E: Identifier + EventType + DeciderType + IsFinal>(
+        self,
+        event: E,
+    ) -> StoredEvent<E> {
+        StoredEvent {
+            sequence: self.sequence,
+            stream_version: self.stream_version,
+            event_id: self.event_id,
+            aggregate_type: event.decider_type(),
+            aggregate_id: event.identifier(),
+            event_type: event.event_type(),
+            schema_version: self.schema_version,
+            is_final: event.is_final(),
+            event,
+            command_id: None,
+            correlation_id: None,
+            causation_id: None,
+            created_at: self.created_at,
         }
+    }
+}
 
-        tracing::debug!(
-            event_count = results.len(),
-            "persisted events to event store"
-        );
-        Ok(results)
+/// Log one committed event at debug level.
+///
+/// Only identifying metadata is logged. Payloads can hold user input and UI
+/// state, so they are never written to logs.
+fn log_persisted<E: Identifier + EventType + DeciderType>(
+    event: &E,
+    event_id: &str,
+    sequence: GlobalSequence,
+) {
+    tracing::debug!(
+        aggregate_type = %event.decider_type(),
+        aggregate_id = %event.identifier(),
+        event_type = %event.event_type(),
+        event_id,
+        %sequence,
+        "persisted event"
+    );
+}
+
+/// Emit `events_persisted_total` for each distinct aggregate type.
+///
+/// Typical batches contain events for a single aggregate, but mixed batches
+/// are counted correctly.
+fn record_persisted<'a, E: DeciderType + 'a>(events: impl Iterator<Item = &'a E>) {
+    let mut counted: std::collections::HashMap<&str, u64> = std::collections::HashMap::new();
+    for event in events {
+        *counted.entry(leak_str(&event.decider_type())).or_default() += 1;
+    }
+    for (agg_type, count) in &counted {
+        metrics::counter!("events_persisted_total", "aggregate_type" => *agg_type)
+            .increment(*count);
     }
 }
 
@@ -482,7 +936,7 @@ where
 
         let rows = sqlx::query(
             r#"
-            SELECT event_id, payload
+            SELECT event_id, payload, compressed, payload_zstd
             FROM events
             WHERE aggregate_type = ? AND aggregate_id = ?
             ORDER BY id
@@ -496,8 +950,7 @@ where
         let mut events = Vec::with_capacity(rows.len());
         for row in rows {
             let event_id: String = row.get("event_id");
-            let payload: String = row.get("payload");
-            let event: E = serde_json::from_str(&payload)?;
+            let event: E = decode_payload(&row)?;
             events.push((event, event_id));
         }
 
@@ -557,11 +1010,14 @@ where
     }
 }
 
-/// SQL migration for the events table.
+/// SQL creating the current events schema, including the compression and
+/// correlation columns.
 ///
-/// Embedded directly so tests can create the schema without depending on
-/// the monolith's migrations directory.
-pub const EVENTS_MIGRATION_SQL: &str = include_str!("events_migration.sql");
+/// Every statement is guarded, so running it against a database that
+/// already has the schema is a no-op. Embedded directly so tests can create
+/// the schema without depending on the monolith's migrations directory,
+/// which upgrades existing databases one step at a time.
+pub const EVENTS_MIGRATION_SQL: &str = include_str!("events_schema.sql");
 
 #[cfg(test)]
 #[allow(clippy::expect_used, clippy::panic)]
@@ -634,6 +1090,127 @@ mod tests {
         pool
     }
 
+    /// Columns, indexes, and triggers of the `events` table.
+    async fn events_schema(pool: &SqlitePool) -> Vec<String> {
+        let mut schema: Vec<String> = sqlx::query_as::<_, (String, String, i64, Option<String>)>(
+            "SELECT name, type, \"notnull\", dflt_value FROM pragma_table_info('events')",
+        )
+        .fetch_all(pool)
+        .await
+        .expect("table info")
+        .into_iter()
+        .map(|column| format!("{column:?}"))
+        .collect();
+        schema.extend(
+            sqlx::query_scalar::<_, String>(
+                "SELECT name FROM sqlite_master \
+                 WHERE tbl_name = 'events' AND type IN ('index', 'trigger') ORDER BY name",
+            )
+            .fetch_all(pool)
+            .await
+            .expect("schema objects"),
+        );
+        schema
+    }
+
+    #[tokio::test]
+    async fn migration_sql_is_idempotent() {
+        let pool = create_test_pool().await;
+
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("second run should be a no-op");
+    }
+
+    #[tokio::test]
+    async fn migration_sql_matches_incremental_migrations() {
+        let incremental = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool");
+        for step in [
+            include_str!("events_migration.sql"),
+            crate::compression::EVENTS_COMPRESSION_MIGRATION_SQL,
+            crate::sequence::EVENTS_STREAM_VERSION_MIGRATION_SQL,
+        ] {
+            sqlx::query(step)
+                .execute(&incremental)
+                .await
+                .expect("migration step");
+        }
+
+        assert_eq!(
+            events_schema(&create_test_pool().await).await,
+            events_schema(&incremental).await
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_version_migration_backfills_existing_events() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("pool");
+        for step in [
+            include_str!("events_migration.sql"),
+            crate::compression::EVENTS_COMPRESSION_MIGRATION_SQL,
+        ] {
+            sqlx::query(step)
+                .execute(&pool)
+                .await
+                .expect("migration step");
+        }
+        let mut previous: Option<String> = None;
+        for stream in ["agg-1", "agg-2", "agg-1"] {
+            let event_id = Uuid::new_v4().to_string();
+            let previous_id = if stream == "agg-1" {
+                previous.replace(event_id.clone())
+            } else {
+                None
+            };
+            sqlx::query(
+                "INSERT INTO events (event_id, aggregate_type, aggregate_id, previous_id, \
+                 event_type, payload) VALUES (?, 'Test', ?, ?, 'TestEvent', ?)",
+            )
+            .bind(&event_id)
+            .bind(stream)
+            .bind(previous_id)
+            .bind(format!(r#"{{"id":"{stream}","data":"old"}}"#))
+            .execute(&pool)
+            .await
+            .expect("insert old event");
+        }
+
+        sqlx::query(crate::sequence::EVENTS_STREAM_VERSION_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("stream version migration");
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        repo.save(&batch_events("agg-1", &["new"]))
+            .await
+            .expect("save after migration");
+
+        let versions: Vec<(String, u64)> = repo
+            .query_all()
+            .await
+            .expect("query all")
+            .into_iter()
+            .map(|s| (s.aggregate_id, s.stream_version.get()))
+            .collect();
+        assert_eq!(
+            versions,
+            [
+                ("agg-1".to_string(), 1),
+                ("agg-2".to_string(), 1),
+                ("agg-1".to_string(), 2),
+                ("agg-1".to_string(), 3),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_save_and_fetch_events() {
         let pool = create_test_pool().await;
@@ -658,6 +1235,91 @@ mod tests {
         assert_eq!(fetched[0].0, event);
     }
 
+    #[tokio::test]
+    async fn fetch_by_event_types_returns_only_listed_types() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        let event = TestEvent {
+            id: "agg-1".to_string(),
+            data: "test data".to_string(),
+        };
+        repo.save(std::slice::from_ref(&event)).await.unwrap();
+
+        let listed = repo
+            .fetch_events_by_event_types("Test", &["Other", "TestEvent"])
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].0, event);
+
+        for event_types in [&["Other"][..], &[]] {
+            let unlisted = repo
+                .fetch_events_by_event_types("Test", event_types)
+                .await
+                .unwrap();
+            assert!(unlisted.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_by_aggregates_returns_only_listed_streams() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        for stream in ["agg-1", "agg-2", "agg-3"] {
+            repo.save(&batch_events(stream, &["a"])).await.unwrap();
+        }
+
+        let listed = repo
+            .fetch_events_by_aggregates("Test", &["agg-3".to_string(), "agg-1".to_string()])
+            .await
+            .unwrap();
+        let streams: Vec<&str> = listed.iter().map(|(e, _)| e.id.as_str()).collect();
+        assert_eq!(streams, ["agg-1", "agg-3"]);
+
+        assert!(
+            repo.fetch_events_by_aggregates("Test", &[])
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn each_persisted_event_is_logged_without_payload() {
+        let capture = ironstar_core::LogCapture::default();
+        let _guard = capture.set_default();
+
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        let events: Vec<TestEvent> = ["first", "second"]
+            .into_iter()
+            .map(|data| TestEvent {
+                id: "agg-1".to_string(),
+                data: data.to_string(),
+            })
+            .collect();
+
+        repo.save(&events[..1]).await.unwrap();
+        let stored = repo
+            .append_batch("agg-1", events[1..].to_vec())
+            .await
+            .unwrap();
+
+        let lines = capture.lines_with_message("persisted event");
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert_eq!(line.field("aggregate_type"), Some("Test"));
+            assert_eq!(line.field("aggregate_id"), Some("agg-1"));
+            assert_eq!(line.field("event_type"), Some("TestEvent"));
+            assert!(line.field("sequence").is_some());
+            assert!(!format!("{line:?}").contains("first"));
+        }
+        assert_eq!(
+            lines[1].field("sequence"),
+            Some(stored[0].sequence.to_string().as_str())
+        );
+    }
+
     #[tokio::test]
     async fn test_previous_id_chain() {
         let pool = create_test_pool().await;
@@ -720,12 +1382,75 @@ mod tests {
         // Query all
         let all_events = repo.query_all().await.unwrap();
         assert_eq!(all_events.len(), 3);
+        let versions: Vec<u64> = all_events.iter().map(|s| s.stream_version.get()).collect();
+        assert_eq!(versions, vec![1, 1, 2]);
 
         // Query since sequence 1 (should get events 2 and 3)
-        let since_events = repo.query_since_sequence(1).await.unwrap();
+        let since_events = repo
+            .query_since_sequence(GlobalSequence::new(1))
+            .await
+            .unwrap();
         assert_eq!(since_events.len(), 2);
-        assert_eq!(since_events[0].sequence, 2);
-        assert_eq!(since_events[1].sequence, 3);
+        assert_eq!(since_events[0].sequence, GlobalSequence::new(2));
+        assert_eq!(since_events[1].sequence, GlobalSequence::new(3));
+        // Stream versions count from the start of each stream, not the cursor
+        assert_eq!(since_events[1].stream_version, StreamVersion::new(2));
+    }
+
+    #[tokio::test]
+    async fn test_load_by_correlation_follows_causal_chain() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        let event = |id: &str, data: &str| TestEvent {
+            id: id.to_string(),
+            data: data.to_string(),
+        };
+
+        repo.save_correlated(&[event("agg-1", "parent")], &Correlation::new("request-1"))
+            .await
+            .unwrap();
+        repo.save(&[event("agg-2", "unrelated")]).await.unwrap();
+
+        let parent = repo.load_by_correlation("request-1").await.unwrap();
+        assert_eq!(parent.len(), 1);
+        assert_eq!(parent[0].correlation_id.as_deref(), Some("request-1"));
+        assert_eq!(parent[0].causation_id, None);
+
+        let child_correlation = parent[0].child_correlation();
+        repo.save_correlated(&[event("agg-3", "child")], &child_correlation)
+            .await
+            .unwrap();
+
+        let chain = repo.load_by_correlation("request-1").await.unwrap();
+        let data: Vec<&str> = chain.iter().map(|s| s.event.data.as_str()).collect();
+        assert_eq!(data, vec!["parent", "child"]);
+        assert_eq!(chain[1].correlation_id.as_deref(), Some("request-1"));
+        assert_eq!(
+            chain[1].causation_id.as_deref(),
+            Some(parent[0].event_id.as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_uncorrelated_event_roots_its_children_chain() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        let event = |id: &str, data: &str| TestEvent {
+            id: id.to_string(),
+            data: data.to_string(),
+        };
+
+        repo.save(&[event("agg-1", "root")]).await.unwrap();
+        let root = repo.query_all().await.unwrap().remove(0);
+        assert_eq!(root.correlation_id, None);
+
+        repo.save_correlated(&[event("agg-2", "child")], &root.child_correlation())
+            .await
+            .unwrap();
+
+        let chain = repo.load_by_correlation(&root.event_id).await.unwrap();
+        let data: Vec<&str> = chain.iter().map(|s| s.event.data.as_str()).collect();
+        assert_eq!(data, vec!["root", "child"]);
     }
 
     #[tokio::test]
@@ -744,8 +1469,9 @@ mod tests {
         };
         repo.save(&[event]).await.unwrap();
 
-        assert_eq!(repo.earliest_sequence().await.unwrap(), Some(1));
-        assert_eq!(repo.latest_sequence().await.unwrap(), Some(1));
+        let first = Some(GlobalSequence::new(1));
+        assert_eq!(repo.earliest_sequence().await.unwrap(), first);
+        assert_eq!(repo.latest_sequence().await.unwrap(), first);
     }
 
     // Test helper: event that marks aggregate as finalized
@@ -874,4 +1600,173 @@ mod tests {
             _ => panic!("Expected OptimisticLockingConflict variant"),
         }
     }
+
+    fn batch_events(stream: &str, data: &[&str]) -> Vec<TestEvent> {
+        data.iter()
+            .map(|d| TestEvent {
+                id: stream.to_string(),
+                data: (*d).to_string(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn stored_aggregate_id_parses_into_components() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        let instance = Uuid::new_v4();
+        let event = TestEvent {
+            id: format!("workspace_{instance}/preferences"),
+            data: "prefs".to_string(),
+        };
+
+        repo.save(&[event]).await.expect("save should succeed");
+        let stored = repo.query_all().await.expect("query should succeed");
+
+        let id = stored[0]
+            .parsed_aggregate_id()
+            .expect("conventional stream id");
+        assert_eq!(id.aggregate_type(), Some("workspace"));
+        assert_eq!(id.instance_id(), instance);
+        assert_eq!(id.sub_path(), Some("preferences"));
+    }
+
+    #[tokio::test]
+    async fn test_append_batch_assigns_contiguous_sequences() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+
+        repo.save(&batch_events("agg-1", &["existing"]))
+            .await
+            .unwrap();
+        let events = batch_events("agg-1", &["a", "b", "c", "d", "e"]);
+
+        let stored = repo.append_batch("agg-1", events.clone()).await.unwrap();
+
+        assert_eq!(stored.len(), 5);
+        for pair in stored.windows(2) {
+            assert_eq!(pair[1].sequence.get(), pair[0].sequence.get() + 1);
+        }
+        let versions: Vec<u64> = stored.iter().map(|s| s.stream_version.get()).collect();
+        assert_eq!(versions, vec![2, 3, 4, 5, 6]);
+        let appended: Vec<TestEvent> = stored.iter().map(|s| s.event.clone()).collect();
+        assert_eq!(appended, events);
+        assert!(stored.iter().all(|s| s.aggregate_id == "agg-1"));
+
+        let fetched = repo
+            .fetch_events_by_aggregate("Test", "agg-1")
+            .await
+            .unwrap();
+        assert_eq!(fetched.len(), 6);
+        let ids: Vec<&str> = fetched[1..].iter().map(|(_, id)| id.as_str()).collect();
+        let stored_ids: Vec<&str> = stored.iter().map(|s| s.event_id.as_str()).collect();
+        assert_eq!(ids, stored_ids);
+    }
+
+    #[tokio::test]
+    async fn test_append_batch_rolls_back_on_failure() {
+        let pool = create_test_pool().await;
+        sqlx::query(
+            r#"
+            CREATE TRIGGER fail_on_boom BEFORE INSERT ON events
+            WHEN json_extract(NEW.payload, '$.data') = 'boom'
+            BEGIN
+                SELECT RAISE(ABORT, 'forced failure');
+            END;
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+
+        let result = repo
+            .append_batch(
+                "agg-1",
+                batch_events("agg-1", &["a", "b", "boom", "d", "e"]),
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(repo.latest_sequence().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_append_batch_rejects_foreign_stream() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> = SqliteEventRepository::new(pool);
+        let mut events = batch_events("agg-1", &["a"]);
+        events.extend(batch_events("agg-2", &["b"]));
+
+        let result = repo.append_batch("agg-1", events).await;
+
+        assert!(result.is_err());
+        assert_eq!(repo.latest_sequence().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn append_in_transaction_commits_or_rolls_back_with_the_caller() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> =
+            SqliteEventRepository::new(pool.clone());
+
+        let mut tx = pool.begin().await.unwrap();
+        repo.append_in_transaction(&mut tx, &batch_events("agg-1", &["a"]))
+            .await
+            .unwrap();
+        drop(tx);
+        assert_eq!(repo.latest_sequence().await.unwrap(), None);
+
+        let mut tx = pool.begin().await.unwrap();
+        let mut stored = repo
+            .append_in_transaction(&mut tx, &batch_events("agg-1", &["a", "b"]))
+            .await
+            .unwrap();
+        stored.extend(
+            repo.append_in_transaction(&mut tx, &batch_events("agg-2", &["c"]))
+                .await
+                .unwrap(),
+        );
+        tx.commit().await.unwrap();
+        repo.record_committed(&stored);
+
+        let versions: Vec<u64> = stored.iter().map(|s| s.stream_version.get()).collect();
+        assert_eq!(versions, [1, 2, 1]);
+        assert_eq!(repo.query_all().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_large_payload_round_trips_through_compression() {
+        let pool = create_test_pool().await;
+        let repo: SqliteEventRepository<TestCommand, TestEvent> =
+            SqliteEventRepository::new(pool.clone())
+                .with_compression(PayloadCompression::with_threshold(1024));
+        let large = TestEvent {
+            id: "agg-1".to_string(),
+            data: r#"{"panels":[1,2,3]}"#.repeat(2_000),
+        };
+        let small = TestEvent {
+            id: "agg-1".to_string(),
+            data: "small".to_string(),
+        };
+
+        repo.save(&[large.clone(), small.clone()]).await.unwrap();
+
+        let flags: Vec<i64> = sqlx::query_scalar("SELECT compressed FROM events ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(flags, vec![1, 0]);
+
+        // Decoding is driven by the row flag, not the reader's configuration
+        let plain_reader: SqliteEventRepository<TestCommand, TestEvent> =
+            SqliteEventRepository::new(pool);
+        let stored = plain_reader.query_all().await.unwrap();
+        assert_eq!(stored.len(), 2);
+        assert_eq!(
+            serde_json::to_vec(&stored[0].event).unwrap(),
+            serde_json::to_vec(&large).unwrap()
+        );
+        assert_eq!(stored[1].event, small);
+    }
 }
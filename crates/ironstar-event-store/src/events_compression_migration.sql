@@ -0,0 +1,7 @@
+-- Optional zstd compression of large event payloads.
+-- A compressed event stores its JSON in payload_zstd and the JSON literal 'null'
+-- in payload (which must remain valid JSON); compressed = 1 marks the row so
+-- readers know which column to decode.
+
+ALTER TABLE events ADD COLUMN compressed INTEGER NOT NULL DEFAULT 0 CHECK(compressed IN (0, 1));
+ALTER TABLE events ADD COLUMN payload_zstd BLOB;
@@ -4,13 +4,25 @@
 //! fmodel-rust's `EventRepository` trait, plus SSE stream utilities for composing
 //! historical replay with live Zenoh subscription streams.
 
+pub mod checkpoint;
+pub mod compression;
+pub mod correlation;
 pub mod error;
 pub mod event_store;
+pub mod sequence;
 pub mod sse_stream;
 
+pub use checkpoint::{CHECKPOINTS_MIGRATION_SQL, SqliteCheckpointStore};
+pub use compression::{
+    DEFAULT_COMPRESSION_LEVEL, DEFAULT_COMPRESSION_THRESHOLD, EVENTS_COMPRESSION_MIGRATION_SQL,
+    PayloadCompression,
+};
+pub use correlation::Correlation;
 pub use error::{EventStoreError, EventStoreErrorKind};
 pub use event_store::{EVENTS_MIGRATION_SQL, SqliteEventRepository, StoredEvent};
+pub use sequence::{EVENTS_STREAM_VERSION_MIGRATION_SQL, GlobalSequence, StreamVersion};
 pub use sse_stream::{
-    DEFAULT_KEEP_ALIVE_SECS, KEEP_ALIVE_COMMENT, KeepAliveStream, SseStreamBuilder,
+    DEFAULT_BUFFER_CAPACITY, DEFAULT_KEEP_ALIVE_SECS, KEEP_ALIVE_COMMENT, KeepAliveStream,
+    RESYNC_EVENT, SSE_CONNECTION_EVENTS, SSE_CONNECTIONS, SseStreamBuilder, bounded_live_stream,
     event_with_sequence, stored_events_to_stream, zenoh_to_sse_stream,
 };
@@ -0,0 +1,184 @@
+//! Typed positions in the event log.
+//!
+//! An event has two positions: its [`GlobalSequence`] across the whole store,
+//! which orders SSE replay and projection checkpoints, and its
+//! [`StreamVersion`] within its own aggregate stream. Both are plain counters
+//! in the database, and mixing them up (resuming a feed from a stream version,
+//! say) silently skips or repeats events. Separate newtypes make that a type
+//! error. Neither converts into the other; both serialize as bare numbers.
+//!
+//! # Schema
+//!
+//! See `EVENTS_STREAM_VERSION_MIGRATION_SQL`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// SQL adding the persisted `stream_version` column to `events`, backfilled
+/// from each stream's event order.
+pub const EVENTS_STREAM_VERSION_MIGRATION_SQL: &str =
+    include_str!("events_stream_version_migration.sql");
+
+/// Position of an event in the store-wide log (the `events.id` column).
+///
+/// Sequences start at 1 and increase across all aggregates. [`ZERO`] sits
+/// before the first event, so querying since it returns the whole log.
+///
+/// [`ZERO`]: GlobalSequence::ZERO
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct GlobalSequence(u64);
+
+impl GlobalSequence {
+    /// The position before the first event.
+    pub const ZERO: Self = Self(0);
+
+    /// Wrap a raw sequence number.
+    #[must_use]
+    pub const fn new(sequence: u64) -> Self {
+        Self(sequence)
+    }
+
+    /// The raw sequence number.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// The value bound to SQLite, which stores integers as `i64`.
+    ///
+    /// Positions past `i64::MAX` lie beyond any stored event, so they clamp.
+    pub(crate) fn to_sql(self) -> i64 {
+        i64::try_from(self.0).unwrap_or(i64::MAX)
+    }
+}
+
+impl fmt::Display for GlobalSequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Parses the decimal form used for SSE `Last-Event-ID`.
+impl FromStr for GlobalSequence {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+/// Position of an event within its aggregate stream.
+///
+/// The first event of a stream is version 1; [`ZERO`] is the version of a
+/// stream with no events.
+///
+/// [`ZERO`]: StreamVersion::ZERO
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct StreamVersion(u64);
+
+impl StreamVersion {
+    /// The version of an empty stream.
+    pub const ZERO: Self = Self(0);
+
+    /// Wrap a raw version number.
+    #[must_use]
+    pub const fn new(version: u64) -> Self {
+        Self(version)
+    }
+
+    /// The raw version number.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// The version of the event appended after this one.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self(self.0.saturating_add(1))
+    }
+}
+
+impl fmt::Display for StreamVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::marker::PhantomData;
+
+    /// Reports whether `To: From<From>` holds, resolved at compile time.
+    ///
+    /// The inherent constant applies only when the bound holds; otherwise
+    /// the blanket trait's default is used.
+    struct Converts<To, From>(PhantomData<(To, From)>);
+
+    trait NoConversion {
+        const CONVERTS: bool = false;
+    }
+
+    impl<T> NoConversion for T {}
+
+    impl<To: std::convert::From<From>, From> Converts<To, From> {
+        const CONVERTS: bool = true;
+    }
+
+    #[test]
+    fn sequences_serialize_as_bare_numbers() {
+        assert_eq!(
+            serde_json::to_string(&GlobalSequence::new(42)).unwrap(),
+            "42"
+        );
+        assert_eq!(serde_json::to_string(&StreamVersion::new(7)).unwrap(), "7");
+        assert_eq!(
+            serde_json::from_str::<GlobalSequence>("42").unwrap(),
+            GlobalSequence::new(42)
+        );
+        assert_eq!(
+            serde_json::from_str::<StreamVersion>("7").unwrap(),
+            StreamVersion::new(7)
+        );
+    }
+
+    #[test]
+    fn sequences_do_not_convert_into_each_other() {
+        // Evaluated during compilation, so adding such a conversion breaks the build.
+        const {
+            assert!(Converts::<u64, u64>::CONVERTS, "probe detects conversions");
+            assert!(!Converts::<GlobalSequence, StreamVersion>::CONVERTS);
+            assert!(!Converts::<StreamVersion, GlobalSequence>::CONVERTS);
+            assert!(!Converts::<GlobalSequence, u64>::CONVERTS);
+            assert!(!Converts::<StreamVersion, u64>::CONVERTS);
+        }
+    }
+
+    #[test]
+    fn global_sequence_parses_last_event_id() {
+        assert_eq!("17".parse(), Ok(GlobalSequence::new(17)));
+        assert!("-1".parse::<GlobalSequence>().is_err());
+        assert!("abc".parse::<GlobalSequence>().is_err());
+    }
+
+    #[test]
+    fn global_sequence_clamps_when_bound() {
+        assert_eq!(GlobalSequence::new(5).to_sql(), 5);
+        assert_eq!(GlobalSequence::new(u64::MAX).to_sql(), i64::MAX);
+    }
+
+    #[test]
+    fn stream_version_advances() {
+        assert_eq!(StreamVersion::ZERO.next(), StreamVersion::new(1));
+        assert_eq!(StreamVersion::new(u64::MAX).next().get(), u64::MAX);
+    }
+}
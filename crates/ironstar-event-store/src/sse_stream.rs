@@ -15,6 +15,21 @@
 //!
 //! This is standard SSE and ignored by clients, but keeps the connection alive.
 //!
+//! # Backpressure
+//!
+//! Live events pass through a bounded per-connection buffer (see
+//! [`bounded_live_stream`]). When a slow client lets the buffer fill, the
+//! oldest events are dropped and the client receives a [`RESYNC_EVENT`]
+//! carrying the number of missed events, signalling it to refresh its state.
+//! Replayed events are already in memory and are not buffered.
+//!
+//! # Connection metrics
+//!
+//! Each [`KeepAliveStream`] represents one SSE connection. It raises the
+//! [`SSE_CONNECTIONS`] gauge when created and lowers it when dropped, and on
+//! drop records how many data events the connection delivered in
+//! [`SSE_CONNECTION_EVENTS`].
+//!
 //! # Subscribe-before-replay invariant
 //!
 //! When using these utilities with event sourcing, **subscribe to the event bus
@@ -50,12 +65,17 @@
 //! }
 //! ```
 
+use crate::sequence::GlobalSequence;
 use axum::response::sse::Event;
 use futures::stream::{Stream, StreamExt};
 use std::convert::Infallible;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::task::{Context, Poll};
 use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
 use tokio_stream::wrappers::IntervalStream;
 
 /// Default keep-alive interval in seconds.
@@ -64,6 +84,43 @@ pub const DEFAULT_KEEP_ALIVE_SECS: u64 = 15;
 /// SSE keep-alive comment text.
 pub const KEEP_ALIVE_COMMENT: &str = "keepalive";
 
+/// Default number of live events buffered per SSE connection.
+pub const DEFAULT_BUFFER_CAPACITY: usize = 256;
+
+/// SSE event name sent when a slow client missed buffered events.
+///
+/// The event data is a JSON object `{"missed": n}`. Clients should discard
+/// their incremental state and reload.
+pub const RESYNC_EVENT: &str = "resync";
+
+/// Gauge of currently open SSE connections.
+pub const SSE_CONNECTIONS: &str = "ironstar_sse_connections";
+
+/// Histogram of data events delivered over each SSE connection's lifetime.
+pub const SSE_CONNECTION_EVENTS: &str = "ironstar_sse_connection_events";
+
+/// Counts one open SSE connection in the connection metrics.
+struct ConnectionGuard {
+    delivered: Arc<AtomicU64>,
+}
+
+impl ConnectionGuard {
+    fn new() -> Self {
+        metrics::gauge!(SSE_CONNECTIONS).increment(1);
+        Self {
+            delivered: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        metrics::gauge!(SSE_CONNECTIONS).decrement(1);
+        let delivered = u32::try_from(self.delivered.load(Ordering::Relaxed)).unwrap_or(u32::MAX);
+        metrics::histogram!(SSE_CONNECTION_EVENTS).record(delivered);
+    }
+}
+
 /// A stream that yields keep-alive SSE comments at regular intervals.
 ///
 /// This stream produces `Event::default().comment(KEEP_ALIVE_COMMENT)` events
@@ -78,8 +135,12 @@ pub const KEEP_ALIVE_COMMENT: &str = "keepalive";
 /// let keep_alive = KeepAliveStream::new(Duration::from_secs(15));
 /// let combined = futures::stream::select(event_stream, keep_alive);
 /// ```
+///
+/// The stream also tracks its connection in the SSE connection metrics until
+/// it is dropped.
 pub struct KeepAliveStream {
     interval: IntervalStream,
+    connection: ConnectionGuard,
 }
 
 impl KeepAliveStream {
@@ -90,9 +151,16 @@ impl KeepAliveStream {
     pub fn new(interval: Duration) -> Self {
         Self {
             interval: IntervalStream::new(tokio::time::interval(interval)),
+            connection: ConnectionGuard::new(),
         }
     }
 
+    /// Counter of data events delivered over this connection, shared with
+    /// the event stream merged alongside it.
+    fn delivered(&self) -> Arc<AtomicU64> {
+        Arc::clone(&self.connection.delivered)
+    }
+
     /// Create a keep-alive stream with the default 15-second interval.
     #[must_use]
     pub fn default_interval() -> Self {
@@ -141,6 +209,7 @@ impl Stream for KeepAliveStream {
 #[derive(Debug, Clone)]
 pub struct SseStreamBuilder {
     keep_alive_interval: Duration,
+    buffer_capacity: usize,
 }
 
 impl Default for SseStreamBuilder {
@@ -152,11 +221,13 @@ impl Default for SseStreamBuilder {
 impl SseStreamBuilder {
     /// Create a new SSE stream builder with default settings.
     ///
-    /// Default keep-alive interval is 15 seconds.
+    /// Default keep-alive interval is 15 seconds and the live event buffer
+    /// holds [`DEFAULT_BUFFER_CAPACITY`] events.
     #[must_use]
     pub fn new() -> Self {
         Self {
             keep_alive_interval: Duration::from_secs(DEFAULT_KEEP_ALIVE_SECS),
+            buffer_capacity: DEFAULT_BUFFER_CAPACITY,
         }
     }
 
@@ -181,11 +252,20 @@ impl SseStreamBuilder {
         self
     }
 
+    /// Set how many live events are buffered before the oldest are dropped.
+    ///
+    /// The capacity is rounded up to a power of two, with a minimum of one.
+    #[must_use]
+    pub fn with_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.buffer_capacity = capacity;
+        self
+    }
+
     /// Build an SSE stream from replay and live event streams.
     ///
     /// The resulting stream:
     /// 1. Emits replayed events first
-    /// 2. Then emits live events
+    /// 2. Then emits live events, buffered while replay is in progress
     /// 3. Interleaves keep-alive comments throughout
     ///
     /// # Type parameters
@@ -204,9 +284,10 @@ impl SseStreamBuilder {
         L: Stream<Item = Result<Event, Infallible>> + Send + 'static,
     {
         let keep_alive = KeepAliveStream::new(self.keep_alive_interval);
+        let live = bounded_live_stream(live, self.buffer_capacity);
 
         // Chain replay then live, merge with keep-alive
-        let events = replay.chain(live);
+        let events = count_delivered(replay.chain(live), keep_alive.delivered());
         futures::stream::select(events, keep_alive)
     }
 
@@ -222,6 +303,8 @@ impl SseStreamBuilder {
         L: Stream<Item = Result<Event, Infallible>> + Send + 'static,
     {
         let keep_alive = KeepAliveStream::new(self.keep_alive_interval);
+        let live = bounded_live_stream(live, self.buffer_capacity);
+        let live = count_delivered(live, keep_alive.delivered());
         futures::stream::select(live, keep_alive)
     }
 
@@ -237,6 +320,74 @@ impl SseStreamBuilder {
     pub fn keep_alive_interval(&self) -> Duration {
         self.keep_alive_interval
     }
+
+    /// Get the configured live event buffer capacity.
+    #[must_use]
+    pub fn buffer_capacity(&self) -> usize {
+        self.buffer_capacity
+    }
+}
+
+/// Increment `delivered` for each event yielded by `events`.
+fn count_delivered<S>(events: S, delivered: Arc<AtomicU64>) -> impl Stream<Item = S::Item> + use<S>
+where
+    S: Stream,
+{
+    events.inspect(move |_| {
+        delivered.fetch_add(1, Ordering::Relaxed);
+    })
+}
+
+/// Decouple a live event stream from its consumer with a bounded buffer.
+///
+/// A background task drains `live` into a buffer of `capacity` events. When
+/// the consumer falls behind and the buffer is full, the oldest events are
+/// dropped and the next item yielded is a [`RESYNC_EVENT`] reporting how many
+/// were missed. The task stops when `live` ends or the returned stream is
+/// dropped.
+///
+/// Must be called within a Tokio runtime.
+pub fn bounded_live_stream<L>(
+    live: L,
+    capacity: usize,
+) -> impl Stream<Item = Result<Event, Infallible>> + Send + use<L>
+where
+    L: Stream<Item = Result<Event, Infallible>> + Send + 'static,
+{
+    let (tx, rx) = broadcast::channel(capacity.max(1));
+
+    tokio::spawn(async move {
+        let mut live = Box::pin(live);
+        loop {
+            tokio::select! {
+                item = live.next() => {
+                    let Some(Ok(event)) = item else { break };
+                    if tx.send(event).is_err() {
+                        break;
+                    }
+                }
+                () = tx.closed() => break,
+            }
+        }
+    });
+
+    futures::stream::unfold(rx, |mut rx| async move {
+        match rx.recv().await {
+            Ok(event) => Some((Ok(event), rx)),
+            Err(RecvError::Lagged(missed)) => {
+                tracing::warn!(missed, "SSE client fell behind, requesting resync");
+                Some((Ok(resync_event(missed)), rx))
+            }
+            Err(RecvError::Closed) => None,
+        }
+    })
+}
+
+/// Build the SSE event telling a client it missed `missed` events.
+fn resync_event(missed: u64) -> Event {
+    Event::default()
+        .event(RESYNC_EVENT)
+        .data(serde_json::json!({ "missed": missed }).to_string())
 }
 
 /// Convert a Zenoh subscriber to an SSE event stream.
@@ -307,7 +458,7 @@ where
 /// # Returns
 ///
 /// SSE event with ID set to the sequence number.
-pub fn event_with_sequence<T: serde::Serialize>(sequence: i64, data: &T) -> Event {
+pub fn event_with_sequence<T: serde::Serialize>(sequence: GlobalSequence, data: &T) -> Event {
     let json = serde_json::to_string(data).unwrap_or_else(|e| {
         tracing::error!(error = %e, "Failed to serialize event data");
         "{}".to_string()
@@ -456,6 +607,39 @@ mod tests {
         assert!(event_ids.len() >= 3);
     }
 
+    #[tokio::test]
+    async fn builder_custom_buffer_capacity() {
+        let builder = SseStreamBuilder::new().with_buffer_capacity(8);
+        assert_eq!(builder.buffer_capacity(), 8);
+        assert_eq!(
+            SseStreamBuilder::new().buffer_capacity(),
+            DEFAULT_BUFFER_CAPACITY
+        );
+    }
+
+    #[tokio::test]
+    async fn overflowing_buffer_sends_resync_then_latest_events() {
+        let live = futures::stream::iter(
+            (1..=5).map(|i| Ok(Event::default().id(i.to_string()).data("live"))),
+        );
+        let mut stream = Box::pin(bounded_live_stream(live, 2));
+
+        // Let the pump fill the buffer before the client reads anything.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let events: Vec<String> = stream
+            .by_ref()
+            .map(|event| format!("{:?}", event.expect("event should be Ok")))
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 3);
+        assert!(events[0].contains("event: resync"), "{}", events[0]);
+        assert!(events[0].contains(r#"{\"missed\":3}"#), "{}", events[0]);
+        assert!(events[1].contains("id: 4"));
+        assert!(events[2].contains("id: 5"));
+    }
+
     #[tokio::test]
     async fn stored_events_to_stream_converts_all() {
         #[derive(Clone)]
@@ -504,7 +688,7 @@ mod tests {
         let data = TestData {
             message: "hello".to_string(),
         };
-        let event = event_with_sequence(42, &data);
+        let event = event_with_sequence(GlobalSequence::new(42), &data);
 
         // Event was created without panic
         drop(event);
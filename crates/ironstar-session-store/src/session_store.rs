@@ -6,10 +6,32 @@
 //! - Reconnection resilience with cookie-based session resumption
 //!
 //! Session IDs use 192 bits of entropy (24 bytes) encoded as URL-safe base64.
+//!
+//! # Expiry
+//!
+//! A session expires at whichever comes first: its absolute lifetime after
+//! creation, or its idle timeout after the last activity (see
+//! [`SessionConfig`]). Activity extends the idle window but never past the
+//! absolute lifetime. `expires_at` stores the resulting deadline; reads and
+//! cleanup also check both limits against the store's current configuration,
+//! so tightening the configuration applies to existing sessions.
+//!
+//! # Metadata
+//!
+//! The client IP address and user agent are captured once at creation (see
+//! [`SessionStore::create_with_metadata`]) and never updated, so they record
+//! where the session logged in from.
+//!
+//! # Flags
+//!
+//! The application may flag a session for review, e.g. when it logged in from
+//! a new location. Flagging never revokes: a flagged session stays usable until
+//! its owner confirms or deletes it from their session listing.
 
 use crate::error::SessionStoreError;
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use chrono::{DateTime, Duration, Utc};
+use ironstar_session::SessionMetadata;
 use rand::RngExt;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
@@ -33,6 +55,19 @@ pub struct Session {
     pub expires_at: DateTime<Utc>,
     /// Session-scoped application state.
     pub data: serde_json::Value,
+    /// Client metadata captured at creation.
+    pub metadata: SessionMetadata,
+    /// Set when the session has been flagged for review.
+    pub flag: Option<SessionFlag>,
+}
+
+/// Why and when a session was flagged for review.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionFlag {
+    /// Human-readable reason, e.g. "login from a new country".
+    pub reason: String,
+    /// When the session was flagged.
+    pub flagged_at: DateTime<Utc>,
 }
 
 /// Generate a cryptographically secure session ID.
@@ -57,6 +92,20 @@ pub trait SessionStore: Send + Sync {
         user_id: Option<&str>,
     ) -> impl Future<Output = Result<Session, SessionStoreError>> + Send;
 
+    /// Create a new session recording the client's IP address and user agent.
+    fn create_with_metadata(
+        &self,
+        user_id: Option<&str>,
+        metadata: SessionMetadata,
+    ) -> impl Future<Output = Result<Session, SessionStoreError>> + Send;
+
+    /// Get the metadata captured when a session was created. Returns None
+    /// if not found or expired.
+    fn get_session_metadata(
+        &self,
+        id: &str,
+    ) -> impl Future<Output = Result<Option<SessionMetadata>, SessionStoreError>> + Send;
+
     /// Get a session by ID. Returns None if not found or expired.
     fn get(
         &self,
@@ -70,13 +119,34 @@ pub trait SessionStore: Send + Sync {
         data: serde_json::Value,
     ) -> impl Future<Output = Result<(), SessionStoreError>> + Send;
 
-    /// Touch session to update last_seen_at timestamp.
+    /// Touch session to update last_seen_at timestamp, extending its idle window.
     fn touch(&self, id: &str) -> impl Future<Output = Result<(), SessionStoreError>> + Send;
 
+    /// Extend a session's idle window and return it. Returns None if not
+    /// found or expired; expired sessions are never revived.
+    fn refresh_session(
+        &self,
+        id: &str,
+    ) -> impl Future<Output = Result<Option<Session>, SessionStoreError>> + Send;
+
+    /// Flag a live session for review. Flagging again replaces the reason.
+    fn flag_session(
+        &self,
+        id: &str,
+        reason: &str,
+    ) -> impl Future<Output = Result<(), SessionStoreError>> + Send;
+
+    /// Live flagged sessions of a user, most recently flagged first.
+    fn flagged_sessions_for_user(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<Session>, SessionStoreError>> + Send;
+
     /// Delete a specific session.
     fn delete(&self, id: &str) -> impl Future<Output = Result<(), SessionStoreError>> + Send;
 
-    /// Cleanup expired sessions, returning count deleted.
+    /// Cleanup sessions past their idle timeout or absolute lifetime,
+    /// returning count deleted.
     fn cleanup_expired(&self) -> impl Future<Output = Result<u64, SessionStoreError>> + Send;
 
     /// Delete all sessions for a user (for logout).
@@ -86,40 +156,143 @@ pub trait SessionStore: Send + Sync {
     ) -> impl Future<Output = Result<u64, SessionStoreError>> + Send;
 }
 
+/// Session lifetime limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionConfig {
+    /// Maximum lifetime from creation, regardless of activity.
+    pub absolute_ttl: Duration,
+    /// Maximum time between activities.
+    pub idle_timeout: Duration,
+}
+
+impl SessionConfig {
+    /// Create a configuration with both limits.
+    #[must_use]
+    pub fn new(absolute_ttl: Duration, idle_timeout: Duration) -> Self {
+        Self {
+            absolute_ttl,
+            idle_timeout,
+        }
+    }
+
+    /// Deadline of a session created at `created_at` and last active at
+    /// `last_seen_at`.
+    #[must_use]
+    pub fn expires_at(
+        &self,
+        created_at: DateTime<Utc>,
+        last_seen_at: DateTime<Utc>,
+    ) -> DateTime<Utc> {
+        (last_seen_at + self.idle_timeout).min(created_at + self.absolute_ttl)
+    }
+}
+
+impl Default for SessionConfig {
+    /// 30 days absolute, 7 days idle.
+    fn default() -> Self {
+        Self::new(Duration::days(30), Duration::days(7))
+    }
+}
+
 /// SQLite-backed session store.
 #[derive(Debug, Clone)]
 pub struct SqliteSessionStore {
     pool: SqlitePool,
-    ttl: Duration,
+    config: SessionConfig,
 }
 
 impl SqliteSessionStore {
-    /// Create a new session store with the given pool and TTL.
+    /// Create a session store whose sessions last `ttl` with no shorter
+    /// idle timeout.
     #[must_use]
     pub fn new(pool: SqlitePool, ttl: Duration) -> Self {
-        Self { pool, ttl }
+        Self::with_config(pool, SessionConfig::new(ttl, ttl))
     }
 
-    /// Create with default TTL of 30 days.
+    /// Create a session store with the given lifetime limits.
+    #[must_use]
+    pub fn with_config(pool: SqlitePool, config: SessionConfig) -> Self {
+        Self { pool, config }
+    }
+
+    /// Create with the default limits: 30 days absolute, 7 days idle.
     #[must_use]
     pub fn with_default_ttl(pool: SqlitePool) -> Self {
-        Self::new(pool, Duration::days(30))
+        Self::with_config(pool, SessionConfig::default())
     }
+
+    /// The store's session lifetime limits.
+    #[must_use]
+    pub fn config(&self) -> SessionConfig {
+        self.config
+    }
+}
+
+/// Columns read by [`parse_session_row`].
+const SESSION_COLUMNS: &str = "id, user_id, created_at, last_seen_at, expires_at, data, \
+     ip_address, user_agent, flag_reason, flagged_at";
+
+/// Predicate matching sessions that have not expired, binding `now`,
+/// `idle_cutoff`, and `absolute_cutoff` in that order.
+const LIVE_SESSION: &str = "expires_at > ? AND last_seen_at > ? AND created_at > ?";
+
+/// Expiry refresh for activity at `now`, binding `now`, `idle_deadline`, and
+/// `absolute_modifier` in that order. The deadline is capped at creation plus
+/// the absolute lifetime.
+const REFRESH_EXPIRY: &str = "last_seen_at = ?, expires_at = MIN(?, datetime(created_at, ?))";
+
+/// Timestamp bindings shared by the expiry-aware queries.
+struct ExpiryBounds {
+    /// Current time.
+    now: String,
+    /// Sessions last seen at or before this are idle-expired.
+    idle_cutoff: String,
+    /// Sessions created at or before this are past their absolute lifetime.
+    absolute_cutoff: String,
+    /// Idle deadline of a session active now.
+    idle_deadline: String,
+    /// SQLite datetime modifier adding the absolute lifetime, e.g. `+2592000 seconds`.
+    absolute_modifier: String,
+}
+
+impl ExpiryBounds {
+    fn at(now: DateTime<Utc>, config: SessionConfig) -> Self {
+        Self {
+            now: format_sqlite_datetime(now),
+            idle_cutoff: format_sqlite_datetime(now - config.idle_timeout),
+            absolute_cutoff: format_sqlite_datetime(now - config.absolute_ttl),
+            idle_deadline: format_sqlite_datetime(now + config.idle_timeout),
+            absolute_modifier: format!("{:+} seconds", config.absolute_ttl.num_seconds()),
+        }
+    }
+}
+
+/// Format a timestamp as SQLite TEXT with second precision.
+fn format_sqlite_datetime(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M:%S").to_string()
 }
 
 impl SessionStore for SqliteSessionStore {
     fn create(
         &self,
         user_id: Option<&str>,
+    ) -> impl Future<Output = Result<Session, SessionStoreError>> + Send {
+        self.create_with_metadata(user_id, SessionMetadata::empty())
+    }
+
+    fn create_with_metadata(
+        &self,
+        user_id: Option<&str>,
+        metadata: SessionMetadata,
     ) -> impl Future<Output = Result<Session, SessionStoreError>> + Send {
         let pool = self.pool.clone();
-        let ttl = self.ttl;
+        let config = self.config;
         let user_id = user_id.map(String::from);
 
         async move {
             let id = generate_session_id();
             let now = Utc::now();
-            let expires_at = now + ttl;
+            let expires_at = config.expires_at(now, now);
 
             let session = Session {
                 id: id.clone(),
@@ -128,17 +301,20 @@ impl SessionStore for SqliteSessionStore {
                 last_seen_at: now,
                 expires_at,
                 data: serde_json::json!({}),
+                metadata,
+                flag: None,
             };
 
             // Format timestamps as ISO 8601 for SQLite TEXT columns
-            let created_at_str = now.format("%Y-%m-%d %H:%M:%S").to_string();
-            let expires_at_str = expires_at.format("%Y-%m-%d %H:%M:%S").to_string();
+            let created_at_str = format_sqlite_datetime(now);
+            let expires_at_str = format_sqlite_datetime(expires_at);
             let data_str = session.data.to_string();
 
             sqlx::query(
                 r#"
-                INSERT INTO sessions (id, user_id, created_at, last_seen_at, expires_at, data)
-                VALUES (?, ?, ?, ?, ?, ?)
+                INSERT INTO sessions
+                    (id, user_id, created_at, last_seen_at, expires_at, data, ip_address, user_agent)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(&id)
@@ -147,6 +323,8 @@ impl SessionStore for SqliteSessionStore {
             .bind(&created_at_str)
             .bind(&expires_at_str)
             .bind(&data_str)
+            .bind(&session.metadata.ip_address)
+            .bind(&session.metadata.user_agent)
             .execute(&pool)
             .await?;
 
@@ -159,21 +337,23 @@ impl SessionStore for SqliteSessionStore {
         id: &str,
     ) -> impl Future<Output = Result<Option<Session>, SessionStoreError>> + Send {
         let pool = self.pool.clone();
+        let config = self.config;
         let id = id.to_string();
 
         async move {
-            let now = Utc::now();
-            let now_str = now.format("%Y-%m-%d %H:%M:%S").to_string();
+            let bounds = ExpiryBounds::at(Utc::now(), config);
 
-            let row = sqlx::query(
+            let row = sqlx::query(&format!(
                 r#"
-                SELECT id, user_id, created_at, last_seen_at, expires_at, data
+                SELECT {SESSION_COLUMNS}
                 FROM sessions
-                WHERE id = ? AND expires_at > ?
-                "#,
-            )
+                WHERE id = ? AND {LIVE_SESSION}
+                "#
+            ))
             .bind(&id)
-            .bind(&now_str)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_cutoff)
+            .bind(&bounds.absolute_cutoff)
             .fetch_optional(&pool)
             .await?;
 
@@ -187,29 +367,63 @@ impl SessionStore for SqliteSessionStore {
         }
     }
 
+    fn get_session_metadata(
+        &self,
+        id: &str,
+    ) -> impl Future<Output = Result<Option<SessionMetadata>, SessionStoreError>> + Send {
+        let pool = self.pool.clone();
+        let config = self.config;
+        let id = id.to_string();
+
+        async move {
+            let bounds = ExpiryBounds::at(Utc::now(), config);
+
+            let row = sqlx::query(&format!(
+                r#"
+                SELECT ip_address, user_agent
+                FROM sessions
+                WHERE id = ? AND {LIVE_SESSION}
+                "#
+            ))
+            .bind(&id)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_cutoff)
+            .bind(&bounds.absolute_cutoff)
+            .fetch_optional(&pool)
+            .await?;
+
+            Ok(row.as_ref().map(parse_metadata_row))
+        }
+    }
+
     fn update_data(
         &self,
         id: &str,
         data: serde_json::Value,
     ) -> impl Future<Output = Result<(), SessionStoreError>> + Send {
         let pool = self.pool.clone();
+        let config = self.config;
         let id = id.to_string();
 
         async move {
-            let now = Utc::now();
-            let now_str = now.format("%Y-%m-%d %H:%M:%S").to_string();
+            let bounds = ExpiryBounds::at(Utc::now(), config);
             let data_str = data.to_string();
 
-            sqlx::query(
+            sqlx::query(&format!(
                 r#"
                 UPDATE sessions
-                SET data = ?, last_seen_at = ?
-                WHERE id = ?
-                "#,
-            )
+                SET data = ?, {REFRESH_EXPIRY}
+                WHERE id = ? AND {LIVE_SESSION}
+                "#
+            ))
             .bind(&data_str)
-            .bind(&now_str)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_deadline)
+            .bind(&bounds.absolute_modifier)
             .bind(&id)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_cutoff)
+            .bind(&bounds.absolute_cutoff)
             .execute(&pool)
             .await?;
 
@@ -219,21 +433,71 @@ impl SessionStore for SqliteSessionStore {
 
     fn touch(&self, id: &str) -> impl Future<Output = Result<(), SessionStoreError>> + Send {
         let pool = self.pool.clone();
+        let config = self.config;
         let id = id.to_string();
 
         async move {
-            let now = Utc::now();
-            let now_str = now.format("%Y-%m-%d %H:%M:%S").to_string();
+            let bounds = ExpiryBounds::at(Utc::now(), config);
 
-            sqlx::query(
+            sqlx::query(&format!(
                 r#"
                 UPDATE sessions
-                SET last_seen_at = ?
-                WHERE id = ?
-                "#,
-            )
-            .bind(&now_str)
+                SET {REFRESH_EXPIRY}
+                WHERE id = ? AND {LIVE_SESSION}
+                "#
+            ))
+            .bind(&bounds.now)
+            .bind(&bounds.idle_deadline)
+            .bind(&bounds.absolute_modifier)
+            .bind(&id)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_cutoff)
+            .bind(&bounds.absolute_cutoff)
+            .execute(&pool)
+            .await?;
+
+            Ok(())
+        }
+    }
+
+    fn refresh_session(
+        &self,
+        id: &str,
+    ) -> impl Future<Output = Result<Option<Session>, SessionStoreError>> + Send {
+        let id = id.to_string();
+
+        async move {
+            self.touch(&id).await?;
+            self.get(&id).await
+        }
+    }
+
+    fn flag_session(
+        &self,
+        id: &str,
+        reason: &str,
+    ) -> impl Future<Output = Result<(), SessionStoreError>> + Send {
+        let pool = self.pool.clone();
+        let config = self.config;
+        let id = id.to_string();
+        let reason = reason.to_string();
+
+        async move {
+            let bounds = ExpiryBounds::at(Utc::now(), config);
+
+            sqlx::query(&format!(
+                r#"
+                UPDATE sessions
+                SET flag_reason = ?, flagged_at = ?
+                WHERE id = ? AND {LIVE_SESSION}
+                "#
+            ))
+            .bind(&reason)
+            .bind(&bounds.now)
             .bind(&id)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_cutoff)
+            .bind(&bounds.absolute_cutoff)
             .execute(&pool)
             .await?;
 
@@ -241,6 +505,36 @@ impl SessionStore for SqliteSessionStore {
         }
     }
 
+    fn flagged_sessions_for_user(
+        &self,
+        user_id: &str,
+    ) -> impl Future<Output = Result<Vec<Session>, SessionStoreError>> + Send {
+        let pool = self.pool.clone();
+        let config = self.config;
+        let user_id = user_id.to_string();
+
+        async move {
+            let bounds = ExpiryBounds::at(Utc::now(), config);
+
+            let rows = sqlx::query(&format!(
+                r#"
+                SELECT {SESSION_COLUMNS}
+                FROM sessions
+                WHERE user_id = ? AND flagged_at IS NOT NULL AND {LIVE_SESSION}
+                ORDER BY flagged_at DESC, id
+                "#
+            ))
+            .bind(&user_id)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_cutoff)
+            .bind(&bounds.absolute_cutoff)
+            .fetch_all(&pool)
+            .await?;
+
+            rows.iter().map(parse_session_row).collect()
+        }
+    }
+
     fn delete(&self, id: &str) -> impl Future<Output = Result<(), SessionStoreError>> + Send {
         let pool = self.pool.clone();
         let id = id.to_string();
@@ -262,18 +556,20 @@ impl SessionStore for SqliteSessionStore {
 
     fn cleanup_expired(&self) -> impl Future<Output = Result<u64, SessionStoreError>> + Send {
         let pool = self.pool.clone();
+        let config = self.config;
 
         async move {
-            let now = Utc::now();
-            let now_str = now.format("%Y-%m-%d %H:%M:%S").to_string();
+            let bounds = ExpiryBounds::at(Utc::now(), config);
 
             let result = sqlx::query(
                 r#"
                 DELETE FROM sessions
-                WHERE expires_at <= ?
+                WHERE expires_at <= ? OR last_seen_at <= ? OR created_at <= ?
                 "#,
             )
-            .bind(&now_str)
+            .bind(&bounds.now)
+            .bind(&bounds.idle_cutoff)
+            .bind(&bounds.absolute_cutoff)
             .execute(&pool)
             .await?;
 
@@ -320,6 +616,16 @@ fn parse_session_row(row: &sqlx::sqlite::SqliteRow) -> Result<Session, SessionSt
 
     let data: serde_json::Value = serde_json::from_str(&data)?;
 
+    let flag_reason: Option<String> = row.get("flag_reason");
+    let flagged_at: Option<String> = row.get("flagged_at");
+    let flag = match (flag_reason, flagged_at) {
+        (Some(reason), Some(flagged_at)) => Some(SessionFlag {
+            reason,
+            flagged_at: parse_sqlite_datetime(&flagged_at)?,
+        }),
+        _ => None,
+    };
+
     Ok(Session {
         id,
         user_id,
@@ -327,9 +633,16 @@ fn parse_session_row(row: &sqlx::sqlite::SqliteRow) -> Result<Session, SessionSt
         last_seen_at,
         expires_at,
         data,
+        metadata: parse_metadata_row(row),
+        flag,
     })
 }
 
+/// Parse the metadata columns of a SQLite row.
+fn parse_metadata_row(row: &sqlx::sqlite::SqliteRow) -> SessionMetadata {
+    SessionMetadata::new(row.get("ip_address"), row.get("user_agent"))
+}
+
 /// Parse SQLite datetime TEXT to chrono DateTime<Utc>.
 fn parse_sqlite_datetime(s: &str) -> Result<DateTime<Utc>, SessionStoreError> {
     use chrono::NaiveDateTime;
@@ -381,7 +694,8 @@ pub fn spawn_session_cleanup(
 /// SQL migration for the sessions table.
 ///
 /// Embedded directly so tests can create the schema without depending on
-/// the monolith's migrations directory.
+/// the monolith's migrations directory. Mirrors the schema after
+/// `002_sessions.sql`, `005_session_metadata.sql`, and `006_session_flags.sql`.
 pub const SESSIONS_MIGRATION_SQL: &str = r#"
 CREATE TABLE IF NOT EXISTS sessions (
     id TEXT PRIMARY KEY,
@@ -389,11 +703,16 @@ CREATE TABLE IF NOT EXISTS sessions (
     created_at TEXT NOT NULL DEFAULT (datetime('now', 'utc')),
     last_seen_at TEXT NOT NULL DEFAULT (datetime('now', 'utc')),
     expires_at TEXT NOT NULL,
-    data TEXT NOT NULL DEFAULT '{}'
+    data TEXT NOT NULL DEFAULT '{}',
+    ip_address TEXT,
+    user_agent TEXT,
+    flag_reason TEXT,
+    flagged_at TEXT
 ) STRICT;
 
 CREATE INDEX IF NOT EXISTS idx_sessions_expires ON sessions(expires_at);
 CREATE INDEX IF NOT EXISTS idx_sessions_user ON sessions(user_id) WHERE user_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_sessions_flagged ON sessions(user_id) WHERE flagged_at IS NOT NULL;
 "#;
 
 #[cfg(test)]
@@ -543,6 +862,223 @@ mod tests {
         assert!(!id.contains('='));
     }
 
+    #[tokio::test]
+    async fn metadata_is_stored_and_retrieved() {
+        let pool = create_test_pool().await;
+        let store = SqliteSessionStore::with_default_ttl(pool);
+        let metadata = SessionMetadata::new(
+            Some("203.0.113.7".to_string()),
+            Some("Mozilla/5.0 (X11; Linux x86_64)".to_string()),
+        );
+
+        let session = store
+            .create_with_metadata(Some("user-123"), metadata.clone())
+            .await
+            .unwrap();
+        assert_eq!(session.metadata, metadata);
+
+        let fetched = store.get_session_metadata(&session.id).await.unwrap();
+        assert_eq!(fetched, Some(metadata.clone()));
+        let fetched_session = store.get(&session.id).await.unwrap().unwrap();
+        assert_eq!(fetched_session.metadata, metadata);
+    }
+
+    #[tokio::test]
+    async fn metadata_defaults_to_empty() {
+        let pool = create_test_pool().await;
+        let store = SqliteSessionStore::with_default_ttl(pool);
+
+        let session = store.create(None).await.unwrap();
+        let fetched = store.get_session_metadata(&session.id).await.unwrap();
+        assert_eq!(fetched, Some(SessionMetadata::empty()));
+    }
+
+    #[tokio::test]
+    async fn metadata_of_missing_or_expired_session_is_none() {
+        let pool = create_test_pool().await;
+        let store = SqliteSessionStore::new(pool, Duration::days(-1));
+
+        let session = store
+            .create_with_metadata(
+                None,
+                SessionMetadata::new(Some("10.0.0.1".to_string()), None),
+            )
+            .await
+            .unwrap();
+        assert!(
+            store
+                .get_session_metadata(&session.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            store
+                .get_session_metadata("nonexistent")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn flagged_session_is_listed_for_its_user() {
+        let pool = create_test_pool().await;
+        let store = SqliteSessionStore::with_default_ttl(pool);
+
+        let flagged = store.create(Some("user-1")).await.unwrap();
+        let unflagged = store.create(Some("user-1")).await.unwrap();
+        let other_user = store.create(Some("user-2")).await.unwrap();
+        store
+            .flag_session(&flagged.id, "login from a new country")
+            .await
+            .unwrap();
+        store
+            .flag_session(&other_user.id, "login from a new country")
+            .await
+            .unwrap();
+
+        let listed = store.flagged_sessions_for_user("user-1").await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, flagged.id);
+        let flag = listed[0].flag.as_ref().expect("flag recorded");
+        assert_eq!(flag.reason, "login from a new country");
+
+        // Flagging does not revoke the session.
+        let fetched = store.get(&flagged.id).await.unwrap().expect("still live");
+        assert_eq!(fetched.flag.as_ref(), Some(flag));
+        assert!(
+            store
+                .get(&unflagged.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .flag
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn reflagging_replaces_reason() {
+        let pool = create_test_pool().await;
+        let store = SqliteSessionStore::with_default_ttl(pool);
+
+        let session = store.create(Some("user-1")).await.unwrap();
+        store.flag_session(&session.id, "new device").await.unwrap();
+        store
+            .flag_session(&session.id, "new country")
+            .await
+            .unwrap();
+
+        let listed = store.flagged_sessions_for_user("user-1").await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].flag.as_ref().unwrap().reason, "new country");
+    }
+
+    #[tokio::test]
+    async fn expired_flagged_session_is_not_listed() {
+        let pool = create_test_pool().await;
+        let store = SqliteSessionStore::with_default_ttl(pool.clone());
+
+        let session = store.create(Some("user-1")).await.unwrap();
+        store.flag_session(&session.id, "new device").await.unwrap();
+        sqlx::query("UPDATE sessions SET expires_at = '2000-01-01 00:00:00' WHERE id = ?")
+            .bind(&session.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert!(
+            store
+                .flagged_sessions_for_user("user-1")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// Backdate a session's creation and last activity without touching
+    /// its stored `expires_at`.
+    async fn backdate(pool: &SqlitePool, id: &str, created_ago: Duration, seen_ago: Duration) {
+        let now = Utc::now();
+        sqlx::query("UPDATE sessions SET created_at = ?, last_seen_at = ? WHERE id = ?")
+            .bind(format_sqlite_datetime(now - created_ago))
+            .bind(format_sqlite_datetime(now - seen_ago))
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn idle_session_expires() {
+        let pool = create_test_pool().await;
+        let store = SqliteSessionStore::with_config(
+            pool.clone(),
+            SessionConfig::new(Duration::days(30), Duration::hours(1)),
+        );
+
+        let session = store.create(None).await.unwrap();
+        backdate(&pool, &session.id, Duration::hours(2), Duration::hours(2)).await;
+
+        assert!(store.get(&session.id).await.unwrap().is_none());
+        assert!(store.refresh_session(&session.id).await.unwrap().is_none());
+        assert_eq!(store.cleanup_expired().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn refresh_is_capped_at_absolute_ttl() {
+        let pool = create_test_pool().await;
+        let config = SessionConfig::new(Duration::hours(2), Duration::hours(1));
+        let store = SqliteSessionStore::with_config(pool.clone(), config);
+
+        // Created 90 minutes ago: a full idle window would outlive the
+        // absolute lifetime by 30 minutes.
+        let session = store.create(None).await.unwrap();
+        backdate(
+            &pool,
+            &session.id,
+            Duration::minutes(90),
+            Duration::minutes(10),
+        )
+        .await;
+
+        let refreshed = store
+            .refresh_session(&session.id)
+            .await
+            .unwrap()
+            .expect("session still live");
+        assert_eq!(
+            refreshed.expires_at,
+            refreshed.created_at + config.absolute_ttl
+        );
+        assert!(refreshed.expires_at < refreshed.last_seen_at + config.idle_timeout);
+
+        // Past the absolute lifetime, recent activity does not keep it alive.
+        backdate(&pool, &session.id, Duration::hours(3), Duration::minutes(1)).await;
+        assert!(store.refresh_session(&session.id).await.unwrap().is_none());
+        assert_eq!(store.cleanup_expired().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn fresh_session_survives_refresh_and_cleanup() {
+        let pool = create_test_pool().await;
+        let config = SessionConfig::new(Duration::hours(2), Duration::hours(1));
+        let store = SqliteSessionStore::with_config(pool, config);
+
+        let session = store.create(None).await.unwrap();
+        assert_eq!(session.expires_at, session.created_at + config.idle_timeout);
+
+        let refreshed = store
+            .refresh_session(&session.id)
+            .await
+            .unwrap()
+            .expect("fresh session is live");
+        assert_eq!(refreshed.id, session.id);
+        assert_eq!(store.cleanup_expired().await.unwrap(), 0);
+        assert!(store.get(&session.id).await.unwrap().is_some());
+    }
+
     #[tokio::test]
     async fn spawn_cleanup_removes_expired_sessions() {
         let pool = create_test_pool().await;
@@ -272,4 +272,37 @@ mod tests {
 
         assert_eq!(cmd.decider_type(), "Session");
     }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let exp = sample_expires();
+        let commands = vec![
+            SessionCommand::Create {
+                session_id: SessionId::new(),
+                user_id: UserId::new(),
+                provider: OAuthProvider::GitHub,
+                created_at: ts,
+                expires_at: exp,
+                metadata: SessionMetadata::empty(),
+            },
+            SessionCommand::Refresh {
+                session_id: SessionId::new(),
+                refreshed_at: ts,
+                new_expires_at: exp,
+            },
+            SessionCommand::Invalidate {
+                session_id: SessionId::new(),
+                invalidated_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: SessionCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
 }
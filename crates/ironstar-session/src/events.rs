@@ -113,7 +113,7 @@ impl SessionEvent {
     /// - Upcaster matching
     /// - Metrics and logging
     #[must_use]
-    pub fn event_type_name(&self) -> &'static str {
+    pub fn event_type_str(&self) -> &'static str {
         match self {
             Self::Created { .. } => "Created",
             Self::Refreshed { .. } => "Refreshed",
@@ -140,7 +140,7 @@ impl Identifier for SessionEvent {
 
 impl EventType for SessionEvent {
     fn event_type(&self) -> String {
-        self.event_type_name().to_string()
+        self.event_type_str().to_string()
     }
 }
 
@@ -265,7 +265,7 @@ mod tests {
         ];
 
         for (event, expected_type) in events {
-            assert_eq!(event.event_type_name(), expected_type);
+            assert_eq!(event.event_type_str(), expected_type);
 
             // Verify serde tag matches
             let json = serde_json::to_value(&event).unwrap();
@@ -26,6 +26,7 @@ use uuid::Uuid;
 ///
 /// - `UserId::new()` - Generate a new random ID
 /// - `UserId::from_uuid(uuid)` - Wrap an existing UUID (for deserialization)
+/// - `UserId::system()` - The system actor (nil UUID)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "domain/", type = "string")]
 #[serde(transparent)]
@@ -47,6 +48,21 @@ impl UserId {
         Self(uuid)
     }
 
+    /// The system actor.
+    ///
+    /// Recorded as the actor of actions no user initiated, and of events
+    /// stored before events recorded an actor.
+    #[must_use]
+    pub fn system() -> Self {
+        Self(Uuid::nil())
+    }
+
+    /// Whether this is the system actor.
+    #[must_use]
+    pub fn is_system(&self) -> bool {
+        self.0.is_nil()
+    }
+
     /// Extract the inner UUID.
     #[must_use]
     pub fn into_inner(self) -> Uuid {
@@ -115,6 +131,12 @@ mod tests {
             let json = serde_json::to_string(&id).unwrap();
             assert_eq!(json, "\"00000000-0000-0000-0000-000000000000\"");
         }
+
+        #[test]
+        fn system_is_distinct_from_generated_ids() {
+            assert!(UserId::system().is_system());
+            assert!(!UserId::new().is_system());
+        }
     }
 
     mod oauth_provider {
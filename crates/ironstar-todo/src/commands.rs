@@ -223,4 +223,41 @@ mod tests {
         assert!(matches!(cmd, TodoCommand::Create { text, .. } if text == "  Trim this text  "));
         // Note: text is NOT trimmed here - that's the decider's job
     }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            TodoCommand::Create {
+                id: TodoId::new(),
+                text: "Buy groceries".to_string(),
+                created_at: ts,
+            },
+            TodoCommand::UpdateText {
+                id: TodoId::new(),
+                text: "Buy vegetables".to_string(),
+                updated_at: ts,
+            },
+            TodoCommand::Complete {
+                id: TodoId::new(),
+                completed_at: ts,
+            },
+            TodoCommand::Uncomplete {
+                id: TodoId::new(),
+                uncompleted_at: ts,
+            },
+            TodoCommand::Delete {
+                id: TodoId::new(),
+                deleted_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: TodoCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
 }
@@ -28,7 +28,7 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use crate::values::{TodoId, TodoText};
-use ironstar_core::traits::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::traits::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
 
 /// Events emitted by the Todo aggregate.
 ///
@@ -111,7 +111,7 @@ impl TodoEvent {
     /// - Upcaster matching
     /// - Metrics and logging
     #[must_use]
-    pub fn event_type(&self) -> &'static str {
+    pub fn event_type_str(&self) -> &'static str {
         match self {
             Self::Created { .. } => "Created",
             Self::TextUpdated { .. } => "TextUpdated",
@@ -139,14 +139,19 @@ impl Identifier for TodoEvent {
 
 impl EventType for TodoEvent {
     fn event_type(&self) -> String {
+        self.event_type_str().to_string()
+    }
+}
+
+impl Timestamped for TodoEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
         match self {
-            Self::Created { .. } => "Created",
-            Self::TextUpdated { .. } => "TextUpdated",
-            Self::Completed { .. } => "Completed",
-            Self::Uncompleted { .. } => "Uncompleted",
-            Self::Deleted { .. } => "Deleted",
+            Self::Created { created_at, .. } => *created_at,
+            Self::TextUpdated { updated_at, .. } => *updated_at,
+            Self::Completed { completed_at, .. } => *completed_at,
+            Self::Uncompleted { uncompleted_at, .. } => *uncompleted_at,
+            Self::Deleted { deleted_at, .. } => *deleted_at,
         }
-        .to_string()
     }
 }
 
@@ -249,7 +254,7 @@ mod tests {
         ];
 
         for (event, expected_type) in events {
-            assert_eq!(event.event_type(), expected_type);
+            assert_eq!(event.event_type_str(), expected_type);
 
             // Verify serde tag matches
             let json = serde_json::to_value(&event).unwrap();
@@ -50,7 +50,7 @@ impl TodoId {
     /// Generate a new random TodoId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a TodoId.
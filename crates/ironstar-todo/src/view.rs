@@ -21,7 +21,7 @@
 //! - `count`: Total non-deleted todos
 //! - `completed_count`: Number of completed todos
 
-use ironstar_core::View;
+use ironstar_core::{View, ViewInvariantError, ViewInvariants, debug_validated};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use uuid::Uuid;
@@ -74,6 +74,17 @@ impl TodoViewState {
     }
 }
 
+impl ViewInvariants for TodoViewState {
+    fn validate_invariants(&self) -> Result<(), ViewInvariantError> {
+        ViewInvariantError::check_count("count", self.count, self.todos.len())?;
+        ViewInvariantError::check_count(
+            "completed_count",
+            self.completed_count,
+            self.todos.iter().filter(|t| t.completed).count(),
+        )
+    }
+}
+
 /// Type alias for the Todo View.
 pub type TodoView<'a> = View<'a, TodoViewState, TodoEvent>;
 
@@ -98,7 +109,7 @@ pub type TodoView<'a> = View<'a, TodoViewState, TodoEvent>;
 /// ```
 pub fn todo_view<'a>() -> TodoView<'a> {
     View {
-        evolve: Box::new(evolve),
+        evolve: Box::new(|state, event| debug_validated(evolve(state, event))),
         initial_state: Box::new(TodoViewState::default),
     }
 }
@@ -593,4 +604,32 @@ mod tests {
         assert_eq!(state.todos.len(), 2);
         assert_eq!(state.count, 2);
     }
+
+    #[test]
+    fn completed_count_out_of_step_fails_validation() {
+        let todo = TodoItemView {
+            id: sample_id().into_inner(),
+            text: "Done".to_string(),
+            completed: true,
+        };
+        let state = TodoViewState {
+            todos: vec![todo],
+            count: 1,
+            completed_count: 1,
+        };
+        assert_eq!(state.validate_invariants(), Ok(()));
+
+        let stale = TodoViewState {
+            completed_count: 0,
+            ..state
+        };
+        assert_eq!(
+            stale.validate_invariants(),
+            Err(ViewInvariantError::CountMismatch {
+                field: "completed_count",
+                count: 0,
+                actual: 1,
+            })
+        );
+    }
 }
@@ -12,6 +12,7 @@ use super::values::{ChartId, ChartPlacement, DashboardId, TabId, TabInfo};
 use crate::workspace::WorkspaceId;
 use ironstar_core::DashboardTitle;
 use ironstar_core::{DeciderType, Identifier};
+use ironstar_shared_kernel::UserId;
 
 /// Commands that can be sent to the Dashboard aggregate.
 ///
@@ -28,6 +29,8 @@ pub enum DashboardCommand {
         dashboard_id: DashboardId,
         workspace_id: WorkspaceId,
         name: DashboardTitle,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         created_at: DateTime<Utc>,
     },
 
@@ -37,6 +40,8 @@ pub enum DashboardCommand {
     RenameDashboard {
         dashboard_id: DashboardId,
         name: DashboardTitle,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         renamed_at: DateTime<Utc>,
     },
 
@@ -46,6 +51,8 @@ pub enum DashboardCommand {
     AddChart {
         dashboard_id: DashboardId,
         placement: ChartPlacement,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         added_at: DateTime<Utc>,
     },
 
@@ -55,6 +62,8 @@ pub enum DashboardCommand {
     RemoveChart {
         dashboard_id: DashboardId,
         chart_id: ChartId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         removed_at: DateTime<Utc>,
     },
 
@@ -64,6 +73,8 @@ pub enum DashboardCommand {
     AddTab {
         dashboard_id: DashboardId,
         tab_info: TabInfo,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         added_at: DateTime<Utc>,
     },
 
@@ -73,6 +84,8 @@ pub enum DashboardCommand {
     RemoveTab {
         dashboard_id: DashboardId,
         tab_id: TabId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         removed_at: DateTime<Utc>,
     },
 
@@ -83,8 +96,32 @@ pub enum DashboardCommand {
         dashboard_id: DashboardId,
         chart_id: ChartId,
         tab_id: TabId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         moved_at: DateTime<Utc>,
     },
+
+    /// Choose the tab the UI opens first.
+    ///
+    /// Fails if the tab does not exist. Idempotent when it is already the
+    /// default.
+    SetDefaultTab {
+        dashboard_id: DashboardId,
+        tab_id: TabId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        set_at: DateTime<Utc>,
+    },
+
+    /// Archive the dashboard, ending its lifecycle.
+    ///
+    /// Idempotent when already archived.
+    ArchiveDashboard {
+        dashboard_id: DashboardId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        archived_at: DateTime<Utc>,
+    },
 }
 
 impl DashboardCommand {
@@ -98,7 +135,9 @@ impl DashboardCommand {
             | Self::RemoveChart { dashboard_id, .. }
             | Self::AddTab { dashboard_id, .. }
             | Self::RemoveTab { dashboard_id, .. }
-            | Self::MoveChartToTab { dashboard_id, .. } => *dashboard_id,
+            | Self::MoveChartToTab { dashboard_id, .. }
+            | Self::SetDefaultTab { dashboard_id, .. }
+            | Self::ArchiveDashboard { dashboard_id, .. } => *dashboard_id,
         }
     }
 
@@ -113,13 +152,15 @@ impl DashboardCommand {
             Self::AddTab { .. } => "AddTab",
             Self::RemoveTab { .. } => "RemoveTab",
             Self::MoveChartToTab { .. } => "MoveChartToTab",
+            Self::SetDefaultTab { .. } => "SetDefaultTab",
+            Self::ArchiveDashboard { .. } => "ArchiveDashboard",
         }
     }
 }
 
 impl Identifier for DashboardCommand {
     fn identifier(&self) -> String {
-        format!("dashboard_{}", self.dashboard_id())
+        self.dashboard_id().identifier()
     }
 }
 
@@ -131,8 +172,10 @@ impl DeciderType for DashboardCommand {
 
 #[cfg(test)]
 mod tests {
+    use super::super::values::{ChartDefinitionRef, ChartSource, GridPosition};
     use super::*;
-    use ironstar_core::DashboardTitle;
+    use ironstar_analytics::ChartType;
+    use ironstar_core::{DashboardTitle, GridSize, TabTitle};
 
     fn sample_time() -> DateTime<Utc> {
         DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
@@ -148,6 +191,7 @@ mod tests {
             dashboard_id: dash_id,
             workspace_id: ws_id,
             name: DashboardTitle::new("Test").unwrap(),
+            actor: UserId::system(),
             created_at: sample_time(),
         };
 
@@ -164,6 +208,7 @@ mod tests {
             dashboard_id: dash_id,
             workspace_id: ws_id,
             name: DashboardTitle::new("Test").unwrap(),
+            actor: UserId::system(),
             created_at: sample_time(),
         };
 
@@ -183,16 +228,19 @@ mod tests {
                 dashboard_id: dash_id,
                 workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
                 name: DashboardTitle::new("Test").unwrap(),
+                actor: UserId::system(),
                 created_at: ts,
             },
             DashboardCommand::RenameDashboard {
                 dashboard_id: dash_id,
                 name: DashboardTitle::new("New Name").unwrap(),
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             DashboardCommand::RemoveChart {
                 dashboard_id: dash_id,
                 chart_id: ChartId::from_uuid(uuid::Uuid::nil()),
+                actor: UserId::system(),
                 removed_at: ts,
             },
         ];
@@ -201,4 +249,86 @@ mod tests {
             assert_eq!(cmd.dashboard_id(), dash_id);
         }
     }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            DashboardCommand::CreateDashboard {
+                dashboard_id: DashboardId::new(),
+                workspace_id: WorkspaceId::new(),
+                name: DashboardTitle::new("Sales").unwrap(),
+                actor: UserId::system(),
+                created_at: ts,
+            },
+            DashboardCommand::RenameDashboard {
+                dashboard_id: DashboardId::new(),
+                name: DashboardTitle::new("Revenue").unwrap(),
+                actor: UserId::system(),
+                renamed_at: ts,
+            },
+            DashboardCommand::AddChart {
+                dashboard_id: DashboardId::new(),
+                placement: ChartPlacement {
+                    chart_id: ChartId::new(),
+                    source: ChartSource::Reference(ChartDefinitionRef {
+                        ref_id: "chart-def-001".to_string(),
+                        chart_type_hint: Some(ChartType::Line),
+                    }),
+                    position: GridPosition { row: 1, col: 2 },
+                    size: GridSize::new(4, 3).unwrap(),
+                    tab_id: Some(TabId::new()),
+                },
+                actor: UserId::system(),
+                added_at: ts,
+            },
+            DashboardCommand::RemoveChart {
+                dashboard_id: DashboardId::new(),
+                chart_id: ChartId::new(),
+                actor: UserId::system(),
+                removed_at: ts,
+            },
+            DashboardCommand::AddTab {
+                dashboard_id: DashboardId::new(),
+                tab_info: TabInfo {
+                    tab_id: TabId::new(),
+                    name: TabTitle::new("Overview").unwrap(),
+                },
+                actor: UserId::system(),
+                added_at: ts,
+            },
+            DashboardCommand::RemoveTab {
+                dashboard_id: DashboardId::new(),
+                tab_id: TabId::new(),
+                actor: UserId::system(),
+                removed_at: ts,
+            },
+            DashboardCommand::MoveChartToTab {
+                dashboard_id: DashboardId::new(),
+                chart_id: ChartId::new(),
+                tab_id: TabId::new(),
+                actor: UserId::system(),
+                moved_at: ts,
+            },
+            DashboardCommand::SetDefaultTab {
+                dashboard_id: DashboardId::new(),
+                tab_id: TabId::new(),
+                actor: UserId::system(),
+                set_at: ts,
+            },
+            DashboardCommand::ArchiveDashboard {
+                dashboard_id: DashboardId::new(),
+                actor: UserId::system(),
+                archived_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: DashboardCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
 }
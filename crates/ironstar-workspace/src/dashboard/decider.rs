@@ -20,15 +20,35 @@
 //!                              ▼
 //!                     ┌───────────────────┐
 //!                     │  DashboardExists  │ (updated fields)
+//!                     └────────┬──────────┘
+//!                              │ ArchiveDashboard
+//!                              ▼
+//!                     ┌───────────────────┐
+//!                     │     Archived      │ (terminal)
 //!                     └───────────────────┘
 //! ```
 //!
 //! # Idempotency
 //!
 //! - RenameDashboard with same name returns `Ok(vec![])`
-//! - AddChart with existing chart_id returns `Ok(vec![])`
+//! - AddChart with an identical existing placement returns `Ok(vec![])`;
+//!   an existing chart_id with a different placement is `ChartAlreadyPlaced`
 //! - RemoveChart with missing chart_id returns `Ok(vec![])`
 //! - AddTab with existing tab_id returns `Ok(vec![])`
+//! - SetDefaultTab naming the current default returns `Ok(vec![])`
+//! - ArchiveDashboard on an archived dashboard returns `Ok(vec![])`
+//!
+//! # Default tab
+//!
+//! The first tab added becomes the default, and SetDefaultTab changes it.
+//! Removing the default tab falls back to the tab after it, or the one
+//! before it when it was last, or none when no tabs remain.
+//!
+//! # Limits
+//!
+//! AddChart is rejected with `TooManyCharts` once the dashboard holds
+//! `max_charts` placements ([`DEFAULT_MAX_CHARTS`] unless configured via
+//! [`dashboard_decider_with_max_charts`]).
 
 use ironstar_core::Decider;
 use tracing::instrument;
@@ -36,9 +56,12 @@ use tracing::instrument;
 use super::commands::DashboardCommand;
 use super::errors::DashboardError;
 use super::events::DashboardEvent;
-use super::state::DashboardState;
+use super::state::{DashboardState, default_tab_after_removal};
 use super::values::ChartPlacement;
 
+/// Maximum number of charts a dashboard may hold by default.
+pub const DEFAULT_MAX_CHARTS: usize = 50;
+
 /// Type alias for the Dashboard Decider.
 pub type DashboardDecider<'a> =
     Decider<'a, DashboardCommand, DashboardState, DashboardEvent, DashboardError>;
@@ -48,8 +71,13 @@ pub type DashboardDecider<'a> =
 /// Translates the specification from `spec/Workspace/Dashboard.idr`
 /// into Rust, preserving the state machine transitions and idempotency invariants.
 pub fn dashboard_decider<'a>() -> DashboardDecider<'a> {
+    dashboard_decider_with_max_charts(DEFAULT_MAX_CHARTS)
+}
+
+/// Factory function creating a Dashboard Decider with a custom chart limit.
+pub fn dashboard_decider_with_max_charts<'a>(max_charts: usize) -> DashboardDecider<'a> {
     Decider {
-        decide: Box::new(decide),
+        decide: Box::new(move |command, state| decide(command, state, max_charts)),
         evolve: Box::new(evolve),
         initial_state: Box::new(DashboardState::default),
     }
@@ -67,6 +95,7 @@ pub fn dashboard_decider<'a>() -> DashboardDecider<'a> {
 fn decide(
     command: &DashboardCommand,
     state: &DashboardState,
+    max_charts: usize,
 ) -> Result<Vec<DashboardEvent>, DashboardError> {
     let result = match (command, state) {
         // CreateDashboard: NoDashboard -> DashboardExists
@@ -75,6 +104,7 @@ fn decide(
                 dashboard_id,
                 workspace_id,
                 name,
+                actor,
                 created_at,
             },
             DashboardState::NoDashboard,
@@ -82,6 +112,7 @@ fn decide(
             dashboard_id: *dashboard_id,
             workspace_id: *workspace_id,
             name: name.clone(),
+            actor: *actor,
             created_at: *created_at,
         }]),
 
@@ -95,6 +126,7 @@ fn decide(
             DashboardCommand::RenameDashboard {
                 dashboard_id,
                 name,
+                actor,
                 renamed_at,
             },
             DashboardState::DashboardExists {
@@ -108,6 +140,7 @@ fn decide(
             Ok(vec![DashboardEvent::DashboardRenamed {
                 dashboard_id: *dashboard_id,
                 name: name.clone(),
+                actor: *actor,
                 renamed_at: *renamed_at,
             }])
         }
@@ -117,22 +150,32 @@ fn decide(
             Err(DashboardError::not_found())
         }
 
-        // AddChart: DashboardExists -> DashboardExists (idempotent on duplicate chart_id)
+        // AddChart: DashboardExists -> DashboardExists (idempotent on identical placement)
         (
             DashboardCommand::AddChart {
                 dashboard_id,
                 placement,
+                actor,
                 added_at,
             },
             DashboardState::DashboardExists { placements, .. },
         ) => {
-            if placements.iter().any(|p| p.chart_id == placement.chart_id) {
-                return Ok(vec![]);
+            if let Some(existing) = state.chart_placement(&placement.chart_id) {
+                return if existing == placement {
+                    Ok(vec![])
+                } else {
+                    Err(DashboardError::chart_already_placed(placement.chart_id))
+                };
+            }
+
+            if placements.len() >= max_charts {
+                return Err(DashboardError::too_many_charts(max_charts));
             }
 
             Ok(vec![DashboardEvent::ChartAdded {
                 dashboard_id: *dashboard_id,
                 placement: placement.clone(),
+                actor: *actor,
                 added_at: *added_at,
             }])
         }
@@ -147,17 +190,19 @@ fn decide(
             DashboardCommand::RemoveChart {
                 dashboard_id,
                 chart_id,
+                actor,
                 removed_at,
             },
-            DashboardState::DashboardExists { placements, .. },
+            DashboardState::DashboardExists { .. },
         ) => {
-            if !placements.iter().any(|p| p.chart_id == *chart_id) {
+            if state.chart_placement(chart_id).is_none() {
                 return Ok(vec![]);
             }
 
             Ok(vec![DashboardEvent::ChartRemoved {
                 dashboard_id: *dashboard_id,
                 chart_id: *chart_id,
+                actor: *actor,
                 removed_at: *removed_at,
             }])
         }
@@ -172,6 +217,7 @@ fn decide(
             DashboardCommand::AddTab {
                 dashboard_id,
                 tab_info,
+                actor,
                 added_at,
             },
             DashboardState::DashboardExists { tabs, .. },
@@ -183,6 +229,7 @@ fn decide(
             Ok(vec![DashboardEvent::TabAdded {
                 dashboard_id: *dashboard_id,
                 tab_info: tab_info.clone(),
+                actor: *actor,
                 added_at: *added_at,
             }])
         }
@@ -197,6 +244,7 @@ fn decide(
             DashboardCommand::RemoveTab {
                 dashboard_id,
                 tab_id,
+                actor,
                 removed_at,
             },
             DashboardState::DashboardExists { tabs, .. },
@@ -208,6 +256,7 @@ fn decide(
             Ok(vec![DashboardEvent::TabRemoved {
                 dashboard_id: *dashboard_id,
                 tab_id: *tab_id,
+                actor: *actor,
                 removed_at: *removed_at,
             }])
         }
@@ -223,13 +272,12 @@ fn decide(
                 dashboard_id,
                 chart_id,
                 tab_id,
+                actor,
                 moved_at,
             },
-            DashboardState::DashboardExists {
-                placements, tabs, ..
-            },
+            DashboardState::DashboardExists { tabs, .. },
         ) => {
-            if !placements.iter().any(|p| p.chart_id == *chart_id) {
+            if state.chart_placement(chart_id).is_none() {
                 return Err(DashboardError::chart_not_found());
             }
 
@@ -241,6 +289,7 @@ fn decide(
                 dashboard_id: *dashboard_id,
                 chart_id: *chart_id,
                 tab_id: *tab_id,
+                actor: *actor,
                 moved_at: *moved_at,
             }])
         }
@@ -249,6 +298,66 @@ fn decide(
         (DashboardCommand::MoveChartToTab { .. }, DashboardState::NoDashboard) => {
             Err(DashboardError::not_found())
         }
+
+        // SetDefaultTab: DashboardExists -> check tab exists (idempotent on current default)
+        (
+            DashboardCommand::SetDefaultTab {
+                dashboard_id,
+                tab_id,
+                actor,
+                set_at,
+            },
+            DashboardState::DashboardExists {
+                tabs,
+                default_tab_id,
+                ..
+            },
+        ) => {
+            if !tabs.iter().any(|t| t.tab_id == *tab_id) {
+                return Err(DashboardError::tab_not_found());
+            }
+
+            if default_tab_id.as_ref() == Some(tab_id) {
+                return Ok(vec![]);
+            }
+
+            Ok(vec![DashboardEvent::DefaultTabSet {
+                dashboard_id: *dashboard_id,
+                tab_id: *tab_id,
+                actor: *actor,
+                set_at: *set_at,
+            }])
+        }
+
+        // SetDefaultTab when not created
+        (DashboardCommand::SetDefaultTab { .. }, DashboardState::NoDashboard) => {
+            Err(DashboardError::not_found())
+        }
+
+        // ArchiveDashboard: DashboardExists -> Archived
+        (
+            DashboardCommand::ArchiveDashboard {
+                dashboard_id,
+                actor,
+                archived_at,
+            },
+            DashboardState::DashboardExists { .. },
+        ) => Ok(vec![DashboardEvent::DashboardArchived {
+            dashboard_id: *dashboard_id,
+            actor: *actor,
+            archived_at: *archived_at,
+        }]),
+
+        // ArchiveDashboard when already archived (idempotent)
+        (DashboardCommand::ArchiveDashboard { .. }, DashboardState::Archived { .. }) => Ok(vec![]),
+
+        // ArchiveDashboard when not created
+        (DashboardCommand::ArchiveDashboard { .. }, DashboardState::NoDashboard) => {
+            Err(DashboardError::not_found())
+        }
+
+        // Any other command against an archived dashboard
+        (_, DashboardState::Archived { .. }) => Err(DashboardError::archived()),
     };
     if let Ok(ref events) = result {
         tracing::debug!(event_count = events.len(), "decision complete");
@@ -276,6 +385,19 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
             name: name.clone(),
             placements: vec![],
             tabs: vec![],
+            default_tab_id: None,
+        },
+
+        DashboardEvent::DashboardArchived { .. } => match state {
+            DashboardState::DashboardExists {
+                dashboard_id,
+                workspace_id,
+                ..
+            } => DashboardState::Archived {
+                dashboard_id: *dashboard_id,
+                workspace_id: *workspace_id,
+            },
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
         },
 
         DashboardEvent::DashboardRenamed { name, .. } => match state {
@@ -284,6 +406,7 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                 workspace_id,
                 placements,
                 tabs,
+                default_tab_id,
                 ..
             } => DashboardState::DashboardExists {
                 dashboard_id: *dashboard_id,
@@ -291,8 +414,9 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                 name: name.clone(),
                 placements: placements.clone(),
                 tabs: tabs.clone(),
+                default_tab_id: *default_tab_id,
             },
-            DashboardState::NoDashboard => state.clone(),
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
         },
 
         DashboardEvent::ChartAdded { placement, .. } => match state {
@@ -302,6 +426,7 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                 name,
                 placements,
                 tabs,
+                default_tab_id,
             } => {
                 let mut new_placements = placements.clone();
                 new_placements.push(placement.clone());
@@ -311,9 +436,10 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                     name: name.clone(),
                     placements: new_placements,
                     tabs: tabs.clone(),
+                    default_tab_id: *default_tab_id,
                 }
             }
-            DashboardState::NoDashboard => state.clone(),
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
         },
 
         DashboardEvent::ChartRemoved { chart_id, .. } => match state {
@@ -323,6 +449,7 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                 name,
                 placements,
                 tabs,
+                default_tab_id,
             } => DashboardState::DashboardExists {
                 dashboard_id: *dashboard_id,
                 workspace_id: *workspace_id,
@@ -333,8 +460,9 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                     .cloned()
                     .collect(),
                 tabs: tabs.clone(),
+                default_tab_id: *default_tab_id,
             },
-            DashboardState::NoDashboard => state.clone(),
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
         },
 
         DashboardEvent::TabAdded { tab_info, .. } => match state {
@@ -344,6 +472,7 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                 name,
                 placements,
                 tabs,
+                default_tab_id,
             } => {
                 let mut new_tabs = tabs.clone();
                 new_tabs.push(tab_info.clone());
@@ -353,9 +482,10 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                     name: name.clone(),
                     placements: placements.clone(),
                     tabs: new_tabs,
+                    default_tab_id: default_tab_id.or(Some(tab_info.tab_id)),
                 }
             }
-            DashboardState::NoDashboard => state.clone(),
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
         },
 
         DashboardEvent::TabRemoved { tab_id, .. } => match state {
@@ -365,6 +495,7 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                 name,
                 placements,
                 tabs,
+                default_tab_id,
             } => DashboardState::DashboardExists {
                 dashboard_id: *dashboard_id,
                 workspace_id: *workspace_id,
@@ -379,8 +510,9 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                     .filter(|t| t.tab_id != *tab_id)
                     .cloned()
                     .collect(),
+                default_tab_id: default_tab_after_removal(tabs, *default_tab_id, tab_id),
             },
-            DashboardState::NoDashboard => state.clone(),
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
         },
 
         DashboardEvent::ChartMovedToTab {
@@ -392,6 +524,7 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                 name,
                 placements,
                 tabs,
+                default_tab_id,
             } => DashboardState::DashboardExists {
                 dashboard_id: *dashboard_id,
                 workspace_id: *workspace_id,
@@ -410,8 +543,28 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
                     })
                     .collect(),
                 tabs: tabs.clone(),
+                default_tab_id: *default_tab_id,
+            },
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
+        },
+
+        DashboardEvent::DefaultTabSet { tab_id, .. } => match state {
+            DashboardState::DashboardExists {
+                dashboard_id,
+                workspace_id,
+                name,
+                placements,
+                tabs,
+                ..
+            } => DashboardState::DashboardExists {
+                dashboard_id: *dashboard_id,
+                workspace_id: *workspace_id,
+                name: name.clone(),
+                placements: placements.clone(),
+                tabs: tabs.clone(),
+                default_tab_id: Some(*tab_id),
             },
-            DashboardState::NoDashboard => state.clone(),
+            DashboardState::NoDashboard | DashboardState::Archived { .. } => state.clone(),
         },
     }
 }
@@ -419,11 +572,13 @@ fn evolve(state: &DashboardState, event: &DashboardEvent) -> DashboardState {
 #[cfg(test)]
 mod tests {
     use super::super::values::{
-        ChartDefinitionRef, ChartId, ChartPlacement, DashboardId, GridPosition, TabId, TabInfo,
+        ChartDefinitionRef, ChartId, ChartPlacement, ChartSource, DashboardId, GridPosition, TabId,
+        TabInfo,
     };
     use super::*;
     use chrono::{DateTime, Utc};
-    use ironstar_core::DeciderTestSpecification;
+    use ironstar_core::{DeciderTestExt, DeciderTestSpecification};
+    use ironstar_shared_kernel::UserId;
 
     use crate::workspace::WorkspaceId;
     use ironstar_analytics::ChartType;
@@ -458,10 +613,10 @@ mod tests {
     fn sample_placement() -> ChartPlacement {
         ChartPlacement {
             chart_id: sample_chart_id(),
-            chart_def_ref: ChartDefinitionRef {
+            source: ChartSource::Reference(ChartDefinitionRef {
                 ref_id: "chart-def-001".to_string(),
                 chart_type_hint: Some(ChartType::Bar),
-            },
+            }),
             position: GridPosition { row: 0, col: 0 },
             size: GridSize::new(4, 3).unwrap(),
             tab_id: None,
@@ -480,6 +635,7 @@ mod tests {
             dashboard_id: sample_dashboard_id(),
             workspace_id: sample_workspace_id(),
             name: sample_title(),
+            actor: UserId::system(),
             created_at: sample_time(),
         }
     }
@@ -500,12 +656,14 @@ mod tests {
                 dashboard_id: dash_id,
                 workspace_id: ws_id,
                 name: title.clone(),
+                actor: UserId::system(),
                 created_at: ts,
             })
             .then(vec![DashboardEvent::DashboardCreated {
                 dashboard_id: dash_id,
                 workspace_id: ws_id,
                 name: title,
+                actor: UserId::system(),
                 created_at: ts,
             }]);
     }
@@ -523,6 +681,7 @@ mod tests {
                 dashboard_id: dash_id,
                 workspace_id: ws_id,
                 name: sample_title(),
+                actor: UserId::system(),
                 created_at: ts,
             })
             .then_error(DashboardError::already_exists());
@@ -542,11 +701,13 @@ mod tests {
             .when(DashboardCommand::RenameDashboard {
                 dashboard_id: dash_id,
                 name: new_name.clone(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then(vec![DashboardEvent::DashboardRenamed {
                 dashboard_id: dash_id,
                 name: new_name,
+                actor: UserId::system(),
                 renamed_at: ts,
             }]);
     }
@@ -562,6 +723,7 @@ mod tests {
             .when(DashboardCommand::RenameDashboard {
                 dashboard_id: dash_id,
                 name: sample_title(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then(vec![]);
@@ -578,6 +740,7 @@ mod tests {
             .when(DashboardCommand::RenameDashboard {
                 dashboard_id: dash_id,
                 name: DashboardTitle::new("New Name").unwrap(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then_error(DashboardError::not_found());
@@ -597,17 +760,19 @@ mod tests {
             .when(DashboardCommand::AddChart {
                 dashboard_id: dash_id,
                 placement: placement.clone(),
+                actor: UserId::system(),
                 added_at: ts,
             })
             .then(vec![DashboardEvent::ChartAdded {
                 dashboard_id: dash_id,
                 placement,
+                actor: UserId::system(),
                 added_at: ts,
             }]);
     }
 
     #[test]
-    fn add_chart_duplicate_chart_id_is_idempotent() {
+    fn add_chart_identical_placement_is_idempotent() {
         let dash_id = sample_dashboard_id();
         let ts = sample_time();
         let placement = sample_placement();
@@ -619,17 +784,115 @@ mod tests {
                 DashboardEvent::ChartAdded {
                     dashboard_id: dash_id,
                     placement: placement.clone(),
+                    actor: UserId::system(),
                     added_at: ts,
                 },
             ])
             .when(DashboardCommand::AddChart {
                 dashboard_id: dash_id,
                 placement,
+                actor: UserId::system(),
                 added_at: ts,
             })
             .then(vec![]);
     }
 
+    #[test]
+    fn add_chart_with_different_placement_fails() {
+        let dash_id = sample_dashboard_id();
+        let ts = sample_time();
+        let placement = sample_placement();
+
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(vec![
+                created_event(),
+                DashboardEvent::ChartAdded {
+                    dashboard_id: dash_id,
+                    placement: placement.clone(),
+                    actor: UserId::system(),
+                    added_at: ts,
+                },
+            ])
+            .when(DashboardCommand::AddChart {
+                dashboard_id: dash_id,
+                placement: ChartPlacement {
+                    position: GridPosition { row: 2, col: 4 },
+                    ..placement
+                },
+                actor: UserId::system(),
+                added_at: ts,
+            })
+            .then_error(DashboardError::chart_already_placed(placement.chart_id));
+    }
+
+    fn placement_at(n: u128) -> ChartPlacement {
+        ChartPlacement {
+            chart_id: ChartId::from_uuid(uuid::Uuid::from_u128(n)),
+            ..sample_placement()
+        }
+    }
+
+    fn charts_added(count: usize) -> Vec<DashboardEvent> {
+        std::iter::once(created_event())
+            .chain((100..).take(count).map(|n| DashboardEvent::ChartAdded {
+                dashboard_id: sample_dashboard_id(),
+                placement: placement_at(n),
+                actor: UserId::system(),
+                added_at: sample_time(),
+            }))
+            .collect()
+    }
+
+    #[test]
+    fn add_chart_up_to_default_limit_succeeds() {
+        let placement = placement_at(1);
+
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(charts_added(DEFAULT_MAX_CHARTS - 1))
+            .when(DashboardCommand::AddChart {
+                dashboard_id: sample_dashboard_id(),
+                placement: placement.clone(),
+                actor: UserId::system(),
+                added_at: sample_time(),
+            })
+            .then(vec![DashboardEvent::ChartAdded {
+                dashboard_id: sample_dashboard_id(),
+                placement,
+                actor: UserId::system(),
+                added_at: sample_time(),
+            }]);
+    }
+
+    #[test]
+    fn add_chart_beyond_default_limit_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(charts_added(DEFAULT_MAX_CHARTS))
+            .when(DashboardCommand::AddChart {
+                dashboard_id: sample_dashboard_id(),
+                placement: placement_at(1),
+                actor: UserId::system(),
+                added_at: sample_time(),
+            })
+            .then_error(DashboardError::too_many_charts(DEFAULT_MAX_CHARTS));
+    }
+
+    #[test]
+    fn add_chart_respects_configured_limit() {
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider_with_max_charts(2))
+            .given(charts_added(2))
+            .when(DashboardCommand::AddChart {
+                dashboard_id: sample_dashboard_id(),
+                placement: placement_at(1),
+                actor: UserId::system(),
+                added_at: sample_time(),
+            })
+            .then_error(DashboardError::too_many_charts(2));
+    }
+
     #[test]
     fn add_chart_not_found_fails() {
         let dash_id = sample_dashboard_id();
@@ -641,6 +904,7 @@ mod tests {
             .when(DashboardCommand::AddChart {
                 dashboard_id: dash_id,
                 placement: sample_placement(),
+                actor: UserId::system(),
                 added_at: ts,
             })
             .then_error(DashboardError::not_found());
@@ -662,17 +926,20 @@ mod tests {
                 DashboardEvent::ChartAdded {
                     dashboard_id: dash_id,
                     placement,
+                    actor: UserId::system(),
                     added_at: ts,
                 },
             ])
             .when(DashboardCommand::RemoveChart {
                 dashboard_id: dash_id,
                 chart_id,
+                actor: UserId::system(),
                 removed_at: ts,
             })
             .then(vec![DashboardEvent::ChartRemoved {
                 dashboard_id: dash_id,
                 chart_id,
+                actor: UserId::system(),
                 removed_at: ts,
             }]);
     }
@@ -688,6 +955,7 @@ mod tests {
             .when(DashboardCommand::RemoveChart {
                 dashboard_id: dash_id,
                 chart_id: sample_chart_id(),
+                actor: UserId::system(),
                 removed_at: ts,
             })
             .then(vec![]);
@@ -704,6 +972,7 @@ mod tests {
             .when(DashboardCommand::RemoveChart {
                 dashboard_id: dash_id,
                 chart_id: sample_chart_id(),
+                actor: UserId::system(),
                 removed_at: ts,
             })
             .then_error(DashboardError::not_found());
@@ -723,11 +992,13 @@ mod tests {
             .when(DashboardCommand::AddTab {
                 dashboard_id: dash_id,
                 tab_info: tab_info.clone(),
+                actor: UserId::system(),
                 added_at: ts,
             })
             .then(vec![DashboardEvent::TabAdded {
                 dashboard_id: dash_id,
                 tab_info,
+                actor: UserId::system(),
                 added_at: ts,
             }]);
     }
@@ -745,12 +1016,14 @@ mod tests {
                 DashboardEvent::TabAdded {
                     dashboard_id: dash_id,
                     tab_info: tab_info.clone(),
+                    actor: UserId::system(),
                     added_at: ts,
                 },
             ])
             .when(DashboardCommand::AddTab {
                 dashboard_id: dash_id,
                 tab_info,
+                actor: UserId::system(),
                 added_at: ts,
             })
             .then(vec![]);
@@ -767,6 +1040,7 @@ mod tests {
             .when(DashboardCommand::AddTab {
                 dashboard_id: dash_id,
                 tab_info: sample_tab_info(),
+                actor: UserId::system(),
                 added_at: ts,
             })
             .then_error(DashboardError::not_found());
@@ -788,17 +1062,20 @@ mod tests {
                 DashboardEvent::TabAdded {
                     dashboard_id: dash_id,
                     tab_info,
+                    actor: UserId::system(),
                     added_at: ts,
                 },
             ])
             .when(DashboardCommand::RemoveTab {
                 dashboard_id: dash_id,
                 tab_id,
+                actor: UserId::system(),
                 removed_at: ts,
             })
             .then(vec![DashboardEvent::TabRemoved {
                 dashboard_id: dash_id,
                 tab_id,
+                actor: UserId::system(),
                 removed_at: ts,
             }]);
     }
@@ -814,6 +1091,7 @@ mod tests {
             .when(DashboardCommand::RemoveTab {
                 dashboard_id: dash_id,
                 tab_id: sample_tab_id(),
+                actor: UserId::system(),
                 removed_at: ts,
             })
             .then_error(DashboardError::tab_not_found());
@@ -830,6 +1108,7 @@ mod tests {
             .when(DashboardCommand::RemoveTab {
                 dashboard_id: dash_id,
                 tab_id: sample_tab_id(),
+                actor: UserId::system(),
                 removed_at: ts,
             })
             .then_error(DashboardError::not_found());
@@ -853,11 +1132,13 @@ mod tests {
                 DashboardEvent::ChartAdded {
                     dashboard_id: dash_id,
                     placement,
+                    actor: UserId::system(),
                     added_at: ts,
                 },
                 DashboardEvent::TabAdded {
                     dashboard_id: dash_id,
                     tab_info,
+                    actor: UserId::system(),
                     added_at: ts,
                 },
             ])
@@ -865,12 +1146,14 @@ mod tests {
                 dashboard_id: dash_id,
                 chart_id,
                 tab_id,
+                actor: UserId::system(),
                 moved_at: ts,
             })
             .then(vec![DashboardEvent::ChartMovedToTab {
                 dashboard_id: dash_id,
                 chart_id,
                 tab_id,
+                actor: UserId::system(),
                 moved_at: ts,
             }]);
     }
@@ -888,6 +1171,7 @@ mod tests {
                 DashboardEvent::TabAdded {
                     dashboard_id: dash_id,
                     tab_info,
+                    actor: UserId::system(),
                     added_at: ts,
                 },
             ])
@@ -895,6 +1179,7 @@ mod tests {
                 dashboard_id: dash_id,
                 chart_id: sample_chart_id(),
                 tab_id: sample_tab_id(),
+                actor: UserId::system(),
                 moved_at: ts,
             })
             .then_error(DashboardError::chart_not_found());
@@ -913,6 +1198,7 @@ mod tests {
                 DashboardEvent::ChartAdded {
                     dashboard_id: dash_id,
                     placement,
+                    actor: UserId::system(),
                     added_at: ts,
                 },
             ])
@@ -920,6 +1206,7 @@ mod tests {
                 dashboard_id: dash_id,
                 chart_id: sample_chart_id(),
                 tab_id: sample_tab_id(),
+                actor: UserId::system(),
                 moved_at: ts,
             })
             .then_error(DashboardError::tab_not_found());
@@ -937,11 +1224,141 @@ mod tests {
                 dashboard_id: dash_id,
                 chart_id: sample_chart_id(),
                 tab_id: sample_tab_id(),
+                actor: UserId::system(),
                 moved_at: ts,
             })
             .then_error(DashboardError::not_found());
     }
 
+    // --- SetDefaultTab transitions ---
+
+    fn tab_added(n: u128) -> DashboardEvent {
+        DashboardEvent::TabAdded {
+            dashboard_id: sample_dashboard_id(),
+            tab_info: TabInfo {
+                tab_id: TabId::from_uuid(uuid::Uuid::from_u128(n)),
+                name: TabTitle::new(format!("Tab {n}")).unwrap(),
+            },
+            actor: UserId::system(),
+            added_at: sample_time(),
+        }
+    }
+
+    fn default_tab_set(n: u128) -> DashboardEvent {
+        DashboardEvent::DefaultTabSet {
+            dashboard_id: sample_dashboard_id(),
+            tab_id: TabId::from_uuid(uuid::Uuid::from_u128(n)),
+            actor: UserId::system(),
+            set_at: sample_time(),
+        }
+    }
+
+    fn replay(events: &[DashboardEvent]) -> DashboardState {
+        events
+            .iter()
+            .fold(DashboardState::default(), |state, event| {
+                evolve(&state, event)
+            })
+    }
+
+    fn set_default_tab(n: u128) -> DashboardCommand {
+        DashboardCommand::SetDefaultTab {
+            dashboard_id: sample_dashboard_id(),
+            tab_id: TabId::from_uuid(uuid::Uuid::from_u128(n)),
+            actor: UserId::system(),
+            set_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn first_added_tab_is_default() {
+        assert_eq!(replay(&[created_event()]).default_tab_id(), None);
+
+        let state = replay(&[created_event(), tab_added(1), tab_added(2)]);
+
+        assert_eq!(
+            state.default_tab_id(),
+            Some(&TabId::from_uuid(uuid::Uuid::from_u128(1)))
+        );
+    }
+
+    #[test]
+    fn set_default_tab_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(vec![created_event(), tab_added(1), tab_added(2)])
+            .when(set_default_tab(2))
+            .then(vec![default_tab_set(2)]);
+
+        let state = replay(&[
+            created_event(),
+            tab_added(1),
+            tab_added(2),
+            default_tab_set(2),
+        ]);
+        assert_eq!(
+            state.default_tab_id(),
+            Some(&TabId::from_uuid(uuid::Uuid::from_u128(2)))
+        );
+    }
+
+    #[test]
+    fn set_default_tab_already_default_is_idempotent() {
+        dashboard_decider().assert_no_events(
+            vec![created_event(), tab_added(1), tab_added(2)],
+            set_default_tab(1),
+        );
+    }
+
+    #[test]
+    fn set_default_tab_missing_tab_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(vec![created_event(), tab_added(1)])
+            .when(set_default_tab(9))
+            .then_error(DashboardError::tab_not_found());
+    }
+
+    #[test]
+    fn set_default_tab_not_found_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(vec![])
+            .when(set_default_tab(1))
+            .then_error(DashboardError::not_found());
+    }
+
+    #[test]
+    fn removing_default_tab_falls_back_to_next_tab() {
+        let tab_removed = |n: u128| DashboardEvent::TabRemoved {
+            dashboard_id: sample_dashboard_id(),
+            tab_id: TabId::from_uuid(uuid::Uuid::from_u128(n)),
+            actor: UserId::system(),
+            removed_at: sample_time(),
+        };
+        let tab = |n: u128| TabId::from_uuid(uuid::Uuid::from_u128(n));
+        let mut events = vec![
+            created_event(),
+            tab_added(1),
+            tab_added(2),
+            tab_added(3),
+            default_tab_set(2),
+        ];
+
+        events.push(tab_removed(1));
+        assert_eq!(replay(&events).default_tab_id(), Some(&tab(2)));
+
+        events.push(tab_removed(2));
+        assert_eq!(replay(&events).default_tab_id(), Some(&tab(3)));
+
+        events.push(tab_removed(3));
+        assert_eq!(replay(&events).default_tab_id(), None);
+
+        // With no tabs left, the next tab added becomes the default.
+        events.push(tab_added(4));
+        assert_eq!(replay(&events).default_tab_id(), Some(&tab(4)));
+    }
+
     // --- Full lifecycle ---
 
     #[test]
@@ -957,9 +1374,11 @@ mod tests {
                 dashboard_id: dash_id,
                 workspace_id: ws_id,
                 name: title,
+                actor: UserId::system(),
                 created_at: ts,
             },
             &DashboardState::default(),
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert_eq!(events.len(), 1);
@@ -975,9 +1394,11 @@ mod tests {
             &DashboardCommand::AddTab {
                 dashboard_id: dash_id,
                 tab_info: tab_info.clone(),
+                actor: UserId::system(),
                 added_at: ts,
             },
             &state,
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert_eq!(events.len(), 1);
@@ -992,9 +1413,11 @@ mod tests {
             &DashboardCommand::AddChart {
                 dashboard_id: dash_id,
                 placement: placement.clone(),
+                actor: UserId::system(),
                 added_at: ts,
             },
             &state,
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert_eq!(events.len(), 1);
@@ -1007,9 +1430,11 @@ mod tests {
             &DashboardCommand::AddChart {
                 dashboard_id: dash_id,
                 placement,
+                actor: UserId::system(),
                 added_at: ts,
             },
             &state,
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert!(events.is_empty());
@@ -1021,9 +1446,11 @@ mod tests {
                 dashboard_id: dash_id,
                 chart_id,
                 tab_id,
+                actor: UserId::system(),
                 moved_at: ts,
             },
             &state,
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert_eq!(events.len(), 1);
@@ -1037,9 +1464,11 @@ mod tests {
             &DashboardCommand::RenameDashboard {
                 dashboard_id: dash_id,
                 name: new_name.clone(),
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             &state,
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert_eq!(events.len(), 1);
@@ -1052,9 +1481,11 @@ mod tests {
             &DashboardCommand::RenameDashboard {
                 dashboard_id: dash_id,
                 name: new_name,
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             &state,
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert!(events.is_empty());
@@ -1064,9 +1495,11 @@ mod tests {
             &DashboardCommand::RemoveTab {
                 dashboard_id: dash_id,
                 tab_id,
+                actor: UserId::system(),
                 removed_at: ts,
             },
             &state,
+            DEFAULT_MAX_CHARTS,
         )
         .unwrap();
         assert_eq!(events.len(), 1);
@@ -1076,4 +1509,53 @@ mod tests {
         // Chart was on the removed tab, so it should be filtered out
         assert_eq!(state.placements().unwrap().len(), 0);
     }
+
+    // --- ArchiveDashboard transitions ---
+
+    fn archived_event() -> DashboardEvent {
+        DashboardEvent::DashboardArchived {
+            dashboard_id: sample_dashboard_id(),
+            actor: UserId::system(),
+            archived_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn archive_dashboard_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(vec![created_event()])
+            .when(DashboardCommand::ArchiveDashboard {
+                dashboard_id: sample_dashboard_id(),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            })
+            .then(vec![archived_event()]);
+    }
+
+    #[test]
+    fn archive_dashboard_twice_is_idempotent() {
+        dashboard_decider().assert_no_events(
+            vec![created_event(), archived_event()],
+            DashboardCommand::ArchiveDashboard {
+                dashboard_id: sample_dashboard_id(),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            },
+        );
+    }
+
+    #[test]
+    fn add_chart_to_archived_dashboard_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(dashboard_decider())
+            .given(vec![created_event(), archived_event()])
+            .when(DashboardCommand::AddChart {
+                dashboard_id: sample_dashboard_id(),
+                placement: sample_placement(),
+                actor: UserId::system(),
+                added_at: sample_time(),
+            })
+            .then_error(DashboardError::archived());
+    }
 }
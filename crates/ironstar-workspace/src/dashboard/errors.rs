@@ -7,6 +7,8 @@ use std::backtrace::Backtrace;
 use std::fmt;
 use uuid::Uuid;
 
+use super::values::ChartId;
+
 /// Domain error for the Dashboard aggregate with UUID tracking.
 #[derive(Debug)]
 pub struct DashboardError {
@@ -29,6 +31,15 @@ pub enum DashboardErrorKind {
 
     /// Chart not found in this dashboard.
     ChartNotFound,
+
+    /// Chart is already placed in this dashboard with different properties.
+    ChartAlreadyPlaced { chart_id: ChartId },
+
+    /// Dashboard is archived and can no longer be modified.
+    Archived,
+
+    /// Dashboard already holds the maximum number of charts.
+    TooManyCharts { max: usize },
 }
 
 impl DashboardError {
@@ -81,6 +92,18 @@ impl DashboardError {
     pub fn chart_not_found() -> Self {
         Self::new(DashboardErrorKind::ChartNotFound)
     }
+
+    pub fn chart_already_placed(chart_id: ChartId) -> Self {
+        Self::new(DashboardErrorKind::ChartAlreadyPlaced { chart_id })
+    }
+
+    pub fn archived() -> Self {
+        Self::new(DashboardErrorKind::Archived)
+    }
+
+    pub fn too_many_charts(max: usize) -> Self {
+        Self::new(DashboardErrorKind::TooManyCharts { max })
+    }
 }
 
 impl fmt::Display for DashboardError {
@@ -98,6 +121,18 @@ impl fmt::Display for DashboardError {
             DashboardErrorKind::ChartNotFound => {
                 write!(f, "chart not found in dashboard")
             }
+            DashboardErrorKind::ChartAlreadyPlaced { chart_id } => {
+                write!(
+                    f,
+                    "chart {chart_id} already placed in dashboard with different properties"
+                )
+            }
+            DashboardErrorKind::Archived => {
+                write!(f, "dashboard is archived")
+            }
+            DashboardErrorKind::TooManyCharts { max } => {
+                write!(f, "dashboard already holds the maximum of {max} charts")
+            }
         }
     }
 }
@@ -134,6 +169,15 @@ mod tests {
             DashboardError::chart_not_found().to_string(),
             "chart not found in dashboard"
         );
+        assert_eq!(
+            DashboardError::chart_already_placed(ChartId::from_uuid(Uuid::nil())).to_string(),
+            "chart 00000000-0000-0000-0000-000000000000 already placed in dashboard \
+             with different properties"
+        );
+        assert_eq!(
+            DashboardError::too_many_charts(50).to_string(),
+            "dashboard already holds the maximum of 50 charts"
+        );
     }
 
     #[test]
@@ -10,7 +10,8 @@ use ts_rs::TS;
 use super::values::{ChartId, ChartPlacement, DashboardId, TabId, TabInfo};
 use crate::workspace::WorkspaceId;
 use ironstar_core::DashboardTitle;
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
+use ironstar_shared_kernel::UserId;
 
 /// Events emitted by the Dashboard aggregate.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
@@ -22,6 +23,8 @@ pub enum DashboardEvent {
         dashboard_id: DashboardId,
         workspace_id: WorkspaceId,
         name: DashboardTitle,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         created_at: DateTime<Utc>,
     },
 
@@ -29,6 +32,8 @@ pub enum DashboardEvent {
     DashboardRenamed {
         dashboard_id: DashboardId,
         name: DashboardTitle,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         renamed_at: DateTime<Utc>,
     },
 
@@ -36,6 +41,8 @@ pub enum DashboardEvent {
     ChartAdded {
         dashboard_id: DashboardId,
         placement: ChartPlacement,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         added_at: DateTime<Utc>,
     },
 
@@ -43,6 +50,8 @@ pub enum DashboardEvent {
     ChartRemoved {
         dashboard_id: DashboardId,
         chart_id: ChartId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         removed_at: DateTime<Utc>,
     },
 
@@ -50,6 +59,8 @@ pub enum DashboardEvent {
     TabAdded {
         dashboard_id: DashboardId,
         tab_info: TabInfo,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         added_at: DateTime<Utc>,
     },
 
@@ -57,6 +68,8 @@ pub enum DashboardEvent {
     TabRemoved {
         dashboard_id: DashboardId,
         tab_id: TabId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         removed_at: DateTime<Utc>,
     },
 
@@ -65,8 +78,27 @@ pub enum DashboardEvent {
         dashboard_id: DashboardId,
         chart_id: ChartId,
         tab_id: TabId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         moved_at: DateTime<Utc>,
     },
+
+    /// A tab was chosen as the one the UI opens first.
+    DefaultTabSet {
+        dashboard_id: DashboardId,
+        tab_id: TabId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        set_at: DateTime<Utc>,
+    },
+
+    /// The dashboard was archived (terminal).
+    DashboardArchived {
+        dashboard_id: DashboardId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        archived_at: DateTime<Utc>,
+    },
 }
 
 impl DashboardEvent {
@@ -80,7 +112,9 @@ impl DashboardEvent {
             | Self::ChartRemoved { dashboard_id, .. }
             | Self::TabAdded { dashboard_id, .. }
             | Self::TabRemoved { dashboard_id, .. }
-            | Self::ChartMovedToTab { dashboard_id, .. } => *dashboard_id,
+            | Self::ChartMovedToTab { dashboard_id, .. }
+            | Self::DefaultTabSet { dashboard_id, .. }
+            | Self::DashboardArchived { dashboard_id, .. } => *dashboard_id,
         }
     }
 
@@ -95,6 +129,26 @@ impl DashboardEvent {
             Self::TabAdded { .. } => "TabAdded",
             Self::TabRemoved { .. } => "TabRemoved",
             Self::ChartMovedToTab { .. } => "ChartMovedToTab",
+            Self::DefaultTabSet { .. } => "DefaultTabSet",
+            Self::DashboardArchived { .. } => "DashboardArchived",
+        }
+    }
+
+    /// Who performed the action.
+    ///
+    /// Events stored before actors were recorded report the system actor.
+    #[must_use]
+    pub fn actor(&self) -> UserId {
+        match self {
+            Self::DashboardCreated { actor, .. }
+            | Self::DashboardRenamed { actor, .. }
+            | Self::ChartAdded { actor, .. }
+            | Self::ChartRemoved { actor, .. }
+            | Self::TabAdded { actor, .. }
+            | Self::TabRemoved { actor, .. }
+            | Self::ChartMovedToTab { actor, .. }
+            | Self::DefaultTabSet { actor, .. }
+            | Self::DashboardArchived { actor, .. } => *actor,
         }
     }
 
@@ -107,7 +161,7 @@ impl DashboardEvent {
 
 impl Identifier for DashboardEvent {
     fn identifier(&self) -> String {
-        format!("dashboard_{}", self.dashboard_id())
+        self.dashboard_id().identifier()
     }
 }
 
@@ -117,6 +171,22 @@ impl EventType for DashboardEvent {
     }
 }
 
+impl Timestamped for DashboardEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::DashboardCreated { created_at, .. } => *created_at,
+            Self::DashboardRenamed { renamed_at, .. } => *renamed_at,
+            Self::ChartAdded { added_at, .. } | Self::TabAdded { added_at, .. } => *added_at,
+            Self::ChartRemoved { removed_at, .. } | Self::TabRemoved { removed_at, .. } => {
+                *removed_at
+            }
+            Self::ChartMovedToTab { moved_at, .. } => *moved_at,
+            Self::DefaultTabSet { set_at, .. } => *set_at,
+            Self::DashboardArchived { archived_at, .. } => *archived_at,
+        }
+    }
+}
+
 impl DeciderType for DashboardEvent {
     fn decider_type(&self) -> String {
         "Dashboard".to_string()
@@ -125,7 +195,7 @@ impl DeciderType for DashboardEvent {
 
 impl IsFinal for DashboardEvent {
     fn is_final(&self) -> bool {
-        false
+        matches!(self, Self::DashboardArchived { .. })
     }
 }
 
@@ -150,6 +220,7 @@ mod tests {
             dashboard_id: sample_dash_id(),
             workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
             name: DashboardTitle::new("Test").unwrap(),
+            actor: UserId::system(),
             created_at: sample_time(),
         };
 
@@ -164,6 +235,7 @@ mod tests {
             dashboard_id: sample_dash_id(),
             workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
             name: DashboardTitle::new("Test").unwrap(),
+            actor: UserId::system(),
             created_at: sample_time(),
         };
 
@@ -181,6 +253,7 @@ mod tests {
                     dashboard_id: sample_dash_id(),
                     workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
                     name: DashboardTitle::new("Test").unwrap(),
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 "DashboardCreated",
@@ -189,6 +262,7 @@ mod tests {
                 DashboardEvent::DashboardRenamed {
                     dashboard_id: sample_dash_id(),
                     name: DashboardTitle::new("New Name").unwrap(),
+                    actor: UserId::system(),
                     renamed_at: sample_time(),
                 },
                 "DashboardRenamed",
@@ -197,6 +271,7 @@ mod tests {
                 DashboardEvent::ChartRemoved {
                     dashboard_id: sample_dash_id(),
                     chart_id: ChartId::from_uuid(uuid::Uuid::nil()),
+                    actor: UserId::system(),
                     removed_at: sample_time(),
                 },
                 "ChartRemoved",
@@ -205,6 +280,7 @@ mod tests {
                 DashboardEvent::TabRemoved {
                     dashboard_id: sample_dash_id(),
                     tab_id: TabId::from_uuid(uuid::Uuid::nil()),
+                    actor: UserId::system(),
                     removed_at: sample_time(),
                 },
                 "TabRemoved",
@@ -214,10 +290,20 @@ mod tests {
                     dashboard_id: sample_dash_id(),
                     chart_id: ChartId::from_uuid(uuid::Uuid::nil()),
                     tab_id: TabId::from_uuid(uuid::Uuid::nil()),
+                    actor: UserId::system(),
                     moved_at: sample_time(),
                 },
                 "ChartMovedToTab",
             ),
+            (
+                DashboardEvent::DefaultTabSet {
+                    dashboard_id: sample_dash_id(),
+                    tab_id: TabId::from_uuid(uuid::Uuid::nil()),
+                    actor: UserId::system(),
+                    set_at: sample_time(),
+                },
+                "DefaultTabSet",
+            ),
         ];
 
         for (event, expected_type) in events {
@@ -235,11 +321,13 @@ mod tests {
                 dashboard_id: sample_dash_id(),
                 workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
                 name: DashboardTitle::new("Test").unwrap(),
+                actor: UserId::system(),
                 created_at: sample_time(),
             },
             DashboardEvent::ChartRemoved {
                 dashboard_id: sample_dash_id(),
                 chart_id: ChartId::from_uuid(uuid::Uuid::nil()),
+                actor: UserId::system(),
                 removed_at: sample_time(),
             },
         ];
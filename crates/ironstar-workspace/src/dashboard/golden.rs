@@ -0,0 +1,297 @@
+//! Golden tests for the serialized form of [`DashboardEvent`].
+//!
+//! Each variant is produced by the decider from a command, then the whole set
+//! is serialized and compared against `goldens/dashboard_events.json`.
+//! `ChartAdded` appears twice, once per [`ChartSource`] shape. A diff in the
+//! golden means stored dashboards may no longer replay: keep the old shape
+//! readable (or add an upcaster) before regenerating with
+//! `IRONSTAR_UPDATE_GOLDENS=1 cargo test`.
+
+use chrono::{DateTime, Utc};
+use ironstar_analytics::{ChartConfig, ChartType};
+use ironstar_core::{DashboardTitle, DeciderTestSpecification, GridSize, TabTitle, assert_golden};
+
+use super::{
+    ChartDefinitionRef, ChartId, ChartPlacement, ChartSource, DashboardCommand, DashboardEvent,
+    DashboardId, GridPosition, TabId, TabInfo, dashboard_decider,
+};
+use crate::workspace::{UserId, WorkspaceId};
+
+const GOLDEN: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/goldens/dashboard_events.json");
+
+fn dashboard_id() -> DashboardId {
+    DashboardId::from_uuid(uuid::Uuid::nil())
+}
+
+fn workspace_id() -> WorkspaceId {
+    WorkspaceId::from_uuid(uuid::Uuid::nil())
+}
+
+fn actor() -> UserId {
+    UserId::from_uuid(uuid::Uuid::from_u128(1))
+}
+
+fn chart_id(n: u128) -> ChartId {
+    ChartId::from_uuid(uuid::Uuid::from_u128(n))
+}
+
+fn tab_id() -> TabId {
+    TabId::from_uuid(uuid::Uuid::from_u128(20))
+}
+
+fn second_tab_id() -> TabId {
+    TabId::from_uuid(uuid::Uuid::from_u128(21))
+}
+
+fn at() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn title(raw: &str) -> DashboardTitle {
+    DashboardTitle::new(raw).unwrap()
+}
+
+fn placement(chart: u128, source: ChartSource, row: u32) -> ChartPlacement {
+    ChartPlacement {
+        chart_id: chart_id(chart),
+        source,
+        position: GridPosition { row, col: 0 },
+        size: GridSize::new(4, 3).unwrap(),
+        tab_id: None,
+    }
+}
+
+/// Assert the decider emits exactly `event` for `command` after `given`.
+fn decided(
+    given: Vec<DashboardEvent>,
+    command: DashboardCommand,
+    event: DashboardEvent,
+) -> DashboardEvent {
+    DeciderTestSpecification::default()
+        .for_decider(dashboard_decider())
+        .given(given)
+        .when(command)
+        .then(vec![event.clone()]);
+    event
+}
+
+/// One instance of every variant, in declaration order.
+fn one_of_each() -> Vec<DashboardEvent> {
+    let created = decided(
+        vec![],
+        DashboardCommand::CreateDashboard {
+            dashboard_id: dashboard_id(),
+            workspace_id: workspace_id(),
+            name: title("Sales"),
+            actor: actor(),
+            created_at: at(),
+        },
+        DashboardEvent::DashboardCreated {
+            dashboard_id: dashboard_id(),
+            workspace_id: workspace_id(),
+            name: title("Sales"),
+            actor: actor(),
+            created_at: at(),
+        },
+    );
+    let referenced = placement(
+        10,
+        ChartSource::Reference(ChartDefinitionRef {
+            ref_id: "chart-def-001".to_string(),
+            chart_type_hint: Some(ChartType::Bar),
+        }),
+        0,
+    );
+    let inline = placement(
+        11,
+        ChartSource::Inline(
+            ChartConfig::new(ChartType::Line)
+                .with_title("Daily totals")
+                .with_x_axis("day")
+                .with_y_axis("total"),
+        ),
+        3,
+    );
+    let chart_added = |placement: ChartPlacement| {
+        decided(
+            vec![created.clone()],
+            DashboardCommand::AddChart {
+                dashboard_id: dashboard_id(),
+                placement: placement.clone(),
+                actor: actor(),
+                added_at: at(),
+            },
+            DashboardEvent::ChartAdded {
+                dashboard_id: dashboard_id(),
+                placement,
+                actor: actor(),
+                added_at: at(),
+            },
+        )
+    };
+    let referenced_added = chart_added(referenced);
+    let inline_added = chart_added(inline);
+    let tab_added = decided(
+        vec![created.clone()],
+        DashboardCommand::AddTab {
+            dashboard_id: dashboard_id(),
+            tab_info: TabInfo {
+                tab_id: tab_id(),
+                name: TabTitle::new("Trends").unwrap(),
+            },
+            actor: actor(),
+            added_at: at(),
+        },
+        DashboardEvent::TabAdded {
+            dashboard_id: dashboard_id(),
+            tab_info: TabInfo {
+                tab_id: tab_id(),
+                name: TabTitle::new("Trends").unwrap(),
+            },
+            actor: actor(),
+            added_at: at(),
+        },
+    );
+    let second_tab_added = DashboardEvent::TabAdded {
+        dashboard_id: dashboard_id(),
+        tab_info: TabInfo {
+            tab_id: second_tab_id(),
+            name: TabTitle::new("Totals").unwrap(),
+        },
+        actor: actor(),
+        added_at: at(),
+    };
+
+    vec![
+        created.clone(),
+        decided(
+            vec![created.clone()],
+            DashboardCommand::RenameDashboard {
+                dashboard_id: dashboard_id(),
+                name: title("Sales 2024"),
+                actor: actor(),
+                renamed_at: at(),
+            },
+            DashboardEvent::DashboardRenamed {
+                dashboard_id: dashboard_id(),
+                name: title("Sales 2024"),
+                actor: actor(),
+                renamed_at: at(),
+            },
+        ),
+        referenced_added.clone(),
+        inline_added,
+        decided(
+            vec![created.clone(), referenced_added.clone()],
+            DashboardCommand::RemoveChart {
+                dashboard_id: dashboard_id(),
+                chart_id: chart_id(10),
+                actor: actor(),
+                removed_at: at(),
+            },
+            DashboardEvent::ChartRemoved {
+                dashboard_id: dashboard_id(),
+                chart_id: chart_id(10),
+                actor: actor(),
+                removed_at: at(),
+            },
+        ),
+        tab_added.clone(),
+        decided(
+            vec![created.clone(), tab_added.clone()],
+            DashboardCommand::RemoveTab {
+                dashboard_id: dashboard_id(),
+                tab_id: tab_id(),
+                actor: actor(),
+                removed_at: at(),
+            },
+            DashboardEvent::TabRemoved {
+                dashboard_id: dashboard_id(),
+                tab_id: tab_id(),
+                actor: actor(),
+                removed_at: at(),
+            },
+        ),
+        decided(
+            vec![created.clone(), referenced_added, tab_added.clone()],
+            DashboardCommand::MoveChartToTab {
+                dashboard_id: dashboard_id(),
+                chart_id: chart_id(10),
+                tab_id: tab_id(),
+                actor: actor(),
+                moved_at: at(),
+            },
+            DashboardEvent::ChartMovedToTab {
+                dashboard_id: dashboard_id(),
+                chart_id: chart_id(10),
+                tab_id: tab_id(),
+                actor: actor(),
+                moved_at: at(),
+            },
+        ),
+        decided(
+            vec![created.clone(), tab_added, second_tab_added],
+            DashboardCommand::SetDefaultTab {
+                dashboard_id: dashboard_id(),
+                tab_id: second_tab_id(),
+                actor: actor(),
+                set_at: at(),
+            },
+            DashboardEvent::DefaultTabSet {
+                dashboard_id: dashboard_id(),
+                tab_id: second_tab_id(),
+                actor: actor(),
+                set_at: at(),
+            },
+        ),
+        decided(
+            vec![created],
+            DashboardCommand::ArchiveDashboard {
+                dashboard_id: dashboard_id(),
+                actor: actor(),
+                archived_at: at(),
+            },
+            DashboardEvent::DashboardArchived {
+                dashboard_id: dashboard_id(),
+                actor: actor(),
+                archived_at: at(),
+            },
+        ),
+    ]
+}
+
+#[test]
+fn every_variant_is_covered() {
+    let events = one_of_each();
+    // Adding a variant fails to compile here until it is given a golden.
+    let covered = events
+        .iter()
+        .map(|event| match event {
+            DashboardEvent::DashboardCreated { .. }
+            | DashboardEvent::DashboardRenamed { .. }
+            | DashboardEvent::ChartAdded { .. }
+            | DashboardEvent::ChartRemoved { .. }
+            | DashboardEvent::TabAdded { .. }
+            | DashboardEvent::TabRemoved { .. }
+            | DashboardEvent::ChartMovedToTab { .. }
+            | DashboardEvent::DefaultTabSet { .. }
+            | DashboardEvent::DashboardArchived { .. } => event.event_type_str(),
+        })
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(covered.len(), 9);
+}
+
+#[test]
+fn serialization_matches_golden() {
+    let events = one_of_each();
+    let json = serde_json::to_string_pretty(&events).unwrap() + "\n";
+    assert_golden(GOLDEN, &json);
+
+    // The checked-in golden must also read back as the events it was
+    // written from, as stored events will be.
+    let golden = std::fs::read_to_string(GOLDEN).unwrap();
+    let decoded: Vec<DashboardEvent> = serde_json::from_str(&golden).unwrap();
+    assert_eq!(decoded, events);
+}
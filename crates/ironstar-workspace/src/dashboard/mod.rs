@@ -49,11 +49,17 @@ pub mod events;
 pub mod state;
 pub mod values;
 
+#[cfg(test)]
+mod golden;
+
 pub use commands::DashboardCommand;
-pub use decider::{DashboardDecider, dashboard_decider};
+pub use decider::{
+    DEFAULT_MAX_CHARTS, DashboardDecider, dashboard_decider, dashboard_decider_with_max_charts,
+};
 pub use errors::{DashboardError, DashboardErrorKind};
 pub use events::DashboardEvent;
 pub use state::DashboardState;
 pub use values::{
-    ChartDefinitionRef, ChartId, ChartPlacement, DashboardId, GridPosition, TabId, TabInfo,
+    ChartDefinitionRef, ChartDefinitionResolver, ChartId, ChartPlacement, ChartSource, DashboardId,
+    GridPosition, TabId, TabInfo,
 };
@@ -3,7 +3,7 @@
 //! State is derived from events via replay. Uses a sum type enum following
 //! the WorkspacePreferences aggregate pattern for clean state machine semantics.
 
-use super::values::{ChartPlacement, DashboardId, TabInfo};
+use super::values::{ChartId, ChartPlacement, DashboardId, GridPosition, TabId, TabInfo};
 use crate::workspace::WorkspaceId;
 use ironstar_core::DashboardTitle;
 
@@ -23,6 +23,11 @@ use ironstar_core::DashboardTitle;
 ///                              ▼
 ///                     ┌───────────────────┐
 ///                     │  DashboardExists  │ (updated fields)
+///                     └────────┬──────────┘
+///                              │ ArchiveDashboard
+///                              ▼
+///                     ┌───────────────────┐
+///                     │     Archived      │ (terminal)
 ///                     └───────────────────┘
 /// ```
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -43,31 +48,51 @@ pub enum DashboardState {
         placements: Vec<ChartPlacement>,
         /// Tabs for organizing charts.
         tabs: Vec<TabInfo>,
+        /// Tab the UI opens first: the first tab added unless set otherwise.
+        default_tab_id: Option<TabId>,
+    },
+
+    /// Dashboard was archived (terminal); only its identity is retained.
+    Archived {
+        /// Identifier of the archived dashboard.
+        dashboard_id: DashboardId,
+        /// The workspace the dashboard belonged to.
+        workspace_id: WorkspaceId,
     },
 }
 
 impl DashboardState {
-    /// Check if the dashboard exists.
+    /// Check if the dashboard exists and has not been archived.
     #[must_use]
     pub fn exists(&self) -> bool {
         matches!(self, Self::DashboardExists { .. })
     }
 
-    /// Get the dashboard ID, if it exists.
+    /// Check if the dashboard has been archived.
+    #[must_use]
+    pub fn is_archived(&self) -> bool {
+        matches!(self, Self::Archived { .. })
+    }
+
+    /// Get the dashboard ID, if it was created.
     #[must_use]
     pub fn dashboard_id(&self) -> Option<&DashboardId> {
         match self {
             Self::NoDashboard => None,
-            Self::DashboardExists { dashboard_id, .. } => Some(dashboard_id),
+            Self::DashboardExists { dashboard_id, .. } | Self::Archived { dashboard_id, .. } => {
+                Some(dashboard_id)
+            }
         }
     }
 
-    /// Get the workspace ID, if it exists.
+    /// Get the workspace ID, if it was created.
     #[must_use]
     pub fn workspace_id(&self) -> Option<&WorkspaceId> {
         match self {
             Self::NoDashboard => None,
-            Self::DashboardExists { workspace_id, .. } => Some(workspace_id),
+            Self::DashboardExists { workspace_id, .. } | Self::Archived { workspace_id, .. } => {
+                Some(workspace_id)
+            }
         }
     }
 
@@ -75,7 +100,7 @@ impl DashboardState {
     #[must_use]
     pub fn name(&self) -> Option<&DashboardTitle> {
         match self {
-            Self::NoDashboard => None,
+            Self::NoDashboard | Self::Archived { .. } => None,
             Self::DashboardExists { name, .. } => Some(name),
         }
     }
@@ -84,7 +109,7 @@ impl DashboardState {
     #[must_use]
     pub fn placements(&self) -> Option<&[ChartPlacement]> {
         match self {
-            Self::NoDashboard => None,
+            Self::NoDashboard | Self::Archived { .. } => None,
             Self::DashboardExists { placements, .. } => Some(placements),
         }
     }
@@ -93,17 +118,88 @@ impl DashboardState {
     #[must_use]
     pub fn tabs(&self) -> Option<&[TabInfo]> {
         match self {
-            Self::NoDashboard => None,
+            Self::NoDashboard | Self::Archived { .. } => None,
             Self::DashboardExists { tabs, .. } => Some(tabs),
         }
     }
+
+    /// Get the default tab, if the dashboard exists and has one.
+    #[must_use]
+    pub fn default_tab_id(&self) -> Option<&TabId> {
+        match self {
+            Self::NoDashboard | Self::Archived { .. } => None,
+            Self::DashboardExists { default_tab_id, .. } => default_tab_id.as_ref(),
+        }
+    }
+
+    /// Find the placement of a chart, if the dashboard exists and holds it.
+    #[must_use]
+    pub fn chart_placement(&self, chart_id: &ChartId) -> Option<&ChartPlacement> {
+        self.placements()?
+            .iter()
+            .find(|placement| placement.chart_id == *chart_id)
+    }
+
+    /// Chart placements assigned to a tab, in placement order.
+    ///
+    /// Empty when the dashboard does not exist or the tab holds no charts.
+    #[must_use]
+    pub fn charts_in_tab(&self, tab_id: &TabId) -> Vec<&ChartPlacement> {
+        self.placements()
+            .unwrap_or_default()
+            .iter()
+            .filter(|placement| placement.tab_id.as_ref() == Some(tab_id))
+            .collect()
+    }
+
+    /// First position on the default view below every chart placed there.
+    ///
+    /// Column 0 of the first row no default-view chart reaches, so a chart
+    /// placed there never overlaps another. `None` when the dashboard does
+    /// not exist.
+    #[must_use]
+    pub fn next_free_position(&self) -> Option<GridPosition> {
+        let row = self
+            .placements()?
+            .iter()
+            .filter(|placement| placement.tab_id.is_none())
+            .map(|placement| {
+                placement
+                    .position
+                    .row
+                    .saturating_add(placement.size.height())
+            })
+            .max()
+            .unwrap_or(0);
+        Some(GridPosition { row, col: 0 })
+    }
+}
+
+/// The default tab once `removed` is taken out of `tabs`.
+///
+/// Removing the default tab falls back to the tab after it, or the one
+/// before it when it was last, or none when it was the only tab. Removing
+/// any other tab keeps the default.
+pub(crate) fn default_tab_after_removal(
+    tabs: &[TabInfo],
+    default_tab_id: Option<TabId>,
+    removed: &TabId,
+) -> Option<TabId> {
+    if default_tab_id.as_ref() != Some(removed) {
+        return default_tab_id;
+    }
+    let index = tabs.iter().position(|tab| tab.tab_id == *removed)?;
+    tabs.get(index + 1)
+        .or_else(|| index.checked_sub(1).and_then(|before| tabs.get(before)))
+        .map(|tab| tab.tab_id)
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::values::{ChartDefinitionRef, ChartSource, GridPosition};
     use super::*;
     use crate::workspace::WorkspaceId;
-    use ironstar_core::DashboardTitle;
+    use ironstar_core::{DashboardTitle, GridSize, TabTitle};
 
     #[test]
     fn default_state_is_no_dashboard() {
@@ -114,6 +210,7 @@ mod tests {
         assert!(state.name().is_none());
         assert!(state.placements().is_none());
         assert!(state.tabs().is_none());
+        assert!(state.default_tab_id().is_none());
     }
 
     #[test]
@@ -128,6 +225,7 @@ mod tests {
             name: title.clone(),
             placements: vec![],
             tabs: vec![],
+            default_tab_id: None,
         };
 
         assert!(state.exists());
@@ -137,4 +235,152 @@ mod tests {
         assert_eq!(state.placements().unwrap().len(), 0);
         assert_eq!(state.tabs().unwrap().len(), 0);
     }
+
+    fn placement(n: u128, tab_id: Option<TabId>) -> ChartPlacement {
+        ChartPlacement {
+            chart_id: ChartId::from_uuid(uuid::Uuid::from_u128(n)),
+            source: ChartSource::Reference(ChartDefinitionRef {
+                ref_id: format!("chart-{n}"),
+                chart_type_hint: None,
+            }),
+            position: GridPosition { row: 0, col: 0 },
+            size: GridSize::new(4, 3).unwrap(),
+            tab_id,
+        }
+    }
+
+    fn dashboard_with_two_tabs() -> (DashboardState, TabId, TabId) {
+        let overview = TabId::from_uuid(uuid::Uuid::from_u128(100));
+        let details = TabId::from_uuid(uuid::Uuid::from_u128(200));
+        let state = DashboardState::DashboardExists {
+            dashboard_id: DashboardId::from_uuid(uuid::Uuid::nil()),
+            workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
+            name: DashboardTitle::new("Test Dashboard").unwrap(),
+            placements: vec![
+                placement(1, Some(overview)),
+                placement(2, Some(details)),
+                placement(3, Some(overview)),
+                placement(4, None),
+            ],
+            tabs: vec![],
+            default_tab_id: None,
+        };
+        (state, overview, details)
+    }
+
+    #[test]
+    fn chart_placement_finds_chart_by_id() {
+        let (state, _, details) = dashboard_with_two_tabs();
+        let chart_id = ChartId::from_uuid(uuid::Uuid::from_u128(2));
+
+        let found = state.chart_placement(&chart_id).unwrap();
+
+        assert_eq!(found.chart_id, chart_id);
+        assert_eq!(found.tab_id, Some(details));
+        assert!(
+            state
+                .chart_placement(&ChartId::from_uuid(uuid::Uuid::from_u128(9)))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn charts_in_tab_filters_by_tab() {
+        let (state, overview, details) = dashboard_with_two_tabs();
+
+        let overview_charts: Vec<ChartId> = state
+            .charts_in_tab(&overview)
+            .iter()
+            .map(|p| p.chart_id)
+            .collect();
+
+        assert_eq!(
+            overview_charts,
+            vec![
+                ChartId::from_uuid(uuid::Uuid::from_u128(1)),
+                ChartId::from_uuid(uuid::Uuid::from_u128(3)),
+            ]
+        );
+        assert_eq!(state.charts_in_tab(&details).len(), 1);
+        assert!(
+            state
+                .charts_in_tab(&TabId::from_uuid(uuid::Uuid::from_u128(300)))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn lookups_on_missing_dashboard_are_empty() {
+        let state = DashboardState::NoDashboard;
+        let chart_id = ChartId::from_uuid(uuid::Uuid::nil());
+
+        assert!(state.chart_placement(&chart_id).is_none());
+        assert!(
+            state
+                .charts_in_tab(&TabId::from_uuid(uuid::Uuid::nil()))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn next_free_position_is_below_default_view_charts() {
+        let (mut state, overview, _) = dashboard_with_two_tabs();
+        assert_eq!(
+            state.next_free_position(),
+            Some(GridPosition { row: 3, col: 0 })
+        );
+
+        // Charts on tabs do not occupy the default view.
+        if let DashboardState::DashboardExists { placements, .. } = &mut state {
+            placements.retain(|placement| placement.tab_id == Some(overview));
+        }
+        assert_eq!(
+            state.next_free_position(),
+            Some(GridPosition { row: 0, col: 0 })
+        );
+        assert_eq!(DashboardState::NoDashboard.next_free_position(), None);
+    }
+
+    fn tab(n: u128) -> TabInfo {
+        TabInfo {
+            tab_id: TabId::from_uuid(uuid::Uuid::from_u128(n)),
+            name: TabTitle::new(format!("Tab {n}")).unwrap(),
+        }
+    }
+
+    #[test]
+    fn removing_default_tab_falls_back_to_next_tab() {
+        let tabs = [tab(1), tab(2), tab(3)];
+        let id = |n: u128| TabId::from_uuid(uuid::Uuid::from_u128(n));
+
+        assert_eq!(
+            default_tab_after_removal(&tabs, Some(id(1)), &id(1)),
+            Some(id(2))
+        );
+        assert_eq!(
+            default_tab_after_removal(&tabs, Some(id(2)), &id(2)),
+            Some(id(3))
+        );
+        // The last tab has no successor, so the one before it takes over.
+        assert_eq!(
+            default_tab_after_removal(&tabs, Some(id(3)), &id(3)),
+            Some(id(2))
+        );
+        assert_eq!(
+            default_tab_after_removal(&[tab(1)], Some(id(1)), &id(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn removing_another_tab_keeps_default() {
+        let tabs = [tab(1), tab(2)];
+        let id = |n: u128| TabId::from_uuid(uuid::Uuid::from_u128(n));
+
+        assert_eq!(
+            default_tab_after_removal(&tabs, Some(id(1)), &id(2)),
+            Some(id(1))
+        );
+        assert_eq!(default_tab_after_removal(&tabs, None, &id(1)), None);
+    }
 }
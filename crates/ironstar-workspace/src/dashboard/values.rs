@@ -4,16 +4,19 @@
 //! - `TabId`: Unique identifier for a tab within a dashboard
 //! - `ChartId`: Unique identifier for a chart within a dashboard
 //! - `ChartDefinitionRef`: Reference to an Analytics ChartDefinition
+//! - `ChartSource`: Referenced or inline chart configuration
 //! - `GridPosition`: Zero-indexed row/col grid position
 //! - `ChartPlacement`: Full chart placement including position, size, and tab
 //! - `TabInfo`: Tab metadata with ID and title
 
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use ts_rs::TS;
 use uuid::Uuid;
 
-use ironstar_analytics::ChartType;
-use ironstar_core::{GridSize, TabTitle};
+use ironstar_analytics::{ChartConfig, ChartType};
+use ironstar_core::{GridSize, Identifier, TabTitle};
 
 // ============================================================================
 // DashboardId - Unique dashboard identifier
@@ -33,7 +36,7 @@ impl DashboardId {
     /// Generate a new random DashboardId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a DashboardId.
@@ -64,6 +67,13 @@ impl std::fmt::Display for DashboardId {
     }
 }
 
+/// Stream id of the Dashboard aggregate identified by this id: `dashboard_{uuid}`.
+impl Identifier for DashboardId {
+    fn identifier(&self) -> String {
+        format!("dashboard_{}", self.0)
+    }
+}
+
 // ============================================================================
 // TabId - Unique tab identifier
 // ============================================================================
@@ -78,7 +88,7 @@ impl TabId {
     /// Generate a new random TabId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a TabId.
@@ -120,7 +130,7 @@ impl ChartId {
     /// Generate a new random ChartId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a ChartId.
@@ -166,6 +176,75 @@ pub struct ChartDefinitionRef {
     pub chart_type_hint: Option<ChartType>,
 }
 
+// ============================================================================
+// ChartSource - Where a placement's chart configuration comes from
+// ============================================================================
+
+/// Configuration source of a placed chart.
+///
+/// Most placements reference a shared Analytics ChartDefinition; small
+/// dashboards can embed a one-off configuration instead, which needs no
+/// resolution. Serialized untagged: a reference has `ref_id`, an inline
+/// configuration has `chart_type`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "domain/")]
+#[serde(untagged)]
+pub enum ChartSource {
+    /// Configuration held by an Analytics ChartDefinition.
+    Reference(ChartDefinitionRef),
+    /// Configuration embedded in the placement.
+    Inline(ChartConfig),
+}
+
+impl ChartSource {
+    /// Chart type, if known without resolving a reference.
+    #[must_use]
+    pub fn chart_type(&self) -> Option<ChartType> {
+        match self {
+            Self::Reference(def_ref) => def_ref.chart_type_hint,
+            Self::Inline(config) => Some(config.chart_type),
+        }
+    }
+
+    /// Chart configuration to render.
+    ///
+    /// Inline sources return their own configuration without consulting
+    /// `resolver`. Returns `None` when a reference does not resolve.
+    pub fn resolve<'a, R>(&'a self, resolver: &R) -> Option<Cow<'a, ChartConfig>>
+    where
+        R: ChartDefinitionResolver + ?Sized,
+    {
+        match self {
+            Self::Inline(config) => Some(Cow::Borrowed(config)),
+            Self::Reference(def_ref) => resolver.resolve(def_ref).map(Cow::Owned),
+        }
+    }
+}
+
+impl From<ChartDefinitionRef> for ChartSource {
+    fn from(def_ref: ChartDefinitionRef) -> Self {
+        Self::Reference(def_ref)
+    }
+}
+
+impl From<ChartConfig> for ChartSource {
+    fn from(config: ChartConfig) -> Self {
+        Self::Inline(config)
+    }
+}
+
+/// Looks up the configuration of a referenced chart definition.
+pub trait ChartDefinitionResolver {
+    /// Configuration of `def_ref`, or `None` if it does not exist.
+    fn resolve(&self, def_ref: &ChartDefinitionRef) -> Option<ChartConfig>;
+}
+
+impl ChartDefinitionResolver for HashMap<String, ChartConfig> {
+    fn resolve(&self, def_ref: &ChartDefinitionRef) -> Option<ChartConfig> {
+        self.get(&def_ref.ref_id).cloned()
+    }
+}
+
 // ============================================================================
 // GridPosition - Zero-indexed row/col position
 // ============================================================================
@@ -193,8 +272,12 @@ pub struct GridPosition {
 pub struct ChartPlacement {
     /// Unique identifier for this chart placement.
     pub chart_id: ChartId,
-    /// Reference to the Analytics ChartDefinition.
-    pub chart_def_ref: ChartDefinitionRef,
+    /// Chart configuration, referenced or inline.
+    ///
+    /// Placements recorded before inline configuration existed stored a
+    /// bare reference under `chart_def_ref`.
+    #[serde(alias = "chart_def_ref")]
+    pub source: ChartSource,
     /// Grid position of the chart.
     pub position: GridPosition,
     /// Grid size of the chart.
@@ -227,6 +310,15 @@ mod tests {
         assert_eq!(id.to_string(), "00000000-0000-0000-0000-000000000000");
     }
 
+    #[test]
+    fn dashboard_id_identifier_is_prefixed_stream_id() {
+        let id = DashboardId::from_uuid(Uuid::nil());
+        assert_eq!(
+            id.identifier(),
+            "dashboard_00000000-0000-0000-0000-000000000000"
+        );
+    }
+
     #[test]
     fn dashboard_id_roundtrip() {
         let original = DashboardId::new();
@@ -269,10 +361,10 @@ mod tests {
     fn chart_placement_serde_roundtrip() {
         let placement = ChartPlacement {
             chart_id: ChartId::from_uuid(Uuid::nil()),
-            chart_def_ref: ChartDefinitionRef {
+            source: ChartSource::Reference(ChartDefinitionRef {
                 ref_id: "ref-1".to_string(),
                 chart_type_hint: None,
-            },
+            }),
             position: GridPosition { row: 1, col: 2 },
             size: GridSize::new(4, 3).unwrap(),
             tab_id: None,
@@ -282,6 +374,87 @@ mod tests {
         assert_eq!(placement, parsed);
     }
 
+    #[test]
+    fn inline_chart_placement_serde_roundtrip() {
+        let placement = ChartPlacement {
+            chart_id: ChartId::from_uuid(Uuid::nil()),
+            source: ChartSource::Inline(ChartConfig::new(ChartType::Line).with_x_axis("date")),
+            position: GridPosition { row: 0, col: 0 },
+            size: GridSize::new(2, 2).unwrap(),
+            tab_id: None,
+        };
+        let json = serde_json::to_string(&placement).unwrap();
+        let parsed: ChartPlacement = serde_json::from_str(&json).unwrap();
+        assert_eq!(placement, parsed);
+    }
+
+    #[test]
+    fn chart_placement_reads_legacy_chart_def_ref() {
+        let json = serde_json::json!({
+            "chart_id": Uuid::nil(),
+            "chart_def_ref": { "ref_id": "ref-1", "chart_type_hint": "bar" },
+            "position": { "row": 0, "col": 0 },
+            "size": { "width": 2, "height": 2 },
+            "tab_id": null,
+        });
+        let parsed: ChartPlacement = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            parsed.source,
+            ChartSource::Reference(ChartDefinitionRef {
+                ref_id: "ref-1".to_string(),
+                chart_type_hint: Some(ChartType::Bar),
+            })
+        );
+    }
+
+    /// Resolver that counts lookups.
+    #[derive(Default)]
+    struct CountingResolver {
+        configs: HashMap<String, ChartConfig>,
+        lookups: std::cell::Cell<usize>,
+    }
+
+    impl ChartDefinitionResolver for CountingResolver {
+        fn resolve(&self, def_ref: &ChartDefinitionRef) -> Option<ChartConfig> {
+            self.lookups.set(self.lookups.get() + 1);
+            self.configs.resolve(def_ref)
+        }
+    }
+
+    #[test]
+    fn inline_source_resolves_without_resolver() {
+        let config = ChartConfig::new(ChartType::Pie).with_title("Share");
+        let source = ChartSource::Inline(config.clone());
+        let resolver = CountingResolver::default();
+
+        let resolved = source.resolve(&resolver).unwrap();
+
+        assert_eq!(resolved.as_ref(), &config);
+        assert!(matches!(resolved, Cow::Borrowed(_)));
+        assert_eq!(resolver.lookups.get(), 0);
+    }
+
+    #[test]
+    fn reference_source_uses_resolver() {
+        let config = ChartConfig::new(ChartType::Bar);
+        let resolver = CountingResolver {
+            configs: HashMap::from([("ref-1".to_string(), config.clone())]),
+            ..CountingResolver::default()
+        };
+        let source = ChartSource::Reference(ChartDefinitionRef {
+            ref_id: "ref-1".to_string(),
+            chart_type_hint: None,
+        });
+        let missing = ChartSource::Reference(ChartDefinitionRef {
+            ref_id: "ref-2".to_string(),
+            chart_type_hint: None,
+        });
+
+        assert_eq!(source.resolve(&resolver).unwrap().as_ref(), &config);
+        assert!(missing.resolve(&resolver).is_none());
+        assert_eq!(resolver.lookups.get(), 2);
+    }
+
     #[test]
     fn tab_info_serde_roundtrip() {
         let tab = TabInfo {
@@ -1,5 +1,5 @@
 //! Workspace domain: 5 aggregates (Workspace, Dashboard, SavedQuery, UserPreferences,
-//! WorkspacePreferences) with views.
+//! WorkspacePreferences) with views and sagas.
 //!
 //! This crate contains the workspace bounded context for ironstar, managing
 //! user workspaces, dashboards, saved queries, and user/workspace preferences.
@@ -7,6 +7,7 @@
 //! chart-related value objects (Customer-Supplier relationship).
 
 pub mod dashboard;
+pub mod sagas;
 pub mod saved_query;
 pub mod user_preferences;
 pub mod views;
@@ -16,7 +17,7 @@ pub mod workspace_preferences;
 // Re-export aggregate types for ergonomic imports
 pub use dashboard::{
     DashboardCommand, DashboardDecider, DashboardError, DashboardErrorKind, DashboardEvent,
-    DashboardState, dashboard_decider,
+    DashboardState, dashboard_decider, dashboard_decider_with_max_charts,
 };
 pub use saved_query::{
     SavedQueryCommand, SavedQueryDecider, SavedQueryError, SavedQueryErrorKind, SavedQueryEvent,
@@ -28,7 +29,7 @@ pub use user_preferences::{
 };
 pub use workspace::{
     WorkspaceCommand, WorkspaceDecider, WorkspaceError, WorkspaceErrorKind, WorkspaceEvent,
-    WorkspaceState, WorkspaceStatus, workspace_decider,
+    WorkspaceRole, WorkspaceState, WorkspaceStatus, workspace_decider,
 };
 pub use workspace_preferences::{
     WorkspacePreferencesCommand, WorkspacePreferencesDecider, WorkspacePreferencesError,
@@ -36,10 +37,22 @@ pub use workspace_preferences::{
     workspace_preferences_decider,
 };
 
+// Re-export sagas
+pub use sagas::preferences_initialization::{
+    PreferencesInitializationSaga, preferences_initialization_saga,
+};
+pub use sagas::workspace_deletion::{
+    WorkspaceChildCommand, WorkspaceDeletionSaga, workspace_deletion_saga,
+};
+
 // Re-export views
+pub use views::audit::{AuditEntry, AuditLogView, AuditLogViewState, Audited, audit_log_view};
+pub use views::combine_views;
 pub use views::workspace::{
     DashboardLayoutView, DashboardLayoutViewState, SavedQueryListEntry, SavedQueryListView,
-    SavedQueryListViewState, UserPreferencesView, UserPreferencesViewState, WorkspaceListEntry,
-    WorkspaceListView, WorkspaceListViewState, dashboard_layout_view, saved_query_list_view,
-    user_preferences_view, workspace_list_view,
+    SavedQueryListViewState, SlugRedirectView, SlugRedirectViewState, UserPreferencesView,
+    UserPreferencesViewState, ViewDiff, WorkspaceListEntry, WorkspaceListView,
+    WorkspaceListViewState, WorkspacePreferencesView, WorkspacePreferencesViewState,
+    dashboard_layout_view, saved_query_list_view, slug_redirect_view, user_preferences_view,
+    workspace_list_view, workspace_preferences_view,
 };
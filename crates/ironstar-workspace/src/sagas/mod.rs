@@ -0,0 +1,4 @@
+//! Sagas coordinating aggregates within the workspace bounded context.
+
+pub mod preferences_initialization;
+pub mod workspace_deletion;
@@ -0,0 +1,106 @@
+//! Saga initializing user preferences when an owner creates a workspace.
+//!
+//! New users have no `UserPreferences` until something initializes them.
+//! This saga reacts to `WorkspaceEvent::Created` by issuing
+//! `InitializePreferences` for the workspace owner, so the user gets default
+//! theme, locale, and UI state without an explicit setup step.
+//!
+//! The saga reacts to every `Created` event; only an owner's first workspace
+//! should result in initialization. Whether preferences already exist is
+//! checked by the application layer before dispatching, since the saga sees
+//! a single event and no read model.
+
+use ironstar_core::Saga;
+
+use crate::user_preferences::commands::UserPreferencesCommand;
+use crate::user_preferences::values::PreferencesId;
+use crate::workspace::events::WorkspaceEvent;
+
+/// Saga reacting to workspace events with preference commands.
+pub type PreferencesInitializationSaga<'a> = Saga<'a, WorkspaceEvent, UserPreferencesCommand>;
+
+/// Factory function creating the preferences initialization saga.
+pub fn preferences_initialization_saga<'a>() -> PreferencesInitializationSaga<'a> {
+    Saga {
+        react: Box::new(react),
+    }
+}
+
+fn react(event: &WorkspaceEvent) -> Vec<UserPreferencesCommand> {
+    match event {
+        WorkspaceEvent::Created {
+            owner_id,
+            created_at,
+            ..
+        } => vec![UserPreferencesCommand::InitializePreferences {
+            preferences_id: PreferencesId::new(),
+            user_id: *owner_id,
+            initialized_at: *created_at,
+        }],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use ironstar_core::ActionComputation;
+    use ironstar_core::id::{SeededIdSource, with_id_source};
+    use ironstar_shared_kernel::UserId;
+    use uuid::Uuid;
+
+    use crate::workspace::values::{Visibility, WorkspaceId, WorkspaceName};
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn sample_owner() -> UserId {
+        UserId::from_uuid(Uuid::from_u128(7))
+    }
+
+    #[test]
+    fn created_event_initializes_owner_preferences() {
+        let event = WorkspaceEvent::Created {
+            workspace_id: WorkspaceId::from_uuid(Uuid::nil()),
+            name: WorkspaceName::new("Analytics").unwrap(),
+            normalized_name: WorkspaceName::new("Analytics").unwrap().normalized(),
+            owner_id: sample_owner(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: sample_time(),
+        };
+        let expected_id = with_id_source(SeededIdSource::new(1), PreferencesId::new);
+
+        let commands = with_id_source(SeededIdSource::new(1), || {
+            preferences_initialization_saga().compute_new_actions(&event)
+        });
+
+        assert_eq!(
+            commands,
+            vec![UserPreferencesCommand::InitializePreferences {
+                preferences_id: expected_id,
+                user_id: sample_owner(),
+                initialized_at: sample_time(),
+            }]
+        );
+    }
+
+    #[test]
+    fn other_workspace_events_issue_no_commands() {
+        let event = WorkspaceEvent::Archived {
+            workspace_id: WorkspaceId::from_uuid(Uuid::nil()),
+            actor: UserId::system(),
+            archived_at: sample_time(),
+        };
+
+        assert!(
+            preferences_initialization_saga()
+                .compute_new_actions(&event)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Workspace deletion saga cascading an archive to child aggregates.
+//!
+//! Dashboards and saved queries are separate aggregates that reference their
+//! workspace by id, so archiving a workspace cannot remove them in the same
+//! decision. This saga reacts to `WorkspaceEvent::Archived` by issuing one
+//! `ArchiveDashboard` per dashboard and one `DeleteQuery` per saved query
+//! belonging to the archived workspace.
+//!
+//! The saga is pure: children are discovered from view snapshots supplied at
+//! construction, and timestamps and the actor come from the event itself. The application
+//! layer materializes the views, builds the saga, and dispatches the returned
+//! commands to their aggregates.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let saga = workspace_deletion_saga(&saved_queries, &dashboards);
+//! for command in saga.compute_new_actions(&archived_event) {
+//!     match command {
+//!         Sum::First(dashboard_command) => handle_dashboard_command(..).await?,
+//!         Sum::Second(saved_query_command) => handle_saved_query_command(..).await?,
+//!     }
+//! }
+//! ```
+
+use ironstar_core::{Saga, Sum};
+
+use crate::dashboard::commands::DashboardCommand;
+use crate::saved_query::commands::SavedQueryCommand;
+use crate::views::workspace::{DashboardLayoutViewState, SavedQueryListViewState};
+use crate::workspace::events::WorkspaceEvent;
+
+/// Child command issued by the workspace deletion saga.
+pub type WorkspaceChildCommand = Sum<DashboardCommand, SavedQueryCommand>;
+
+/// Saga reacting to workspace events with child aggregate commands.
+pub type WorkspaceDeletionSaga<'a> = Saga<'a, WorkspaceEvent, WorkspaceChildCommand>;
+
+/// Factory function creating a workspace deletion saga over view snapshots.
+///
+/// Dashboards already archived are skipped; the dashboard decider would
+/// treat a repeat archive as a no-op anyway.
+pub fn workspace_deletion_saga<'a>(
+    saved_queries: &'a SavedQueryListViewState,
+    dashboards: &'a [DashboardLayoutViewState],
+) -> WorkspaceDeletionSaga<'a> {
+    Saga {
+        react: Box::new(move |event| react(saved_queries, dashboards, event)),
+    }
+}
+
+fn react(
+    saved_queries: &SavedQueryListViewState,
+    dashboards: &[DashboardLayoutViewState],
+    event: &WorkspaceEvent,
+) -> Vec<WorkspaceChildCommand> {
+    let WorkspaceEvent::Archived {
+        workspace_id,
+        actor,
+        archived_at,
+    } = event
+    else {
+        return Vec::new();
+    };
+
+    let archive_dashboards = dashboards
+        .iter()
+        .filter(|d| d.workspace_id.as_ref() == Some(workspace_id) && !d.archived)
+        .filter_map(|d| d.dashboard_id)
+        .map(|dashboard_id| {
+            Sum::First(DashboardCommand::ArchiveDashboard {
+                dashboard_id,
+                actor: *actor,
+                archived_at: *archived_at,
+            })
+        });
+
+    let delete_queries = saved_queries
+        .queries_for_workspace(workspace_id)
+        .into_iter()
+        .map(|q| {
+            Sum::Second(SavedQueryCommand::DeleteQuery {
+                query_id: q.query_id,
+                actor: *actor,
+                deleted_at: *archived_at,
+            })
+        });
+
+    archive_dashboards.chain(delete_queries).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use ironstar_core::{ActionComputation, DashboardTitle};
+    use uuid::Uuid;
+
+    use crate::dashboard::values::DashboardId;
+    use crate::saved_query::values::{QueryName, SavedQueryId};
+    use crate::views::workspace::SavedQueryListEntry;
+    use crate::workspace::values::{Visibility, WorkspaceId, WorkspaceName};
+    use ironstar_shared_kernel::UserId;
+
+    fn workspace(n: u128) -> WorkspaceId {
+        WorkspaceId::from_uuid(Uuid::from_u128(n))
+    }
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn dashboard(n: u128, workspace_id: WorkspaceId, archived: bool) -> DashboardLayoutViewState {
+        DashboardLayoutViewState {
+            dashboard_id: Some(DashboardId::from_uuid(Uuid::from_u128(n))),
+            workspace_id: Some(workspace_id),
+            name: Some(DashboardTitle::new("Dashboard").unwrap()),
+            archived,
+            ..DashboardLayoutViewState::default()
+        }
+    }
+
+    fn query(n: u128, workspace_id: WorkspaceId) -> SavedQueryListEntry {
+        SavedQueryListEntry {
+            query_id: SavedQueryId::from_uuid(Uuid::from_u128(n)),
+            workspace_id,
+            name: QueryName::new("Query").unwrap(),
+            sql: "SELECT 1".to_string(),
+            dataset_ref: "hf://datasets/test".to_string(),
+            saved_at: sample_time(),
+            run_count: 0,
+            last_run_at: None,
+            version: 1,
+        }
+    }
+
+    fn archived(workspace_id: WorkspaceId) -> WorkspaceEvent {
+        WorkspaceEvent::Archived {
+            workspace_id,
+            actor: UserId::system(),
+            archived_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn archive_cascades_to_children_of_that_workspace() {
+        let saved_queries = SavedQueryListViewState {
+            queries: vec![query(10, workspace(1)), query(11, workspace(2))],
+            count: 2,
+        };
+        let dashboards = vec![
+            dashboard(20, workspace(1), false),
+            dashboard(21, workspace(2), false),
+        ];
+        let saga = workspace_deletion_saga(&saved_queries, &dashboards);
+
+        let commands = saga.compute_new_actions(&archived(workspace(1)));
+
+        assert_eq!(
+            commands,
+            vec![
+                Sum::First(DashboardCommand::ArchiveDashboard {
+                    dashboard_id: DashboardId::from_uuid(Uuid::from_u128(20)),
+                    actor: UserId::system(),
+                    archived_at: sample_time(),
+                }),
+                Sum::Second(SavedQueryCommand::DeleteQuery {
+                    query_id: SavedQueryId::from_uuid(Uuid::from_u128(10)),
+                    actor: UserId::system(),
+                    deleted_at: sample_time(),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn already_archived_dashboards_are_skipped() {
+        let saved_queries = SavedQueryListViewState::default();
+        let dashboards = vec![dashboard(20, workspace(1), true)];
+        let saga = workspace_deletion_saga(&saved_queries, &dashboards);
+
+        assert!(saga.compute_new_actions(&archived(workspace(1))).is_empty());
+    }
+
+    #[test]
+    fn other_workspace_events_issue_no_commands() {
+        let saved_queries = SavedQueryListViewState {
+            queries: vec![query(10, workspace(1))],
+            count: 1,
+        };
+        let dashboards = vec![dashboard(20, workspace(1), false)];
+        let saga = workspace_deletion_saga(&saved_queries, &dashboards);
+
+        let created = WorkspaceEvent::Created {
+            workspace_id: workspace(1),
+            name: WorkspaceName::new("Analytics").unwrap(),
+            normalized_name: WorkspaceName::new("Analytics").unwrap().normalized(),
+            owner_id: UserId::from_uuid(Uuid::nil()),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: sample_time(),
+        };
+
+        assert!(saga.compute_new_actions(&created).is_empty());
+    }
+}
@@ -12,6 +12,7 @@ use super::values::{QueryName, SavedQueryId};
 use crate::workspace::WorkspaceId;
 use ironstar_analytics::{DatasetRef, SqlQuery};
 use ironstar_core::{DeciderType, Identifier};
+use ironstar_shared_kernel::UserId;
 
 /// Commands that can be sent to the SavedQuery aggregate.
 ///
@@ -29,6 +30,8 @@ pub enum SavedQueryCommand {
         name: QueryName,
         sql: SqlQuery,
         dataset_ref: DatasetRef,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         saved_at: DateTime<Utc>,
     },
 
@@ -37,6 +40,8 @@ pub enum SavedQueryCommand {
     /// Transitions the aggregate to the terminal NoQuery state.
     DeleteQuery {
         query_id: SavedQueryId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         deleted_at: DateTime<Utc>,
     },
 
@@ -46,6 +51,8 @@ pub enum SavedQueryCommand {
     RenameQuery {
         query_id: SavedQueryId,
         name: QueryName,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         renamed_at: DateTime<Utc>,
     },
 
@@ -55,6 +62,8 @@ pub enum SavedQueryCommand {
     UpdateQuerySql {
         query_id: SavedQueryId,
         sql: SqlQuery,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         updated_at: DateTime<Utc>,
     },
 
@@ -64,8 +73,29 @@ pub enum SavedQueryCommand {
     UpdateDatasetRef {
         query_id: SavedQueryId,
         dataset_ref: DatasetRef,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         updated_at: DateTime<Utc>,
     },
+
+    /// Move an existing query to another workspace.
+    ///
+    /// Idempotent when the query is already in the target workspace.
+    MoveToWorkspace {
+        query_id: SavedQueryId,
+        target_workspace_id: WorkspaceId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        moved_at: DateTime<Utc>,
+    },
+
+    /// Record that an existing query was executed.
+    ///
+    /// Never idempotent: every run is counted.
+    RecordRun {
+        query_id: SavedQueryId,
+        run_at: DateTime<Utc>,
+    },
 }
 
 impl SavedQueryCommand {
@@ -77,7 +107,9 @@ impl SavedQueryCommand {
             | Self::DeleteQuery { query_id, .. }
             | Self::RenameQuery { query_id, .. }
             | Self::UpdateQuerySql { query_id, .. }
-            | Self::UpdateDatasetRef { query_id, .. } => *query_id,
+            | Self::UpdateDatasetRef { query_id, .. }
+            | Self::MoveToWorkspace { query_id, .. }
+            | Self::RecordRun { query_id, .. } => *query_id,
         }
     }
 
@@ -90,13 +122,15 @@ impl SavedQueryCommand {
             Self::RenameQuery { .. } => "RenameQuery",
             Self::UpdateQuerySql { .. } => "UpdateQuerySql",
             Self::UpdateDatasetRef { .. } => "UpdateDatasetRef",
+            Self::MoveToWorkspace { .. } => "MoveToWorkspace",
+            Self::RecordRun { .. } => "RecordRun",
         }
     }
 }
 
 impl Identifier for SavedQueryCommand {
     fn identifier(&self) -> String {
-        format!("saved_query_{}", self.query_id())
+        self.query_id().identifier()
     }
 }
 
@@ -126,6 +160,7 @@ mod tests {
             name: QueryName::new("Test").unwrap(),
             sql: SqlQuery::new("SELECT 1").unwrap(),
             dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+            actor: UserId::system(),
             saved_at: sample_time(),
         };
 
@@ -139,6 +174,7 @@ mod tests {
         let original = SavedQueryCommand::RenameQuery {
             query_id: SavedQueryId::new(),
             name: QueryName::new("New Name").unwrap(),
+            actor: UserId::system(),
             renamed_at: sample_time(),
         };
 
@@ -152,6 +188,7 @@ mod tests {
         let qid = SavedQueryId::from_uuid(uuid::Uuid::nil());
         let cmd = SavedQueryCommand::DeleteQuery {
             query_id: qid,
+            actor: UserId::system(),
             deleted_at: sample_time(),
         };
 
@@ -174,31 +211,103 @@ mod tests {
                 name: QueryName::new("Test").unwrap(),
                 sql: SqlQuery::new("SELECT 1").unwrap(),
                 dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+                actor: UserId::system(),
                 saved_at: ts,
             },
             SavedQueryCommand::DeleteQuery {
                 query_id: qid,
+                actor: UserId::system(),
                 deleted_at: ts,
             },
             SavedQueryCommand::RenameQuery {
                 query_id: qid,
                 name: QueryName::new("New").unwrap(),
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             SavedQueryCommand::UpdateQuerySql {
                 query_id: qid,
                 sql: SqlQuery::new("SELECT 2").unwrap(),
+                actor: UserId::system(),
                 updated_at: ts,
             },
             SavedQueryCommand::UpdateDatasetRef {
                 query_id: qid,
                 dataset_ref: DatasetRef::new("s3://bucket/data").unwrap(),
+                actor: UserId::system(),
                 updated_at: ts,
             },
+            SavedQueryCommand::MoveToWorkspace {
+                query_id: qid,
+                target_workspace_id: WorkspaceId::new(),
+                actor: UserId::system(),
+                moved_at: ts,
+            },
+            SavedQueryCommand::RecordRun {
+                query_id: qid,
+                run_at: ts,
+            },
         ];
 
         for cmd in commands {
             assert_eq!(cmd.query_id(), qid);
         }
     }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            SavedQueryCommand::SaveQuery {
+                query_id: SavedQueryId::new(),
+                workspace_id: WorkspaceId::new(),
+                name: QueryName::new("Daily totals").unwrap(),
+                sql: SqlQuery::new("SELECT count(*) FROM events").unwrap(),
+                dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+                actor: UserId::system(),
+                saved_at: ts,
+            },
+            SavedQueryCommand::DeleteQuery {
+                query_id: SavedQueryId::new(),
+                actor: UserId::system(),
+                deleted_at: ts,
+            },
+            SavedQueryCommand::RenameQuery {
+                query_id: SavedQueryId::new(),
+                name: QueryName::new("Weekly totals").unwrap(),
+                actor: UserId::system(),
+                renamed_at: ts,
+            },
+            SavedQueryCommand::UpdateQuerySql {
+                query_id: SavedQueryId::new(),
+                sql: SqlQuery::new("SELECT 2").unwrap(),
+                actor: UserId::system(),
+                updated_at: ts,
+            },
+            SavedQueryCommand::UpdateDatasetRef {
+                query_id: SavedQueryId::new(),
+                dataset_ref: DatasetRef::new("hf://datasets/test/other").unwrap(),
+                actor: UserId::system(),
+                updated_at: ts,
+            },
+            SavedQueryCommand::MoveToWorkspace {
+                query_id: SavedQueryId::new(),
+                target_workspace_id: WorkspaceId::new(),
+                actor: UserId::system(),
+                moved_at: ts,
+            },
+            SavedQueryCommand::RecordRun {
+                query_id: SavedQueryId::new(),
+                run_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: SavedQueryCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
 }
@@ -8,8 +8,8 @@
 //!
 //! ```text
 //!                 ┌───────────┐
-//!  SaveQuery ────►│QueryExists│◄──── RenameQuery, UpdateSql, UpdateDatasetRef
-//!                 └─────┬─────┘
+//!  SaveQuery ────►│QueryExists│◄──── RenameQuery, UpdateSql, UpdateDatasetRef,
+//!                 └─────┬─────┘      MoveToWorkspace, RecordRun
 //!                       │
 //!                  DeleteQuery
 //!                       │
@@ -25,6 +25,16 @@
 //! - RenameQuery with same name returns `Ok(vec![])`
 //! - UpdateQuerySql with same SQL returns `Ok(vec![])`
 //! - UpdateDatasetRef with same reference returns `Ok(vec![])`
+//! - MoveToWorkspace to the current workspace returns `Ok(vec![])`
+//!
+//! RecordRun is the exception: every run is a distinct fact and is counted.
+//!
+//! # Dataset consistency
+//!
+//! Queries read their dataset through the `dataset` table (`FROM dataset`),
+//! which only resolves when a dataset reference is bound. SaveQuery and
+//! UpdateDatasetRef both require a `DatasetRef`, so every existing query
+//! carries one and UpdateQuerySql needs no dataset check of its own.
 //!
 //! # Terminal state
 //!
@@ -69,7 +79,7 @@ fn decide(
     state: &SavedQueryState,
 ) -> Result<Vec<SavedQueryEvent>, SavedQueryError> {
     let result = match (command, state) {
-        // SaveQuery: NoQuery -> QueryExists
+        // SaveQuery: NoQuery | Deleted -> QueryExists
         (
             SavedQueryCommand::SaveQuery {
                 query_id,
@@ -77,15 +87,17 @@ fn decide(
                 name,
                 sql,
                 dataset_ref,
+                actor,
                 saved_at,
             },
-            SavedQueryState::NoQuery,
+            SavedQueryState::NoQuery | SavedQueryState::Deleted,
         ) => Ok(vec![SavedQueryEvent::QuerySaved {
             query_id: *query_id,
             workspace_id: *workspace_id,
             name: name.clone(),
             sql: sql.clone(),
             dataset_ref: dataset_ref.clone(),
+            actor: *actor,
             saved_at: *saved_at,
         }]),
 
@@ -94,28 +106,32 @@ fn decide(
             Err(SavedQueryError::already_exists())
         }
 
-        // DeleteQuery: QueryExists -> NoQuery (terminal)
+        // DeleteQuery: QueryExists -> Deleted (terminal)
         (
             SavedQueryCommand::DeleteQuery {
                 query_id,
+                actor,
                 deleted_at,
             },
             SavedQueryState::QueryExists { .. },
         ) => Ok(vec![SavedQueryEvent::QueryDeleted {
             query_id: *query_id,
+            actor: *actor,
             deleted_at: *deleted_at,
         }]),
 
         // DeleteQuery when no query exists
-        (SavedQueryCommand::DeleteQuery { .. }, SavedQueryState::NoQuery) => {
-            Err(SavedQueryError::not_found())
-        }
+        (
+            SavedQueryCommand::DeleteQuery { .. },
+            SavedQueryState::NoQuery | SavedQueryState::Deleted,
+        ) => Err(SavedQueryError::not_found()),
 
         // RenameQuery: QueryExists -> QueryExists (idempotent if same name)
         (
             SavedQueryCommand::RenameQuery {
                 query_id,
                 name,
+                actor,
                 renamed_at,
             },
             SavedQueryState::QueryExists {
@@ -129,20 +145,23 @@ fn decide(
             Ok(vec![SavedQueryEvent::QueryRenamed {
                 query_id: *query_id,
                 name: name.clone(),
+                actor: *actor,
                 renamed_at: *renamed_at,
             }])
         }
 
         // RenameQuery when no query exists
-        (SavedQueryCommand::RenameQuery { .. }, SavedQueryState::NoQuery) => {
-            Err(SavedQueryError::not_found())
-        }
+        (
+            SavedQueryCommand::RenameQuery { .. },
+            SavedQueryState::NoQuery | SavedQueryState::Deleted,
+        ) => Err(SavedQueryError::not_found()),
 
         // UpdateQuerySql: QueryExists -> QueryExists (idempotent if same SQL)
         (
             SavedQueryCommand::UpdateQuerySql {
                 query_id,
                 sql,
+                actor,
                 updated_at,
             },
             SavedQueryState::QueryExists {
@@ -156,20 +175,23 @@ fn decide(
             Ok(vec![SavedQueryEvent::QuerySqlUpdated {
                 query_id: *query_id,
                 sql: sql.clone(),
+                actor: *actor,
                 updated_at: *updated_at,
             }])
         }
 
         // UpdateQuerySql when no query exists
-        (SavedQueryCommand::UpdateQuerySql { .. }, SavedQueryState::NoQuery) => {
-            Err(SavedQueryError::not_found())
-        }
+        (
+            SavedQueryCommand::UpdateQuerySql { .. },
+            SavedQueryState::NoQuery | SavedQueryState::Deleted,
+        ) => Err(SavedQueryError::not_found()),
 
         // UpdateDatasetRef: QueryExists -> QueryExists (idempotent if same ref)
         (
             SavedQueryCommand::UpdateDatasetRef {
                 query_id,
                 dataset_ref,
+                actor,
                 updated_at,
             },
             SavedQueryState::QueryExists {
@@ -184,14 +206,60 @@ fn decide(
             Ok(vec![SavedQueryEvent::DatasetRefUpdated {
                 query_id: *query_id,
                 dataset_ref: dataset_ref.clone(),
+                actor: *actor,
                 updated_at: *updated_at,
             }])
         }
 
         // UpdateDatasetRef when no query exists
-        (SavedQueryCommand::UpdateDatasetRef { .. }, SavedQueryState::NoQuery) => {
-            Err(SavedQueryError::not_found())
+        (
+            SavedQueryCommand::UpdateDatasetRef { .. },
+            SavedQueryState::NoQuery | SavedQueryState::Deleted,
+        ) => Err(SavedQueryError::not_found()),
+
+        // MoveToWorkspace: QueryExists -> QueryExists (idempotent if same workspace)
+        (
+            SavedQueryCommand::MoveToWorkspace {
+                query_id,
+                target_workspace_id,
+                actor,
+                moved_at,
+            },
+            SavedQueryState::QueryExists { workspace_id, .. },
+        ) => {
+            if workspace_id == target_workspace_id {
+                return Ok(vec![]);
+            }
+
+            Ok(vec![SavedQueryEvent::QueryMovedToWorkspace {
+                query_id: *query_id,
+                from_workspace_id: *workspace_id,
+                to_workspace_id: *target_workspace_id,
+                actor: *actor,
+                moved_at: *moved_at,
+            }])
         }
+
+        // MoveToWorkspace when no query exists
+        (
+            SavedQueryCommand::MoveToWorkspace { .. },
+            SavedQueryState::NoQuery | SavedQueryState::Deleted,
+        ) => Err(SavedQueryError::not_found()),
+
+        // RecordRun: QueryExists -> QueryExists (every run counts)
+        (
+            SavedQueryCommand::RecordRun { query_id, run_at },
+            SavedQueryState::QueryExists { .. },
+        ) => Ok(vec![SavedQueryEvent::QueryRun {
+            query_id: *query_id,
+            run_at: *run_at,
+        }]),
+
+        // RecordRun when no query exists
+        (
+            SavedQueryCommand::RecordRun { .. },
+            SavedQueryState::NoQuery | SavedQueryState::Deleted,
+        ) => Err(SavedQueryError::not_found()),
     };
     if let Ok(ref events) = result {
         tracing::debug!(event_count = events.len(), "decision complete");
@@ -221,60 +289,63 @@ fn evolve(state: &SavedQueryState, event: &SavedQueryEvent) -> SavedQueryState {
             name: name.clone(),
             sql: sql.clone(),
             dataset_ref: dataset_ref.clone(),
+            run_count: 0,
+            last_run_at: None,
         },
 
-        SavedQueryEvent::QueryDeleted { .. } => SavedQueryState::NoQuery,
+        SavedQueryEvent::QueryDeleted { .. } => SavedQueryState::Deleted,
 
-        SavedQueryEvent::QueryRenamed { name, .. } => match state {
-            SavedQueryState::QueryExists {
-                query_id,
-                workspace_id,
-                sql,
-                dataset_ref,
-                ..
-            } => SavedQueryState::QueryExists {
-                query_id: *query_id,
-                workspace_id: *workspace_id,
-                name: name.clone(),
-                sql: sql.clone(),
-                dataset_ref: dataset_ref.clone(),
-            },
-            SavedQueryState::NoQuery => state.clone(),
-        },
+        SavedQueryEvent::QueryRenamed { name, .. } => {
+            let mut next = state.clone();
+            if let SavedQueryState::QueryExists { name: current, .. } = &mut next {
+                *current = name.clone();
+            }
+            next
+        }
 
-        SavedQueryEvent::QuerySqlUpdated { sql, .. } => match state {
-            SavedQueryState::QueryExists {
-                query_id,
-                workspace_id,
-                name,
-                dataset_ref,
+        SavedQueryEvent::QuerySqlUpdated { sql, .. } => {
+            let mut next = state.clone();
+            if let SavedQueryState::QueryExists { sql: current, .. } = &mut next {
+                *current = sql.clone();
+            }
+            next
+        }
+
+        SavedQueryEvent::DatasetRefUpdated { dataset_ref, .. } => {
+            let mut next = state.clone();
+            if let SavedQueryState::QueryExists {
+                dataset_ref: current,
                 ..
-            } => SavedQueryState::QueryExists {
-                query_id: *query_id,
-                workspace_id: *workspace_id,
-                name: name.clone(),
-                sql: sql.clone(),
-                dataset_ref: dataset_ref.clone(),
-            },
-            SavedQueryState::NoQuery => state.clone(),
-        },
+            } = &mut next
+            {
+                *current = dataset_ref.clone();
+            }
+            next
+        }
 
-        SavedQueryEvent::DatasetRefUpdated { dataset_ref, .. } => match state {
-            SavedQueryState::QueryExists {
-                query_id,
-                workspace_id,
-                name,
-                sql,
+        SavedQueryEvent::QueryMovedToWorkspace {
+            to_workspace_id, ..
+        } => {
+            let mut next = state.clone();
+            if let SavedQueryState::QueryExists { workspace_id, .. } = &mut next {
+                *workspace_id = *to_workspace_id;
+            }
+            next
+        }
+
+        SavedQueryEvent::QueryRun { run_at, .. } => {
+            let mut next = state.clone();
+            if let SavedQueryState::QueryExists {
+                run_count,
+                last_run_at,
                 ..
-            } => SavedQueryState::QueryExists {
-                query_id: *query_id,
-                workspace_id: *workspace_id,
-                name: name.clone(),
-                sql: sql.clone(),
-                dataset_ref: dataset_ref.clone(),
-            },
-            SavedQueryState::NoQuery => state.clone(),
-        },
+            } = &mut next
+            {
+                *run_count += 1;
+                *last_run_at = Some(*run_at);
+            }
+            next
+        }
     }
 }
 
@@ -282,7 +353,8 @@ fn evolve(state: &SavedQueryState, event: &SavedQueryEvent) -> SavedQueryState {
 mod tests {
     use super::*;
     use chrono::{DateTime, Utc};
-    use ironstar_core::DeciderTestSpecification;
+    use ironstar_core::{DeciderTestExt, DeciderTestSpecification};
+    use ironstar_shared_kernel::UserId;
 
     use super::super::values::{QueryName, SavedQueryId};
     use crate::workspace::WorkspaceId;
@@ -321,6 +393,7 @@ mod tests {
             name: sample_name(),
             sql: sample_sql(),
             dataset_ref: sample_dataset_ref(),
+            actor: UserId::system(),
             saved_at: sample_time(),
         }
     }
@@ -345,6 +418,7 @@ mod tests {
                 name: name.clone(),
                 sql: sql.clone(),
                 dataset_ref: dataset.clone(),
+                actor: UserId::system(),
                 saved_at: ts,
             })
             .then(vec![SavedQueryEvent::QuerySaved {
@@ -353,6 +427,7 @@ mod tests {
                 name,
                 sql,
                 dataset_ref: dataset,
+                actor: UserId::system(),
                 saved_at: ts,
             }]);
     }
@@ -372,6 +447,7 @@ mod tests {
                 name: QueryName::new("Other").unwrap(),
                 sql: SqlQuery::new("SELECT 1").unwrap(),
                 dataset_ref: DatasetRef::new("hf://datasets/other/data").unwrap(),
+                actor: UserId::system(),
                 saved_at: ts,
             })
             .then_error(SavedQueryError::already_exists());
@@ -389,10 +465,12 @@ mod tests {
             .given(vec![saved_event()])
             .when(SavedQueryCommand::DeleteQuery {
                 query_id: qid,
+                actor: UserId::system(),
                 deleted_at: ts,
             })
             .then(vec![SavedQueryEvent::QueryDeleted {
                 query_id: qid,
+                actor: UserId::system(),
                 deleted_at: ts,
             }]);
     }
@@ -407,6 +485,7 @@ mod tests {
             .given(vec![])
             .when(SavedQueryCommand::DeleteQuery {
                 query_id: qid,
+                actor: UserId::system(),
                 deleted_at: ts,
             })
             .then_error(SavedQueryError::not_found());
@@ -426,11 +505,13 @@ mod tests {
             .when(SavedQueryCommand::RenameQuery {
                 query_id: qid,
                 name: new_name.clone(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then(vec![SavedQueryEvent::QueryRenamed {
                 query_id: qid,
                 name: new_name,
+                actor: UserId::system(),
                 renamed_at: ts,
             }]);
     }
@@ -440,15 +521,15 @@ mod tests {
         let qid = sample_query_id();
         let ts = sample_time();
 
-        DeciderTestSpecification::default()
-            .for_decider(saved_query_decider())
-            .given(vec![saved_event()])
-            .when(SavedQueryCommand::RenameQuery {
+        saved_query_decider().assert_no_events(
+            vec![saved_event()],
+            SavedQueryCommand::RenameQuery {
                 query_id: qid,
                 name: sample_name(),
+                actor: UserId::system(),
                 renamed_at: ts,
-            })
-            .then(vec![]);
+            },
+        );
     }
 
     #[test]
@@ -462,6 +543,7 @@ mod tests {
             .when(SavedQueryCommand::RenameQuery {
                 query_id: qid,
                 name: QueryName::new("Any Name").unwrap(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then_error(SavedQueryError::not_found());
@@ -481,29 +563,55 @@ mod tests {
             .when(SavedQueryCommand::UpdateQuerySql {
                 query_id: qid,
                 sql: new_sql.clone(),
+                actor: UserId::system(),
                 updated_at: ts,
             })
             .then(vec![SavedQueryEvent::QuerySqlUpdated {
                 query_id: qid,
                 sql: new_sql,
+                actor: UserId::system(),
                 updated_at: ts,
             }]);
     }
 
     #[test]
-    fn update_sql_same_value_is_idempotent() {
+    fn update_sql_reading_dataset_table_succeeds() {
         let qid = sample_query_id();
         let ts = sample_time();
+        let new_sql =
+            SqlQuery::new("SELECT month, SUM(total) FROM dataset GROUP BY month").unwrap();
 
         DeciderTestSpecification::default()
             .for_decider(saved_query_decider())
             .given(vec![saved_event()])
             .when(SavedQueryCommand::UpdateQuerySql {
                 query_id: qid,
-                sql: sample_sql(),
+                sql: new_sql.clone(),
+                actor: UserId::system(),
                 updated_at: ts,
             })
-            .then(vec![]);
+            .then(vec![SavedQueryEvent::QuerySqlUpdated {
+                query_id: qid,
+                sql: new_sql,
+                actor: UserId::system(),
+                updated_at: ts,
+            }]);
+    }
+
+    #[test]
+    fn update_sql_same_value_is_idempotent() {
+        let qid = sample_query_id();
+        let ts = sample_time();
+
+        saved_query_decider().assert_no_events(
+            vec![saved_event()],
+            SavedQueryCommand::UpdateQuerySql {
+                query_id: qid,
+                sql: sample_sql(),
+                actor: UserId::system(),
+                updated_at: ts,
+            },
+        );
     }
 
     #[test]
@@ -517,6 +625,7 @@ mod tests {
             .when(SavedQueryCommand::UpdateQuerySql {
                 query_id: qid,
                 sql: SqlQuery::new("SELECT 1").unwrap(),
+                actor: UserId::system(),
                 updated_at: ts,
             })
             .then_error(SavedQueryError::not_found());
@@ -536,11 +645,13 @@ mod tests {
             .when(SavedQueryCommand::UpdateDatasetRef {
                 query_id: qid,
                 dataset_ref: new_ref.clone(),
+                actor: UserId::system(),
                 updated_at: ts,
             })
             .then(vec![SavedQueryEvent::DatasetRefUpdated {
                 query_id: qid,
                 dataset_ref: new_ref,
+                actor: UserId::system(),
                 updated_at: ts,
             }]);
     }
@@ -550,15 +661,15 @@ mod tests {
         let qid = sample_query_id();
         let ts = sample_time();
 
-        DeciderTestSpecification::default()
-            .for_decider(saved_query_decider())
-            .given(vec![saved_event()])
-            .when(SavedQueryCommand::UpdateDatasetRef {
+        saved_query_decider().assert_no_events(
+            vec![saved_event()],
+            SavedQueryCommand::UpdateDatasetRef {
                 query_id: qid,
                 dataset_ref: sample_dataset_ref(),
+                actor: UserId::system(),
                 updated_at: ts,
-            })
-            .then(vec![]);
+            },
+        );
     }
 
     #[test]
@@ -572,11 +683,147 @@ mod tests {
             .when(SavedQueryCommand::UpdateDatasetRef {
                 query_id: qid,
                 dataset_ref: DatasetRef::new("hf://datasets/other/data").unwrap(),
+                actor: UserId::system(),
                 updated_at: ts,
             })
             .then_error(SavedQueryError::not_found());
     }
 
+    // --- MoveToWorkspace transitions ---
+
+    #[test]
+    fn move_to_workspace_succeeds() {
+        let qid = sample_query_id();
+        let ts = sample_time();
+        let target = WorkspaceId::from_uuid(uuid::Uuid::from_u128(1));
+
+        DeciderTestSpecification::default()
+            .for_decider(saved_query_decider())
+            .given(vec![saved_event()])
+            .when(SavedQueryCommand::MoveToWorkspace {
+                query_id: qid,
+                target_workspace_id: target,
+                actor: UserId::system(),
+                moved_at: ts,
+            })
+            .then(vec![SavedQueryEvent::QueryMovedToWorkspace {
+                query_id: qid,
+                from_workspace_id: sample_workspace_id(),
+                to_workspace_id: target,
+                actor: UserId::system(),
+                moved_at: ts,
+            }]);
+    }
+
+    #[test]
+    fn move_to_current_workspace_is_idempotent() {
+        saved_query_decider().assert_no_events(
+            vec![saved_event()],
+            SavedQueryCommand::MoveToWorkspace {
+                query_id: sample_query_id(),
+                target_workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                moved_at: sample_time(),
+            },
+        );
+    }
+
+    #[test]
+    fn move_to_workspace_when_no_query_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(saved_query_decider())
+            .given(vec![])
+            .when(SavedQueryCommand::MoveToWorkspace {
+                query_id: sample_query_id(),
+                target_workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                moved_at: sample_time(),
+            })
+            .then_error(SavedQueryError::not_found());
+    }
+
+    #[test]
+    fn moved_event_updates_workspace() {
+        let decider = saved_query_decider();
+        let target = WorkspaceId::from_uuid(uuid::Uuid::from_u128(1));
+        let events = [
+            saved_event(),
+            SavedQueryEvent::QueryMovedToWorkspace {
+                query_id: sample_query_id(),
+                from_workspace_id: sample_workspace_id(),
+                to_workspace_id: target,
+                actor: UserId::system(),
+                moved_at: sample_time(),
+            },
+        ];
+
+        let state = events
+            .iter()
+            .fold((decider.initial_state)(), |state, event| {
+                (decider.evolve)(&state, event)
+            });
+
+        assert_eq!(state.workspace_id(), Some(&target));
+    }
+
+    // --- RecordRun transitions ---
+
+    #[test]
+    fn record_run_emits_query_run() {
+        let qid = sample_query_id();
+        let ts = sample_time();
+
+        DeciderTestSpecification::default()
+            .for_decider(saved_query_decider())
+            .given(vec![saved_event()])
+            .when(SavedQueryCommand::RecordRun {
+                query_id: qid,
+                run_at: ts,
+            })
+            .then(vec![SavedQueryEvent::QueryRun {
+                query_id: qid,
+                run_at: ts,
+            }]);
+    }
+
+    #[test]
+    fn record_run_when_no_query_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(saved_query_decider())
+            .given(vec![])
+            .when(SavedQueryCommand::RecordRun {
+                query_id: sample_query_id(),
+                run_at: sample_time(),
+            })
+            .then_error(SavedQueryError::not_found());
+    }
+
+    #[test]
+    fn query_run_increments_run_count_and_sets_last_run() {
+        let decider = saved_query_decider();
+        let later = sample_time() + chrono::Duration::hours(1);
+        let events = [
+            saved_event(),
+            SavedQueryEvent::QueryRun {
+                query_id: sample_query_id(),
+                run_at: sample_time(),
+            },
+            SavedQueryEvent::QueryRun {
+                query_id: sample_query_id(),
+                run_at: later,
+            },
+        ];
+
+        let state = events
+            .iter()
+            .fold((decider.initial_state)(), |state, event| {
+                (decider.evolve)(&state, event)
+            });
+
+        assert_eq!(state.run_count(), 2);
+        assert_eq!(state.last_run_at(), Some(later));
+    }
+
     // --- Full lifecycle ---
 
     #[test]
@@ -593,6 +840,7 @@ mod tests {
                 name: sample_name(),
                 sql: sample_sql(),
                 dataset_ref: sample_dataset_ref(),
+                actor: UserId::system(),
                 saved_at: ts,
             },
             &SavedQueryState::default(),
@@ -612,6 +860,7 @@ mod tests {
             &SavedQueryCommand::RenameQuery {
                 query_id: qid,
                 name: new_name.clone(),
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             &state,
@@ -628,6 +877,7 @@ mod tests {
             &SavedQueryCommand::UpdateQuerySql {
                 query_id: qid,
                 sql: new_sql.clone(),
+                actor: UserId::system(),
                 updated_at: ts,
             },
             &state,
@@ -643,6 +893,7 @@ mod tests {
             &SavedQueryCommand::UpdateQuerySql {
                 query_id: qid,
                 sql: new_sql,
+                actor: UserId::system(),
                 updated_at: ts,
             },
             &state,
@@ -654,6 +905,7 @@ mod tests {
         let events = decide(
             &SavedQueryCommand::DeleteQuery {
                 query_id: qid,
+                actor: UserId::system(),
                 deleted_at: ts,
             },
             &state,
@@ -663,7 +915,7 @@ mod tests {
 
         let state = evolve(&state, &events[0]);
         assert!(!state.exists());
-        assert_eq!(state, SavedQueryState::NoQuery);
+        assert_eq!(state, SavedQueryState::Deleted);
     }
 
     // --- Terminal state: re-creation after deletion ---
@@ -680,6 +932,7 @@ mod tests {
                 saved_event(),
                 SavedQueryEvent::QueryDeleted {
                     query_id: qid,
+                    actor: UserId::system(),
                     deleted_at: ts,
                 },
             ])
@@ -689,6 +942,7 @@ mod tests {
                 name: QueryName::new("Re-created Query").unwrap(),
                 sql: SqlQuery::new("SELECT 1").unwrap(),
                 dataset_ref: DatasetRef::new("hf://datasets/new/data").unwrap(),
+                actor: UserId::system(),
                 saved_at: ts,
             })
             .then(vec![SavedQueryEvent::QuerySaved {
@@ -697,6 +951,7 @@ mod tests {
                 name: QueryName::new("Re-created Query").unwrap(),
                 sql: SqlQuery::new("SELECT 1").unwrap(),
                 dataset_ref: DatasetRef::new("hf://datasets/new/data").unwrap(),
+                actor: UserId::system(),
                 saved_at: ts,
             }]);
     }
@@ -23,6 +23,9 @@ pub enum SavedQueryErrorKind {
 
     /// No saved query exists with this ID.
     NotFound,
+
+    /// Another saved query in the same workspace already uses this name.
+    DuplicateName(String),
 }
 
 impl SavedQueryError {
@@ -67,6 +70,11 @@ impl SavedQueryError {
     pub fn not_found() -> Self {
         Self::new(SavedQueryErrorKind::NotFound)
     }
+
+    /// Creates a `DuplicateName` error for the given name.
+    pub fn duplicate_name(name: impl Into<String>) -> Self {
+        Self::new(SavedQueryErrorKind::DuplicateName(name.into()))
+    }
 }
 
 impl fmt::Display for SavedQueryError {
@@ -78,6 +86,9 @@ impl fmt::Display for SavedQueryError {
             SavedQueryErrorKind::NotFound => {
                 write!(f, "saved query not found")
             }
+            SavedQueryErrorKind::DuplicateName(name) => {
+                write!(f, "saved query name already used in workspace: {name}")
+            }
         }
     }
 }
@@ -106,6 +117,10 @@ mod tests {
             SavedQueryError::not_found().to_string(),
             "saved query not found"
         );
+        assert_eq!(
+            SavedQueryError::duplicate_name("Revenue").to_string(),
+            "saved query name already used in workspace: Revenue"
+        );
     }
 
     #[test]
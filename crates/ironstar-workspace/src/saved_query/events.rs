@@ -13,7 +13,8 @@ use ts_rs::TS;
 use super::values::{QueryName, SavedQueryId};
 use crate::workspace::WorkspaceId;
 use ironstar_analytics::{DatasetRef, SqlQuery};
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
+use ironstar_shared_kernel::UserId;
 
 /// Events emitted by the SavedQuery aggregate.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
@@ -27,12 +28,16 @@ pub enum SavedQueryEvent {
         name: QueryName,
         sql: SqlQuery,
         dataset_ref: DatasetRef,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         saved_at: DateTime<Utc>,
     },
 
     /// A query was deleted (terminal event).
     QueryDeleted {
         query_id: SavedQueryId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         deleted_at: DateTime<Utc>,
     },
 
@@ -40,6 +45,8 @@ pub enum SavedQueryEvent {
     QueryRenamed {
         query_id: SavedQueryId,
         name: QueryName,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         renamed_at: DateTime<Utc>,
     },
 
@@ -47,6 +54,8 @@ pub enum SavedQueryEvent {
     QuerySqlUpdated {
         query_id: SavedQueryId,
         sql: SqlQuery,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         updated_at: DateTime<Utc>,
     },
 
@@ -54,8 +63,26 @@ pub enum SavedQueryEvent {
     DatasetRefUpdated {
         query_id: SavedQueryId,
         dataset_ref: DatasetRef,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         updated_at: DateTime<Utc>,
     },
+
+    /// A query was moved to another workspace.
+    QueryMovedToWorkspace {
+        query_id: SavedQueryId,
+        from_workspace_id: WorkspaceId,
+        to_workspace_id: WorkspaceId,
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        moved_at: DateTime<Utc>,
+    },
+
+    /// A query was executed.
+    QueryRun {
+        query_id: SavedQueryId,
+        run_at: DateTime<Utc>,
+    },
 }
 
 impl SavedQueryEvent {
@@ -67,7 +94,9 @@ impl SavedQueryEvent {
             | Self::QueryDeleted { query_id, .. }
             | Self::QueryRenamed { query_id, .. }
             | Self::QuerySqlUpdated { query_id, .. }
-            | Self::DatasetRefUpdated { query_id, .. } => *query_id,
+            | Self::DatasetRefUpdated { query_id, .. }
+            | Self::QueryMovedToWorkspace { query_id, .. }
+            | Self::QueryRun { query_id, .. } => *query_id,
         }
     }
 
@@ -80,6 +109,25 @@ impl SavedQueryEvent {
             Self::QueryRenamed { .. } => "QueryRenamed",
             Self::QuerySqlUpdated { .. } => "QuerySqlUpdated",
             Self::DatasetRefUpdated { .. } => "DatasetRefUpdated",
+            Self::QueryMovedToWorkspace { .. } => "QueryMovedToWorkspace",
+            Self::QueryRun { .. } => "QueryRun",
+        }
+    }
+
+    /// Who performed the action, for events that change the query.
+    ///
+    /// `QueryRun` records usage rather than a change and carries no actor.
+    /// Events stored before actors were recorded report the system actor.
+    #[must_use]
+    pub fn actor(&self) -> Option<UserId> {
+        match self {
+            Self::QuerySaved { actor, .. }
+            | Self::QueryDeleted { actor, .. }
+            | Self::QueryRenamed { actor, .. }
+            | Self::QuerySqlUpdated { actor, .. }
+            | Self::DatasetRefUpdated { actor, .. }
+            | Self::QueryMovedToWorkspace { actor, .. } => Some(*actor),
+            Self::QueryRun { .. } => None,
         }
     }
 
@@ -92,7 +140,7 @@ impl SavedQueryEvent {
 
 impl Identifier for SavedQueryEvent {
     fn identifier(&self) -> String {
-        format!("saved_query_{}", self.query_id())
+        self.query_id().identifier()
     }
 }
 
@@ -102,6 +150,20 @@ impl EventType for SavedQueryEvent {
     }
 }
 
+impl Timestamped for SavedQueryEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::QuerySaved { saved_at, .. } => *saved_at,
+            Self::QueryDeleted { deleted_at, .. } => *deleted_at,
+            Self::QueryRenamed { renamed_at, .. } => *renamed_at,
+            Self::QuerySqlUpdated { updated_at, .. }
+            | Self::DatasetRefUpdated { updated_at, .. } => *updated_at,
+            Self::QueryMovedToWorkspace { moved_at, .. } => *moved_at,
+            Self::QueryRun { run_at, .. } => *run_at,
+        }
+    }
+}
+
 impl DeciderType for SavedQueryEvent {
     fn decider_type(&self) -> String {
         "SavedQuery".to_string()
@@ -136,6 +198,7 @@ mod tests {
             name: QueryName::new("Test").unwrap(),
             sql: SqlQuery::new("SELECT 1").unwrap(),
             dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+            actor: UserId::system(),
             saved_at: sample_time(),
         };
 
@@ -149,6 +212,7 @@ mod tests {
         let original = SavedQueryEvent::QueryRenamed {
             query_id: sample_id(),
             name: QueryName::new("New Name").unwrap(),
+            actor: UserId::system(),
             renamed_at: sample_time(),
         };
 
@@ -165,6 +229,7 @@ mod tests {
             name: QueryName::new("Test").unwrap(),
             sql: SqlQuery::new("SELECT 1").unwrap(),
             dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+            actor: UserId::system(),
             saved_at: sample_time(),
         };
 
@@ -184,6 +249,7 @@ mod tests {
                     name: QueryName::new("Test").unwrap(),
                     sql: SqlQuery::new("SELECT 1").unwrap(),
                     dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+                    actor: UserId::system(),
                     saved_at: sample_time(),
                 },
                 "QuerySaved",
@@ -191,6 +257,7 @@ mod tests {
             (
                 SavedQueryEvent::QueryDeleted {
                     query_id: sample_id(),
+                    actor: UserId::system(),
                     deleted_at: sample_time(),
                 },
                 "QueryDeleted",
@@ -199,6 +266,7 @@ mod tests {
                 SavedQueryEvent::QueryRenamed {
                     query_id: sample_id(),
                     name: QueryName::new("Renamed").unwrap(),
+                    actor: UserId::system(),
                     renamed_at: sample_time(),
                 },
                 "QueryRenamed",
@@ -207,6 +275,7 @@ mod tests {
                 SavedQueryEvent::QuerySqlUpdated {
                     query_id: sample_id(),
                     sql: SqlQuery::new("SELECT 2").unwrap(),
+                    actor: UserId::system(),
                     updated_at: sample_time(),
                 },
                 "QuerySqlUpdated",
@@ -215,10 +284,28 @@ mod tests {
                 SavedQueryEvent::DatasetRefUpdated {
                     query_id: sample_id(),
                     dataset_ref: DatasetRef::new("s3://bucket/data").unwrap(),
+                    actor: UserId::system(),
                     updated_at: sample_time(),
                 },
                 "DatasetRefUpdated",
             ),
+            (
+                SavedQueryEvent::QueryMovedToWorkspace {
+                    query_id: sample_id(),
+                    from_workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
+                    to_workspace_id: WorkspaceId::from_uuid(uuid::Uuid::from_u128(1)),
+                    actor: UserId::system(),
+                    moved_at: sample_time(),
+                },
+                "QueryMovedToWorkspace",
+            ),
+            (
+                SavedQueryEvent::QueryRun {
+                    query_id: sample_id(),
+                    run_at: sample_time(),
+                },
+                "QueryRun",
+            ),
         ];
 
         for (event, expected_type) in events {
@@ -233,6 +320,7 @@ mod tests {
     fn is_final_returns_true_only_for_deleted() {
         let deleted = SavedQueryEvent::QueryDeleted {
             query_id: sample_id(),
+            actor: UserId::system(),
             deleted_at: sample_time(),
         };
         assert!(deleted.is_final());
@@ -243,6 +331,7 @@ mod tests {
             name: QueryName::new("Test").unwrap(),
             sql: SqlQuery::new("SELECT 1").unwrap(),
             dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+            actor: UserId::system(),
             saved_at: sample_time(),
         };
         assert!(!saved.is_final());
@@ -250,6 +339,7 @@ mod tests {
         let renamed = SavedQueryEvent::QueryRenamed {
             query_id: sample_id(),
             name: QueryName::new("New").unwrap(),
+            actor: UserId::system(),
             renamed_at: sample_time(),
         };
         assert!(!renamed.is_final());
@@ -8,7 +8,7 @@
 //!
 //! ```text
 //!                 ┌───────────┐
-//!  SaveQuery ────►│QueryExists│◄──── RenameQuery, UpdateSql, UpdateDatasetRef
+//!  SaveQuery ────►│QueryExists│◄──── RenameQuery, UpdateSql, UpdateDatasetRef, RecordRun
 //!                 └─────┬─────┘
 //!                       │
 //!                  DeleteQuery
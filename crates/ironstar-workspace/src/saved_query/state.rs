@@ -1,29 +1,32 @@
 //! SavedQuery aggregate state types.
 //!
 //! State is derived from events via replay. Uses a sum type enum with
-//! a terminal transition: DeleteQuery returns the aggregate to NoQuery.
+//! a terminal transition: DeleteQuery moves the aggregate to Deleted.
+
+use chrono::{DateTime, Utc};
 
 use super::values::{QueryName, SavedQueryId};
 use crate::workspace::WorkspaceId;
 use ironstar_analytics::{DatasetRef, SqlQuery};
+use ironstar_core::IsFinal;
 
 /// State of a saved query, derived from events.
 ///
 /// ```text
 ///                 ┌───────────┐
-///  SaveQuery ────►│QueryExists│◄──── RenameQuery, UpdateSql, UpdateDatasetRef
-///                 └─────┬─────┘
+///  SaveQuery ────►│QueryExists│◄──── RenameQuery, UpdateSql, UpdateDatasetRef,
+///                 └─────┬─────┘      MoveToWorkspace, RecordRun
 ///                       │
 ///                  DeleteQuery
 ///                       │
 ///                       ▼
 ///                 ┌───────────┐
-///                 │  NoQuery  │ (terminal / can be re-created)
+///                 │  Deleted  │ (terminal / can be re-created)
 ///                 └───────────┘
 /// ```
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum SavedQueryState {
-    /// Initial state before any query has been saved, or after deletion.
+    /// Initial state before any query has been saved.
     #[default]
     NoQuery,
 
@@ -39,7 +42,14 @@ pub enum SavedQueryState {
         sql: SqlQuery,
         /// Reference to the dataset this query targets.
         dataset_ref: DatasetRef,
+        /// Number of recorded executions.
+        run_count: u64,
+        /// When the query was last executed, if ever.
+        last_run_at: Option<DateTime<Utc>>,
     },
+
+    /// The query was deleted.
+    Deleted,
 }
 
 impl SavedQueryState {
@@ -53,7 +63,7 @@ impl SavedQueryState {
     #[must_use]
     pub fn query_id(&self) -> Option<&SavedQueryId> {
         match self {
-            Self::NoQuery => None,
+            Self::NoQuery | Self::Deleted => None,
             Self::QueryExists { query_id, .. } => Some(query_id),
         }
     }
@@ -62,7 +72,7 @@ impl SavedQueryState {
     #[must_use]
     pub fn workspace_id(&self) -> Option<&WorkspaceId> {
         match self {
-            Self::NoQuery => None,
+            Self::NoQuery | Self::Deleted => None,
             Self::QueryExists { workspace_id, .. } => Some(workspace_id),
         }
     }
@@ -71,7 +81,7 @@ impl SavedQueryState {
     #[must_use]
     pub fn name(&self) -> Option<&QueryName> {
         match self {
-            Self::NoQuery => None,
+            Self::NoQuery | Self::Deleted => None,
             Self::QueryExists { name, .. } => Some(name),
         }
     }
@@ -80,7 +90,7 @@ impl SavedQueryState {
     #[must_use]
     pub fn sql(&self) -> Option<&SqlQuery> {
         match self {
-            Self::NoQuery => None,
+            Self::NoQuery | Self::Deleted => None,
             Self::QueryExists { sql, .. } => Some(sql),
         }
     }
@@ -89,10 +99,36 @@ impl SavedQueryState {
     #[must_use]
     pub fn dataset_ref(&self) -> Option<&DatasetRef> {
         match self {
-            Self::NoQuery => None,
+            Self::NoQuery | Self::Deleted => None,
             Self::QueryExists { dataset_ref, .. } => Some(dataset_ref),
         }
     }
+
+    /// Number of recorded executions; zero when no query exists.
+    #[must_use]
+    pub fn run_count(&self) -> u64 {
+        match self {
+            Self::NoQuery | Self::Deleted => 0,
+            Self::QueryExists { run_count, .. } => *run_count,
+        }
+    }
+
+    /// When the query was last executed, if it exists and has been run.
+    #[must_use]
+    pub fn last_run_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::NoQuery | Self::Deleted => None,
+            Self::QueryExists { last_run_at, .. } => *last_run_at,
+        }
+    }
+}
+
+/// Only `Deleted` is terminal; `NoQuery` is the initial state a new stream
+/// starts from.
+impl IsFinal for SavedQueryState {
+    fn is_final(&self) -> bool {
+        matches!(self, Self::Deleted)
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +144,8 @@ mod tests {
         assert!(state.name().is_none());
         assert!(state.sql().is_none());
         assert!(state.dataset_ref().is_none());
+        assert_eq!(state.run_count(), 0);
+        assert!(state.last_run_at().is_none());
     }
 
     #[test]
@@ -124,6 +162,8 @@ mod tests {
             name: name.clone(),
             sql: sql.clone(),
             dataset_ref: dataset.clone(),
+            run_count: 0,
+            last_run_at: None,
         };
 
         assert!(state.exists());
@@ -132,5 +172,24 @@ mod tests {
         assert_eq!(state.name(), Some(&name));
         assert_eq!(state.sql(), Some(&sql));
         assert_eq!(state.dataset_ref(), Some(&dataset));
+        assert_eq!(state.run_count(), 0);
+        assert!(state.last_run_at().is_none());
+    }
+
+    #[test]
+    fn only_deleted_query_is_final() {
+        let state = SavedQueryState::QueryExists {
+            query_id: SavedQueryId::from_uuid(uuid::Uuid::nil()),
+            workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
+            name: QueryName::new("Test Query").unwrap(),
+            sql: SqlQuery::new("SELECT 1").unwrap(),
+            dataset_ref: DatasetRef::new("hf://datasets/test/data").unwrap(),
+            run_count: 0,
+            last_run_at: None,
+        };
+
+        assert!(SavedQueryState::Deleted.is_final());
+        assert!(!SavedQueryState::NoQuery.is_final());
+        assert!(!state.is_final());
     }
 }
@@ -8,6 +8,7 @@ use ts_rs::TS;
 use uuid::Uuid;
 
 use ironstar_core::BoundedString;
+use ironstar_core::Identifier;
 use ironstar_core::ValidationError;
 
 /// Maximum length for query names in characters.
@@ -38,7 +39,7 @@ impl SavedQueryId {
     /// Generate a new random SavedQueryId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a SavedQueryId.
@@ -69,6 +70,13 @@ impl std::fmt::Display for SavedQueryId {
     }
 }
 
+/// Stream id of the SavedQuery aggregate identified by this id: `saved_query_{uuid}`.
+impl Identifier for SavedQueryId {
+    fn identifier(&self) -> String {
+        format!("saved_query_{}", self.0)
+    }
+}
+
 // ============================================================================
 // QueryName - Validated name for a saved query
 // ============================================================================
@@ -180,6 +188,15 @@ mod tests {
             let copied = id;
             assert_eq!(id, copied);
         }
+
+        #[test]
+        fn identifier_is_prefixed_stream_id() {
+            let id = SavedQueryId::from_uuid(Uuid::nil());
+            assert_eq!(
+                id.identifier(),
+                "saved_query_00000000-0000-0000-0000-000000000000"
+            );
+        }
     }
 
     mod query_name {
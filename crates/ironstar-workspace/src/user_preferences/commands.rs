@@ -8,7 +8,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use super::values::{Locale, PreferencesId, Theme, UiState};
+use super::values::{Locale, NotificationPreferences, PreferencesId, Theme, UiState};
+use crate::workspace::values::WorkspaceId;
 use ironstar_core::{DeciderType, Identifier};
 use ironstar_shared_kernel::UserId;
 
@@ -58,6 +59,45 @@ pub enum UserPreferencesCommand {
         ui_state: UiState,
         updated_at: DateTime<Utc>,
     },
+
+    /// Set the workspace this user lands on after login.
+    ///
+    /// Requires preferences to be initialized. Idempotent when
+    /// setting the same workspace.
+    SetDefaultWorkspace {
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+        set_at: DateTime<Utc>,
+    },
+
+    /// Clear the default workspace.
+    ///
+    /// Requires preferences to be initialized. Idempotent when no
+    /// default workspace is set.
+    ClearDefaultWorkspace {
+        user_id: UserId,
+        cleared_at: DateTime<Utc>,
+    },
+
+    /// Record that the user opened a workspace.
+    ///
+    /// Requires preferences to be initialized. Idempotent when the
+    /// workspace is already the most recently viewed.
+    RecordWorkspaceView {
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+        viewed_at: DateTime<Utc>,
+    },
+
+    /// Set email, in-app, and digest notification settings.
+    ///
+    /// Requires preferences to be initialized. Idempotent when
+    /// setting the same notification preferences.
+    SetNotificationPreferences {
+        user_id: UserId,
+        notifications: NotificationPreferences,
+        set_at: DateTime<Utc>,
+    },
 }
 
 impl UserPreferencesCommand {
@@ -68,7 +108,11 @@ impl UserPreferencesCommand {
             Self::InitializePreferences { user_id, .. }
             | Self::SetTheme { user_id, .. }
             | Self::SetLocale { user_id, .. }
-            | Self::UpdateUiState { user_id, .. } => *user_id,
+            | Self::UpdateUiState { user_id, .. }
+            | Self::SetDefaultWorkspace { user_id, .. }
+            | Self::ClearDefaultWorkspace { user_id, .. }
+            | Self::RecordWorkspaceView { user_id, .. }
+            | Self::SetNotificationPreferences { user_id, .. } => *user_id,
         }
     }
 
@@ -80,6 +124,10 @@ impl UserPreferencesCommand {
             Self::SetTheme { .. } => "SetTheme",
             Self::SetLocale { .. } => "SetLocale",
             Self::UpdateUiState { .. } => "UpdateUiState",
+            Self::SetDefaultWorkspace { .. } => "SetDefaultWorkspace",
+            Self::ClearDefaultWorkspace { .. } => "ClearDefaultWorkspace",
+            Self::RecordWorkspaceView { .. } => "RecordWorkspaceView",
+            Self::SetNotificationPreferences { .. } => "SetNotificationPreferences",
         }
     }
 }
@@ -182,4 +230,58 @@ mod tests {
             assert_eq!(cmd.user_id(), user_id);
         }
     }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            UserPreferencesCommand::InitializePreferences {
+                preferences_id: PreferencesId::new(),
+                user_id: UserId::new(),
+                initialized_at: ts,
+            },
+            UserPreferencesCommand::SetTheme {
+                user_id: UserId::new(),
+                theme: Theme::System,
+                set_at: ts,
+            },
+            UserPreferencesCommand::SetLocale {
+                user_id: UserId::new(),
+                locale: Locale::new("de-DE").unwrap(),
+                set_at: ts,
+            },
+            UserPreferencesCommand::UpdateUiState {
+                user_id: UserId::new(),
+                ui_state: UiState::new(r#"{"sidebar":"collapsed"}"#),
+                updated_at: ts,
+            },
+            UserPreferencesCommand::SetDefaultWorkspace {
+                user_id: UserId::new(),
+                workspace_id: WorkspaceId::new(),
+                set_at: ts,
+            },
+            UserPreferencesCommand::ClearDefaultWorkspace {
+                user_id: UserId::new(),
+                cleared_at: ts,
+            },
+            UserPreferencesCommand::RecordWorkspaceView {
+                user_id: UserId::new(),
+                workspace_id: WorkspaceId::new(),
+                viewed_at: ts,
+            },
+            UserPreferencesCommand::SetNotificationPreferences {
+                user_id: UserId::new(),
+                notifications: NotificationPreferences::default(),
+                set_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: UserPreferencesCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
 }
@@ -15,7 +15,9 @@
 //!          ┌──────────────────┼──────────────────┐
 //!          │                  │                   │
 //!       SetTheme          SetLocale        UpdateUiState
-//!          │                  │                   │
+//!          │                  │            Set/ClearDefaultWorkspace
+//!          │                  │            RecordWorkspaceView
+//!          │                  │            SetNotificationPreferences
 //!          └──────────────────┴──────────────────-┘
 //!                             │
 //!                             ▼
@@ -30,6 +32,10 @@
 //! - SetTheme with same theme returns `Ok(vec![])`
 //! - SetLocale with same locale returns `Ok(vec![])`
 //! - UpdateUiState with same state returns `Ok(vec![])`
+//! - SetDefaultWorkspace with the current default returns `Ok(vec![])`
+//! - ClearDefaultWorkspace with no default set returns `Ok(vec![])`
+//! - RecordWorkspaceView of the most recent workspace returns `Ok(vec![])`
+//! - SetNotificationPreferences with unchanged settings returns `Ok(vec![])`
 
 use ironstar_core::Decider;
 use tracing::instrument;
@@ -38,7 +44,7 @@ use super::commands::UserPreferencesCommand;
 use super::errors::UserPreferencesError;
 use super::events::UserPreferencesEvent;
 use super::state::UserPreferencesState;
-use super::values::{Locale, Theme, UiState};
+use super::values::{Locale, NotificationPreferences, RecentWorkspaces, Theme, UiState};
 
 /// Type alias for the UserPreferences Decider.
 pub type UserPreferencesDecider<'a> = Decider<
@@ -178,6 +184,112 @@ fn decide(
         (UserPreferencesCommand::UpdateUiState { .. }, UserPreferencesState::NotInitialized) => {
             Err(UserPreferencesError::not_initialized())
         }
+
+        // SetDefaultWorkspace: Initialized -> Initialized (idempotent if same workspace)
+        (
+            UserPreferencesCommand::SetDefaultWorkspace {
+                user_id,
+                workspace_id,
+                set_at,
+            },
+            UserPreferencesState::Initialized {
+                default_workspace, ..
+            },
+        ) => {
+            if *default_workspace == Some(*workspace_id) {
+                return Ok(vec![]);
+            }
+
+            Ok(vec![UserPreferencesEvent::DefaultWorkspaceSet {
+                user_id: *user_id,
+                workspace_id: *workspace_id,
+                set_at: *set_at,
+            }])
+        }
+
+        // ClearDefaultWorkspace: Initialized -> Initialized (idempotent if none set)
+        (
+            UserPreferencesCommand::ClearDefaultWorkspace {
+                user_id,
+                cleared_at,
+            },
+            UserPreferencesState::Initialized {
+                default_workspace, ..
+            },
+        ) => {
+            if default_workspace.is_none() {
+                return Ok(vec![]);
+            }
+
+            Ok(vec![UserPreferencesEvent::DefaultWorkspaceCleared {
+                user_id: *user_id,
+                cleared_at: *cleared_at,
+            }])
+        }
+
+        // Default workspace changes when not initialized
+        (
+            UserPreferencesCommand::SetDefaultWorkspace { .. }
+            | UserPreferencesCommand::ClearDefaultWorkspace { .. },
+            UserPreferencesState::NotInitialized,
+        ) => Err(UserPreferencesError::not_initialized()),
+
+        // RecordWorkspaceView: Initialized -> Initialized (idempotent if already most recent)
+        (
+            UserPreferencesCommand::RecordWorkspaceView {
+                user_id,
+                workspace_id,
+                viewed_at,
+            },
+            UserPreferencesState::Initialized {
+                recent_workspaces, ..
+            },
+        ) => {
+            if recent_workspaces.most_recent() == Some(*workspace_id) {
+                return Ok(vec![]);
+            }
+
+            Ok(vec![UserPreferencesEvent::WorkspaceViewed {
+                user_id: *user_id,
+                workspace_id: *workspace_id,
+                viewed_at: *viewed_at,
+            }])
+        }
+
+        // RecordWorkspaceView when not initialized
+        (
+            UserPreferencesCommand::RecordWorkspaceView { .. },
+            UserPreferencesState::NotInitialized,
+        ) => Err(UserPreferencesError::not_initialized()),
+
+        // SetNotificationPreferences: Initialized -> Initialized (idempotent if unchanged)
+        (
+            UserPreferencesCommand::SetNotificationPreferences {
+                user_id,
+                notifications,
+                set_at,
+            },
+            UserPreferencesState::Initialized {
+                notifications: current_notifications,
+                ..
+            },
+        ) => {
+            if current_notifications == notifications {
+                return Ok(vec![]);
+            }
+
+            Ok(vec![UserPreferencesEvent::NotificationPreferencesSet {
+                user_id: *user_id,
+                notifications: *notifications,
+                set_at: *set_at,
+            }])
+        }
+
+        // SetNotificationPreferences when not initialized
+        (
+            UserPreferencesCommand::SetNotificationPreferences { .. },
+            UserPreferencesState::NotInitialized,
+        ) => Err(UserPreferencesError::not_initialized()),
     };
     if let Ok(ref events) = result {
         tracing::debug!(event_count = events.len(), "decision complete");
@@ -193,70 +305,53 @@ fn decide(
     fields(aggregate_type = "UserPreferences")
 )]
 fn evolve(state: &UserPreferencesState, event: &UserPreferencesEvent) -> UserPreferencesState {
-    match event {
-        UserPreferencesEvent::PreferencesInitialized {
-            preferences_id,
-            user_id,
-            ..
-        } => UserPreferencesState::Initialized {
+    if let UserPreferencesEvent::PreferencesInitialized {
+        preferences_id,
+        user_id,
+        ..
+    } = event
+    {
+        return UserPreferencesState::Initialized {
             preferences_id: *preferences_id,
             user_id: *user_id,
             theme: Theme::default(),
             locale: Locale::default(),
             ui_state: UiState::default(),
-        },
-
-        UserPreferencesEvent::ThemeSet { theme, .. } => match state {
-            UserPreferencesState::Initialized {
-                preferences_id,
-                user_id,
-                locale,
-                ui_state,
-                ..
-            } => UserPreferencesState::Initialized {
-                preferences_id: *preferences_id,
-                user_id: *user_id,
-                theme: *theme,
-                locale: locale.clone(),
-                ui_state: ui_state.clone(),
-            },
-            UserPreferencesState::NotInitialized => state.clone(),
-        },
-
-        UserPreferencesEvent::LocaleSet { locale, .. } => match state {
-            UserPreferencesState::Initialized {
-                preferences_id,
-                user_id,
-                theme,
-                ui_state,
-                ..
-            } => UserPreferencesState::Initialized {
-                preferences_id: *preferences_id,
-                user_id: *user_id,
-                theme: *theme,
-                locale: locale.clone(),
-                ui_state: ui_state.clone(),
-            },
-            UserPreferencesState::NotInitialized => state.clone(),
-        },
+            notifications: NotificationPreferences::default(),
+            default_workspace: None,
+            recent_workspaces: RecentWorkspaces::default(),
+        };
+    }
 
-        UserPreferencesEvent::UiStateUpdated { ui_state, .. } => match state {
-            UserPreferencesState::Initialized {
-                preferences_id,
-                user_id,
-                theme,
-                locale,
-                ..
-            } => UserPreferencesState::Initialized {
-                preferences_id: *preferences_id,
-                user_id: *user_id,
-                theme: *theme,
-                locale: locale.clone(),
-                ui_state: ui_state.clone(),
-            },
-            UserPreferencesState::NotInitialized => state.clone(),
-        },
+    let mut next = state.clone();
+    if let UserPreferencesState::Initialized {
+        theme,
+        locale,
+        ui_state,
+        notifications,
+        default_workspace,
+        recent_workspaces,
+        ..
+    } = &mut next
+    {
+        match event {
+            UserPreferencesEvent::PreferencesInitialized { .. } => {}
+            UserPreferencesEvent::ThemeSet { theme: new, .. } => *theme = *new,
+            UserPreferencesEvent::LocaleSet { locale: new, .. } => *locale = new.clone(),
+            UserPreferencesEvent::UiStateUpdated { ui_state: new, .. } => *ui_state = new.clone(),
+            UserPreferencesEvent::DefaultWorkspaceSet { workspace_id, .. } => {
+                *default_workspace = Some(*workspace_id);
+            }
+            UserPreferencesEvent::DefaultWorkspaceCleared { .. } => *default_workspace = None,
+            UserPreferencesEvent::WorkspaceViewed { workspace_id, .. } => {
+                *recent_workspaces = recent_workspaces.record(*workspace_id);
+            }
+            UserPreferencesEvent::NotificationPreferencesSet {
+                notifications: new, ..
+            } => *notifications = *new,
+        }
     }
+    next
 }
 
 #[cfg(test)]
@@ -265,7 +360,8 @@ mod tests {
     use chrono::{DateTime, Utc};
     use ironstar_core::DeciderTestSpecification;
 
-    use super::super::values::PreferencesId;
+    use super::super::values::{DigestFrequency, PreferencesId};
+    use crate::workspace::values::WorkspaceId;
     use ironstar_shared_kernel::UserId;
 
     fn sample_user_id() -> UserId {
@@ -497,6 +593,240 @@ mod tests {
             .then_error(UserPreferencesError::not_initialized());
     }
 
+    // --- Default workspace transitions ---
+
+    fn sample_workspace_id() -> WorkspaceId {
+        WorkspaceId::from_uuid(uuid::Uuid::from_u128(1))
+    }
+
+    fn default_workspace_set_event() -> UserPreferencesEvent {
+        UserPreferencesEvent::DefaultWorkspaceSet {
+            user_id: sample_user_id(),
+            workspace_id: sample_workspace_id(),
+            set_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn set_default_workspace_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event()])
+            .when(UserPreferencesCommand::SetDefaultWorkspace {
+                user_id: sample_user_id(),
+                workspace_id: sample_workspace_id(),
+                set_at: sample_time(),
+            })
+            .then(vec![default_workspace_set_event()]);
+    }
+
+    #[test]
+    fn set_default_workspace_same_value_is_idempotent() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event(), default_workspace_set_event()])
+            .when(UserPreferencesCommand::SetDefaultWorkspace {
+                user_id: sample_user_id(),
+                workspace_id: sample_workspace_id(),
+                set_at: sample_time(),
+            })
+            .then(vec![]);
+    }
+
+    #[test]
+    fn set_default_workspace_not_initialized_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![])
+            .when(UserPreferencesCommand::SetDefaultWorkspace {
+                user_id: sample_user_id(),
+                workspace_id: sample_workspace_id(),
+                set_at: sample_time(),
+            })
+            .then_error(UserPreferencesError::not_initialized());
+    }
+
+    #[test]
+    fn clear_default_workspace_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event(), default_workspace_set_event()])
+            .when(UserPreferencesCommand::ClearDefaultWorkspace {
+                user_id: sample_user_id(),
+                cleared_at: sample_time(),
+            })
+            .then(vec![UserPreferencesEvent::DefaultWorkspaceCleared {
+                user_id: sample_user_id(),
+                cleared_at: sample_time(),
+            }]);
+    }
+
+    #[test]
+    fn clear_default_workspace_when_unset_is_idempotent() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event()])
+            .when(UserPreferencesCommand::ClearDefaultWorkspace {
+                user_id: sample_user_id(),
+                cleared_at: sample_time(),
+            })
+            .then(vec![]);
+    }
+
+    #[test]
+    fn default_workspace_evolves_through_set_and_clear() {
+        let state = evolve(&UserPreferencesState::default(), &initialized_event());
+        assert_eq!(state.default_workspace(), None);
+
+        let state = evolve(&state, &default_workspace_set_event());
+        assert_eq!(state.default_workspace(), Some(sample_workspace_id()));
+
+        let state = evolve(
+            &state,
+            &UserPreferencesEvent::DefaultWorkspaceCleared {
+                user_id: sample_user_id(),
+                cleared_at: sample_time(),
+            },
+        );
+        assert_eq!(state.default_workspace(), None);
+        assert!(state.is_initialized());
+    }
+
+    // --- Recently viewed workspaces ---
+
+    fn workspace_viewed_event(n: u128) -> UserPreferencesEvent {
+        UserPreferencesEvent::WorkspaceViewed {
+            user_id: sample_user_id(),
+            workspace_id: WorkspaceId::from_uuid(uuid::Uuid::from_u128(n)),
+            viewed_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn record_workspace_view_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event(), workspace_viewed_event(2)])
+            .when(UserPreferencesCommand::RecordWorkspaceView {
+                user_id: sample_user_id(),
+                workspace_id: sample_workspace_id(),
+                viewed_at: sample_time(),
+            })
+            .then(vec![workspace_viewed_event(1)]);
+    }
+
+    #[test]
+    fn record_view_of_most_recent_workspace_is_idempotent() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event(), workspace_viewed_event(1)])
+            .when(UserPreferencesCommand::RecordWorkspaceView {
+                user_id: sample_user_id(),
+                workspace_id: sample_workspace_id(),
+                viewed_at: sample_time(),
+            })
+            .then(vec![]);
+    }
+
+    #[test]
+    fn record_workspace_view_not_initialized_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![])
+            .when(UserPreferencesCommand::RecordWorkspaceView {
+                user_id: sample_user_id(),
+                workspace_id: sample_workspace_id(),
+                viewed_at: sample_time(),
+            })
+            .then_error(UserPreferencesError::not_initialized());
+    }
+
+    #[test]
+    fn workspace_views_evolve_most_recent_first() {
+        let state = [1, 2, 3, 1].into_iter().map(workspace_viewed_event).fold(
+            evolve(&UserPreferencesState::default(), &initialized_event()),
+            |state, event| evolve(&state, &event),
+        );
+
+        let recent: Vec<u128> = state
+            .recent_workspaces()
+            .unwrap()
+            .as_slice()
+            .iter()
+            .map(|id| id.into_inner().as_u128())
+            .collect();
+        assert_eq!(recent, [1, 3, 2]);
+    }
+
+    // --- Notification preferences ---
+
+    fn email_weekly() -> NotificationPreferences {
+        NotificationPreferences::new(true, true, DigestFrequency::Weekly)
+    }
+
+    #[test]
+    fn set_notification_preferences_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event()])
+            .when(UserPreferencesCommand::SetNotificationPreferences {
+                user_id: sample_user_id(),
+                notifications: email_weekly(),
+                set_at: sample_time(),
+            })
+            .then(vec![UserPreferencesEvent::NotificationPreferencesSet {
+                user_id: sample_user_id(),
+                notifications: email_weekly(),
+                set_at: sample_time(),
+            }]);
+    }
+
+    #[test]
+    fn set_notification_preferences_unchanged_is_idempotent() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![
+                initialized_event(),
+                UserPreferencesEvent::NotificationPreferencesSet {
+                    user_id: sample_user_id(),
+                    notifications: email_weekly(),
+                    set_at: sample_time(),
+                },
+            ])
+            .when(UserPreferencesCommand::SetNotificationPreferences {
+                user_id: sample_user_id(),
+                notifications: email_weekly(),
+                set_at: sample_time(),
+            })
+            .then(vec![]);
+    }
+
+    #[test]
+    fn set_default_notification_preferences_is_idempotent() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![initialized_event()])
+            .when(UserPreferencesCommand::SetNotificationPreferences {
+                user_id: sample_user_id(),
+                notifications: NotificationPreferences::default(),
+                set_at: sample_time(),
+            })
+            .then(vec![]);
+    }
+
+    #[test]
+    fn set_notification_preferences_not_initialized_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(user_preferences_decider())
+            .given(vec![])
+            .when(UserPreferencesCommand::SetNotificationPreferences {
+                user_id: sample_user_id(),
+                notifications: email_weekly(),
+                set_at: sample_time(),
+            })
+            .then_error(UserPreferencesError::not_initialized());
+    }
+
     // --- Full lifecycle ---
 
     #[test]
@@ -7,8 +7,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use super::values::{Locale, PreferencesId, Theme, UiState};
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use super::values::{Locale, NotificationPreferences, PreferencesId, Theme, UiState};
+use crate::workspace::values::WorkspaceId;
+use ironstar_core::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
 use ironstar_shared_kernel::UserId;
 
 /// Events emitted by the UserPreferences aggregate.
@@ -43,6 +44,33 @@ pub enum UserPreferencesEvent {
         ui_state: UiState,
         updated_at: DateTime<Utc>,
     },
+
+    /// Default workspace was set.
+    DefaultWorkspaceSet {
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+        set_at: DateTime<Utc>,
+    },
+
+    /// Default workspace was cleared.
+    DefaultWorkspaceCleared {
+        user_id: UserId,
+        cleared_at: DateTime<Utc>,
+    },
+
+    /// User opened a workspace.
+    WorkspaceViewed {
+        user_id: UserId,
+        workspace_id: WorkspaceId,
+        viewed_at: DateTime<Utc>,
+    },
+
+    /// Notification settings were changed.
+    NotificationPreferencesSet {
+        user_id: UserId,
+        notifications: NotificationPreferences,
+        set_at: DateTime<Utc>,
+    },
 }
 
 impl UserPreferencesEvent {
@@ -53,7 +81,11 @@ impl UserPreferencesEvent {
             Self::PreferencesInitialized { user_id, .. }
             | Self::ThemeSet { user_id, .. }
             | Self::LocaleSet { user_id, .. }
-            | Self::UiStateUpdated { user_id, .. } => *user_id,
+            | Self::UiStateUpdated { user_id, .. }
+            | Self::DefaultWorkspaceSet { user_id, .. }
+            | Self::DefaultWorkspaceCleared { user_id, .. }
+            | Self::WorkspaceViewed { user_id, .. }
+            | Self::NotificationPreferencesSet { user_id, .. } => *user_id,
         }
     }
 
@@ -65,6 +97,10 @@ impl UserPreferencesEvent {
             Self::ThemeSet { .. } => "ThemeSet",
             Self::LocaleSet { .. } => "LocaleSet",
             Self::UiStateUpdated { .. } => "UiStateUpdated",
+            Self::DefaultWorkspaceSet { .. } => "DefaultWorkspaceSet",
+            Self::DefaultWorkspaceCleared { .. } => "DefaultWorkspaceCleared",
+            Self::WorkspaceViewed { .. } => "WorkspaceViewed",
+            Self::NotificationPreferencesSet { .. } => "NotificationPreferencesSet",
         }
     }
 
@@ -87,6 +123,21 @@ impl EventType for UserPreferencesEvent {
     }
 }
 
+impl Timestamped for UserPreferencesEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::PreferencesInitialized { initialized_at, .. } => *initialized_at,
+            Self::ThemeSet { set_at, .. }
+            | Self::LocaleSet { set_at, .. }
+            | Self::DefaultWorkspaceSet { set_at, .. }
+            | Self::NotificationPreferencesSet { set_at, .. } => *set_at,
+            Self::UiStateUpdated { updated_at, .. } => *updated_at,
+            Self::DefaultWorkspaceCleared { cleared_at, .. } => *cleared_at,
+            Self::WorkspaceViewed { viewed_at, .. } => *viewed_at,
+        }
+    }
+}
+
 impl DeciderType for UserPreferencesEvent {
     fn decider_type(&self) -> String {
         "UserPreferences".to_string()
@@ -192,6 +243,37 @@ mod tests {
                 },
                 "UiStateUpdated",
             ),
+            (
+                UserPreferencesEvent::DefaultWorkspaceSet {
+                    user_id: sample_id(),
+                    workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
+                    set_at: sample_time(),
+                },
+                "DefaultWorkspaceSet",
+            ),
+            (
+                UserPreferencesEvent::DefaultWorkspaceCleared {
+                    user_id: sample_id(),
+                    cleared_at: sample_time(),
+                },
+                "DefaultWorkspaceCleared",
+            ),
+            (
+                UserPreferencesEvent::WorkspaceViewed {
+                    user_id: sample_id(),
+                    workspace_id: WorkspaceId::from_uuid(uuid::Uuid::nil()),
+                    viewed_at: sample_time(),
+                },
+                "WorkspaceViewed",
+            ),
+            (
+                UserPreferencesEvent::NotificationPreferencesSet {
+                    user_id: sample_id(),
+                    notifications: NotificationPreferences::default(),
+                    set_at: sample_time(),
+                },
+                "NotificationPreferencesSet",
+            ),
         ];
 
         for (event, expected_type) in events {
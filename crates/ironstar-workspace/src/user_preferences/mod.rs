@@ -1,7 +1,8 @@
 //! UserPreferences aggregate for user-scoped personal settings.
 //!
 //! Manages per-user settings that follow the user across all workspaces:
-//! theme, locale, and arbitrary UI state as JSON.
+//! theme, locale, arbitrary UI state as JSON, notification settings, a
+//! default workspace, and the most recently viewed workspaces.
 //! This is distinct from WorkspacePreferences (workspace-scoped, shared
 //! across all users in a workspace).
 //!
@@ -15,7 +16,9 @@
 //!          ┌──────────────────┼──────────────────┐
 //!          │                  │                   │
 //!       SetTheme          SetLocale        UpdateUiState
-//!          │                  │                   │
+//!          │                  │            Set/ClearDefaultWorkspace
+//!          │                  │            RecordWorkspaceView
+//!          │                  │            SetNotificationPreferences
 //!          └──────────────────┴──────────────────-┘
 //!                             │
 //!                             ▼
@@ -40,7 +43,8 @@
 //! - [`errors`]: UserPreferencesError with UUID tracking
 //! - [`events`]: UserPreferencesEvent enum
 //! - [`state`]: UserPreferencesState enum (NotInitialized | Initialized)
-//! - [`values`]: Value objects (PreferencesId, Theme, Locale, UiState)
+//! - [`values`]: Value objects (PreferencesId, Theme, Locale, UiState,
+//!   RecentWorkspaces, NotificationPreferences)
 
 pub mod commands;
 pub mod decider;
@@ -54,4 +58,7 @@ pub use decider::{UserPreferencesDecider, user_preferences_decider};
 pub use errors::{UserPreferencesError, UserPreferencesErrorKind};
 pub use events::UserPreferencesEvent;
 pub use state::UserPreferencesState;
-pub use values::{LOCALE_MAX_LENGTH, Locale, PreferencesId, Theme, UiState};
+pub use values::{
+    DigestFrequency, LOCALE_MAX_LENGTH, Locale, MAX_RECENT_WORKSPACES, NotificationPreferences,
+    PreferencesId, RecentWorkspaces, Theme, UiState,
+};
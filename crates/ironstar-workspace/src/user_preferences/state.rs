@@ -3,7 +3,10 @@
 //! State is derived from events via replay. Uses a sum type enum following
 //! the WorkspacePreferences aggregate pattern for clean state machine semantics.
 
-use super::values::{Locale, PreferencesId, Theme, UiState};
+use super::values::{
+    Locale, NotificationPreferences, PreferencesId, RecentWorkspaces, Theme, UiState,
+};
+use crate::workspace::values::WorkspaceId;
 use ironstar_shared_kernel::UserId;
 
 /// State of user preferences, derived from events.
@@ -16,7 +19,9 @@ use ironstar_shared_kernel::UserId;
 ///          ┌──────────────────┼──────────────────┐
 ///          │                  │                   │
 ///       SetTheme          SetLocale        UpdateUiState
-///          │                  │                   │
+///          │                  │            Set/ClearDefaultWorkspace
+///          │                  │            RecordWorkspaceView
+///          │                  │            SetNotificationPreferences
 ///          └──────────────────┴──────────────────-┘
 ///                             │
 ///                             ▼
@@ -42,6 +47,12 @@ pub enum UserPreferencesState {
         locale: Locale,
         /// Arbitrary UI state as JSON.
         ui_state: UiState,
+        /// Email, in-app, and digest notification settings.
+        notifications: NotificationPreferences,
+        /// Workspace the user lands on after login.
+        default_workspace: Option<WorkspaceId>,
+        /// Most recently viewed workspaces, newest first.
+        recent_workspaces: RecentWorkspaces,
     },
 }
 
@@ -96,6 +107,37 @@ impl UserPreferencesState {
             Self::Initialized { ui_state, .. } => Some(ui_state),
         }
     }
+
+    /// Get the notification settings, if initialized.
+    #[must_use]
+    pub fn notifications(&self) -> Option<&NotificationPreferences> {
+        match self {
+            Self::NotInitialized => None,
+            Self::Initialized { notifications, .. } => Some(notifications),
+        }
+    }
+
+    /// Get the default workspace, if initialized and set.
+    #[must_use]
+    pub fn default_workspace(&self) -> Option<WorkspaceId> {
+        match self {
+            Self::NotInitialized => None,
+            Self::Initialized {
+                default_workspace, ..
+            } => *default_workspace,
+        }
+    }
+
+    /// Get the most recently viewed workspaces, if initialized.
+    #[must_use]
+    pub fn recent_workspaces(&self) -> Option<&RecentWorkspaces> {
+        match self {
+            Self::NotInitialized => None,
+            Self::Initialized {
+                recent_workspaces, ..
+            } => Some(recent_workspaces),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +153,9 @@ mod tests {
         assert!(state.theme().is_none());
         assert!(state.locale().is_none());
         assert!(state.ui_state().is_none());
+        assert!(state.notifications().is_none());
+        assert!(state.default_workspace().is_none());
+        assert!(state.recent_workspaces().is_none());
     }
 
     #[test]
@@ -123,6 +168,9 @@ mod tests {
             theme: Theme::Dark,
             locale: Locale::new("fr-FR").unwrap(),
             ui_state: UiState::new(r#"{"sidebar": "open"}"#),
+            notifications: NotificationPreferences::default(),
+            default_workspace: Some(WorkspaceId::from_uuid(uuid::Uuid::nil())),
+            recent_workspaces: RecentWorkspaces::default(),
         };
 
         assert!(state.is_initialized());
@@ -131,5 +179,17 @@ mod tests {
         assert_eq!(state.theme(), Some(&Theme::Dark));
         assert_eq!(state.locale().unwrap().as_str(), "fr-FR");
         assert_eq!(state.ui_state().unwrap().as_str(), r#"{"sidebar": "open"}"#);
+        assert_eq!(
+            state.notifications(),
+            Some(&NotificationPreferences::default())
+        );
+        assert_eq!(
+            state.default_workspace(),
+            Some(WorkspaceId::from_uuid(uuid::Uuid::nil()))
+        );
+        assert_eq!(
+            state.recent_workspaces(),
+            Some(&RecentWorkspaces::default())
+        );
     }
 }
@@ -4,6 +4,8 @@
 //! - `Theme`: Visual theme selection (Light, Dark, System)
 //! - `Locale`: Validated BCP-47 language tag
 //! - `UiState`: Opaque JSON string for arbitrary UI state
+//! - `RecentWorkspaces`: Bounded most-recently-viewed workspace list
+//! - `NotificationPreferences`: Email, in-app, and digest notification settings
 
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -11,9 +13,14 @@ use uuid::Uuid;
 
 use ironstar_core::{ValidationError, ValidationErrorKind};
 
+use crate::workspace::values::WorkspaceId;
+
 /// Maximum length for a BCP-47 locale tag in characters.
 pub const LOCALE_MAX_LENGTH: usize = 35;
 
+/// Maximum number of workspaces kept in [`RecentWorkspaces`].
+pub const MAX_RECENT_WORKSPACES: usize = 10;
+
 /// Unique identifier for a UserPreferences instance.
 ///
 /// Wraps a UUID v4, providing type safety to prevent mixing up different
@@ -32,7 +39,7 @@ impl PreferencesId {
     /// Generate a new random PreferencesId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a PreferencesId.
@@ -66,6 +73,8 @@ impl std::fmt::Display for PreferencesId {
 /// Visual theme selection for the user interface.
 ///
 /// Defaults to `System`, which defers to the operating system preference.
+/// Parsing from a string accepts only `light`, `dark`, or `system`
+/// (case-insensitive), matching the `Display` form.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "domain/")]
 pub enum Theme {
@@ -85,6 +94,97 @@ impl std::fmt::Display for Theme {
     }
 }
 
+impl std::str::FromStr for Theme {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "light" => Ok(Self::Light),
+            "dark" => Ok(Self::Dark),
+            "system" => Ok(Self::System),
+            _ => Err(ValidationError::new(ValidationErrorKind::InvalidFormat {
+                field: "theme".to_string(),
+                expected: "one of light, dark, system".to_string(),
+            })),
+        }
+    }
+}
+
+/// How often a notification digest email is sent.
+///
+/// Defaults to `Off`. Parsing from a string accepts only `off`, `daily`, or
+/// `weekly` (case-insensitive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "domain/")]
+#[serde(rename_all = "lowercase")]
+pub enum DigestFrequency {
+    /// No digest.
+    #[default]
+    Off,
+    /// One digest per day.
+    Daily,
+    /// One digest per week.
+    Weekly,
+}
+
+impl std::fmt::Display for DigestFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::Daily => write!(f, "daily"),
+            Self::Weekly => write!(f, "weekly"),
+        }
+    }
+}
+
+impl std::str::FromStr for DigestFrequency {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(Self::Off),
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            _ => Err(ValidationError::new(ValidationErrorKind::InvalidFormat {
+                field: "digest".to_string(),
+                expected: "one of off, daily, weekly".to_string(),
+            })),
+        }
+    }
+}
+
+/// Notification settings for a user.
+///
+/// Defaults to in-app notifications only, with no email and no digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "domain/")]
+pub struct NotificationPreferences {
+    /// Send notifications by email.
+    pub email_enabled: bool,
+    /// Show notifications in the application.
+    pub in_app_enabled: bool,
+    /// Digest email frequency.
+    pub digest: DigestFrequency,
+}
+
+impl NotificationPreferences {
+    /// Create notification preferences.
+    #[must_use]
+    pub fn new(email_enabled: bool, in_app_enabled: bool, digest: DigestFrequency) -> Self {
+        Self {
+            email_enabled,
+            in_app_enabled,
+            digest,
+        }
+    }
+}
+
+impl Default for NotificationPreferences {
+    fn default() -> Self {
+        Self::new(false, true, DigestFrequency::Off)
+    }
+}
+
 /// Validated BCP-47 language tag.
 ///
 /// Structural guarantees:
@@ -133,6 +233,23 @@ impl Locale {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Tags to try when looking up locale-specific data, most specific first.
+    ///
+    /// Drops one trailing subtag at a time, so `zh-Hant-TW` yields
+    /// `zh-Hant-TW`, `zh-Hant`, `zh`.
+    #[must_use]
+    pub fn fallback_chain(&self) -> Vec<&str> {
+        let mut chain = vec![self.as_str()];
+        let mut tag = self.as_str();
+        while let Some((prefix, _)) = tag.rsplit_once('-') {
+            if !prefix.is_empty() {
+                chain.push(prefix);
+            }
+            tag = prefix;
+        }
+        chain
+    }
 }
 
 impl Default for Locale {
@@ -208,6 +325,43 @@ impl From<UiState> for String {
     }
 }
 
+/// Most recently viewed workspaces, newest first.
+///
+/// Holds at most [`MAX_RECENT_WORKSPACES`] entries without duplicates:
+/// viewing a workspace already in the list moves it to the front, and the
+/// oldest entry is dropped once the bound is exceeded.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RecentWorkspaces(Vec<WorkspaceId>);
+
+impl RecentWorkspaces {
+    /// Return the list with `workspace_id` recorded as the most recent view.
+    #[must_use]
+    pub fn record(&self, workspace_id: WorkspaceId) -> Self {
+        let mut workspaces = Vec::with_capacity(MAX_RECENT_WORKSPACES);
+        workspaces.push(workspace_id);
+        workspaces.extend(
+            self.0
+                .iter()
+                .copied()
+                .filter(|id| *id != workspace_id)
+                .take(MAX_RECENT_WORKSPACES - 1),
+        );
+        Self(workspaces)
+    }
+
+    /// The most recently viewed workspace, if any.
+    #[must_use]
+    pub fn most_recent(&self) -> Option<WorkspaceId> {
+        self.0.first().copied()
+    }
+
+    /// Workspaces ordered from most to least recently viewed.
+    #[must_use]
+    pub fn as_slice(&self) -> &[WorkspaceId] {
+        &self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,6 +413,15 @@ mod tests {
             assert_eq!(Theme::System.to_string(), "system");
         }
 
+        #[test]
+        fn parses_display_form() {
+            for theme in [Theme::Light, Theme::Dark, Theme::System] {
+                assert_eq!(theme.to_string().parse::<Theme>().unwrap(), theme);
+            }
+            assert_eq!(" DARK ".parse::<Theme>().unwrap(), Theme::Dark);
+            assert!("sepia".parse::<Theme>().is_err());
+        }
+
         #[test]
         fn serde_roundtrip() {
             let original = Theme::Dark;
@@ -283,6 +446,13 @@ mod tests {
             assert_eq!(locale.as_str(), "fr-FR");
         }
 
+        #[test]
+        fn fallback_chain_drops_trailing_subtags() {
+            let locale = Locale::new("zh-Hant-TW").unwrap();
+            assert_eq!(locale.fallback_chain(), ["zh-Hant-TW", "zh-Hant", "zh"]);
+            assert_eq!(Locale::new("de").unwrap().fallback_chain(), ["de"]);
+        }
+
         #[test]
         fn default_is_en_us() {
             assert_eq!(Locale::default().as_str(), "en-US");
@@ -363,4 +533,80 @@ mod tests {
             assert_eq!(original, parsed);
         }
     }
+
+    mod notifications {
+        use super::*;
+
+        #[test]
+        fn digest_parses_known_frequencies() {
+            assert_eq!(
+                "off".parse::<DigestFrequency>().unwrap(),
+                DigestFrequency::Off
+            );
+            assert_eq!(
+                " Daily ".parse::<DigestFrequency>().unwrap(),
+                DigestFrequency::Daily
+            );
+            assert_eq!(
+                "WEEKLY".parse::<DigestFrequency>().unwrap(),
+                DigestFrequency::Weekly
+            );
+        }
+
+        #[test]
+        fn digest_rejects_unknown_frequency() {
+            let err = "hourly".parse::<DigestFrequency>().unwrap_err();
+            assert!(matches!(
+                err.kind(),
+                ValidationErrorKind::InvalidFormat { field, .. } if field == "digest"
+            ));
+            assert!(serde_json::from_str::<DigestFrequency>("\"monthly\"").is_err());
+        }
+
+        #[test]
+        fn default_is_in_app_only() {
+            let prefs = NotificationPreferences::default();
+            assert!(!prefs.email_enabled);
+            assert!(prefs.in_app_enabled);
+            assert_eq!(prefs.digest, DigestFrequency::Off);
+        }
+
+        #[test]
+        fn serde_roundtrip() {
+            let original = NotificationPreferences::new(true, false, DigestFrequency::Weekly);
+            let json = serde_json::to_string(&original).unwrap();
+            assert!(json.contains(r#""digest":"weekly""#));
+            let parsed: NotificationPreferences = serde_json::from_str(&json).unwrap();
+            assert_eq!(original, parsed);
+        }
+    }
+
+    mod recent_workspaces {
+        use super::*;
+
+        fn workspace(n: u128) -> WorkspaceId {
+            WorkspaceId::from_uuid(Uuid::from_u128(n))
+        }
+
+        #[test]
+        fn record_moves_existing_entry_to_front() {
+            let recent = RecentWorkspaces::default()
+                .record(workspace(1))
+                .record(workspace(2))
+                .record(workspace(1));
+
+            assert_eq!(recent.as_slice(), [workspace(1), workspace(2)]);
+            assert_eq!(recent.most_recent(), Some(workspace(1)));
+        }
+
+        #[test]
+        fn record_drops_oldest_beyond_bound() {
+            let recent = (1..=12).fold(RecentWorkspaces::default(), |recent, n| {
+                recent.record(workspace(n))
+            });
+
+            let expected: Vec<WorkspaceId> = (3..=12).rev().map(workspace).collect();
+            assert_eq!(recent.as_slice(), expected.as_slice());
+        }
+    }
 }
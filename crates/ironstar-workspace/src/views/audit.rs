@@ -0,0 +1,174 @@
+//! Audit log view recording who changed workspaces, dashboards, and queries.
+//!
+//! Mutating workspace, dashboard, and saved query events carry the `actor`
+//! who issued the command. [`audit_log_view`] folds any of those event
+//! streams into a chronological log of who did what to which aggregate.
+//! Events without an actor, such as `QueryRun`, are left out of the log.
+//!
+//! Events stored before actors were recorded deserialize with
+//! [`UserId::system`], so they appear in the log attributed to the system.
+
+use chrono::{DateTime, Utc};
+use ironstar_core::{EventType, Identifier, Timestamped, View};
+use ironstar_shared_kernel::UserId;
+
+use crate::dashboard::events::DashboardEvent;
+use crate::saved_query::events::SavedQueryEvent;
+use crate::workspace::events::WorkspaceEvent;
+
+/// Events that record the user who caused them.
+pub trait Audited: Identifier + EventType + Timestamped {
+    /// Who performed the action, or `None` for events that are not audited.
+    fn actor(&self) -> Option<UserId>;
+}
+
+impl Audited for WorkspaceEvent {
+    fn actor(&self) -> Option<UserId> {
+        Some(Self::actor(self))
+    }
+}
+
+impl Audited for DashboardEvent {
+    fn actor(&self) -> Option<UserId> {
+        Some(Self::actor(self))
+    }
+}
+
+impl Audited for SavedQueryEvent {
+    fn actor(&self) -> Option<UserId> {
+        Self::actor(self)
+    }
+}
+
+/// A single audited action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub actor: UserId,
+    /// Aggregate identifier as stored in the event store.
+    pub aggregate_id: String,
+    pub event_type: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// State materialized by the audit log view, in event order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditLogViewState {
+    pub entries: Vec<AuditEntry>,
+}
+
+impl AuditLogViewState {
+    /// Actions performed by a specific user.
+    #[must_use]
+    pub fn entries_by(&self, actor: &UserId) -> Vec<&AuditEntry> {
+        self.entries.iter().filter(|e| &e.actor == actor).collect()
+    }
+}
+
+pub type AuditLogView<'a, E> = View<'a, AuditLogViewState, E>;
+
+/// Factory function creating a pure audit log view over an audited event type.
+pub fn audit_log_view<'a, E: Audited>() -> AuditLogView<'a, E> {
+    View {
+        evolve: Box::new(evolve_audit_log),
+        initial_state: Box::new(AuditLogViewState::default),
+    }
+}
+
+fn evolve_audit_log<E: Audited>(state: &AuditLogViewState, event: &E) -> AuditLogViewState {
+    let Some(actor) = event.actor() else {
+        return state.clone();
+    };
+
+    let mut entries = state.entries.clone();
+    entries.push(AuditEntry {
+        actor,
+        aggregate_id: event.identifier(),
+        event_type: event.event_type(),
+        occurred_at: event.occurred_at(),
+    });
+    AuditLogViewState { entries }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ironstar_core::ViewStateComputation;
+    use uuid::Uuid;
+
+    use crate::saved_query::values::SavedQueryId;
+    use crate::workspace::values::{Visibility, WorkspaceId, WorkspaceName};
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn alice() -> UserId {
+        UserId::from_uuid(Uuid::from_u128(1))
+    }
+
+    fn bob() -> UserId {
+        UserId::from_uuid(Uuid::from_u128(2))
+    }
+
+    #[test]
+    fn entries_record_actor_of_each_event() {
+        let workspace_id = WorkspaceId::from_uuid(Uuid::from_u128(10));
+        let name = WorkspaceName::new("Analytics").unwrap();
+        let events = [
+            WorkspaceEvent::Created {
+                workspace_id,
+                normalized_name: name.normalized(),
+                name,
+                owner_id: alice(),
+                visibility: Visibility::Private,
+                actor: alice(),
+                created_at: sample_time(),
+            },
+            WorkspaceEvent::VisibilityChanged {
+                workspace_id,
+                old_visibility: Visibility::Private,
+                new_visibility: Visibility::Public,
+                actor: bob(),
+                changed_at: sample_time(),
+            },
+        ];
+
+        let refs: Vec<&WorkspaceEvent> = events.iter().collect();
+
+        let state = audit_log_view().compute_new_state(None, &refs);
+
+        let log: Vec<(UserId, &str)> = state
+            .entries
+            .iter()
+            .map(|e| (e.actor, e.event_type.as_str()))
+            .collect();
+        assert_eq!(log, [(alice(), "Created"), (bob(), "VisibilityChanged")]);
+        assert_eq!(state.entries_by(&bob()).len(), 1);
+        assert_eq!(state.entries[0].aggregate_id, workspace_id.to_string());
+    }
+
+    #[test]
+    fn query_runs_are_not_audited() {
+        let query_id = SavedQueryId::from_uuid(Uuid::from_u128(20));
+        let events = [
+            SavedQueryEvent::QueryRun {
+                query_id,
+                run_at: sample_time(),
+            },
+            SavedQueryEvent::QueryDeleted {
+                query_id,
+                actor: alice(),
+                deleted_at: sample_time(),
+            },
+        ];
+
+        let refs: Vec<&SavedQueryEvent> = events.iter().collect();
+
+        let state = audit_log_view().compute_new_state(None, &refs);
+
+        assert_eq!(state.entries.len(), 1);
+        assert_eq!(state.entries[0].event_type, "QueryDeleted");
+    }
+}
@@ -0,0 +1,120 @@
+//! Folding several views over one event stream in a single pass.
+//!
+//! Materializing each view separately replays the stream once per view.
+//! [`combine_views`] pairs two views over the same event type into one whose
+//! state is the tuple of both, so a single fold updates them in lockstep.
+//! Nest calls to combine more than two views.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let view = combine_views(combine_views(workspace_list_view(), stats_view()), other_view());
+//! let ((list, stats), other) = view.compute_new_state(None, &events);
+//! ```
+
+use ironstar_core::View;
+
+/// Combine two views over the same event type into one view of both states.
+pub fn combine_views<'a, S1, S2, E>(
+    first: View<'a, S1, E>,
+    second: View<'a, S2, E>,
+) -> View<'a, (S1, S2), E>
+where
+    S1: Clone,
+    S2: Clone,
+{
+    first.merge(second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use ironstar_core::ViewStateComputation;
+    use ironstar_shared_kernel::UserId;
+    use uuid::Uuid;
+
+    use crate::views::workspace::workspace_list_view;
+    use crate::workspace::events::WorkspaceEvent;
+    use crate::workspace::values::{Visibility, WorkspaceId, WorkspaceName};
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    struct WorkspaceStats {
+        created: usize,
+        archived: usize,
+    }
+
+    fn stats_view<'a>() -> View<'a, WorkspaceStats, WorkspaceEvent> {
+        View {
+            evolve: Box::new(
+                |state: &WorkspaceStats, event: &WorkspaceEvent| match event {
+                    WorkspaceEvent::Created { .. } => WorkspaceStats {
+                        created: state.created + 1,
+                        ..state.clone()
+                    },
+                    WorkspaceEvent::Archived { .. } => WorkspaceStats {
+                        archived: state.archived + 1,
+                        ..state.clone()
+                    },
+                    _ => state.clone(),
+                },
+            ),
+            initial_state: Box::new(WorkspaceStats::default),
+        }
+    }
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn created(n: u128) -> WorkspaceEvent {
+        let name = WorkspaceName::new(format!("Workspace {n}")).unwrap();
+        WorkspaceEvent::Created {
+            workspace_id: WorkspaceId::from_uuid(Uuid::from_u128(n)),
+            normalized_name: name.normalized(),
+            name,
+            owner_id: UserId::from_uuid(Uuid::nil()),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn single_fold_updates_list_and_stats_views() {
+        let view = combine_views(workspace_list_view(), stats_view());
+        let events = [
+            created(1),
+            created(2),
+            WorkspaceEvent::Archived {
+                workspace_id: WorkspaceId::from_uuid(Uuid::from_u128(1)),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            },
+        ];
+        let refs: Vec<&WorkspaceEvent> = events.iter().collect();
+
+        let (list, stats) = view.compute_new_state(None, &refs);
+
+        assert_eq!(list, workspace_list_view().compute_new_state(None, &refs));
+        assert_eq!(
+            stats,
+            WorkspaceStats {
+                created: 2,
+                archived: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn initial_state_pairs_both_initial_states() {
+        let view = combine_views(workspace_list_view(), stats_view());
+
+        let (list, stats) = (view.initial_state)();
+
+        assert_eq!(list.count, 0);
+        assert_eq!(stats, WorkspaceStats::default());
+    }
+}
@@ -1,3 +1,7 @@
 //! Read-side views for the workspace bounded context.
 
+pub mod audit;
+pub mod combine;
 pub mod workspace;
+
+pub use combine::combine_views;
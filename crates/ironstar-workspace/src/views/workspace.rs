@@ -1,24 +1,34 @@
 //! Workspace bounded context Views for read-side projections.
 //!
-//! Four views materialize events from the five workspace aggregates into
+//! Six views materialize events from the five workspace aggregates into
 //! queryable read models optimized for rendering:
 //!
 //! - `WorkspaceListView`: All workspaces with metadata, filterable by owner
+//! - `SlugRedirectView`: Stale workspace slugs mapped to current ones
 //! - `DashboardLayoutView`: Full dashboard state with charts and tabs
 //! - `SavedQueryListView`: All saved queries, filterable by workspace
 //! - `UserPreferencesView`: Per-user preferences singleton
+//! - `WorkspacePreferencesView`: Per-workspace preferences singleton
 
 use chrono::{DateTime, Utc};
-use ironstar_core::View;
+use ironstar_analytics::ChartType;
+use ironstar_core::{View, ViewInvariantError, ViewInvariants, debug_validated};
+use serde::Serialize;
+use std::collections::HashMap;
 
 use crate::dashboard::events::DashboardEvent;
-use crate::dashboard::values::{ChartPlacement, DashboardId, TabInfo};
+use crate::dashboard::state::default_tab_after_removal;
+use crate::dashboard::values::{ChartPlacement, DashboardId, TabId, TabInfo};
 use crate::saved_query::events::SavedQueryEvent;
 use crate::saved_query::values::{QueryName, SavedQueryId};
 use crate::user_preferences::events::UserPreferencesEvent;
-use crate::user_preferences::values::{Locale, PreferencesId, Theme, UiState};
+use crate::user_preferences::values::{
+    Locale, NotificationPreferences, PreferencesId, RecentWorkspaces, Theme, UiState,
+};
 use crate::workspace::events::WorkspaceEvent;
-use crate::workspace::values::{Visibility, WorkspaceId, WorkspaceName};
+use crate::workspace::values::{Visibility, WorkspaceId, WorkspaceName, WorkspaceRole};
+use crate::workspace_preferences::events::WorkspacePreferencesEvent;
+use crate::workspace_preferences::values::{CatalogUri, LayoutDefaults};
 use ironstar_core::DashboardTitle;
 use ironstar_shared_kernel::UserId;
 
@@ -34,12 +44,18 @@ pub struct WorkspaceListEntry {
     pub owner_id: UserId,
     pub visibility: Visibility,
     pub created_at: DateTime<Utc>,
+    /// Why the workspace is suspended, or `None` while it is active.
+    pub suspension: Option<String>,
+    /// Starts at 1 on creation and increments on each event mutating this
+    /// entry, letting clients discard out-of-order fragments.
+    pub version: u64,
 }
 
 /// State materialized by the workspace list view.
 ///
-/// Contains all workspaces in creation order. Use `workspaces_for_user` to
-/// filter by owner.
+/// Contains all non-archived workspaces in creation order. Use
+/// `workspaces_for_user` to filter by owner, or `filter` to combine owner
+/// and visibility facets.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct WorkspaceListViewState {
     pub workspaces: Vec<WorkspaceListEntry>,
@@ -56,6 +72,129 @@ impl WorkspaceListViewState {
             .filter(|w| &w.owner_id == user_id)
             .collect()
     }
+
+    /// Workspaces visible to all authenticated users.
+    #[must_use]
+    pub fn public_workspaces(&self) -> Vec<&WorkspaceListEntry> {
+        self.filter(None, Some(Visibility::Public))
+    }
+
+    /// Workspaces visible only to their owner.
+    #[must_use]
+    pub fn private_workspaces(&self) -> Vec<&WorkspaceListEntry> {
+        self.filter(None, Some(Visibility::Private))
+    }
+
+    /// The role `user_id` holds in a workspace, or `None` if the workspace
+    /// is unknown (or archived) or the user has no access to it.
+    ///
+    /// Nobody may change a suspended workspace, so its owner holds only
+    /// [`Viewer`](WorkspaceRole::Viewer) until it is unsuspended.
+    #[must_use]
+    pub fn role_of(&self, workspace_id: &WorkspaceId, user_id: &UserId) -> Option<WorkspaceRole> {
+        let workspace = self
+            .workspaces
+            .iter()
+            .find(|w| &w.workspace_id == workspace_id)?;
+        let role = WorkspaceRole::of(user_id, &workspace.owner_id, workspace.visibility)?;
+        if workspace.suspension.is_some() {
+            Some(role.min(WorkspaceRole::Viewer))
+        } else {
+            Some(role)
+        }
+    }
+
+    /// Why a workspace is suspended, or `None` if it is active or unknown.
+    #[must_use]
+    pub fn suspension(&self, workspace_id: &WorkspaceId) -> Option<&str> {
+        self.workspaces
+            .iter()
+            .find(|w| &w.workspace_id == workspace_id)
+            .and_then(|w| w.suspension.as_deref())
+    }
+
+    /// Filter workspaces by owner and visibility; `None` matches any value.
+    #[must_use]
+    pub fn filter(
+        &self,
+        owner: Option<&UserId>,
+        visibility: Option<Visibility>,
+    ) -> Vec<&WorkspaceListEntry> {
+        self.workspaces
+            .iter()
+            .filter(|w| owner.is_none_or(|owner| &w.owner_id == owner))
+            .filter(|w| visibility.is_none_or(|visibility| w.visibility == visibility))
+            .collect()
+    }
+}
+
+impl WorkspaceListViewState {
+    /// Apply an event in place and report which entries it touched.
+    ///
+    /// Lets the SSE layer patch individual list fragments instead of
+    /// re-rendering the whole list. Events that leave the list unchanged
+    /// (e.g. a rename of an unknown workspace) yield an empty diff.
+    pub fn apply_with_diff(&mut self, event: &WorkspaceEvent) -> ViewDiff<WorkspaceId> {
+        let next = debug_validated(evolve_workspace_list(self, event));
+        let diff = ViewDiff::between(&self.workspaces, &next.workspaces, |w| w.workspace_id);
+        *self = next;
+        diff
+    }
+}
+
+/// Entry-level changes produced by applying one event to a list view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewDiff<Id> {
+    /// Entries present after the event but not before.
+    pub added: Vec<Id>,
+    /// Entries present before the event but not after.
+    pub removed: Vec<Id>,
+    /// Entries present on both sides whose contents changed.
+    pub updated: Vec<Id>,
+}
+
+impl<Id> Default for ViewDiff<Id> {
+    fn default() -> Self {
+        Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+            updated: Vec::new(),
+        }
+    }
+}
+
+impl<Id: PartialEq> ViewDiff<Id> {
+    /// Compare entry lists before and after an event, keyed by `id_of`.
+    fn between<E: PartialEq>(before: &[E], after: &[E], id_of: impl Fn(&E) -> Id) -> Self {
+        let mut diff = Self::default();
+        for entry in after {
+            let id = id_of(entry);
+            match before.iter().find(|e| id_of(e) == id) {
+                None => diff.added.push(id),
+                Some(previous) if previous != entry => diff.updated.push(id),
+                Some(_) => {}
+            }
+        }
+        for entry in before {
+            let id = id_of(entry);
+            if !after.iter().any(|e| id_of(e) == id) {
+                diff.removed.push(id);
+            }
+        }
+        diff
+    }
+
+    /// Whether the event left the view unchanged.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+}
+
+impl ViewInvariants for WorkspaceListViewState {
+    fn validate_invariants(&self) -> Result<(), ViewInvariantError> {
+        ViewInvariantError::check_count("count", self.count, self.workspaces.len())
+    }
 }
 
 pub type WorkspaceListView<'a> = View<'a, WorkspaceListViewState, WorkspaceEvent>;
@@ -63,7 +202,7 @@ pub type WorkspaceListView<'a> = View<'a, WorkspaceListViewState, WorkspaceEvent
 /// Factory function creating a pure workspace list view.
 pub fn workspace_list_view<'a>() -> WorkspaceListView<'a> {
     View {
-        evolve: Box::new(evolve_workspace_list),
+        evolve: Box::new(|state, event| debug_validated(evolve_workspace_list(state, event))),
         initial_state: Box::new(WorkspaceListViewState::default),
     }
 }
@@ -79,6 +218,7 @@ fn evolve_workspace_list(
             owner_id,
             visibility,
             created_at,
+            ..
         } => {
             let mut workspaces = state.workspaces.clone();
             workspaces.push(WorkspaceListEntry {
@@ -87,6 +227,8 @@ fn evolve_workspace_list(
                 owner_id: *owner_id,
                 visibility: *visibility,
                 created_at: *created_at,
+                suspension: None,
+                version: 1,
             });
             WorkspaceListViewState {
                 workspaces,
@@ -105,6 +247,7 @@ fn evolve_workspace_list(
                 .find(|w| w.workspace_id == *workspace_id)
             {
                 ws.name = new_name.clone();
+                ws.version += 1;
             }
             WorkspaceListViewState {
                 workspaces,
@@ -123,12 +266,186 @@ fn evolve_workspace_list(
                 .find(|w| w.workspace_id == *workspace_id)
             {
                 ws.visibility = *new_visibility;
+                ws.version += 1;
+            }
+            WorkspaceListViewState {
+                workspaces,
+                count: state.count,
+            }
+        }
+
+        // Suspended workspaces stay listed; only their mutations are blocked.
+        WorkspaceEvent::Suspended {
+            workspace_id,
+            reason,
+            ..
+        } => {
+            let mut workspaces = state.workspaces.clone();
+            if let Some(ws) = workspaces
+                .iter_mut()
+                .find(|w| w.workspace_id == *workspace_id)
+            {
+                ws.suspension = Some(reason.clone());
+                ws.version += 1;
+            }
+            WorkspaceListViewState {
+                workspaces,
+                count: state.count,
+            }
+        }
+
+        WorkspaceEvent::Unsuspended { workspace_id, .. } => {
+            let mut workspaces = state.workspaces.clone();
+            if let Some(ws) = workspaces
+                .iter_mut()
+                .find(|w| w.workspace_id == *workspace_id)
+            {
+                ws.suspension = None;
+                ws.version += 1;
             }
             WorkspaceListViewState {
                 workspaces,
                 count: state.count,
             }
         }
+
+        WorkspaceEvent::Archived { workspace_id, .. } => {
+            let workspaces: Vec<WorkspaceListEntry> = state
+                .workspaces
+                .iter()
+                .filter(|w| w.workspace_id != *workspace_id)
+                .cloned()
+                .collect();
+            WorkspaceListViewState {
+                count: workspaces.len(),
+                workspaces,
+            }
+        }
+    }
+}
+
+// ============================================================================
+// SlugRedirectView
+// ============================================================================
+
+/// State materialized by the slug redirect view.
+///
+/// Slugs derive from workspace names (see [`WorkspaceName::slug`]), so a
+/// rename changes the slug. Every slug a workspace has had maps directly
+/// to its current slug, letting the router answer a stale link with a
+/// single redirect even after several renames.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlugRedirectViewState {
+    /// Current slug of each non-archived workspace.
+    pub current: HashMap<WorkspaceId, String>,
+    /// Stale slug to current slug.
+    pub redirects: HashMap<String, String>,
+}
+
+impl SlugRedirectViewState {
+    /// Current slug `slug` should redirect to, or `None` if `slug` is not
+    /// stale.
+    #[must_use]
+    pub fn resolve(&self, slug: &str) -> Option<&str> {
+        self.redirects.get(slug).map(String::as_str)
+    }
+}
+
+pub type SlugRedirectView<'a> = View<'a, SlugRedirectViewState, WorkspaceEvent>;
+
+/// Factory function creating a pure slug redirect view.
+pub fn slug_redirect_view<'a>() -> SlugRedirectView<'a> {
+    View {
+        evolve: Box::new(evolve_slug_redirects),
+        initial_state: Box::new(SlugRedirectViewState::default),
+    }
+}
+
+fn evolve_slug_redirects(
+    state: &SlugRedirectViewState,
+    event: &WorkspaceEvent,
+) -> SlugRedirectViewState {
+    match event {
+        WorkspaceEvent::Created {
+            workspace_id, name, ..
+        } => {
+            let slug = name.slug();
+            let mut redirects = state.redirects.clone();
+            // A live workspace owns its slug; it no longer redirects.
+            redirects.remove(&slug);
+            let mut current = state.current.clone();
+            current.insert(*workspace_id, slug);
+            SlugRedirectViewState { current, redirects }
+        }
+
+        WorkspaceEvent::Renamed {
+            workspace_id,
+            old_name,
+            new_name,
+            ..
+        } => {
+            let old_slug = state
+                .current
+                .get(workspace_id)
+                .cloned()
+                .unwrap_or_else(|| old_name.slug());
+            let new_slug = new_name.slug();
+            if old_slug == new_slug {
+                return state.clone();
+            }
+
+            // Distinct names can share a slug. While another live workspace
+            // still answers to the old slug it must not redirect, and links
+            // already resolving to it stay with that workspace.
+            let old_slug_taken = state
+                .current
+                .iter()
+                .any(|(id, slug)| id != workspace_id && *slug == old_slug);
+
+            let mut redirects: HashMap<String, String> = if old_slug_taken {
+                state.redirects.clone()
+            } else {
+                // Repoint earlier slugs so chains resolve in one hop.
+                state
+                    .redirects
+                    .iter()
+                    .map(|(stale, target)| {
+                        let target = if *target == old_slug {
+                            new_slug.clone()
+                        } else {
+                            target.clone()
+                        };
+                        (stale.clone(), target)
+                    })
+                    .collect()
+            };
+            redirects.remove(&new_slug);
+            if !old_slug_taken {
+                redirects.insert(old_slug, new_slug.clone());
+            }
+
+            let mut current = state.current.clone();
+            current.insert(*workspace_id, new_slug);
+            SlugRedirectViewState { current, redirects }
+        }
+
+        WorkspaceEvent::VisibilityChanged { .. }
+        | WorkspaceEvent::Suspended { .. }
+        | WorkspaceEvent::Unsuspended { .. } => state.clone(),
+
+        WorkspaceEvent::Archived { workspace_id, .. } => {
+            let mut current = state.current.clone();
+            let Some(slug) = current.remove(workspace_id) else {
+                return state.clone();
+            };
+            let redirects = state
+                .redirects
+                .iter()
+                .filter(|(_, target)| **target != slug)
+                .map(|(stale, target)| (stale.clone(), target.clone()))
+                .collect();
+            SlugRedirectViewState { current, redirects }
+        }
     }
 }
 
@@ -139,16 +456,90 @@ fn evolve_workspace_list(
 /// State materialized by the dashboard layout view.
 ///
 /// Represents the full rendering state of a single dashboard including
-/// all chart placements and tab organization.
-#[derive(Debug, Clone, Default, PartialEq)]
+/// all chart placements and tab organization. Serializes with tabs and
+/// placements in the order they were added.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
 pub struct DashboardLayoutViewState {
     pub dashboard_id: Option<DashboardId>,
     pub workspace_id: Option<WorkspaceId>,
     pub name: Option<DashboardTitle>,
     pub placements: Vec<ChartPlacement>,
     pub tabs: Vec<TabInfo>,
+    /// Invariant: `chart_count == placements.len()`
     pub chart_count: usize,
+    /// Invariant: `tab_count == tabs.len()`
     pub tab_count: usize,
+    /// Tab the UI opens first; the first tab added unless set otherwise.
+    pub default_tab_id: Option<TabId>,
+    /// Set once the dashboard is archived; the layout is kept for reference.
+    pub archived: bool,
+}
+
+impl DashboardLayoutViewState {
+    /// Placements shown on the tab `tab_id`, in the order they were added.
+    ///
+    /// `None` selects the charts not assigned to any tab.
+    pub fn placements_in_tab(&self, tab_id: Option<&TabId>) -> Vec<&ChartPlacement> {
+        self.placements
+            .iter()
+            .filter(|placement| placement.tab_id.as_ref() == tab_id)
+            .collect()
+    }
+
+    /// Render the layout as the JSON document consumed by the frontend.
+    ///
+    /// Each placement carries a resolved `chart_type`: its inline
+    /// configuration's type or its definition's hint, falling back to the
+    /// default chart type when a reference gives no hint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_frontend_json(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(FrontendLayout {
+            dashboard_id: self.dashboard_id,
+            workspace_id: self.workspace_id,
+            name: self.name.as_ref(),
+            archived: self.archived,
+            default_tab_id: self.default_tab_id,
+            tabs: &self.tabs,
+            placements: self
+                .placements
+                .iter()
+                .map(|placement| FrontendPlacement {
+                    placement,
+                    chart_type: placement.source.chart_type().unwrap_or_default(),
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Frontend shape of a dashboard layout.
+#[derive(Serialize)]
+struct FrontendLayout<'a> {
+    dashboard_id: Option<DashboardId>,
+    workspace_id: Option<WorkspaceId>,
+    name: Option<&'a DashboardTitle>,
+    archived: bool,
+    default_tab_id: Option<TabId>,
+    tabs: &'a [TabInfo],
+    placements: Vec<FrontendPlacement<'a>>,
+}
+
+/// A placement with its chart type hint resolved.
+#[derive(Serialize)]
+struct FrontendPlacement<'a> {
+    #[serde(flatten)]
+    placement: &'a ChartPlacement,
+    chart_type: ChartType,
+}
+
+impl ViewInvariants for DashboardLayoutViewState {
+    fn validate_invariants(&self) -> Result<(), ViewInvariantError> {
+        ViewInvariantError::check_count("chart_count", self.chart_count, self.placements.len())?;
+        ViewInvariantError::check_count("tab_count", self.tab_count, self.tabs.len())
+    }
 }
 
 pub type DashboardLayoutView<'a> = View<'a, DashboardLayoutViewState, DashboardEvent>;
@@ -156,7 +547,7 @@ pub type DashboardLayoutView<'a> = View<'a, DashboardLayoutViewState, DashboardE
 /// Factory function creating a pure dashboard layout view.
 pub fn dashboard_layout_view<'a>() -> DashboardLayoutView<'a> {
     View {
-        evolve: Box::new(evolve_dashboard_layout),
+        evolve: Box::new(|state, event| debug_validated(evolve_dashboard_layout(state, event))),
         initial_state: Box::new(DashboardLayoutViewState::default),
     }
 }
@@ -179,6 +570,13 @@ fn evolve_dashboard_layout(
             tabs: Vec::new(),
             chart_count: 0,
             tab_count: 0,
+            default_tab_id: None,
+            archived: false,
+        },
+
+        DashboardEvent::DashboardArchived { .. } => DashboardLayoutViewState {
+            archived: true,
+            ..state.clone()
         },
 
         DashboardEvent::DashboardRenamed { name, .. } => DashboardLayoutViewState {
@@ -220,6 +618,7 @@ fn evolve_dashboard_layout(
             DashboardLayoutViewState {
                 tabs,
                 tab_count: state.tab_count + 1,
+                default_tab_id: state.default_tab_id.or(Some(tab_info.tab_id)),
                 ..state.clone()
             }
         }
@@ -231,6 +630,11 @@ fn evolve_dashboard_layout(
                 DashboardLayoutViewState {
                     tabs,
                     tab_count: state.tab_count.saturating_sub(1),
+                    default_tab_id: default_tab_after_removal(
+                        &state.tabs,
+                        state.default_tab_id,
+                        tab_id,
+                    ),
                     ..state.clone()
                 }
             } else {
@@ -250,6 +654,11 @@ fn evolve_dashboard_layout(
                 ..state.clone()
             }
         }
+
+        DashboardEvent::DefaultTabSet { tab_id, .. } => DashboardLayoutViewState {
+            default_tab_id: Some(*tab_id),
+            ..state.clone()
+        },
     }
 }
 
@@ -266,6 +675,11 @@ pub struct SavedQueryListEntry {
     pub sql: String,
     pub dataset_ref: String,
     pub saved_at: DateTime<Utc>,
+    pub run_count: u64,
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// Starts at 1 when saved and increments on each event mutating this
+    /// entry, letting clients discard out-of-order fragments.
+    pub version: u64,
 }
 
 /// State materialized by the saved query list view.
@@ -288,6 +702,39 @@ impl SavedQueryListViewState {
             .filter(|q| &q.workspace_id == workspace_id)
             .collect()
     }
+
+    /// Whether a query in `workspace_id` already uses `name`.
+    ///
+    /// Matching ignores case and surrounding whitespace, so `"Revenue"` and
+    /// `"revenue "` collide. Queries in other workspaces are not considered.
+    #[must_use]
+    pub fn name_exists_in_workspace(&self, workspace_id: &WorkspaceId, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.queries
+            .iter()
+            .any(|q| &q.workspace_id == workspace_id && q.name.as_str().to_lowercase() == name)
+    }
+
+    /// Up to `n` queries with the highest run counts, most run first.
+    ///
+    /// Ties are broken by the most recent run.
+    #[must_use]
+    pub fn most_run(&self, n: usize) -> Vec<&SavedQueryListEntry> {
+        let mut queries: Vec<&SavedQueryListEntry> = self.queries.iter().collect();
+        queries.sort_by(|a, b| {
+            b.run_count
+                .cmp(&a.run_count)
+                .then_with(|| b.last_run_at.cmp(&a.last_run_at))
+        });
+        queries.truncate(n);
+        queries
+    }
+}
+
+impl ViewInvariants for SavedQueryListViewState {
+    fn validate_invariants(&self) -> Result<(), ViewInvariantError> {
+        ViewInvariantError::check_count("count", self.count, self.queries.len())
+    }
 }
 
 pub type SavedQueryListView<'a> = View<'a, SavedQueryListViewState, SavedQueryEvent>;
@@ -295,7 +742,7 @@ pub type SavedQueryListView<'a> = View<'a, SavedQueryListViewState, SavedQueryEv
 /// Factory function creating a pure saved query list view.
 pub fn saved_query_list_view<'a>() -> SavedQueryListView<'a> {
     View {
-        evolve: Box::new(evolve_saved_query_list),
+        evolve: Box::new(|state, event| debug_validated(evolve_saved_query_list(state, event))),
         initial_state: Box::new(SavedQueryListViewState::default),
     }
 }
@@ -312,6 +759,7 @@ fn evolve_saved_query_list(
             sql,
             dataset_ref,
             saved_at,
+            ..
         } => {
             let mut queries = state.queries.clone();
             queries.push(SavedQueryListEntry {
@@ -321,6 +769,9 @@ fn evolve_saved_query_list(
                 sql: sql.to_string(),
                 dataset_ref: dataset_ref.to_string(),
                 saved_at: *saved_at,
+                run_count: 0,
+                last_run_at: None,
+                version: 1,
             });
             SavedQueryListViewState {
                 queries,
@@ -345,6 +796,7 @@ fn evolve_saved_query_list(
             let mut queries = state.queries.clone();
             if let Some(q) = queries.iter_mut().find(|q| q.query_id == *query_id) {
                 q.name = name.clone();
+                q.version += 1;
             }
             SavedQueryListViewState {
                 queries,
@@ -356,6 +808,7 @@ fn evolve_saved_query_list(
             let mut queries = state.queries.clone();
             if let Some(q) = queries.iter_mut().find(|q| q.query_id == *query_id) {
                 q.sql = sql.to_string();
+                q.version += 1;
             }
             SavedQueryListViewState {
                 queries,
@@ -371,6 +824,36 @@ fn evolve_saved_query_list(
             let mut queries = state.queries.clone();
             if let Some(q) = queries.iter_mut().find(|q| q.query_id == *query_id) {
                 q.dataset_ref = dataset_ref.to_string();
+                q.version += 1;
+            }
+            SavedQueryListViewState {
+                queries,
+                count: state.count,
+            }
+        }
+
+        SavedQueryEvent::QueryMovedToWorkspace {
+            query_id,
+            to_workspace_id,
+            ..
+        } => {
+            let mut queries = state.queries.clone();
+            if let Some(q) = queries.iter_mut().find(|q| q.query_id == *query_id) {
+                q.workspace_id = *to_workspace_id;
+                q.version += 1;
+            }
+            SavedQueryListViewState {
+                queries,
+                count: state.count,
+            }
+        }
+
+        SavedQueryEvent::QueryRun { query_id, run_at } => {
+            let mut queries = state.queries.clone();
+            if let Some(q) = queries.iter_mut().find(|q| q.query_id == *query_id) {
+                q.run_count += 1;
+                q.last_run_at = Some(*run_at);
+                q.version += 1;
             }
             SavedQueryListViewState {
                 queries,
@@ -395,9 +878,20 @@ pub struct UserPreferencesViewState {
     pub theme: Theme,
     pub locale: Locale,
     pub ui_state: UiState,
+    pub notifications: NotificationPreferences,
+    pub default_workspace: Option<WorkspaceId>,
+    pub recent_workspaces: RecentWorkspaces,
     pub initialized: bool,
 }
 
+impl UserPreferencesViewState {
+    /// Most recently viewed workspaces, newest first.
+    #[must_use]
+    pub fn recent_workspaces(&self) -> &[WorkspaceId] {
+        self.recent_workspaces.as_slice()
+    }
+}
+
 pub type UserPreferencesView<'a> = View<'a, UserPreferencesViewState, UserPreferencesEvent>;
 
 /// Factory function creating a pure user preferences view.
@@ -423,6 +917,9 @@ fn evolve_user_preferences(
             theme: Theme::default(),
             locale: Locale::default(),
             ui_state: UiState::default(),
+            notifications: NotificationPreferences::default(),
+            default_workspace: None,
+            recent_workspaces: RecentWorkspaces::default(),
             initialized: true,
         },
 
@@ -440,6 +937,104 @@ fn evolve_user_preferences(
             ui_state: ui_state.clone(),
             ..state.clone()
         },
+
+        UserPreferencesEvent::DefaultWorkspaceSet { workspace_id, .. } => {
+            UserPreferencesViewState {
+                default_workspace: Some(*workspace_id),
+                ..state.clone()
+            }
+        }
+
+        UserPreferencesEvent::DefaultWorkspaceCleared { .. } => UserPreferencesViewState {
+            default_workspace: None,
+            ..state.clone()
+        },
+
+        UserPreferencesEvent::NotificationPreferencesSet { notifications, .. } => {
+            UserPreferencesViewState {
+                notifications: *notifications,
+                ..state.clone()
+            }
+        }
+
+        UserPreferencesEvent::WorkspaceViewed { workspace_id, .. } => UserPreferencesViewState {
+            recent_workspaces: state.recent_workspaces.record(*workspace_id),
+            ..state.clone()
+        },
+    }
+}
+
+// ============================================================================
+// WorkspacePreferencesView
+// ============================================================================
+
+/// State materialized by the workspace preferences view.
+///
+/// Represents the current settings for a single workspace. Singleton per
+/// workspace-scoped aggregate.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WorkspacePreferencesViewState {
+    pub workspace_id: Option<WorkspaceId>,
+    pub default_catalog: Option<CatalogUri>,
+    pub layout_defaults: LayoutDefaults,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub initialized: bool,
+}
+
+pub type WorkspacePreferencesView<'a> =
+    View<'a, WorkspacePreferencesViewState, WorkspacePreferencesEvent>;
+
+/// Factory function creating a pure workspace preferences view.
+pub fn workspace_preferences_view<'a>() -> WorkspacePreferencesView<'a> {
+    View {
+        evolve: Box::new(evolve_workspace_preferences),
+        initial_state: Box::new(WorkspacePreferencesViewState::default),
+    }
+}
+
+fn evolve_workspace_preferences(
+    state: &WorkspacePreferencesViewState,
+    event: &WorkspacePreferencesEvent,
+) -> WorkspacePreferencesViewState {
+    match event {
+        WorkspacePreferencesEvent::WorkspacePreferencesInitialized {
+            workspace_id,
+            initialized_at,
+        } => WorkspacePreferencesViewState {
+            workspace_id: Some(*workspace_id),
+            default_catalog: None,
+            layout_defaults: LayoutDefaults::default(),
+            updated_at: Some(*initialized_at),
+            initialized: true,
+        },
+
+        WorkspacePreferencesEvent::DefaultCatalogSet {
+            catalog_uri,
+            set_at,
+            ..
+        } => WorkspacePreferencesViewState {
+            default_catalog: Some(catalog_uri.clone()),
+            updated_at: Some(*set_at),
+            ..state.clone()
+        },
+
+        WorkspacePreferencesEvent::DefaultCatalogCleared { cleared_at, .. } => {
+            WorkspacePreferencesViewState {
+                default_catalog: None,
+                updated_at: Some(*cleared_at),
+                ..state.clone()
+            }
+        }
+
+        WorkspacePreferencesEvent::LayoutDefaultsUpdated {
+            layout_defaults,
+            updated_at,
+            ..
+        } => WorkspacePreferencesViewState {
+            layout_defaults: layout_defaults.clone(),
+            updated_at: Some(*updated_at),
+            ..state.clone()
+        },
     }
 }
 
@@ -447,7 +1042,7 @@ fn evolve_user_preferences(
 #[allow(clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
-    use crate::dashboard::values::{ChartDefinitionRef, GridPosition};
+    use crate::dashboard::values::{ChartDefinitionRef, ChartSource, GridPosition};
     use ironstar_analytics::{DatasetRef, SqlQuery};
     use ironstar_core::ViewStateComputation;
     use ironstar_core::{DashboardTitle, GridSize, TabTitle};
@@ -489,47 +1084,129 @@ mod tests {
         use super::*;
 
         #[test]
-        fn initial_state_is_empty() {
-            let view = workspace_list_view();
-            let state = (view.initial_state)();
-            assert!(state.workspaces.is_empty());
-            assert_eq!(state.count, 0);
-        }
-
-        #[test]
-        fn created_adds_workspace() {
+        fn role_of_follows_ownership_and_visibility() {
             let view = workspace_list_view();
-            let event = WorkspaceEvent::Created {
-                workspace_id: sample_workspace_id(),
+            let owner = sample_owner_2();
+            let other = UserId::from_uuid(Uuid::from_u128(3));
+            let created = |workspace_id, visibility| WorkspaceEvent::Created {
+                workspace_id,
                 name: sample_name(),
-                owner_id: sample_owner(),
-                visibility: Visibility::Private,
+                normalized_name: sample_name().normalized(),
+                owner_id: owner,
+                visibility,
+                actor: owner,
                 created_at: sample_time(),
             };
+            let events = vec![
+                created(sample_workspace_id(), Visibility::Private),
+                created(sample_workspace_id_2(), Visibility::Public),
+            ];
+            let state = view.compute_new_state(None, &as_refs(&events));
 
-            let state = view.compute_new_state(None, &[&event]);
-
-            assert_eq!(state.workspaces.len(), 1);
-            assert_eq!(state.count, 1);
-            assert_eq!(state.workspaces[0].name, sample_name());
+            let private = sample_workspace_id();
+            let public = sample_workspace_id_2();
+            assert_eq!(state.role_of(&private, &owner), Some(WorkspaceRole::Owner));
+            assert_eq!(state.role_of(&private, &other), None);
+            assert_eq!(state.role_of(&public, &other), Some(WorkspaceRole::Viewer));
+            assert_eq!(state.role_of(&private, &UserId::system()), None);
+            assert_eq!(state.role_of(&public, &UserId::system()), None);
+            let unknown = WorkspaceId::from_uuid(Uuid::from_u128(9));
+            assert_eq!(state.role_of(&unknown, &owner), None);
         }
 
         #[test]
-        fn renamed_updates_name() {
+        fn suspension_caps_roles_at_viewer_until_lifted() {
             let view = workspace_list_view();
-            let new_name = WorkspaceName::new("Renamed").unwrap();
-            let events = vec![
+            let owner = sample_owner_2();
+            let workspace_id = sample_workspace_id();
+            let mut events = vec![
+                WorkspaceEvent::Created {
+                    workspace_id,
+                    name: sample_name(),
+                    normalized_name: sample_name().normalized(),
+                    owner_id: owner,
+                    visibility: Visibility::Private,
+                    actor: owner,
+                    created_at: sample_time(),
+                },
+                WorkspaceEvent::Suspended {
+                    workspace_id,
+                    reason: "pending review".to_string(),
+                    actor: UserId::system(),
+                    suspended_at: sample_time(),
+                },
+            ];
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(
+                state.role_of(&workspace_id, &owner),
+                Some(WorkspaceRole::Viewer)
+            );
+            assert_eq!(state.suspension(&workspace_id), Some("pending review"));
+            assert_eq!(state.workspaces[0].version, 2);
+
+            events.push(WorkspaceEvent::Unsuspended {
+                workspace_id,
+                actor: UserId::system(),
+                unsuspended_at: sample_time(),
+            });
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(
+                state.role_of(&workspace_id, &owner),
+                Some(WorkspaceRole::Owner)
+            );
+            assert_eq!(state.suspension(&workspace_id), None);
+        }
+
+        #[test]
+        fn initial_state_is_empty() {
+            let view = workspace_list_view();
+            let state = (view.initial_state)();
+            assert!(state.workspaces.is_empty());
+            assert_eq!(state.count, 0);
+        }
+
+        #[test]
+        fn created_adds_workspace() {
+            let view = workspace_list_view();
+            let event = WorkspaceEvent::Created {
+                workspace_id: sample_workspace_id(),
+                name: sample_name(),
+                normalized_name: sample_name().normalized(),
+                owner_id: sample_owner(),
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: sample_time(),
+            };
+
+            let state = view.compute_new_state(None, &[&event]);
+
+            assert_eq!(state.workspaces.len(), 1);
+            assert_eq!(state.count, 1);
+            assert_eq!(state.workspaces[0].name, sample_name());
+        }
+
+        #[test]
+        fn renamed_updates_name() {
+            let view = workspace_list_view();
+            let new_name = WorkspaceName::new("Renamed").unwrap();
+            let events = vec![
                 WorkspaceEvent::Created {
                     workspace_id: sample_workspace_id(),
                     name: sample_name(),
+                    normalized_name: sample_name().normalized(),
                     owner_id: sample_owner(),
                     visibility: Visibility::Private,
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 WorkspaceEvent::Renamed {
                     workspace_id: sample_workspace_id(),
                     old_name: sample_name(),
                     new_name: new_name.clone(),
+                    normalized_name: new_name.normalized(),
+                    actor: UserId::system(),
                     renamed_at: sample_time(),
                 },
             ];
@@ -547,14 +1224,17 @@ mod tests {
                 WorkspaceEvent::Created {
                     workspace_id: sample_workspace_id(),
                     name: sample_name(),
+                    normalized_name: sample_name().normalized(),
                     owner_id: sample_owner(),
                     visibility: Visibility::Private,
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 WorkspaceEvent::VisibilityChanged {
                     workspace_id: sample_workspace_id(),
                     old_visibility: Visibility::Private,
                     new_visibility: Visibility::Public,
+                    actor: UserId::system(),
                     changed_at: sample_time(),
                 },
             ];
@@ -571,6 +1251,8 @@ mod tests {
                 workspace_id: sample_workspace_id(),
                 old_name: sample_name(),
                 new_name: WorkspaceName::new("New").unwrap(),
+                normalized_name: WorkspaceName::new("New").unwrap().normalized(),
+                actor: UserId::system(),
                 renamed_at: sample_time(),
             }];
 
@@ -587,15 +1269,19 @@ mod tests {
                 WorkspaceEvent::Created {
                     workspace_id: sample_workspace_id(),
                     name: sample_name(),
+                    normalized_name: sample_name().normalized(),
                     owner_id: sample_owner(),
                     visibility: Visibility::Private,
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 WorkspaceEvent::Created {
                     workspace_id: sample_workspace_id_2(),
                     name: WorkspaceName::new("Other").unwrap(),
+                    normalized_name: WorkspaceName::new("Other").unwrap().normalized(),
                     owner_id: sample_owner_2(),
                     visibility: Visibility::Private,
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
             ];
@@ -607,6 +1293,341 @@ mod tests {
             assert_eq!(user1_workspaces.len(), 1);
             assert_eq!(user1_workspaces[0].workspace_id, sample_workspace_id());
         }
+
+        #[test]
+        fn version_increments_only_for_mutated_entry() {
+            let view = workspace_list_view();
+            let new_name = WorkspaceName::new("Renamed").unwrap();
+            let events = vec![
+                WorkspaceEvent::Created {
+                    workspace_id: sample_workspace_id(),
+                    name: sample_name(),
+                    normalized_name: sample_name().normalized(),
+                    owner_id: sample_owner(),
+                    visibility: Visibility::Private,
+                    actor: UserId::system(),
+                    created_at: sample_time(),
+                },
+                WorkspaceEvent::Created {
+                    workspace_id: sample_workspace_id_2(),
+                    name: sample_name(),
+                    normalized_name: sample_name().normalized(),
+                    owner_id: sample_owner(),
+                    visibility: Visibility::Private,
+                    actor: UserId::system(),
+                    created_at: sample_time(),
+                },
+                WorkspaceEvent::Renamed {
+                    workspace_id: sample_workspace_id(),
+                    old_name: sample_name(),
+                    new_name: new_name.clone(),
+                    normalized_name: new_name.normalized(),
+                    actor: UserId::system(),
+                    renamed_at: sample_time(),
+                },
+                WorkspaceEvent::VisibilityChanged {
+                    workspace_id: sample_workspace_id(),
+                    old_visibility: Visibility::Private,
+                    new_visibility: Visibility::Public,
+                    actor: UserId::system(),
+                    changed_at: sample_time(),
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.workspaces[0].version, 3);
+            assert_eq!(state.workspaces[1].version, 1);
+        }
+
+        #[test]
+        fn apply_with_diff_reports_created_as_added() {
+            let mut state = WorkspaceListViewState::default();
+
+            let diff = state.apply_with_diff(&WorkspaceEvent::Created {
+                workspace_id: sample_workspace_id(),
+                name: sample_name(),
+                normalized_name: sample_name().normalized(),
+                owner_id: sample_owner(),
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: sample_time(),
+            });
+
+            assert_eq!(diff.added, vec![sample_workspace_id()]);
+            assert!(diff.removed.is_empty() && diff.updated.is_empty());
+            assert_eq!(state.count, 1);
+        }
+
+        #[test]
+        fn apply_with_diff_reports_renamed_as_updated() {
+            let mut state = WorkspaceListViewState::default();
+            state.apply_with_diff(&WorkspaceEvent::Created {
+                workspace_id: sample_workspace_id(),
+                name: sample_name(),
+                normalized_name: sample_name().normalized(),
+                owner_id: sample_owner(),
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: sample_time(),
+            });
+            let new_name = WorkspaceName::new("Renamed").unwrap();
+
+            let diff = state.apply_with_diff(&WorkspaceEvent::Renamed {
+                workspace_id: sample_workspace_id(),
+                old_name: sample_name(),
+                new_name: new_name.clone(),
+                normalized_name: new_name.normalized(),
+                actor: UserId::system(),
+                renamed_at: sample_time(),
+            });
+
+            assert_eq!(diff.updated, vec![sample_workspace_id()]);
+            assert!(diff.added.is_empty() && diff.removed.is_empty());
+            assert_eq!(state.workspaces[0].name, new_name);
+        }
+
+        #[test]
+        fn apply_with_diff_reports_archived_as_removed() {
+            let mut state = WorkspaceListViewState::default();
+            state.apply_with_diff(&WorkspaceEvent::Created {
+                workspace_id: sample_workspace_id(),
+                name: sample_name(),
+                normalized_name: sample_name().normalized(),
+                owner_id: sample_owner(),
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: sample_time(),
+            });
+
+            let diff = state.apply_with_diff(&WorkspaceEvent::Archived {
+                workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            });
+
+            assert_eq!(diff.removed, vec![sample_workspace_id()]);
+            assert!(state.workspaces.is_empty());
+        }
+
+        #[test]
+        fn apply_with_diff_is_empty_for_noop_event() {
+            let mut state = WorkspaceListViewState::default();
+
+            let diff = state.apply_with_diff(&WorkspaceEvent::Renamed {
+                workspace_id: sample_workspace_id(),
+                old_name: sample_name(),
+                new_name: WorkspaceName::new("New").unwrap(),
+                normalized_name: WorkspaceName::new("New").unwrap().normalized(),
+                actor: UserId::system(),
+                renamed_at: sample_time(),
+            });
+
+            assert!(diff.is_empty());
+        }
+
+        fn mixed_visibility_state() -> WorkspaceListViewState {
+            let created =
+                |n: u128, owner_id: UserId, visibility: Visibility| WorkspaceEvent::Created {
+                    workspace_id: WorkspaceId::from_uuid(Uuid::from_u128(n)),
+                    name: sample_name(),
+                    normalized_name: sample_name().normalized(),
+                    owner_id,
+                    visibility,
+                    actor: UserId::system(),
+                    created_at: sample_time(),
+                };
+            let events = vec![
+                created(1, sample_owner(), Visibility::Public),
+                created(2, sample_owner(), Visibility::Private),
+                created(3, sample_owner_2(), Visibility::Public),
+                created(4, sample_owner_2(), Visibility::Private),
+                created(5, sample_owner_2(), Visibility::Public),
+            ];
+
+            workspace_list_view().compute_new_state(None, &as_refs(&events))
+        }
+
+        fn ids(entries: &[&WorkspaceListEntry]) -> Vec<WorkspaceId> {
+            entries.iter().map(|w| w.workspace_id).collect()
+        }
+
+        fn workspace(n: u128) -> WorkspaceId {
+            WorkspaceId::from_uuid(Uuid::from_u128(n))
+        }
+
+        #[test]
+        fn public_workspaces_excludes_private() {
+            let state = mixed_visibility_state();
+
+            assert_eq!(
+                ids(&state.public_workspaces()),
+                vec![workspace(1), workspace(3), workspace(5)]
+            );
+        }
+
+        #[test]
+        fn private_workspaces_excludes_public() {
+            let state = mixed_visibility_state();
+
+            assert_eq!(
+                ids(&state.private_workspaces()),
+                vec![workspace(2), workspace(4)]
+            );
+        }
+
+        #[test]
+        fn filter_combines_owner_and_visibility() {
+            let state = mixed_visibility_state();
+
+            assert_eq!(
+                ids(&state.filter(Some(&sample_owner_2()), Some(Visibility::Public))),
+                vec![workspace(3), workspace(5)]
+            );
+            assert_eq!(
+                ids(&state.filter(Some(&sample_owner()), None)),
+                vec![workspace(1), workspace(2)]
+            );
+            assert_eq!(state.filter(None, None).len(), 5);
+        }
+
+        #[test]
+        fn count_out_of_step_with_entries_fails_validation() {
+            assert_eq!(
+                WorkspaceListViewState::default().validate_invariants(),
+                Ok(())
+            );
+
+            let state = WorkspaceListViewState {
+                workspaces: vec![],
+                count: 1,
+            };
+            assert_eq!(
+                state.validate_invariants(),
+                Err(ViewInvariantError::CountMismatch {
+                    field: "count",
+                    count: 1,
+                    actual: 0,
+                })
+            );
+        }
+    }
+
+    // --- SlugRedirectView ---
+
+    mod slug_redirect {
+        use super::*;
+
+        fn created(name: &str) -> WorkspaceEvent {
+            WorkspaceEvent::Created {
+                workspace_id: sample_workspace_id(),
+                name: WorkspaceName::new(name).unwrap(),
+                normalized_name: name.to_lowercase(),
+                owner_id: sample_owner(),
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: sample_time(),
+            }
+        }
+
+        fn renamed(old: &str, new: &str) -> WorkspaceEvent {
+            WorkspaceEvent::Renamed {
+                workspace_id: sample_workspace_id(),
+                old_name: WorkspaceName::new(old).unwrap(),
+                new_name: WorkspaceName::new(new).unwrap(),
+                normalized_name: new.to_lowercase(),
+                actor: UserId::system(),
+                renamed_at: sample_time(),
+            }
+        }
+
+        #[test]
+        fn single_rename_redirects_old_slug() {
+            let view = slug_redirect_view();
+            let events = vec![created("Sales Data"), renamed("Sales Data", "Revenue")];
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.resolve("sales-data"), Some("revenue"));
+            assert_eq!(state.resolve("revenue"), None);
+            assert_eq!(
+                state
+                    .current
+                    .get(&sample_workspace_id())
+                    .map(String::as_str),
+                Some("revenue")
+            );
+        }
+
+        #[test]
+        fn chained_renames_resolve_to_latest() {
+            let view = slug_redirect_view();
+            let events = vec![created("A"), renamed("A", "B"), renamed("B", "C")];
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.resolve("a"), Some("c"));
+            assert_eq!(state.resolve("b"), Some("c"));
+            assert_eq!(state.resolve("c"), None);
+        }
+
+        #[test]
+        fn renaming_back_drops_redirect_loop() {
+            let view = slug_redirect_view();
+            let events = vec![created("A"), renamed("A", "B"), renamed("B", "A")];
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.resolve("a"), None);
+            assert_eq!(state.resolve("b"), Some("a"));
+        }
+
+        #[test]
+        fn archiving_drops_redirects_to_workspace() {
+            let view = slug_redirect_view();
+            let events = vec![
+                created("A"),
+                renamed("A", "B"),
+                WorkspaceEvent::Archived {
+                    workspace_id: sample_workspace_id(),
+                    actor: UserId::system(),
+                    archived_at: sample_time(),
+                },
+            ];
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state, SlugRedirectViewState::default());
+        }
+
+        #[test]
+        fn slug_shared_with_live_workspace_does_not_redirect() {
+            let other = sample_workspace_id_2();
+            let view = slug_redirect_view();
+            let events = vec![
+                created("Sales Data"),
+                WorkspaceEvent::Created {
+                    workspace_id: other,
+                    name: WorkspaceName::new("Sales-Data").unwrap(),
+                    normalized_name: "sales-data".to_string(),
+                    owner_id: sample_owner(),
+                    visibility: Visibility::Private,
+                    actor: UserId::system(),
+                    created_at: sample_time(),
+                },
+                renamed("Sales Data", "Revenue"),
+            ];
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.resolve("sales-data"), None);
+            assert_eq!(
+                state.current.get(&other).map(String::as_str),
+                Some("sales-data")
+            );
+            assert_eq!(
+                state
+                    .current
+                    .get(&sample_workspace_id())
+                    .map(String::as_str),
+                Some("revenue")
+            );
+        }
     }
 
     // --- DashboardLayoutView ---
@@ -634,10 +1655,10 @@ mod tests {
         fn sample_placement(chart_id: ChartId) -> ChartPlacement {
             ChartPlacement {
                 chart_id,
-                chart_def_ref: ChartDefinitionRef {
+                source: ChartSource::Reference(ChartDefinitionRef {
                     ref_id: "ref-1".to_string(),
                     chart_type_hint: None,
-                },
+                }),
                 position: GridPosition { row: 0, col: 0 },
                 size: GridSize::new(4, 3).unwrap(),
                 tab_id: None,
@@ -662,6 +1683,7 @@ mod tests {
                 dashboard_id: sample_dash_id(),
                 workspace_id: sample_workspace_id(),
                 name: DashboardTitle::new("Main").unwrap(),
+                actor: UserId::system(),
                 created_at: sample_time(),
             };
 
@@ -680,11 +1702,13 @@ mod tests {
                     dashboard_id: sample_dash_id(),
                     workspace_id: sample_workspace_id(),
                     name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 DashboardEvent::ChartAdded {
                     dashboard_id: sample_dash_id(),
                     placement: sample_placement(sample_chart_id()),
+                    actor: UserId::system(),
                     added_at: sample_time(),
                 },
             ];
@@ -703,16 +1727,19 @@ mod tests {
                     dashboard_id: sample_dash_id(),
                     workspace_id: sample_workspace_id(),
                     name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 DashboardEvent::ChartAdded {
                     dashboard_id: sample_dash_id(),
                     placement: sample_placement(sample_chart_id()),
+                    actor: UserId::system(),
                     added_at: sample_time(),
                 },
                 DashboardEvent::ChartRemoved {
                     dashboard_id: sample_dash_id(),
                     chart_id: sample_chart_id(),
+                    actor: UserId::system(),
                     removed_at: sample_time(),
                 },
             ];
@@ -731,16 +1758,19 @@ mod tests {
                     dashboard_id: sample_dash_id(),
                     workspace_id: sample_workspace_id(),
                     name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 DashboardEvent::ChartAdded {
                     dashboard_id: sample_dash_id(),
                     placement: sample_placement(sample_chart_id()),
+                    actor: UserId::system(),
                     added_at: sample_time(),
                 },
                 DashboardEvent::ChartRemoved {
                     dashboard_id: sample_dash_id(),
                     chart_id: sample_chart_id_2(),
+                    actor: UserId::system(),
                     removed_at: sample_time(),
                 },
             ];
@@ -759,6 +1789,7 @@ mod tests {
                     dashboard_id: sample_dash_id(),
                     workspace_id: sample_workspace_id(),
                     name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 DashboardEvent::TabAdded {
@@ -767,6 +1798,7 @@ mod tests {
                         tab_id: sample_tab_id(),
                         name: TabTitle::new("Overview").unwrap(),
                     },
+                    actor: UserId::system(),
                     added_at: sample_time(),
                 },
             ];
@@ -778,6 +1810,7 @@ mod tests {
             let remove = vec![DashboardEvent::TabRemoved {
                 dashboard_id: sample_dash_id(),
                 tab_id: sample_tab_id(),
+                actor: UserId::system(),
                 removed_at: sample_time(),
             }];
             let state = view.compute_new_state(Some(state), &as_refs(&remove));
@@ -793,17 +1826,20 @@ mod tests {
                     dashboard_id: sample_dash_id(),
                     workspace_id: sample_workspace_id(),
                     name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 DashboardEvent::ChartAdded {
                     dashboard_id: sample_dash_id(),
                     placement: sample_placement(sample_chart_id()),
+                    actor: UserId::system(),
                     added_at: sample_time(),
                 },
                 DashboardEvent::ChartMovedToTab {
                     dashboard_id: sample_dash_id(),
                     chart_id: sample_chart_id(),
                     tab_id: sample_tab_id(),
+                    actor: UserId::system(),
                     moved_at: sample_time(),
                 },
             ];
@@ -812,6 +1848,233 @@ mod tests {
 
             assert_eq!(state.placements[0].tab_id, Some(sample_tab_id()));
         }
+
+        #[test]
+        fn default_tab_is_set_and_falls_back_on_removal() {
+            let view = dashboard_layout_view();
+            let overview = sample_tab_id();
+            let details = TabId::from_uuid(Uuid::from_u128(1));
+            let tab_added = |tab_id: TabId, name: &str| DashboardEvent::TabAdded {
+                dashboard_id: sample_dash_id(),
+                tab_info: TabInfo {
+                    tab_id,
+                    name: TabTitle::new(name).unwrap(),
+                },
+                actor: UserId::system(),
+                added_at: sample_time(),
+            };
+            let tab_removed = |tab_id: TabId| DashboardEvent::TabRemoved {
+                dashboard_id: sample_dash_id(),
+                tab_id,
+                actor: UserId::system(),
+                removed_at: sample_time(),
+            };
+            let events = vec![
+                DashboardEvent::DashboardCreated {
+                    dashboard_id: sample_dash_id(),
+                    workspace_id: sample_workspace_id(),
+                    name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
+                    created_at: sample_time(),
+                },
+                tab_added(overview, "Overview"),
+                tab_added(details, "Details"),
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+            assert_eq!(state.default_tab_id, Some(overview));
+
+            let set = vec![DashboardEvent::DefaultTabSet {
+                dashboard_id: sample_dash_id(),
+                tab_id: details,
+                actor: UserId::system(),
+                set_at: sample_time(),
+            }];
+            let state = view.compute_new_state(Some(state), &as_refs(&set));
+            assert_eq!(state.default_tab_id, Some(details));
+
+            let remove = vec![tab_removed(details)];
+            let state = view.compute_new_state(Some(state), &as_refs(&remove));
+            assert_eq!(state.default_tab_id, Some(overview));
+
+            let remove = vec![tab_removed(overview)];
+            let state = view.compute_new_state(Some(state), &as_refs(&remove));
+            assert_eq!(state.default_tab_id, None);
+        }
+
+        #[test]
+        fn frontend_json_has_stable_shape() {
+            let view = dashboard_layout_view();
+            let mut placement = sample_placement(sample_chart_id());
+            placement.tab_id = Some(sample_tab_id());
+            let events = vec![
+                DashboardEvent::DashboardCreated {
+                    dashboard_id: sample_dash_id(),
+                    workspace_id: sample_workspace_id(),
+                    name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
+                    created_at: sample_time(),
+                },
+                DashboardEvent::TabAdded {
+                    dashboard_id: sample_dash_id(),
+                    tab_info: TabInfo {
+                        tab_id: sample_tab_id(),
+                        name: TabTitle::new("Overview").unwrap(),
+                    },
+                    actor: UserId::system(),
+                    added_at: sample_time(),
+                },
+                DashboardEvent::ChartAdded {
+                    dashboard_id: sample_dash_id(),
+                    placement,
+                    actor: UserId::system(),
+                    added_at: sample_time(),
+                },
+            ];
+            let state = view.compute_new_state(None, &as_refs(&events));
+            let nil = Uuid::nil().to_string();
+
+            assert_eq!(
+                state.to_frontend_json().unwrap(),
+                serde_json::json!({
+                    "dashboard_id": nil,
+                    "workspace_id": sample_workspace_id().to_string(),
+                    "name": "Main",
+                    "archived": false,
+                    "default_tab_id": nil,
+                    "tabs": [{ "tab_id": nil, "name": "Overview" }],
+                    "placements": [{
+                        "chart_id": nil,
+                        "source": { "ref_id": "ref-1", "chart_type_hint": null },
+                        "position": { "row": 0, "col": 0 },
+                        "size": { "width": 4, "height": 3 },
+                        "tab_id": nil,
+                        "chart_type": "line",
+                    }],
+                })
+            );
+        }
+
+        #[test]
+        fn placements_in_tab_selects_that_tab() {
+            let overview = sample_tab_id();
+            let details = TabId::from_uuid(Uuid::from_u128(1));
+            let in_tab = |n: u128, tab_id: Option<TabId>| ChartPlacement {
+                tab_id,
+                ..sample_placement(ChartId::from_uuid(Uuid::from_u128(n)))
+            };
+            let state = DashboardLayoutViewState {
+                placements: vec![
+                    in_tab(1, Some(overview)),
+                    in_tab(2, Some(details)),
+                    in_tab(3, Some(overview)),
+                    in_tab(4, None),
+                ],
+                chart_count: 4,
+                ..DashboardLayoutViewState::default()
+            };
+            let chart_ids = |placements: Vec<&ChartPlacement>| {
+                placements
+                    .into_iter()
+                    .map(|p| p.chart_id)
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(
+                chart_ids(state.placements_in_tab(Some(&overview))),
+                vec![
+                    ChartId::from_uuid(Uuid::from_u128(1)),
+                    ChartId::from_uuid(Uuid::from_u128(3)),
+                ]
+            );
+            assert_eq!(
+                chart_ids(state.placements_in_tab(Some(&details))),
+                vec![ChartId::from_uuid(Uuid::from_u128(2))]
+            );
+            assert!(
+                state
+                    .placements_in_tab(Some(&TabId::from_uuid(Uuid::from_u128(9))))
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn placements_in_no_tab_are_the_untabbed_charts() {
+            let view = dashboard_layout_view();
+            let events = vec![
+                DashboardEvent::DashboardCreated {
+                    dashboard_id: sample_dash_id(),
+                    workspace_id: sample_workspace_id(),
+                    name: DashboardTitle::new("Main").unwrap(),
+                    actor: UserId::system(),
+                    created_at: sample_time(),
+                },
+                DashboardEvent::ChartAdded {
+                    dashboard_id: sample_dash_id(),
+                    placement: sample_placement(sample_chart_id()),
+                    actor: UserId::system(),
+                    added_at: sample_time(),
+                },
+                DashboardEvent::ChartAdded {
+                    dashboard_id: sample_dash_id(),
+                    placement: sample_placement(sample_chart_id_2()),
+                    actor: UserId::system(),
+                    added_at: sample_time(),
+                },
+                DashboardEvent::ChartMovedToTab {
+                    dashboard_id: sample_dash_id(),
+                    chart_id: sample_chart_id(),
+                    tab_id: sample_tab_id(),
+                    actor: UserId::system(),
+                    moved_at: sample_time(),
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            let untabbed = state.placements_in_tab(None);
+            assert_eq!(untabbed.len(), 1);
+            assert_eq!(untabbed[0].chart_id, sample_chart_id_2());
+            let tabbed = state.placements_in_tab(Some(&sample_tab_id()));
+            assert_eq!(tabbed.len(), 1);
+            assert_eq!(tabbed[0].chart_id, sample_chart_id());
+        }
+
+        #[test]
+        fn counts_out_of_step_with_layout_fail_validation() {
+            let state = DashboardLayoutViewState {
+                placements: vec![sample_placement(sample_chart_id())],
+                chart_count: 1,
+                ..DashboardLayoutViewState::default()
+            };
+            assert_eq!(state.validate_invariants(), Ok(()));
+
+            let stale_charts = DashboardLayoutViewState {
+                chart_count: 0,
+                ..state.clone()
+            };
+            assert_eq!(
+                stale_charts.validate_invariants(),
+                Err(ViewInvariantError::CountMismatch {
+                    field: "chart_count",
+                    count: 0,
+                    actual: 1,
+                })
+            );
+
+            let stale_tabs = DashboardLayoutViewState {
+                tab_count: 2,
+                ..state
+            };
+            assert_eq!(
+                stale_tabs.validate_invariants(),
+                Err(ViewInvariantError::CountMismatch {
+                    field: "tab_count",
+                    count: 2,
+                    actual: 0,
+                })
+            );
+        }
     }
 
     // --- SavedQueryListView ---
@@ -844,6 +2107,7 @@ mod tests {
                 name: QueryName::new("Revenue").unwrap(),
                 sql: SqlQuery::new("SELECT 1").unwrap(),
                 dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                actor: UserId::system(),
                 saved_at: sample_time(),
             };
 
@@ -863,10 +2127,12 @@ mod tests {
                     name: QueryName::new("Revenue").unwrap(),
                     sql: SqlQuery::new("SELECT 1").unwrap(),
                     dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                    actor: UserId::system(),
                     saved_at: sample_time(),
                 },
                 SavedQueryEvent::QueryDeleted {
                     query_id: sample_query_id(),
+                    actor: UserId::system(),
                     deleted_at: sample_time(),
                 },
             ];
@@ -882,6 +2148,7 @@ mod tests {
             let view = saved_query_list_view();
             let events = vec![SavedQueryEvent::QueryDeleted {
                 query_id: sample_query_id(),
+                actor: UserId::system(),
                 deleted_at: sample_time(),
             }];
 
@@ -902,11 +2169,13 @@ mod tests {
                     name: QueryName::new("Original").unwrap(),
                     sql: SqlQuery::new("SELECT 1").unwrap(),
                     dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                    actor: UserId::system(),
                     saved_at: sample_time(),
                 },
                 SavedQueryEvent::QueryRenamed {
                     query_id: sample_query_id(),
                     name: new_name.clone(),
+                    actor: UserId::system(),
                     renamed_at: sample_time(),
                 },
             ];
@@ -926,11 +2195,13 @@ mod tests {
                     name: QueryName::new("Test").unwrap(),
                     sql: SqlQuery::new("SELECT 1").unwrap(),
                     dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                    actor: UserId::system(),
                     saved_at: sample_time(),
                 },
                 SavedQueryEvent::QuerySqlUpdated {
                     query_id: sample_query_id(),
                     sql: SqlQuery::new("SELECT 2").unwrap(),
+                    actor: UserId::system(),
                     updated_at: sample_time(),
                 },
             ];
@@ -950,6 +2221,7 @@ mod tests {
                     name: QueryName::new("WS1 Query").unwrap(),
                     sql: SqlQuery::new("SELECT 1").unwrap(),
                     dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                    actor: UserId::system(),
                     saved_at: sample_time(),
                 },
                 SavedQueryEvent::QuerySaved {
@@ -958,6 +2230,7 @@ mod tests {
                     name: QueryName::new("WS2 Query").unwrap(),
                     sql: SqlQuery::new("SELECT 2").unwrap(),
                     dataset_ref: DatasetRef::new("hf://datasets/other").unwrap(),
+                    actor: UserId::system(),
                     saved_at: sample_time(),
                 },
             ];
@@ -970,6 +2243,177 @@ mod tests {
             assert_eq!(ws1_queries[0].query_id, sample_query_id());
         }
 
+        #[test]
+        fn moved_query_follows_workspace_filter() {
+            let view = saved_query_list_view();
+            let events = vec![
+                SavedQueryEvent::QuerySaved {
+                    query_id: sample_query_id(),
+                    workspace_id: sample_workspace_id(),
+                    name: QueryName::new("Revenue").unwrap(),
+                    sql: SqlQuery::new("SELECT 1").unwrap(),
+                    dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                    actor: UserId::system(),
+                    saved_at: sample_time(),
+                },
+                SavedQueryEvent::QueryMovedToWorkspace {
+                    query_id: sample_query_id(),
+                    from_workspace_id: sample_workspace_id(),
+                    to_workspace_id: sample_workspace_id_2(),
+                    actor: UserId::system(),
+                    moved_at: sample_time(),
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.count, 1);
+            assert!(
+                state
+                    .queries_for_workspace(&sample_workspace_id())
+                    .is_empty()
+            );
+            let moved = state.queries_for_workspace(&sample_workspace_id_2());
+            assert_eq!(moved.len(), 1);
+            assert_eq!(moved[0].query_id, sample_query_id());
+            assert_eq!(moved[0].version, 2);
+        }
+
+        #[test]
+        fn moved_unknown_query_is_noop() {
+            let view = saved_query_list_view();
+            let events = vec![SavedQueryEvent::QueryMovedToWorkspace {
+                query_id: sample_query_id(),
+                from_workspace_id: sample_workspace_id(),
+                to_workspace_id: sample_workspace_id_2(),
+                actor: UserId::system(),
+                moved_at: sample_time(),
+            }];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert!(state.queries.is_empty());
+            assert_eq!(state.count, 0);
+        }
+
+        #[test]
+        fn version_increments_only_for_mutated_entry() {
+            let view = saved_query_list_view();
+            let saved = |query_id| SavedQueryEvent::QuerySaved {
+                query_id,
+                workspace_id: sample_workspace_id(),
+                name: QueryName::new("Original").unwrap(),
+                sql: SqlQuery::new("SELECT 1").unwrap(),
+                dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                actor: UserId::system(),
+                saved_at: sample_time(),
+            };
+            let events = vec![
+                saved(sample_query_id()),
+                saved(sample_query_id_2()),
+                SavedQueryEvent::QueryRenamed {
+                    query_id: sample_query_id(),
+                    name: QueryName::new("Updated").unwrap(),
+                    actor: UserId::system(),
+                    renamed_at: sample_time(),
+                },
+                SavedQueryEvent::QuerySqlUpdated {
+                    query_id: sample_query_id(),
+                    sql: SqlQuery::new("SELECT 2").unwrap(),
+                    actor: UserId::system(),
+                    updated_at: sample_time(),
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.queries[0].version, 3);
+            assert_eq!(state.queries[1].version, 1);
+        }
+
+        #[test]
+        fn query_run_increments_run_count() {
+            let view = saved_query_list_view();
+            let later = sample_time() + chrono::Duration::hours(1);
+            let run = |run_at| SavedQueryEvent::QueryRun {
+                query_id: sample_query_id(),
+                run_at,
+            };
+            let events = vec![
+                SavedQueryEvent::QuerySaved {
+                    query_id: sample_query_id(),
+                    workspace_id: sample_workspace_id(),
+                    name: QueryName::new("Revenue").unwrap(),
+                    sql: SqlQuery::new("SELECT 1").unwrap(),
+                    dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                    actor: UserId::system(),
+                    saved_at: sample_time(),
+                },
+                run(sample_time()),
+                run(later),
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.queries[0].run_count, 2);
+            assert_eq!(state.queries[0].last_run_at, Some(later));
+        }
+
+        #[test]
+        fn most_run_orders_by_run_count() {
+            let view = saved_query_list_view();
+            let third = SavedQueryId::from_uuid(Uuid::from_u128(2));
+            let saved = |query_id| SavedQueryEvent::QuerySaved {
+                query_id,
+                workspace_id: sample_workspace_id(),
+                name: QueryName::new("Query").unwrap(),
+                sql: SqlQuery::new("SELECT 1").unwrap(),
+                dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                actor: UserId::system(),
+                saved_at: sample_time(),
+            };
+            let run = |query_id| SavedQueryEvent::QueryRun {
+                query_id,
+                run_at: sample_time(),
+            };
+            let events = vec![
+                saved(sample_query_id()),
+                saved(sample_query_id_2()),
+                saved(third),
+                run(sample_query_id_2()),
+                run(third),
+                run(sample_query_id_2()),
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            let top: Vec<SavedQueryId> = state.most_run(2).iter().map(|q| q.query_id).collect();
+            assert_eq!(top, vec![sample_query_id_2(), third]);
+            assert_eq!(state.most_run(10).len(), 3);
+        }
+
+        #[test]
+        fn name_exists_only_within_workspace() {
+            let view = saved_query_list_view();
+            let other_workspace = WorkspaceId::from_uuid(Uuid::from_u128(99));
+            let events = vec![SavedQueryEvent::QuerySaved {
+                query_id: sample_query_id(),
+                workspace_id: sample_workspace_id(),
+                name: QueryName::new("Revenue").unwrap(),
+                sql: SqlQuery::new("SELECT 1").unwrap(),
+                dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                actor: UserId::system(),
+                saved_at: sample_time(),
+            }];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert!(state.name_exists_in_workspace(&sample_workspace_id(), "Revenue"));
+            assert!(state.name_exists_in_workspace(&sample_workspace_id(), " revenue "));
+            assert!(!state.name_exists_in_workspace(&sample_workspace_id(), "Costs"));
+            assert!(!state.name_exists_in_workspace(&other_workspace, "Revenue"));
+        }
+
         #[test]
         fn count_invariant_after_delete_nonexistent() {
             let view = saved_query_list_view();
@@ -979,12 +2423,14 @@ mod tests {
                 name: QueryName::new("Keep").unwrap(),
                 sql: SqlQuery::new("SELECT 1").unwrap(),
                 dataset_ref: DatasetRef::new("hf://datasets/test").unwrap(),
+                actor: UserId::system(),
                 saved_at: sample_time(),
             }];
             let state = view.compute_new_state(None, &as_refs(&events));
 
             let delete = vec![SavedQueryEvent::QueryDeleted {
                 query_id: sample_query_id_2(),
+                actor: UserId::system(),
                 deleted_at: sample_time(),
             }];
             let state = view.compute_new_state(Some(state), &as_refs(&delete));
@@ -992,12 +2438,29 @@ mod tests {
             assert_eq!(state.queries.len(), 1);
             assert_eq!(state.count, state.queries.len());
         }
+
+        #[test]
+        fn count_out_of_step_with_entries_fails_validation() {
+            let state = SavedQueryListViewState {
+                queries: vec![],
+                count: 1,
+            };
+            assert_eq!(
+                state.validate_invariants(),
+                Err(ViewInvariantError::CountMismatch {
+                    field: "count",
+                    count: 1,
+                    actual: 0,
+                })
+            );
+        }
     }
 
     // --- UserPreferencesView ---
 
     mod user_preferences {
         use super::*;
+        use crate::user_preferences::values::DigestFrequency;
 
         fn sample_pref_id() -> PreferencesId {
             PreferencesId::from_uuid(Uuid::nil())
@@ -1096,6 +2559,84 @@ mod tests {
             assert_eq!(state.ui_state, ui_state);
         }
 
+        #[test]
+        fn default_workspace_reflects_set_and_clear() {
+            let view = user_preferences_view();
+            let mut events = vec![
+                UserPreferencesEvent::PreferencesInitialized {
+                    preferences_id: sample_pref_id(),
+                    user_id: sample_owner(),
+                    initialized_at: sample_time(),
+                },
+                UserPreferencesEvent::DefaultWorkspaceSet {
+                    user_id: sample_owner(),
+                    workspace_id: sample_workspace_id(),
+                    set_at: sample_time(),
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+            assert_eq!(state.default_workspace, Some(sample_workspace_id()));
+
+            events.push(UserPreferencesEvent::DefaultWorkspaceCleared {
+                user_id: sample_owner(),
+                cleared_at: sample_time(),
+            });
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+            assert_eq!(state.default_workspace, None);
+        }
+
+        #[test]
+        fn notification_preferences_are_surfaced() {
+            let view = user_preferences_view();
+            let notifications = NotificationPreferences::new(true, false, DigestFrequency::Daily);
+            let events = vec![
+                UserPreferencesEvent::PreferencesInitialized {
+                    preferences_id: sample_pref_id(),
+                    user_id: sample_owner(),
+                    initialized_at: sample_time(),
+                },
+                UserPreferencesEvent::NotificationPreferencesSet {
+                    user_id: sample_owner(),
+                    notifications,
+                    set_at: sample_time(),
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(state.notifications, notifications);
+        }
+
+        #[test]
+        fn recent_workspaces_are_most_recent_first_and_bounded() {
+            let view = user_preferences_view();
+            let workspace = |n: u128| WorkspaceId::from_uuid(Uuid::from_u128(n));
+            let mut events = vec![UserPreferencesEvent::PreferencesInitialized {
+                preferences_id: sample_pref_id(),
+                user_id: sample_owner(),
+                initialized_at: sample_time(),
+            }];
+            events.extend(
+                (1..=12)
+                    .chain([5])
+                    .map(|n| UserPreferencesEvent::WorkspaceViewed {
+                        user_id: sample_owner(),
+                        workspace_id: workspace(n),
+                        viewed_at: sample_time(),
+                    }),
+            );
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            let expected: Vec<WorkspaceId> = [5, 12, 11, 10, 9, 8, 7, 6, 4, 3]
+                .into_iter()
+                .map(workspace)
+                .collect();
+            assert_eq!(state.recent_workspaces(), expected.as_slice());
+        }
+
         #[test]
         fn full_lifecycle() {
             let view = user_preferences_view();
@@ -1129,4 +2670,84 @@ mod tests {
             assert_eq!(state.locale, Locale::new("fr-FR").unwrap());
         }
     }
+
+    // --- WorkspacePreferencesView ---
+
+    mod workspace_preferences {
+        use super::*;
+
+        fn initialized() -> WorkspacePreferencesEvent {
+            WorkspacePreferencesEvent::WorkspacePreferencesInitialized {
+                workspace_id: sample_workspace_id(),
+                initialized_at: sample_time(),
+            }
+        }
+
+        #[test]
+        fn initial_state_is_not_initialized() {
+            let view = workspace_preferences_view();
+            let state = (view.initial_state)();
+            assert!(!state.initialized);
+            assert!(state.workspace_id.is_none());
+            assert!(state.default_catalog.is_none());
+        }
+
+        #[test]
+        fn initialize_then_set_default_catalog() {
+            let view = workspace_preferences_view();
+            let set_at = sample_time() + chrono::Duration::minutes(5);
+            let events = vec![
+                initialized(),
+                WorkspacePreferencesEvent::DefaultCatalogSet {
+                    workspace_id: sample_workspace_id(),
+                    catalog_uri: CatalogUri::new("ducklake:hf://datasets/sciexp/fixtures").unwrap(),
+                    set_at,
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert_eq!(
+                state,
+                WorkspacePreferencesViewState {
+                    workspace_id: Some(sample_workspace_id()),
+                    default_catalog: Some(
+                        CatalogUri::new("ducklake:hf://datasets/sciexp/fixtures").unwrap()
+                    ),
+                    layout_defaults: LayoutDefaults::default(),
+                    updated_at: Some(set_at),
+                    initialized: true,
+                }
+            );
+        }
+
+        #[test]
+        fn clear_catalog_and_update_layout() {
+            let view = workspace_preferences_view();
+            let layout = LayoutDefaults::new(r#"{"columns":12}"#);
+            let events = vec![
+                initialized(),
+                WorkspacePreferencesEvent::DefaultCatalogSet {
+                    workspace_id: sample_workspace_id(),
+                    catalog_uri: CatalogUri::new("ducklake:space").unwrap(),
+                    set_at: sample_time(),
+                },
+                WorkspacePreferencesEvent::DefaultCatalogCleared {
+                    workspace_id: sample_workspace_id(),
+                    cleared_at: sample_time(),
+                },
+                WorkspacePreferencesEvent::LayoutDefaultsUpdated {
+                    workspace_id: sample_workspace_id(),
+                    layout_defaults: layout.clone(),
+                    updated_at: sample_time(),
+                },
+            ];
+
+            let state = view.compute_new_state(None, &as_refs(&events));
+
+            assert!(state.initialized);
+            assert!(state.default_catalog.is_none());
+            assert_eq!(state.layout_defaults, layout);
+        }
+    }
 }
@@ -60,6 +60,9 @@ pub enum WorkspaceCommand {
         owner_id: UserId,
         /// Visibility setting.
         visibility: Visibility,
+        /// Who issued the command (injected at boundary).
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         /// When the command was issued (injected at boundary).
         created_at: DateTime<Utc>,
     },
@@ -70,6 +73,9 @@ pub enum WorkspaceCommand {
         workspace_id: WorkspaceId,
         /// New name (raw, will be validated).
         new_name: String,
+        /// Who issued the command (injected at boundary).
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         /// When the rename was issued (injected at boundary).
         renamed_at: DateTime<Utc>,
     },
@@ -80,9 +86,54 @@ pub enum WorkspaceCommand {
         workspace_id: WorkspaceId,
         /// New visibility setting.
         visibility: Visibility,
+        /// Who issued the command (injected at boundary).
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         /// When the change was issued (injected at boundary).
         changed_at: DateTime<Utc>,
     },
+
+    /// Suspend a workspace pending administrative review.
+    ///
+    /// Blocks every other modifying command until `Unsuspend`; reads are
+    /// unaffected. Only administrators may suspend or unsuspend, which the
+    /// application layer checks before the decider runs.
+    Suspend {
+        /// Which workspace to suspend.
+        workspace_id: WorkspaceId,
+        /// Why the workspace is being suspended.
+        reason: String,
+        /// Who issued the command (injected at boundary).
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        /// When the suspension was issued (injected at boundary).
+        suspended_at: DateTime<Utc>,
+    },
+
+    /// Lift a suspension, making the workspace modifiable again.
+    Unsuspend {
+        /// Which workspace to unsuspend.
+        workspace_id: WorkspaceId,
+        /// Who issued the command (injected at boundary).
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        /// When the suspension was lifted (injected at boundary).
+        unsuspended_at: DateTime<Utc>,
+    },
+
+    /// Archive a workspace, ending its lifecycle.
+    ///
+    /// Child dashboards and saved queries are cleaned up separately by
+    /// `WorkspaceDeletionSaga` reacting to the resulting event.
+    Archive {
+        /// Which workspace to archive.
+        workspace_id: WorkspaceId,
+        /// Who issued the command (injected at boundary).
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        /// When the archive was issued (injected at boundary).
+        archived_at: DateTime<Utc>,
+    },
 }
 
 impl WorkspaceCommand {
@@ -94,7 +145,10 @@ impl WorkspaceCommand {
         match self {
             Self::Create { workspace_id, .. }
             | Self::Rename { workspace_id, .. }
-            | Self::SetVisibility { workspace_id, .. } => *workspace_id,
+            | Self::SetVisibility { workspace_id, .. }
+            | Self::Suspend { workspace_id, .. }
+            | Self::Unsuspend { workspace_id, .. }
+            | Self::Archive { workspace_id, .. } => *workspace_id,
         }
     }
 
@@ -105,13 +159,16 @@ impl WorkspaceCommand {
             Self::Create { .. } => "Create",
             Self::Rename { .. } => "Rename",
             Self::SetVisibility { .. } => "SetVisibility",
+            Self::Suspend { .. } => "Suspend",
+            Self::Unsuspend { .. } => "Unsuspend",
+            Self::Archive { .. } => "Archive",
         }
     }
 }
 
 impl Identifier for WorkspaceCommand {
     fn identifier(&self) -> String {
-        self.aggregate_id().to_string()
+        self.aggregate_id().identifier()
     }
 }
 
@@ -138,6 +195,7 @@ mod tests {
             name: "My Workspace".to_string(),
             owner_id: UserId::from_uuid(uuid::Uuid::nil()),
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: sample_time(),
         };
 
@@ -155,6 +213,7 @@ mod tests {
         let original = WorkspaceCommand::Rename {
             workspace_id: WorkspaceId::new(),
             new_name: "New Name".to_string(),
+            actor: UserId::system(),
             renamed_at: sample_time(),
         };
 
@@ -176,16 +235,19 @@ mod tests {
                 name: "test".to_string(),
                 owner_id: owner,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             },
             WorkspaceCommand::Rename {
                 workspace_id: id,
                 new_name: "updated".to_string(),
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             WorkspaceCommand::SetVisibility {
                 workspace_id: id,
                 visibility: Visibility::Public,
+                actor: UserId::system(),
                 changed_at: ts,
             },
         ];
@@ -214,4 +276,44 @@ mod tests {
         );
         // Note: name is NOT trimmed here - that's the decider's job
     }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            WorkspaceCommand::Create {
+                workspace_id: WorkspaceId::new(),
+                name: "Analytics".to_string(),
+                owner_id: UserId::new(),
+                visibility: Visibility::Public,
+                actor: UserId::system(),
+                created_at: ts,
+            },
+            WorkspaceCommand::Rename {
+                workspace_id: WorkspaceId::new(),
+                new_name: "Reporting".to_string(),
+                actor: UserId::system(),
+                renamed_at: ts,
+            },
+            WorkspaceCommand::SetVisibility {
+                workspace_id: WorkspaceId::new(),
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                changed_at: ts,
+            },
+            WorkspaceCommand::Archive {
+                workspace_id: WorkspaceId::new(),
+                actor: UserId::system(),
+                archived_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: WorkspaceCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
 }
@@ -11,23 +11,29 @@
 //!     Create ───────►│    Active    │
 //!                    └──────┬───────┘
 //!                           │
-//!            ┌──────────────┼──────────────┐
-//!            │              │              │
-//!         Rename     SetVisibility    (future: Delete)
-//!            │              │              │
-//!            └──────────────┴──────────────┘
-//!                           │
-//!                           ▼
-//!                    ┌──────────────┐
-//!                    │    Active    │ (same state, updated fields)
-//!                    └──────────────┘
+//!            ┌──────────────┼──────────────┬──────────────┐
+//!            │              │              │              │
+//!         Rename     SetVisibility      Archive        Suspend
+//!            │              │              │              │
+//!            ▼              ▼              ▼              ▼
+//!     ┌──────────────────────────┐  ┌──────────────┐ ┌─────────────┐
+//!     │ Active (updated fields)  │  │   Archived   │ │  Suspended  │
+//!     └──────────────────────────┘  └──────────────┘ └──────┬──────┘
+//!                                     (terminal)            │
+//!                                                 Unsuspend → Active
 //! ```
 //!
+//! A suspended workspace rejects Rename, SetVisibility, and Archive with
+//! `WorkspaceSuspended` until an administrator unsuspends it.
+//!
 //! # Idempotency
 //!
 //! Operations that would result in the same state return `Ok(vec![])`:
 //! - Rename with the same name
 //! - SetVisibility with the same visibility
+//! - Suspend with the reason the workspace is already suspended for
+//! - Unsuspend of an active workspace
+//! - Archive of an already archived workspace
 
 use ironstar_core::Decider;
 use tracing::instrument;
@@ -50,6 +56,9 @@ pub type WorkspaceDecider<'a> =
 /// The decider embodies the state machine from `spec/Workspace/WorkspaceAggregate.idr`:
 /// - NotCreated → Active (Create)
 /// - Active → Active (Rename, SetVisibility)
+/// - Active → Suspended (Suspend) → Active (Unsuspend); only suspension
+///   commands are accepted while suspended
+/// - Active → Archived (Archive); every other command is rejected once archived
 /// - Idempotent operations return `Ok(vec![])` when already in target state
 /// - Precondition violations return `Err(WorkspaceError::X)`
 ///
@@ -93,7 +102,7 @@ fn decide(
     command: &WorkspaceCommand,
     state: &WorkspaceState,
 ) -> Result<Vec<WorkspaceEvent>, WorkspaceError> {
-    let result = match (command, state.status) {
+    let result = match (command, &state.status) {
         // Create: NotCreated → Active
         (
             WorkspaceCommand::Create {
@@ -101,6 +110,7 @@ fn decide(
                 name,
                 owner_id,
                 visibility,
+                actor,
                 created_at,
             },
             WorkspaceStatus::NotCreated,
@@ -108,23 +118,27 @@ fn decide(
             let validated_name = WorkspaceName::new(name.clone())?;
             Ok(vec![WorkspaceEvent::Created {
                 workspace_id: *workspace_id,
+                normalized_name: validated_name.normalized(),
                 name: validated_name,
                 owner_id: *owner_id,
                 visibility: *visibility,
+                actor: *actor,
                 created_at: *created_at,
             }])
         }
 
         // Create when already exists
-        (WorkspaceCommand::Create { .. }, WorkspaceStatus::Active) => {
-            Err(WorkspaceError::already_exists())
-        }
+        (
+            WorkspaceCommand::Create { .. },
+            WorkspaceStatus::Active | WorkspaceStatus::Suspended { .. } | WorkspaceStatus::Archived,
+        ) => Err(WorkspaceError::already_exists()),
 
         // Rename: Active → Active (idempotent if same name)
         (
             WorkspaceCommand::Rename {
                 workspace_id,
                 new_name,
+                actor,
                 renamed_at,
             },
             WorkspaceStatus::Active,
@@ -140,7 +154,9 @@ fn decide(
                 Ok(vec![WorkspaceEvent::Renamed {
                     workspace_id: *workspace_id,
                     old_name: current_name.clone(),
+                    normalized_name: validated_name.normalized(),
                     new_name: validated_name,
+                    actor: *actor,
                     renamed_at: *renamed_at,
                 }])
             } else {
@@ -159,6 +175,7 @@ fn decide(
             WorkspaceCommand::SetVisibility {
                 workspace_id,
                 visibility,
+                actor,
                 changed_at,
             },
             WorkspaceStatus::Active,
@@ -173,6 +190,7 @@ fn decide(
                     workspace_id: *workspace_id,
                     old_visibility: *current_visibility,
                     new_visibility: *visibility,
+                    actor: *actor,
                     changed_at: *changed_at,
                 }])
             } else {
@@ -185,6 +203,108 @@ fn decide(
         (WorkspaceCommand::SetVisibility { .. }, WorkspaceStatus::NotCreated) => {
             Err(WorkspaceError::not_found())
         }
+
+        // Archive: Active → Archived
+        (
+            WorkspaceCommand::Archive {
+                workspace_id,
+                actor,
+                archived_at,
+            },
+            WorkspaceStatus::Active,
+        ) => Ok(vec![WorkspaceEvent::Archived {
+            workspace_id: *workspace_id,
+            actor: *actor,
+            archived_at: *archived_at,
+        }]),
+
+        // Archive when already archived (idempotent)
+        (WorkspaceCommand::Archive { .. }, WorkspaceStatus::Archived) => Ok(vec![]),
+
+        // Archive when not created
+        (WorkspaceCommand::Archive { .. }, WorkspaceStatus::NotCreated) => {
+            Err(WorkspaceError::not_found())
+        }
+
+        // Suspend: Active → Suspended
+        (
+            WorkspaceCommand::Suspend {
+                workspace_id,
+                reason,
+                actor,
+                suspended_at,
+            },
+            WorkspaceStatus::Active,
+        ) => Ok(vec![WorkspaceEvent::Suspended {
+            workspace_id: *workspace_id,
+            reason: reason.clone(),
+            actor: *actor,
+            suspended_at: *suspended_at,
+        }]),
+
+        // Suspend when already suspended: idempotent for the same reason,
+        // otherwise records the new reason
+        (
+            WorkspaceCommand::Suspend {
+                workspace_id,
+                reason,
+                actor,
+                suspended_at,
+            },
+            WorkspaceStatus::Suspended {
+                reason: current_reason,
+            },
+        ) => {
+            if reason == current_reason {
+                return Ok(vec![]);
+            }
+            Ok(vec![WorkspaceEvent::Suspended {
+                workspace_id: *workspace_id,
+                reason: reason.clone(),
+                actor: *actor,
+                suspended_at: *suspended_at,
+            }])
+        }
+
+        // Unsuspend: Suspended → Active
+        (
+            WorkspaceCommand::Unsuspend {
+                workspace_id,
+                actor,
+                unsuspended_at,
+            },
+            WorkspaceStatus::Suspended { .. },
+        ) => Ok(vec![WorkspaceEvent::Unsuspended {
+            workspace_id: *workspace_id,
+            actor: *actor,
+            unsuspended_at: *unsuspended_at,
+        }]),
+
+        // Unsuspend when not suspended (idempotent)
+        (WorkspaceCommand::Unsuspend { .. }, WorkspaceStatus::Active) => Ok(vec![]),
+
+        // Suspension commands when not created
+        (
+            WorkspaceCommand::Suspend { .. } | WorkspaceCommand::Unsuspend { .. },
+            WorkspaceStatus::NotCreated,
+        ) => Err(WorkspaceError::not_found()),
+
+        // Mutations of a suspended workspace
+        (
+            WorkspaceCommand::Rename { .. }
+            | WorkspaceCommand::SetVisibility { .. }
+            | WorkspaceCommand::Archive { .. },
+            WorkspaceStatus::Suspended { reason },
+        ) => Err(WorkspaceError::suspended(reason.clone())),
+
+        // Mutations of an archived workspace
+        (
+            WorkspaceCommand::Rename { .. }
+            | WorkspaceCommand::SetVisibility { .. }
+            | WorkspaceCommand::Suspend { .. }
+            | WorkspaceCommand::Unsuspend { .. },
+            WorkspaceStatus::Archived,
+        ) => Err(WorkspaceError::archived()),
     };
     if let Ok(ref events) = result {
         tracing::debug!(event_count = events.len(), "decision complete");
@@ -211,6 +331,7 @@ fn evolve(state: &WorkspaceState, event: &WorkspaceEvent) -> WorkspaceState {
             owner_id,
             visibility,
             created_at,
+            ..
         } => WorkspaceState {
             id: Some(*workspace_id),
             name: Some(name.clone()),
@@ -231,6 +352,26 @@ fn evolve(state: &WorkspaceState, event: &WorkspaceEvent) -> WorkspaceState {
             visibility: Some(*new_visibility),
             ..state.clone()
         },
+
+        // Suspended: Active → Suspended (or a new reason while suspended)
+        WorkspaceEvent::Suspended { reason, .. } => WorkspaceState {
+            status: WorkspaceStatus::Suspended {
+                reason: reason.clone(),
+            },
+            ..state.clone()
+        },
+
+        // Unsuspended: Suspended → Active
+        WorkspaceEvent::Unsuspended { .. } => WorkspaceState {
+            status: WorkspaceStatus::Active,
+            ..state.clone()
+        },
+
+        // Archived: Active → Archived
+        WorkspaceEvent::Archived { .. } => WorkspaceState {
+            status: WorkspaceStatus::Archived,
+            ..state.clone()
+        },
     }
 }
 
@@ -238,7 +379,7 @@ fn evolve(state: &WorkspaceState, event: &WorkspaceEvent) -> WorkspaceState {
 mod tests {
     use super::*;
     use chrono::{DateTime, Utc};
-    use ironstar_core::DeciderTestSpecification;
+    use ironstar_core::{DeciderTestExt, DeciderTestSpecification};
 
     use super::super::values::{Visibility, WorkspaceId};
     use ironstar_shared_kernel::UserId;
@@ -277,13 +418,44 @@ mod tests {
                 name: "My Workspace".to_string(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             })
             .then(vec![WorkspaceEvent::Created {
                 workspace_id: ws_id,
                 name: sample_name(),
+                normalized_name: sample_name().normalized(),
+                owner_id: user_id,
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: ts,
+            }]);
+    }
+
+    #[test]
+    fn create_records_normalized_name_separately() {
+        let ws_id = sample_workspace_id();
+        let user_id = sample_user_id();
+        let ts = sample_time();
+
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![])
+            .when(WorkspaceCommand::Create {
+                workspace_id: ws_id,
+                name: "  Sales ANALYTICS ".to_string(),
+                owner_id: user_id,
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: ts,
+            })
+            .then(vec![WorkspaceEvent::Created {
+                workspace_id: ws_id,
+                name: WorkspaceName::new("Sales ANALYTICS").unwrap(),
+                normalized_name: "sales analytics".to_string(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             }]);
     }
@@ -299,8 +471,10 @@ mod tests {
             .given(vec![WorkspaceEvent::Created {
                 workspace_id: ws_id,
                 name: sample_name(),
+                normalized_name: sample_name().normalized(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             }])
             .when(WorkspaceCommand::Create {
@@ -308,6 +482,7 @@ mod tests {
                 name: "Another Workspace".to_string(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             })
             .then_error(WorkspaceError::already_exists());
@@ -327,6 +502,7 @@ mod tests {
                 name: "   ".to_string(), // Empty after trim
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             })
             .then_error(WorkspaceError::invalid_name(
@@ -347,27 +523,31 @@ mod tests {
             .given(vec![WorkspaceEvent::Created {
                 workspace_id: ws_id,
                 name: sample_name(),
+                normalized_name: sample_name().normalized(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             }])
             .when(WorkspaceCommand::Rename {
                 workspace_id: ws_id,
                 new_name: "New Name".to_string(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then(vec![WorkspaceEvent::Renamed {
                 workspace_id: ws_id,
                 old_name: sample_name(),
                 new_name: WorkspaceName::new("New Name").unwrap(),
+                normalized_name: WorkspaceName::new("New Name").unwrap().normalized(),
+                actor: UserId::system(),
                 renamed_at: ts,
             }]);
     }
 
     #[test]
-    fn rename_same_name_is_idempotent() {
+    fn rename_records_normalized_new_name() {
         let ws_id = sample_workspace_id();
-        let user_id = sample_user_id();
         let ts = sample_time();
 
         DeciderTestSpecification::default()
@@ -375,16 +555,51 @@ mod tests {
             .given(vec![WorkspaceEvent::Created {
                 workspace_id: ws_id,
                 name: sample_name(),
-                owner_id: user_id,
+                normalized_name: sample_name().normalized(),
+                owner_id: sample_user_id(),
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             }])
             .when(WorkspaceCommand::Rename {
                 workspace_id: ws_id,
-                new_name: "My Workspace".to_string(), // Same name
+                new_name: "Q3 Report".to_string(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
-            .then(vec![]); // Idempotent: no events
+            .then(vec![WorkspaceEvent::Renamed {
+                workspace_id: ws_id,
+                old_name: sample_name(),
+                new_name: WorkspaceName::new("Q3 Report").unwrap(),
+                normalized_name: "q3 report".to_string(),
+                actor: UserId::system(),
+                renamed_at: ts,
+            }]);
+    }
+
+    #[test]
+    fn rename_same_name_is_idempotent() {
+        let ws_id = sample_workspace_id();
+        let user_id = sample_user_id();
+        let ts = sample_time();
+
+        workspace_decider().assert_no_events(
+            vec![WorkspaceEvent::Created {
+                workspace_id: ws_id,
+                name: sample_name(),
+                normalized_name: sample_name().normalized(),
+                owner_id: user_id,
+                visibility: Visibility::Private,
+                actor: UserId::system(),
+                created_at: ts,
+            }],
+            WorkspaceCommand::Rename {
+                workspace_id: ws_id,
+                new_name: "My Workspace".to_string(), // Same name
+                actor: UserId::system(),
+                renamed_at: ts,
+            },
+        );
     }
 
     #[test]
@@ -398,6 +613,7 @@ mod tests {
             .when(WorkspaceCommand::Rename {
                 workspace_id: ws_id,
                 new_name: "New Name".to_string(),
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then_error(WorkspaceError::not_found());
@@ -414,13 +630,16 @@ mod tests {
             .given(vec![WorkspaceEvent::Created {
                 workspace_id: ws_id,
                 name: sample_name(),
+                normalized_name: sample_name().normalized(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             }])
             .when(WorkspaceCommand::Rename {
                 workspace_id: ws_id,
                 new_name: "".to_string(), // Invalid
+                actor: UserId::system(),
                 renamed_at: ts,
             })
             .then_error(WorkspaceError::invalid_name(
@@ -441,19 +660,23 @@ mod tests {
             .given(vec![WorkspaceEvent::Created {
                 workspace_id: ws_id,
                 name: sample_name(),
+                normalized_name: sample_name().normalized(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             }])
             .when(WorkspaceCommand::SetVisibility {
                 workspace_id: ws_id,
                 visibility: Visibility::Public,
+                actor: UserId::system(),
                 changed_at: ts,
             })
             .then(vec![WorkspaceEvent::VisibilityChanged {
                 workspace_id: ws_id,
                 old_visibility: Visibility::Private,
                 new_visibility: Visibility::Public,
+                actor: UserId::system(),
                 changed_at: ts,
             }]);
     }
@@ -469,13 +692,16 @@ mod tests {
             .given(vec![WorkspaceEvent::Created {
                 workspace_id: ws_id,
                 name: sample_name(),
+                normalized_name: sample_name().normalized(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             }])
             .when(WorkspaceCommand::SetVisibility {
                 workspace_id: ws_id,
                 visibility: Visibility::Private, // Same visibility
+                actor: UserId::system(),
                 changed_at: ts,
             })
             .then(vec![]); // Idempotent: no events
@@ -492,6 +718,7 @@ mod tests {
             .when(WorkspaceCommand::SetVisibility {
                 workspace_id: ws_id,
                 visibility: Visibility::Public,
+                actor: UserId::system(),
                 changed_at: ts,
             })
             .then_error(WorkspaceError::not_found());
@@ -512,6 +739,7 @@ mod tests {
                 name: "Initial Name".to_string(),
                 owner_id: user_id,
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: ts,
             },
             &WorkspaceState::default(),
@@ -529,6 +757,7 @@ mod tests {
             &WorkspaceCommand::Rename {
                 workspace_id: ws_id,
                 new_name: "New Name".to_string(),
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             &state,
@@ -545,6 +774,7 @@ mod tests {
             &WorkspaceCommand::SetVisibility {
                 workspace_id: ws_id,
                 visibility: Visibility::Public,
+                actor: UserId::system(),
                 changed_at: ts,
             },
             &state,
@@ -561,6 +791,7 @@ mod tests {
             &WorkspaceCommand::Rename {
                 workspace_id: ws_id,
                 new_name: "New Name".to_string(), // Same name
+                actor: UserId::system(),
                 renamed_at: ts,
             },
             &state,
@@ -572,6 +803,7 @@ mod tests {
             &WorkspaceCommand::SetVisibility {
                 workspace_id: ws_id,
                 visibility: Visibility::Public, // Same visibility
+                actor: UserId::system(),
                 changed_at: ts,
             },
             &state,
@@ -579,4 +811,220 @@ mod tests {
         .unwrap();
         assert!(events.is_empty()); // Idempotent
     }
+
+    // --- Archive transitions ---
+
+    fn created_event() -> WorkspaceEvent {
+        WorkspaceEvent::Created {
+            workspace_id: sample_workspace_id(),
+            name: sample_name(),
+            normalized_name: sample_name().normalized(),
+            owner_id: sample_user_id(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: sample_time(),
+        }
+    }
+
+    fn archived_event() -> WorkspaceEvent {
+        WorkspaceEvent::Archived {
+            workspace_id: sample_workspace_id(),
+            actor: UserId::system(),
+            archived_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn archive_active_workspace_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![created_event()])
+            .when(WorkspaceCommand::Archive {
+                workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            })
+            .then(vec![archived_event()]);
+    }
+
+    #[test]
+    fn archive_records_issuing_actor() {
+        let actor = UserId::from_uuid(uuid::Uuid::from_u128(42));
+
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![created_event()])
+            .when(WorkspaceCommand::Archive {
+                workspace_id: sample_workspace_id(),
+                actor,
+                archived_at: sample_time(),
+            })
+            .then(vec![WorkspaceEvent::Archived {
+                workspace_id: sample_workspace_id(),
+                actor,
+                archived_at: sample_time(),
+            }]);
+    }
+
+    #[test]
+    fn archive_archived_workspace_is_idempotent() {
+        workspace_decider().assert_no_events(
+            vec![created_event(), archived_event()],
+            WorkspaceCommand::Archive {
+                workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            },
+        );
+    }
+
+    #[test]
+    fn archive_not_created_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![])
+            .when(WorkspaceCommand::Archive {
+                workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            })
+            .then_error(WorkspaceError::not_found());
+    }
+
+    #[test]
+    fn rename_archived_workspace_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![created_event(), archived_event()])
+            .when(WorkspaceCommand::Rename {
+                workspace_id: sample_workspace_id(),
+                new_name: "Renamed".to_string(),
+                actor: UserId::system(),
+                renamed_at: sample_time(),
+            })
+            .then_error(WorkspaceError::archived());
+    }
+
+    // --- Suspend transitions ---
+
+    fn suspended_event() -> WorkspaceEvent {
+        WorkspaceEvent::Suspended {
+            workspace_id: sample_workspace_id(),
+            reason: "pending review".to_string(),
+            actor: UserId::system(),
+            suspended_at: sample_time(),
+        }
+    }
+
+    fn unsuspended_event() -> WorkspaceEvent {
+        WorkspaceEvent::Unsuspended {
+            workspace_id: sample_workspace_id(),
+            actor: UserId::system(),
+            unsuspended_at: sample_time(),
+        }
+    }
+
+    fn rename_command() -> WorkspaceCommand {
+        WorkspaceCommand::Rename {
+            workspace_id: sample_workspace_id(),
+            new_name: "Renamed".to_string(),
+            actor: UserId::system(),
+            renamed_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn suspend_active_workspace_succeeds() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![created_event()])
+            .when(WorkspaceCommand::Suspend {
+                workspace_id: sample_workspace_id(),
+                reason: "pending review".to_string(),
+                actor: UserId::system(),
+                suspended_at: sample_time(),
+            })
+            .then(vec![suspended_event()]);
+    }
+
+    #[test]
+    fn suspend_blocks_rename() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![created_event(), suspended_event()])
+            .when(rename_command())
+            .then_error(WorkspaceError::suspended("pending review"));
+    }
+
+    #[test]
+    fn suspend_blocks_archive() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![created_event(), suspended_event()])
+            .when(WorkspaceCommand::Archive {
+                workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                archived_at: sample_time(),
+            })
+            .then_error(WorkspaceError::suspended("pending review"));
+    }
+
+    #[test]
+    fn suspend_with_same_reason_is_idempotent() {
+        workspace_decider().assert_no_events(
+            vec![created_event(), suspended_event()],
+            WorkspaceCommand::Suspend {
+                workspace_id: sample_workspace_id(),
+                reason: "pending review".to_string(),
+                actor: UserId::system(),
+                suspended_at: sample_time(),
+            },
+        );
+    }
+
+    #[test]
+    fn unsuspend_restores_rename() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![
+                created_event(),
+                suspended_event(),
+                unsuspended_event(),
+            ])
+            .when(rename_command())
+            .then(vec![WorkspaceEvent::Renamed {
+                workspace_id: sample_workspace_id(),
+                old_name: sample_name(),
+                new_name: WorkspaceName::new("Renamed").unwrap(),
+                normalized_name: "renamed".to_string(),
+                actor: UserId::system(),
+                renamed_at: sample_time(),
+            }]);
+    }
+
+    #[test]
+    fn unsuspend_active_workspace_is_idempotent() {
+        workspace_decider().assert_no_events(
+            vec![created_event()],
+            WorkspaceCommand::Unsuspend {
+                workspace_id: sample_workspace_id(),
+                actor: UserId::system(),
+                unsuspended_at: sample_time(),
+            },
+        );
+    }
+
+    #[test]
+    fn suspend_archived_workspace_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_decider())
+            .given(vec![created_event(), archived_event()])
+            .when(WorkspaceCommand::Suspend {
+                workspace_id: sample_workspace_id(),
+                reason: "pending review".to_string(),
+                actor: UserId::system(),
+                suspended_at: sample_time(),
+            })
+            .then_error(WorkspaceError::archived());
+    }
 }
@@ -44,6 +44,18 @@ pub enum WorkspaceErrorKind {
 
     /// Invalid workspace name.
     InvalidName(String),
+
+    /// Workspace is archived and can no longer be modified.
+    Archived,
+
+    /// Workspace is suspended by an administrator; carries the reason.
+    WorkspaceSuspended(String),
+
+    /// Only an administrator may suspend or unsuspend a workspace.
+    AdminRequired,
+
+    /// Workspace name collides with a reserved route segment.
+    ReservedName(String),
 }
 
 impl WorkspaceError {
@@ -96,10 +108,30 @@ impl WorkspaceError {
         Self::new(WorkspaceErrorKind::NotFound)
     }
 
+    /// Creates an `Archived` error.
+    pub fn archived() -> Self {
+        Self::new(WorkspaceErrorKind::Archived)
+    }
+
+    /// Creates a `WorkspaceSuspended` error.
+    pub fn suspended(reason: impl Into<String>) -> Self {
+        Self::new(WorkspaceErrorKind::WorkspaceSuspended(reason.into()))
+    }
+
+    /// Creates an `AdminRequired` error.
+    pub fn admin_required() -> Self {
+        Self::new(WorkspaceErrorKind::AdminRequired)
+    }
+
     /// Creates an `InvalidName` error with the given reason.
     pub fn invalid_name(reason: impl Into<String>) -> Self {
         Self::new(WorkspaceErrorKind::InvalidName(reason.into()))
     }
+
+    /// Creates a `ReservedName` error for the given name.
+    pub fn reserved_name(name: impl Into<String>) -> Self {
+        Self::new(WorkspaceErrorKind::ReservedName(name.into()))
+    }
 }
 
 impl fmt::Display for WorkspaceError {
@@ -110,6 +142,19 @@ impl fmt::Display for WorkspaceError {
             WorkspaceErrorKind::InvalidName(reason) => {
                 write!(f, "invalid workspace name: {reason}")
             }
+            WorkspaceErrorKind::Archived => write!(f, "workspace is archived"),
+            WorkspaceErrorKind::WorkspaceSuspended(reason) => {
+                write!(f, "workspace is suspended: {reason}")
+            }
+            WorkspaceErrorKind::AdminRequired => {
+                write!(
+                    f,
+                    "only an administrator may suspend or unsuspend a workspace"
+                )
+            }
+            WorkspaceErrorKind::ReservedName(name) => {
+                write!(f, "workspace name is reserved: {name}")
+            }
         }
     }
 }
@@ -142,6 +187,14 @@ mod tests {
             WorkspaceError::invalid_name("cannot be empty").to_string(),
             "invalid workspace name: cannot be empty"
         );
+        assert_eq!(
+            WorkspaceError::reserved_name("settings").to_string(),
+            "workspace name is reserved: settings"
+        );
+        assert_eq!(
+            WorkspaceError::suspended("pending review").to_string(),
+            "workspace is suspended: pending review"
+        );
     }
 
     #[test]
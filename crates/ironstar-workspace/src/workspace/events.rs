@@ -19,6 +19,10 @@
 //!
 //! Events include old values (`old_name`, `old_visibility`) for audit purposes,
 //! enabling reconstruction of historical state without replaying the entire stream.
+//! Every event records the `actor` who issued the command. Events stored before
+//! actors were recorded deserialize with `UserId::system()`, and `Created` and
+//! `Renamed` events stored before `normalized_name` was recorded derive it from
+//! the stored name.
 //!
 //! # Serialization
 //!
@@ -29,11 +33,11 @@
 //! - Easy pattern matching in frontend code
 
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ts_rs::TS;
 
 use super::values::{Visibility, WorkspaceId, WorkspaceName};
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
 use ironstar_shared_kernel::UserId;
 
 /// Events emitted by the Workspace aggregate.
@@ -47,7 +51,7 @@ use ironstar_shared_kernel::UserId;
 /// handled by upcasters that transform old event formats during loading.
 /// See `EventUpcaster` trait in the infrastructure layer.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
-#[serde(tag = "type")]
+#[serde(tag = "type", remote = "Self")]
 #[ts(export, export_to = "events/")]
 pub enum WorkspaceEvent {
     /// A new workspace was created.
@@ -56,10 +60,16 @@ pub enum WorkspaceEvent {
         workspace_id: WorkspaceId,
         /// The workspace's name (validated).
         name: WorkspaceName,
+        /// Trimmed, lowercased name for uniqueness checks in projections.
+        #[serde(default)]
+        normalized_name: String,
         /// Owner of the workspace.
         owner_id: UserId,
         /// Visibility setting.
         visibility: Visibility,
+        /// Who performed the action.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         /// When the workspace was created.
         created_at: DateTime<Utc>,
     },
@@ -72,6 +82,12 @@ pub enum WorkspaceEvent {
         old_name: WorkspaceName,
         /// The new name.
         new_name: WorkspaceName,
+        /// Trimmed, lowercased new name for uniqueness checks in projections.
+        #[serde(default)]
+        normalized_name: String,
+        /// Who performed the action.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         /// When the rename occurred.
         renamed_at: DateTime<Utc>,
     },
@@ -84,9 +100,47 @@ pub enum WorkspaceEvent {
         old_visibility: Visibility,
         /// The new visibility.
         new_visibility: Visibility,
+        /// Who performed the action.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
         /// When the change occurred.
         changed_at: DateTime<Utc>,
     },
+
+    /// An administrator suspended the workspace pending review.
+    Suspended {
+        /// Which workspace was suspended.
+        workspace_id: WorkspaceId,
+        /// Why the workspace was suspended.
+        reason: String,
+        /// Who performed the action.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        /// When the workspace was suspended.
+        suspended_at: DateTime<Utc>,
+    },
+
+    /// An administrator lifted a suspension.
+    Unsuspended {
+        /// Which workspace was unsuspended.
+        workspace_id: WorkspaceId,
+        /// Who performed the action.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        /// When the suspension was lifted.
+        unsuspended_at: DateTime<Utc>,
+    },
+
+    /// The workspace was archived (terminal).
+    Archived {
+        /// Which workspace was archived.
+        workspace_id: WorkspaceId,
+        /// Who performed the action.
+        #[serde(default = "UserId::system")]
+        actor: UserId,
+        /// When the workspace was archived.
+        archived_at: DateTime<Utc>,
+    },
 }
 
 impl WorkspaceEvent {
@@ -98,7 +152,10 @@ impl WorkspaceEvent {
         match self {
             Self::Created { workspace_id, .. }
             | Self::Renamed { workspace_id, .. }
-            | Self::VisibilityChanged { workspace_id, .. } => *workspace_id,
+            | Self::VisibilityChanged { workspace_id, .. }
+            | Self::Suspended { workspace_id, .. }
+            | Self::Unsuspended { workspace_id, .. }
+            | Self::Archived { workspace_id, .. } => *workspace_id,
         }
     }
 
@@ -114,6 +171,24 @@ impl WorkspaceEvent {
             Self::Created { .. } => "Created",
             Self::Renamed { .. } => "Renamed",
             Self::VisibilityChanged { .. } => "VisibilityChanged",
+            Self::Suspended { .. } => "Suspended",
+            Self::Unsuspended { .. } => "Unsuspended",
+            Self::Archived { .. } => "Archived",
+        }
+    }
+
+    /// Who performed the action.
+    ///
+    /// Events stored before actors were recorded report the system actor.
+    #[must_use]
+    pub fn actor(&self) -> UserId {
+        match self {
+            Self::Created { actor, .. }
+            | Self::Renamed { actor, .. }
+            | Self::VisibilityChanged { actor, .. }
+            | Self::Suspended { actor, .. }
+            | Self::Unsuspended { actor, .. }
+            | Self::Archived { actor, .. } => *actor,
         }
     }
 
@@ -127,9 +202,40 @@ impl WorkspaceEvent {
     }
 }
 
+impl Serialize for WorkspaceEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(self, serializer)
+    }
+}
+
+/// Fills in `normalized_name` from the stored name when an older event
+/// lacks it, so it never falls back to an empty string.
+impl<'de> Deserialize<'de> for WorkspaceEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = serde_json::Value::deserialize(deserializer)?;
+        if let Some(fields) = value.as_object_mut() {
+            let name_field = match fields.get("type").and_then(serde_json::Value::as_str) {
+                Some("Created") => Some("name"),
+                Some("Renamed") => Some("new_name"),
+                _ => None,
+            };
+            let normalized = name_field
+                .filter(|_| !fields.contains_key("normalized_name"))
+                .and_then(|field| fields.get(field))
+                .and_then(serde_json::Value::as_str)
+                .and_then(|name| WorkspaceName::new(name).ok())
+                .map(|name| name.normalized());
+            if let Some(normalized) = normalized {
+                fields.insert("normalized_name".to_string(), normalized.into());
+            }
+        }
+        Self::deserialize(value).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Identifier for WorkspaceEvent {
     fn identifier(&self) -> String {
-        self.aggregate_id().to_string()
+        self.aggregate_id().identifier()
     }
 }
 
@@ -139,6 +245,19 @@ impl EventType for WorkspaceEvent {
     }
 }
 
+impl Timestamped for WorkspaceEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::Created { created_at, .. } => *created_at,
+            Self::Renamed { renamed_at, .. } => *renamed_at,
+            Self::VisibilityChanged { changed_at, .. } => *changed_at,
+            Self::Suspended { suspended_at, .. } => *suspended_at,
+            Self::Unsuspended { unsuspended_at, .. } => *unsuspended_at,
+            Self::Archived { archived_at, .. } => *archived_at,
+        }
+    }
+}
+
 impl DeciderType for WorkspaceEvent {
     fn decider_type(&self) -> String {
         "Workspace".to_string()
@@ -147,8 +266,7 @@ impl DeciderType for WorkspaceEvent {
 
 impl IsFinal for WorkspaceEvent {
     fn is_final(&self) -> bool {
-        // Workspace has no terminal state currently (no Delete event)
-        false
+        matches!(self, Self::Archived { .. })
     }
 }
 
@@ -179,8 +297,10 @@ mod tests {
         let event = WorkspaceEvent::Created {
             workspace_id: sample_id(),
             name: sample_name(),
+            normalized_name: sample_name().normalized(),
             owner_id: sample_owner(),
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: sample_time(),
         };
 
@@ -199,6 +319,8 @@ mod tests {
             workspace_id: sample_id(),
             old_name: sample_name(),
             new_name: WorkspaceName::new("New Name").unwrap(),
+            normalized_name: WorkspaceName::new("New Name").unwrap().normalized(),
+            actor: UserId::system(),
             renamed_at: sample_time(),
         };
 
@@ -208,6 +330,50 @@ mod tests {
         assert_eq!(original, parsed);
     }
 
+    #[test]
+    fn event_stored_without_actor_is_attributed_to_system() {
+        let json = r#"{
+            "type": "Archived",
+            "workspace_id": "00000000-0000-0000-0000-000000000000",
+            "archived_at": "2024-01-15T10:30:00Z"
+        }"#;
+
+        let event: WorkspaceEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.actor(), UserId::system());
+    }
+
+    #[test]
+    fn event_stored_without_normalized_name_derives_it_from_name() {
+        let created = r#"{
+            "type": "Created",
+            "workspace_id": "00000000-0000-0000-0000-000000000000",
+            "name": "Sales ANALYTICS",
+            "owner_id": "00000000-0000-0000-0000-000000000000",
+            "visibility": "private",
+            "created_at": "2024-01-15T10:30:00Z"
+        }"#;
+        let renamed = r#"{
+            "type": "Renamed",
+            "workspace_id": "00000000-0000-0000-0000-000000000000",
+            "old_name": "Sales ANALYTICS",
+            "new_name": "Q3 Report",
+            "renamed_at": "2024-01-15T10:30:00Z"
+        }"#;
+
+        let created: WorkspaceEvent = serde_json::from_str(created).unwrap();
+        let renamed: WorkspaceEvent = serde_json::from_str(renamed).unwrap();
+
+        assert!(matches!(
+            created,
+            WorkspaceEvent::Created { ref normalized_name, .. } if normalized_name == "sales analytics"
+        ));
+        assert!(matches!(
+            renamed,
+            WorkspaceEvent::Renamed { ref normalized_name, .. } if normalized_name == "q3 report"
+        ));
+    }
+
     #[test]
     fn aggregate_id_extracts_correctly() {
         let id = WorkspaceId::new();
@@ -215,6 +381,7 @@ mod tests {
             workspace_id: id,
             old_visibility: Visibility::Private,
             new_visibility: Visibility::Public,
+            actor: UserId::system(),
             changed_at: sample_time(),
         };
 
@@ -228,8 +395,10 @@ mod tests {
                 WorkspaceEvent::Created {
                     workspace_id: sample_id(),
                     name: sample_name(),
+                    normalized_name: sample_name().normalized(),
                     owner_id: sample_owner(),
                     visibility: Visibility::Private,
+                    actor: UserId::system(),
                     created_at: sample_time(),
                 },
                 "Created",
@@ -239,6 +408,8 @@ mod tests {
                     workspace_id: sample_id(),
                     old_name: sample_name(),
                     new_name: WorkspaceName::new("New").unwrap(),
+                    normalized_name: WorkspaceName::new("New").unwrap().normalized(),
+                    actor: UserId::system(),
                     renamed_at: sample_time(),
                 },
                 "Renamed",
@@ -248,6 +419,7 @@ mod tests {
                     workspace_id: sample_id(),
                     old_visibility: Visibility::Private,
                     new_visibility: Visibility::Public,
+                    actor: UserId::system(),
                     changed_at: sample_time(),
                 },
                 "VisibilityChanged",
@@ -269,20 +441,25 @@ mod tests {
             WorkspaceEvent::Created {
                 workspace_id: sample_id(),
                 name: sample_name(),
+                normalized_name: sample_name().normalized(),
                 owner_id: sample_owner(),
                 visibility: Visibility::Private,
+                actor: UserId::system(),
                 created_at: sample_time(),
             },
             WorkspaceEvent::Renamed {
                 workspace_id: sample_id(),
                 old_name: sample_name(),
                 new_name: WorkspaceName::new("New").unwrap(),
+                normalized_name: WorkspaceName::new("New").unwrap().normalized(),
+                actor: UserId::system(),
                 renamed_at: sample_time(),
             },
             WorkspaceEvent::VisibilityChanged {
                 workspace_id: sample_id(),
                 old_visibility: Visibility::Private,
                 new_visibility: Visibility::Public,
+                actor: UserId::system(),
                 changed_at: sample_time(),
             },
         ];
@@ -0,0 +1,187 @@
+//! Golden tests for the serialized form of [`WorkspaceEvent`].
+//!
+//! Each variant is produced by the decider from a command, then the whole set
+//! is serialized and compared against `goldens/workspace_events.json`. A diff
+//! there means events already in the store may no longer deserialize: keep
+//! the old shape readable (or add an upcaster) before regenerating with
+//! `IRONSTAR_UPDATE_GOLDENS=1 cargo test`.
+
+use chrono::{DateTime, Utc};
+use ironstar_core::{DeciderTestSpecification, assert_golden};
+
+use super::{
+    UserId, Visibility, WorkspaceCommand, WorkspaceEvent, WorkspaceId, WorkspaceName,
+    workspace_decider,
+};
+
+const GOLDEN: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/goldens/workspace_events.json");
+
+fn workspace_id() -> WorkspaceId {
+    WorkspaceId::from_uuid(uuid::Uuid::nil())
+}
+
+fn owner() -> UserId {
+    UserId::from_uuid(uuid::Uuid::from_u128(1))
+}
+
+fn actor() -> UserId {
+    UserId::from_uuid(uuid::Uuid::from_u128(2))
+}
+
+fn at() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn name(raw: &str) -> WorkspaceName {
+    WorkspaceName::new(raw).unwrap()
+}
+
+/// Assert the decider emits exactly `event` for `command` after `given`.
+fn decided(
+    given: Vec<WorkspaceEvent>,
+    command: WorkspaceCommand,
+    event: WorkspaceEvent,
+) -> WorkspaceEvent {
+    DeciderTestSpecification::default()
+        .for_decider(workspace_decider())
+        .given(given)
+        .when(command)
+        .then(vec![event.clone()]);
+    event
+}
+
+/// One instance of every variant, in declaration order.
+fn one_of_each() -> Vec<WorkspaceEvent> {
+    let created = decided(
+        vec![],
+        WorkspaceCommand::Create {
+            workspace_id: workspace_id(),
+            name: "Research".to_string(),
+            owner_id: owner(),
+            visibility: Visibility::Private,
+            actor: owner(),
+            created_at: at(),
+        },
+        WorkspaceEvent::Created {
+            workspace_id: workspace_id(),
+            name: name("Research"),
+            normalized_name: "research".to_string(),
+            owner_id: owner(),
+            visibility: Visibility::Private,
+            actor: owner(),
+            created_at: at(),
+        },
+    );
+    let suspended = decided(
+        vec![created.clone()],
+        WorkspaceCommand::Suspend {
+            workspace_id: workspace_id(),
+            reason: "Under review".to_string(),
+            actor: actor(),
+            suspended_at: at(),
+        },
+        WorkspaceEvent::Suspended {
+            workspace_id: workspace_id(),
+            reason: "Under review".to_string(),
+            actor: actor(),
+            suspended_at: at(),
+        },
+    );
+
+    vec![
+        created.clone(),
+        decided(
+            vec![created.clone()],
+            WorkspaceCommand::Rename {
+                workspace_id: workspace_id(),
+                new_name: "Lab Notes".to_string(),
+                actor: actor(),
+                renamed_at: at(),
+            },
+            WorkspaceEvent::Renamed {
+                workspace_id: workspace_id(),
+                old_name: name("Research"),
+                new_name: name("Lab Notes"),
+                normalized_name: "lab notes".to_string(),
+                actor: actor(),
+                renamed_at: at(),
+            },
+        ),
+        decided(
+            vec![created.clone()],
+            WorkspaceCommand::SetVisibility {
+                workspace_id: workspace_id(),
+                visibility: Visibility::Public,
+                actor: actor(),
+                changed_at: at(),
+            },
+            WorkspaceEvent::VisibilityChanged {
+                workspace_id: workspace_id(),
+                old_visibility: Visibility::Private,
+                new_visibility: Visibility::Public,
+                actor: actor(),
+                changed_at: at(),
+            },
+        ),
+        suspended.clone(),
+        decided(
+            vec![created.clone(), suspended],
+            WorkspaceCommand::Unsuspend {
+                workspace_id: workspace_id(),
+                actor: actor(),
+                unsuspended_at: at(),
+            },
+            WorkspaceEvent::Unsuspended {
+                workspace_id: workspace_id(),
+                actor: actor(),
+                unsuspended_at: at(),
+            },
+        ),
+        decided(
+            vec![created],
+            WorkspaceCommand::Archive {
+                workspace_id: workspace_id(),
+                actor: actor(),
+                archived_at: at(),
+            },
+            WorkspaceEvent::Archived {
+                workspace_id: workspace_id(),
+                actor: actor(),
+                archived_at: at(),
+            },
+        ),
+    ]
+}
+
+#[test]
+fn every_variant_is_covered() {
+    let events = one_of_each();
+    // Adding a variant fails to compile here until it is given a golden.
+    let covered = events
+        .iter()
+        .map(|event| match event {
+            WorkspaceEvent::Created { .. }
+            | WorkspaceEvent::Renamed { .. }
+            | WorkspaceEvent::VisibilityChanged { .. }
+            | WorkspaceEvent::Suspended { .. }
+            | WorkspaceEvent::Unsuspended { .. }
+            | WorkspaceEvent::Archived { .. } => event.event_type_str(),
+        })
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(covered.len(), 6);
+}
+
+#[test]
+fn serialization_matches_golden() {
+    let events = one_of_each();
+    let json = serde_json::to_string_pretty(&events).unwrap() + "\n";
+    assert_golden(GOLDEN, &json);
+
+    // The checked-in golden must also read back as the events it was
+    // written from, as stored events will be.
+    let golden = std::fs::read_to_string(GOLDEN).unwrap();
+    let decoded: Vec<WorkspaceEvent> = serde_json::from_str(&golden).unwrap();
+    assert_eq!(decoded, events);
+}
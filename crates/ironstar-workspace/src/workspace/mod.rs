@@ -13,14 +13,12 @@
 //!                           │
 //!            ┌──────────────┼──────────────┐
 //!            │              │              │
-//!         Rename     SetVisibility    (future: Delete)
+//!         Rename     SetVisibility      Archive
 //!            │              │              │
-//!            └──────────────┴──────────────┘
-//!                           │
-//!                           ▼
-//!                    ┌──────────────┐
-//!                    │    Active    │ (same state, updated fields)
-//!                    └──────────────┘
+//!            ▼              ▼              ▼
+//!     ┌──────────────────────────┐  ┌──────────────┐
+//!     │ Active (updated fields)  │  │   Archived   │ (terminal)
+//!     └──────────────────────────┘  └──────────────┘
 //! ```
 //!
 //! # Shared Kernel Pattern
@@ -34,6 +32,7 @@
 //! Operations that would result in the same state return `Ok(vec![])`:
 //! - Rename with the same name
 //! - SetVisibility with the same visibility
+//! - Archive of an already archived workspace
 //!
 //! # Module Organization
 //!
@@ -51,6 +50,11 @@ pub mod events;
 pub mod state;
 pub mod values;
 
+#[cfg(test)]
+mod golden;
+#[cfg(test)]
+mod proptests;
+
 // Re-export UserId from Shared Kernel (Session context)
 // This demonstrates the shared kernel pattern: Workspace depends on Session's UserId
 pub use ironstar_shared_kernel::UserId;
@@ -61,7 +65,9 @@ pub use decider::{WorkspaceDecider, workspace_decider};
 pub use errors::{WorkspaceError, WorkspaceErrorKind};
 pub use events::WorkspaceEvent;
 pub use state::{WorkspaceState, WorkspaceStatus};
-pub use values::{Visibility, WORKSPACE_NAME_MAX_LENGTH, WorkspaceId, WorkspaceName};
+pub use values::{
+    Visibility, WORKSPACE_NAME_MAX_LENGTH, WorkspaceId, WorkspaceName, WorkspaceRole,
+};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,116 @@
+//! Property-based tests for the Workspace Decider.
+//!
+//! Generates random but well-typed command sequences against a created
+//! workspace and checks invariants that must hold for every sequence:
+//!
+//! - the aggregate version never decreases and advances by exactly the number
+//!   of emitted events
+//! - repeating a successful Rename or SetVisibility emits nothing
+//! - the state is `Active` after every successful command
+//! - Create is always rejected once the workspace exists
+
+use chrono::{DateTime, Duration, Utc};
+use ironstar_core::AggregateRoot;
+use proptest::prelude::*;
+
+use super::{
+    UserId, Visibility, WorkspaceCommand, WorkspaceErrorKind, WorkspaceId, WorkspaceStatus,
+    workspace_decider,
+};
+
+/// Command shape without identifiers or timestamps, which are fixed per run.
+#[derive(Debug, Clone)]
+enum Step {
+    Create(String, Visibility),
+    Rename(String),
+    SetVisibility(Visibility),
+}
+
+/// Small pool of valid names so that repeats and same-name renames occur often.
+fn name_strategy() -> impl Strategy<Value = String> {
+    prop::sample::select(vec!["Alpha", "Beta", "Gamma", "Data Team", "  Alpha  "])
+        .prop_map(String::from)
+}
+
+fn visibility_strategy() -> impl Strategy<Value = Visibility> {
+    prop_oneof![Just(Visibility::Private), Just(Visibility::Public)]
+}
+
+fn step_strategy() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        1 => (name_strategy(), visibility_strategy())
+            .prop_map(|(name, visibility)| Step::Create(name, visibility)),
+        4 => name_strategy().prop_map(Step::Rename),
+        4 => visibility_strategy().prop_map(Step::SetVisibility),
+    ]
+}
+
+fn base_time() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn to_command(step: &Step, workspace_id: WorkspaceId, at: DateTime<Utc>) -> WorkspaceCommand {
+    match step {
+        Step::Create(name, visibility) => WorkspaceCommand::Create {
+            workspace_id,
+            name: name.clone(),
+            owner_id: UserId::from_uuid(uuid::Uuid::nil()),
+            visibility: *visibility,
+            actor: UserId::system(),
+            created_at: at,
+        },
+        Step::Rename(name) => WorkspaceCommand::Rename {
+            workspace_id,
+            new_name: name.clone(),
+            actor: UserId::system(),
+            renamed_at: at,
+        },
+        Step::SetVisibility(visibility) => WorkspaceCommand::SetVisibility {
+            workspace_id,
+            visibility: *visibility,
+            actor: UserId::system(),
+            changed_at: at,
+        },
+    }
+}
+
+proptest! {
+    #[test]
+    fn command_sequences_preserve_invariants(
+        name in name_strategy(),
+        visibility in visibility_strategy(),
+        steps in prop::collection::vec(step_strategy(), 0..32),
+    ) {
+        let workspace_id = WorkspaceId::from_uuid(uuid::Uuid::nil());
+        let mut root = AggregateRoot::new(workspace_decider());
+
+        let create = to_command(&Step::Create(name, visibility), workspace_id, base_time());
+        let created = root.handle(&create).unwrap();
+        prop_assert_eq!(created.len(), 1);
+        prop_assert_eq!(&root.state().status, &WorkspaceStatus::Active);
+
+        for (offset, step) in (1_i64..).zip(&steps) {
+            let command = to_command(step, workspace_id, base_time() + Duration::seconds(offset));
+            let before = root.version();
+
+            match root.handle(&command) {
+                Ok(events) => {
+                    prop_assert!(!matches!(step, Step::Create(..)));
+                    prop_assert_eq!(root.version(), before + u64::try_from(events.len()).unwrap());
+                    prop_assert_eq!(&root.state().status, &WorkspaceStatus::Active);
+
+                    let repeated = root.handle(&command).unwrap();
+                    prop_assert!(repeated.is_empty(), "repeat of {:?} emitted {:?}", step, repeated);
+                }
+                Err(error) => {
+                    prop_assert!(matches!(step, Step::Create(..)));
+                    prop_assert_eq!(error.kind(), &WorkspaceErrorKind::AlreadyExists);
+                    prop_assert_eq!(root.version(), before);
+                }
+            }
+            prop_assert!(root.version() >= before);
+        }
+    }
+}
@@ -9,13 +9,23 @@ use super::values::{Visibility, WorkspaceId, WorkspaceName};
 use ironstar_shared_kernel::UserId;
 
 /// Lifecycle status of a workspace.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum WorkspaceStatus {
     /// Initial state (before any events).
     #[default]
     NotCreated,
     /// Workspace is active and can be modified.
     Active,
+    /// Workspace is held by an administrator pending review.
+    ///
+    /// Readable but not modifiable until unsuspended. Unlike archiving,
+    /// which the owner does, suspension is administrative and reversible.
+    Suspended {
+        /// Why the workspace was suspended.
+        reason: String,
+    },
+    /// Workspace was archived; terminal, no further modification.
+    Archived,
 }
 
 /// State of a single workspace, derived from events.
@@ -50,6 +60,12 @@ impl WorkspaceState {
     pub fn is_active(&self) -> bool {
         self.status == WorkspaceStatus::Active
     }
+
+    /// Check if the workspace is suspended.
+    #[must_use]
+    pub fn is_suspended(&self) -> bool {
+        matches!(self.status, WorkspaceStatus::Suspended { .. })
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +94,18 @@ mod tests {
         assert!(state.exists());
         assert!(state.is_active());
     }
+
+    #[test]
+    fn suspended_state_exists_but_is_not_active() {
+        let state = WorkspaceState {
+            status: WorkspaceStatus::Suspended {
+                reason: "pending review".to_string(),
+            },
+            ..WorkspaceState::default()
+        };
+
+        assert!(state.exists());
+        assert!(!state.is_active());
+        assert!(state.is_suspended());
+    }
 }
@@ -13,6 +13,9 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use uuid::Uuid;
 
+use ironstar_core::Identifier;
+use ironstar_shared_kernel::UserId;
+
 use super::errors::WorkspaceError;
 #[cfg(test)]
 use super::errors::WorkspaceErrorKind;
@@ -27,7 +30,8 @@ pub const WORKSPACE_NAME_MAX_LENGTH: usize = 255;
 ///
 /// # Construction
 ///
-/// - `WorkspaceId::new()` - Generate a new random ID
+/// - `WorkspaceId::new()` - Generate a new ID from the current
+///   [`IdSource`](ironstar_core::IdSource) (random unless a test installs one)
 /// - `WorkspaceId::from_uuid(uuid)` - Wrap an existing UUID (for deserialization)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "domain/", type = "string")]
@@ -38,7 +42,7 @@ impl WorkspaceId {
     /// Generate a new random WorkspaceId.
     #[must_use]
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(ironstar_core::id::next_uuid())
     }
 
     /// Wrap an existing UUID as a WorkspaceId.
@@ -69,6 +73,18 @@ impl std::fmt::Display for WorkspaceId {
     }
 }
 
+/// Stream id of the Workspace aggregate identified by this id: the bare
+/// `{uuid}`.
+///
+/// Workspace streams were written under the bare UUID before the other
+/// aggregates adopted prefixed ids, and stored events are immutable, so
+/// the format stays unprefixed to keep existing streams reachable.
+impl Identifier for WorkspaceId {
+    fn identifier(&self) -> String {
+        self.0.to_string()
+    }
+}
+
 /// Validated workspace name.
 ///
 /// Guarantees:
@@ -121,6 +137,35 @@ impl WorkspaceName {
         &self.0
     }
 
+    /// Normalized form used for uniqueness comparisons: trimmed and lowercased.
+    ///
+    /// Two names that differ only in case normalize equal, so `"Analytics"`
+    /// and `"analytics"` conflict while both remain valid display names.
+    #[must_use]
+    pub fn normalized(&self) -> String {
+        self.0.to_lowercase()
+    }
+
+    /// URL slug derived from the name: lowercased alphanumeric runs joined
+    /// by single hyphens, so `"Sales & Analytics"` becomes `"sales-analytics"`.
+    ///
+    /// A name with no alphanumeric characters slugs to `"workspace"`.
+    #[must_use]
+    pub fn slug(&self) -> String {
+        let slug = self
+            .0
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            "workspace".to_string()
+        } else {
+            slug
+        }
+    }
+
     /// Consume self and return the inner String.
     #[must_use]
     pub fn into_inner(self) -> String {
@@ -172,6 +217,40 @@ impl std::fmt::Display for Visibility {
     }
 }
 
+/// A user's role in a workspace, derived from ownership and visibility.
+///
+/// Roles are ordered by privilege, so `role >= WorkspaceRole::Viewer`
+/// checks for at least read access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceRole {
+    /// May read the workspace but not change it.
+    Viewer,
+    /// May read and change the workspace and its preferences.
+    Owner,
+}
+
+impl WorkspaceRole {
+    /// The role `user_id` holds in a workspace, or `None` without access.
+    ///
+    /// The owner holds [`Owner`](Self::Owner). Other users are viewers of
+    /// public workspaces and have no access to private ones. The system
+    /// actor, which requests without a signed-in user act as, holds no role
+    /// in any workspace.
+    #[must_use]
+    pub fn of(user_id: &UserId, owner_id: &UserId, visibility: Visibility) -> Option<Self> {
+        if user_id.is_system() {
+            None
+        } else if user_id == owner_id {
+            Some(Self::Owner)
+        } else if visibility == Visibility::Public {
+            Some(Self::Viewer)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,6 +265,20 @@ mod tests {
             assert_ne!(id1, id2);
         }
 
+        #[test]
+        fn seeded_source_produces_predictable_ids() {
+            use ironstar_core::SeededIdSource;
+            use ironstar_core::id::with_id_source;
+
+            let generate = || (WorkspaceId::new(), WorkspaceId::new());
+            let (first, second) = with_id_source(SeededIdSource::new(7), generate);
+            let replayed = with_id_source(SeededIdSource::new(7), generate);
+
+            assert_eq!((first, second), replayed);
+            assert_ne!(first, second);
+            assert_eq!(first.to_string(), "00000000-0000-4000-8000-000700000000");
+        }
+
         #[test]
         fn from_uuid_roundtrips() {
             let uuid = Uuid::new_v4();
@@ -199,11 +292,32 @@ mod tests {
             let json = serde_json::to_string(&id).unwrap();
             assert_eq!(json, "\"00000000-0000-0000-0000-000000000000\"");
         }
+
+        #[test]
+        fn identifier_is_bare_uuid_stream_id() {
+            let id = WorkspaceId::from_uuid(Uuid::nil());
+            assert_eq!(id.identifier(), "00000000-0000-0000-0000-000000000000");
+        }
     }
 
     mod workspace_name {
         use super::*;
 
+        #[test]
+        fn normalized_lowercases_trimmed_name() {
+            let name = WorkspaceName::new("  Sales ANALYTICS  ").unwrap();
+            assert_eq!(name.as_str(), "Sales ANALYTICS");
+            assert_eq!(name.normalized(), "sales analytics");
+        }
+
+        #[test]
+        fn slug_joins_alphanumeric_runs() {
+            let slug = |name: &str| WorkspaceName::new(name).unwrap().slug();
+            assert_eq!(slug("Sales & Analytics"), "sales-analytics");
+            assert_eq!(slug("  Q3 -- Report! "), "q3-report");
+            assert_eq!(slug("???"), "workspace");
+        }
+
         #[test]
         fn accepts_valid_name() {
             let name = WorkspaceName::new("My Workspace").unwrap();
@@ -300,4 +414,36 @@ mod tests {
             assert_eq!(Visibility::Public.to_string(), "public");
         }
     }
+
+    mod workspace_role {
+        use super::*;
+
+        #[test]
+        fn anonymous_holds_no_role() {
+            let anonymous = UserId::system();
+            let owner = UserId::new();
+            for visibility in [Visibility::Private, Visibility::Public] {
+                assert_eq!(WorkspaceRole::of(&anonymous, &owner, visibility), None);
+            }
+            // Not even in a workspace recorded as owned by the system actor.
+            assert_eq!(
+                WorkspaceRole::of(&anonymous, &anonymous, Visibility::Public),
+                None
+            );
+        }
+
+        #[test]
+        fn non_member_is_denied_private_and_views_public() {
+            let (user, owner) = (UserId::new(), UserId::new());
+            assert_eq!(WorkspaceRole::of(&user, &owner, Visibility::Private), None);
+            assert_eq!(
+                WorkspaceRole::of(&user, &owner, Visibility::Public),
+                Some(WorkspaceRole::Viewer)
+            );
+            assert_eq!(
+                WorkspaceRole::of(&owner, &owner, Visibility::Private),
+                Some(WorkspaceRole::Owner)
+            );
+        }
+    }
 }
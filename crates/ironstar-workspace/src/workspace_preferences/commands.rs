@@ -9,7 +9,7 @@
 //! Commands carry `CatalogUri` (not raw `String`) because catalog URI
 //! validation is structural (non-empty, max length) and can fail early.
 //! `LayoutDefaults` is accepted as-is since JSON validation is deferred
-//! to the boundary.
+//! to the boundary; the decider only range-checks its grid spacing keys.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -56,8 +56,9 @@ pub enum WorkspacePreferencesCommand {
 
     /// Update layout defaults for this workspace.
     ///
-    /// Requires preferences to be initialized. Idempotent when
-    /// setting the same layout defaults.
+    /// Requires preferences to be initialized. Fails if `row_height_px` or
+    /// `gap_px` is out of range. Idempotent when setting the same layout
+    /// defaults.
     UpdateLayoutDefaults {
         workspace_id: WorkspaceId,
         layout_defaults: LayoutDefaults,
@@ -181,4 +182,37 @@ mod tests {
             assert_eq!(cmd.workspace_id(), ws_id);
         }
     }
+
+    #[test]
+    fn every_variant_roundtrips_through_json() {
+        let ts = sample_time();
+        let commands = vec![
+            WorkspacePreferencesCommand::InitializeWorkspacePreferences {
+                workspace_id: WorkspaceId::new(),
+                initialized_at: ts,
+            },
+            WorkspacePreferencesCommand::SetDefaultCatalog {
+                workspace_id: WorkspaceId::new(),
+                catalog_uri: CatalogUri::new("ducklake:test").unwrap(),
+                set_at: ts,
+            },
+            WorkspacePreferencesCommand::ClearDefaultCatalog {
+                workspace_id: WorkspaceId::new(),
+                cleared_at: ts,
+            },
+            WorkspacePreferencesCommand::UpdateLayoutDefaults {
+                workspace_id: WorkspaceId::new(),
+                layout_defaults: LayoutDefaults::new(r#"{"columns":12}"#),
+                updated_at: ts,
+            },
+        ];
+
+        for original in commands {
+            let json = serde_json::to_value(&original).unwrap();
+            assert_eq!(json["type"], original.command_type());
+
+            let parsed: WorkspacePreferencesCommand = serde_json::from_value(json).unwrap();
+            assert_eq!(parsed, original);
+        }
+    }
 }
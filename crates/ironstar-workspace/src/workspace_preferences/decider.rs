@@ -30,6 +30,11 @@
 //! - SetDefaultCatalog with same URI returns `Ok(vec![])`
 //! - ClearDefaultCatalog when already cleared returns `Ok(vec![])`
 //! - UpdateLayoutDefaults with same JSON returns `Ok(vec![])`
+//!
+//! # Validation
+//!
+//! UpdateLayoutDefaults is rejected when `row_height_px` or `gap_px` is not
+//! an integer within its range (see [`LayoutDefaults::validate`]).
 
 use ironstar_core::Decider;
 use tracing::instrument;
@@ -161,6 +166,8 @@ fn decide(
                 ..
             },
         ) => {
+            layout_defaults.validate()?;
+
             if current_defaults == layout_defaults {
                 return Ok(vec![]);
             }
@@ -495,6 +502,55 @@ mod tests {
             .then_error(WorkspacePreferencesError::not_initialized());
     }
 
+    #[test]
+    fn update_layout_defaults_grid_spacing_round_trips() {
+        let ws_id = sample_workspace_id();
+        let ts = sample_time();
+        let ld = LayoutDefaults::new(r#"{"row_height_px": 120, "gap_px": 8}"#);
+        let initialized = evolve(&WorkspacePreferencesState::default(), &initialized_event());
+
+        let events = decide(
+            &WorkspacePreferencesCommand::UpdateLayoutDefaults {
+                workspace_id: ws_id,
+                layout_defaults: ld.clone(),
+                updated_at: ts,
+            },
+            &initialized,
+        )
+        .unwrap();
+        assert_eq!(
+            events,
+            vec![WorkspacePreferencesEvent::LayoutDefaultsUpdated {
+                workspace_id: ws_id,
+                layout_defaults: ld,
+                updated_at: ts,
+            }]
+        );
+
+        let state = evolve(&initialized, &events[0]);
+        let stored = state.layout_defaults().unwrap();
+        assert_eq!(stored.row_height_px(), 120);
+        assert_eq!(stored.gap_px(), 8);
+    }
+
+    #[test]
+    fn update_layout_defaults_out_of_range_fails() {
+        DeciderTestSpecification::default()
+            .for_decider(workspace_preferences_decider())
+            .given(vec![initialized_event()])
+            .when(WorkspacePreferencesCommand::UpdateLayoutDefaults {
+                workspace_id: sample_workspace_id(),
+                layout_defaults: LayoutDefaults::new(r#"{"row_height_px": 500}"#),
+                updated_at: sample_time(),
+            })
+            .then_error(WorkspacePreferencesError::layout_value_out_of_range(
+                "row_height_px",
+                20,
+                400,
+                500,
+            ));
+    }
+
     // --- Full lifecycle ---
 
     #[test]
@@ -29,6 +29,17 @@ pub enum WorkspacePreferencesErrorKind {
 
     /// Catalog URI exceeds maximum length.
     CatalogUriTooLong { max: usize, actual: usize },
+
+    /// Layout defaults key is not an integer.
+    InvalidLayoutValue { field: &'static str },
+
+    /// Layout defaults key is outside its allowed range.
+    LayoutValueOutOfRange {
+        field: &'static str,
+        min: u32,
+        max: u32,
+        actual: i64,
+    },
 }
 
 impl WorkspacePreferencesError {
@@ -81,6 +92,19 @@ impl WorkspacePreferencesError {
     pub fn catalog_uri_too_long(max: usize, actual: usize) -> Self {
         Self::new(WorkspacePreferencesErrorKind::CatalogUriTooLong { max, actual })
     }
+
+    pub fn invalid_layout_value(field: &'static str) -> Self {
+        Self::new(WorkspacePreferencesErrorKind::InvalidLayoutValue { field })
+    }
+
+    pub fn layout_value_out_of_range(field: &'static str, min: u32, max: u32, actual: i64) -> Self {
+        Self::new(WorkspacePreferencesErrorKind::LayoutValueOutOfRange {
+            field,
+            min,
+            max,
+            actual,
+        })
+    }
 }
 
 impl fmt::Display for WorkspacePreferencesError {
@@ -101,6 +125,20 @@ impl fmt::Display for WorkspacePreferencesError {
                     "catalog URI cannot exceed {max} characters (got {actual})"
                 )
             }
+            WorkspacePreferencesErrorKind::InvalidLayoutValue { field } => {
+                write!(f, "layout default {field} must be an integer")
+            }
+            WorkspacePreferencesErrorKind::LayoutValueOutOfRange {
+                field,
+                min,
+                max,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "layout default {field} must be between {min} and {max} (got {actual})"
+                )
+            }
         }
     }
 }
@@ -137,6 +175,15 @@ mod tests {
             WorkspacePreferencesError::catalog_uri_too_long(512, 600).to_string(),
             "catalog URI cannot exceed 512 characters (got 600)"
         );
+        assert_eq!(
+            WorkspacePreferencesError::invalid_layout_value("gap_px").to_string(),
+            "layout default gap_px must be an integer"
+        );
+        assert_eq!(
+            WorkspacePreferencesError::layout_value_out_of_range("row_height_px", 20, 400, 10)
+                .to_string(),
+            "layout default row_height_px must be between 20 and 400 (got 10)"
+        );
     }
 
     #[test]
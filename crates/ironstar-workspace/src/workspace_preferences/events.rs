@@ -9,7 +9,7 @@ use ts_rs::TS;
 
 use super::values::{CatalogUri, LayoutDefaults};
 use crate::workspace::WorkspaceId;
-use ironstar_core::{DeciderType, EventType, Identifier, IsFinal};
+use ironstar_core::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
 
 /// Events emitted by the WorkspacePreferences aggregate.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
@@ -85,6 +85,17 @@ impl EventType for WorkspacePreferencesEvent {
     }
 }
 
+impl Timestamped for WorkspacePreferencesEvent {
+    fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::WorkspacePreferencesInitialized { initialized_at, .. } => *initialized_at,
+            Self::DefaultCatalogSet { set_at, .. } => *set_at,
+            Self::DefaultCatalogCleared { cleared_at, .. } => *cleared_at,
+            Self::LayoutDefaultsUpdated { updated_at, .. } => *updated_at,
+        }
+    }
+}
+
 impl DeciderType for WorkspacePreferencesEvent {
     fn decider_type(&self) -> String {
         "WorkspacePreferences".to_string()
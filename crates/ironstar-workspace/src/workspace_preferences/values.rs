@@ -5,7 +5,10 @@
 //!
 //! Catalog existence validation is deferred to the boundary layer;
 //! the domain only validates structural constraints (non-empty, max length).
-//! JSON validation for LayoutDefaults is likewise deferred to the boundary.
+//! JSON validation for LayoutDefaults is likewise deferred to the boundary,
+//! but the grid spacing keys it understands are range-checked here.
+
+use std::ops::RangeInclusive;
 
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -17,6 +20,18 @@ use super::errors::WorkspacePreferencesErrorKind;
 /// Maximum length for a catalog URI in characters.
 pub const CATALOG_URI_MAX_LENGTH: usize = 512;
 
+/// Allowed dashboard grid row heights in pixels.
+pub const ROW_HEIGHT_PX_RANGE: RangeInclusive<u32> = 20..=400;
+
+/// Allowed dashboard grid gaps in pixels.
+pub const GAP_PX_RANGE: RangeInclusive<u32> = 0..=64;
+
+/// Row height used when the layout defaults do not set one.
+pub const DEFAULT_ROW_HEIGHT_PX: u32 = 80;
+
+/// Gap used when the layout defaults do not set one.
+pub const DEFAULT_GAP_PX: u32 = 16;
+
 /// URI referencing a DuckDB catalog.
 ///
 /// Structural guarantees:
@@ -90,6 +105,10 @@ impl From<CatalogUri> for String {
 /// is deferred to the boundary layer per Hoffman's Law 7 (work is a
 /// side effect).
 ///
+/// Two keys are understood by the domain: `row_height_px` and `gap_px`
+/// size the dashboard grid. [`LayoutDefaults::validate`] range-checks them
+/// when present; other keys pass through untouched.
+///
 /// Default value is `"{}"` (empty JSON object).
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "domain/", type = "string")]
@@ -111,6 +130,70 @@ impl LayoutDefaults {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Dashboard grid row height in pixels.
+    ///
+    /// Falls back to [`DEFAULT_ROW_HEIGHT_PX`] when unset or invalid.
+    #[must_use]
+    pub fn row_height_px(&self) -> u32 {
+        self.grid_value("row_height_px", &ROW_HEIGHT_PX_RANGE)
+            .and_then(Result::ok)
+            .unwrap_or(DEFAULT_ROW_HEIGHT_PX)
+    }
+
+    /// Dashboard grid gap in pixels.
+    ///
+    /// Falls back to [`DEFAULT_GAP_PX`] when unset or invalid.
+    #[must_use]
+    pub fn gap_px(&self) -> u32 {
+        self.grid_value("gap_px", &GAP_PX_RANGE)
+            .and_then(Result::ok)
+            .unwrap_or(DEFAULT_GAP_PX)
+    }
+
+    /// Check the grid spacing keys against their allowed ranges.
+    ///
+    /// Absent keys, and defaults that are not a JSON object, are accepted.
+    ///
+    /// # Errors
+    ///
+    /// - [`WorkspacePreferencesError::InvalidLayoutValue`] if a key is not an integer
+    /// - [`WorkspacePreferencesError::LayoutValueOutOfRange`] if a key is outside its range
+    pub fn validate(&self) -> Result<(), WorkspacePreferencesError> {
+        if let Some(result) = self.grid_value("row_height_px", &ROW_HEIGHT_PX_RANGE) {
+            result?;
+        }
+        if let Some(result) = self.grid_value("gap_px", &GAP_PX_RANGE) {
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Read an integer key, or `None` when the key is absent.
+    fn grid_value(
+        &self,
+        field: &'static str,
+        range: &RangeInclusive<u32>,
+    ) -> Option<Result<u32, WorkspacePreferencesError>> {
+        let json: serde_json::Value = serde_json::from_str(&self.0).ok()?;
+        let value = json.get(field)?;
+        let Some(actual) = value.as_i64() else {
+            return Some(Err(WorkspacePreferencesError::invalid_layout_value(field)));
+        };
+        Some(
+            u32::try_from(actual)
+                .ok()
+                .filter(|px| range.contains(px))
+                .ok_or_else(|| {
+                    WorkspacePreferencesError::layout_value_out_of_range(
+                        field,
+                        *range.start(),
+                        *range.end(),
+                        actual,
+                    )
+                }),
+        )
+    }
 }
 
 impl Default for LayoutDefaults {
@@ -217,6 +300,66 @@ mod tests {
             assert_eq!(ld.as_str(), r#"{"columns": 3}"#);
         }
 
+        #[test]
+        fn grid_spacing_defaults_when_unset() {
+            let ld = LayoutDefaults::new(r#"{"columns": 3}"#);
+            assert!(ld.validate().is_ok());
+            assert_eq!(ld.row_height_px(), DEFAULT_ROW_HEIGHT_PX);
+            assert_eq!(ld.gap_px(), DEFAULT_GAP_PX);
+        }
+
+        #[test]
+        fn accepts_grid_spacing_at_range_bounds() {
+            for (row_height, gap) in [(20, 0), (400, 64)] {
+                let ld = LayoutDefaults::new(format!(
+                    r#"{{"row_height_px": {row_height}, "gap_px": {gap}}}"#
+                ));
+                assert!(ld.validate().is_ok());
+                assert_eq!(ld.row_height_px(), row_height);
+                assert_eq!(ld.gap_px(), gap);
+            }
+        }
+
+        #[test]
+        fn rejects_row_height_out_of_range() {
+            for row_height in [19, 401, -5] {
+                let ld = LayoutDefaults::new(format!(r#"{{"row_height_px": {row_height}}}"#));
+                assert_eq!(
+                    ld.validate().unwrap_err().kind(),
+                    &WorkspacePreferencesErrorKind::LayoutValueOutOfRange {
+                        field: "row_height_px",
+                        min: 20,
+                        max: 400,
+                        actual: row_height,
+                    }
+                );
+                assert_eq!(ld.row_height_px(), DEFAULT_ROW_HEIGHT_PX);
+            }
+        }
+
+        #[test]
+        fn rejects_gap_out_of_range() {
+            let ld = LayoutDefaults::new(r#"{"gap_px": 65}"#);
+            assert!(matches!(
+                ld.validate().unwrap_err().kind(),
+                WorkspacePreferencesErrorKind::LayoutValueOutOfRange {
+                    field: "gap_px",
+                    ..
+                }
+            ));
+            assert_eq!(ld.gap_px(), DEFAULT_GAP_PX);
+        }
+
+        #[test]
+        fn rejects_non_integer_grid_spacing() {
+            for json in [r#"{"gap_px": 1.5}"#, r#"{"row_height_px": "tall"}"#] {
+                assert!(matches!(
+                    LayoutDefaults::new(json).validate().unwrap_err().kind(),
+                    WorkspacePreferencesErrorKind::InvalidLayoutValue { .. }
+                ));
+            }
+        }
+
         #[test]
         fn serde_roundtrip() {
             let original = LayoutDefaults::new(r#"{"theme": "dark"}"#);
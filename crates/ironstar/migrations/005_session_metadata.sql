@@ -0,0 +1,5 @@
+-- Client metadata captured at session creation.
+-- Lets a user's session listing show where each session logged in from.
+
+ALTER TABLE sessions ADD COLUMN ip_address TEXT;
+ALTER TABLE sessions ADD COLUMN user_agent TEXT;
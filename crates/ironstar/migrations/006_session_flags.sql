@@ -0,0 +1,7 @@
+-- Review flags on sessions, e.g. a login from a new location.
+-- A flag surfaces the session for its owner to confirm; it does not revoke it.
+
+ALTER TABLE sessions ADD COLUMN flag_reason TEXT;
+ALTER TABLE sessions ADD COLUMN flagged_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_sessions_flagged ON sessions(user_id) WHERE flagged_at IS NOT NULL;
@@ -0,0 +1,22 @@
+-- Persisted stream version: an event's 1-based position in its own
+-- aggregate stream. Readers select it directly instead of counting the
+-- events before each row, and the unique index stops two writers from
+-- claiming the same position in a stream.
+
+ALTER TABLE events ADD COLUMN stream_version INTEGER NOT NULL DEFAULT 0;
+
+-- Backfill existing rows; the immutability trigger is lifted for the update.
+DROP TRIGGER IF EXISTS prevent_event_update;
+UPDATE events SET stream_version = (
+    SELECT COUNT(*) FROM events AS prior
+    WHERE prior.aggregate_type = events.aggregate_type
+      AND prior.aggregate_id = events.aggregate_id
+      AND prior.id <= events.id
+);
+CREATE TRIGGER IF NOT EXISTS prevent_event_update
+BEFORE UPDATE ON events
+BEGIN
+    SELECT RAISE(ABORT, 'Events are immutable: UPDATE not allowed');
+END;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_events_stream_version ON events(aggregate_type, aggregate_id, stream_version);
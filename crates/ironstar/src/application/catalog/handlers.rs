@@ -9,6 +9,7 @@
 //! from the Decider's perspective.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::timestamps::LatestTimestamp;
 use crate::domain::catalog::{CatalogCommand, CatalogError, CatalogEvent, catalog_decider};
 use crate::infrastructure::event_bus::{EventBus, ZenohEventBus, publish_events_fire_and_forget};
 use crate::infrastructure::event_store::SqliteEventRepository;
@@ -22,12 +23,16 @@ use std::sync::Arc;
 /// from the underlying repository into `CommandPipelineError::Infrastructure`.
 pub struct CatalogEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<CatalogCommand, CatalogEvent>>,
+    latest: LatestTimestamp,
 }
 
 impl CatalogEventRepositoryAdapter {
     /// Create a new adapter wrapping the given repository.
     pub fn new(inner: Arc<SqliteEventRepository<CatalogCommand, CatalogEvent>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+        }
     }
 }
 
@@ -38,13 +43,16 @@ impl EventRepository<CatalogCommand, CatalogEvent, String, CommandPipelineError>
         &self,
         command: &CatalogCommand,
     ) -> Result<Vec<(CatalogEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[CatalogEvent],
     ) -> Result<Vec<(CatalogEvent, String)>, CommandPipelineError> {
+        self.latest.check(events)?;
         self.inner.save(events).await.map_err(Into::into)
     }
 
@@ -124,6 +132,7 @@ mod tests {
     use super::*;
     use crate::domain::catalog::{CatalogErrorKind, CatalogMetadata, CatalogRef, DatasetInfo};
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
 
@@ -134,7 +143,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -154,6 +163,7 @@ mod tests {
                 name: "test_dataset".to_string(),
                 table_count: 5,
                 schema_version: "1.0".to_string(),
+                row_count: None,
             }],
             last_refreshed: Utc::now(),
         }
@@ -9,6 +9,8 @@
 
 mod handlers;
 pub mod queries;
+mod row_counts;
 
 pub use handlers::{handle_catalog_command, handle_catalog_command_zenoh};
 pub use queries::{query_catalog_metadata, query_catalog_state};
+pub use row_counts::{MAX_COUNTED_DATASETS, populate_row_counts};
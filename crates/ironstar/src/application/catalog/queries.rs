@@ -52,6 +52,7 @@ mod tests {
     use crate::domain::CatalogCommand;
     use crate::domain::{CatalogRef, DatasetInfo};
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
     use std::sync::Arc;
@@ -63,7 +64,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -149,6 +150,7 @@ mod tests {
                     name: "genomics".to_string(),
                     table_count: 5,
                     schema_version: "1.0".to_string(),
+                    row_count: None,
                 }],
                 last_refreshed: Utc::now(),
             },
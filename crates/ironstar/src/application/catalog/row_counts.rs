@@ -0,0 +1,129 @@
+//! Optional row counts for catalog metadata refreshes.
+//!
+//! Row counts come from a `SELECT COUNT(*)` per dataset, which scans each
+//! one. A refresh only counts rows when the caller opts in, and at most
+//! [`MAX_COUNTED_DATASETS`] datasets are counted per refresh. Counting is
+//! best effort: a dataset whose count fails keeps `row_count: None` and the
+//! refresh proceeds with the rest of the metadata.
+
+use crate::domain::CatalogMetadata;
+use crate::infrastructure::analytics::DuckDBService;
+use tracing::warn;
+
+/// Upper bound on datasets counted in a single refresh.
+pub const MAX_COUNTED_DATASETS: usize = 64;
+
+/// Fill in `row_count` for the datasets in `metadata`.
+///
+/// Datasets beyond [`MAX_COUNTED_DATASETS`] are left uncounted.
+pub async fn populate_row_counts(service: &DuckDBService, metadata: &mut CatalogMetadata) {
+    for dataset in metadata.datasets.iter_mut().take(MAX_COUNTED_DATASETS) {
+        match service.count_rows(&dataset.name).await {
+            Ok(count) => dataset.row_count = Some(count),
+            Err(e) => {
+                warn!(dataset = %dataset.name, error = %e, "failed to count dataset rows");
+                dataset.row_count = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::application::catalog::{handle_catalog_command, query_catalog_metadata};
+    use crate::domain::{CatalogCommand, CatalogRef, DatasetInfo};
+    use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
+    use crate::infrastructure::event_store::SqliteEventRepository;
+    use chrono::Utc;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use std::sync::Arc;
+
+    async fn create_test_pool() -> sqlx::SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("Failed to create test pool");
+
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("Failed to run migration");
+
+        pool
+    }
+
+    async fn duckdb_with_table(rows: u32) -> DuckDBService {
+        let pool = async_duckdb::PoolBuilder::new()
+            .num_conns(1)
+            .open()
+            .await
+            .expect("failed to create DuckDB pool");
+        let service = DuckDBService::new(Some(pool));
+        service
+            .query_mut(move |conn| {
+                conn.execute_batch(&format!(
+                    "CREATE TABLE astronauts AS SELECT * FROM range({rows}) t(id)"
+                ))
+            })
+            .await
+            .expect("create table");
+        service
+    }
+
+    fn dataset(name: &str) -> DatasetInfo {
+        DatasetInfo {
+            name: name.to_string(),
+            table_count: 1,
+            schema_version: "1.0".to_string(),
+            row_count: None,
+        }
+    }
+
+    const NO_EVENT_BUS: Option<&ZenohEventBus> = None;
+
+    #[tokio::test]
+    async fn counted_rows_flow_into_catalog_metadata() {
+        let repo = Arc::new(SqliteEventRepository::new(create_test_pool().await));
+        let service = duckdb_with_table(3).await;
+        let now = Utc::now();
+
+        handle_catalog_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            CatalogCommand::SelectCatalog {
+                catalog_ref: CatalogRef::new("ducklake:test").expect("valid ref"),
+                selected_at: now,
+            },
+        )
+        .await
+        .expect("select should succeed");
+
+        let mut metadata = CatalogMetadata {
+            datasets: vec![dataset("astronauts"), dataset("missing")],
+            last_refreshed: now,
+        };
+        populate_row_counts(&service, &mut metadata).await;
+
+        handle_catalog_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            CatalogCommand::RefreshCatalogMetadata {
+                metadata,
+                refreshed_at: now,
+            },
+        )
+        .await
+        .expect("refresh should succeed");
+
+        let stored = query_catalog_metadata(&repo)
+            .await
+            .expect("query should succeed")
+            .expect("metadata should be present");
+        let counts: Vec<_> = stored.datasets.iter().map(|d| d.row_count).collect();
+        assert_eq!(counts, [Some(3), None]);
+    }
+}
@@ -0,0 +1,107 @@
+//! Pinning a saved query's chart to a dashboard in one step.
+//!
+//! [`add_query_result_to_dashboard`] places a chart of a saved query's
+//! result on a dashboard in the same workspace. The placement references
+//! the query by id as its chart definition, with the picked chart type as
+//! the rendering hint, so the chart follows later edits to the query
+//! instead of freezing a copy of it.
+//!
+//! Without an explicit position the chart goes on the default view below
+//! every chart already there (see
+//! [`DashboardState::next_free_position`](crate::domain::dashboard::DashboardState::next_free_position)).
+
+use chrono::Utc;
+
+use super::handlers::handle_dashboard_command;
+use crate::application::error::CommandPipelineError;
+use crate::application::workspace::query_saved_query_list;
+use crate::domain::UserId;
+use crate::domain::analytics::ChartType;
+use crate::domain::common::GridSize;
+use crate::domain::dashboard::{
+    ChartDefinitionRef, ChartId, ChartPlacement, ChartSource, DashboardCommand, DashboardEvent,
+    DashboardId, GridPosition, dashboard_decider,
+};
+use crate::domain::saved_query::{
+    SavedQueryCommand, SavedQueryError, SavedQueryEvent, SavedQueryId,
+};
+use crate::infrastructure::event_bus::EventBus;
+use crate::infrastructure::event_store::SqliteEventRepository;
+use fmodel_rust::Identifier;
+use std::sync::Arc;
+
+/// Add a `chart_type` chart of `query_id`'s result to `dashboard_id` on
+/// behalf of `actor`.
+///
+/// Places the chart at `position`, or at the dashboard's next free position
+/// when none is given. Returns the id of the new chart.
+///
+/// # Errors
+///
+/// Returns `NotFound` when the dashboard or query does not exist, `Archived`
+/// when the dashboard is archived, `Forbidden` when the query belongs to
+/// another workspace, and any error from loading or saving events.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "both aggregates' repositories plus the placement and its author"
+)]
+pub async fn add_query_result_to_dashboard<B: EventBus>(
+    dashboard_repository: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
+    saved_query_repository: &SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>,
+    event_bus: Option<&B>,
+    dashboard_id: DashboardId,
+    query_id: SavedQueryId,
+    chart_type: ChartType,
+    position: Option<GridPosition>,
+    actor: UserId,
+) -> Result<ChartId, CommandPipelineError> {
+    let queries = query_saved_query_list(saved_query_repository).await?;
+    let Some(query) = queries.queries.iter().find(|q| q.query_id == query_id) else {
+        return Err(CommandPipelineError::SavedQuery(
+            SavedQueryError::not_found(),
+        ));
+    };
+
+    let events = dashboard_repository
+        .fetch_events_by_aggregate("Dashboard", &dashboard_id.identifier())
+        .await?;
+    let decider = dashboard_decider();
+    let dashboard = events
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _version)| {
+            (decider.evolve)(&state, event)
+        });
+
+    if let Some(workspace_id) = dashboard.workspace_id()
+        && *workspace_id != query.workspace_id
+    {
+        return Err(CommandPipelineError::forbidden(format!(
+            "add saved query {query_id} to dashboard {dashboard_id}"
+        )));
+    }
+    // A missing or archived dashboard has no free position; the decider
+    // rejects the command below.
+    let position = position
+        .or_else(|| dashboard.next_free_position())
+        .unwrap_or(GridPosition { row: 0, col: 0 });
+
+    let chart_id = ChartId::new();
+    let command = DashboardCommand::AddChart {
+        dashboard_id,
+        placement: ChartPlacement {
+            chart_id,
+            source: ChartSource::Reference(ChartDefinitionRef {
+                ref_id: query_id.to_string(),
+                chart_type_hint: Some(chart_type),
+            }),
+            position,
+            size: GridSize::DEFAULT_CHART,
+            tab_id: None,
+        },
+        actor,
+        added_at: Utc::now(),
+    };
+    handle_dashboard_command(dashboard_repository, event_bus, command).await?;
+
+    Ok(chart_id)
+}
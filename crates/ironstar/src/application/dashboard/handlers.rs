@@ -3,24 +3,37 @@
 //! This module provides the `handle_dashboard_command` function that creates an
 //! EventSourcedAggregate from the Dashboard Decider and SQLite event repository,
 //! unifying domain and infrastructure errors via `CommandPipelineError`.
+//!
+//! Dashboards in a suspended workspace must not change. The decider cannot
+//! see the workspace, so every command is checked with
+//! [`ensure_not_suspended`] before it runs.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::instrumentation::record_command_outcome;
+use crate::application::timestamps::LatestTimestamp;
+use crate::application::workspace::ensure_not_suspended;
 use crate::domain::dashboard::{
     DashboardCommand, DashboardError, DashboardEvent, dashboard_decider,
 };
 use crate::infrastructure::event_bus::{EventBus, ZenohEventBus, publish_events_fire_and_forget};
 use crate::infrastructure::event_store::SqliteEventRepository;
+use fmodel_rust::Identifier;
 use fmodel_rust::aggregate::{EventRepository, EventSourcedAggregate};
 use std::sync::Arc;
+use tracing::instrument;
 
 /// Adapter wrapping SqliteEventRepository to map errors to CommandPipelineError.
 pub struct DashboardEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
+    latest: LatestTimestamp,
 }
 
 impl DashboardEventRepositoryAdapter {
     pub fn new(inner: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+        }
     }
 }
 
@@ -31,13 +44,16 @@ impl EventRepository<DashboardCommand, DashboardEvent, String, CommandPipelineEr
         &self,
         command: &DashboardCommand,
     ) -> Result<Vec<(DashboardEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[DashboardEvent],
     ) -> Result<Vec<(DashboardEvent, String)>, CommandPipelineError> {
+        self.latest.check(events)?;
         self.inner.save(events).await.map_err(Into::into)
     }
 
@@ -49,12 +65,50 @@ impl EventRepository<DashboardCommand, DashboardEvent, String, CommandPipelineEr
     }
 }
 
+/// Reject a command that would change a dashboard in a suspended workspace.
+///
+/// A new dashboard is checked against the workspace it names and every other
+/// command against the workspace the dashboard belongs to.
+async fn check_not_suspended(
+    event_repository: &SqliteEventRepository<DashboardCommand, DashboardEvent>,
+    command: &DashboardCommand,
+) -> Result<(), CommandPipelineError> {
+    let pool = event_repository.pool();
+    if let DashboardCommand::CreateDashboard { workspace_id, .. } = command {
+        return ensure_not_suspended(pool, workspace_id).await;
+    }
+
+    let decider = dashboard_decider();
+    let dashboard = event_repository
+        .fetch_events(command)
+        .await?
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _version)| {
+            (decider.evolve)(&state, event)
+        });
+    match dashboard.workspace_id() {
+        Some(workspace_id) => ensure_not_suspended(pool, workspace_id).await,
+        None => Ok(()),
+    }
+}
+
 /// Handle a Dashboard command through the EventSourcedAggregate pipeline.
+#[instrument(
+    name = "command.dashboard.handle",
+    skip_all,
+    fields(
+        aggregate_id = %command.identifier(),
+        command_kind = command.command_type(),
+        event_count = tracing::field::Empty,
+        outcome = tracing::field::Empty,
+    )
+)]
 pub async fn handle_dashboard_command<B: EventBus>(
     event_repository: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
     event_bus: Option<&B>,
     command: DashboardCommand,
 ) -> Result<Vec<(DashboardEvent, String)>, CommandPipelineError> {
+    check_not_suspended(&event_repository, &command).await?;
     let repo_adapter = DashboardEventRepositoryAdapter::new(event_repository);
 
     let mapped_decider = dashboard_decider().map_error(|e: &DashboardError| {
@@ -63,7 +117,9 @@ pub async fn handle_dashboard_command<B: EventBus>(
 
     let aggregate = EventSourcedAggregate::new(repo_adapter, mapped_decider);
 
-    let saved_events = aggregate.handle(&command).await?;
+    let result = aggregate.handle(&command).await;
+    record_command_outcome(&result);
+    let saved_events = result?;
 
     if let Some(bus) = event_bus {
         publish_events_fire_and_forget(bus, &saved_events).await;
@@ -73,11 +129,22 @@ pub async fn handle_dashboard_command<B: EventBus>(
 }
 
 /// Handle a Dashboard command with Zenoh event bus support.
+#[instrument(
+    name = "command.dashboard.handle",
+    skip_all,
+    fields(
+        aggregate_id = %command.identifier(),
+        command_kind = command.command_type(),
+        event_count = tracing::field::Empty,
+        outcome = tracing::field::Empty,
+    )
+)]
 pub async fn handle_dashboard_command_zenoh(
     event_repository: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
     event_bus: Option<&ZenohEventBus>,
     command: DashboardCommand,
 ) -> Result<Vec<(DashboardEvent, String)>, CommandPipelineError> {
+    check_not_suspended(&event_repository, &command).await?;
     let repo_adapter = DashboardEventRepositoryAdapter::new(event_repository);
 
     let mapped_decider = dashboard_decider().map_error(|e: &DashboardError| {
@@ -86,7 +153,9 @@ pub async fn handle_dashboard_command_zenoh(
 
     let aggregate = EventSourcedAggregate::new(repo_adapter, mapped_decider);
 
-    let saved_events = aggregate.handle(&command).await?;
+    let result = aggregate.handle(&command).await;
+    record_command_outcome(&result);
+    let saved_events = result?;
 
     if let Some(bus) = event_bus {
         publish_events_fire_and_forget(bus, &saved_events).await;
@@ -99,10 +168,12 @@ pub async fn handle_dashboard_command_zenoh(
 #[allow(clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use crate::domain::UserId;
     use crate::domain::common::DashboardTitle;
     use crate::domain::dashboard::{DashboardErrorKind, DashboardId};
     use crate::domain::workspace::WorkspaceId;
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
     use uuid::Uuid;
@@ -114,7 +185,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -133,6 +204,7 @@ mod tests {
             dashboard_id: DashboardId::from_uuid(Uuid::new_v4()),
             workspace_id: WorkspaceId::from_uuid(Uuid::new_v4()),
             name: DashboardTitle::new("Test Dashboard").expect("valid title"),
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
 
@@ -157,6 +229,7 @@ mod tests {
             dashboard_id: dash_id,
             workspace_id: ws_id,
             name: DashboardTitle::new("Dashboard").expect("valid title"),
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
 
@@ -168,6 +241,7 @@ mod tests {
             dashboard_id: dash_id,
             workspace_id: ws_id,
             name: DashboardTitle::new("Duplicate").expect("valid title"),
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
 
@@ -188,6 +262,7 @@ mod tests {
         let command = DashboardCommand::RenameDashboard {
             dashboard_id: DashboardId::from_uuid(Uuid::new_v4()),
             name: DashboardTitle::new("New Name").expect("valid title"),
+            actor: UserId::system(),
             renamed_at: Utc::now(),
         };
 
@@ -1,8 +1,11 @@
 //! Dashboard aggregate application layer.
 //!
 //! This module wires the Dashboard Decider to the SQLite event repository,
-//! providing command handling for dashboard lifecycle within workspaces.
+//! providing command handling for dashboard lifecycle within workspaces,
+//! including pinning a saved query's chart in one step.
 
+mod from_query;
 mod handlers;
 
+pub use from_query::add_query_result_to_dashboard;
 pub use handlers::{handle_dashboard_command, handle_dashboard_command_zenoh};
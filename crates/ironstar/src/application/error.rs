@@ -119,6 +119,7 @@ impl AggregateError {
 // CommandPipelineError: Unified error type for EventSourcedAggregate pipeline
 // =============================================================================
 
+use crate::domain::analytics::AnalyticsValidationError;
 use crate::domain::catalog::CatalogError;
 use crate::domain::dashboard::DashboardError;
 use crate::domain::query_session::QuerySessionError;
@@ -128,6 +129,7 @@ use crate::domain::user_preferences::UserPreferencesError;
 use crate::domain::workspace::WorkspaceError;
 use crate::domain::workspace_preferences::WorkspacePreferencesError;
 use crate::infrastructure::error::InfrastructureError;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 /// Error type for EventSourcedAggregate command pipeline.
@@ -173,12 +175,31 @@ pub enum CommandPipelineError {
     SavedQuery(SavedQueryError),
     /// UserPreferences aggregate domain error.
     UserPreferences(UserPreferencesError),
+    /// SQL that fails validation, such as an imported query that writes.
+    InvalidSql(AnalyticsValidationError),
     // Session(SessionError),      // future: ironstar-507
+    /// New event is timestamped before the aggregate's latest event.
+    NonMonotonicTimestamp {
+        error_id: Uuid,
+        latest: DateTime<Utc>,
+        attempted: DateTime<Utc>,
+    },
+    /// Actor lacks the workspace role the command requires.
+    Forbidden { error_id: Uuid, action: String },
     /// Infrastructure failure (from EventRepository adapter).
     Infrastructure(InfrastructureError),
 }
 
 impl CommandPipelineError {
+    /// Create a `Forbidden` error for an action the actor may not perform.
+    #[must_use]
+    pub fn forbidden(action: impl Into<String>) -> Self {
+        Self::Forbidden {
+            error_id: Uuid::new_v4(),
+            action: action.into(),
+        }
+    }
+
     /// Get the unique error ID for tracing correlation.
     ///
     /// Returns the error_id from the underlying error type, preserving
@@ -194,6 +215,10 @@ impl CommandPipelineError {
             Self::Dashboard(e) => e.error_id(),
             Self::SavedQuery(e) => e.error_id(),
             Self::UserPreferences(e) => e.error_id(),
+            Self::InvalidSql(e) => e.error_id(),
+            Self::NonMonotonicTimestamp { error_id, .. } | Self::Forbidden { error_id, .. } => {
+                *error_id
+            }
             Self::Infrastructure(e) => e.error_id(),
         }
     }
@@ -210,6 +235,14 @@ impl fmt::Display for CommandPipelineError {
             Self::Dashboard(e) => write!(f, "Dashboard: {e}"),
             Self::SavedQuery(e) => write!(f, "SavedQuery: {e}"),
             Self::UserPreferences(e) => write!(f, "UserPreferences: {e}"),
+            Self::InvalidSql(e) => write!(f, "SQL: {e}"),
+            Self::NonMonotonicTimestamp {
+                latest, attempted, ..
+            } => write!(
+                f,
+                "event timestamp {attempted} precedes latest event at {latest}"
+            ),
+            Self::Forbidden { action, .. } => write!(f, "not permitted to {action}"),
             Self::Infrastructure(e) => write!(f, "{e}"),
         }
     }
@@ -226,6 +259,8 @@ impl std::error::Error for CommandPipelineError {
             Self::Dashboard(e) => Some(e),
             Self::SavedQuery(e) => Some(e),
             Self::UserPreferences(e) => Some(e),
+            Self::InvalidSql(e) => Some(e),
+            Self::NonMonotonicTimestamp { .. } | Self::Forbidden { .. } => None,
             Self::Infrastructure(e) => Some(e),
         }
     }
@@ -249,6 +284,12 @@ impl From<ironstar_event_bus::EventBusError> for CommandPipelineError {
     }
 }
 
+impl From<AnalyticsValidationError> for CommandPipelineError {
+    fn from(e: AnalyticsValidationError) -> Self {
+        Self::InvalidSql(e)
+    }
+}
+
 impl From<TodoError> for CommandPipelineError {
     fn from(e: TodoError) -> Self {
         Self::Todo(e)
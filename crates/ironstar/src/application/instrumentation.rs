@@ -0,0 +1,30 @@
+//! Tracing helpers shared by command handlers.
+//!
+//! Command handlers open a span carrying the aggregate id and command kind,
+//! with `event_count` and `outcome` declared empty and filled in once the
+//! aggregate has decided. The span nests under the HTTP request span, so a
+//! request's trace links the command to the events it persisted.
+
+use tracing::Span;
+
+use super::error::CommandPipelineError;
+
+/// Record `event_count` and `outcome` on the current command span.
+///
+/// Outcomes are `ok`, `rejected` for domain errors, and `error` for
+/// infrastructure failures.
+pub(crate) fn record_command_outcome<E>(result: &Result<Vec<E>, CommandPipelineError>) {
+    let span = Span::current();
+    match result {
+        Ok(events) => {
+            span.record("event_count", events.len());
+            span.record("outcome", "ok");
+        }
+        Err(CommandPipelineError::Infrastructure(_)) => {
+            span.record("outcome", "error");
+        }
+        Err(_) => {
+            span.record("outcome", "rejected");
+        }
+    }
+}
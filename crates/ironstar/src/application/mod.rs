@@ -101,33 +101,41 @@
 pub mod catalog;
 pub mod dashboard;
 pub mod error;
+mod instrumentation;
 pub mod query_session;
 pub mod saved_query;
+mod timestamps;
 pub mod todo;
 pub mod user_preferences;
 pub mod workspace;
 pub mod workspace_preferences;
 
 pub use catalog::{
-    handle_catalog_command, handle_catalog_command_zenoh, query_catalog_metadata,
-    query_catalog_state,
+    handle_catalog_command, handle_catalog_command_zenoh, populate_row_counts,
+    query_catalog_metadata, query_catalog_state,
+};
+pub use dashboard::{
+    add_query_result_to_dashboard, handle_dashboard_command, handle_dashboard_command_zenoh,
 };
-pub use dashboard::{handle_dashboard_command, handle_dashboard_command_zenoh};
 pub use error::{AggregateError, CommandPipelineError};
 pub use query_session::{
     QueryExecutionParams, handle_query_session_command, handle_query_session_command_with_spawn,
-    handle_query_session_command_zenoh, query_query_history, query_session_state,
-    spawn_query_execution,
+    handle_query_session_command_zenoh, handle_start_query_superseding, query_query_history,
+    query_session_state, spawn_query_execution,
+};
+pub use saved_query::{
+    BulkDeleteSummary, clone_saved_query, delete_saved_queries, execute_saved_query,
+    handle_saved_query_command, handle_saved_query_command_zenoh,
 };
-pub use saved_query::{handle_saved_query_command, handle_saved_query_command_zenoh};
 pub use todo::{handle_todo_command, query_all_todos, query_todo_state};
 pub use user_preferences::{
     handle_user_preferences_command, handle_user_preferences_command_zenoh,
 };
 pub use workspace::{
+    DEFAULT_RESERVED_WORKSPACE_NAMES, ReservedWorkspaceNames, WorkspaceAdministrators,
     handle_workspace_command, handle_workspace_command_zenoh, query_dashboard_layout,
     query_saved_query_list, query_user_preferences, query_workspace_list,
-    query_workspaces_for_user,
+    query_workspace_preferences, query_workspaces_for_user,
 };
 pub use workspace_preferences::{
     handle_workspace_preferences_command, handle_workspace_preferences_command_zenoh,
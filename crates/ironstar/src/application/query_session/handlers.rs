@@ -16,12 +16,25 @@
 //! with the spawn-after-persist pattern: when a `QueryStarted` event is persisted,
 //! it spawns a background DuckDB execution task that issues subsequent commands
 //! (BeginExecution, CompleteQuery/FailQuery) back through the Decider.
+//!
+//! # Superseding
+//!
+//! `handle_start_query_superseding` lets a re-run replace a query that is
+//! still in flight. The decider rejects `StartQuery` while a query is pending
+//! or executing, so the handler first cancels that query as `Superseded` and
+//! resets the session, each as an ordinary command through the Decider. Only
+//! the actor who started the in-flight query can supersede it; a `StartQuery`
+//! from anyone else is rejected rather than cancelling their query. Anonymous
+//! callers all act as the system user, so they never supersede.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::timestamps::LatestTimestamp;
+use crate::domain::analytics::CancellationReason;
 use crate::domain::query_session::{
-    QuerySessionCommand, QuerySessionError, QuerySessionEvent, query_session_decider,
+    QuerySessionCommand, QuerySessionError, QuerySessionEvent, QuerySessionStatus,
+    query_session_decider,
 };
-use crate::infrastructure::analytics::DuckDBService;
+use crate::infrastructure::analytics::AnalyticsState;
 use crate::infrastructure::event_bus::{EventBus, ZenohEventBus, publish_events_fire_and_forget};
 use crate::infrastructure::event_store::SqliteEventRepository;
 use fmodel_rust::aggregate::{EventRepository, EventSourcedAggregate};
@@ -36,12 +49,16 @@ use super::spawn::{QueryExecutionParams, spawn_query_execution};
 /// from the underlying repository into `CommandPipelineError::Infrastructure`.
 pub struct QuerySessionEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>,
+    latest: LatestTimestamp,
 }
 
 impl QuerySessionEventRepositoryAdapter {
     /// Create a new adapter wrapping the given repository.
     pub fn new(inner: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+        }
     }
 }
 
@@ -52,13 +69,16 @@ impl EventRepository<QuerySessionCommand, QuerySessionEvent, String, CommandPipe
         &self,
         command: &QuerySessionCommand,
     ) -> Result<Vec<(QuerySessionEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[QuerySessionEvent],
     ) -> Result<Vec<(QuerySessionEvent, String)>, CommandPipelineError> {
+        self.latest.check(events)?;
         self.inner.save(events).await.map_err(Into::into)
     }
 
@@ -159,16 +179,94 @@ pub async fn handle_query_session_command_zenoh(
     Ok(saved_events)
 }
 
+/// Start a query, superseding the same actor's query still in flight.
+///
+/// When `command` is a `StartQuery` and the session has a pending or
+/// executing query started by the same actor, that query is cancelled with
+/// [`CancellationReason::Superseded`] and the session is reset before the new
+/// query starts. The three commands are handled in order, each persisting
+/// its own events, and every saved event is returned. Any other command, or
+/// a `StartQuery` against a session with nothing of the actor's in flight, is
+/// handled as by `handle_query_session_command_zenoh`, so another actor's
+/// query is never cancelled and the Decider rejects the new one instead.
+/// The system actor never matches, because every anonymous caller shares it.
+///
+/// The superseded query's background task keeps running; its completion
+/// command is rejected by the Decider because the session has moved on.
+pub async fn handle_start_query_superseding(
+    event_repository: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>,
+    event_bus: Option<&ZenohEventBus>,
+    command: QuerySessionCommand,
+) -> Result<Vec<(QuerySessionEvent, String)>, CommandPipelineError> {
+    let QuerySessionCommand::StartQuery {
+        started_at, actor, ..
+    } = &command
+    else {
+        return handle_query_session_command_zenoh(event_repository, event_bus, command).await;
+    };
+    let (started_at, actor) = (*started_at, *actor);
+
+    let decider = query_session_decider();
+    let state = event_repository
+        .fetch_events(&command)
+        .await?
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _)| {
+            (decider.evolve)(&state, event)
+        });
+
+    let mut saved_events = Vec::new();
+    if let QuerySessionStatus::Pending {
+        query_id,
+        started_by,
+        ..
+    }
+    | QuerySessionStatus::Executing {
+        query_id,
+        started_by,
+        ..
+    } = state.status
+        && started_by == actor
+        && !actor.is_system()
+    {
+        tracing::info!(query_id = %query_id, "superseding in-flight query");
+        for preceding in [
+            QuerySessionCommand::CancelQuery {
+                query_id,
+                reason: CancellationReason::Superseded,
+                cancelled_at: started_at,
+            },
+            QuerySessionCommand::ResetSession {
+                reset_at: started_at,
+            },
+        ] {
+            saved_events.extend(
+                handle_query_session_command_zenoh(
+                    Arc::clone(&event_repository),
+                    event_bus,
+                    preceding,
+                )
+                .await?,
+            );
+        }
+    }
+
+    saved_events
+        .extend(handle_query_session_command_zenoh(event_repository, event_bus, command).await?);
+    Ok(saved_events)
+}
+
 /// Handle a QuerySession command with spawn-after-persist for DuckDB execution.
 ///
-/// Composes `handle_query_session_command_zenoh` with the spawn-after-persist
+/// Composes `handle_start_query_superseding` with the spawn-after-persist
 /// pattern. After the base handler persists and publishes events, this function
 /// checks for `QueryStarted` events and spawns a background DuckDB execution
 /// task for each.
 ///
 /// Use this function in HTTP handlers where DuckDB execution should follow
 /// query initiation. The spawned task issues subsequent commands (BeginExecution,
-/// CompleteQuery/FailQuery) back through the Decider autonomously.
+/// CompleteQuery/FailQuery) back through the Decider autonomously. A
+/// `StartQuery` supersedes the same actor's query still in flight first.
 ///
 /// The base `handle_query_session_command_zenoh` remains available for internal
 /// use by the spawn module itself, where subsequent commands should not trigger
@@ -176,12 +274,12 @@ pub async fn handle_query_session_command_zenoh(
 pub async fn handle_query_session_command_with_spawn(
     event_repository: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>,
     event_bus: Option<Arc<ZenohEventBus>>,
-    duckdb_service: DuckDBService,
+    analytics: AnalyticsState,
     command: QuerySessionCommand,
 ) -> Result<Vec<(QuerySessionEvent, String)>, CommandPipelineError> {
     let bus_ref = event_bus.as_deref();
     let saved_events =
-        handle_query_session_command_zenoh(Arc::clone(&event_repository), bus_ref, command).await?;
+        handle_start_query_superseding(Arc::clone(&event_repository), bus_ref, command).await?;
 
     // Spawn-after-persist: if QueryStarted was persisted, kick off DuckDB execution
     for (event, _version) in &saved_events {
@@ -189,7 +287,7 @@ pub async fn handle_query_session_command_with_spawn(
             spawn_query_execution(
                 Arc::clone(&event_repository),
                 event_bus.clone(),
-                duckdb_service.clone(),
+                analytics.clone(),
                 params,
             );
         }
@@ -202,9 +300,13 @@ pub async fn handle_query_session_command_with_spawn(
 #[allow(clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use crate::application::query_session::{query_query_history, query_session_state};
+    use crate::domain::UserId;
     use crate::domain::analytics::{QueryId, SqlQuery};
     use crate::domain::query_session::{QuerySessionErrorKind, QuerySessionEvent};
+    use crate::domain::views::QueryOutcome;
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
 
@@ -215,7 +317,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -245,6 +347,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: Utc::now(),
+            actor: UserId::system(),
         };
 
         let result = handle_query_session_command(repo, NO_EVENT_BUS, command).await;
@@ -271,6 +374,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: now,
+            actor: UserId::system(),
         };
         let _ = handle_query_session_command(Arc::clone(&repo), NO_EVENT_BUS, command1)
             .await
@@ -283,6 +387,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: now,
+            actor: UserId::system(),
         };
         let result = handle_query_session_command(repo, NO_EVENT_BUS, command2).await;
         assert!(result.is_err());
@@ -309,6 +414,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: now,
+            actor: UserId::system(),
         };
         let _ = handle_query_session_command(Arc::clone(&repo), NO_EVENT_BUS, start)
             .await
@@ -328,6 +434,7 @@ mod tests {
         let complete = QuerySessionCommand::CompleteQuery {
             query_id,
             row_count: 42,
+            result_ref: "query_session:result:1".to_string(),
             duration_ms: 150,
             completed_at: now,
         };
@@ -343,4 +450,180 @@ mod tests {
             .expect("reset should succeed");
         assert_eq!(events.len(), 1);
     }
+
+    #[tokio::test]
+    async fn rerun_mid_execution_supersedes_first_query() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let now = Utc::now();
+        let actor = UserId::new();
+        let start = |query_id| QuerySessionCommand::StartQuery {
+            query_id,
+            sql: sample_sql(),
+            dataset_ref: None,
+            chart_config: None,
+            started_at: now,
+            actor,
+        };
+
+        let first = sample_query_id();
+        for command in [
+            start(first),
+            QuerySessionCommand::BeginExecution {
+                query_id: first,
+                began_at: now,
+            },
+        ] {
+            handle_query_session_command(Arc::clone(&repo), NO_EVENT_BUS, command)
+                .await
+                .expect("first query should start executing");
+        }
+
+        let second = sample_query_id();
+        let events: Vec<QuerySessionEvent> =
+            handle_start_query_superseding(Arc::clone(&repo), NO_EVENT_BUS, start(second))
+                .await
+                .expect("re-run should supersede")
+                .into_iter()
+                .map(|(event, _)| event)
+                .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                QuerySessionEvent::QueryCancelled {
+                    query_id: first,
+                    reason: CancellationReason::Superseded,
+                    cancelled_at: now,
+                },
+                QuerySessionEvent::SessionReset { reset_at: now },
+                QuerySessionEvent::QueryStarted {
+                    query_id: second,
+                    sql: sample_sql(),
+                    dataset_ref: None,
+                    chart_config: None,
+                    started_at: now,
+                    actor,
+                },
+            ]
+        );
+
+        let history = query_query_history(&repo).await.expect("history");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].query_id, first);
+        assert!(matches!(
+            history[0].outcome,
+            QueryOutcome::Cancelled {
+                reason: CancellationReason::Superseded,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn start_by_another_user_does_not_supersede() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let now = Utc::now();
+        let (alice, bob) = (UserId::new(), UserId::new());
+        let start = |query_id, actor| QuerySessionCommand::StartQuery {
+            query_id,
+            sql: sample_sql(),
+            dataset_ref: None,
+            chart_config: None,
+            started_at: now,
+            actor,
+        };
+
+        let first = sample_query_id();
+        handle_start_query_superseding(Arc::clone(&repo), NO_EVENT_BUS, start(first, alice))
+            .await
+            .expect("alice's query should start");
+
+        match handle_start_query_superseding(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            start(sample_query_id(), bob),
+        )
+        .await
+        {
+            Err(CommandPipelineError::QuerySession(ref e))
+                if *e.kind() == QuerySessionErrorKind::QueryAlreadyInProgress => {}
+            other => panic!("Expected QueryAlreadyInProgress, got: {other:?}"),
+        }
+
+        let state = query_session_state(&repo).await.expect("state");
+        assert_eq!(state.status.query_id(), Some(first));
+        assert_eq!(state.status.started_by(), Some(alice));
+        assert!(
+            query_query_history(&repo)
+                .await
+                .expect("history")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn anonymous_start_does_not_supersede_another_anonymous_query() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let start = |query_id| QuerySessionCommand::StartQuery {
+            query_id,
+            sql: sample_sql(),
+            dataset_ref: None,
+            chart_config: None,
+            started_at: Utc::now(),
+            actor: UserId::system(),
+        };
+
+        let first = sample_query_id();
+        handle_start_query_superseding(Arc::clone(&repo), NO_EVENT_BUS, start(first))
+            .await
+            .expect("first anonymous query should start");
+
+        match handle_start_query_superseding(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            start(sample_query_id()),
+        )
+        .await
+        {
+            Err(CommandPipelineError::QuerySession(ref e))
+                if *e.kind() == QuerySessionErrorKind::QueryAlreadyInProgress => {}
+            other => panic!("Expected QueryAlreadyInProgress, got: {other:?}"),
+        }
+
+        let state = query_session_state(&repo).await.expect("state");
+        assert_eq!(state.status.query_id(), Some(first));
+        assert!(
+            query_query_history(&repo)
+                .await
+                .expect("history")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn start_without_query_in_flight_does_not_cancel() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+
+        let command = QuerySessionCommand::StartQuery {
+            query_id: sample_query_id(),
+            sql: sample_sql(),
+            dataset_ref: None,
+            chart_config: None,
+            started_at: Utc::now(),
+            actor: UserId::system(),
+        };
+        let events = handle_start_query_superseding(repo, NO_EVENT_BUS, command)
+            .await
+            .expect("start should succeed");
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0].0,
+            QuerySessionEvent::QueryStarted { .. }
+        ));
+    }
 }
@@ -22,7 +22,7 @@ mod spawn;
 
 pub use handlers::{
     handle_query_session_command, handle_query_session_command_with_spawn,
-    handle_query_session_command_zenoh,
+    handle_query_session_command_zenoh, handle_start_query_superseding,
 };
 pub use queries::{query_query_history, query_session_state};
-pub use spawn::{QueryExecutionParams, spawn_query_execution};
+pub use spawn::{QUERY_RESULT_CACHE_PREFIX, QueryExecutionParams, spawn_query_execution};
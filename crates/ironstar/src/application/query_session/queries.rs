@@ -51,8 +51,11 @@ mod tests {
     use super::*;
     use crate::application::query_session::handle_query_session_command;
     use crate::domain::QuerySessionCommand;
+    use crate::domain::UserId;
+    use crate::domain::views::QueryOutcome;
     use crate::domain::{QueryId, SqlQuery};
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
     use std::sync::Arc;
@@ -64,7 +67,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -100,6 +103,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: Utc::now(),
+            actor: UserId::system(),
         };
         handle_query_session_command(Arc::clone(&repo), NO_EVENT_BUS, command)
             .await
@@ -124,6 +128,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: Utc::now(),
+            actor: UserId::system(),
         };
         handle_query_session_command(Arc::clone(&repo), NO_EVENT_BUS, command)
             .await
@@ -150,6 +155,7 @@ mod tests {
             dataset_ref: None,
             chart_config: None,
             started_at: Utc::now(),
+            actor: UserId::system(),
         };
         handle_query_session_command(Arc::clone(&repo), NO_EVENT_BUS, start)
             .await
@@ -168,6 +174,7 @@ mod tests {
         let complete = QuerySessionCommand::CompleteQuery {
             query_id: qid,
             row_count: 42,
+            result_ref: "query_session:result:1".to_string(),
             duration_ms: 150,
             completed_at: Utc::now(),
         };
@@ -181,5 +188,9 @@ mod tests {
 
         assert_eq!(history.len(), 1);
         assert_eq!(history[0].query_id, qid);
+        assert!(matches!(
+            &history[0].outcome,
+            QueryOutcome::Completed { result_ref, .. } if result_ref == "query_session:result:1"
+        ));
     }
 }
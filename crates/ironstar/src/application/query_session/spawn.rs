@@ -5,7 +5,12 @@
 //!
 //! 1. Issues `BeginExecution` command through the Decider (persists `ExecutionBegan`)
 //! 2. Executes the DuckDB query via `DuckDBService`
-//! 3. Issues `CompleteQuery` or `FailQuery` command through the Decider
+//! 3. Caches the result rows as JSON under the query's `result_ref`
+//! 4. Issues `CompleteQuery` or `FailQuery` command through the Decider
+//!
+//! Completed queries carry a `result_ref`, the analytics cache key derived
+//! from the SQL. The result is cached before `QueryCompleted` is persisted,
+//! so a client reacting to that event can page or chart it straight away.
 //!
 //! All state transitions flow through the Decider, preserving the aggregate
 //! invariant. The spawned task is just an async command issuer.
@@ -19,7 +24,8 @@
 
 use crate::domain::analytics::{QueryId, SqlQuery};
 use crate::domain::query_session::{QuerySessionCommand, QuerySessionEvent};
-use crate::infrastructure::analytics::DuckDBService;
+use crate::infrastructure::analytics::{AnalyticsState, fetch_rows};
+use crate::infrastructure::cache_key;
 use crate::infrastructure::event_bus::ZenohEventBus;
 use crate::infrastructure::event_store::SqliteEventRepository;
 use chrono::Utc;
@@ -28,6 +34,9 @@ use tokio::task::JoinHandle;
 
 use super::handlers::handle_query_session_command_zenoh;
 
+/// Cache key prefix for query session results.
+pub const QUERY_RESULT_CACHE_PREFIX: &str = "query_session:result";
+
 /// Parameters extracted from a persisted `QueryStarted` event for spawning
 /// async DuckDB execution.
 #[derive(Debug, Clone)]
@@ -64,24 +73,29 @@ impl QueryExecutionParams {
 /// The spawned task:
 /// 1. Issues `BeginExecution` → Decider persists `ExecutionBegan`
 /// 2. Runs the SQL query against DuckDB
-/// 3. On success: issues `CompleteQuery` → Decider persists `QueryCompleted`
+/// 3. On success: caches the rows under `result_ref`, then issues
+///    `CompleteQuery` → Decider persists `QueryCompleted`
 /// 4. On failure: issues `FailQuery` → Decider persists `QueryFailed`
 ///
+/// Without an analytics cache the query still completes, but its result
+/// cannot be paged or charted.
+///
 /// # Arguments
 ///
 /// * `event_repository` - Shared SQLite event repository for command handling
 /// * `event_bus` - Optional Zenoh event bus for post-persist notification
-/// * `duckdb_service` - DuckDB service for query execution
+/// * `analytics` - DuckDB service for query execution and the result cache
 /// * `params` - Query parameters extracted from the `QueryStarted` event
 pub fn spawn_query_execution(
     event_repository: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>,
     event_bus: Option<Arc<ZenohEventBus>>,
-    duckdb_service: DuckDBService,
+    analytics: AnalyticsState,
     params: QueryExecutionParams,
 ) -> JoinHandle<()> {
     tokio::spawn(async move {
         let query_id = params.query_id;
         let sql_str = params.sql.as_str().to_string();
+        let result_ref = cache_key(QUERY_RESULT_CACHE_PREFIX, &sql_str);
 
         // Step 1: Issue BeginExecution command
         let begin_cmd = QuerySessionCommand::BeginExecution {
@@ -106,25 +120,31 @@ pub fn spawn_query_execution(
 
         // Step 2: Execute the DuckDB query
         let start_time = std::time::Instant::now();
-        let query_result = duckdb_service
-            .query(move |conn| {
-                let mut stmt = conn.prepare(&sql_str)?;
-                let mut rows = stmt.query([])?;
-                let mut row_count: usize = 0;
-                while rows.next()?.is_some() {
-                    row_count += 1;
-                }
-                Ok(row_count)
-            })
+        let query_result = analytics
+            .service
+            .query(move |conn| fetch_rows(conn, &sql_str))
             .await;
         let duration_ms = u64::try_from(start_time.elapsed().as_millis()).unwrap_or(0);
 
         // Step 3: Issue completion or failure command
         match query_result {
-            Ok(row_count) => {
+            Ok(rows) => {
+                let row_count = rows.row_count();
+                if let Some(cached) = &analytics.cached {
+                    match serde_json::to_vec(&rows) {
+                        Ok(bytes) => cached.cache().insert(result_ref.clone(), bytes).await,
+                        Err(e) => tracing::warn!(
+                            query_id = %query_id,
+                            error = %e,
+                            "Failed to serialize query result for caching"
+                        ),
+                    }
+                }
+
                 let complete_cmd = QuerySessionCommand::CompleteQuery {
                     query_id,
                     row_count,
+                    result_ref,
                     duration_ms,
                     completed_at: Utc::now(),
                 };
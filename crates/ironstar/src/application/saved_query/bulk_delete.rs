@@ -0,0 +1,192 @@
+//! Bulk deletion of saved queries.
+//!
+//! Each saved query is its own aggregate, so there is no transaction spanning
+//! a batch. [`delete_saved_queries`] issues one `DeleteQuery` per id through
+//! the regular command pipeline: every deletion commits (or fails) on its own,
+//! and a failure partway through leaves earlier deletions in place.
+//!
+//! Deleting a query that does not exist is treated as an idempotent no-op at
+//! this level. The decider still rejects it with `NotFound`; the batch records
+//! the id as already deleted instead of failing.
+
+use super::handlers::handle_saved_query_command;
+use crate::application::error::CommandPipelineError;
+use crate::domain::UserId;
+use crate::domain::clock::Clock;
+use crate::domain::saved_query::{
+    SavedQueryCommand, SavedQueryErrorKind, SavedQueryEvent, SavedQueryId,
+};
+use crate::infrastructure::event_bus::EventBus;
+use crate::infrastructure::event_store::SqliteEventRepository;
+use std::sync::Arc;
+
+/// Outcome of a bulk saved query deletion, in request order.
+#[derive(Debug, Default)]
+pub struct BulkDeleteSummary {
+    /// Queries deleted by this batch.
+    pub deleted: Vec<SavedQueryId>,
+    /// Queries that were already deleted or never existed.
+    pub already_deleted: Vec<SavedQueryId>,
+    /// Queries whose deletion failed for any other reason.
+    pub failed: Vec<(SavedQueryId, CommandPipelineError)>,
+}
+
+impl BulkDeleteSummary {
+    /// Whether every requested query is now absent.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Delete each saved query in `query_ids` on behalf of `actor`.
+///
+/// Each deletion is timestamped by `clock` as it is issued. Duplicate ids
+/// are handled in order, so the second occurrence is reported as already
+/// deleted.
+pub async fn delete_saved_queries<B: EventBus>(
+    event_repository: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+    event_bus: Option<&B>,
+    query_ids: Vec<SavedQueryId>,
+    actor: UserId,
+    clock: &dyn Clock,
+) -> BulkDeleteSummary {
+    let mut summary = BulkDeleteSummary::default();
+
+    for query_id in query_ids {
+        let command = SavedQueryCommand::DeleteQuery {
+            query_id,
+            actor,
+            deleted_at: clock.now(),
+        };
+
+        match handle_saved_query_command(Arc::clone(&event_repository), event_bus, command).await {
+            Ok(_) => summary.deleted.push(query_id),
+            Err(CommandPipelineError::SavedQuery(e))
+                if *e.kind() == SavedQueryErrorKind::NotFound =>
+            {
+                summary.already_deleted.push(query_id);
+            }
+            Err(e) => summary.failed.push((query_id, e)),
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::domain::analytics::{DatasetRef, SqlQuery};
+    use crate::domain::clock::{FixedClock, SystemClock};
+    use crate::domain::saved_query::QueryName;
+    use crate::domain::workspace::WorkspaceId;
+    use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
+    use chrono::Utc;
+    use fmodel_rust::Identifier;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn create_test_pool() -> sqlx::SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("Failed to create test pool");
+
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("Failed to run migration");
+
+        pool
+    }
+
+    const NO_EVENT_BUS: Option<&ZenohEventBus> = None;
+
+    async fn save_query(
+        repo: &Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+    ) -> SavedQueryId {
+        let query_id = SavedQueryId::new();
+        let command = SavedQueryCommand::SaveQuery {
+            query_id,
+            workspace_id: WorkspaceId::from_uuid(Uuid::new_v4()),
+            name: QueryName::try_from("Query".to_string()).expect("valid name"),
+            sql: SqlQuery::try_from("SELECT 1".to_string()).expect("valid sql"),
+            dataset_ref: DatasetRef::try_from("hf://test/dataset".to_string()).expect("valid ref"),
+            actor: UserId::system(),
+            saved_at: Utc::now(),
+        };
+        handle_saved_query_command(Arc::clone(repo), NO_EVENT_BUS, command)
+            .await
+            .expect("save should succeed");
+        query_id
+    }
+
+    #[tokio::test]
+    async fn mixed_batch_reports_deleted_and_already_deleted() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let actor = UserId::new();
+
+        let live = save_query(&repo).await;
+        let removed = save_query(&repo).await;
+        let never_saved = SavedQueryId::new();
+
+        let first = delete_saved_queries(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            vec![removed],
+            actor,
+            &SystemClock,
+        )
+        .await;
+        assert_eq!(first.deleted, [removed]);
+
+        let summary = delete_saved_queries(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            vec![live, removed, never_saved],
+            actor,
+            &SystemClock,
+        )
+        .await;
+
+        assert_eq!(summary.deleted, [live]);
+        assert_eq!(summary.already_deleted, [removed, never_saved]);
+        assert!(summary.is_complete());
+
+        let again = delete_saved_queries(repo, NO_EVENT_BUS, vec![live], actor, &SystemClock).await;
+        assert_eq!(again.already_deleted, [live]);
+    }
+
+    #[tokio::test]
+    async fn deletions_are_timestamped_by_the_clock() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let query_id = save_query(&repo).await;
+        let at = Utc::now() + chrono::Duration::days(1);
+
+        delete_saved_queries(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            vec![query_id],
+            UserId::new(),
+            &FixedClock::new(at),
+        )
+        .await;
+
+        let events = repo
+            .fetch_events_by_aggregate("SavedQuery", &query_id.identifier())
+            .await
+            .expect("fetch events");
+        match events.last() {
+            Some((SavedQueryEvent::QueryDeleted { deleted_at, .. }, _)) => {
+                assert_eq!(*deleted_at, at);
+            }
+            other => panic!("expected QueryDeleted, got {other:?}"),
+        }
+    }
+}
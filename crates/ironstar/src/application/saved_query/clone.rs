@@ -0,0 +1,78 @@
+//! Cloning a saved query under a new name.
+//!
+//! [`clone_saved_query`] replays the source query's stream to read its
+//! current SQL and dataset reference, then saves them as a new query in the
+//! same workspace. The copy is its own aggregate: later edits to either query
+//! leave the other untouched, and nothing links the copy back to its source.
+//!
+//! The copy goes through the regular `SaveQuery` pipeline, so the new name
+//! must still be unique within the workspace.
+
+use super::handlers::handle_saved_query_command;
+use crate::application::error::CommandPipelineError;
+use crate::domain::UserId;
+use crate::domain::clock::Clock;
+use crate::domain::saved_query::{
+    QueryName, SavedQueryCommand, SavedQueryError, SavedQueryEvent, SavedQueryId, SavedQueryState,
+    saved_query_decider,
+};
+use crate::infrastructure::event_bus::EventBus;
+use crate::infrastructure::event_store::SqliteEventRepository;
+use fmodel_rust::Identifier;
+use std::sync::Arc;
+
+/// Save a copy of `source_id` named `new_name` on behalf of `actor`,
+/// timestamped by `clock`.
+///
+/// Returns the id of the new query.
+///
+/// # Errors
+///
+/// Returns `NotFound` when the source query does not exist or was deleted,
+/// `DuplicateName` when `new_name` is taken in the source's workspace, and
+/// any error from loading or saving events.
+pub async fn clone_saved_query<B: EventBus>(
+    event_repository: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+    event_bus: Option<&B>,
+    source_id: SavedQueryId,
+    new_name: QueryName,
+    actor: UserId,
+    clock: &dyn Clock,
+) -> Result<SavedQueryId, CommandPipelineError> {
+    let events = event_repository
+        .fetch_events_by_aggregate("SavedQuery", &source_id.identifier())
+        .await?;
+
+    let decider = saved_query_decider();
+    let source = events
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _version)| {
+            (decider.evolve)(&state, event)
+        });
+
+    let SavedQueryState::QueryExists {
+        workspace_id,
+        sql,
+        dataset_ref,
+        ..
+    } = source
+    else {
+        return Err(CommandPipelineError::SavedQuery(
+            SavedQueryError::not_found(),
+        ));
+    };
+
+    let query_id = SavedQueryId::new();
+    let command = SavedQueryCommand::SaveQuery {
+        query_id,
+        workspace_id,
+        name: new_name,
+        sql,
+        dataset_ref,
+        actor,
+        saved_at: clock.now(),
+    };
+    handle_saved_query_command(event_repository, event_bus, command).await?;
+
+    Ok(query_id)
+}
@@ -0,0 +1,199 @@
+//! Authorized execution of saved queries.
+//!
+//! A saved query belongs to one workspace, and running it reads that
+//! workspace's data. [`execute_saved_query`] looks up the query's workspace
+//! in the saved query list view and the actor's role in the workspace list
+//! view before recording the run. Actors without at least
+//! [`WorkspaceRole::Viewer`] are refused with `Forbidden`.
+
+use chrono::{DateTime, Utc};
+
+use super::handlers::handle_saved_query_command;
+use crate::application::error::CommandPipelineError;
+use crate::application::workspace::{query_saved_query_list, query_workspace_list};
+use crate::domain::UserId;
+use crate::domain::saved_query::{
+    SavedQueryCommand, SavedQueryError, SavedQueryEvent, SavedQueryId,
+};
+use crate::domain::workspace::{WorkspaceCommand, WorkspaceEvent, WorkspaceRole};
+use crate::infrastructure::event_bus::EventBus;
+use crate::infrastructure::event_store::SqliteEventRepository;
+use std::sync::Arc;
+
+/// Record a run of `query_id` by `actor` if they may read its workspace.
+///
+/// # Errors
+///
+/// Returns `NotFound` when the query does not exist, `Forbidden` when the
+/// actor holds no role of at least `Viewer` in the query's workspace, and
+/// any error from loading or saving events.
+pub async fn execute_saved_query<B: EventBus>(
+    saved_query_repository: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+    workspace_repository: &SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>,
+    event_bus: Option<&B>,
+    query_id: SavedQueryId,
+    actor: UserId,
+    run_at: DateTime<Utc>,
+) -> Result<Vec<(SavedQueryEvent, String)>, CommandPipelineError> {
+    let queries = query_saved_query_list(&saved_query_repository).await?;
+    let Some(query) = queries.queries.iter().find(|q| q.query_id == query_id) else {
+        return Err(CommandPipelineError::SavedQuery(
+            SavedQueryError::not_found(),
+        ));
+    };
+
+    let workspaces = query_workspace_list(workspace_repository).await?;
+    match workspaces.role_of(&query.workspace_id, &actor) {
+        Some(role) if role >= WorkspaceRole::Viewer => {}
+        _ => {
+            return Err(CommandPipelineError::forbidden(format!(
+                "run saved query {query_id}"
+            )));
+        }
+    }
+
+    let command = SavedQueryCommand::RecordRun { query_id, run_at };
+    handle_saved_query_command(saved_query_repository, event_bus, command).await
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::application::workspace::{
+        ReservedWorkspaceNames, WorkspaceAdministrators, handle_workspace_command,
+    };
+    use crate::domain::analytics::{DatasetRef, SqlQuery};
+    use crate::domain::saved_query::QueryName;
+    use crate::domain::workspace::{Visibility, WorkspaceId};
+    use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    const NO_EVENT_BUS: Option<&ZenohEventBus> = None;
+
+    async fn create_test_pool() -> sqlx::SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("Failed to create test pool");
+
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("Failed to run migration");
+
+        pool
+    }
+
+    /// Create a private workspace owned by `owner` holding one saved query.
+    async fn private_query(
+        pool: &sqlx::SqlitePool,
+        owner: UserId,
+    ) -> (
+        Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+        SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>,
+        SavedQueryId,
+    ) {
+        let workspace_repo = SqliteEventRepository::new(pool.clone());
+        let saved_query_repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        let workspace_id = WorkspaceId::new();
+
+        handle_workspace_command(
+            Arc::new(SqliteEventRepository::new(pool.clone())),
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            WorkspaceCommand::Create {
+                workspace_id,
+                name: "Research".to_string(),
+                owner_id: owner,
+                visibility: Visibility::Private,
+                actor: owner,
+                created_at: Utc::now(),
+            },
+        )
+        .await
+        .expect("create workspace");
+
+        let query_id = SavedQueryId::new();
+        handle_saved_query_command(
+            Arc::clone(&saved_query_repo),
+            NO_EVENT_BUS,
+            SavedQueryCommand::SaveQuery {
+                query_id,
+                workspace_id,
+                name: QueryName::new("Totals").expect("valid name"),
+                sql: SqlQuery::new("SELECT 1").expect("valid sql"),
+                dataset_ref: DatasetRef::new("hf://test/dataset").expect("valid ref"),
+                actor: owner,
+                saved_at: Utc::now(),
+            },
+        )
+        .await
+        .expect("save query");
+
+        (saved_query_repo, workspace_repo, query_id)
+    }
+
+    #[tokio::test]
+    async fn member_runs_query() {
+        let pool = create_test_pool().await;
+        let owner = UserId::new();
+        let (saved_query_repo, workspace_repo, query_id) = private_query(&pool, owner).await;
+
+        let events = execute_saved_query(
+            saved_query_repo,
+            &workspace_repo,
+            NO_EVENT_BUS,
+            query_id,
+            owner,
+            Utc::now(),
+        )
+        .await
+        .expect("owner may run the query");
+
+        assert!(matches!(
+            events.as_slice(),
+            [(SavedQueryEvent::QueryRun { .. }, _)]
+        ));
+    }
+
+    /// Run the private query as `actor` and assert it is forbidden and not
+    /// recorded.
+    async fn assert_private_query_forbidden_to(actor: UserId) {
+        let pool = create_test_pool().await;
+        let (saved_query_repo, workspace_repo, query_id) =
+            private_query(&pool, UserId::new()).await;
+
+        let result = execute_saved_query(
+            Arc::clone(&saved_query_repo),
+            &workspace_repo,
+            NO_EVENT_BUS,
+            query_id,
+            actor,
+            Utc::now(),
+        )
+        .await;
+
+        match result.expect_err("actor may not run the query") {
+            CommandPipelineError::Forbidden { .. } => {}
+            other => panic!("Expected Forbidden, got: {other:?}"),
+        }
+        let list = query_saved_query_list(&saved_query_repo)
+            .await
+            .expect("list queries");
+        assert_eq!(list.queries[0].run_count, 0);
+    }
+
+    #[tokio::test]
+    async fn non_member_is_forbidden() {
+        assert_private_query_forbidden_to(UserId::new()).await;
+    }
+
+    #[tokio::test]
+    async fn anonymous_caller_is_forbidden() {
+        assert_private_query_forbidden_to(UserId::system()).await;
+    }
+}
@@ -3,24 +3,47 @@
 //! This module provides the `handle_saved_query_command` function that creates an
 //! EventSourcedAggregate from the SavedQuery Decider and SQLite event repository,
 //! unifying domain and infrastructure errors via `CommandPipelineError`.
+//!
+//! Query names must be unique within a workspace. Each saved query is its own
+//! aggregate, so the decider cannot see its siblings; `SaveQuery` and
+//! `MoveToWorkspace` commands are checked against the saved query list view
+//! before the decider runs. The check builds the view from only the events
+//! that set a query's name or workspace, looked up by event type, so runs
+//! and SQL edits are never read.
+//!
+//! Queries in a suspended workspace must not change, and the decider cannot
+//! see the workspace either, so every command but `RecordRun` is checked
+//! with [`ensure_not_suspended`] first. A move is checked against both the
+//! query's workspace and the target.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::instrumentation::record_command_outcome;
+use crate::application::timestamps::LatestTimestamp;
+use crate::application::workspace::ensure_not_suspended;
 use crate::domain::saved_query::{
-    SavedQueryCommand, SavedQueryError, SavedQueryEvent, saved_query_decider,
+    QueryName, SavedQueryCommand, SavedQueryError, SavedQueryEvent, saved_query_decider,
 };
+use crate::domain::views::{SavedQueryListViewState, saved_query_list_view};
+use crate::domain::workspace::WorkspaceId;
 use crate::infrastructure::event_bus::{EventBus, ZenohEventBus, publish_events_fire_and_forget};
 use crate::infrastructure::event_store::SqliteEventRepository;
+use fmodel_rust::Identifier;
 use fmodel_rust::aggregate::{EventRepository, EventSourcedAggregate};
 use std::sync::Arc;
+use tracing::instrument;
 
 /// Adapter wrapping SqliteEventRepository to map errors to CommandPipelineError.
 pub struct SavedQueryEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+    latest: LatestTimestamp,
 }
 
 impl SavedQueryEventRepositoryAdapter {
     pub fn new(inner: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+        }
     }
 }
 
@@ -31,13 +54,16 @@ impl EventRepository<SavedQueryCommand, SavedQueryEvent, String, CommandPipeline
         &self,
         command: &SavedQueryCommand,
     ) -> Result<Vec<(SavedQueryEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[SavedQueryEvent],
     ) -> Result<Vec<(SavedQueryEvent, String)>, CommandPipelineError> {
+        self.latest.check(events)?;
         self.inner.save(events).await.map_err(Into::into)
     }
 
@@ -49,12 +75,134 @@ impl EventRepository<SavedQueryCommand, SavedQueryEvent, String, CommandPipeline
     }
 }
 
+/// Event types that decide which name a saved query holds in which workspace.
+const NAME_EVENT_TYPES: [&str; 4] = [
+    "QuerySaved",
+    "QueryRenamed",
+    "QueryMovedToWorkspace",
+    "QueryDeleted",
+];
+
+/// The saved query list view with current names and workspaces, built from
+/// the [`NAME_EVENT_TYPES`] events alone.
+///
+/// Other entry fields, such as run counts and SQL, are left as first saved.
+async fn query_saved_query_names(
+    event_repository: &SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>,
+) -> Result<SavedQueryListViewState, CommandPipelineError> {
+    let events = event_repository
+        .fetch_events_by_event_types("SavedQuery", &NAME_EVENT_TYPES)
+        .await?;
+    let view = saved_query_list_view();
+    Ok(events
+        .iter()
+        .fold((view.initial_state)(), |state, (event, _version)| {
+            (view.evolve)(&state, event)
+        }))
+}
+
+/// Reject a `SaveQuery` whose name is already used in its workspace, or a
+/// `MoveToWorkspace` whose query's name is already used in the target.
+async fn check_unique_name(
+    event_repository: &SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>,
+    command: &SavedQueryCommand,
+) -> Result<(), CommandPipelineError> {
+    match command {
+        SavedQueryCommand::SaveQuery {
+            workspace_id, name, ..
+        } => {
+            let list = query_saved_query_names(event_repository).await?;
+            ensure_name_free(&list, workspace_id, name)
+        }
+        SavedQueryCommand::MoveToWorkspace {
+            query_id,
+            target_workspace_id,
+            ..
+        } => {
+            let list = query_saved_query_names(event_repository).await?;
+            // Unknown queries and moves within the same workspace are left
+            // to the decider.
+            match list.queries.iter().find(|q| q.query_id == *query_id) {
+                Some(entry) if entry.workspace_id != *target_workspace_id => {
+                    ensure_name_free(&list, target_workspace_id, &entry.name)
+                }
+                _ => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+fn ensure_name_free(
+    list: &SavedQueryListViewState,
+    workspace_id: &WorkspaceId,
+    name: &QueryName,
+) -> Result<(), CommandPipelineError> {
+    if list.name_exists_in_workspace(workspace_id, name.as_str()) {
+        return Err(CommandPipelineError::SavedQuery(
+            SavedQueryError::duplicate_name(name.as_str()),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject a command that would change a query in a suspended workspace.
+///
+/// A new query is checked against the workspace it names and every other
+/// command against the workspace the query is in, plus the target of a move.
+/// Recording a run only reads the workspace, so it is always let through.
+async fn check_not_suspended(
+    event_repository: &SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>,
+    command: &SavedQueryCommand,
+) -> Result<(), CommandPipelineError> {
+    let pool = event_repository.pool();
+    match command {
+        SavedQueryCommand::SaveQuery { workspace_id, .. } => {
+            return ensure_not_suspended(pool, workspace_id).await;
+        }
+        SavedQueryCommand::MoveToWorkspace {
+            target_workspace_id,
+            ..
+        } => ensure_not_suspended(pool, target_workspace_id).await?,
+        SavedQueryCommand::RecordRun { .. } => return Ok(()),
+        SavedQueryCommand::DeleteQuery { .. }
+        | SavedQueryCommand::RenameQuery { .. }
+        | SavedQueryCommand::UpdateQuerySql { .. }
+        | SavedQueryCommand::UpdateDatasetRef { .. } => {}
+    }
+
+    let decider = saved_query_decider();
+    let query = event_repository
+        .fetch_events(command)
+        .await?
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _version)| {
+            (decider.evolve)(&state, event)
+        });
+    match query.workspace_id() {
+        Some(workspace_id) => ensure_not_suspended(pool, workspace_id).await,
+        None => Ok(()),
+    }
+}
+
 /// Handle a SavedQuery command through the EventSourcedAggregate pipeline.
+#[instrument(
+    name = "command.saved_query.handle",
+    skip_all,
+    fields(
+        aggregate_id = %command.identifier(),
+        command_kind = command.command_type(),
+        event_count = tracing::field::Empty,
+        outcome = tracing::field::Empty,
+    )
+)]
 pub async fn handle_saved_query_command<B: EventBus>(
     event_repository: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
     event_bus: Option<&B>,
     command: SavedQueryCommand,
 ) -> Result<Vec<(SavedQueryEvent, String)>, CommandPipelineError> {
+    check_not_suspended(&event_repository, &command).await?;
+    check_unique_name(&event_repository, &command).await?;
     let repo_adapter = SavedQueryEventRepositoryAdapter::new(event_repository);
 
     let mapped_decider = saved_query_decider().map_error(|e: &SavedQueryError| {
@@ -63,7 +211,9 @@ pub async fn handle_saved_query_command<B: EventBus>(
 
     let aggregate = EventSourcedAggregate::new(repo_adapter, mapped_decider);
 
-    let saved_events = aggregate.handle(&command).await?;
+    let result = aggregate.handle(&command).await;
+    record_command_outcome(&result);
+    let saved_events = result?;
 
     if let Some(bus) = event_bus {
         publish_events_fire_and_forget(bus, &saved_events).await;
@@ -73,11 +223,23 @@ pub async fn handle_saved_query_command<B: EventBus>(
 }
 
 /// Handle a SavedQuery command with Zenoh event bus support.
+#[instrument(
+    name = "command.saved_query.handle",
+    skip_all,
+    fields(
+        aggregate_id = %command.identifier(),
+        command_kind = command.command_type(),
+        event_count = tracing::field::Empty,
+        outcome = tracing::field::Empty,
+    )
+)]
 pub async fn handle_saved_query_command_zenoh(
     event_repository: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
     event_bus: Option<&ZenohEventBus>,
     command: SavedQueryCommand,
 ) -> Result<Vec<(SavedQueryEvent, String)>, CommandPipelineError> {
+    check_not_suspended(&event_repository, &command).await?;
+    check_unique_name(&event_repository, &command).await?;
     let repo_adapter = SavedQueryEventRepositoryAdapter::new(event_repository);
 
     let mapped_decider = saved_query_decider().map_error(|e: &SavedQueryError| {
@@ -86,7 +248,9 @@ pub async fn handle_saved_query_command_zenoh(
 
     let aggregate = EventSourcedAggregate::new(repo_adapter, mapped_decider);
 
-    let saved_events = aggregate.handle(&command).await?;
+    let result = aggregate.handle(&command).await;
+    record_command_outcome(&result);
+    let saved_events = result?;
 
     if let Some(bus) = event_bus {
         publish_events_fire_and_forget(bus, &saved_events).await;
@@ -99,10 +263,12 @@ pub async fn handle_saved_query_command_zenoh(
 #[allow(clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use crate::domain::UserId;
     use crate::domain::analytics::{DatasetRef, SqlQuery};
     use crate::domain::saved_query::{QueryName, SavedQueryErrorKind, SavedQueryId};
     use crate::domain::workspace::WorkspaceId;
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
     use uuid::Uuid;
@@ -114,7 +280,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -135,6 +301,7 @@ mod tests {
             name: QueryName::try_from("Test Query".to_string()).expect("valid name"),
             sql: SqlQuery::try_from("SELECT 1".to_string()).expect("valid sql"),
             dataset_ref: DatasetRef::try_from("hf://test/dataset".to_string()).expect("valid ref"),
+            actor: UserId::system(),
             saved_at: Utc::now(),
         };
 
@@ -157,6 +324,7 @@ mod tests {
             name: QueryName::try_from("Query".to_string()).expect("valid name"),
             sql: SqlQuery::try_from("SELECT 1".to_string()).expect("valid sql"),
             dataset_ref: DatasetRef::try_from("hf://other/dataset".to_string()).expect("valid ref"),
+            actor: UserId::system(),
             saved_at: Utc::now(),
         };
 
@@ -170,6 +338,7 @@ mod tests {
             name: QueryName::try_from("Duplicate".to_string()).expect("valid name"),
             sql: SqlQuery::try_from("SELECT 2".to_string()).expect("valid sql"),
             dataset_ref: DatasetRef::try_from("hf://other/dataset".to_string()).expect("valid ref"),
+            actor: UserId::system(),
             saved_at: Utc::now(),
         };
 
@@ -190,6 +359,7 @@ mod tests {
         let command = SavedQueryCommand::RenameQuery {
             query_id: SavedQueryId::new(),
             name: QueryName::try_from("New Name".to_string()).expect("valid name"),
+            actor: UserId::system(),
             renamed_at: Utc::now(),
         };
 
@@ -201,4 +371,109 @@ mod tests {
             other => panic!("Expected NotFound, got: {other:?}"),
         }
     }
+
+    fn save_named(workspace_id: WorkspaceId, name: &str) -> SavedQueryCommand {
+        SavedQueryCommand::SaveQuery {
+            query_id: SavedQueryId::new(),
+            workspace_id,
+            name: QueryName::try_from(name.to_string()).expect("valid name"),
+            sql: SqlQuery::try_from("SELECT 1".to_string()).expect("valid sql"),
+            dataset_ref: DatasetRef::try_from("hf://test/dataset".to_string()).expect("valid ref"),
+            actor: UserId::system(),
+            saved_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn duplicate_name_in_workspace_fails() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let workspace_id = WorkspaceId::from_uuid(Uuid::new_v4());
+
+        handle_saved_query_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            save_named(workspace_id, "Revenue"),
+        )
+        .await
+        .expect("first save should succeed");
+
+        let result =
+            handle_saved_query_command(repo, NO_EVENT_BUS, save_named(workspace_id, "Revenue"))
+                .await;
+        match result.expect_err("duplicate name should fail") {
+            CommandPipelineError::SavedQuery(ref e)
+                if *e.kind() == SavedQueryErrorKind::DuplicateName("Revenue".to_string()) => {}
+            other => panic!("Expected DuplicateName, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn same_name_in_other_workspace_succeeds() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+
+        for _ in 0..2 {
+            let command = save_named(WorkspaceId::from_uuid(Uuid::new_v4()), "Revenue");
+            handle_saved_query_command(Arc::clone(&repo), NO_EVENT_BUS, command)
+                .await
+                .expect("save in a fresh workspace should succeed");
+        }
+    }
+
+    #[tokio::test]
+    async fn move_into_workspace_with_same_name_fails() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let source = WorkspaceId::from_uuid(Uuid::new_v4());
+        let target = WorkspaceId::from_uuid(Uuid::new_v4());
+
+        let moving = save_named(source, "Revenue");
+        for command in [moving.clone(), save_named(target, "Revenue")] {
+            handle_saved_query_command(Arc::clone(&repo), NO_EVENT_BUS, command)
+                .await
+                .expect("save should succeed");
+        }
+
+        let command = SavedQueryCommand::MoveToWorkspace {
+            query_id: moving.query_id(),
+            target_workspace_id: target,
+            actor: UserId::system(),
+            moved_at: Utc::now(),
+        };
+        let result = handle_saved_query_command(repo, NO_EVENT_BUS, command).await;
+        match result.expect_err("move onto a taken name should fail") {
+            CommandPipelineError::SavedQuery(ref e)
+                if *e.kind() == SavedQueryErrorKind::DuplicateName("Revenue".to_string()) => {}
+            other => panic!("Expected DuplicateName, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn renamed_query_frees_its_old_name() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let workspace_id = WorkspaceId::from_uuid(Uuid::new_v4());
+        let first = save_named(workspace_id, "Revenue");
+
+        for command in [
+            first.clone(),
+            SavedQueryCommand::RenameQuery {
+                query_id: first.query_id(),
+                name: QueryName::try_from("Income".to_string()).expect("valid name"),
+                actor: UserId::system(),
+                renamed_at: Utc::now(),
+            },
+            save_named(workspace_id, "Revenue"),
+        ] {
+            handle_saved_query_command(Arc::clone(&repo), NO_EVENT_BUS, command)
+                .await
+                .expect("command should succeed");
+        }
+
+        let result =
+            handle_saved_query_command(repo, NO_EVENT_BUS, save_named(workspace_id, "Income"))
+                .await;
+        assert!(result.is_err(), "the new name is taken");
+    }
 }
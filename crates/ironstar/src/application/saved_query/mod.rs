@@ -1,8 +1,16 @@
 //! SavedQuery aggregate application layer.
 //!
 //! This module wires the SavedQuery Decider to the SQLite event repository,
-//! providing command handling for saved query lifecycle within workspaces.
+//! providing command handling for saved query lifecycle within workspaces,
+//! including batched deletion of many queries, copying a query under a
+//! new name, and running a query on behalf of a workspace member.
 
+mod bulk_delete;
+mod clone;
+mod execute;
 mod handlers;
 
+pub use bulk_delete::{BulkDeleteSummary, delete_saved_queries};
+pub use clone::clone_saved_query;
+pub use execute::execute_saved_query;
 pub use handlers::{handle_saved_query_command, handle_saved_query_command_zenoh};
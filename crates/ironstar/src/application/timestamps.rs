@@ -0,0 +1,52 @@
+//! Timestamp monotonicity guard for event repository adapters.
+//!
+//! Commands carry caller-supplied timestamps, so nothing in the deciders
+//! stops an event from being dated before the events already in its stream.
+//! Time-ordered views would then fold events out of order. Each adapter
+//! records the latest `occurred_at` while loading the aggregate's events and
+//! rejects new events dated before it when saving.
+
+use std::sync::{Mutex, PoisonError};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::error::CommandPipelineError;
+use crate::domain::traits::Timestamped;
+
+/// Latest event timestamp observed while loading an aggregate.
+#[derive(Debug, Default)]
+pub(crate) struct LatestTimestamp(Mutex<Option<DateTime<Utc>>>);
+
+impl LatestTimestamp {
+    /// Record the newest timestamp among loaded events.
+    pub(crate) fn observe<E: Timestamped, V>(&self, events: &[(E, V)]) {
+        let newest = events.iter().map(|(event, _)| event.occurred_at()).max();
+        let mut latest = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        *latest = (*latest).max(newest);
+    }
+
+    /// Reject events timestamped before the latest observed event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NonMonotonicTimestamp` for the first event dated too early.
+    pub(crate) fn check<E: Timestamped>(&self, events: &[E]) -> Result<(), CommandPipelineError> {
+        let Some(latest) = *self.0.lock().unwrap_or_else(PoisonError::into_inner) else {
+            return Ok(());
+        };
+
+        match events
+            .iter()
+            .map(Timestamped::occurred_at)
+            .find(|at| *at < latest)
+        {
+            Some(attempted) => Err(CommandPipelineError::NonMonotonicTimestamp {
+                error_id: Uuid::new_v4(),
+                latest,
+                attempted,
+            }),
+            None => Ok(()),
+        }
+    }
+}
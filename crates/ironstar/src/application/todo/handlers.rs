@@ -11,6 +11,7 @@
 //! updates while ensuring the event store remains the source of truth.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::timestamps::LatestTimestamp;
 use crate::domain::todo::{TodoCommand, TodoError, TodoEvent, todo_decider};
 use crate::infrastructure::event_bus::{EventBus, ZenohEventBus, publish_events_fire_and_forget};
 use crate::infrastructure::event_store::SqliteEventRepository;
@@ -24,12 +25,16 @@ use std::sync::Arc;
 /// from the underlying repository into `CommandPipelineError::Infrastructure`.
 pub struct TodoEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<TodoCommand, TodoEvent>>,
+    latest: LatestTimestamp,
 }
 
 impl TodoEventRepositoryAdapter {
     /// Create a new adapter wrapping the given repository.
     pub fn new(inner: Arc<SqliteEventRepository<TodoCommand, TodoEvent>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+        }
     }
 }
 
@@ -40,13 +45,16 @@ impl EventRepository<TodoCommand, TodoEvent, String, CommandPipelineError>
         &self,
         command: &TodoCommand,
     ) -> Result<Vec<(TodoEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[TodoEvent],
     ) -> Result<Vec<(TodoEvent, String)>, CommandPipelineError> {
+        self.latest.check(events)?;
         self.inner.save(events).await.map_err(Into::into)
     }
 
@@ -173,6 +181,7 @@ mod tests {
     use super::*;
     use crate::domain::todo::{TodoErrorKind, TodoId};
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
 
@@ -183,7 +192,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -4,26 +4,51 @@
 //! creates an EventSourcedAggregate from the UserPreferences Decider and
 //! SQLite event repository, unifying domain and infrastructure errors via
 //! `CommandPipelineError`.
+//!
+//! `initialize_preferences_for_new_owner` dispatches the commands produced by
+//! the preferences initialization saga, skipping owners whose preferences
+//! already exist. The events it writes join the triggering workspace event's
+//! correlation chain. Under [`ReplayMode::Replay`], as when rebuilding from
+//! the store, nothing it writes is published to the event bus.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::timestamps::LatestTimestamp;
+use crate::domain::sagas::preferences_initialization_saga;
 use crate::domain::user_preferences::{
     UserPreferencesCommand, UserPreferencesError, UserPreferencesEvent, user_preferences_decider,
 };
-use crate::infrastructure::event_bus::{EventBus, ZenohEventBus, publish_events_fire_and_forget};
-use crate::infrastructure::event_store::SqliteEventRepository;
+use crate::domain::workspace::WorkspaceEvent;
+use crate::infrastructure::event_bus::{
+    EventBus, ReplayMode, ZenohEventBus, publish_events_fire_and_forget,
+};
+use crate::infrastructure::event_store::{Correlation, SqliteEventRepository, StoredEvent};
 use fmodel_rust::aggregate::{EventRepository, EventSourcedAggregate};
+use fmodel_rust::saga::ActionComputation;
 use std::sync::Arc;
 
 /// Adapter wrapping SqliteEventRepository to map errors to CommandPipelineError.
 pub struct UserPreferencesEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<UserPreferencesCommand, UserPreferencesEvent>>,
+    latest: LatestTimestamp,
+    correlation: Option<Correlation>,
 }
 
 impl UserPreferencesEventRepositoryAdapter {
     pub fn new(
         inner: Arc<SqliteEventRepository<UserPreferencesCommand, UserPreferencesEvent>>,
     ) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+            correlation: None,
+        }
+    }
+
+    /// Record `correlation` with every saved event.
+    #[must_use]
+    pub fn with_correlation(mut self, correlation: Correlation) -> Self {
+        self.correlation = Some(correlation);
+        self
     }
 }
 
@@ -34,14 +59,21 @@ impl EventRepository<UserPreferencesCommand, UserPreferencesEvent, String, Comma
         &self,
         command: &UserPreferencesCommand,
     ) -> Result<Vec<(UserPreferencesEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[UserPreferencesEvent],
     ) -> Result<Vec<(UserPreferencesEvent, String)>, CommandPipelineError> {
-        self.inner.save(events).await.map_err(Into::into)
+        self.latest.check(events)?;
+        match &self.correlation {
+            Some(correlation) => self.inner.save_correlated(events, correlation).await,
+            None => self.inner.save(events).await,
+        }
+        .map_err(Into::into)
     }
 
     async fn version_provider(
@@ -58,8 +90,20 @@ pub async fn handle_user_preferences_command<B: EventBus>(
     event_bus: Option<&B>,
     command: UserPreferencesCommand,
 ) -> Result<Vec<(UserPreferencesEvent, String)>, CommandPipelineError> {
-    let repo_adapter = UserPreferencesEventRepositoryAdapter::new(event_repository);
+    handle_with_adapter(
+        UserPreferencesEventRepositoryAdapter::new(event_repository),
+        event_bus,
+        command,
+    )
+    .await
+}
 
+/// Run `command` through the aggregate, saving via `repo_adapter`.
+async fn handle_with_adapter<B: EventBus>(
+    repo_adapter: UserPreferencesEventRepositoryAdapter,
+    event_bus: Option<&B>,
+    command: UserPreferencesCommand,
+) -> Result<Vec<(UserPreferencesEvent, String)>, CommandPipelineError> {
     let mapped_decider = user_preferences_decider().map_error(|e: &UserPreferencesError| {
         CommandPipelineError::UserPreferences(UserPreferencesError::with_id(
             e.error_id(),
@@ -78,6 +122,34 @@ pub async fn handle_user_preferences_command<B: EventBus>(
     Ok(saved_events)
 }
 
+/// Initialize preferences for the owner of a newly created workspace.
+///
+/// Runs the preferences initialization saga on `parent` and dispatches each
+/// `InitializePreferences` command whose user has no preferences stream yet,
+/// so only an owner's first workspace initializes preferences. The saved
+/// events carry the parent's correlation id and name it as their cause, and
+/// are published only in [`ReplayMode::Live`]. Returns the persisted events,
+/// which are empty when nothing needed initializing.
+pub async fn initialize_preferences_for_new_owner<B: EventBus>(
+    event_repository: Arc<SqliteEventRepository<UserPreferencesCommand, UserPreferencesEvent>>,
+    event_bus: Option<&B>,
+    parent: &StoredEvent<WorkspaceEvent>,
+    mode: ReplayMode,
+) -> Result<Vec<(UserPreferencesEvent, String)>, CommandPipelineError> {
+    let event_bus = mode.event_bus(event_bus);
+    let mut saved = Vec::new();
+    for command in preferences_initialization_saga().compute_new_actions(&parent.event) {
+        if !event_repository.fetch_events(&command).await?.is_empty() {
+            continue;
+        }
+        let repo_adapter =
+            UserPreferencesEventRepositoryAdapter::new(Arc::clone(&event_repository))
+                .with_correlation(parent.child_correlation());
+        saved.extend(handle_with_adapter(repo_adapter, event_bus, command).await?);
+    }
+    Ok(saved)
+}
+
 /// Handle a UserPreferences command with Zenoh event bus support.
 pub async fn handle_user_preferences_command_zenoh(
     event_repository: Arc<SqliteEventRepository<UserPreferencesCommand, UserPreferencesEvent>>,
@@ -110,9 +182,13 @@ mod tests {
     use super::*;
     use crate::domain::UserId;
     use crate::domain::user_preferences::{PreferencesId, Theme, UserPreferencesErrorKind};
-    use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::domain::workspace::{Visibility, WorkspaceCommand, WorkspaceId, WorkspaceName};
+    use crate::infrastructure::event_bus::{InProcessEventBus, ZenohEventBus};
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
+    use crate::infrastructure::key_expr::ALL_EVENTS;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
+    use std::time::Duration;
 
     async fn create_test_pool() -> sqlx::SqlitePool {
         let pool = SqlitePoolOptions::new()
@@ -121,7 +197,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -202,4 +278,123 @@ mod tests {
             other => panic!("Expected NotInitialized, got: {other:?}"),
         }
     }
+
+    /// Persist a workspace `Created` event for `owner` under `request_id`
+    /// and return it as stored.
+    async fn workspace_created(
+        pool: &sqlx::SqlitePool,
+        owner_id: UserId,
+        request_id: &str,
+    ) -> StoredEvent<WorkspaceEvent> {
+        let repo: SqliteEventRepository<WorkspaceCommand, WorkspaceEvent> =
+            SqliteEventRepository::new(pool.clone());
+        let event = WorkspaceEvent::Created {
+            workspace_id: WorkspaceId::new(),
+            name: WorkspaceName::new("Analytics").expect("valid name"),
+            normalized_name: "analytics".to_string(),
+            owner_id,
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        };
+        repo.save_correlated(&[event], &Correlation::new(request_id))
+            .await
+            .expect("workspace event should save");
+        repo.load_by_correlation(request_id)
+            .await
+            .expect("workspace event should load")
+            .remove(0)
+    }
+
+    #[tokio::test]
+    async fn first_workspace_initializes_owner_preferences_once() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        let owner = UserId::new();
+
+        let first = initialize_preferences_for_new_owner(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &workspace_created(&pool, owner, "request-1").await,
+            ReplayMode::Live,
+        )
+        .await
+        .expect("first workspace should initialize preferences");
+        let second = initialize_preferences_for_new_owner(
+            repo,
+            NO_EVENT_BUS,
+            &workspace_created(&pool, owner, "request-2").await,
+            ReplayMode::Live,
+        )
+        .await
+        .expect("second workspace should be a no-op");
+
+        assert_eq!(first.len(), 1);
+        assert!(matches!(
+            first[0].0,
+            UserPreferencesEvent::PreferencesInitialized { user_id, .. } if user_id == owner
+        ));
+        assert!(second.is_empty());
+    }
+
+    #[tokio::test]
+    async fn saga_initialized_preferences_join_parent_correlation() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        let parent = workspace_created(&pool, UserId::new(), "request-1").await;
+
+        initialize_preferences_for_new_owner(repo, NO_EVENT_BUS, &parent, ReplayMode::Live)
+            .await
+            .expect("preferences should initialize");
+
+        let chain = SqliteEventRepository::<(), serde_json::Value>::new(pool)
+            .load_by_correlation("request-1")
+            .await
+            .expect("chain should load");
+        let [workspace, preferences] = chain.as_slice() else {
+            panic!("expected two events in the chain, got {}", chain.len());
+        };
+        assert_eq!(workspace.event_id, parent.event_id);
+        assert_eq!(preferences.event_type, "PreferencesInitialized");
+        assert_eq!(preferences.correlation_id.as_deref(), Some("request-1"));
+        assert_eq!(
+            preferences.causation_id.as_deref(),
+            Some(parent.event_id.as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn rebuild_in_replay_mode_publishes_nothing() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        workspace_created(&pool, UserId::new(), "request-1").await;
+        workspace_created(&pool, UserId::new(), "request-2").await;
+        let bus = InProcessEventBus::new();
+        let mut subscriber = bus.subscribe(ALL_EVENTS);
+
+        let workspace_events: SqliteEventRepository<WorkspaceCommand, WorkspaceEvent> =
+            SqliteEventRepository::new(pool);
+        let mut rebuilt = Vec::new();
+        for parent in workspace_events
+            .query_all()
+            .await
+            .expect("events should load")
+        {
+            rebuilt.extend(
+                initialize_preferences_for_new_owner(
+                    Arc::clone(&repo),
+                    Some(&bus),
+                    &parent,
+                    ReplayMode::Replay,
+                )
+                .await
+                .expect("replay should initialize preferences"),
+            );
+        }
+
+        assert_eq!(rebuilt.len(), 2);
+        let published =
+            tokio::time::timeout(Duration::from_millis(50), subscriber.recv_async()).await;
+        assert!(published.is_err(), "replay published {published:?}");
+    }
 }
@@ -5,4 +5,7 @@
 
 mod handlers;
 
-pub use handlers::{handle_user_preferences_command, handle_user_preferences_command_zenoh};
+pub use handlers::{
+    handle_user_preferences_command, handle_user_preferences_command_zenoh,
+    initialize_preferences_for_new_owner,
+};
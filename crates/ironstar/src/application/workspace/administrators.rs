@@ -0,0 +1,73 @@
+//! Workspace administrators.
+//!
+//! Suspension freezes a workspace pending review, so only administrators may
+//! issue `Suspend` or `Unsuspend`. Who counts as an administrator is
+//! deployment configuration rather than workspace state, so the check runs
+//! in the command handlers before the decider sees the command. The default
+//! set is empty: nobody may suspend a workspace until administrators are
+//! configured.
+
+use std::collections::HashSet;
+
+use crate::domain::session::UserId;
+use crate::domain::workspace::{WorkspaceCommand, WorkspaceError};
+
+/// Configurable set of users who may suspend and unsuspend workspaces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceAdministrators(HashSet<UserId>);
+
+impl WorkspaceAdministrators {
+    /// Create an administrator set from the given users.
+    #[must_use]
+    pub fn new(users: impl IntoIterator<Item = UserId>) -> Self {
+        Self(users.into_iter().collect())
+    }
+
+    /// Whether `user_id` is an administrator.
+    ///
+    /// The system actor, which anonymous requests act as, never is.
+    #[must_use]
+    pub fn is_administrator(&self, user_id: &UserId) -> bool {
+        !user_id.is_system() && self.0.contains(user_id)
+    }
+
+    /// Reject `Suspend` and `Unsuspend` commands from non-administrators.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AdminRequired` error when the actor is not an
+    /// administrator.
+    pub fn check(&self, command: &WorkspaceCommand) -> Result<(), WorkspaceError> {
+        let actor = match command {
+            WorkspaceCommand::Suspend { actor, .. } | WorkspaceCommand::Unsuspend { actor, .. } => {
+                actor
+            }
+            WorkspaceCommand::Create { .. }
+            | WorkspaceCommand::Rename { .. }
+            | WorkspaceCommand::SetVisibility { .. }
+            | WorkspaceCommand::Archive { .. } => return Ok(()),
+        };
+
+        if self.is_administrator(actor) {
+            Ok(())
+        } else {
+            Err(WorkspaceError::admin_required())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_listed_users_are_administrators() {
+        let admin = UserId::new();
+        let administrators = WorkspaceAdministrators::new([admin, UserId::system()]);
+
+        assert!(administrators.is_administrator(&admin));
+        assert!(!administrators.is_administrator(&UserId::new()));
+        assert!(!administrators.is_administrator(&UserId::system()));
+        assert!(!WorkspaceAdministrators::default().is_administrator(&admin));
+    }
+}
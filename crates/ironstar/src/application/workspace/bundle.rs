@@ -0,0 +1,429 @@
+//! Moving a workspace between instances as a serializable bundle.
+//!
+//! [`export_workspace_bundle`] captures a workspace's metadata together with
+//! its live dashboards and saved queries. [`import_workspace_bundle`]
+//! recreates them under fresh ids for a new owner, so a bundle can be
+//! imported into the store it came from without colliding with the source.
+//!
+//! Import remaps every id a child holds:
+//!
+//! - Dashboard tabs get new ids; placements and the default tab follow them.
+//! - Placements get new chart ids.
+//! - A chart definition reference naming an exported saved query, by stream
+//!   id or bare UUID, is rewritten to name the imported query.
+//!
+//! Run history and audit trails are not part of a bundle; the imported
+//! aggregates start with fresh event streams.
+//!
+//! Import writes its events directly rather than through the dashboard and
+//! saved query command handlers, so it skips their suspension check. It only
+//! ever writes into the workspace it creates, which cannot be suspended yet.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+use fmodel_rust::decider::EventComputation;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use super::reserved_names::ReservedWorkspaceNames;
+use crate::application::error::CommandPipelineError;
+use crate::domain::UserId;
+use crate::domain::analytics::{DatasetRef, SqlQuery};
+use crate::domain::clock::Clock;
+use crate::domain::common::DashboardTitle;
+use crate::domain::dashboard::{
+    ChartDefinitionRef, ChartId, ChartPlacement, ChartSource, DashboardCommand, DashboardEvent,
+    DashboardId, DashboardState, TabId, TabInfo, dashboard_decider,
+};
+use crate::domain::saved_query::{
+    QueryName, SavedQueryCommand, SavedQueryError, SavedQueryEvent, SavedQueryId, SavedQueryState,
+    saved_query_decider,
+};
+use crate::domain::workspace::{
+    Visibility, WorkspaceCommand, WorkspaceError, WorkspaceEvent, WorkspaceId, WorkspaceName,
+    WorkspaceStatus, workspace_decider,
+};
+use crate::infrastructure::event_bus::{EventBus, publish_events_fire_and_forget};
+use crate::infrastructure::event_store::{EventStoreError, SqliteEventRepository, StoredEvent};
+use fmodel_rust::Identifier;
+
+/// Event repositories of a workspace and the aggregates it owns.
+#[derive(Clone)]
+pub struct WorkspaceRepositories {
+    pub workspaces: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>,
+    pub dashboards: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
+    pub saved_queries: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+}
+
+impl WorkspaceRepositories {
+    /// Repositories over one event store.
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            workspaces: Arc::new(SqliteEventRepository::new(pool.clone())),
+            dashboards: Arc::new(SqliteEventRepository::new(pool.clone())),
+            saved_queries: Arc::new(SqliteEventRepository::new(pool)),
+        }
+    }
+}
+
+/// A workspace with its dashboards and saved queries, as exported.
+///
+/// Ids are those of the source instance; import replaces all of them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceBundle {
+    pub workspace_id: WorkspaceId,
+    pub name: WorkspaceName,
+    pub visibility: Visibility,
+    pub dashboards: Vec<DashboardBundle>,
+    pub saved_queries: Vec<SavedQueryBundle>,
+}
+
+/// An exported dashboard with its tabs and chart placements.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashboardBundle {
+    pub dashboard_id: DashboardId,
+    pub name: DashboardTitle,
+    pub tabs: Vec<TabInfo>,
+    /// Absent from bundles exported before dashboards had a default tab.
+    #[serde(default)]
+    pub default_tab_id: Option<TabId>,
+    pub placements: Vec<ChartPlacement>,
+}
+
+/// An exported saved query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedQueryBundle {
+    pub query_id: SavedQueryId,
+    pub name: QueryName,
+    pub sql: SqlQuery,
+    pub dataset_ref: DatasetRef,
+}
+
+/// Export `workspace_id` with its live dashboards and saved queries.
+///
+/// Archived dashboards and deleted queries are left out. Only the streams
+/// of aggregates that were ever in the workspace are loaded: their ids come
+/// from the creation and move events, looked up by event type, and their
+/// events are then fetched by aggregate id.
+///
+/// # Errors
+///
+/// Returns `NotFound` when the workspace does not exist, `Archived` when it
+/// was archived, and any error from loading events.
+pub async fn export_workspace_bundle(
+    repositories: &WorkspaceRepositories,
+    workspace_id: WorkspaceId,
+) -> Result<WorkspaceBundle, CommandPipelineError> {
+    let events = repositories
+        .workspaces
+        .fetch_events_by_aggregate("Workspace", &workspace_id.identifier())
+        .await?;
+    let decider = workspace_decider();
+    let workspace = events
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _version)| {
+            (decider.evolve)(&state, event)
+        });
+    if workspace.status == WorkspaceStatus::Archived {
+        return Err(CommandPipelineError::Workspace(WorkspaceError::archived()));
+    }
+    let (Some(name), Some(visibility)) = (workspace.name, workspace.visibility) else {
+        return Err(CommandPipelineError::Workspace(WorkspaceError::not_found()));
+    };
+
+    let dashboard_ids: BTreeSet<String> = repositories
+        .dashboards
+        .fetch_events_by_event_types("Dashboard", &["DashboardCreated"])
+        .await?
+        .into_iter()
+        .filter(|(event, _version)| {
+            matches!(
+                event,
+                DashboardEvent::DashboardCreated { workspace_id: owner, .. } if *owner == workspace_id
+            )
+        })
+        .map(|(event, _version)| event.identifier())
+        .collect();
+    let decider = dashboard_decider();
+    let mut dashboards: BTreeMap<String, DashboardState> = BTreeMap::new();
+    for (event, _version) in repositories
+        .dashboards
+        .fetch_events_by_aggregates("Dashboard", &Vec::from_iter(dashboard_ids))
+        .await?
+    {
+        let state = dashboards
+            .entry(event.identifier())
+            .or_insert_with(|| (decider.initial_state)());
+        *state = (decider.evolve)(state, &event);
+    }
+
+    let query_ids: BTreeSet<String> = repositories
+        .saved_queries
+        .fetch_events_by_event_types("SavedQuery", &["QuerySaved", "QueryMovedToWorkspace"])
+        .await?
+        .into_iter()
+        .filter(|(event, _version)| match event {
+            SavedQueryEvent::QuerySaved {
+                workspace_id: owner,
+                ..
+            }
+            | SavedQueryEvent::QueryMovedToWorkspace {
+                to_workspace_id: owner,
+                ..
+            } => *owner == workspace_id,
+            _ => false,
+        })
+        .map(|(event, _version)| event.identifier())
+        .collect();
+    let decider = saved_query_decider();
+    let mut saved_queries: BTreeMap<String, SavedQueryState> = BTreeMap::new();
+    for (event, _version) in repositories
+        .saved_queries
+        .fetch_events_by_aggregates("SavedQuery", &Vec::from_iter(query_ids))
+        .await?
+    {
+        let state = saved_queries
+            .entry(event.identifier())
+            .or_insert_with(|| (decider.initial_state)());
+        *state = (decider.evolve)(state, &event);
+    }
+
+    Ok(WorkspaceBundle {
+        workspace_id,
+        name,
+        visibility,
+        dashboards: dashboards
+            .into_values()
+            .filter_map(|state| match state {
+                DashboardState::DashboardExists {
+                    dashboard_id,
+                    workspace_id: owner,
+                    name,
+                    placements,
+                    tabs,
+                    default_tab_id,
+                } if owner == workspace_id => Some(DashboardBundle {
+                    dashboard_id,
+                    name,
+                    tabs,
+                    default_tab_id,
+                    placements,
+                }),
+                _ => None,
+            })
+            .collect(),
+        saved_queries: saved_queries
+            .into_values()
+            .filter_map(|state| match state {
+                SavedQueryState::QueryExists {
+                    query_id,
+                    workspace_id: owner,
+                    name,
+                    sql,
+                    dataset_ref,
+                    ..
+                } if owner == workspace_id => Some(SavedQueryBundle {
+                    query_id,
+                    name,
+                    sql,
+                    dataset_ref,
+                }),
+                _ => None,
+            })
+            .collect(),
+    })
+}
+
+/// Recreate `bundle` as a new workspace owned by `new_owner`, timestamped
+/// by `clock`.
+///
+/// Every aggregate is created under a fresh id with `new_owner` as actor.
+/// The deciders compute all events up front and they are appended in one
+/// transaction, so an import either lands whole or leaves nothing behind.
+/// Returns the id of the new workspace.
+///
+/// # Errors
+///
+/// Returns any error from the workspace, saved query, or dashboard
+/// deciders, such as a reserved workspace name or two queries sharing a
+/// name, an error if a saved query's SQL is not a single read-only
+/// statement, and any error from saving events.
+pub async fn import_workspace_bundle<B: EventBus>(
+    repositories: &WorkspaceRepositories,
+    event_bus: Option<&B>,
+    reserved_names: &ReservedWorkspaceNames,
+    clock: &dyn Clock,
+    bundle: &WorkspaceBundle,
+    new_owner: UserId,
+) -> Result<WorkspaceId, CommandPipelineError> {
+    let now = clock.now();
+    let workspace_id = WorkspaceId::new();
+    let create = WorkspaceCommand::Create {
+        workspace_id,
+        name: bundle.name.as_str().to_string(),
+        owner_id: new_owner,
+        visibility: bundle.visibility,
+        actor: new_owner,
+        created_at: now,
+    };
+    reserved_names.check(&create)?;
+    let workspace_events = workspace_decider()
+        .compute_new_events(&[], &create)
+        .map_err(CommandPipelineError::Workspace)?;
+
+    // Old query id, by stream id and bare UUID, to the imported query's.
+    let mut query_refs: HashMap<String, String> = HashMap::new();
+    let mut query_names: HashSet<&str> = HashSet::new();
+    let mut query_events = Vec::new();
+    for query in &bundle.saved_queries {
+        // The workspace is new, so only the bundle's own queries can clash.
+        if !query_names.insert(query.name.as_str()) {
+            return Err(CommandPipelineError::SavedQuery(
+                SavedQueryError::duplicate_name(query.name.as_str()),
+            ));
+        }
+        // A bundle is user input, so its SQL gets the same check as a save.
+        let sql = SqlQuery::new_read_only(query.sql.as_str())?;
+        let query_id = SavedQueryId::new();
+        let command = SavedQueryCommand::SaveQuery {
+            query_id,
+            workspace_id,
+            name: query.name.clone(),
+            sql,
+            dataset_ref: query.dataset_ref.clone(),
+            actor: new_owner,
+            saved_at: now,
+        };
+        query_events.extend(
+            saved_query_decider()
+                .compute_new_events(&[], &command)
+                .map_err(CommandPipelineError::SavedQuery)?,
+        );
+        query_refs.insert(query.query_id.identifier(), query_id.identifier());
+        query_refs.insert(query.query_id.to_string(), query_id.to_string());
+    }
+
+    let mut dashboard_events = Vec::new();
+    for dashboard in &bundle.dashboards {
+        let dashboard_id = DashboardId::new();
+        let mut commands = vec![DashboardCommand::CreateDashboard {
+            dashboard_id,
+            workspace_id,
+            name: dashboard.name.clone(),
+            actor: new_owner,
+            created_at: now,
+        }];
+
+        let mut tab_ids: HashMap<TabId, TabId> = HashMap::new();
+        for tab in &dashboard.tabs {
+            let tab_id = TabId::new();
+            tab_ids.insert(tab.tab_id, tab_id);
+            commands.push(DashboardCommand::AddTab {
+                dashboard_id,
+                tab_info: TabInfo {
+                    tab_id,
+                    name: tab.name.clone(),
+                },
+                actor: new_owner,
+                added_at: now,
+            });
+        }
+
+        if let Some(&tab_id) = dashboard
+            .default_tab_id
+            .and_then(|tab_id| tab_ids.get(&tab_id))
+        {
+            commands.push(DashboardCommand::SetDefaultTab {
+                dashboard_id,
+                tab_id,
+                actor: new_owner,
+                set_at: now,
+            });
+        }
+
+        for placement in &dashboard.placements {
+            commands.push(DashboardCommand::AddChart {
+                dashboard_id,
+                placement: ChartPlacement {
+                    chart_id: ChartId::new(),
+                    source: remap_source(&placement.source, &query_refs),
+                    position: placement.position,
+                    size: placement.size,
+                    tab_id: placement
+                        .tab_id
+                        .and_then(|tab_id| tab_ids.get(&tab_id).copied()),
+                },
+                actor: new_owner,
+                added_at: now,
+            });
+        }
+
+        let decider = dashboard_decider();
+        let mut events: Vec<DashboardEvent> = Vec::new();
+        for command in commands {
+            let new_events = decider
+                .compute_new_events(&events, &command)
+                .map_err(CommandPipelineError::Dashboard)?;
+            events.extend(new_events);
+        }
+        dashboard_events.extend(events);
+    }
+
+    // The three repositories share one pool, so one transaction spans them.
+    let mut tx = repositories
+        .workspaces
+        .pool()
+        .begin()
+        .await
+        .map_err(EventStoreError::from)?;
+    let workspace_events = repositories
+        .workspaces
+        .append_in_transaction(&mut tx, &workspace_events)
+        .await?;
+    let query_events = repositories
+        .saved_queries
+        .append_in_transaction(&mut tx, &query_events)
+        .await?;
+    let dashboard_events = repositories
+        .dashboards
+        .append_in_transaction(&mut tx, &dashboard_events)
+        .await?;
+    tx.commit().await.map_err(EventStoreError::from)?;
+
+    repositories.workspaces.record_committed(&workspace_events);
+    repositories.saved_queries.record_committed(&query_events);
+    repositories.dashboards.record_committed(&dashboard_events);
+
+    if let Some(bus) = event_bus {
+        publish_events_fire_and_forget(bus, &saved(workspace_events)).await;
+        publish_events_fire_and_forget(bus, &saved(query_events)).await;
+        publish_events_fire_and_forget(bus, &saved(dashboard_events)).await;
+    }
+
+    Ok(workspace_id)
+}
+
+/// Committed events paired with their versions, as the command handlers
+/// return them.
+fn saved<E>(stored: Vec<StoredEvent<E>>) -> Vec<(E, String)> {
+    stored
+        .into_iter()
+        .map(|stored| (stored.event, stored.event_id))
+        .collect()
+}
+
+/// Point a chart definition reference at the imported query it named.
+fn remap_source(source: &ChartSource, query_refs: &HashMap<String, String>) -> ChartSource {
+    match source {
+        ChartSource::Reference(def_ref) => match query_refs.get(&def_ref.ref_id) {
+            Some(ref_id) => ChartSource::Reference(ChartDefinitionRef {
+                ref_id: ref_id.clone(),
+                chart_type_hint: def_ref.chart_type_hint,
+            }),
+            None => source.clone(),
+        },
+        ChartSource::Inline(_) => source.clone(),
+    }
+}
@@ -3,24 +3,50 @@
 //! This module provides the `handle_workspace_command` function that creates an
 //! EventSourcedAggregate from the Workspace Decider and SQLite event repository,
 //! unifying domain and infrastructure errors via `CommandPipelineError`.
+//!
+//! Names are checked against [`ReservedWorkspaceNames`] before the decider
+//! runs, since reserved names are a routing concern, and suspension commands
+//! against [`WorkspaceAdministrators`].
+//!
+//! `handle_workspace_command_zenoh` records the request's [`Correlation`]
+//! with the events it saves, so sagas reacting to them join the request's
+//! chain.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::instrumentation::record_command_outcome;
+use crate::application::timestamps::LatestTimestamp;
+use crate::application::workspace::{ReservedWorkspaceNames, WorkspaceAdministrators};
 use crate::domain::workspace::{
     WorkspaceCommand, WorkspaceError, WorkspaceEvent, workspace_decider,
 };
 use crate::infrastructure::event_bus::{EventBus, ZenohEventBus, publish_events_fire_and_forget};
-use crate::infrastructure::event_store::SqliteEventRepository;
+use crate::infrastructure::event_store::{Correlation, SqliteEventRepository};
+use fmodel_rust::Identifier;
 use fmodel_rust::aggregate::{EventRepository, EventSourcedAggregate};
 use std::sync::Arc;
+use tracing::instrument;
 
 /// Adapter wrapping SqliteEventRepository to map errors to CommandPipelineError.
 pub struct WorkspaceEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>,
+    latest: LatestTimestamp,
+    correlation: Option<Correlation>,
 }
 
 impl WorkspaceEventRepositoryAdapter {
     pub fn new(inner: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+            correlation: None,
+        }
+    }
+
+    /// Record `correlation` with every saved event.
+    #[must_use]
+    pub fn with_correlation(mut self, correlation: Correlation) -> Self {
+        self.correlation = Some(correlation);
+        self
     }
 }
 
@@ -31,14 +57,21 @@ impl EventRepository<WorkspaceCommand, WorkspaceEvent, String, CommandPipelineEr
         &self,
         command: &WorkspaceCommand,
     ) -> Result<Vec<(WorkspaceEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[WorkspaceEvent],
     ) -> Result<Vec<(WorkspaceEvent, String)>, CommandPipelineError> {
-        self.inner.save(events).await.map_err(Into::into)
+        self.latest.check(events)?;
+        match &self.correlation {
+            Some(correlation) => self.inner.save_correlated(events, correlation).await,
+            None => self.inner.save(events).await,
+        }
+        .map_err(Into::into)
     }
 
     async fn version_provider(
@@ -50,11 +83,25 @@ impl EventRepository<WorkspaceCommand, WorkspaceEvent, String, CommandPipelineEr
 }
 
 /// Handle a Workspace command through the EventSourcedAggregate pipeline.
+#[instrument(
+    name = "command.workspace.handle",
+    skip_all,
+    fields(
+        aggregate_id = %command.identifier(),
+        command_kind = command.command_type(),
+        event_count = tracing::field::Empty,
+        outcome = tracing::field::Empty,
+    )
+)]
 pub async fn handle_workspace_command<B: EventBus>(
     event_repository: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>,
     event_bus: Option<&B>,
+    reserved_names: &ReservedWorkspaceNames,
+    administrators: &WorkspaceAdministrators,
     command: WorkspaceCommand,
 ) -> Result<Vec<(WorkspaceEvent, String)>, CommandPipelineError> {
+    reserved_names.check(&command)?;
+    administrators.check(&command)?;
     let repo_adapter = WorkspaceEventRepositoryAdapter::new(event_repository);
 
     let mapped_decider = workspace_decider().map_error(|e: &WorkspaceError| {
@@ -63,7 +110,9 @@ pub async fn handle_workspace_command<B: EventBus>(
 
     let aggregate = EventSourcedAggregate::new(repo_adapter, mapped_decider);
 
-    let saved_events = aggregate.handle(&command).await?;
+    let result = aggregate.handle(&command).await;
+    record_command_outcome(&result);
+    let saved_events = result?;
 
     if let Some(bus) = event_bus {
         publish_events_fire_and_forget(bus, &saved_events).await;
@@ -74,13 +123,30 @@ pub async fn handle_workspace_command<B: EventBus>(
 
 /// Handle a Workspace command with Zenoh event bus support.
 ///
-/// Concrete (non-generic) version for axum `Send` bounds.
+/// Concrete (non-generic) version for axum `Send` bounds. The saved events
+/// carry `correlation`, which HTTP handlers start at the request id.
+#[instrument(
+    name = "command.workspace.handle",
+    skip_all,
+    fields(
+        aggregate_id = %command.identifier(),
+        command_kind = command.command_type(),
+        event_count = tracing::field::Empty,
+        outcome = tracing::field::Empty,
+    )
+)]
 pub async fn handle_workspace_command_zenoh(
     event_repository: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>,
     event_bus: Option<&ZenohEventBus>,
+    reserved_names: &ReservedWorkspaceNames,
+    administrators: &WorkspaceAdministrators,
     command: WorkspaceCommand,
+    correlation: Correlation,
 ) -> Result<Vec<(WorkspaceEvent, String)>, CommandPipelineError> {
-    let repo_adapter = WorkspaceEventRepositoryAdapter::new(event_repository);
+    reserved_names.check(&command)?;
+    administrators.check(&command)?;
+    let repo_adapter =
+        WorkspaceEventRepositoryAdapter::new(event_repository).with_correlation(correlation);
 
     let mapped_decider = workspace_decider().map_error(|e: &WorkspaceError| {
         CommandPipelineError::Workspace(WorkspaceError::with_id(e.error_id(), e.kind().clone()))
@@ -88,7 +154,9 @@ pub async fn handle_workspace_command_zenoh(
 
     let aggregate = EventSourcedAggregate::new(repo_adapter, mapped_decider);
 
-    let saved_events = aggregate.handle(&command).await?;
+    let result = aggregate.handle(&command).await;
+    record_command_outcome(&result);
+    let saved_events = result?;
 
     if let Some(bus) = event_bus {
         publish_events_fire_and_forget(bus, &saved_events).await;
@@ -104,6 +172,7 @@ mod tests {
     use crate::domain::UserId;
     use crate::domain::workspace::{Visibility, WorkspaceErrorKind, WorkspaceId};
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
 
@@ -114,7 +183,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -134,16 +203,57 @@ mod tests {
             name: "Test Workspace".to_string(),
             owner_id: UserId::new(),
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
 
-        let result = handle_workspace_command(repo, NO_EVENT_BUS, command).await;
+        let result = handle_workspace_command(
+            repo,
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await;
         assert!(result.is_ok());
         let events = result.expect("command should succeed");
         assert_eq!(events.len(), 1);
         assert!(matches!(events[0].0, WorkspaceEvent::Created { .. }));
     }
 
+    #[tokio::test]
+    async fn zenoh_handler_records_the_request_correlation() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+
+        let command = WorkspaceCommand::Create {
+            workspace_id: WorkspaceId::new(),
+            name: "Correlated".to_string(),
+            owner_id: UserId::new(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        };
+        handle_workspace_command_zenoh(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            command,
+            Correlation::new("request-1"),
+        )
+        .await
+        .expect("command should succeed");
+
+        let chain = repo
+            .load_by_correlation("request-1")
+            .await
+            .expect("chain should load");
+        assert_eq!(chain.len(), 1);
+        assert!(matches!(chain[0].event, WorkspaceEvent::Created { .. }));
+        assert_eq!(chain[0].correlation_id.as_deref(), Some("request-1"));
+    }
+
     #[tokio::test]
     async fn duplicate_create_fails() {
         let pool = create_test_pool().await;
@@ -155,22 +265,37 @@ mod tests {
             name: "Workspace".to_string(),
             owner_id: UserId::new(),
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
 
-        let _ = handle_workspace_command(Arc::clone(&repo), NO_EVENT_BUS, command)
-            .await
-            .expect("first create should succeed");
+        let _ = handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await
+        .expect("first create should succeed");
 
         let duplicate = WorkspaceCommand::Create {
             workspace_id: id,
             name: "Duplicate".to_string(),
             owner_id: UserId::new(),
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
 
-        let result = handle_workspace_command(repo, NO_EVENT_BUS, duplicate).await;
+        let result = handle_workspace_command(
+            repo,
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            duplicate,
+        )
+        .await;
         assert!(result.is_err());
         match result.expect_err("duplicate should fail") {
             CommandPipelineError::Workspace(ref e)
@@ -187,10 +312,18 @@ mod tests {
         let command = WorkspaceCommand::Rename {
             workspace_id: WorkspaceId::new(),
             new_name: "New Name".to_string(),
+            actor: UserId::system(),
             renamed_at: Utc::now(),
         };
 
-        let result = handle_workspace_command(repo, NO_EVENT_BUS, command).await;
+        let result = handle_workspace_command(
+            repo,
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await;
         assert!(result.is_err());
         match result.expect_err("rename without create should fail") {
             CommandPipelineError::Workspace(ref e) if *e.kind() == WorkspaceErrorKind::NotFound => {
@@ -198,4 +331,355 @@ mod tests {
             other => panic!("Expected NotFound, got: {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn reserved_name_is_rejected_before_decide() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let reserved = ReservedWorkspaceNames::default();
+
+        let command = WorkspaceCommand::Create {
+            workspace_id: WorkspaceId::new(),
+            name: " Settings ".to_string(),
+            owner_id: UserId::new(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        };
+
+        let result = handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &reserved,
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await;
+        match result.expect_err("reserved name should fail") {
+            CommandPipelineError::Workspace(ref e)
+                if *e.kind() == WorkspaceErrorKind::ReservedName("Settings".to_string()) => {}
+            other => panic!("Expected ReservedName, got: {other:?}"),
+        }
+
+        let command = WorkspaceCommand::Create {
+            workspace_id: WorkspaceId::new(),
+            name: "Settings Review".to_string(),
+            owner_id: UserId::new(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        };
+
+        let events = handle_workspace_command(
+            repo,
+            NO_EVENT_BUS,
+            &reserved,
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await
+        .expect("non-reserved name should succeed");
+        assert_eq!(events.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn command_actor_is_recorded_in_audit_log() {
+        use crate::domain::views::audit_log_view;
+
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let reserved = ReservedWorkspaceNames::default();
+        let workspace_id = WorkspaceId::new();
+        let (owner, editor) = (UserId::new(), UserId::new());
+
+        let create = WorkspaceCommand::Create {
+            workspace_id,
+            name: "Audited".to_string(),
+            owner_id: owner,
+            visibility: Visibility::Private,
+            actor: owner,
+            created_at: Utc::now(),
+        };
+        let rename = WorkspaceCommand::Rename {
+            workspace_id,
+            new_name: "Audited Twice".to_string(),
+            actor: editor,
+            renamed_at: Utc::now(),
+        };
+        for command in [create, rename] {
+            handle_workspace_command(
+                Arc::clone(&repo),
+                NO_EVENT_BUS,
+                &reserved,
+                &WorkspaceAdministrators::default(),
+                command,
+            )
+            .await
+            .expect("command should succeed");
+        }
+
+        let view = audit_log_view();
+        let audit = repo
+            .query_all()
+            .await
+            .expect("query events")
+            .iter()
+            .fold((view.initial_state)(), |state, stored| {
+                (view.evolve)(&state, &stored.event)
+            });
+
+        let log: Vec<(UserId, &str)> = audit
+            .entries
+            .iter()
+            .map(|e| (e.actor, e.event_type.as_str()))
+            .collect();
+        assert_eq!(log, [(owner, "Created"), (editor, "Renamed")]);
+    }
+
+    #[tokio::test]
+    async fn past_timestamp_is_rejected() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let reserved = ReservedWorkspaceNames::default();
+        let workspace_id = WorkspaceId::new();
+        let created_at = Utc::now();
+
+        let command = WorkspaceCommand::Create {
+            workspace_id,
+            name: "Timeline".to_string(),
+            owner_id: UserId::new(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at,
+        };
+        handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &reserved,
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await
+        .expect("create should succeed");
+
+        let renamed_at = created_at - chrono::Duration::hours(1);
+        let command = WorkspaceCommand::Rename {
+            workspace_id,
+            new_name: "Rewritten".to_string(),
+            actor: UserId::system(),
+            renamed_at,
+        };
+        let result = handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &reserved,
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await;
+        match result.expect_err("past timestamp should fail") {
+            CommandPipelineError::NonMonotonicTimestamp {
+                latest, attempted, ..
+            } => {
+                assert_eq!(latest, created_at);
+                assert_eq!(attempted, renamed_at);
+            }
+            other => panic!("Expected NonMonotonicTimestamp, got: {other:?}"),
+        }
+
+        let command = WorkspaceCommand::Rename {
+            workspace_id,
+            new_name: "Same Instant".to_string(),
+            actor: UserId::system(),
+            renamed_at: created_at,
+        };
+        handle_workspace_command(
+            repo,
+            NO_EVENT_BUS,
+            &reserved,
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await
+        .expect("equal timestamp should succeed");
+    }
+
+    #[tokio::test]
+    async fn only_administrators_may_suspend() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let reserved = ReservedWorkspaceNames::default();
+        let owner = UserId::new();
+        let admin = UserId::new();
+        let administrators = WorkspaceAdministrators::new([admin]);
+        let workspace_id = WorkspaceId::new();
+
+        let command = WorkspaceCommand::Create {
+            workspace_id,
+            name: "Under Review".to_string(),
+            owner_id: owner,
+            visibility: Visibility::Private,
+            actor: owner,
+            created_at: Utc::now(),
+        };
+        handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &reserved,
+            &administrators,
+            command,
+        )
+        .await
+        .expect("create should succeed");
+
+        let suspend = |actor| WorkspaceCommand::Suspend {
+            workspace_id,
+            reason: "pending review".to_string(),
+            actor,
+            suspended_at: Utc::now(),
+        };
+        for actor in [owner, UserId::system()] {
+            let result = handle_workspace_command(
+                Arc::clone(&repo),
+                NO_EVENT_BUS,
+                &reserved,
+                &administrators,
+                suspend(actor),
+            )
+            .await;
+            match result.expect_err("non-administrator should be rejected") {
+                CommandPipelineError::Workspace(e)
+                    if *e.kind() == WorkspaceErrorKind::AdminRequired => {}
+                other => panic!("Expected AdminRequired, got: {other:?}"),
+            }
+        }
+
+        let events = handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &reserved,
+            &administrators,
+            suspend(admin),
+        )
+        .await
+        .expect("administrator may suspend");
+        assert_eq!(events.len(), 1);
+
+        let unsuspend = WorkspaceCommand::Unsuspend {
+            workspace_id,
+            actor: owner,
+            unsuspended_at: Utc::now(),
+        };
+        let result =
+            handle_workspace_command(repo, NO_EVENT_BUS, &reserved, &administrators, unsuspend)
+                .await;
+        assert!(result.is_err(), "owner may not lift a suspension");
+    }
+
+    type CapturedFields = Vec<(String, String)>;
+
+    /// Layer capturing the name and fields of every span, including fields
+    /// recorded after creation.
+    #[derive(Clone, Default)]
+    struct SpanCapture(Arc<std::sync::Mutex<Vec<(String, CapturedFields)>>>);
+
+    struct FieldVisitor<'a>(&'a mut CapturedFields);
+
+    impl tracing::field::Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.push((field.name().to_string(), value.to_string()));
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl<S> tracing_subscriber::Layer<S> for SpanCapture
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            _id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = Vec::new();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            self.0
+                .lock()
+                .expect("capture lock")
+                .push((attrs.metadata().name().to_string(), fields));
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let Some(span) = ctx.span(id) else { return };
+            let mut fields = Vec::new();
+            values.record(&mut FieldVisitor(&mut fields));
+            self.0
+                .lock()
+                .expect("capture lock")
+                .push((span.name().to_string(), fields));
+        }
+    }
+
+    #[tokio::test]
+    async fn handling_records_command_span() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let capture = SpanCapture::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool));
+        let workspace_id = WorkspaceId::new();
+
+        let command = WorkspaceCommand::Create {
+            workspace_id,
+            name: "Traced".to_string(),
+            owner_id: UserId::new(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        };
+        handle_workspace_command(
+            repo,
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await
+        .expect("command should succeed");
+
+        let fields: CapturedFields = capture
+            .0
+            .lock()
+            .expect("capture lock")
+            .iter()
+            .filter(|(name, _)| name == "command.workspace.handle")
+            .flat_map(|(_, fields)| fields.clone())
+            .collect();
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        assert_eq!(field("command_kind"), Some("Create"));
+        assert_eq!(
+            field("aggregate_id"),
+            Some(workspace_id.to_string().as_str())
+        );
+        assert_eq!(field("event_count"), Some("1"));
+        assert_eq!(field("outcome"), Some("ok"));
+    }
 }
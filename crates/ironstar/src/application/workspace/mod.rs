@@ -1,13 +1,25 @@
 //! Workspace aggregate application layer.
 //!
 //! This module wires the Workspace Decider and View to the SQLite event repository,
-//! providing both command handling and query services.
+//! providing both command handling and query services, plus export and
+//! import of a whole workspace as a bundle.
 
+mod administrators;
+mod bundle;
 mod handlers;
 mod queries;
+mod reserved_names;
+mod suspension;
 
+pub use administrators::WorkspaceAdministrators;
+pub use bundle::{
+    DashboardBundle, SavedQueryBundle, WorkspaceBundle, WorkspaceRepositories,
+    export_workspace_bundle, import_workspace_bundle,
+};
 pub use handlers::{handle_workspace_command, handle_workspace_command_zenoh};
 pub use queries::{
     query_dashboard_layout, query_saved_query_list, query_user_preferences, query_workspace_list,
-    query_workspaces_for_user,
+    query_workspace_preferences, query_workspaces_for_user,
 };
+pub use reserved_names::{DEFAULT_RESERVED_WORKSPACE_NAMES, ReservedWorkspaceNames};
+pub(crate) use suspension::ensure_not_suspended;
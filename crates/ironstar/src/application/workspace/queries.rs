@@ -5,17 +5,21 @@
 //! pattern as the catalog and todo query handlers.
 
 use crate::domain::dashboard::events::DashboardEvent;
+use crate::domain::dashboard::values::DashboardId;
 use crate::domain::saved_query::events::SavedQueryEvent;
 use crate::domain::session::UserId;
 use crate::domain::user_preferences::events::UserPreferencesEvent;
 use crate::domain::views::{
     DashboardLayoutViewState, SavedQueryListViewState, UserPreferencesViewState,
-    WorkspaceListViewState, dashboard_layout_view, saved_query_list_view, user_preferences_view,
-    workspace_list_view,
+    WorkspaceListViewState, WorkspacePreferencesViewState, dashboard_layout_view,
+    saved_query_list_view, user_preferences_view, workspace_list_view, workspace_preferences_view,
 };
 use crate::domain::workspace::events::WorkspaceEvent;
+use crate::domain::workspace::values::WorkspaceId;
+use crate::domain::workspace_preferences::WorkspacePreferencesEvent;
 use crate::infrastructure::error::InfrastructureError;
 use crate::infrastructure::event_store::SqliteEventRepository;
+use fmodel_rust::Identifier;
 
 /// Query workspace list state by replaying all workspace events through the view.
 ///
@@ -61,10 +65,10 @@ pub async fn query_workspaces_for_user<C>(
 /// Query the layout state for a specific dashboard by replaying its events.
 pub async fn query_dashboard_layout<C>(
     repo: &SqliteEventRepository<C, DashboardEvent>,
-    dashboard_id: &str,
+    dashboard_id: &DashboardId,
 ) -> Result<DashboardLayoutViewState, InfrastructureError> {
     let events = repo
-        .fetch_events_by_aggregate("Dashboard", dashboard_id)
+        .fetch_events_by_aggregate("Dashboard", &dashboard_id.identifier())
         .await?;
 
     let view = dashboard_layout_view();
@@ -122,14 +126,43 @@ pub async fn query_user_preferences<C>(
     Ok(state)
 }
 
+/// Query a workspace's preferences by replaying its preferences aggregate.
+///
+/// Uninitialized preferences yield the default state.
+pub async fn query_workspace_preferences<C>(
+    repo: &SqliteEventRepository<C, WorkspacePreferencesEvent>,
+    workspace_id: &WorkspaceId,
+) -> Result<WorkspacePreferencesViewState, InfrastructureError> {
+    let aggregate_id = format!("workspace_{workspace_id}/preferences");
+    let events = repo
+        .fetch_events_by_aggregate("WorkspacePreferences", &aggregate_id)
+        .await?;
+
+    let view = workspace_preferences_view();
+    let initial_state = (view.initial_state)();
+
+    let state = events
+        .iter()
+        .fold(initial_state, |state, (event, _version)| {
+            (view.evolve)(&state, event)
+        });
+
+    Ok(state)
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
     use super::*;
-    use crate::application::workspace::handle_workspace_command;
+    use crate::application::workspace::{
+        ReservedWorkspaceNames, WorkspaceAdministrators, handle_workspace_command,
+    };
+    use crate::application::workspace_preferences::handle_workspace_preferences_command;
     use crate::domain::workspace::commands::WorkspaceCommand;
     use crate::domain::workspace::values::{Visibility, WorkspaceId};
+    use crate::domain::workspace_preferences::{CatalogUri, WorkspacePreferencesCommand};
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
     use std::sync::Arc;
@@ -141,7 +174,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -175,11 +208,18 @@ mod tests {
             name: "Test Workspace".to_string(),
             owner_id: UserId::new(),
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
-        handle_workspace_command(Arc::clone(&repo), NO_EVENT_BUS, command)
-            .await
-            .expect("create should succeed");
+        handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            command,
+        )
+        .await
+        .expect("create should succeed");
 
         let state = query_workspace_list(&repo)
             .await
@@ -202,6 +242,7 @@ mod tests {
             name: "User1 WS".to_string(),
             owner_id: user1,
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
         let cmd2 = WorkspaceCommand::Create {
@@ -209,15 +250,28 @@ mod tests {
             name: "User2 WS".to_string(),
             owner_id: user2,
             visibility: Visibility::Private,
+            actor: UserId::system(),
             created_at: Utc::now(),
         };
 
-        handle_workspace_command(Arc::clone(&repo), NO_EVENT_BUS, cmd1)
-            .await
-            .expect("create should succeed");
-        handle_workspace_command(Arc::clone(&repo), NO_EVENT_BUS, cmd2)
-            .await
-            .expect("create should succeed");
+        handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            cmd1,
+        )
+        .await
+        .expect("create should succeed");
+        handle_workspace_command(
+            Arc::clone(&repo),
+            NO_EVENT_BUS,
+            &ReservedWorkspaceNames::default(),
+            &WorkspaceAdministrators::default(),
+            cmd2,
+        )
+        .await
+        .expect("create should succeed");
 
         let state = query_workspaces_for_user(&repo, &user1)
             .await
@@ -226,4 +280,43 @@ mod tests {
         assert_eq!(state.count, 1);
         assert_eq!(state.workspaces[0].owner_id, user1);
     }
+
+    #[tokio::test]
+    async fn query_workspace_preferences_folds_default_catalog() {
+        let pool = create_test_pool().await;
+        let repo: Arc<
+            SqliteEventRepository<WorkspacePreferencesCommand, WorkspacePreferencesEvent>,
+        > = Arc::new(SqliteEventRepository::new(pool));
+        let workspace_id = WorkspaceId::new();
+        let catalog_uri = CatalogUri::new("ducklake:space").expect("valid uri");
+
+        let uninitialized = query_workspace_preferences(&repo, &workspace_id)
+            .await
+            .expect("query should succeed");
+        assert!(!uninitialized.initialized);
+
+        for command in [
+            WorkspacePreferencesCommand::InitializeWorkspacePreferences {
+                workspace_id,
+                initialized_at: Utc::now(),
+            },
+            WorkspacePreferencesCommand::SetDefaultCatalog {
+                workspace_id,
+                catalog_uri: catalog_uri.clone(),
+                set_at: Utc::now(),
+            },
+        ] {
+            handle_workspace_preferences_command(Arc::clone(&repo), NO_EVENT_BUS, command)
+                .await
+                .expect("command should succeed");
+        }
+
+        let state = query_workspace_preferences(&repo, &workspace_id)
+            .await
+            .expect("query should succeed");
+
+        assert!(state.initialized);
+        assert_eq!(state.workspace_id, Some(workspace_id));
+        assert_eq!(state.default_catalog, Some(catalog_uri));
+    }
 }
@@ -0,0 +1,97 @@
+//! Reserved workspace names.
+//!
+//! Workspace names appear in URLs, so names such as `new`, `settings`, or
+//! `api` would collide with route segments. This is a routing concern rather
+//! than a domain rule, so the check runs in the command handlers before the
+//! decider sees `Create` or `Rename`. Matching ignores case and surrounding
+//! whitespace, mirroring how the decider normalizes names.
+
+use crate::domain::workspace::{WorkspaceCommand, WorkspaceError};
+
+/// Names rejected when no reserved list is configured.
+pub const DEFAULT_RESERVED_WORKSPACE_NAMES: &[&str] = &[
+    "new", "settings", "api", "admin", "login", "logout", "static", "metrics", "health",
+];
+
+/// Configurable set of workspace names that may not be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservedWorkspaceNames(Vec<String>);
+
+impl Default for ReservedWorkspaceNames {
+    fn default() -> Self {
+        Self::new(DEFAULT_RESERVED_WORKSPACE_NAMES.iter().copied())
+    }
+}
+
+impl ReservedWorkspaceNames {
+    /// Create a reserved list from the given names.
+    #[must_use]
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self(
+            names
+                .into_iter()
+                .map(|name| normalize(name.as_ref()))
+                .collect(),
+        )
+    }
+
+    /// Whether `name` is reserved, ignoring case and surrounding whitespace.
+    #[must_use]
+    pub fn is_reserved(&self, name: &str) -> bool {
+        let name = normalize(name);
+        self.0.contains(&name)
+    }
+
+    /// Reject `Create` and `Rename` commands that use a reserved name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ReservedName` error naming the rejected input.
+    pub fn check(&self, command: &WorkspaceCommand) -> Result<(), WorkspaceError> {
+        let name = match command {
+            WorkspaceCommand::Create { name, .. } => name,
+            WorkspaceCommand::Rename { new_name, .. } => new_name,
+            WorkspaceCommand::SetVisibility { .. }
+            | WorkspaceCommand::Suspend { .. }
+            | WorkspaceCommand::Unsuspend { .. }
+            | WorkspaceCommand::Archive { .. } => {
+                return Ok(());
+            }
+        };
+
+        if self.is_reserved(name) {
+            return Err(WorkspaceError::reserved_name(name.trim()));
+        }
+        Ok(())
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_ignores_case_and_whitespace() {
+        let reserved = ReservedWorkspaceNames::default();
+
+        assert!(reserved.is_reserved("settings"));
+        assert!(reserved.is_reserved("  API "));
+        assert!(!reserved.is_reserved("Analytics"));
+    }
+
+    #[test]
+    fn custom_list_replaces_defaults() {
+        let reserved = ReservedWorkspaceNames::new(["Reports"]);
+
+        assert!(reserved.is_reserved("reports"));
+        assert!(!reserved.is_reserved("settings"));
+    }
+}
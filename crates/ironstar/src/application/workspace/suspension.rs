@@ -0,0 +1,53 @@
+//! Suspended workspaces.
+//!
+//! A suspended workspace is frozen pending review: nothing in it may change
+//! until an administrator unsuspends it. The workspace decider enforces that
+//! for workspace commands, but dashboards and saved queries are aggregates of
+//! their own that only name their workspace, so their deciders cannot see the
+//! suspension. The dashboard and saved query command handlers check it before
+//! the decider runs instead, the way workspace commands are checked against
+//! [`ReservedWorkspaceNames`](super::ReservedWorkspaceNames) and
+//! [`WorkspaceAdministrators`](super::WorkspaceAdministrators).
+
+use sqlx::SqlitePool;
+
+use crate::application::error::CommandPipelineError;
+use crate::domain::workspace::{
+    WorkspaceCommand, WorkspaceError, WorkspaceEvent, WorkspaceId, WorkspaceStatus,
+    workspace_decider,
+};
+use crate::infrastructure::event_store::SqliteEventRepository;
+use fmodel_rust::Identifier;
+
+/// Reject a change inside `workspace_id` while it is suspended.
+///
+/// The workspace's stream is read from the event store behind `pool`. An
+/// unknown workspace is not suspended, so the command is left to its decider.
+///
+/// # Errors
+///
+/// Returns a `WorkspaceSuspended` error carrying the suspension reason, and
+/// any error from loading events.
+pub(crate) async fn ensure_not_suspended(
+    pool: &SqlitePool,
+    workspace_id: &WorkspaceId,
+) -> Result<(), CommandPipelineError> {
+    let workspaces: SqliteEventRepository<WorkspaceCommand, WorkspaceEvent> =
+        SqliteEventRepository::new(pool.clone());
+    let events = workspaces
+        .fetch_events_by_aggregate("Workspace", &workspace_id.identifier())
+        .await?;
+    let decider = workspace_decider();
+    let workspace = events
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _version)| {
+            (decider.evolve)(&state, event)
+        });
+
+    match workspace.status {
+        WorkspaceStatus::Suspended { reason } => Err(CommandPipelineError::Workspace(
+            WorkspaceError::suspended(reason),
+        )),
+        _ => Ok(()),
+    }
+}
@@ -6,6 +6,7 @@
 //! `CommandPipelineError`.
 
 use crate::application::error::CommandPipelineError;
+use crate::application::timestamps::LatestTimestamp;
 use crate::domain::workspace_preferences::{
     WorkspacePreferencesCommand, WorkspacePreferencesError, WorkspacePreferencesEvent,
     workspace_preferences_decider,
@@ -18,13 +19,17 @@ use std::sync::Arc;
 /// Adapter wrapping SqliteEventRepository to map errors to CommandPipelineError.
 pub struct WorkspacePreferencesEventRepositoryAdapter {
     inner: Arc<SqliteEventRepository<WorkspacePreferencesCommand, WorkspacePreferencesEvent>>,
+    latest: LatestTimestamp,
 }
 
 impl WorkspacePreferencesEventRepositoryAdapter {
     pub fn new(
         inner: Arc<SqliteEventRepository<WorkspacePreferencesCommand, WorkspacePreferencesEvent>>,
     ) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            latest: LatestTimestamp::default(),
+        }
     }
 }
 
@@ -40,13 +45,16 @@ impl
         &self,
         command: &WorkspacePreferencesCommand,
     ) -> Result<Vec<(WorkspacePreferencesEvent, String)>, CommandPipelineError> {
-        self.inner.fetch_events(command).await.map_err(Into::into)
+        let events = self.inner.fetch_events(command).await?;
+        self.latest.observe(&events);
+        Ok(events)
     }
 
     async fn save(
         &self,
         events: &[WorkspacePreferencesEvent],
     ) -> Result<Vec<(WorkspacePreferencesEvent, String)>, CommandPipelineError> {
+        self.latest.check(events)?;
         self.inner.save(events).await.map_err(Into::into)
     }
 
@@ -123,6 +131,7 @@ mod tests {
     use crate::domain::workspace::WorkspaceId;
     use crate::domain::workspace_preferences::{CatalogUri, WorkspacePreferencesErrorKind};
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use chrono::Utc;
     use sqlx::sqlite::SqlitePoolOptions;
     use uuid::Uuid;
@@ -134,7 +143,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
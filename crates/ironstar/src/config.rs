@@ -15,7 +15,16 @@
 //! | `IRONSTAR_ENABLE_ANALYTICS` | true | Enable DuckDB analytics pool |
 //! | `IRONSTAR_ANALYTICS_PATH` | (none) | DuckDB database path (in-memory if unset) |
 //! | `IRONSTAR_ANALYTICS_NUM_CONNS` | 4 | Number of DuckDB connections in pool |
+//! | `IRONSTAR_ANALYTICS_MEMORY_LIMIT` | 2GB | DuckDB memory limit per connection |
+//! | `IRONSTAR_ANALYTICS_THREADS` | (none) | DuckDB worker threads (one per core if unset) |
+//! | `IRONSTAR_ANALYTICS_TEMP_DIR` | (none) | DuckDB spill directory (DuckDB default if unset) |
+//! | `IRONSTAR_ANALYTICS_READ_ONLY` | false | Open the DuckDB file read-only (requires `IRONSTAR_ANALYTICS_PATH`) |
 //! | `IRONSTAR_SHUTDOWN_TIMEOUT_SECS` | 30 | Graceful shutdown timeout |
+//! | `IRONSTAR_MAX_REQUEST_BODY_BYTES` | 262144 | Request body limit (256 KiB) |
+//! | `IRONSTAR_ANALYTICS_MAX_REQUEST_BODY_BYTES` | 1048576 | Request body limit for analytics routes (1 MiB) |
+//! | `IRONSTAR_METRICS_TOKEN` | (none) | Bearer token required by `/metrics` (all scrapes rejected if unset) |
+//! | `IRONSTAR_EVENT_COMPRESSION_THRESHOLD` | 4096 | Event payloads at least this many bytes are stored zstd-compressed (`off` disables) |
+//! | `IRONSTAR_WORKSPACE_ADMINISTRATORS` | (none) | Comma-separated user ids who may suspend workspaces (nobody may if unset) |
 //!
 //! Standard variables (no prefix):
 //!
@@ -28,6 +37,16 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use uuid::Uuid;
+
+use crate::application::WorkspaceAdministrators;
+use crate::domain::UserId;
+use crate::infrastructure::analytics::{DEFAULT_MEMORY_LIMIT, DuckDbConfig};
+use crate::infrastructure::event_store::{DEFAULT_COMPRESSION_THRESHOLD, PayloadCompression};
+use crate::presentation::body_limit::{
+    DEFAULT_ANALYTICS_REQUEST_BODY_LIMIT, DEFAULT_REQUEST_BODY_LIMIT, RequestBodyLimits,
+};
+
 /// Application configuration loaded from environment variables.
 ///
 /// Use [`Config::from_env()`] to load configuration at startup.
@@ -59,11 +78,48 @@ pub struct Config {
     /// Number of DuckDB connections in the analytics pool.
     pub analytics_num_conns: usize,
 
+    /// DuckDB memory limit in DuckDB size syntax, e.g. `2GB`.
+    pub analytics_memory_limit: String,
+
+    /// DuckDB worker threads; `None` keeps DuckDB's default.
+    pub analytics_threads: Option<usize>,
+
+    /// DuckDB spill directory; `None` keeps DuckDB's default.
+    pub analytics_temp_directory: Option<PathBuf>,
+
+    /// Whether to open the DuckDB database file read-only.
+    ///
+    /// Requires `analytics_database_path`; in-memory databases cannot be
+    /// opened read-only.
+    pub analytics_read_only: bool,
+
     /// Graceful shutdown timeout.
     ///
     /// When shutdown is signaled, the server waits this long for in-flight
     /// requests to complete before forcefully terminating.
     pub shutdown_timeout: Duration,
+
+    /// Maximum request body size in bytes; larger requests get 413.
+    pub max_request_body_bytes: usize,
+
+    /// Maximum request body size in bytes for analytics routes, which
+    /// accept ad hoc SQL.
+    pub analytics_max_request_body_bytes: usize,
+
+    /// Bearer token required to scrape `/metrics`.
+    ///
+    /// When `None`, every scrape is rejected.
+    pub metrics_token: Option<String>,
+
+    /// Minimum event payload size, in bytes, stored zstd-compressed.
+    ///
+    /// When `None`, payloads are always stored as plain JSON.
+    pub event_compression_threshold: Option<usize>,
+
+    /// Users who may suspend and unsuspend workspaces.
+    ///
+    /// When empty, nobody may.
+    pub workspace_administrators: Vec<UserId>,
 }
 
 impl Config {
@@ -112,6 +168,28 @@ impl Config {
             })
             .unwrap_or(4);
 
+        let analytics_memory_limit = env::var("IRONSTAR_ANALYTICS_MEMORY_LIMIT")
+            .unwrap_or_else(|_| DEFAULT_MEMORY_LIMIT.to_string());
+
+        let analytics_threads: Option<usize> =
+            env::var("IRONSTAR_ANALYTICS_THREADS").ok().and_then(|s| {
+                s.parse().ok().or_else(|| {
+                    tracing::warn!(
+                        value = %s,
+                        "Invalid IRONSTAR_ANALYTICS_THREADS value, using default"
+                    );
+                    None
+                })
+            });
+
+        let analytics_temp_directory = env::var("IRONSTAR_ANALYTICS_TEMP_DIR")
+            .ok()
+            .map(PathBuf::from);
+
+        let analytics_read_only = env::var("IRONSTAR_ANALYTICS_READ_ONLY")
+            .map(|s| matches!(s.to_lowercase().as_str(), "true" | "1" | "yes"))
+            .unwrap_or(false);
+
         let shutdown_timeout_secs: u64 = env::var("IRONSTAR_SHUTDOWN_TIMEOUT_SECS")
             .ok()
             .and_then(|s| {
@@ -125,6 +203,53 @@ impl Config {
             })
             .unwrap_or(30);
 
+        let max_request_body_bytes: usize = env::var("IRONSTAR_MAX_REQUEST_BODY_BYTES")
+            .ok()
+            .and_then(|s| {
+                s.parse().ok().or_else(|| {
+                    tracing::warn!(
+                        value = %s,
+                        "Invalid IRONSTAR_MAX_REQUEST_BODY_BYTES value, using default"
+                    );
+                    None
+                })
+            })
+            .unwrap_or(DEFAULT_REQUEST_BODY_LIMIT);
+
+        let analytics_max_request_body_bytes: usize =
+            env::var("IRONSTAR_ANALYTICS_MAX_REQUEST_BODY_BYTES")
+                .ok()
+                .and_then(|s| {
+                    s.parse().ok().or_else(|| {
+                        tracing::warn!(
+                            value = %s,
+                            "Invalid IRONSTAR_ANALYTICS_MAX_REQUEST_BODY_BYTES value, using default"
+                        );
+                        None
+                    })
+                })
+                .unwrap_or(DEFAULT_ANALYTICS_REQUEST_BODY_LIMIT);
+
+        let metrics_token = env::var("IRONSTAR_METRICS_TOKEN")
+            .ok()
+            .filter(|s| !s.is_empty());
+
+        let event_compression_threshold = match env::var("IRONSTAR_EVENT_COMPRESSION_THRESHOLD") {
+            Ok(s) if matches!(s.to_lowercase().as_str(), "off" | "false" | "no") => None,
+            Ok(s) => Some(s.parse().unwrap_or_else(|_| {
+                tracing::warn!(
+                    value = %s,
+                    "Invalid IRONSTAR_EVENT_COMPRESSION_THRESHOLD value, using default"
+                );
+                DEFAULT_COMPRESSION_THRESHOLD
+            })),
+            Err(_) => Some(DEFAULT_COMPRESSION_THRESHOLD),
+        };
+
+        let workspace_administrators = env::var("IRONSTAR_WORKSPACE_ADMINISTRATORS")
+            .map(|s| parse_user_ids(&s))
+            .unwrap_or_default();
+
         Self {
             port,
             database_url,
@@ -132,7 +257,16 @@ impl Config {
             enable_analytics,
             analytics_database_path,
             analytics_num_conns,
+            analytics_memory_limit,
+            analytics_threads,
+            analytics_temp_directory,
+            analytics_read_only,
             shutdown_timeout: Duration::from_secs(shutdown_timeout_secs),
+            max_request_body_bytes,
+            analytics_max_request_body_bytes,
+            metrics_token,
+            event_compression_threshold,
+            workspace_administrators,
         }
     }
 
@@ -142,6 +276,39 @@ impl Config {
         SocketAddr::from(([0, 0, 0, 0], self.port))
     }
 
+    /// DuckDB resource limits for the analytics pool.
+    #[must_use]
+    pub fn duckdb_config(&self) -> DuckDbConfig {
+        DuckDbConfig {
+            memory_limit: self.analytics_memory_limit.clone(),
+            threads: self.analytics_threads,
+            temp_directory: self.analytics_temp_directory.clone(),
+            read_only: self.analytics_read_only,
+        }
+    }
+
+    /// Payload compression for the event repositories, if enabled.
+    #[must_use]
+    pub fn event_compression(&self) -> Option<PayloadCompression> {
+        self.event_compression_threshold
+            .map(PayloadCompression::with_threshold)
+    }
+
+    /// Request body limits enforced by the router.
+    #[must_use]
+    pub fn request_body_limits(&self) -> RequestBodyLimits {
+        RequestBodyLimits {
+            default: self.max_request_body_bytes,
+            analytics: self.analytics_max_request_body_bytes,
+        }
+    }
+
+    /// Users who may suspend and unsuspend workspaces.
+    #[must_use]
+    pub fn workspace_administrators(&self) -> WorkspaceAdministrators {
+        WorkspaceAdministrators::new(self.workspace_administrators.iter().copied())
+    }
+
     /// Get the database path for directory creation.
     ///
     /// Returns `None` for in-memory databases (`:memory:` or URLs starting with
@@ -174,11 +341,40 @@ impl Default for Config {
             enable_analytics: true,
             analytics_database_path: None,
             analytics_num_conns: 4,
+            analytics_memory_limit: DEFAULT_MEMORY_LIMIT.to_string(),
+            analytics_threads: None,
+            analytics_temp_directory: None,
+            analytics_read_only: false,
             shutdown_timeout: Duration::from_secs(30),
+            max_request_body_bytes: DEFAULT_REQUEST_BODY_LIMIT,
+            analytics_max_request_body_bytes: DEFAULT_ANALYTICS_REQUEST_BODY_LIMIT,
+            metrics_token: None,
+            event_compression_threshold: Some(DEFAULT_COMPRESSION_THRESHOLD),
+            workspace_administrators: Vec::new(),
         }
     }
 }
 
+/// Parse a comma-separated list of user ids, skipping blank entries.
+///
+/// Invalid ids are logged as warnings and left out.
+fn parse_user_ids(list: &str) -> Vec<UserId> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| match Uuid::parse_str(s) {
+            Ok(uuid) => Some(UserId::from_uuid(uuid)),
+            Err(_) => {
+                tracing::warn!(
+                    value = %s,
+                    "Invalid IRONSTAR_WORKSPACE_ADMINISTRATORS entry, skipping"
+                );
+                None
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,7 +388,29 @@ mod tests {
         assert!(config.enable_analytics);
         assert!(config.analytics_database_path.is_none());
         assert_eq!(config.analytics_num_conns, 4);
+        assert_eq!(config.duckdb_config(), DuckDbConfig::default());
         assert_eq!(config.shutdown_timeout, Duration::from_secs(30));
+        assert_eq!(config.request_body_limits(), RequestBodyLimits::default());
+        assert!(config.metrics_token.is_none());
+        assert_eq!(
+            config.workspace_administrators(),
+            WorkspaceAdministrators::default()
+        );
+        assert_eq!(
+            config.event_compression(),
+            Some(PayloadCompression::default())
+        );
+    }
+
+    #[test]
+    fn user_ids_parse_from_a_comma_separated_list() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        assert_eq!(
+            parse_user_ids(&format!(" {a}, ,not-a-uuid,{b},")),
+            vec![UserId::from_uuid(a), UserId::from_uuid(b)]
+        );
+        assert!(parse_user_ids("").is_empty());
     }
 
     #[test]
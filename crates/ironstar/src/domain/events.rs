@@ -0,0 +1,320 @@
+//! Unified event type spanning every aggregate.
+//!
+//! Projections otherwise subscribe once per aggregate. [`IronstarEvent`]
+//! wraps each aggregate's events in nested [`Sum`]s grouped by bounded
+//! context, so a single subscriber can route on the variant:
+//!
+//! | Pattern | Event |
+//! |---------|-------|
+//! | `First(First(_))` | [`WorkspaceEvent`] |
+//! | `First(Second(First(_)))` | [`DashboardEvent`] |
+//! | `First(Second(Second(_)))` | [`SavedQueryEvent`] |
+//! | `Second(First(First(_)))` | [`CatalogEvent`] |
+//! | `Second(First(Second(_)))` | [`QuerySessionEvent`] |
+//! | `Second(Second(First(_)))` | [`TodoEvent`] |
+//! | `Second(Second(Second(_)))` | [`SessionEvent`] |
+//!
+//! The analytics branch is [`AnalyticsEvent`], the event type of the
+//! combined analytics decider, so its events pass through unchanged.
+//!
+//! Each aggregate event converts in with `From` and back out with
+//! `TryFrom`, which returns the unified event on a mismatch so routing can
+//! try the next aggregate.
+
+use ironstar_core::Sum;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::analytics::AnalyticsEvent;
+use crate::domain::catalog::CatalogEvent;
+use crate::domain::dashboard::DashboardEvent;
+use crate::domain::query_session::QuerySessionEvent;
+use crate::domain::saved_query::SavedQueryEvent;
+use crate::domain::session::SessionEvent;
+use crate::domain::todo::TodoEvent;
+use crate::domain::traits::{EventType, Identifier};
+use crate::domain::workspace::WorkspaceEvent;
+
+/// Events of the workspace bounded context.
+pub type WorkspaceContextEvent = Sum<WorkspaceEvent, Sum<DashboardEvent, SavedQueryEvent>>;
+
+/// Events of the todo and session aggregates.
+pub type TodoSessionEvent = Sum<TodoEvent, SessionEvent>;
+
+/// Any event emitted by any aggregate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IronstarEvent(pub Sum<WorkspaceContextEvent, Sum<AnalyticsEvent, TodoSessionEvent>>);
+
+impl Identifier for IronstarEvent {
+    fn identifier(&self) -> String {
+        match &self.0 {
+            Sum::First(Sum::First(e)) => Identifier::identifier(e),
+            Sum::First(Sum::Second(Sum::First(e))) => Identifier::identifier(e),
+            Sum::First(Sum::Second(Sum::Second(e))) => Identifier::identifier(e),
+            Sum::Second(Sum::First(Sum::First(e))) => Identifier::identifier(e),
+            Sum::Second(Sum::First(Sum::Second(e))) => Identifier::identifier(e),
+            Sum::Second(Sum::Second(Sum::First(e))) => Identifier::identifier(e),
+            Sum::Second(Sum::Second(Sum::Second(e))) => Identifier::identifier(e),
+        }
+    }
+}
+
+impl EventType for IronstarEvent {
+    fn event_type(&self) -> String {
+        match &self.0 {
+            Sum::First(Sum::First(e)) => EventType::event_type(e),
+            Sum::First(Sum::Second(Sum::First(e))) => EventType::event_type(e),
+            Sum::First(Sum::Second(Sum::Second(e))) => EventType::event_type(e),
+            Sum::Second(Sum::First(Sum::First(e))) => EventType::event_type(e),
+            Sum::Second(Sum::First(Sum::Second(e))) => EventType::event_type(e),
+            Sum::Second(Sum::Second(Sum::First(e))) => EventType::event_type(e),
+            Sum::Second(Sum::Second(Sum::Second(e))) => EventType::event_type(e),
+        }
+    }
+}
+
+impl From<WorkspaceEvent> for IronstarEvent {
+    fn from(event: WorkspaceEvent) -> Self {
+        Self(Sum::First(Sum::First(event)))
+    }
+}
+
+impl From<DashboardEvent> for IronstarEvent {
+    fn from(event: DashboardEvent) -> Self {
+        Self(Sum::First(Sum::Second(Sum::First(event))))
+    }
+}
+
+impl From<SavedQueryEvent> for IronstarEvent {
+    fn from(event: SavedQueryEvent) -> Self {
+        Self(Sum::First(Sum::Second(Sum::Second(event))))
+    }
+}
+
+impl From<AnalyticsEvent> for IronstarEvent {
+    fn from(event: AnalyticsEvent) -> Self {
+        Self(Sum::Second(Sum::First(event)))
+    }
+}
+
+impl From<CatalogEvent> for IronstarEvent {
+    fn from(event: CatalogEvent) -> Self {
+        Self::from(AnalyticsEvent::First(event))
+    }
+}
+
+impl From<QuerySessionEvent> for IronstarEvent {
+    fn from(event: QuerySessionEvent) -> Self {
+        Self::from(AnalyticsEvent::Second(event))
+    }
+}
+
+impl From<TodoEvent> for IronstarEvent {
+    fn from(event: TodoEvent) -> Self {
+        Self(Sum::Second(Sum::Second(Sum::First(event))))
+    }
+}
+
+impl From<SessionEvent> for IronstarEvent {
+    fn from(event: SessionEvent) -> Self {
+        Self(Sum::Second(Sum::Second(Sum::Second(event))))
+    }
+}
+
+impl TryFrom<IronstarEvent> for WorkspaceEvent {
+    type Error = IronstarEvent;
+
+    fn try_from(event: IronstarEvent) -> Result<Self, Self::Error> {
+        match event.0 {
+            Sum::First(Sum::First(e)) => Ok(e),
+            other => Err(IronstarEvent(other)),
+        }
+    }
+}
+
+impl TryFrom<IronstarEvent> for DashboardEvent {
+    type Error = IronstarEvent;
+
+    fn try_from(event: IronstarEvent) -> Result<Self, Self::Error> {
+        match event.0 {
+            Sum::First(Sum::Second(Sum::First(e))) => Ok(e),
+            other => Err(IronstarEvent(other)),
+        }
+    }
+}
+
+impl TryFrom<IronstarEvent> for SavedQueryEvent {
+    type Error = IronstarEvent;
+
+    fn try_from(event: IronstarEvent) -> Result<Self, Self::Error> {
+        match event.0 {
+            Sum::First(Sum::Second(Sum::Second(e))) => Ok(e),
+            other => Err(IronstarEvent(other)),
+        }
+    }
+}
+
+impl TryFrom<IronstarEvent> for CatalogEvent {
+    type Error = IronstarEvent;
+
+    fn try_from(event: IronstarEvent) -> Result<Self, Self::Error> {
+        match event.0 {
+            Sum::Second(Sum::First(Sum::First(e))) => Ok(e),
+            other => Err(IronstarEvent(other)),
+        }
+    }
+}
+
+impl TryFrom<IronstarEvent> for QuerySessionEvent {
+    type Error = IronstarEvent;
+
+    fn try_from(event: IronstarEvent) -> Result<Self, Self::Error> {
+        match event.0 {
+            Sum::Second(Sum::First(Sum::Second(e))) => Ok(e),
+            other => Err(IronstarEvent(other)),
+        }
+    }
+}
+
+impl TryFrom<IronstarEvent> for TodoEvent {
+    type Error = IronstarEvent;
+
+    fn try_from(event: IronstarEvent) -> Result<Self, Self::Error> {
+        match event.0 {
+            Sum::Second(Sum::Second(Sum::First(e))) => Ok(e),
+            other => Err(IronstarEvent(other)),
+        }
+    }
+}
+
+impl TryFrom<IronstarEvent> for SessionEvent {
+    type Error = IronstarEvent;
+
+    fn try_from(event: IronstarEvent) -> Result<Self, Self::Error> {
+        match event.0 {
+            Sum::Second(Sum::Second(Sum::Second(e))) => Ok(e),
+            other => Err(IronstarEvent(other)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::domain::analytics::{QueryId, SqlQuery};
+    use crate::domain::catalog::CatalogRef;
+    use crate::domain::dashboard::DashboardId;
+    use crate::domain::saved_query::SavedQueryId;
+    use crate::domain::session::{SessionId, UserId};
+    use crate::domain::todo::TodoId;
+    use crate::domain::workspace::WorkspaceId;
+    use chrono::{DateTime, Utc};
+    use std::fmt::Debug;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .expect("valid timestamp")
+            .with_timezone(&Utc)
+    }
+
+    /// Convert `event` in, through JSON, and back out.
+    fn assert_round_trips<E>(event: E)
+    where
+        E: Into<IronstarEvent> + TryFrom<IronstarEvent> + Clone + PartialEq + Debug,
+        <E as TryFrom<IronstarEvent>>::Error: Debug,
+    {
+        let unified: IronstarEvent = event.clone().into();
+        let json = serde_json::to_string(&unified).expect("serialize unified event");
+        let decoded: IronstarEvent = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(decoded, unified);
+        assert_eq!(E::try_from(decoded).expect("same aggregate"), event);
+    }
+
+    #[test]
+    fn workspace_event_round_trips() {
+        assert_round_trips(WorkspaceEvent::Archived {
+            workspace_id: WorkspaceId::new(),
+            actor: UserId::system(),
+            archived_at: sample_time(),
+        });
+    }
+
+    #[test]
+    fn dashboard_event_round_trips() {
+        assert_round_trips(DashboardEvent::DashboardArchived {
+            dashboard_id: DashboardId::new(),
+            actor: UserId::system(),
+            archived_at: sample_time(),
+        });
+    }
+
+    #[test]
+    fn saved_query_event_round_trips() {
+        assert_round_trips(SavedQueryEvent::QueryDeleted {
+            query_id: SavedQueryId::new(),
+            actor: UserId::system(),
+            deleted_at: sample_time(),
+        });
+    }
+
+    #[test]
+    fn catalog_event_round_trips() {
+        assert_round_trips(CatalogEvent::CatalogSelected {
+            catalog_ref: CatalogRef::try_from("ducklake:test".to_string())
+                .expect("valid catalog ref"),
+            selected_at: sample_time(),
+        });
+    }
+
+    #[test]
+    fn query_session_event_round_trips() {
+        assert_round_trips(QuerySessionEvent::QueryStarted {
+            query_id: QueryId::new(),
+            sql: SqlQuery::try_from("SELECT 1".to_string()).expect("valid SQL"),
+            dataset_ref: None,
+            chart_config: None,
+            started_at: sample_time(),
+            actor: UserId::system(),
+        });
+    }
+
+    #[test]
+    fn todo_event_round_trips() {
+        assert_round_trips(TodoEvent::Completed {
+            id: TodoId::new(),
+            completed_at: sample_time(),
+        });
+    }
+
+    #[test]
+    fn session_event_round_trips() {
+        assert_round_trips(SessionEvent::Invalidated {
+            session_id: SessionId::new(),
+            invalidated_at: sample_time(),
+        });
+    }
+
+    #[test]
+    fn mismatched_aggregate_returns_event() {
+        let unified = IronstarEvent::from(TodoEvent::Completed {
+            id: TodoId::new(),
+            completed_at: sample_time(),
+        });
+
+        let returned = WorkspaceEvent::try_from(unified.clone()).expect_err("not a workspace");
+        assert_eq!(returned, unified);
+    }
+
+    #[test]
+    fn identity_delegates_to_aggregate_event() {
+        let event = SessionEvent::Invalidated {
+            session_id: SessionId::new(),
+            invalidated_at: sample_time(),
+        };
+        let unified = IronstarEvent::from(event.clone());
+
+        assert_eq!(unified.identifier(), event.identifier());
+        assert_eq!(unified.event_type(), "Invalidated");
+    }
+}
@@ -8,6 +8,7 @@
 //!
 //! - [`analytics`]: Analytics value objects (QueryId, DatasetRef, SqlQuery, ChartConfig)
 //! - [`common`]: Shared value objects (BoundedString, DashboardTitle, TabTitle, GridSize)
+//! - [`events`]: Unified event type spanning every aggregate
 //! - [`query_session`]: QuerySession decider (commands, events, state, errors)
 //! - [`session`]: Session decider for authentication lifecycle (Shared Kernel: UserId)
 //! - [`todo`]: Todo decider (commands, events, state, values, errors)
@@ -109,6 +110,16 @@ pub mod workspace_preferences {
     pub use ironstar_workspace::workspace_preferences::*;
 }
 
+pub mod sagas {
+    //! Saga re-exports from `ironstar-workspace` crate.
+    pub use ironstar_workspace::sagas::preferences_initialization::{
+        PreferencesInitializationSaga, preferences_initialization_saga,
+    };
+    pub use ironstar_workspace::sagas::workspace_deletion::{
+        WorkspaceChildCommand, WorkspaceDeletionSaga, workspace_deletion_saga,
+    };
+}
+
 pub mod common {
     //! Common domain value objects re-exported from `ironstar-core`.
     pub mod values {
@@ -133,9 +144,14 @@ pub mod error {
     };
 }
 
+pub mod clock {
+    //! Injectable clock re-exported from `ironstar-core`.
+    pub use ironstar_core::clock::{Clock, FixedClock, SystemClock};
+}
+
 pub mod traits {
     //! fmodel-rust identifier trait and ironstar-specific marker traits.
-    pub use ironstar_core::traits::{DeciderType, EventType, Identifier, IsFinal};
+    pub use ironstar_core::traits::{DeciderType, EventType, Identifier, IsFinal, Timestamped};
 }
 
 pub mod views {
@@ -156,20 +172,32 @@ pub mod views {
         pub use ironstar_workspace::views::workspace::*;
     }
 
+    pub mod audit {
+        //! Audit log view re-exports from `ironstar-workspace` crate.
+        pub use ironstar_workspace::views::audit::*;
+    }
+
+    pub use audit::{AuditEntry, AuditLogView, AuditLogViewState, Audited, audit_log_view};
     pub use catalog::{CatalogView, CatalogViewState, catalog_view};
     pub use ironstar_todo::{TodoItemView, TodoView, TodoViewState, todo_view};
+    pub use ironstar_workspace::combine_views;
     pub use query_session::{
-        QueryHistoryEntry, QueryOutcome, QuerySessionView, QuerySessionViewState,
-        query_session_view,
+        DEFAULT_MAX_HISTORY, QueryHistoryEntry, QueryOutcome, QuerySessionView,
+        QuerySessionViewState, query_session_view, query_session_view_with_max_history,
     };
     pub use workspace::{
         DashboardLayoutView, DashboardLayoutViewState, SavedQueryListEntry, SavedQueryListView,
-        SavedQueryListViewState, UserPreferencesView, UserPreferencesViewState, WorkspaceListEntry,
-        WorkspaceListView, WorkspaceListViewState, dashboard_layout_view, saved_query_list_view,
-        user_preferences_view, workspace_list_view,
+        SavedQueryListViewState, SlugRedirectView, SlugRedirectViewState, UserPreferencesView,
+        UserPreferencesViewState, WorkspaceListEntry, WorkspaceListView, WorkspaceListViewState,
+        WorkspacePreferencesView, WorkspacePreferencesViewState, dashboard_layout_view,
+        saved_query_list_view, slug_redirect_view, user_preferences_view, workspace_list_view,
+        workspace_preferences_view,
     };
 }
 
+// Unified event type composed from the aggregate events above
+pub mod events;
+
 // Signals module kept as a real file (429 lines of original code)
 pub mod signals;
 
@@ -185,14 +213,15 @@ pub use todo::{
 // Analytics re-exports
 pub use analytics::{
     AnalyticsError, AnalyticsErrorKind, AnalyticsValidationError, AnalyticsValidationErrorKind,
-    ChartConfig, ChartType, DATASET_REF_MAX_LENGTH, DatasetRef, QueryId, SQL_QUERY_MAX_LENGTH,
-    SqlQuery,
+    CancellationReason, ChartConfig, ChartType, DATASET_REF_MAX_LENGTH, DatasetRef, QueryId,
+    SQL_QUERY_MAX_LENGTH, SqlQuery,
 };
 
 // Catalog re-exports
 pub use catalog::{
     CATALOG_REF_MAX_LENGTH, CatalogCommand, CatalogDecider, CatalogError, CatalogErrorKind,
-    CatalogEvent, CatalogMetadata, CatalogRef, CatalogState, DatasetInfo, catalog_decider,
+    CatalogEvent, CatalogMetadata, CatalogMetadataDiff, CatalogRef, CatalogState, DatasetInfo,
+    catalog_decider,
 };
 
 // QuerySession re-exports
@@ -201,8 +230,13 @@ pub use query_session::{
     QuerySessionEvent, QuerySessionState, QuerySessionStatus, query_session_decider,
 };
 
+// Unified event re-exports
+pub use events::{IronstarEvent, TodoSessionEvent, WorkspaceContextEvent};
+
 // Signal re-exports
-pub use signals::{ChartSelection, ChartSignals, TodoFilter, TodoItemView, TodoSignals};
+pub use signals::{
+    ChartSelection, ChartSignals, SettingsSignals, TodoFilter, TodoItemView, TodoSignals,
+};
 
 // Error re-exports
 pub use error::{DomainError, DomainErrorKind, ValidationError, ValidationErrorKind};
@@ -229,7 +263,7 @@ pub use session::{
 // Workspace re-exports
 pub use workspace::{
     Visibility, WORKSPACE_NAME_MAX_LENGTH, WorkspaceCommand, WorkspaceDecider, WorkspaceError,
-    WorkspaceErrorKind, WorkspaceEvent, WorkspaceId, WorkspaceName, WorkspaceState,
+    WorkspaceErrorKind, WorkspaceEvent, WorkspaceId, WorkspaceName, WorkspaceRole, WorkspaceState,
     WorkspaceStatus, workspace_decider,
 };
 
@@ -242,16 +276,18 @@ pub use saved_query::{
 
 // UserPreferences re-exports
 pub use user_preferences::{
-    LOCALE_MAX_LENGTH, Locale, PreferencesId, Theme, UiState, UserPreferencesCommand,
+    DigestFrequency, LOCALE_MAX_LENGTH, Locale, MAX_RECENT_WORKSPACES, NotificationPreferences,
+    PreferencesId, RecentWorkspaces, Theme, UiState, UserPreferencesCommand,
     UserPreferencesDecider, UserPreferencesError, UserPreferencesErrorKind, UserPreferencesEvent,
     UserPreferencesState, user_preferences_decider,
 };
 
 // Dashboard re-exports
 pub use dashboard::{
-    ChartDefinitionRef, ChartId, ChartPlacement, DashboardCommand, DashboardDecider,
-    DashboardError, DashboardErrorKind, DashboardEvent, DashboardId, DashboardState, GridPosition,
-    TabId, TabInfo, dashboard_decider,
+    ChartDefinitionRef, ChartDefinitionResolver, ChartId, ChartPlacement, ChartSource,
+    DashboardCommand, DashboardDecider, DashboardError, DashboardErrorKind, DashboardEvent,
+    DashboardId, DashboardState, GridPosition, TabId, TabInfo, dashboard_decider,
+    dashboard_decider_with_max_charts,
 };
 
 // WorkspacePreferences re-exports
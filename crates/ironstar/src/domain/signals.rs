@@ -43,6 +43,9 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use uuid::Uuid;
 
+use crate::domain::DigestFrequency;
+use crate::domain::views::UserPreferencesViewState;
+
 /// Todo application signals.
 ///
 /// Represents the client-side state for the todo list UI:
@@ -177,6 +180,45 @@ pub struct ChartSignals {
     pub error: Option<String>,
 }
 
+/// User settings page signals.
+///
+/// Mirrors the editable fields of a user's preferences. The settings page
+/// binds its controls to these signals and each settings command responds
+/// with the full set, so the page reflects the stored preferences after
+/// every change.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, export_to = "signals/")]
+pub struct SettingsSignals {
+    /// Theme in its lowercase form: `light`, `dark`, or `system`.
+    #[ts(type = "\"light\" | \"dark\" | \"system\"")]
+    pub theme: String,
+
+    /// BCP-47 language tag.
+    pub locale: String,
+
+    /// Send notifications by email.
+    pub email_enabled: bool,
+
+    /// Show notifications in the application.
+    pub in_app_enabled: bool,
+
+    /// Digest email frequency.
+    pub digest: DigestFrequency,
+}
+
+impl From<&UserPreferencesViewState> for SettingsSignals {
+    fn from(preferences: &UserPreferencesViewState) -> Self {
+        Self {
+            theme: preferences.theme.to_string(),
+            locale: preferences.locale.as_str().to_string(),
+            email_enabled: preferences.notifications.email_enabled,
+            in_app_enabled: preferences.notifications.in_app_enabled,
+            digest: preferences.notifications.digest,
+        }
+    }
+}
+
 /// Minimal comonad model for verifying Datastar signal composition laws.
 ///
 /// Datastar signals on the client form a comonad where `extract` reads the
@@ -619,4 +661,15 @@ mod tests {
         assert_eq!(parsed.value["y"], 20);
         assert_eq!(parsed.value["size"], 5);
     }
+
+    #[test]
+    fn settings_signals_follow_preferences() {
+        let signals = SettingsSignals::from(&UserPreferencesViewState::default());
+        let json = serde_json::to_value(&signals).unwrap();
+
+        assert_eq!(json["theme"], "system");
+        assert_eq!(json["emailEnabled"], false);
+        assert_eq!(json["inAppEnabled"], true);
+        assert_eq!(json["digest"], "off");
+    }
 }
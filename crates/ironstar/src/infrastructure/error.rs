@@ -208,7 +208,9 @@ impl From<ironstar_event_store::EventStoreError> for InfrastructureError {
             | ironstar_event_store::EventStoreErrorKind::DatabaseMessage(_) => {
                 Self::database(e.to_string())
             }
-            ironstar_event_store::EventStoreErrorKind::Serialization(_) => {
+            ironstar_event_store::EventStoreErrorKind::Serialization(_)
+            | ironstar_event_store::EventStoreErrorKind::Compression(_)
+            | ironstar_event_store::EventStoreErrorKind::UnknownAggregateType(_) => {
                 Self::new(InfrastructureErrorKind::DatabaseMessage(e.to_string()))
             }
             ironstar_event_store::EventStoreErrorKind::OptimisticLockingConflict {
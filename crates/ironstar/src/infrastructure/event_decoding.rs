@@ -0,0 +1,152 @@
+//! Decoding stored events of any aggregate into [`IronstarEvent`].
+//!
+//! A repository read with `serde_json::Value` payloads returns events of
+//! every aggregate in global sequence order. [`DecodeAny`] picks the
+//! concrete event type from each row's aggregate type, so a generic
+//! projection can fold heterogeneous streams from one cursor.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::domain::IronstarEvent;
+use crate::domain::catalog::CatalogEvent;
+use crate::domain::dashboard::DashboardEvent;
+use crate::domain::query_session::QuerySessionEvent;
+use crate::domain::saved_query::SavedQueryEvent;
+use crate::domain::session::SessionEvent;
+use crate::domain::todo::TodoEvent;
+use crate::domain::workspace::WorkspaceEvent;
+use crate::infrastructure::event_store::{EventStoreError, StoredEvent};
+
+/// Decode a stored event into the unified event type.
+pub trait DecodeAny {
+    /// Decode the payload as the event type of its aggregate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UnknownAggregateType` when the aggregate type is not part
+    /// of [`IronstarEvent`], and `Serialization` when the payload does not
+    /// match its aggregate's event type.
+    fn decode_any(&self) -> Result<IronstarEvent, EventStoreError>;
+}
+
+impl DecodeAny for StoredEvent<Value> {
+    fn decode_any(&self) -> Result<IronstarEvent, EventStoreError> {
+        // Aggregate type names match each event type's `DeciderType`.
+        match self.aggregate_type.as_str() {
+            "Workspace" => decode::<WorkspaceEvent>(&self.event),
+            "Dashboard" => decode::<DashboardEvent>(&self.event),
+            "SavedQuery" => decode::<SavedQueryEvent>(&self.event),
+            "Catalog" => decode::<CatalogEvent>(&self.event),
+            "QuerySession" => decode::<QuerySessionEvent>(&self.event),
+            "Todo" => decode::<TodoEvent>(&self.event),
+            "Session" => decode::<SessionEvent>(&self.event),
+            other => Err(EventStoreError::unknown_aggregate_type(other)),
+        }
+    }
+}
+
+fn decode<E>(payload: &Value) -> Result<IronstarEvent, EventStoreError>
+where
+    E: DeserializeOwned + Into<IronstarEvent>,
+{
+    Ok(E::deserialize(payload)?.into())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::domain::common::DashboardTitle;
+    use crate::domain::dashboard::{DashboardCommand, DashboardId};
+    use crate::domain::session::UserId;
+    use crate::domain::traits::Identifier;
+    use crate::domain::workspace::{Visibility, WorkspaceCommand, WorkspaceId, WorkspaceName};
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
+    use crate::infrastructure::event_store::{
+        EventStoreErrorKind, GlobalSequence, SqliteEventRepository, StreamVersion,
+    };
+    use chrono::Utc;
+    use sqlx::SqlitePool;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn create_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("Failed to create test pool");
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("Failed to run migration");
+        pool
+    }
+
+    #[tokio::test]
+    async fn decodes_workspace_and_dashboard_streams() {
+        let pool = create_pool().await;
+        let workspace_id = WorkspaceId::new();
+        let created = WorkspaceEvent::Created {
+            workspace_id,
+            name: WorkspaceName::new("Analytics").expect("valid name"),
+            normalized_name: "analytics".to_string(),
+            owner_id: UserId::system(),
+            visibility: Visibility::Private,
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        };
+        let dashboard = DashboardEvent::DashboardCreated {
+            dashboard_id: DashboardId::new(),
+            workspace_id,
+            name: DashboardTitle::new("Overview").expect("valid title"),
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        };
+
+        SqliteEventRepository::<WorkspaceCommand, WorkspaceEvent>::new(pool.clone())
+            .append_batch(&created.identifier(), vec![created.clone()])
+            .await
+            .expect("append workspace event");
+        SqliteEventRepository::<DashboardCommand, DashboardEvent>::new(pool.clone())
+            .append_batch(&dashboard.identifier(), vec![dashboard.clone()])
+            .await
+            .expect("append dashboard event");
+
+        let stored = SqliteEventRepository::<(), Value>::new(pool)
+            .query_all()
+            .await
+            .expect("query all events");
+        let decoded: Vec<IronstarEvent> = stored
+            .iter()
+            .map(|e| e.decode_any().expect("decodable event"))
+            .collect();
+
+        assert_eq!(decoded, vec![created.into(), dashboard.into()]);
+    }
+
+    #[tokio::test]
+    async fn unknown_aggregate_type_is_rejected() {
+        let stored = StoredEvent {
+            sequence: GlobalSequence::new(1),
+            stream_version: StreamVersion::new(1),
+            event_id: "event".to_string(),
+            aggregate_type: "UserPreferences".to_string(),
+            aggregate_id: "prefs".to_string(),
+            event_type: "PreferencesInitialized".to_string(),
+            schema_version: 1,
+            event: Value::Null,
+            command_id: None,
+            correlation_id: None,
+            causation_id: None,
+            is_final: false,
+            created_at: Utc::now().to_rfc3339(),
+        };
+
+        let error = stored.decode_any().expect_err("not an IronstarEvent");
+        assert!(matches!(
+            error.kind(),
+            EventStoreErrorKind::UnknownAggregateType(t) if t == "UserPreferences"
+        ));
+    }
+}
@@ -44,6 +44,14 @@ pub const CACHE_MISSES_TOTAL: &str = "cache_misses_total";
 /// Query execution duration histogram in seconds.
 pub const QUERY_DURATION_SECONDS: &str = "query_duration_seconds";
 
+/// Event bus publish duration histogram and failure counter, emitted by
+/// `ZenohEventBus` (labels: aggregate_type).
+pub use ironstar_event_bus::{EVENT_PUBLISH_DURATION_SECONDS, EVENT_PUBLISH_FAILURES_TOTAL};
+
+/// Open SSE connection gauge and per-connection delivered event histogram,
+/// emitted by the SSE stream builders.
+pub use ironstar_event_store::{SSE_CONNECTION_EVENTS, SSE_CONNECTIONS};
+
 // ---------------------------------------------------------------------------
 // Recorder initialization
 // ---------------------------------------------------------------------------
@@ -113,6 +121,30 @@ fn describe_metrics() {
         metrics::Unit::Seconds,
         "Query execution duration in seconds"
     );
+
+    metrics::describe_histogram!(
+        EVENT_PUBLISH_DURATION_SECONDS,
+        metrics::Unit::Seconds,
+        "Event bus publish duration in seconds"
+    );
+
+    metrics::describe_counter!(
+        EVENT_PUBLISH_FAILURES_TOTAL,
+        metrics::Unit::Count,
+        "Total number of failed event bus publishes"
+    );
+
+    metrics::describe_gauge!(
+        SSE_CONNECTIONS,
+        metrics::Unit::Count,
+        "Number of currently open SSE connections"
+    );
+
+    metrics::describe_histogram!(
+        SSE_CONNECTION_EVENTS,
+        metrics::Unit::Count,
+        "Data events delivered over each SSE connection's lifetime"
+    );
 }
 
 /// Create a non-global Prometheus handle for testing.
@@ -155,9 +187,46 @@ mod tests {
         assert!(EVENTS_PERSISTED_TOTAL.ends_with("_total"));
         assert!(CACHE_HITS_TOTAL.ends_with("_total"));
         assert!(CACHE_MISSES_TOTAL.ends_with("_total"));
+        assert!(EVENT_PUBLISH_FAILURES_TOTAL.ends_with("_total"));
 
         // Histograms end with _seconds
         assert!(HTTP_REQUEST_DURATION_SECONDS.ends_with("_seconds"));
         assert!(QUERY_DURATION_SECONDS.ends_with("_seconds"));
+        assert!(EVENT_PUBLISH_DURATION_SECONDS.ends_with("_seconds"));
+    }
+
+    #[tokio::test]
+    async fn sse_connection_gauge_returns_to_zero_when_stream_drops() {
+        use crate::infrastructure::sse_stream::SseStreamBuilder;
+        use futures::StreamExt;
+
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let live =
+            futures::stream::iter(vec![Ok(axum::response::sse::Event::default().data("live"))]);
+        let mut stream =
+            Box::pin(SseStreamBuilder::new().build_with_streams(futures::stream::empty(), live));
+        assert!(
+            handle.render().contains(&format!("{SSE_CONNECTIONS} 1")),
+            "{}",
+            handle.render()
+        );
+
+        // Keep-alive comments may arrive first; wait for the data event.
+        while let Some(Ok(event)) = stream.next().await {
+            if format!("{event:?}").contains("data: live") {
+                break;
+            }
+        }
+        drop(stream);
+
+        let output = handle.render();
+        assert!(output.contains(&format!("{SSE_CONNECTIONS} 0")), "{output}");
+        assert!(
+            output.contains(&format!("{SSE_CONNECTION_EVENTS}_sum 1")),
+            "{output}"
+        );
     }
 }
@@ -101,7 +101,8 @@
 pub mod event_bus {
     //! Event bus re-exports from `ironstar-event-bus` crate.
     pub use ironstar_event_bus::{
-        EventBus, ZenohEventBus, open_embedded_session, publish_events_fire_and_forget,
+        CatchUpSubscription, EventBus, InProcessEventBus, InProcessSubscriber, PublishedEvent,
+        ReplayMode, ZenohEventBus, open_embedded_session, publish_events_fire_and_forget,
         zenoh_embedded_config,
     };
 
@@ -120,15 +121,18 @@ pub mod event_store {
     //! Event store re-exports from `ironstar-event-store` crate.
     pub use ironstar_event_store::event_store::EVENTS_MIGRATION_SQL;
     pub use ironstar_event_store::{
-        EventStoreError, EventStoreErrorKind, SqliteEventRepository, StoredEvent,
+        CHECKPOINTS_MIGRATION_SQL, Correlation, DEFAULT_COMPRESSION_THRESHOLD, EventStoreError,
+        EventStoreErrorKind, GlobalSequence, PayloadCompression, SqliteCheckpointStore,
+        SqliteEventRepository, StoredEvent, StreamVersion,
     };
 }
 
 pub mod session_store {
     //! Session store re-exports from `ironstar-session-store` crate.
     pub use ironstar_session_store::{
-        SESSIONS_MIGRATION_SQL, Session, SessionStore, SessionStoreError, SessionStoreErrorKind,
-        SqliteSessionStore, generate_session_id, spawn_session_cleanup,
+        SESSIONS_MIGRATION_SQL, Session, SessionConfig, SessionFlag, SessionStore,
+        SessionStoreError, SessionStoreErrorKind, SqliteSessionStore, generate_session_id,
+        spawn_session_cleanup,
     };
 }
 
@@ -149,7 +153,10 @@ pub mod cache_dependency {
 pub mod analytics {
     //! Analytics infrastructure re-exports from `ironstar-analytics-infra` crate.
     pub use ironstar_analytics_infra::analytics::duckdb;
-    pub use ironstar_analytics_infra::{AnalyticsState, DuckDBService, DuckDbPool};
+    pub use ironstar_analytics_infra::{
+        AnalyticsState, ColumnType, DEFAULT_MEMORY_LIMIT, DuckDBService, DuckDbConfig, DuckDbPool,
+        ResultColumn, ResultRows, StatementCache, describe_columns, fetch_rows,
+    };
 }
 
 pub mod analytics_cache {
@@ -159,7 +166,9 @@ pub mod analytics_cache {
 
 pub mod cached_analytics {
     //! Cached analytics service re-exports from `ironstar-analytics-infra` crate.
-    pub use ironstar_analytics_infra::{CachedAnalyticsService, cache_key, query_hash};
+    pub use ironstar_analytics_infra::{
+        CachedAnalyticsService, cache_key, chart_cache_key, query_hash,
+    };
 }
 
 pub mod cache_invalidation {
@@ -177,22 +186,25 @@ pub mod embedded_catalogs {
 pub mod sse_stream {
     //! SSE stream utilities re-exports from `ironstar-event-store` crate.
     pub use ironstar_event_store::{
-        DEFAULT_KEEP_ALIVE_SECS, KEEP_ALIVE_COMMENT, KeepAliveStream, SseStreamBuilder,
-        event_with_sequence, stored_events_to_stream, zenoh_to_sse_stream,
+        DEFAULT_BUFFER_CAPACITY, DEFAULT_KEEP_ALIVE_SECS, KEEP_ALIVE_COMMENT, KeepAliveStream,
+        RESYNC_EVENT, SSE_CONNECTION_EVENTS, SSE_CONNECTIONS, SseStreamBuilder,
+        bounded_live_stream, event_with_sequence, stored_events_to_stream, zenoh_to_sse_stream,
     };
 }
 
 // Original code modules kept as real files
 pub mod assets;
 pub mod error;
+pub mod event_decoding;
 pub mod metrics;
+pub mod translations;
 
 pub use analytics::{AnalyticsState, DuckDBService};
 pub use analytics_cache::AnalyticsCache;
 pub use assets::{AssetManifest, StaticAssets, create_static_router, static_file_handler};
 pub use cache_dependency::{CacheDependency, matches_key_expression};
 pub use cache_invalidation::{CacheInvalidationRegistry, spawn_cache_invalidation};
-pub use cached_analytics::{CachedAnalyticsService, cache_key, query_hash};
+pub use cached_analytics::{CachedAnalyticsService, cache_key, chart_cache_key, query_hash};
 pub use embedded_catalogs::{DuckLakeCatalogs, embedded_cache_key_prefix};
 pub use error::{InfrastructureError, InfrastructureErrorKind};
 pub use event_bus::workspace::{
@@ -202,11 +214,15 @@ pub use event_bus::workspace::{
     workspace_events_pattern,
 };
 pub use event_bus::{
-    EventBus, ZenohEventBus, open_embedded_session, publish_events_fire_and_forget,
+    CatchUpSubscription, EventBus, InProcessEventBus, InProcessSubscriber, PublishedEvent,
+    ReplayMode, ZenohEventBus, open_embedded_session, publish_events_fire_and_forget,
     zenoh_embedded_config,
 };
+pub use event_decoding::DecodeAny;
 pub use event_store::{
-    EVENTS_MIGRATION_SQL, EventStoreError, EventStoreErrorKind, SqliteEventRepository, StoredEvent,
+    CHECKPOINTS_MIGRATION_SQL, Correlation, EVENTS_MIGRATION_SQL, EventStoreError,
+    EventStoreErrorKind, GlobalSequence, PayloadCompression, SqliteCheckpointStore,
+    SqliteEventRepository, StoredEvent, StreamVersion,
 };
 pub use key_expr::{
     ALL_EVENTS, DOUBLE_WILD, EVENTS_ROOT, EventKeyExpr, ParseError as KeyExprParseError,
@@ -215,13 +231,16 @@ pub use key_expr::{
 };
 pub use metrics::{
     CACHE_HITS_TOTAL, CACHE_MISSES_TOTAL, EVENTS_PERSISTED_TOTAL, HTTP_REQUEST_DURATION_SECONDS,
-    HTTP_REQUESTS_TOTAL, QUERY_DURATION_SECONDS, init_prometheus_recorder, test_prometheus_handle,
+    HTTP_REQUESTS_TOTAL, QUERY_DURATION_SECONDS, SSE_CONNECTION_EVENTS, SSE_CONNECTIONS,
+    init_prometheus_recorder, test_prometheus_handle,
 };
 pub use session_store::{
-    SESSIONS_MIGRATION_SQL, Session, SessionStore, SessionStoreError, SessionStoreErrorKind,
-    SqliteSessionStore, generate_session_id, spawn_session_cleanup,
+    SESSIONS_MIGRATION_SQL, Session, SessionConfig, SessionFlag, SessionStore, SessionStoreError,
+    SessionStoreErrorKind, SqliteSessionStore, generate_session_id, spawn_session_cleanup,
 };
 pub use sse_stream::{
-    DEFAULT_KEEP_ALIVE_SECS, KEEP_ALIVE_COMMENT, KeepAliveStream, SseStreamBuilder,
-    event_with_sequence, stored_events_to_stream, zenoh_to_sse_stream,
+    DEFAULT_BUFFER_CAPACITY, DEFAULT_KEEP_ALIVE_SECS, KEEP_ALIVE_COMMENT, KeepAliveStream,
+    RESYNC_EVENT, SseStreamBuilder, bounded_live_stream, event_with_sequence,
+    stored_events_to_stream, zenoh_to_sse_stream,
 };
+pub use translations::{DEFAULT_TRANSLATION_LOCALE, Translations};
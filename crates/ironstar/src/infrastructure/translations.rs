@@ -0,0 +1,138 @@
+//! UI message translations keyed by locale.
+//!
+//! Messages live in `locales/<tag>.json` as flat `{"key": "message"}`
+//! objects and are embedded at build time. Lookups walk the requested
+//! locale's [`Locale::fallback_chain`], then [`DEFAULT_TRANSLATION_LOCALE`],
+//! and finally return the key itself, so a missing message never breaks a
+//! page.
+
+use rust_embed::RustEmbed;
+use std::collections::HashMap;
+use tracing::warn;
+
+use crate::domain::user_preferences::values::Locale;
+
+/// Locale whose messages back every other locale.
+pub const DEFAULT_TRANSLATION_LOCALE: &str = "en";
+
+/// Embedded translation files from `locales/`.
+#[derive(RustEmbed)]
+#[folder = "$CARGO_MANIFEST_DIR/locales"]
+#[include = "*.json"]
+struct LocaleFiles;
+
+/// Messages per locale tag.
+///
+/// Tags are matched case-insensitively, as BCP-47 tags are.
+#[derive(Debug, Clone, Default)]
+pub struct Translations(HashMap<String, HashMap<String, String>>);
+
+impl Translations {
+    /// Load the embedded translation files.
+    ///
+    /// A file that is not a flat JSON object of strings is skipped with a
+    /// warning; its locale then falls back like any missing locale.
+    pub fn load() -> Self {
+        let mut translations = Self::default();
+        for path in LocaleFiles::iter() {
+            let Some(tag) = path.strip_suffix(".json") else {
+                continue;
+            };
+            let Some(file) = LocaleFiles::get(&path) else {
+                continue;
+            };
+            match serde_json::from_slice(&file.data) {
+                Ok(messages) => translations.insert(tag, messages),
+                Err(e) => warn!(file = %path, error = %e, "skipping malformed translation file"),
+            }
+        }
+        translations
+    }
+
+    /// Add or replace the messages for `tag`.
+    pub fn insert(&mut self, tag: &str, messages: HashMap<String, String>) {
+        self.0.insert(tag.to_ascii_lowercase(), messages);
+    }
+
+    /// Message for `key` in the best available match for `locale`.
+    ///
+    /// Returns `key` itself when no locale in the fallback chain has it.
+    pub fn translate<'a>(&'a self, locale: &Locale, key: &'a str) -> &'a str {
+        locale
+            .fallback_chain()
+            .into_iter()
+            .chain([DEFAULT_TRANSLATION_LOCALE])
+            .find_map(|tag| {
+                self.0
+                    .get(&tag.to_ascii_lowercase())
+                    .and_then(|messages| messages.get(key))
+            })
+            .map_or(key, String::as_str)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn locale(tag: &str) -> Locale {
+        Locale::new(tag).expect("valid locale")
+    }
+
+    fn translations() -> Translations {
+        let mut translations = Translations::default();
+        translations.insert(
+            "en",
+            HashMap::from([
+                ("greeting".to_string(), "Hello".to_string()),
+                ("farewell".to_string(), "Goodbye".to_string()),
+            ]),
+        );
+        translations.insert(
+            "pt",
+            HashMap::from([("greeting".to_string(), "Olá".to_string())]),
+        );
+        translations.insert(
+            "pt-BR",
+            HashMap::from([("greeting".to_string(), "Oi".to_string())]),
+        );
+        translations
+    }
+
+    #[test]
+    fn exact_locale_hit() {
+        assert_eq!(translations().translate(&locale("pt-BR"), "greeting"), "Oi");
+    }
+
+    #[test]
+    fn falls_back_to_shorter_tag_then_default() {
+        let translations = translations();
+        assert_eq!(translations.translate(&locale("pt-PT"), "greeting"), "Olá");
+        assert_eq!(
+            translations.translate(&locale("pt-BR"), "farewell"),
+            "Goodbye"
+        );
+    }
+
+    #[test]
+    fn missing_key_returns_key() {
+        assert_eq!(
+            translations().translate(&locale("pt-BR"), "unknown.key"),
+            "unknown.key"
+        );
+    }
+
+    #[test]
+    fn embedded_files_load() {
+        let translations = Translations::load();
+        assert_eq!(
+            translations.translate(&locale("de-AT"), "settings.title"),
+            "Einstellungen"
+        );
+        assert_eq!(
+            translations.translate(&Locale::default(), "settings.title"),
+            "Settings"
+        );
+    }
+}
@@ -29,6 +29,7 @@ use ironstar::infrastructure::{
 use ironstar::presentation::app_router;
 use ironstar::state::AppState;
 use sqlx::sqlite::SqlitePoolOptions;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::net::TcpSocket;
 use tokio::signal;
@@ -135,20 +136,31 @@ async fn main() -> Result<(), StartupError> {
 
     // 9. Initialize DuckDB analytics pool (optional)
     let analytics = if config.enable_analytics {
-        let mut builder = async_duckdb::PoolBuilder::new().num_conns(config.analytics_num_conns);
-        if let Some(ref path) = config.analytics_database_path {
-            builder = builder.path(path);
-        }
-        match builder.open().await {
+        let duckdb_config = config.duckdb_config();
+        let path = config.analytics_database_path.as_deref().map(Path::new);
+        match duckdb_config
+            .open_pool(path, config.analytics_num_conns)
+            .await
+        {
             Ok(pool) => {
                 tracing::info!(
                     num_conns = config.analytics_num_conns,
                     path = ?config.analytics_database_path,
+                    read_only = duckdb_config.read_only,
                     "DuckDB analytics pool initialized"
                 );
 
-                // Load httpfs and ducklake extensions on all connections
-                let service = DuckDBService::new(Some(pool.clone()));
+                // Apply resource limits, then load httpfs and ducklake extensions
+                let service = match DuckDBService::with_config(pool.clone(), &duckdb_config).await {
+                    Ok(service) => service,
+                    Err(e) => {
+                        tracing::warn!(
+                            error = %e,
+                            "Failed to apply DuckDB settings, using DuckDB defaults"
+                        );
+                        DuckDBService::new(Some(pool.clone()))
+                    }
+                };
                 match service.initialize_extensions().await {
                     Ok(()) => {
                         tracing::info!("DuckDB extensions loaded (httpfs, ducklake)");
@@ -236,7 +248,9 @@ async fn main() -> Result<(), StartupError> {
     }
 
     // 12. Construct AppState
-    let mut app_state = AppState::new(db_pool.clone(), assets, prometheus_handle);
+    let mut app_state = AppState::new(db_pool.clone(), assets, prometheus_handle)
+        .with_request_body_limits(config.request_body_limits())
+        .with_workspace_administrators(config.workspace_administrators());
     if let Some(bus) = event_bus {
         app_state = app_state.with_event_bus(bus);
     }
@@ -246,6 +260,14 @@ async fn main() -> Result<(), StartupError> {
     if let Some(cached) = cached_analytics {
         app_state = app_state.with_cached_analytics(cached);
     }
+    if let Some(compression) = config.event_compression() {
+        app_state = app_state.with_event_compression(compression);
+    }
+    if let Some(token) = config.metrics_token.clone() {
+        app_state = app_state.with_metrics_token(token);
+    } else {
+        tracing::warn!("IRONSTAR_METRICS_TOKEN unset, /metrics will reject every scrape");
+    }
 
     // 13. Compose router
     let app = app_router(app_state);
@@ -16,6 +16,10 @@
 //! - `GET /api/queries/{id}` - Get specific query from history
 //! - `GET /api/queries` - List query history
 //!
+//! Results:
+//! - `GET /results/{id}/page/{n}` - One page of a completed query's cached result
+//! - `POST /chart/{id}/type` - Redraw a completed query's chart from its cached result
+//!
 //! SSE:
 //! - `GET /api/feed` - Combined Catalog + QuerySession event stream
 
@@ -26,30 +30,50 @@ use axum::http::{HeaderMap, StatusCode};
 use axum::response::sse::{Event, Sse};
 use axum::routing::{delete as route_delete, get, post};
 use chrono::Utc;
-use futures::Stream;
+use datastar::prelude::PatchElements;
+use futures::{Stream, StreamExt};
+use hypertext::Renderable;
 use serde::{Deserialize, Serialize};
 use std::convert::Infallible;
 use std::sync::Arc;
 use tracing::{instrument, warn};
 use uuid::Uuid;
 
-use crate::application::catalog::{handle_catalog_command_zenoh, query_catalog_state};
+use crate::application::catalog::{
+    handle_catalog_command_zenoh, populate_row_counts, query_catalog_state,
+};
 use crate::application::query_session::{
-    handle_query_session_command_zenoh, query_query_history, query_session_state,
+    handle_query_session_command_with_spawn, handle_query_session_command_zenoh,
+    query_query_history, query_session_state,
+};
+use crate::domain::UserId;
+use crate::domain::clock::Clock;
+use crate::domain::signals::ChartSignals;
+use crate::domain::views::{
+    CatalogViewState, QueryHistoryEntry, QueryOutcome, QuerySessionViewState,
 };
-use crate::domain::traits::EventType;
-use crate::domain::views::{CatalogViewState, QueryHistoryEntry, QuerySessionViewState};
 use crate::domain::{
-    CatalogCommand, CatalogEvent, CatalogMetadata, CatalogRef, DatasetInfo, QueryId,
-    QuerySessionCommand, QuerySessionEvent, SqlQuery,
+    CancellationReason, CatalogCommand, CatalogEvent, CatalogMetadata, CatalogRef,
+    ChartConfig as QueryChartConfig, DatasetInfo, QueryId, QuerySessionCommand, QuerySessionEvent,
+    SqlQuery,
 };
+use crate::infrastructure::analytics::AnalyticsState;
+use crate::infrastructure::chart_cache_key;
 use crate::infrastructure::event_bus::ZenohEventBus;
-use crate::infrastructure::event_store::{SqliteEventRepository, StoredEvent};
+use crate::infrastructure::event_store::{GlobalSequence, SqliteEventRepository, StoredEvent};
 use crate::infrastructure::key_expr::aggregate_type_pattern;
 use crate::infrastructure::sse_stream::{
     SseStreamBuilder, stored_events_to_stream, zenoh_to_sse_stream,
 };
+use crate::presentation::chart_templates::{chart_slot_id, echarts_chart};
+use crate::presentation::chart_transformer::{
+    ChartConfig, ChartType, QueryResult, TransformError, transformer_for,
+};
 use crate::presentation::error::AppError;
+use crate::presentation::extractors::Actor;
+use crate::presentation::results_templates::{
+    RESULTS_PAGE_SIZE, ResultPage, results_pagination, results_table_body,
+};
 use crate::state::AppState;
 
 /// Application state for Analytics handlers.
@@ -61,6 +85,7 @@ pub struct AnalyticsAppState {
     pub catalog_repo: Arc<SqliteEventRepository<CatalogCommand, CatalogEvent>>,
     pub query_session_repo: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>,
     pub event_bus: Option<Arc<ZenohEventBus>>,
+    pub clock: Arc<dyn Clock>,
 }
 
 // =============================================================================
@@ -79,6 +104,10 @@ pub fn routes() -> Router<AppState> {
         .route("/api/queries", get(list_query_history))
         .route("/api/queries/{id}", get(get_query))
         .route("/api/queries/{id}", route_delete(cancel_query))
+        // Result paging
+        .route("/results/{id}/page/{n}", get(get_results_page))
+        // Chart type switching
+        .route("/chart/{id}/type", post(switch_chart_type))
         // SSE feed
         .route("/api/feed", get(analytics_feed_handler))
 }
@@ -93,9 +122,13 @@ pub fn routes() -> Router<AppState> {
 /// Supports `Last-Event-ID` reconnection. Subscribe-before-replay invariant
 /// is maintained: Zenoh subscriptions are established before querying historical
 /// events.
+///
+/// The stream holds a [`CancelOnDisconnect`] guard, so when the client goes
+/// away the actor's in-flight query is cancelled as `ClientDisconnected`.
 #[instrument(name = "handler.analytics.feed", skip(state, headers))]
 async fn analytics_feed_handler(
     State(state): State<AnalyticsAppState>,
+    Actor(actor): Actor,
     headers: HeaderMap,
 ) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>> + Send>, StatusCode> {
     let event_bus = state.event_bus.as_ref().ok_or_else(|| {
@@ -103,11 +136,11 @@ async fn analytics_feed_handler(
         StatusCode::SERVICE_UNAVAILABLE
     })?;
 
-    let last_event_id: i64 = headers
+    let last_event_id: GlobalSequence = headers
         .get("Last-Event-ID")
         .and_then(|h| h.to_str().ok())
         .and_then(|s| s.parse().ok())
-        .unwrap_or(0);
+        .unwrap_or_default();
 
     // Subscribe BEFORE loading historical events (critical invariant).
     let catalog_sub = event_bus
@@ -148,7 +181,8 @@ async fn analytics_feed_handler(
         })?;
 
     // Merge historical events sorted by global sequence for correct SSE ordering.
-    let mut all_sse: Vec<(i64, Event)> = Vec::with_capacity(catalog_events.len() + qs_events.len());
+    let mut all_sse: Vec<(GlobalSequence, Event)> =
+        Vec::with_capacity(catalog_events.len() + qs_events.len());
     for stored in catalog_events {
         let seq = stored.sequence;
         all_sse.push((seq, stored_catalog_event_to_sse(stored)));
@@ -168,11 +202,87 @@ async fn analytics_feed_handler(
     let combined_live = futures::stream::select(catalog_live, qs_live);
 
     let builder = SseStreamBuilder::new().with_keep_alive_secs(15);
-    let stream = builder.build_with_streams(replay_stream, combined_live);
+    let guard = CancelOnDisconnect::new(&state, actor);
+    let stream = builder
+        .build_with_streams(replay_stream, combined_live)
+        .map(move |event| {
+            let _ = &guard;
+            event
+        });
 
     Ok(Sse::new(stream))
 }
 
+/// Cancels the actor's in-flight query when dropped.
+///
+/// The analytics feed stream owns one, and axum drops the stream when the
+/// client disconnects. Only a query the actor started is cancelled, matching
+/// the scope of supersession in `start_query`. Anonymous clients all act as
+/// the system user, so their disconnects cancel nothing.
+struct CancelOnDisconnect {
+    query_session_repo: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>,
+    event_bus: Option<Arc<ZenohEventBus>>,
+    clock: Arc<dyn Clock>,
+    actor: UserId,
+}
+
+impl CancelOnDisconnect {
+    fn new(state: &AnalyticsAppState, actor: UserId) -> Self {
+        Self {
+            query_session_repo: Arc::clone(&state.query_session_repo),
+            event_bus: state.event_bus.clone(),
+            clock: Arc::clone(&state.clock),
+            actor,
+        }
+    }
+}
+
+impl Drop for CancelOnDisconnect {
+    fn drop(&mut self) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let repo = Arc::clone(&self.query_session_repo);
+        let event_bus = self.event_bus.clone();
+        let clock = Arc::clone(&self.clock);
+        let actor = self.actor;
+        runtime.spawn(async move {
+            if let Err(e) =
+                cancel_disconnected_query(repo, event_bus.as_deref(), clock.as_ref(), actor).await
+            {
+                warn!(error = %e, "Failed to cancel query after client disconnect");
+            }
+        });
+    }
+}
+
+/// Cancel `actor`'s in-flight query as `ClientDisconnected`, if there is one.
+async fn cancel_disconnected_query(
+    repo: Arc<SqliteEventRepository<QuerySessionCommand, QuerySessionEvent>>,
+    event_bus: Option<&ZenohEventBus>,
+    clock: &dyn Clock,
+    actor: UserId,
+) -> Result<(), AppError> {
+    if actor.is_system() {
+        return Ok(());
+    }
+    let status = query_session_state(&repo).await?.status;
+    let Some(query_id) = status
+        .query_id()
+        .filter(|_| status.started_by() == Some(actor))
+    else {
+        return Ok(());
+    };
+
+    let command = QuerySessionCommand::CancelQuery {
+        query_id,
+        reason: CancellationReason::ClientDisconnected,
+        cancelled_at: clock.now(),
+    };
+    handle_query_session_command_zenoh(repo, event_bus, command).await?;
+    Ok(())
+}
+
 fn stored_catalog_event_to_sse(stored: StoredEvent<CatalogEvent>) -> Event {
     Event::default()
         .id(stored.sequence.to_string())
@@ -194,21 +304,21 @@ fn stored_qs_event_to_sse(stored: StoredEvent<QuerySessionEvent>) -> Event {
 }
 
 fn live_catalog_event_to_sse(event: CatalogEvent) -> Event {
-    Event::default()
-        .event(event.event_type())
-        .data(serde_json::to_string(&event).unwrap_or_else(|e| {
+    Event::default().event(event.event_type_str()).data(
+        serde_json::to_string(&event).unwrap_or_else(|e| {
             warn!(error = %e, "Failed to serialize live CatalogEvent");
             "{}".to_string()
-        }))
+        }),
+    )
 }
 
 fn live_qs_event_to_sse(event: QuerySessionEvent) -> Event {
-    Event::default()
-        .event(event.event_type())
-        .data(serde_json::to_string(&event).unwrap_or_else(|e| {
+    Event::default().event(event.event_type_str()).data(
+        serde_json::to_string(&event).unwrap_or_else(|e| {
             warn!(error = %e, "Failed to serialize live QuerySessionEvent");
             "{}".to_string()
-        }))
+        }),
+    )
 }
 
 // =============================================================================
@@ -275,7 +385,7 @@ pub async fn select_catalog(
     let catalog_ref = CatalogRef::new(request.catalog_ref)?;
     let command = CatalogCommand::SelectCatalog {
         catalog_ref,
-        selected_at: Utc::now(),
+        selected_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
@@ -296,19 +406,29 @@ pub async fn select_catalog(
 #[serde(rename_all = "camelCase")]
 pub struct RefreshCatalogRequest {
     pub datasets: Vec<DatasetInfo>,
+    /// Count each dataset's rows with DuckDB before recording the refresh.
+    #[serde(default)]
+    pub include_row_counts: bool,
 }
 
 /// POST /api/catalog/refresh - Refresh catalog metadata.
-#[instrument(name = "handler.catalog.refresh", skip(state, request))]
+///
+/// Row counts are only taken when the request sets `includeRowCounts`, since
+/// counting scans every dataset.
+#[instrument(name = "handler.catalog.refresh", skip(state, analytics, request))]
 pub async fn refresh_catalog(
     State(state): State<AnalyticsAppState>,
+    State(analytics): State<AnalyticsState>,
     Json(request): Json<RefreshCatalogRequest>,
 ) -> Result<(StatusCode, Json<AnalyticsCommandResponse>), AppError> {
-    let now = Utc::now();
-    let metadata = CatalogMetadata {
+    let now = state.clock.now();
+    let mut metadata = CatalogMetadata {
         datasets: request.datasets,
         last_refreshed: now,
     };
+    if request.include_row_counts {
+        populate_row_counts(&analytics.service, &mut metadata).await;
+    }
     let command = CatalogCommand::RefreshCatalogMetadata {
         metadata,
         refreshed_at: now,
@@ -424,6 +544,200 @@ pub async fn get_query(
     Ok(Json(QueryHistoryEntryResponse::from(entry)))
 }
 
+/// GET /results/{id}/page/{n} - Render page `n` of a completed query's result.
+///
+/// Reads the result cached under the query's `result_ref` and patches the
+/// results table body and pagination. Pages outside the result are clamped
+/// to the first or last page. Responds 404 when the query did not complete
+/// or its result is no longer cached.
+#[instrument(name = "handler.query_session.results_page", skip(state, analytics), fields(query_id = %id))]
+pub async fn get_results_page(
+    State(state): State<AnalyticsAppState>,
+    State(analytics): State<AnalyticsState>,
+    Path((id, n)): Path<(Uuid, usize)>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let query_id = QueryId::from_uuid(id);
+    let (_, result) = cached_query_result(&state, &analytics, query_id).await?;
+
+    let page = ResultPage::of(&result, n, RESULTS_PAGE_SIZE);
+    let html = format!(
+        "{}{}",
+        results_table_body(&page, result.column_count())
+            .render()
+            .into_inner(),
+        results_pagination(&query_id, &page).render().into_inner()
+    );
+    let event: Event = PatchElements::new(html).into();
+
+    Ok(Sse::new(futures::stream::once(async move { Ok(event) })))
+}
+
+/// Request body for switching a chart's type.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartTypeRequest {
+    pub chart_type: ChartType,
+}
+
+/// POST /chart/{id}/type - Redraw a completed query's chart as another type.
+///
+/// Re-transforms the query's cached result instead of re-running it, and
+/// patches the chart slot. Transformed charts are cached too, so switching
+/// back to a previously drawn type skips the transformation. Axis columns
+/// come from the query's chart config when it names them; otherwise the
+/// first column is the category and the rest are values. Chart types without
+/// a transformer, and results the transformer rejects, render the chart's
+/// error state. Responds 404 like [`get_results_page`] when no cached result
+/// exists.
+#[instrument(name = "handler.query_session.chart_type", skip(state, analytics), fields(query_id = %id))]
+pub async fn switch_chart_type(
+    State(state): State<AnalyticsAppState>,
+    State(analytics): State<AnalyticsState>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ChartTypeRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let query_id = QueryId::from_uuid(id);
+    let (entry, result) = cached_query_result(&state, &analytics, query_id).await?;
+
+    let (category_column, value_columns) = chart_columns(entry.chart_config.as_ref(), &result);
+    let config = ChartConfig {
+        chart_type: request.chart_type,
+        title: entry
+            .chart_config
+            .as_ref()
+            .and_then(|c| c.title().map(str::to_string)),
+        category_column,
+        value_columns,
+    };
+
+    let transformed = transform_cached(&analytics, &entry, &result, &config).await;
+    let signals = match transformed {
+        Ok(chart_option) => ChartSignals {
+            chart_option,
+            selected: None,
+            loading: false,
+            error: None,
+        },
+        Err(e) => ChartSignals {
+            chart_option: serde_json::json!({}),
+            selected: None,
+            loading: false,
+            error: Some(format!("Transform error: {e}")),
+        },
+    };
+    let html = echarts_chart(&chart_slot_id(&query_id), &signals, CHART_HEIGHT)
+        .render()
+        .into_inner();
+    let event: Event = PatchElements::new(html).into();
+
+    Ok(Sse::new(futures::stream::once(async move { Ok(event) })))
+}
+
+/// Height of charts redrawn in a query's chart slot.
+const CHART_HEIGHT: &str = "400px";
+
+/// Transform `result` into a chart option, memoized in the chart data cache.
+///
+/// The cache key extends the query's `result_ref` with the chart config and
+/// the query's completion time as the dataset version, so rerunning the same
+/// SQL never serves a chart drawn from older data. Only successful transforms
+/// are cached.
+async fn transform_cached(
+    analytics: &AnalyticsState,
+    entry: &QueryHistoryEntry,
+    result: &QueryResult,
+    config: &ChartConfig,
+) -> Result<serde_json::Value, TransformError> {
+    let key = match (&analytics.cached, &entry.outcome) {
+        (
+            Some(cached),
+            QueryOutcome::Completed {
+                result_ref,
+                completed_at,
+                ..
+            },
+        ) => Some((
+            cached,
+            chart_cache_key(result_ref, config, &completed_at.to_rfc3339()),
+        )),
+        _ => None,
+    };
+
+    if let Some((cached, key)) = &key
+        && let Some(bytes) = cached.cache().get(key).await
+        && let Ok(option) = serde_json::from_slice(&bytes)
+    {
+        return Ok(option);
+    }
+
+    let option = transformer_for(config.chart_type)
+        .ok_or_else(|| {
+            TransformError::TransformFailed(format!(
+                "no transformer for {:?} charts",
+                config.chart_type
+            ))
+        })
+        .and_then(|transformer| transformer.transform(result, config))?;
+
+    if let Some((cached, key)) = key
+        && let Ok(bytes) = serde_json::to_vec(&option)
+    {
+        cached.cache().insert(key, bytes).await;
+    }
+    Ok(option)
+}
+
+/// Look up a completed query and the result cached under its `result_ref`.
+///
+/// Responds 404 when the query did not complete or its result is no longer
+/// cached.
+async fn cached_query_result(
+    state: &AnalyticsAppState,
+    analytics: &AnalyticsState,
+    query_id: QueryId,
+) -> Result<(QueryHistoryEntry, QueryResult), AppError> {
+    let history = query_query_history(&state.query_session_repo).await?;
+    let missing = || AppError::not_found("Query result", query_id.to_string());
+
+    let entry = history
+        .into_iter()
+        .find(|e| e.query_id == query_id)
+        .ok_or_else(missing)?;
+    let QueryOutcome::Completed { result_ref, .. } = &entry.outcome else {
+        return Err(missing());
+    };
+
+    let Some(cached) = &analytics.cached else {
+        return Err(missing());
+    };
+    let bytes = cached.cache().get(result_ref).await.ok_or_else(missing)?;
+    let result: QueryResult = serde_json::from_slice(&bytes).map_err(|e| {
+        warn!(error = %e, result_ref, "Cached query result is unreadable");
+        missing()
+    })?;
+
+    Ok((entry, result))
+}
+
+/// Category and value columns for charting `result`.
+///
+/// Uses the query's configured axes when both are set, otherwise the first
+/// column as the category and every other column as a value.
+fn chart_columns(
+    configured: Option<&QueryChartConfig>,
+    result: &QueryResult,
+) -> (String, Vec<String>) {
+    if let Some(config) = configured
+        && let (Some(x), Some(y)) = (config.x_axis(), config.y_axis())
+    {
+        return (x.to_string(), vec![y.to_string()]);
+    }
+
+    let mut names = result.columns.iter().map(|c| c.name.clone());
+    let category = names.next().unwrap_or_default();
+    (category, names.collect())
+}
+
 // =============================================================================
 // QuerySession command handlers
 // =============================================================================
@@ -437,13 +751,21 @@ pub struct StartQueryRequest {
 }
 
 /// POST /api/queries - Start a new analytics query.
-#[instrument(name = "handler.query_session.start", skip(state, request))]
+///
+/// A query the same signed-in user still has in flight is cancelled as
+/// superseded first; another user's or an anonymous query is left running
+/// and the request is rejected.
+/// The query then runs in the background and its result is cached for paging
+/// and charting.
+#[instrument(name = "handler.query_session.start", skip(state, analytics, request))]
 pub async fn start_query(
     State(state): State<AnalyticsAppState>,
+    State(analytics): State<AnalyticsState>,
+    Actor(actor): Actor,
     Json(request): Json<StartQueryRequest>,
 ) -> Result<(StatusCode, Json<StartQueryResponse>), AppError> {
     let query_id = QueryId::new();
-    let sql = SqlQuery::new(&request.sql)?;
+    let sql = SqlQuery::new_read_only(&request.sql)?;
     let dataset_ref = request
         .dataset_ref
         .map(crate::domain::DatasetRef::new)
@@ -454,13 +776,14 @@ pub async fn start_query(
         sql,
         dataset_ref,
         chart_config: None,
-        started_at: Utc::now(),
+        started_at: state.clock.now(),
+        actor,
     };
 
-    let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
-    let events = handle_query_session_command_zenoh(
+    let events = handle_query_session_command_with_spawn(
         Arc::clone(&state.query_session_repo),
-        event_bus_ref,
+        state.event_bus.clone(),
+        analytics,
         command,
     )
     .await?;
@@ -483,14 +806,21 @@ pub struct StartQueryResponse {
 }
 
 /// Request body for cancelling a query.
+///
+/// `reason` takes a [`CancellationReason`] code; any other text is recorded
+/// as `CancellationReason::Other`. Clients need not report disconnects: the
+/// analytics feed cancels as `ClientDisconnected` when its connection drops.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelQueryRequest {
     #[serde(default)]
-    pub reason: Option<String>,
+    pub reason: Option<CancellationReason>,
 }
 
 /// DELETE /api/queries/{id} - Cancel a running query.
+///
+/// Without a body or `reason`, the cancellation is recorded as
+/// `UserCancelled`.
 #[instrument(name = "handler.query_session.cancel", skip(state, body), fields(query_id = %id))]
 pub async fn cancel_query(
     State(state): State<AnalyticsAppState>,
@@ -498,11 +828,11 @@ pub async fn cancel_query(
     body: Option<Json<CancelQueryRequest>>,
 ) -> Result<(StatusCode, Json<AnalyticsCommandResponse>), AppError> {
     let query_id = QueryId::from_uuid(id);
-    let reason = body.and_then(|b| b.0.reason);
+    let reason = body.and_then(|b| b.0.reason).unwrap_or_default();
     let command = QuerySessionCommand::CancelQuery {
         query_id,
         reason,
-        cancelled_at: Utc::now(),
+        cancelled_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
@@ -522,10 +852,12 @@ pub async fn cancel_query(
 }
 
 #[cfg(test)]
-#[allow(clippy::expect_used)]
+#[allow(clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
     use crate::application::catalog::handle_catalog_command;
+    use crate::domain::clock::SystemClock;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use axum::body::Body;
     use axum::http::Request;
     use sqlx::sqlite::SqlitePoolOptions;
@@ -538,7 +870,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -553,6 +885,7 @@ mod tests {
             catalog_repo: Arc::new(SqliteEventRepository::new(pool.clone())),
             query_session_repo: Arc::new(SqliteEventRepository::new(pool)),
             event_bus: None,
+            clock: Arc::new(SystemClock),
         }
     }
 
@@ -655,6 +988,171 @@ mod tests {
         assert_eq!(json["history"], serde_json::json!([]));
     }
 
+    /// Start a query, cancel it over HTTP with an optional JSON `body`, and
+    /// return the recorded reason.
+    async fn cancel_over_http(body: Option<&'static str>) -> CancellationReason {
+        let pool = create_test_pool().await;
+        let state = create_analytics_state(pool);
+        let query_id = QueryId::new();
+        handle_query_session_command_zenoh(
+            Arc::clone(&state.query_session_repo),
+            NO_EVENT_BUS,
+            QuerySessionCommand::StartQuery {
+                query_id,
+                sql: SqlQuery::new("SELECT 1").expect("valid sql"),
+                dataset_ref: None,
+                chart_config: None,
+                started_at: Utc::now(),
+                actor: UserId::system(),
+            },
+        )
+        .await
+        .expect("start query");
+
+        let app = Router::new()
+            .route("/api/queries/{id}", route_delete(cancel_query))
+            .with_state(state.clone());
+        let request = Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/queries/{}", query_id.into_inner()));
+        let request = match body {
+            Some(json) => request
+                .header("content-type", "application/json")
+                .body(Body::from(json)),
+            None => request.body(Body::empty()),
+        };
+        let response = app
+            .oneshot(request.expect("request body"))
+            .await
+            .expect("request should succeed");
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+        let history = query_query_history(&state.query_session_repo)
+            .await
+            .expect("history");
+        match &history[0].outcome {
+            QueryOutcome::Cancelled { reason, .. } => Some(reason.clone()),
+            _ => None,
+        }
+        .expect("query should be cancelled")
+    }
+
+    #[tokio::test]
+    async fn cancel_without_reason_is_user_cancelled() {
+        assert_eq!(
+            cancel_over_http(None).await,
+            CancellationReason::UserCancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_on_disconnect_is_client_disconnected() {
+        let body = r#"{"reason":"client_disconnected"}"#;
+        assert_eq!(
+            cancel_over_http(Some(body)).await,
+            CancellationReason::ClientDisconnected
+        );
+    }
+
+    #[tokio::test]
+    async fn dropping_the_feed_guard_cancels_only_the_actors_query() {
+        let pool = create_test_pool().await;
+        let state = create_analytics_state(pool);
+        let (owner, bystander) = (UserId::new(), UserId::new());
+        handle_query_session_command_zenoh(
+            Arc::clone(&state.query_session_repo),
+            NO_EVENT_BUS,
+            QuerySessionCommand::StartQuery {
+                query_id: QueryId::new(),
+                sql: SqlQuery::new("SELECT 1").expect("valid sql"),
+                dataset_ref: None,
+                chart_config: None,
+                started_at: Utc::now(),
+                actor: owner,
+            },
+        )
+        .await
+        .expect("start query");
+
+        cancel_disconnected_query(
+            Arc::clone(&state.query_session_repo),
+            NO_EVENT_BUS,
+            &SystemClock,
+            bystander,
+        )
+        .await
+        .expect("bystander disconnect is a no-op");
+        assert!(
+            query_query_history(&state.query_session_repo)
+                .await
+                .expect("history")
+                .is_empty()
+        );
+
+        drop(CancelOnDisconnect::new(&state, owner));
+        let mut history = Vec::new();
+        for _ in 0..100 {
+            history = query_query_history(&state.query_session_repo)
+                .await
+                .expect("history");
+            if !history.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(matches!(
+            history.as_slice(),
+            [QueryHistoryEntry {
+                outcome: QueryOutcome::Cancelled {
+                    reason: CancellationReason::ClientDisconnected,
+                    ..
+                },
+                ..
+            }]
+        ));
+    }
+
+    #[tokio::test]
+    async fn anonymous_disconnect_leaves_another_anonymous_query_running() {
+        let pool = create_test_pool().await;
+        let state = create_analytics_state(pool);
+        handle_query_session_command_zenoh(
+            Arc::clone(&state.query_session_repo),
+            NO_EVENT_BUS,
+            QuerySessionCommand::StartQuery {
+                query_id: QueryId::new(),
+                sql: SqlQuery::new("SELECT 1").expect("valid sql"),
+                dataset_ref: None,
+                chart_config: None,
+                started_at: Utc::now(),
+                actor: UserId::system(),
+            },
+        )
+        .await
+        .expect("start query");
+
+        cancel_disconnected_query(
+            Arc::clone(&state.query_session_repo),
+            NO_EVENT_BUS,
+            &SystemClock,
+            UserId::system(),
+        )
+        .await
+        .expect("anonymous disconnect is a no-op");
+
+        let status = query_session_state(&state.query_session_repo)
+            .await
+            .expect("state")
+            .status;
+        assert!(status.query_id().is_some());
+        assert!(
+            query_query_history(&state.query_session_repo)
+                .await
+                .expect("history")
+                .is_empty()
+        );
+    }
+
     #[tokio::test]
     async fn get_query_not_found() {
         let pool = create_test_pool().await;
@@ -674,4 +1172,336 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
+
+    /// Complete a query whose result is cached under `result_ref`, returning
+    /// a router serving result pages and chart redraws from full application
+    /// state.
+    async fn results_router(result: &QueryResult) -> (Router, QueryId) {
+        let (router, query_id, _, _) = results_fixture(result).await;
+        (router, query_id)
+    }
+
+    /// Like [`results_router`], also returning the analytics cache and the
+    /// query's completion time.
+    async fn results_fixture(
+        result: &QueryResult,
+    ) -> (
+        Router,
+        QueryId,
+        crate::infrastructure::CachedAnalyticsService,
+        chrono::DateTime<Utc>,
+    ) {
+        use crate::infrastructure::assets::AssetManifest;
+        use crate::infrastructure::{AnalyticsCache, CachedAnalyticsService, DuckDBService};
+        use axum::extract::FromRef;
+
+        let pool = create_test_pool().await;
+        let cached = CachedAnalyticsService::new(DuckDBService::new(None), AnalyticsCache::new());
+        let app_state = AppState::new(
+            pool,
+            AssetManifest::default(),
+            crate::infrastructure::metrics::test_prometheus_handle(),
+        )
+        .with_cached_analytics(cached.clone());
+        let analytics_state = AnalyticsAppState::from_ref(&app_state);
+
+        let query_id = QueryId::new();
+        let result_ref = "query_session:result:paging".to_string();
+        let started_at = Utc::now();
+        let completed_at = started_at + chrono::Duration::milliseconds(1);
+        for command in [
+            QuerySessionCommand::StartQuery {
+                query_id,
+                sql: SqlQuery::new("SELECT * FROM t").expect("valid sql"),
+                dataset_ref: None,
+                chart_config: None,
+                started_at,
+                actor: UserId::system(),
+            },
+            QuerySessionCommand::BeginExecution {
+                query_id,
+                began_at: started_at,
+            },
+            QuerySessionCommand::CompleteQuery {
+                query_id,
+                row_count: result.row_count(),
+                result_ref: result_ref.clone(),
+                duration_ms: 1,
+                completed_at,
+            },
+        ] {
+            handle_query_session_command_zenoh(
+                Arc::clone(&analytics_state.query_session_repo),
+                NO_EVENT_BUS,
+                command,
+            )
+            .await
+            .expect("query session command");
+        }
+        cached
+            .cache()
+            .insert(result_ref, serde_json::to_vec(result).expect("serialize"))
+            .await;
+
+        let router = Router::new()
+            .route("/results/{id}/page/{n}", get(get_results_page))
+            .route("/chart/{id}/type", post(switch_chart_type))
+            .with_state(app_state);
+        (router, query_id, cached, completed_at)
+    }
+
+    async fn get_body(app: Router, uri: String) -> (StatusCode, String) {
+        send(
+            app,
+            Request::builder()
+                .uri(uri)
+                .body(Body::empty())
+                .expect("request body"),
+        )
+        .await
+    }
+
+    async fn post_chart_type(
+        app: Router,
+        query_id: QueryId,
+        chart_type: &str,
+    ) -> (StatusCode, String) {
+        send(
+            app,
+            Request::builder()
+                .method("POST")
+                .uri(format!("/chart/{query_id}/type"))
+                .header("content-type", "application/json")
+                .body(Body::from(format!(r#"{{"chartType":"{chart_type}"}}"#)))
+                .expect("request body"),
+        )
+        .await
+    }
+
+    async fn send(app: Router, request: Request<Body>) -> (StatusCode, String) {
+        let response = app.oneshot(request).await.expect("request should succeed");
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body read");
+        (status, String::from_utf8(body.to_vec()).expect("utf8 body"))
+    }
+
+    fn numbered_rows(count: usize) -> QueryResult {
+        QueryResult::new(
+            Vec::new(),
+            (1..=count)
+                .map(|i| vec![serde_json::Value::String(format!("row-{i}"))])
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn results_page_two_renders_its_rows() {
+        let result = numbered_rows(RESULTS_PAGE_SIZE + 3);
+        let (app, query_id) = results_router(&result).await;
+
+        let (status, body) = get_body(app, format!("/results/{query_id}/page/2")).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("datastar-patch-elements"));
+        for i in RESULTS_PAGE_SIZE + 1..=RESULTS_PAGE_SIZE + 3 {
+            assert!(
+                body.contains(&format!("<td>row-{i}</td>")),
+                "row {i} missing"
+            );
+        }
+        assert!(!body.contains(&format!("<td>row-{RESULTS_PAGE_SIZE}</td>")));
+        assert!(body.contains("Page 2 of 2"));
+    }
+
+    #[tokio::test]
+    async fn results_page_past_end_renders_last_page() {
+        let result = numbered_rows(3);
+        let (app, query_id) = results_router(&result).await;
+
+        let (status, body) = get_body(app, format!("/results/{query_id}/page/7")).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("<td>row-3</td>"));
+        assert!(body.contains("Page 1 of 1"));
+    }
+
+    #[tokio::test]
+    async fn results_page_for_unknown_query_is_not_found() {
+        let (app, _) = results_router(&numbered_rows(1)).await;
+
+        let (status, _) = get_body(app, format!("/results/{}/page/1", Uuid::new_v4())).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn chart_type_switch_redraws_cached_result_as_line() {
+        use crate::presentation::chart_transformer::ColumnMetadata;
+
+        let result = QueryResult::new(
+            vec![
+                ColumnMetadata::new("nationality", "VARCHAR"),
+                ColumnMetadata::new("count", "BIGINT"),
+            ],
+            vec![
+                vec![serde_json::json!("USA"), serde_json::json!(123)],
+                vec![serde_json::json!("Russia"), serde_json::json!(72)],
+            ],
+        );
+        let (app, query_id) = results_router(&result).await;
+        let series_type = |t: &str| format!("&quot;type&quot;:&quot;{t}&quot;");
+
+        let (status, bar) = post_chart_type(app.clone(), query_id, "bar").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(bar.contains(&series_type("bar")));
+
+        let (status, line) = post_chart_type(app, query_id, "line").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(line.contains("datastar-patch-elements"));
+        assert!(line.contains(&format!(r#"id="{}""#, chart_slot_id(&query_id))));
+        assert!(line.contains(&series_type("line")));
+        assert!(!line.contains(&series_type("bar")));
+        assert!(line.contains("Russia"));
+    }
+
+    #[tokio::test]
+    async fn repeat_chart_type_switch_is_a_cache_hit() {
+        use crate::presentation::chart_transformer::ColumnMetadata;
+
+        let result = QueryResult::new(
+            vec![
+                ColumnMetadata::new("nationality", "VARCHAR"),
+                ColumnMetadata::new("count", "BIGINT"),
+            ],
+            vec![vec![serde_json::json!("USA"), serde_json::json!(123)]],
+        );
+        let (app, query_id, cached, completed_at) = results_fixture(&result).await;
+
+        let (status, first) = post_chart_type(app.clone(), query_id, "bar").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(first.contains("USA"));
+
+        let config = ChartConfig {
+            chart_type: ChartType::Bar,
+            title: None,
+            category_column: "nationality".to_string(),
+            value_columns: vec!["count".to_string()],
+        };
+        let key = chart_cache_key(
+            "query_session:result:paging",
+            &config,
+            &completed_at.to_rfc3339(),
+        );
+        assert!(cached.cache().get(&key).await.is_some());
+
+        // Overwrite the cached chart: a hit serves it instead of
+        // re-transforming the result.
+        cached
+            .cache()
+            .insert(key, br#"{"title":{"text":"from-cache"}}"#.to_vec())
+            .await;
+
+        let (status, second) = post_chart_type(app, query_id, "bar").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(second.contains("from-cache"));
+        assert!(!second.contains("USA"));
+    }
+
+    /// Start `sql` over HTTP against an in-memory DuckDB and wait for it to
+    /// complete, returning a router serving result pages and chart redraws.
+    /// Nothing is seeded: the result is whatever execution cached.
+    async fn executed_query_router(sql: &str) -> (Router, QueryId) {
+        use crate::infrastructure::assets::AssetManifest;
+        use crate::infrastructure::{AnalyticsCache, CachedAnalyticsService, DuckDBService};
+        use axum::extract::FromRef;
+
+        let duckdb = async_duckdb::PoolBuilder::new()
+            .num_conns(1)
+            .open()
+            .await
+            .expect("in-memory duckdb");
+        let cached = CachedAnalyticsService::new(
+            DuckDBService::new(Some(duckdb.clone())),
+            AnalyticsCache::new(),
+        );
+        let app_state = AppState::new(
+            create_test_pool().await,
+            AssetManifest::default(),
+            crate::infrastructure::metrics::test_prometheus_handle(),
+        )
+        .with_analytics(duckdb)
+        .with_cached_analytics(cached);
+        let analytics_state = AnalyticsAppState::from_ref(&app_state);
+        let router = Router::new()
+            .route("/api/queries", post(start_query))
+            .route("/results/{id}/page/{n}", get(get_results_page))
+            .route("/chart/{id}/type", post(switch_chart_type))
+            .with_state(app_state);
+
+        let (status, body) = send(
+            router.clone(),
+            Request::builder()
+                .method("POST")
+                .uri("/api/queries")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::json!({ "sql": sql }).to_string()))
+                .expect("request body"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::ACCEPTED, "{body}");
+        let started: serde_json::Value = serde_json::from_str(&body).expect("json body");
+        let query_id = QueryId::from_uuid(
+            started["queryId"]
+                .as_str()
+                .and_then(|id| id.parse().ok())
+                .expect("query id"),
+        );
+
+        for _ in 0..500 {
+            let history = query_query_history(&analytics_state.query_session_repo)
+                .await
+                .expect("history");
+            match history
+                .iter()
+                .find(|e| e.query_id == query_id)
+                .map(|e| &e.outcome)
+            {
+                Some(QueryOutcome::Completed { .. }) => return (router, query_id),
+                Some(QueryOutcome::Failed { error, .. }) => panic!("query failed: {error}"),
+                _ => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            }
+        }
+        panic!("query did not complete");
+    }
+
+    #[tokio::test]
+    async fn executed_query_results_can_be_paged() {
+        let sql = format!(
+            "SELECT 'row-' || i AS label FROM range(1, {}) AS t(i)",
+            RESULTS_PAGE_SIZE + 4
+        );
+        let (app, query_id) = executed_query_router(&sql).await;
+
+        let (status, body) = get_body(app, format!("/results/{query_id}/page/2")).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(&format!("<td>row-{}</td>", RESULTS_PAGE_SIZE + 3)));
+        assert!(!body.contains(&format!("<td>row-{RESULTS_PAGE_SIZE}</td>")));
+        assert!(body.contains("Page 2 of 2"));
+    }
+
+    #[tokio::test]
+    async fn executed_query_result_can_switch_chart_type() {
+        let sql = "SELECT * FROM (VALUES ('USA', 123), ('Russia', 72)) AS t(nationality, count)";
+        let (app, query_id) = executed_query_router(sql).await;
+        let series_type = |t: &str| format!("&quot;type&quot;:&quot;{t}&quot;");
+
+        let (status, body) = post_chart_type(app, query_id, "line").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(&series_type("line")));
+        assert!(body.contains("Russia"));
+    }
 }
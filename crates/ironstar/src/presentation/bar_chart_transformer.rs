@@ -4,7 +4,7 @@
 //! This is a presentation concern: mapping query results to chart-specific JSON.
 
 use crate::presentation::chart_transformer::{
-    ChartConfig, ChartTransformer, ChartType, QueryResult, TransformError,
+    ChartConfig, ChartTransformer, ChartType, QueryResult, TransformError, category_axis_option,
 };
 
 /// Transforms query results into ECharts bar chart configuration.
@@ -29,8 +29,8 @@ use crate::presentation::chart_transformer::{
 ///
 /// let result = QueryResult::new(
 ///     vec![
-///         ColumnMetadata { name: "nationality".into(), data_type: "VARCHAR".into() },
-///         ColumnMetadata { name: "count".into(), data_type: "BIGINT".into() },
+///         ColumnMetadata::new("nationality", "VARCHAR"),
+///         ColumnMetadata::new("count", "BIGINT"),
 ///     ],
 ///     vec![
 ///         vec![json!("USA"), json!(123)],
@@ -64,109 +64,21 @@ impl ChartTransformer for BarChartTransformer {
             )));
         }
 
-        // Validate non-empty result
-        if result.rows.is_empty() {
-            return Err(TransformError::EmptyResult);
-        }
-
-        // Find category column index
-        let category_idx = result
-            .column_index(&config.category_column)
-            .ok_or_else(|| TransformError::MissingColumn(config.category_column.clone()))?;
-
-        // Find value column indices
-        let value_indices: Vec<usize> = config
-            .value_columns
-            .iter()
-            .map(|name| {
-                result
-                    .column_index(name)
-                    .ok_or_else(|| TransformError::MissingColumn(name.clone()))
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
-        // Extract category labels (X-axis)
-        let categories: Vec<String> = result
-            .rows
-            .iter()
-            .map(|row| {
-                row.get(category_idx)
-                    .map(|v| match v {
-                        serde_json::Value::String(s) => s.clone(),
-                        other => other.to_string(),
-                    })
-                    .unwrap_or_default()
-            })
-            .collect();
-
-        // Build series (one per value column)
-        let series: Vec<serde_json::Value> = config
-            .value_columns
-            .iter()
-            .zip(value_indices.iter())
-            .map(|(name, &idx)| {
-                let data: Vec<serde_json::Value> = result
-                    .rows
-                    .iter()
-                    .map(|row| row.get(idx).cloned().unwrap_or(serde_json::Value::Null))
-                    .collect();
-
-                serde_json::json!({
-                    "name": name,
-                    "type": "bar",
-                    "data": data
-                })
-            })
-            .collect();
-
-        // Build complete ECharts option
-        let mut option = serde_json::json!({
-            "xAxis": {
-                "type": "category",
-                "data": categories
-            },
-            "yAxis": {
-                "type": "value"
-            },
-            "series": series,
-            "tooltip": {
-                "trigger": "axis"
-            }
-        });
-
-        // Add title if provided
-        if let Some(title) = &config.title
-            && let Some(obj) = option.as_object_mut()
-        {
-            obj.insert(
-                "title".to_string(),
-                serde_json::json!({
-                    "text": title
-                }),
-            );
-        }
-
-        Ok(option)
+        category_axis_option(result, config)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::presentation::chart_transformer::ColumnMetadata;
+    use crate::presentation::chart_transformer::{ColumnMetadata, series_values};
     use serde_json::json;
 
     fn astronaut_result() -> QueryResult {
         QueryResult::new(
             vec![
-                ColumnMetadata {
-                    name: "nationality".into(),
-                    data_type: "VARCHAR".into(),
-                },
-                ColumnMetadata {
-                    name: "count".into(),
-                    data_type: "BIGINT".into(),
-                },
+                ColumnMetadata::new("nationality", "VARCHAR"),
+                ColumnMetadata::new("count", "BIGINT"),
             ],
             vec![
                 vec![json!("USA"), json!(123)],
@@ -205,13 +117,13 @@ mod tests {
         assert_eq!(series.len(), 1);
         assert_eq!(series[0]["name"], "count");
         assert_eq!(series[0]["type"], "bar");
-        assert_eq!(series[0]["data"], json!([123, 72, 18]));
+        assert_eq!(series_values(&series[0]), json!([123, 72, 18]));
 
         // Verify title
         assert_eq!(option["title"]["text"], "Astronauts by Nationality");
 
         // Verify tooltip
-        assert_eq!(option["tooltip"]["trigger"], "axis");
+        assert_eq!(option["tooltip"]["trigger"], "item");
     }
 
     #[test]
@@ -219,18 +131,9 @@ mod tests {
         let transformer = BarChartTransformer;
         let result = QueryResult::new(
             vec![
-                ColumnMetadata {
-                    name: "year".into(),
-                    data_type: "INTEGER".into(),
-                },
-                ColumnMetadata {
-                    name: "sales".into(),
-                    data_type: "DOUBLE".into(),
-                },
-                ColumnMetadata {
-                    name: "revenue".into(),
-                    data_type: "DOUBLE".into(),
-                },
+                ColumnMetadata::new("year", "INTEGER"),
+                ColumnMetadata::new("sales", "DOUBLE"),
+                ColumnMetadata::new("revenue", "DOUBLE"),
             ],
             vec![
                 vec![json!(2022), json!(100.5), json!(150.0)],
@@ -256,10 +159,10 @@ mod tests {
         assert_eq!(series.len(), 2);
 
         assert_eq!(series[0]["name"], "sales");
-        assert_eq!(series[0]["data"], json!([100.5, 120.3, 140.0]));
+        assert_eq!(series_values(&series[0]), json!([100.5, 120.3, 140.0]));
 
         assert_eq!(series[1]["name"], "revenue");
-        assert_eq!(series[1]["data"], json!([150.0, 180.5, 210.2]));
+        assert_eq!(series_values(&series[1]), json!([150.0, 180.5, 210.2]));
 
         // Verify no title when not provided
         assert!(option.get("title").is_none());
@@ -306,14 +209,8 @@ mod tests {
         let transformer = BarChartTransformer;
         let result = QueryResult::new(
             vec![
-                ColumnMetadata {
-                    name: "nationality".into(),
-                    data_type: "VARCHAR".into(),
-                },
-                ColumnMetadata {
-                    name: "count".into(),
-                    data_type: "BIGINT".into(),
-                },
+                ColumnMetadata::new("nationality", "VARCHAR"),
+                ColumnMetadata::new("count", "BIGINT"),
             ],
             vec![], // empty rows
         );
@@ -388,14 +285,8 @@ mod tests {
         let transformer = BarChartTransformer;
         let result = QueryResult::new(
             vec![
-                ColumnMetadata {
-                    name: "category".into(),
-                    data_type: "VARCHAR".into(),
-                },
-                ColumnMetadata {
-                    name: "value".into(),
-                    data_type: "INTEGER".into(),
-                },
+                ColumnMetadata::new("category", "VARCHAR"),
+                ColumnMetadata::new("value", "INTEGER"),
             ],
             vec![
                 vec![json!("A"), json!(10)],
@@ -415,6 +306,6 @@ mod tests {
 
         // Null values should be preserved in the data array
         let series = option["series"].as_array().unwrap();
-        assert_eq!(series[0]["data"], json!([10, null, 30]));
+        assert_eq!(series_values(&series[0]), json!([10, null, 30]));
     }
 }
@@ -0,0 +1,50 @@
+//! Request body size limits.
+//!
+//! Unbounded request bodies let a single client exhaust memory, so every
+//! route in `app_router` sits behind a `RequestBodyLimitLayer`. Requests whose
+//! body exceeds the limit are rejected with `413 Payload Too Large` before a
+//! handler runs.
+//!
+//! Analytics endpoints accept ad hoc SQL, which can legitimately be larger
+//! than the commands and UI state posted elsewhere, so the `/analytics`
+//! router has its own, higher limit.
+
+use tower_http::limit::RequestBodyLimitLayer;
+
+/// Default body limit for all routes: 256 KiB.
+pub const DEFAULT_REQUEST_BODY_LIMIT: usize = 256 * 1024;
+
+/// Default body limit for analytics routes: 1 MiB.
+pub const DEFAULT_ANALYTICS_REQUEST_BODY_LIMIT: usize = 1024 * 1024;
+
+/// Maximum request body sizes in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestBodyLimits {
+    /// Limit applied to every route without a more specific limit.
+    pub default: usize,
+    /// Limit applied to the `/analytics` routes.
+    pub analytics: usize,
+}
+
+impl Default for RequestBodyLimits {
+    fn default() -> Self {
+        Self {
+            default: DEFAULT_REQUEST_BODY_LIMIT,
+            analytics: DEFAULT_ANALYTICS_REQUEST_BODY_LIMIT,
+        }
+    }
+}
+
+impl RequestBodyLimits {
+    /// Layer enforcing the default limit.
+    #[must_use]
+    pub fn default_layer(&self) -> RequestBodyLimitLayer {
+        RequestBodyLimitLayer::new(self.default)
+    }
+
+    /// Layer enforcing the analytics limit.
+    #[must_use]
+    pub fn analytics_layer(&self) -> RequestBodyLimitLayer {
+        RequestBodyLimitLayer::new(self.analytics)
+    }
+}
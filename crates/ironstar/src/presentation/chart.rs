@@ -38,15 +38,18 @@ use hypertext::Renderable;
 use tracing::{instrument, warn};
 
 use crate::domain::signals::ChartSignals;
-use crate::infrastructure::analytics::AnalyticsState;
+use crate::infrastructure::analytics::{AnalyticsState, describe_columns};
 use crate::infrastructure::assets::AssetManifest;
 use crate::infrastructure::sse_stream::SseStreamBuilder;
 use crate::infrastructure::{cache_key, embedded_cache_key_prefix};
 use crate::presentation::bar_chart_transformer::BarChartTransformer;
-use crate::presentation::chart_templates::echarts_chart;
+use crate::presentation::chart_templates::{echarts_chart, echarts_empty_chart};
 use crate::presentation::chart_transformer::{
-    ChartConfig, ChartTransformer, ChartType, ColumnMetadata, QueryResult,
+    ChartConfig, ChartTransformer, ChartType, ColumnMetadata, QueryResult, TransformError,
 };
+use crate::presentation::extractors::Actor;
+use crate::presentation::layout::actor_theme;
+use crate::presentation::workspace::WorkspaceAppState;
 use crate::state::AppState;
 
 /// SSE endpoint for astronaut nationality chart data.
@@ -85,10 +88,14 @@ use crate::state::AppState;
 pub async fn astronauts_chart_sse(
     State(analytics): State<AnalyticsState>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let signals = astronauts_chart_signals(&analytics).await;
-
-    // Render chart template with embedded signals
-    let html = echarts_chart("astronauts-chart", &signals, "400px").render();
+    // Render chart template with embedded signals, or a placeholder when
+    // the query returned no rows
+    let html = match astronauts_chart_signals(&analytics).await {
+        ChartOutcome::Data(signals) => {
+            echarts_chart("astronauts-chart", &signals, "400px").render()
+        }
+        ChartOutcome::Empty => echarts_empty_chart(NO_DATA_MESSAGE).render(),
+    };
 
     // Create SSE event via SDK PatchElements (datastar-patch-elements event type)
     let event: Event = PatchElements::new(html.into_inner()).into();
@@ -96,13 +103,46 @@ pub async fn astronauts_chart_sse(
     Sse::new(stream::once(async move { Ok(event) }))
 }
 
+/// Top ten astronaut nationalities by head count.
+const ASTRONAUTS_BY_NATIONALITY_SQL: &str = "SELECT nationality, COUNT(*) as count
+     FROM space.main.astronauts
+     GROUP BY nationality
+     ORDER BY count DESC
+     LIMIT 10";
+
+/// Message shown when a chart query returns no rows.
+const NO_DATA_MESSAGE: &str = "No data to display";
+
+/// Result of computing a chart from its query.
+enum ChartOutcome {
+    /// Signals to render, carrying either a chart option or an error.
+    Data(ChartSignals),
+    /// The query returned no rows to plot.
+    Empty,
+}
+
+impl ChartOutcome {
+    /// Signals for signal-only transports, reporting an empty chart as an error.
+    fn into_signals(self) -> ChartSignals {
+        match self {
+            Self::Data(signals) => signals,
+            Self::Empty => ChartSignals {
+                chart_option: serde_json::json!({}),
+                selected: None,
+                loading: false,
+                error: Some(NO_DATA_MESSAGE.to_string()),
+            },
+        }
+    }
+}
+
 /// Produce chart signals for the astronaut nationality chart.
 ///
 /// Uses the cached analytics service when available to memoize the DuckDB
 /// query result as JSON bytes.
 /// On cache hit, the cached JSON is deserialized directly without querying DuckDB.
 /// On cache miss, the query executes, the result is cached, and signals are returned.
-async fn astronauts_chart_signals(analytics: &AnalyticsState) -> ChartSignals {
+async fn astronauts_chart_signals(analytics: &AnalyticsState) -> ChartOutcome {
     let key = cache_key(
         &embedded_cache_key_prefix("space", "astronauts"),
         &"nationality_counts_top10",
@@ -113,25 +153,23 @@ async fn astronauts_chart_signals(analytics: &AnalyticsState) -> ChartSignals {
         && let Some(bytes) = cached.cache().get(&key).await
         && let Ok(chart_option) = serde_json::from_slice::<serde_json::Value>(&bytes)
     {
-        return ChartSignals {
+        return ChartOutcome::Data(ChartSignals {
             chart_option,
             selected: None,
             loading: false,
             error: None,
-        };
+        });
     }
 
     // Cache miss or no cache: execute query.
     let query_result = analytics
         .service
         .query(|conn| {
-            let mut stmt = conn.prepare(
-                "SELECT nationality, COUNT(*) as count
-             FROM space.main.astronauts
-             GROUP BY nationality
-             ORDER BY count DESC
-             LIMIT 10",
-            )?;
+            let columns = describe_columns(conn, ASTRONAUTS_BY_NATIONALITY_SQL)?
+                .into_iter()
+                .map(ColumnMetadata::from)
+                .collect();
+            let mut stmt = conn.prepare(ASTRONAUTS_BY_NATIONALITY_SQL)?;
 
             let rows: Vec<Vec<serde_json::Value>> = stmt
                 .query_map([], |row| {
@@ -142,19 +180,7 @@ async fn astronauts_chart_signals(analytics: &AnalyticsState) -> ChartSignals {
                 })?
                 .collect::<Result<Vec<_>, _>>()?;
 
-            Ok(QueryResult::new(
-                vec![
-                    ColumnMetadata {
-                        name: "nationality".to_string(),
-                        data_type: "VARCHAR".to_string(),
-                    },
-                    ColumnMetadata {
-                        name: "count".to_string(),
-                        data_type: "BIGINT".to_string(),
-                    },
-                ],
-                rows,
-            ))
+            Ok(QueryResult::new(columns, rows))
         })
         .await;
 
@@ -175,27 +201,28 @@ async fn astronauts_chart_signals(analytics: &AnalyticsState) -> ChartSignals {
                     {
                         cached.cache().insert(key, bytes).await;
                     }
-                    ChartSignals {
+                    ChartOutcome::Data(ChartSignals {
                         chart_option,
                         selected: None,
                         loading: false,
                         error: None,
-                    }
+                    })
                 }
-                Err(e) => ChartSignals {
+                Err(TransformError::EmptyResult) => ChartOutcome::Empty,
+                Err(e) => ChartOutcome::Data(ChartSignals {
                     chart_option: serde_json::json!({}),
                     selected: None,
                     loading: false,
                     error: Some(format!("Transform error: {e}")),
-                },
+                }),
             }
         }
-        Err(e) => ChartSignals {
+        Err(e) => ChartOutcome::Data(ChartSignals {
             chart_option: serde_json::json!({}),
             selected: None,
             loading: false,
             error: Some(format!("Query error: {e}")),
-        },
+        }),
     }
 }
 
@@ -207,12 +234,17 @@ async fn astronauts_chart_signals(analytics: &AnalyticsState) -> ChartSignals {
 /// # Route
 ///
 /// GET /charts/astronauts
-#[instrument(name = "handler.chart.astronauts_page", skip(manifest))]
-pub async fn astronauts_chart_page(State(manifest): State<AssetManifest>) -> impl IntoResponse {
+#[instrument(name = "handler.chart.astronauts_page", skip(manifest, workspace))]
+pub async fn astronauts_chart_page(
+    State(manifest): State<AssetManifest>,
+    State(workspace): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+) -> impl IntoResponse {
     use crate::presentation::chart_templates::chart_page;
 
     let html = chart_page(
         &manifest,
+        actor_theme(&workspace, &actor).await,
         "Astronaut Demographics",
         "astronauts",
         "/charts/api/astronauts/data",
@@ -282,7 +314,7 @@ pub async fn chart_feed_handler(
     })
     .chain(stream::once(async move {
         // Compute chart signals from DuckDB.
-        let signals = astronauts_chart_signals(&analytics).await;
+        let signals = astronauts_chart_signals(&analytics).await.into_signals();
         let signals_json = serde_json::to_string(&signals).unwrap_or_else(|e| {
             warn!(error = %e, "Failed to serialize ChartSignals");
             r#"{"chartOption":{},"loading":false,"error":"Serialization error"}"#.to_string()
@@ -332,14 +364,8 @@ mod tests {
 
         let result = QueryResult::new(
             vec![
-                ColumnMetadata {
-                    name: "nationality".to_string(),
-                    data_type: "VARCHAR".to_string(),
-                },
-                ColumnMetadata {
-                    name: "count".to_string(),
-                    data_type: "BIGINT".to_string(),
-                },
+                ColumnMetadata::new("nationality", "VARCHAR"),
+                ColumnMetadata::new("count", "BIGINT"),
             ],
             rows,
         );
@@ -375,14 +401,8 @@ mod tests {
     fn transformation_produces_valid_echarts_option() {
         let result = QueryResult::new(
             vec![
-                ColumnMetadata {
-                    name: "nationality".to_string(),
-                    data_type: "VARCHAR".to_string(),
-                },
-                ColumnMetadata {
-                    name: "count".to_string(),
-                    data_type: "BIGINT".to_string(),
-                },
+                ColumnMetadata::new("nationality", "VARCHAR"),
+                ColumnMetadata::new("count", "BIGINT"),
             ],
             vec![
                 vec![json!("USA"), json!(123)],
@@ -448,14 +468,8 @@ mod tests {
     fn transformation_handles_empty_result() {
         let result = QueryResult::new(
             vec![
-                ColumnMetadata {
-                    name: "nationality".to_string(),
-                    data_type: "VARCHAR".to_string(),
-                },
-                ColumnMetadata {
-                    name: "count".to_string(),
-                    data_type: "BIGINT".to_string(),
-                },
+                ColumnMetadata::new("nationality", "VARCHAR"),
+                ColumnMetadata::new("count", "BIGINT"),
             ],
             vec![], // Empty rows
         );
@@ -469,10 +483,16 @@ mod tests {
 
         // BarChartTransformer should return EmptyResult error
         let err = BarChartTransformer.transform(&result, &config).unwrap_err();
-        assert!(matches!(
-            err,
-            crate::presentation::chart_transformer::TransformError::EmptyResult
-        ));
+        assert!(matches!(err, TransformError::EmptyResult));
+    }
+
+    /// Verify an empty chart reaches signal-only clients as a message.
+    #[test]
+    fn empty_outcome_reports_no_data_message() {
+        let signals = ChartOutcome::Empty.into_signals();
+
+        assert_eq!(signals.error.as_deref(), Some(NO_DATA_MESSAGE));
+        assert_eq!(signals.chart_option, serde_json::json!({}));
     }
 
     /// Verify chart_feed_handler returns 404 for unknown chart IDs.
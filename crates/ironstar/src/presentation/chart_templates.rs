@@ -41,9 +41,15 @@
 use hypertext::Raw;
 use hypertext::prelude::*;
 
+use crate::domain::QueryId;
 use crate::domain::signals::ChartSignals;
+use crate::domain::user_preferences::values::Theme;
 use crate::infrastructure::assets::AssetManifest;
-use crate::presentation::layout::base_layout;
+use crate::presentation::components::{button, loading_spinner};
+use crate::presentation::layout::themed_layout;
+
+/// Longest error message, in characters, shown in a chart slot.
+const MAX_CHART_ERROR_CHARS: usize = 200;
 
 /// Renders the ds-echarts custom element with Datastar attributes.
 ///
@@ -108,6 +114,93 @@ pub fn echarts_chart(id: &str, signals: &ChartSignals, height: &str) -> impl Ren
     }
 }
 
+/// Renders a placeholder in place of a chart whose query returned no rows.
+///
+/// ECharts draws an empty canvas for an empty dataset, which reads as a
+/// broken chart; this template explains the absence of data instead.
+pub fn echarts_empty_chart(message: &str) -> impl Renderable {
+    maud! {
+        div class="chart-container chart-empty" role="status" {
+            p class="chart-empty-message" { (message) }
+        }
+    }
+}
+
+/// Element id of the chart slot for a query session.
+///
+/// The completion SSE fragment targets this id to replace the loading
+/// placeholder with the rendered chart, empty state, or error.
+#[must_use]
+pub fn chart_slot_id(session_id: &QueryId) -> String {
+    format!("chart-{session_id}")
+}
+
+/// Datastar signal that is `true` while a query session is executing.
+///
+/// Signal names cannot contain hyphens, so the id is embedded in its
+/// simple (unhyphenated) form.
+#[must_use]
+pub fn chart_loading_signal(session_id: &QueryId) -> String {
+    format!("query{}Loading", session_id.into_inner().simple())
+}
+
+/// Renders a chart slot placeholder while a query session executes.
+///
+/// The spinner is shown while the session's loading signal is `true`; the
+/// fragment delivered when the session completes replaces the whole slot
+/// by [`chart_slot_id`].
+pub fn echarts_chart_loading(session_id: &QueryId) -> impl Renderable {
+    let slot_id = chart_slot_id(session_id);
+    let signal = chart_loading_signal(session_id);
+    let signals_json = format!(r#"{{"{signal}":true}}"#);
+
+    maud! {
+        div
+            id=(slot_id)
+            class="chart-container chart-pending"
+            "data-signals"=(signals_json)
+            "aria-busy"="true"
+        {
+            (loading_spinner(&signal))
+            span class="chart-pending-message" { "Running query..." }
+        }
+    }
+}
+
+/// Renders a failed chart with its error and a retry button.
+///
+/// Error messages longer than 200 characters are truncated with an
+/// ellipsis so raw DuckDB errors cannot overflow the chart slot.
+///
+/// # Arguments
+///
+/// * `error` - Error message to display
+/// * `retry_url` - Endpoint re-issued via Datastar `@get` when retrying
+///
+/// # XSS safety
+///
+/// The error text is escaped by maud. `retry_url` is interpolated into a
+/// Datastar expression and must be a server-generated path.
+pub fn echarts_chart_error(error: &str, retry_url: &str) -> impl Renderable {
+    let message = truncate_error(error);
+    let retry_attrs = format!(r#"data-on:click="@get('{retry_url}')""#);
+
+    maud! {
+        div class="chart-container chart-error" role="alert" {
+            p class="chart-error-message" { (message) }
+            (button("Retry", "outlined", Some("small"), &retry_attrs))
+        }
+    }
+}
+
+/// Shorten `error` to at most [`MAX_CHART_ERROR_CHARS`] characters.
+fn truncate_error(error: &str) -> String {
+    match error.char_indices().nth(MAX_CHART_ERROR_CHARS) {
+        Some((end, _)) => format!("{}…", error.get(..end).unwrap_or(error)),
+        None => error.to_string(),
+    }
+}
+
 /// Renders a chart page with SSE connection for data streaming.
 ///
 /// This template provides the outer page structure for chart-focused views,
@@ -117,6 +210,7 @@ pub fn echarts_chart(id: &str, signals: &ChartSignals, height: &str) -> impl Ren
 /// # Arguments
 ///
 /// * `manifest` - Asset manifest for CSS/JS paths
+/// * `theme` - Theme of the user the page is rendered for
 /// * `title` - Page title displayed as h1
 /// * `chart_id` - ID for the chart container (used in DOM targeting)
 /// * `sse_endpoint` - SSE endpoint path (e.g., "/api/charts/astronauts/data")
@@ -128,6 +222,7 @@ pub fn echarts_chart(id: &str, signals: &ChartSignals, height: &str) -> impl Ren
 /// the chart configuration.
 pub fn chart_page(
     manifest: &AssetManifest,
+    theme: Theme,
     title: &str,
     chart_id: &str,
     sse_endpoint: &str,
@@ -151,7 +246,7 @@ pub fn chart_page(
         }
     };
 
-    base_layout(manifest, content)
+    themed_layout(manifest, theme, content)
 }
 
 /// Renders a chart with selection feedback section.
@@ -318,6 +413,67 @@ mod tests {
         assert!(body.contains("&lt;script&gt;"));
     }
 
+    #[test]
+    fn echarts_empty_chart_renders_message() {
+        let raw = echarts_empty_chart("No astronauts match this filter");
+        let html = raw.render();
+        let body = html.as_inner();
+
+        assert!(body.contains("chart-empty"));
+        assert!(body.contains("No astronauts match this filter"));
+        assert!(!body.contains("ds-echarts"));
+    }
+
+    #[test]
+    fn echarts_empty_chart_escapes_message() {
+        let raw = echarts_empty_chart("<script>alert(1)</script>");
+        let html = raw.render();
+        let body = html.as_inner();
+
+        assert!(!body.contains("<script>"));
+        assert!(body.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn echarts_chart_error_renders_message_and_retry() {
+        let raw = echarts_chart_error(
+            "Query error: table not found",
+            "/charts/api/astronauts/data",
+        );
+        let html = raw.render();
+        let body = html.as_inner();
+
+        assert!(body.contains("Query error: table not found"));
+        assert!(body.contains(">Retry</button>"));
+        assert!(body.contains(r#"data-on:click="@get('/charts/api/astronauts/data')""#));
+    }
+
+    #[test]
+    fn echarts_chart_error_truncates_long_messages() {
+        let error = "x".repeat(MAX_CHART_ERROR_CHARS + 50);
+        let raw = echarts_chart_error(&error, "/retry");
+        let html = raw.render();
+        let body = html.as_inner();
+
+        let shown = format!("{}…", "x".repeat(MAX_CHART_ERROR_CHARS));
+        assert!(body.contains(&shown));
+        assert!(!body.contains(&error));
+    }
+
+    #[test]
+    fn echarts_chart_loading_binds_spinner_to_session() {
+        let session_id = QueryId::from_uuid(uuid::Uuid::from_u128(0xabc));
+        let raw = echarts_chart_loading(&session_id);
+        let html = raw.render();
+        let body = html.as_inner();
+
+        let signal = chart_loading_signal(&session_id);
+        assert!(body.contains(&format!(r#"id="chart-{session_id}""#)));
+        assert!(body.contains("loading-spinner"));
+        assert!(body.contains(&format!(r#"data-show="${signal}""#)));
+        assert!(!signal.contains('-'));
+    }
+
     fn test_manifest() -> AssetManifest {
         AssetManifest::default()
     }
@@ -327,6 +483,7 @@ mod tests {
         let manifest = test_manifest();
         let raw = chart_page(
             &manifest,
+            Theme::System,
             "Test Chart",
             "astronauts",
             "/api/charts/astronauts/data",
@@ -345,7 +502,13 @@ mod tests {
     #[test]
     fn chart_page_renders_title() {
         let manifest = test_manifest();
-        let raw = chart_page(&manifest, "Astronaut Statistics", "astronauts", "/api/data");
+        let raw = chart_page(
+            &manifest,
+            Theme::System,
+            "Astronaut Statistics",
+            "astronauts",
+            "/api/data",
+        );
         let html = raw.render();
         let body = html.as_inner();
 
@@ -356,7 +519,7 @@ mod tests {
     #[test]
     fn chart_page_renders_container_with_id() {
         let manifest = test_manifest();
-        let raw = chart_page(&manifest, "Test", "my-chart", "/api/data");
+        let raw = chart_page(&manifest, Theme::System, "Test", "my-chart", "/api/data");
         let html = raw.render();
         let body = html.as_inner();
 
@@ -366,7 +529,13 @@ mod tests {
     #[test]
     fn chart_page_escapes_xss_in_title() {
         let manifest = test_manifest();
-        let raw = chart_page(&manifest, "<script>alert(1)</script>", "test", "/api/data");
+        let raw = chart_page(
+            &manifest,
+            Theme::System,
+            "<script>alert(1)</script>",
+            "test",
+            "/api/data",
+        );
         let html = raw.render();
         let body = html.as_inner();
 
@@ -378,15 +547,15 @@ mod tests {
     #[test]
     fn chart_page_renders_full_html_document() {
         let manifest = test_manifest();
-        let raw = chart_page(&manifest, "Test Chart", "test", "/api/data");
+        let raw = chart_page(&manifest, Theme::System, "Test Chart", "test", "/api/data");
         let html = raw.render();
         let body = html.as_inner();
 
-        // Should have full HTML document structure from base_layout
+        // Should have full HTML document structure from themed_layout
         assert!(body.starts_with("<!DOCTYPE html>"));
         assert!(body.contains("<html"));
         assert!(body.contains("<head>"));
-        assert!(body.contains("<body>"));
+        assert!(body.contains("<body "));
     }
 
     #[test]
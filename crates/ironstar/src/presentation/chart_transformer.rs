@@ -28,8 +28,8 @@
 //!
 //! let result = QueryResult {
 //!     columns: vec![
-//!         ColumnMetadata { name: "category".into(), data_type: "VARCHAR".into() },
-//!         ColumnMetadata { name: "value".into(), data_type: "INTEGER".into() },
+//!         ColumnMetadata::new("category", "VARCHAR"),
+//!         ColumnMetadata::new("value", "INTEGER"),
 //!     ],
 //!     rows: vec![
 //!         vec![json!("A"), json!(10)],
@@ -50,6 +50,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::infrastructure::analytics::{ColumnType, ResultColumn};
+use crate::presentation::bar_chart_transformer::BarChartTransformer;
+use crate::presentation::line_chart_transformer::LineChartTransformer;
+
 /// Column metadata from DuckDB query results.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ColumnMetadata {
@@ -57,6 +61,32 @@ pub struct ColumnMetadata {
     pub name: String,
     /// DuckDB type as string (e.g., "VARCHAR", "INTEGER", "DOUBLE").
     pub data_type: String,
+    /// Coarse kind derived from `data_type`.
+    #[serde(default)]
+    pub column_type: ColumnType,
+}
+
+impl ColumnMetadata {
+    /// Create column metadata, deriving its kind from the DuckDB type name.
+    #[must_use]
+    pub fn new(name: impl Into<String>, data_type: impl Into<String>) -> Self {
+        let data_type = data_type.into();
+        Self {
+            name: name.into(),
+            column_type: ColumnType::from_type_name(&data_type),
+            data_type,
+        }
+    }
+}
+
+impl From<ResultColumn> for ColumnMetadata {
+    fn from(column: ResultColumn) -> Self {
+        Self {
+            name: column.name,
+            data_type: column.data_type,
+            column_type: column.column_type,
+        }
+    }
 }
 
 /// Query result for chart transformation.
@@ -64,7 +94,10 @@ pub struct ColumnMetadata {
 /// Captures DuckDB query output in a format suitable for chart transformers.
 /// This is a presentation-layer type, not a domain type. It serves as the
 /// input to chart transformers and is constructed from DuckDB query results.
-#[derive(Clone, Debug)]
+/// Completed query results are cached under the query's `result_ref` as the
+/// JSON of a [`ResultRows`](crate::infrastructure::analytics::ResultRows),
+/// which deserializes as this type, for result paging.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct QueryResult {
     /// Column metadata describing the result schema.
     pub columns: Vec<ColumnMetadata>,
@@ -140,7 +173,7 @@ pub enum TransformError {
 /// Configuration for chart transformation.
 ///
 /// Specifies how to map query result columns to chart axes and series.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ChartConfig {
     /// Type of chart to generate.
     pub chart_type: ChartType,
@@ -153,7 +186,7 @@ pub struct ChartConfig {
 }
 
 /// Supported chart types for ECharts transformation.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ChartType {
     /// Vertical bar chart.
@@ -212,6 +245,219 @@ pub trait ChartTransformer {
     ) -> Result<serde_json::Value, TransformError>;
 }
 
+/// Build a category-axis ECharts option: one series per value column.
+///
+/// Shared by the bar and line transformers, which differ only in the series
+/// type taken from `config.chart_type`.
+///
+/// # Errors
+///
+/// Returns `EmptyResult` when the result has no rows and `MissingColumn`
+/// when a configured column is absent.
+pub(crate) fn category_axis_option(
+    result: &QueryResult,
+    config: &ChartConfig,
+) -> Result<serde_json::Value, TransformError> {
+    // Validate non-empty result
+    if result.rows.is_empty() {
+        return Err(TransformError::EmptyResult);
+    }
+
+    // Find category column index
+    let category_idx = result
+        .column_index(&config.category_column)
+        .ok_or_else(|| TransformError::MissingColumn(config.category_column.clone()))?;
+
+    // Find value column indices
+    let value_indices: Vec<usize> = config
+        .value_columns
+        .iter()
+        .map(|name| {
+            result
+                .column_index(name)
+                .ok_or_else(|| TransformError::MissingColumn(name.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Extract category labels (X-axis)
+    let categories: Vec<String> = result
+        .rows
+        .iter()
+        .map(|row| {
+            row.get(category_idx)
+                .map(|v| match v {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .unwrap_or_default()
+        })
+        .collect();
+
+    // Build series (one per value column), labelling each point with its
+    // formatted value and giving it a tooltip that shows the same text
+    let series: Vec<serde_json::Value> = config
+        .value_columns
+        .iter()
+        .zip(value_indices.iter())
+        .map(|(name, &idx)| {
+            let column_type = result
+                .columns
+                .get(idx)
+                .map(|c| c.column_type)
+                .unwrap_or_default();
+            let data: Vec<serde_json::Value> = result
+                .rows
+                .iter()
+                .map(|row| {
+                    let value = row.get(idx).cloned().unwrap_or(serde_json::Value::Null);
+                    match value.as_f64() {
+                        Some(v) => {
+                            let formatted = format_chart_value(v, &column_type);
+                            serde_json::json!({
+                                "value": value,
+                                "label": {"show": true, "formatter": formatted},
+                                "tooltip": {"formatter": format!("{{a}}<br/>{{b}}: {formatted}")}
+                            })
+                        }
+                        None => value,
+                    }
+                })
+                .collect();
+
+            serde_json::json!({
+                "name": name,
+                "type": config.chart_type.echarts_type(),
+                "data": data
+            })
+        })
+        .collect();
+
+    // Build complete ECharts option
+    let mut option = serde_json::json!({
+        "xAxis": {
+            "type": "category",
+            "data": categories
+        },
+        "yAxis": {
+            "type": "value"
+        },
+        "series": series,
+        "tooltip": {
+            "trigger": "item"
+        }
+    });
+
+    // Add title if provided
+    if let Some(title) = &config.title
+        && let Some(obj) = option.as_object_mut()
+    {
+        obj.insert(
+            "title".to_string(),
+            serde_json::json!({
+                "text": title
+            }),
+        );
+    }
+
+    Ok(option)
+}
+
+/// Decimal places [`format_chart_value`] keeps for float columns.
+pub const DEFAULT_CHART_DECIMALS: usize = 2;
+
+/// Smallest magnitude [`format_chart_value`] groups with thousands separators.
+///
+/// Four-digit values such as years stay ungrouped.
+const THOUSANDS_SEPARATOR_MIN: f64 = 10_000.0;
+
+/// Format a chart value for display in labels and tooltips.
+///
+/// Integer columns render without decimals. Other columns round to
+/// [`DEFAULT_CHART_DECIMALS`] places with trailing zeros dropped, so
+/// `3.999999998` renders as `4`. Magnitudes of 10,000 and above are grouped
+/// with thousands separators.
+#[must_use]
+pub fn format_chart_value(v: f64, column_type: &ColumnType) -> String {
+    format_chart_value_with_decimals(v, column_type, DEFAULT_CHART_DECIMALS)
+}
+
+/// [`format_chart_value`] with a caller-chosen number of decimal places.
+#[must_use]
+pub fn format_chart_value_with_decimals(
+    v: f64,
+    column_type: &ColumnType,
+    decimals: usize,
+) -> String {
+    if !v.is_finite() {
+        return v.to_string();
+    }
+
+    let decimals = if *column_type == ColumnType::Integer {
+        0
+    } else {
+        decimals
+    };
+    let rounded = format!("{v:.decimals$}");
+    let trimmed = if rounded.contains('.') {
+        rounded.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &rounded
+    };
+    let trimmed = if trimmed == "-0" { "0" } else { trimmed };
+
+    if v.abs() < THOUSANDS_SEPARATOR_MIN {
+        return trimmed.to_string();
+    }
+
+    let (sign, unsigned) = match trimmed.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", trimmed),
+    };
+    let (whole, fraction) = match unsigned.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+    for (i, digit) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    match fraction {
+        Some(fraction) => format!("{sign}{grouped}.{fraction}"),
+        None => format!("{sign}{grouped}"),
+    }
+}
+
+/// Raw values of a series built by [`category_axis_option`], with point
+/// labels stripped.
+#[cfg(test)]
+pub(crate) fn series_values(series: &serde_json::Value) -> serde_json::Value {
+    series["data"]
+        .as_array()
+        .map(|data| {
+            data.iter()
+                .map(|point| point.get("value").cloned().unwrap_or_else(|| point.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The transformer that renders `chart_type`, if one exists.
+///
+/// Pie and scatter charts have no transformer yet.
+#[must_use]
+pub fn transformer_for(chart_type: ChartType) -> Option<&'static dyn ChartTransformer> {
+    match chart_type {
+        ChartType::Bar => Some(&BarChartTransformer),
+        ChartType::Line => Some(&LineChartTransformer),
+        ChartType::Pie | ChartType::Scatter => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,14 +466,8 @@ mod tests {
     #[test]
     fn query_result_construction() {
         let columns = vec![
-            ColumnMetadata {
-                name: "category".into(),
-                data_type: "VARCHAR".into(),
-            },
-            ColumnMetadata {
-                name: "value".into(),
-                data_type: "INTEGER".into(),
-            },
+            ColumnMetadata::new("category", "VARCHAR"),
+            ColumnMetadata::new("value", "INTEGER"),
         ];
         let rows = vec![
             vec![json!("A"), json!(10)],
@@ -244,10 +484,7 @@ mod tests {
 
     #[test]
     fn query_result_empty() {
-        let columns = vec![ColumnMetadata {
-            name: "x".into(),
-            data_type: "INTEGER".into(),
-        }];
+        let columns = vec![ColumnMetadata::new("x", "INTEGER")];
         let rows = vec![];
 
         let result = QueryResult::new(columns, rows);
@@ -259,18 +496,9 @@ mod tests {
     #[test]
     fn query_result_column_lookup() {
         let columns = vec![
-            ColumnMetadata {
-                name: "a".into(),
-                data_type: "VARCHAR".into(),
-            },
-            ColumnMetadata {
-                name: "b".into(),
-                data_type: "INTEGER".into(),
-            },
-            ColumnMetadata {
-                name: "c".into(),
-                data_type: "DOUBLE".into(),
-            },
+            ColumnMetadata::new("a", "VARCHAR"),
+            ColumnMetadata::new("b", "INTEGER"),
+            ColumnMetadata::new("c", "DOUBLE"),
         ];
         let result = QueryResult::new(columns, vec![]);
 
@@ -281,6 +509,15 @@ mod tests {
 
         let col_b = result.column("b").unwrap();
         assert_eq!(col_b.data_type, "INTEGER");
+        assert_eq!(col_b.column_type, ColumnType::Integer);
+    }
+
+    #[test]
+    fn column_metadata_from_result_column_keeps_type() {
+        let column = ColumnMetadata::from(ResultColumn::new("ratio", "DECIMAL(18,3)"));
+
+        assert_eq!(column, ColumnMetadata::new("ratio", "DECIMAL(18,3)"));
+        assert_eq!(column.column_type, ColumnType::Float);
     }
 
     #[test]
@@ -307,23 +544,75 @@ mod tests {
 
     #[test]
     fn column_metadata_equality() {
-        let col1 = ColumnMetadata {
-            name: "x".into(),
-            data_type: "INTEGER".into(),
-        };
-        let col2 = ColumnMetadata {
-            name: "x".into(),
-            data_type: "INTEGER".into(),
-        };
-        let col3 = ColumnMetadata {
-            name: "x".into(),
-            data_type: "VARCHAR".into(),
-        };
+        let col1 = ColumnMetadata::new("x", "INTEGER");
+        let col2 = ColumnMetadata::new("x", "INTEGER");
+        let col3 = ColumnMetadata::new("x", "VARCHAR");
 
         assert_eq!(col1, col2);
         assert_ne!(col1, col3);
     }
 
+    #[test]
+    fn format_chart_value_renders_integers_without_decimals() {
+        assert_eq!(format_chart_value(42.0, &ColumnType::Integer), "42");
+        assert_eq!(format_chart_value(-7.0, &ColumnType::Integer), "-7");
+        assert_eq!(format_chart_value(2024.0, &ColumnType::Integer), "2024");
+        assert_eq!(format_chart_value(5.0, &ColumnType::Float), "5");
+    }
+
+    #[test]
+    fn format_chart_value_rounds_floats() {
+        assert_eq!(format_chart_value(3.999_999_998, &ColumnType::Float), "4");
+        assert_eq!(format_chart_value(0.125_6, &ColumnType::Float), "0.13");
+        assert_eq!(format_chart_value(2.5, &ColumnType::Float), "2.5");
+        assert_eq!(format_chart_value(-0.001, &ColumnType::Float), "0");
+        assert_eq!(
+            format_chart_value_with_decimals(1.234_56, &ColumnType::Float, 4),
+            "1.2346"
+        );
+        assert_eq!(format_chart_value(f64::NAN, &ColumnType::Float), "NaN");
+    }
+
+    #[test]
+    fn format_chart_value_groups_large_numbers() {
+        assert_eq!(
+            format_chart_value(1_234_567.0, &ColumnType::Integer),
+            "1,234,567"
+        );
+        assert_eq!(
+            format_chart_value(-98_765.432, &ColumnType::Float),
+            "-98,765.43"
+        );
+        assert_eq!(format_chart_value(100_000.0, &ColumnType::Float), "100,000");
+        assert_eq!(format_chart_value(9_999.0, &ColumnType::Integer), "9999");
+    }
+
+    #[test]
+    fn category_axis_points_carry_formatted_labels() {
+        let result = QueryResult::new(
+            vec![
+                ColumnMetadata::new("month", "VARCHAR"),
+                ColumnMetadata::new("ratio", "DOUBLE"),
+            ],
+            vec![vec![json!("Jan"), json!(3.999_999_998)]],
+        );
+        let config = ChartConfig {
+            chart_type: ChartType::Bar,
+            title: None,
+            category_column: "month".into(),
+            value_columns: vec!["ratio".into()],
+        };
+
+        let option = category_axis_option(&result, &config).unwrap();
+        let point = &option["series"][0]["data"][0];
+
+        assert_eq!(point["value"], json!(3.999_999_998));
+        assert_eq!(point["label"]["show"], true);
+        assert_eq!(point["label"]["formatter"], "4");
+        assert_eq!(option["tooltip"]["trigger"], "item");
+        assert_eq!(point["tooltip"]["formatter"], "{a}<br/>{b}: 4");
+    }
+
     #[test]
     fn transform_error_display() {
         let err = TransformError::MissingColumn("category".into());
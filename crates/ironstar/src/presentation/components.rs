@@ -6,6 +6,8 @@
 use hypertext::Raw;
 use hypertext::prelude::*;
 
+use crate::domain::CatalogMetadata;
+
 /// Button component.
 ///
 /// # Arguments
@@ -161,9 +163,131 @@ pub fn icon(name: &str, size: Option<&str>) -> impl Renderable {
     }
 }
 
+/// Dataset picker component with a filter box.
+///
+/// Renders a search input bound to the `datasetFilter` signal and a `<select>`
+/// bound to `dataset`, with one option per catalog dataset labelled with its
+/// row count when known. Each option hides itself via `data-show` when its
+/// name does not contain the filter text, ignoring case, so the browser
+/// narrows the list with the same rule as [`CatalogMetadata::datasets_matching`].
+///
+/// # Arguments
+///
+/// * `catalog` - Catalog metadata listing the datasets
+/// * `selected` - Name of the currently selected dataset, if any
+///
+/// # Example
+///
+/// ```no_run
+/// use ironstar::domain::CatalogMetadata;
+/// use ironstar::presentation::components::dataset_picker;
+/// # fn render(catalog: &CatalogMetadata) {
+/// let picker = dataset_picker(catalog, Some("genomics"));
+/// # }
+/// ```
+pub fn dataset_picker(catalog: &CatalogMetadata, selected: Option<&str>) -> impl Renderable {
+    let options: Vec<(String, String, bool, String)> = catalog
+        .datasets
+        .iter()
+        .map(|dataset| {
+            let label = match dataset.row_count {
+                Some(rows) => format!("{} ({rows} rows)", dataset.name),
+                None => dataset.name.clone(),
+            };
+            // JSON string literal so dataset names cannot break out of the expression
+            let name_literal = serde_json::Value::String(dataset.name.to_lowercase());
+            let show = format!("{name_literal}.includes($datasetFilter.trim().toLowerCase())");
+            let is_selected = selected == Some(dataset.name.as_str());
+            (dataset.name.clone(), label, is_selected, show)
+        })
+        .collect();
+
+    maud! {
+        div class="dataset-picker stack" {
+            input
+                type="search"
+                name="datasetFilter"
+                placeholder="Filter datasets"
+                aria-label="Filter datasets"
+                "data-bind"="datasetFilter";
+            select name="dataset" aria-label="Dataset" "data-bind"="dataset" {
+                option value="" disabled selected[selected.is_none()] { "Choose a dataset" }
+                @for (name, label, is_selected, show) in &options {
+                    option value=(name) selected[*is_selected] "data-show"=(show) { (label) }
+                }
+            }
+        }
+    }
+}
+
+/// Element id of the SQL editor's completions list.
+pub const SQL_COMPLETIONS_ID: &str = "sql-completions";
+
+/// SQL editor component.
+///
+/// Renders a `<textarea>` two-way bound to the `sql` signal, a run button
+/// posting the signals to the analytics query endpoint, and an empty
+/// completions list. Typing pauses trigger a debounced GET to
+/// `completions_url`, whose SSE response patches the element with id
+/// [`SQL_COMPLETIONS_ID`]. The initial SQL is HTML-escaped, and the URL is
+/// carried in a `data-completions-url` attribute rather than spliced into
+/// the Datastar expression, so quotes in it cannot alter the expression.
+///
+/// # Arguments
+///
+/// * `initial_sql` - SQL shown when the editor first renders
+/// * `completions_url` - Endpoint returning completion suggestions
+///
+/// # Example
+///
+/// ```no_run
+/// use ironstar::presentation::components::sql_editor;
+/// let editor = sql_editor("SELECT 1", "/analytics/api/completions");
+/// ```
+pub fn sql_editor(initial_sql: &str, completions_url: &str) -> impl Renderable {
+    let run_attrs =
+        r#"data-on:click="@post('/analytics/api/queries')" data-indicator="runningQuery""#;
+
+    maud! {
+        div class="sql-editor stack" {
+            textarea
+                name="sql"
+                class="sql-editor-input"
+                rows="8"
+                spellcheck="false"
+                aria-label="SQL"
+                aria-controls=(SQL_COMPLETIONS_ID)
+                "data-bind"="sql"
+                "data-completions-url"=(completions_url)
+                "data-on:input__debounce.300ms"="@get(el.dataset.completionsUrl)"
+            { (initial_sql) }
+            ul id=(SQL_COMPLETIONS_ID) class="sql-completions" role="listbox" {}
+            div class="cluster" {
+                (button("Run", "filled", None, run_attrs))
+                (loading_spinner("runningQuery"))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::DatasetInfo;
+    use chrono::Utc;
+
+    fn catalog() -> CatalogMetadata {
+        let dataset = |name: &str, row_count| DatasetInfo {
+            name: name.to_string(),
+            table_count: 1,
+            schema_version: "1.0".to_string(),
+            row_count,
+        };
+        CatalogMetadata {
+            datasets: vec![dataset("genomics", Some(1200)), dataset("imaging", None)],
+            last_refreshed: Utc::now(),
+        }
+    }
 
     #[test]
     fn button_renders_with_variant() {
@@ -262,4 +386,71 @@ mod tests {
 
         assert!(html.contains(r#"class="icon small""#));
     }
+
+    #[test]
+    fn dataset_picker_lists_catalog_datasets() {
+        let catalog = catalog();
+        let picker = dataset_picker(&catalog, Some("imaging")).render();
+        let html = picker.as_inner();
+
+        let values: Vec<&str> = html
+            .split(r#"<option value=""#)
+            .skip(1)
+            .filter_map(|option| option.split('"').next())
+            .filter(|value| !value.is_empty())
+            .collect();
+        let names: Vec<&str> = catalog.datasets.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(values, names);
+
+        assert!(html.contains(">genomics (1200 rows)</option>"));
+        assert!(html.contains(">imaging</option>"));
+        assert!(html.contains(r#"<option value="imaging" selected"#));
+        assert!(!html.contains(r#"<option value="genomics" selected"#));
+        assert!(html.contains(r#"data-bind="datasetFilter""#));
+    }
+
+    #[test]
+    fn dataset_picker_without_selection_shows_placeholder() {
+        let picker = dataset_picker(&catalog(), None).render();
+        let html = picker.as_inner();
+
+        assert!(html.contains(r#"<option value="" disabled selected>Choose a dataset</option>"#));
+        assert!(!html.contains(r#"" selected data-show"#));
+    }
+
+    #[test]
+    fn sql_editor_escapes_initial_sql_and_binds_signal() {
+        let editor = sql_editor(
+            "SELECT * FROM t WHERE a < 1 AND b = '</textarea><script>'",
+            "/analytics/api/completions",
+        )
+        .render();
+        let html = editor.as_inner();
+
+        assert!(html.contains("SELECT * FROM t WHERE a &lt; 1"));
+        assert!(html.contains("&lt;/textarea&gt;&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains(r#"data-bind="sql""#));
+        assert!(html.contains(r#"data-completions-url="/analytics/api/completions""#));
+        assert!(html.contains("@get(el.dataset.completionsUrl)"));
+        assert!(html.contains(r#"id="sql-completions""#));
+        assert!(html.contains("@post('/analytics/api/queries')"));
+    }
+
+    #[test]
+    fn sql_editor_keeps_completions_url_out_of_the_expression() {
+        let editor = sql_editor("", "/c?q=');alert(1);('").render();
+        let html = editor.as_inner();
+
+        assert!(
+            html.contains(r#"data-on:input__debounce.300ms="@get(el.dataset.completionsUrl)""#)
+        );
+        let url = html
+            .split(r#"data-completions-url=""#)
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        assert!(url.contains("alert(1)"));
+        assert_eq!(html.matches("alert(1)").count(), 1);
+    }
 }
@@ -0,0 +1,114 @@
+//! Response compression middleware.
+//!
+//! HTML pages and fragments compress well, so responses are gzip-encoded
+//! when the client sends `Accept-Encoding: gzip`.
+//!
+//! # SSE exclusion
+//!
+//! `text/event-stream` responses are never compressed. SSE relies on each
+//! event reaching the client as soon as it is written, but a gzip encoder
+//! holds output until it has enough input to emit a block. Compressed SSE
+//! streams would stall events and keep-alives behind that buffer, so clients
+//! would see updates late and proxies would time out idle-looking
+//! connections. Per-event flushing would defeat most of the compression
+//! anyway, since Datastar events are small and independent.
+//!
+//! Images other than SVG are already compressed and tiny bodies gain nothing,
+//! so both are skipped as well.
+
+use tower_http::compression::CompressionLayer;
+use tower_http::compression::predicate::{NotForContentType, Predicate, SizeAbove};
+
+/// Responses smaller than this many bytes are sent uncompressed.
+pub const MIN_COMPRESSED_SIZE: u16 = 256;
+
+/// Build the gzip compression layer used by `app_router`.
+#[must_use]
+pub fn compression_layer() -> CompressionLayer<impl Predicate> {
+    let predicate = SizeAbove::new(MIN_COMPRESSED_SIZE)
+        .and(NotForContentType::SSE)
+        .and(NotForContentType::IMAGES);
+
+    CompressionLayer::new().compress_when(predicate)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::response::Html;
+    use axum::response::sse::{Event, Sse};
+    use axum::routing::get;
+    use futures::StreamExt;
+    use http::{Request, header};
+    use std::convert::Infallible;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/page",
+                get(|| async { Html(format!("<ul>{}</ul>", "<li>item</li>".repeat(100))) }),
+            )
+            .route(
+                "/feed",
+                get(|| async {
+                    // One event, then the stream stays open like a live feed.
+                    let events = futures::stream::once(async {
+                        Ok::<_, Infallible>(Event::default().data("first"))
+                    })
+                    .chain(futures::stream::pending());
+                    Sse::new(events)
+                }),
+            )
+            .layer(compression_layer())
+    }
+
+    fn gzip_request(uri: &str) -> Request<Body> {
+        Request::builder()
+            .uri(uri)
+            .header(header::ACCEPT_ENCODING, "gzip")
+            .body(Body::empty())
+            .expect("test request")
+    }
+
+    #[tokio::test]
+    async fn html_is_gzipped_when_accepted() {
+        let response = app()
+            .oneshot(gzip_request("/page"))
+            .await
+            .expect("request should succeed");
+
+        assert_eq!(
+            response.headers().get(header::CONTENT_ENCODING),
+            Some(&http::HeaderValue::from_static("gzip"))
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        assert_eq!(body.get(..2), Some(&[0x1f, 0x8b][..]), "gzip magic bytes");
+    }
+
+    #[tokio::test]
+    async fn sse_is_not_compressed_or_buffered() {
+        let response = app()
+            .oneshot(gzip_request("/feed"))
+            .await
+            .expect("request should succeed");
+
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+
+        // The first event arrives while the stream is still open.
+        let mut body = response.into_body().into_data_stream();
+        let chunk = tokio::time::timeout(Duration::from_secs(1), body.next())
+            .await
+            .expect("event should not be held back")
+            .expect("stream should yield a chunk")
+            .expect("chunk should be Ok");
+        assert_eq!(&chunk[..], b"data: first\n\n");
+    }
+}
@@ -25,6 +25,16 @@
 //!   "errorId": "550e8400-e29b-41d4-a716-446655440000"
 //! }
 //! ```
+//!
+//! # Error ID tracking
+//!
+//! Every lower-layer error carries a UUID. Conversions into [`AppError`] keep
+//! that UUID rather than minting a new one, and the response logs the error
+//! with an `error_id` field holding the same value it returns as `errorId`,
+//! so a client-reported ID leads straight to the server log line.
+//!
+//! Rate-limited requests get `429 Too Many Requests` with a `Retry-After`
+//! header in whole seconds, mirrored as `details.retryAfterSeconds` in the body.
 
 use crate::application::error::{AggregateError, CommandPipelineError};
 use crate::common::ErrorCode;
@@ -40,11 +50,13 @@ use crate::domain::workspace::WorkspaceErrorKind;
 use crate::domain::workspace_preferences::WorkspacePreferencesErrorKind;
 use crate::infrastructure::error::InfrastructureError;
 use axum::Json;
-use axum::http::StatusCode;
+use axum::http::{HeaderValue, StatusCode, header};
 use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
 use std::backtrace::Backtrace;
 use std::fmt;
+use std::time::Duration;
+use tracing::{error, info};
 use uuid::Uuid;
 
 /// Top-level application error unifying all error categories with UUID tracking.
@@ -83,6 +95,10 @@ pub enum AppErrorKind {
     Infrastructure(InfrastructureError),
     /// Resource not found at presentation layer.
     NotFound { resource: String, id: String },
+    /// Request rejected by a rate limit; retry after the given delay.
+    RateLimited { retry_after: Duration },
+    /// The acting user lacks the role the action requires.
+    Forbidden { action: String },
 }
 
 impl AppError {
@@ -134,6 +150,23 @@ impl AppError {
             AppErrorKind::Domain(e) => e.error_code(),
             AppErrorKind::Infrastructure(e) => e.error_code(),
             AppErrorKind::NotFound { .. } => ErrorCode::NotFound,
+            AppErrorKind::RateLimited { .. } => ErrorCode::RateLimited,
+            AppErrorKind::Forbidden { .. } => ErrorCode::Forbidden,
+        }
+    }
+
+    /// Seconds the client should wait before retrying, for rate-limited errors.
+    ///
+    /// Rounded up to whole seconds and never zero, since `Retry-After: 0`
+    /// invites an immediate retry that would be limited again.
+    #[must_use]
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        match &self.kind {
+            AppErrorKind::RateLimited { retry_after } => {
+                let secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                Some(secs.max(1))
+            }
+            _ => None,
         }
     }
 
@@ -151,7 +184,9 @@ impl AppError {
             code: self.error_code(),
             message: self.to_string(),
             error_id: self.id,
-            details: None,
+            details: self
+                .retry_after_secs()
+                .map(|secs| serde_json::json!({ "retryAfterSeconds": secs })),
         }
     }
 
@@ -163,6 +198,20 @@ impl AppError {
             id: id.into(),
         })
     }
+
+    /// Create an error rejecting an action the acting user may not perform.
+    #[must_use]
+    pub fn forbidden(action: impl Into<String>) -> Self {
+        Self::new(AppErrorKind::Forbidden {
+            action: action.into(),
+        })
+    }
+
+    /// Create a rate limit error asking the client to retry after `retry_after`.
+    #[must_use]
+    pub fn rate_limited(retry_after: Duration) -> Self {
+        Self::new(AppErrorKind::RateLimited { retry_after })
+    }
 }
 
 impl fmt::Display for AppError {
@@ -172,6 +221,8 @@ impl fmt::Display for AppError {
             AppErrorKind::Domain(e) => write!(f, "{e}"),
             AppErrorKind::Infrastructure(e) => write!(f, "{e}"),
             AppErrorKind::NotFound { resource, id } => write!(f, "{resource} {id} not found"),
+            AppErrorKind::RateLimited { .. } => write!(f, "too many requests"),
+            AppErrorKind::Forbidden { action } => write!(f, "not permitted to {action}"),
         }
     }
 }
@@ -182,7 +233,9 @@ impl std::error::Error for AppError {
             AppErrorKind::Validation(e) => Some(e),
             AppErrorKind::Domain(e) => Some(e),
             AppErrorKind::Infrastructure(e) => Some(e),
-            AppErrorKind::NotFound { .. } => None,
+            AppErrorKind::NotFound { .. }
+            | AppErrorKind::RateLimited { .. }
+            | AppErrorKind::Forbidden { .. } => None,
         }
     }
 }
@@ -209,13 +262,13 @@ pub struct ErrorResponse {
 
 impl From<ValidationError> for AppError {
     fn from(e: ValidationError) -> Self {
-        Self::new(AppErrorKind::Validation(e))
+        Self::with_id(e.error_id(), AppErrorKind::Validation(e))
     }
 }
 
 impl From<DomainError> for AppError {
     fn from(e: DomainError) -> Self {
-        Self::new(AppErrorKind::Domain(e))
+        Self::with_id(e.error_id(), AppErrorKind::Domain(e))
     }
 }
 
@@ -230,9 +283,9 @@ impl From<AggregateError> for AppError {
                 // which should not be called with empty vec (caller invariant)
                 #[allow(clippy::expect_used)]
                 let first = v.pop().expect("validation errors non-empty");
-                Self::new(AppErrorKind::Validation(first))
+                Self::from(first)
             }
-            AggregateError::Domain(d) => Self::new(AppErrorKind::Domain(d)),
+            AggregateError::Domain(d) => Self::from(d),
         }
     }
 }
@@ -363,6 +416,15 @@ impl From<CommandPipelineError> for AppError {
                             },
                         )),
                     ),
+                    CatalogErrorKind::InvalidCatalogRef(_) => Self::with_id(
+                        error_id,
+                        AppErrorKind::Validation(ValidationError::new(
+                            ValidationErrorKind::InvalidFormat {
+                                field: "catalog_ref".to_string(),
+                                expected: "a ducklake: URI or file path".to_string(),
+                            },
+                        )),
+                    ),
                     CatalogErrorKind::CatalogAlreadyActive
                     | CatalogErrorKind::NoCatalogSelected => Self::with_id(
                         error_id,
@@ -401,6 +463,39 @@ impl From<CommandPipelineError> for AppError {
                             },
                         )),
                     ),
+                    WorkspaceErrorKind::Archived => Self::with_id(
+                        error_id,
+                        AppErrorKind::Domain(DomainError::new(
+                            DomainErrorKind::InvalidTransition {
+                                from: "Archived".to_string(),
+                                to: "requested".to_string(),
+                            },
+                        )),
+                    ),
+                    WorkspaceErrorKind::WorkspaceSuspended(_) => Self::with_id(
+                        error_id,
+                        AppErrorKind::Domain(DomainError::new(
+                            DomainErrorKind::InvalidTransition {
+                                from: "Suspended".to_string(),
+                                to: "requested".to_string(),
+                            },
+                        )),
+                    ),
+                    WorkspaceErrorKind::AdminRequired => Self::with_id(
+                        error_id,
+                        AppErrorKind::Forbidden {
+                            action: "suspend or unsuspend a workspace".to_string(),
+                        },
+                    ),
+                    WorkspaceErrorKind::ReservedName(_) => Self::with_id(
+                        error_id,
+                        AppErrorKind::Validation(ValidationError::new(
+                            ValidationErrorKind::InvalidFormat {
+                                field: "name".to_string(),
+                                expected: "a name that is not reserved".to_string(),
+                            },
+                        )),
+                    ),
                 }
             }
             CommandPipelineError::WorkspacePreferences(wp_err) => {
@@ -440,6 +535,31 @@ impl From<CommandPipelineError> for AppError {
                             )),
                         )
                     }
+                    WorkspacePreferencesErrorKind::InvalidLayoutValue { field } => Self::with_id(
+                        error_id,
+                        AppErrorKind::Validation(ValidationError::new(
+                            ValidationErrorKind::InvalidFormat {
+                                field: field.to_string(),
+                                expected: "an integer".to_string(),
+                            },
+                        )),
+                    ),
+                    WorkspacePreferencesErrorKind::LayoutValueOutOfRange {
+                        field,
+                        min,
+                        max,
+                        actual,
+                    } => Self::with_id(
+                        error_id,
+                        AppErrorKind::Validation(ValidationError::new(
+                            ValidationErrorKind::OutOfRange {
+                                field: field.to_string(),
+                                min: i64::from(min),
+                                max: i64::from(max),
+                                actual,
+                            },
+                        )),
+                    ),
                 }
             }
             CommandPipelineError::Dashboard(dash_err) => {
@@ -468,6 +588,31 @@ impl From<CommandPipelineError> for AppError {
                             })),
                         )
                     }
+                    DashboardErrorKind::Archived => Self::with_id(
+                        error_id,
+                        AppErrorKind::Domain(DomainError::new(
+                            DomainErrorKind::InvalidTransition {
+                                from: "Archived".to_string(),
+                                to: "requested".to_string(),
+                            },
+                        )),
+                    ),
+                    DashboardErrorKind::ChartAlreadyPlaced { chart_id } => Self::with_id(
+                        error_id,
+                        AppErrorKind::Domain(DomainError::new(DomainErrorKind::AlreadyExists {
+                            aggregate_type: "Chart".to_string(),
+                            aggregate_id: chart_id.to_string(),
+                        })),
+                    ),
+                    DashboardErrorKind::TooManyCharts { max } => Self::with_id(
+                        error_id,
+                        AppErrorKind::Validation(ValidationError::new(
+                            ValidationErrorKind::TooMany {
+                                field: "charts".to_string(),
+                                max_count: max,
+                            },
+                        )),
+                    ),
                 }
             }
             CommandPipelineError::SavedQuery(sq_err) => {
@@ -487,6 +632,13 @@ impl From<CommandPipelineError> for AppError {
                             aggregate_id: "unknown".to_string(),
                         })),
                     ),
+                    SavedQueryErrorKind::DuplicateName(name) => Self::with_id(
+                        error_id,
+                        AppErrorKind::Domain(DomainError::new(DomainErrorKind::AlreadyExists {
+                            aggregate_type: "SavedQuery".to_string(),
+                            aggregate_id: name,
+                        })),
+                    ),
                 }
             }
             CommandPipelineError::UserPreferences(up_err) => {
@@ -508,6 +660,19 @@ impl From<CommandPipelineError> for AppError {
                     ),
                 }
             }
+            CommandPipelineError::InvalidSql(e) => e.into(),
+            CommandPipelineError::NonMonotonicTimestamp { latest, .. } => Self::with_id(
+                error_id,
+                AppErrorKind::Validation(ValidationError::new(
+                    ValidationErrorKind::InvalidFormat {
+                        field: "timestamp".to_string(),
+                        expected: format!("a time at or after {latest}"),
+                    },
+                )),
+            ),
+            CommandPipelineError::Forbidden { action, .. } => {
+                Self::with_id(error_id, AppErrorKind::Forbidden { action })
+            }
             CommandPipelineError::Infrastructure(infra) => {
                 // Preserve error_id from infrastructure layer
                 Self::with_id(error_id, AppErrorKind::Infrastructure(infra))
@@ -535,6 +700,15 @@ impl From<CatalogError> for AppError {
                     actual_length: actual,
                 })),
             ),
+            CatalogErrorKind::InvalidCatalogRef(_) => Self::with_id(
+                error_id,
+                AppErrorKind::Validation(ValidationError::new(
+                    ValidationErrorKind::InvalidFormat {
+                        field: "catalog_ref".to_string(),
+                        expected: "a ducklake: URI or file path".to_string(),
+                    },
+                )),
+            ),
             CatalogErrorKind::CatalogAlreadyActive | CatalogErrorKind::NoCatalogSelected => {
                 Self::with_id(
                     error_id,
@@ -567,6 +741,15 @@ impl From<AnalyticsValidationError> for AppError {
                     actual_length: actual,
                 })),
             ),
+            AnalyticsValidationErrorKind::NotReadOnly { reason } => Self::with_id(
+                error_id,
+                AppErrorKind::Validation(ValidationError::new(
+                    ValidationErrorKind::InvalidFormat {
+                        field: "sql".to_string(),
+                        expected: reason.to_string(),
+                    },
+                )),
+            ),
             AnalyticsValidationErrorKind::EmptyDatasetRef => Self::with_id(
                 error_id,
                 AppErrorKind::Validation(ValidationError::new(ValidationErrorKind::EmptyField {
@@ -608,6 +791,15 @@ impl From<AnalyticsValidationError> for AppError {
                     },
                 )),
             ),
+            AnalyticsValidationErrorKind::DatasetReferenceMismatch { reason } => Self::with_id(
+                error_id,
+                AppErrorKind::Validation(ValidationError::new(
+                    ValidationErrorKind::InvalidFormat {
+                        field: "dataset_ref".to_string(),
+                        expected: reason.to_string(),
+                    },
+                )),
+            ),
         }
     }
 }
@@ -617,8 +809,20 @@ impl From<AnalyticsValidationError> for AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = self.http_status();
-        let body = Json(self.to_response());
-        (status, body).into_response()
+        if status.is_server_error() {
+            error!(error_id = %self.id, code = ?self.error_code(), error = %self, "request failed");
+        } else {
+            info!(error_id = %self.id, code = ?self.error_code(), error = %self, "request rejected");
+        }
+
+        let retry_after = self.retry_after_secs();
+        let mut response = (status, Json(self.to_response())).into_response();
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(secs));
+        }
+        response
     }
 }
 
@@ -665,6 +869,70 @@ mod tests {
         assert!(json.contains("Todo 123 not found"));
     }
 
+    #[tokio::test]
+    async fn rate_limited_responds_429_with_retry_after() {
+        let response = AppError::rate_limited(Duration::from_millis(2500)).into_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get(header::RETRY_AFTER),
+            Some(&HeaderValue::from_static("3"))
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["code"], "RATE_LIMITED");
+        assert_eq!(json["details"]["retryAfterSeconds"], 3);
+    }
+
+    #[test]
+    fn lower_layer_conversions_keep_error_id() {
+        let validation = ValidationError::new(ValidationErrorKind::EmptyField {
+            field: "title".to_string(),
+        });
+        let validation_id = validation.error_id();
+        assert_eq!(AppError::from(validation).error_id(), validation_id);
+
+        let domain = DomainError::new(DomainErrorKind::NotFound {
+            aggregate_type: "Todo".to_string(),
+            aggregate_id: "abc".to_string(),
+        });
+        let domain_id = domain.error_id();
+        assert_eq!(
+            AppError::from(AggregateError::Domain(domain)).error_id(),
+            domain_id
+        );
+    }
+
+    #[tokio::test]
+    async fn workspace_error_id_matches_log_and_response() {
+        use crate::domain::workspace::{WorkspaceError, WorkspaceErrorKind};
+
+        let capture = ironstar_core::LogCapture::default();
+        let _guard = capture.set_default();
+
+        let workspace_err = WorkspaceError::new(WorkspaceErrorKind::NotFound);
+        let error_id = workspace_err.error_id();
+        let response =
+            AppError::from(CommandPipelineError::Workspace(workspace_err)).into_response();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["errorId"], error_id.to_string());
+        assert!(json.get("traceId").is_none());
+
+        let logged: Vec<_> = capture
+            .lines()
+            .iter()
+            .filter_map(|line| line.field("error_id").map(str::to_string))
+            .collect();
+        assert_eq!(logged, [error_id.to_string()]);
+    }
+
     #[test]
     fn command_pipeline_error_preserves_error_id() {
         use crate::domain::todo::{TodoError, TodoErrorKind};
@@ -686,6 +954,18 @@ mod tests {
         assert_eq!(app_err.error_id(), original_id);
     }
 
+    #[test]
+    fn forbidden_pipeline_error_maps_to_403() {
+        let pipeline_err = CommandPipelineError::forbidden("run saved query q");
+        let original_id = pipeline_err.error_id();
+
+        let app_err: AppError = pipeline_err.into();
+
+        assert_eq!(app_err.http_status(), StatusCode::FORBIDDEN);
+        assert_eq!(app_err.error_id(), original_id);
+        assert_eq!(app_err.to_string(), "not permitted to run saved query q");
+    }
+
     #[test]
     fn infrastructure_error_preserves_error_id() {
         use crate::infrastructure::error::InfrastructureError;
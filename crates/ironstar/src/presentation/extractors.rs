@@ -11,6 +11,15 @@
 //! - [`SessionExtractor`] loads a valid session from cookies via the
 //!   session store configured in [`crate::state::AppState`].
 //!
+//! - [`Actor`] resolves the user a request acts as, for attributing
+//!   commands in the audit trail.
+//!
+//! - [`RequestCorrelation`] starts the causal chain of the events a request
+//!   leads to at the request id.
+//!
+//! - [`Command`] deserializes a JSON request body into a domain command,
+//!   rejecting malformed bodies with a 400 [`ErrorResponse`].
+//!
 //! # Usage
 //!
 //! ```rust,ignore
@@ -38,16 +47,26 @@
 //! let jar = jar.add(cookie);
 //! ```
 
-use crate::infrastructure::{Session, SessionStore, SessionStoreError};
+use crate::common::ErrorCode;
+use crate::domain::session::UserId;
+use crate::infrastructure::event_store::Correlation;
+use crate::infrastructure::{Session, SessionStore, SessionStoreError, SqliteSessionStore};
+use crate::presentation::error::ErrorResponse;
+use crate::presentation::workspace::WorkspaceAppState;
 use crate::state::AppState;
-use axum::extract::{FromRef, FromRequestParts};
+use axum::Json;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{FromRef, FromRequest, FromRequestParts, Request};
 use axum::http::StatusCode;
 use axum::http::request::Parts;
 use axum::response::{IntoResponse, Response};
 use axum_extra::extract::CookieJar;
 use axum_extra::extract::cookie::{Cookie, SameSite};
+use serde::de::DeserializeOwned;
 use std::convert::Infallible;
 use std::fmt;
+use tower_http::request_id::RequestId;
+use uuid::Uuid;
 
 /// Header name sent by Datastar on all SSE requests.
 ///
@@ -55,6 +74,9 @@ use std::fmt;
 /// is `pub(crate)` and therefore unavailable outside the SDK.
 const DATASTAR_REQUEST_HEADER: &str = "datastar-request";
 
+/// Header carrying the request id, as set by `SetRequestIdLayer`.
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
 /// Extractor that detects Datastar SSE requests via the `datastar-request: true` header.
 ///
 /// Returns `true` when the request originates from a Datastar SSE interaction
@@ -103,6 +125,98 @@ where
     }
 }
 
+/// Extractor that deserializes a JSON request body into a domain command.
+///
+/// Commands are serde-tagged enums (`{"type": "Rename", ...}`), so any
+/// aggregate's command type can be parsed directly from the body. Validation
+/// of the command's contents remains the decider's responsibility; this
+/// extractor only rejects bodies that do not deserialize.
+///
+/// # Usage
+///
+/// ```rust,ignore
+/// async fn rename_workspace(
+///     Command(command): Command<WorkspaceCommand>,
+/// ) -> Result<impl IntoResponse, AppError> {
+///     // dispatch command to the workspace aggregate
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command<C>(pub C);
+
+impl<C> Command<C> {
+    /// Get the inner command.
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+/// Rejection for request bodies that do not deserialize into a command.
+///
+/// Responds with 400 and a JSON [`ErrorResponse`] carrying the parse error.
+#[derive(Debug)]
+pub struct CommandRejection {
+    error_id: Uuid,
+    source: JsonRejection,
+}
+
+impl CommandRejection {
+    fn new(source: JsonRejection) -> Self {
+        Self {
+            error_id: Uuid::new_v4(),
+            source,
+        }
+    }
+
+    /// Returns the unique error identifier for tracing correlation.
+    #[must_use]
+    pub fn error_id(&self) -> Uuid {
+        self.error_id
+    }
+}
+
+impl fmt::Display for CommandRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid command body: {}", self.source.body_text())
+    }
+}
+
+impl std::error::Error for CommandRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl IntoResponse for CommandRejection {
+    fn into_response(self) -> Response {
+        let body = ErrorResponse {
+            code: ErrorCode::ValidationFailed,
+            message: self.to_string(),
+            error_id: self.error_id,
+            details: None,
+        };
+
+        (StatusCode::BAD_REQUEST, Json(body)).into_response()
+    }
+}
+
+impl<C, S> FromRequest<S> for Command<C>
+where
+    C: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = CommandRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(command) = Json::<C>::from_request(req, state)
+            .await
+            .map_err(CommandRejection::new)?;
+
+        Ok(Self(command))
+    }
+}
+
 /// Cookie name for session identification.
 pub const SESSION_COOKIE_NAME: &str = "ironstar_session";
 
@@ -192,30 +306,94 @@ where
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
         // Extract AppState using FromRef
         let app_state = AppState::from_ref(state);
+        let session = load_session(app_state.session_store.as_deref(), parts).await?;
+        Ok(Self(session))
+    }
+}
 
-        // Get session store from AppState
-        let session_store = app_state
-            .session_store
-            .as_ref()
-            .ok_or(SessionRejection::NoSessionStore)?;
+/// Load the session referenced by the request's session cookie.
+async fn load_session(
+    session_store: Option<&SqliteSessionStore>,
+    parts: &Parts,
+) -> Result<Session, SessionRejection> {
+    let session_store = session_store.ok_or(SessionRejection::NoSessionStore)?;
+
+    // Extract cookies from request headers
+    let jar = CookieJar::from_headers(&parts.headers);
+
+    // Look for session cookie
+    let session_id = jar
+        .get(SESSION_COOKIE_NAME)
+        .map(Cookie::value)
+        .ok_or(SessionRejection::NoCookie)?;
+
+    // Load session from store
+    session_store
+        .get(session_id)
+        .await
+        .map_err(SessionRejection::StoreError)?
+        .ok_or(SessionRejection::SessionNotFound)
+}
 
-        // Extract cookies from request headers
-        let jar = CookieJar::from_headers(&parts.headers);
+/// Extractor resolving the user a request acts as.
+///
+/// Returns the user bound to the request's session. Requests without a
+/// session, or whose session has no user bound yet, act as
+/// [`UserId::system`], so deployments without sign-in keep working and
+/// their events are attributed the same way as events stored before
+/// actors were recorded. Only a failing session lookup is rejected.
+///
+/// Reads the session store from [`WorkspaceAppState`], the state of the
+/// handlers that issue audited commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Actor(pub UserId);
 
-        // Look for session cookie
-        let session_id = jar
-            .get(SESSION_COOKIE_NAME)
-            .map(Cookie::value)
-            .ok_or(SessionRejection::NoCookie)?;
+impl<S> FromRequestParts<S> for Actor
+where
+    S: Send + Sync,
+    WorkspaceAppState: FromRef<S>,
+{
+    type Rejection = SessionRejection;
 
-        // Load session from store
-        let session = session_store
-            .get(session_id)
-            .await
-            .map_err(SessionRejection::StoreError)?
-            .ok_or(SessionRejection::SessionNotFound)?;
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let workspace_state = WorkspaceAppState::from_ref(state);
+        match load_session(workspace_state.session_store.as_deref(), parts).await {
+            Ok(session) => Ok(Self(
+                session
+                    .user_id
+                    .and_then(|id| Uuid::parse_str(&id).ok())
+                    .map_or_else(UserId::system, UserId::from_uuid),
+            )),
+            Err(SessionRejection::StoreError(e)) => Err(SessionRejection::StoreError(e)),
+            Err(_) => Ok(Self(UserId::system())),
+        }
+    }
+}
 
-        Ok(Self(session))
+/// Extractor starting a correlation chain at the request id.
+///
+/// Uses the id `SetRequestIdLayer` assigned to the request, falling back to
+/// the `x-request-id` header and then to a fresh UUID v7 when the handler
+/// runs without the request id layer, as in handler tests. Events saved
+/// with it can be traced back to the request in the logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestCorrelation(pub Correlation);
+
+impl<S> FromRequestParts<S> for RequestCorrelation
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let request_id = parts
+            .extensions
+            .get::<RequestId>()
+            .map(RequestId::header_value)
+            .or_else(|| parts.headers.get(REQUEST_ID_HEADER))
+            .and_then(|value| value.to_str().ok())
+            .map_or_else(|| Uuid::now_v7().to_string(), str::to_string);
+        Ok(Self(Correlation::new(request_id)))
     }
 }
 
@@ -276,11 +454,12 @@ pub fn clear_session_cookie() -> Cookie<'static> {
 #[allow(clippy::expect_used, clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use crate::infrastructure::{AssetManifest, SqliteSessionStore};
     use axum::Router;
     use axum::body::Body;
     use axum::http::Request;
-    use axum::routing::get;
+    use axum::routing::{get, post};
     use chrono::Duration;
     use sqlx::sqlite::SqlitePoolOptions;
     use std::sync::Arc;
@@ -293,15 +472,19 @@ mod tests {
             .await
             .expect("test pool");
 
-        sqlx::query(include_str!("../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("events migration");
 
-        sqlx::query(include_str!("../../migrations/002_sessions.sql"))
-            .execute(&pool)
-            .await
-            .expect("sessions migration");
+        sqlx::query(concat!(
+            include_str!("../../migrations/002_sessions.sql"),
+            include_str!("../../migrations/005_session_metadata.sql"),
+            include_str!("../../migrations/006_session_flags.sql")
+        ))
+        .execute(&pool)
+        .await
+        .expect("sessions migration");
 
         pool
     }
@@ -421,6 +604,71 @@ mod tests {
         assert_eq!(format!("{a:?}"), "DatastarRequest(true)");
     }
 
+    // --- Command extractor tests ---
+
+    async fn command_test_handler(
+        Command(command): Command<crate::domain::WorkspaceCommand>,
+    ) -> String {
+        match command {
+            crate::domain::WorkspaceCommand::Rename { new_name, .. } => {
+                format!("rename:{new_name}")
+            }
+            other => other.command_type().to_owned(),
+        }
+    }
+
+    fn command_request(body: &'static str) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri("/command")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn command_extractor_parses_workspace_rename() {
+        let app = Router::new().route("/command", post(command_test_handler));
+
+        let response = app
+            .oneshot(command_request(
+                r#"{
+                    "type": "Rename",
+                    "workspace_id": "550e8400-e29b-41d4-a716-446655440000",
+                    "new_name": "Quarterly Reports",
+                    "renamed_at": "2024-01-15T10:30:00Z"
+                }"#,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"rename:Quarterly Reports");
+    }
+
+    #[tokio::test]
+    async fn command_extractor_rejects_malformed_body() {
+        let app = Router::new().route("/command", post(command_test_handler));
+
+        let response = app
+            .oneshot(command_request(r#"{"type": "Rename", "workspace_id": 42}"#))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, ErrorCode::ValidationFailed);
+        assert!(error.message.starts_with("invalid command body"));
+    }
+
     // --- Session cookie tests ---
 
     #[test]
@@ -613,4 +861,111 @@ mod tests {
         // Expired session should return 401 Unauthorized
         assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
+
+    // --- RequestCorrelation extractor tests ---
+
+    async fn correlation_test_handler(
+        RequestCorrelation(correlation): RequestCorrelation,
+    ) -> String {
+        correlation.correlation_id
+    }
+
+    #[tokio::test]
+    async fn request_correlation_starts_at_the_request_id() {
+        let app = Router::new().route("/test", get(correlation_test_handler));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/test")
+                    .header("x-request-id", "request-1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"request-1");
+
+        let response = app
+            .oneshot(Request::builder().uri("/test").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let generated = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(
+            Uuid::parse_str(&generated)
+                .ok()
+                .map(|id| id.get_version_num()),
+            Some(7)
+        );
+    }
+
+    // --- Actor extractor tests ---
+
+    async fn actor_test_handler(Actor(actor): Actor) -> String {
+        actor.to_string()
+    }
+
+    async fn actor_for(state: AppState, cookie: Option<String>) -> String {
+        let app = Router::new()
+            .route("/test", get(actor_test_handler))
+            .with_state(state);
+        let mut request = Request::builder().uri("/test");
+        if let Some(cookie) = cookie {
+            request = request.header("Cookie", cookie);
+        }
+
+        let response = app
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn actor_is_session_user() {
+        let pool = create_test_pool().await;
+        let state = create_app_state(pool);
+        let user_id = UserId::new();
+        let session = state
+            .session_store
+            .as_ref()
+            .unwrap()
+            .create(Some(&user_id.to_string()))
+            .await
+            .expect("create session");
+
+        let cookie = format!("{}={}", SESSION_COOKIE_NAME, session.id);
+
+        assert_eq!(actor_for(state, Some(cookie)).await, user_id.to_string());
+    }
+
+    #[tokio::test]
+    async fn actor_without_signed_in_user_is_system() {
+        let pool = create_test_pool().await;
+        let state = create_app_state(pool);
+        let anonymous = state
+            .session_store
+            .as_ref()
+            .unwrap()
+            .create(None)
+            .await
+            .expect("create session");
+        let system = UserId::system().to_string();
+
+        let cookie = format!("{}={}", SESSION_COOKIE_NAME, anonymous.id);
+        assert_eq!(actor_for(state.clone(), Some(cookie)).await, system);
+        assert_eq!(actor_for(state, None).await, system);
+    }
 }
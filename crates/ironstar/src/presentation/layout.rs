@@ -5,10 +5,53 @@
 //! - Head with charset, viewport, and asset links
 //! - Body with optional hot reload div (debug builds only)
 //! - Content slot for page-specific markup
+//!
+//! # Theme
+//!
+//! The body carries the user's theme as the `theme` Datastar signal and as
+//! the `color-scheme` and `--color-scheme` CSS properties the stylesheet's
+//! `light-dark()` palette and `style(--color-scheme: ...)` queries key off.
+//! A `data-effect` re-applies both whenever the signal changes, so a signal
+//! patch switches the theme without a reload.
 
 use hypertext::prelude::*;
+use tracing::warn;
 
+use crate::application::workspace::query_user_preferences;
+use crate::domain::session::UserId;
+use crate::domain::user_preferences::values::Theme;
 use crate::infrastructure::assets::AssetManifest;
+use crate::presentation::workspace::WorkspaceAppState;
+
+/// Datastar expression applying the `theme` signal to the element.
+const APPLY_THEME_EFFECT: &str = "el.style.colorScheme = $theme === 'system' ? 'light dark' : $theme; \
+     el.style.setProperty('--color-scheme', $theme === 'system' ? '' : $theme)";
+
+/// Theme of the user a page is rendered for.
+///
+/// Anonymous requests act as [`UserId::system`] and always get the default
+/// theme, without looking up preferences. A failed lookup also falls back
+/// to the default rather than failing the page.
+pub async fn actor_theme(state: &WorkspaceAppState, actor: &UserId) -> Theme {
+    if actor.is_system() {
+        return Theme::default();
+    }
+    match query_user_preferences(&state.user_preferences_repo, actor).await {
+        Ok(preferences) => preferences.theme,
+        Err(e) => {
+            warn!(error = %e, "failed to load theme, using default theme");
+            Theme::default()
+        }
+    }
+}
+
+/// Inline style applying `theme` before Datastar loads.
+fn theme_style(theme: Theme) -> String {
+    match theme {
+        Theme::System => "color-scheme: light dark".to_string(),
+        Theme::Light | Theme::Dark => format!("color-scheme: {theme}; --color-scheme: {theme}"),
+    }
+}
 
 /// Renders the base HTML layout with Datastar initialization.
 ///
@@ -23,6 +66,8 @@ use crate::infrastructure::assets::AssetManifest;
 /// In debug builds, the body includes a hot reload div that polls `/reload`
 /// for development workflow support.
 ///
+/// The page uses [`Theme::System`]; see [`themed_layout`] for a user's theme.
+///
 /// # Arguments
 ///
 /// * `manifest` - Asset manifest for resolving hashed filenames
@@ -45,6 +90,21 @@ use crate::infrastructure::assets::AssetManifest;
 /// let rendered = html.render();
 /// ```
 pub fn base_layout(manifest: &AssetManifest, content: impl Renderable) -> impl Renderable {
+    themed_layout(manifest, Theme::System, content)
+}
+
+/// Renders the base HTML layout for a user with the given theme.
+///
+/// Same document as [`base_layout`], with the body's theme signal and CSS
+/// properties set from `theme`. Pages rendered for a user should pass
+/// [`actor_theme`].
+pub fn themed_layout(
+    manifest: &AssetManifest,
+    theme: Theme,
+    content: impl Renderable,
+) -> impl Renderable {
+    let theme_signal = format!("'{theme}'");
+    let body_style = theme_style(theme);
     let css_href = format!("/static/{}", manifest.resolve("bundle.css"));
     let datastar_src = format!("/static/{}", manifest.resolve("datastar.js"));
 
@@ -58,7 +118,11 @@ pub fn base_layout(manifest: &AssetManifest, content: impl Renderable) -> impl R
                 link rel="stylesheet" href=(css_href);
                 script defer type="module" src=(datastar_src) {}
             }
-            body {
+            body
+                "data-signals-theme"=(theme_signal)
+                "data-effect"=(APPLY_THEME_EFFECT)
+                style=(body_style)
+            {
                 @if cfg!(debug_assertions) {
                     div "data-init"="@get('/reload', {retryMaxCount: 1000, retryInterval:20, retryMaxWaitMs:200})" {}
                 }
@@ -181,4 +245,67 @@ mod tests {
 
         assert!(body.contains("datastar-def67890.js"));
     }
+
+    #[test]
+    fn base_layout_defaults_to_system_theme() {
+        let manifest = AssetManifest::default();
+        let content = maud! { main { "test" } };
+        let html = base_layout(&manifest, content).render();
+        let body = html.as_inner();
+
+        assert!(body.contains(r#"data-signals-theme="'system'""#));
+        assert!(body.contains(r#"style="color-scheme: light dark""#));
+    }
+
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn themed_layout_embeds_dark_theme_of_user() {
+        use crate::application::user_preferences::handle_user_preferences_command_zenoh;
+        use crate::domain::user_preferences::commands::UserPreferencesCommand;
+        use crate::domain::user_preferences::values::PreferencesId;
+        use crate::presentation::test_support::workspace_app_state;
+        use chrono::Utc;
+        use std::sync::Arc;
+
+        let state = workspace_app_state().await;
+
+        let user_id = UserId::new();
+        // The system actor has dark preferences too, saved before anonymous
+        // settings changes were forbidden.
+        for user_id in [user_id, UserId::system()] {
+            for command in [
+                UserPreferencesCommand::InitializePreferences {
+                    preferences_id: PreferencesId::new(),
+                    user_id,
+                    initialized_at: Utc::now(),
+                },
+                UserPreferencesCommand::SetTheme {
+                    user_id,
+                    theme: Theme::Dark,
+                    set_at: Utc::now(),
+                },
+            ] {
+                handle_user_preferences_command_zenoh(
+                    Arc::clone(&state.user_preferences_repo),
+                    state.event_bus.as_deref(),
+                    command,
+                )
+                .await
+                .expect("command should succeed");
+            }
+        }
+
+        assert_eq!(
+            actor_theme(&state, &UserId::system()).await,
+            Theme::default()
+        );
+
+        let theme = actor_theme(&state, &user_id).await;
+        let content = maud! { main { "test" } };
+        let html = themed_layout(&AssetManifest::default(), theme, content).render();
+        let body = html.as_inner();
+
+        assert!(body.contains(r#"data-signals-theme="'dark'""#));
+        assert!(body.contains("color-scheme: dark; --color-scheme: dark"));
+    }
 }
@@ -0,0 +1,88 @@
+//! Line chart transformer for DuckDB query results.
+//!
+//! Transforms tabular DuckDB results into ECharts line chart configuration.
+//! The option has the same shape as the bar chart's, so a cached result can
+//! be redrawn as either without re-querying.
+
+use crate::presentation::chart_transformer::{
+    ChartConfig, ChartTransformer, ChartType, QueryResult, TransformError, category_axis_option,
+};
+
+/// Transforms query results into ECharts line chart configuration.
+///
+/// Expects `QueryResult` with:
+/// - One category column (for X-axis labels)
+/// - One or more value columns (one line each)
+///
+/// Produces ECharts option with:
+/// - `xAxis`: category type with data from category column
+/// - `yAxis`: value type
+/// - `series`: one line series per value column
+pub struct LineChartTransformer;
+
+impl ChartTransformer for LineChartTransformer {
+    fn transform(
+        &self,
+        result: &QueryResult,
+        config: &ChartConfig,
+    ) -> Result<serde_json::Value, TransformError> {
+        if config.chart_type != ChartType::Line {
+            return Err(TransformError::TransformFailed(format!(
+                "LineChartTransformer requires ChartType::Line, got {:?}",
+                config.chart_type
+            )));
+        }
+
+        category_axis_option(result, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::chart_transformer::{ColumnMetadata, series_values};
+    use serde_json::json;
+
+    fn monthly_result() -> QueryResult {
+        QueryResult::new(
+            vec![
+                ColumnMetadata::new("month", "VARCHAR"),
+                ColumnMetadata::new("launches", "BIGINT"),
+            ],
+            vec![vec![json!("Jan"), json!(4)], vec![json!("Feb"), json!(7)]],
+        )
+    }
+
+    fn line_config(chart_type: ChartType) -> ChartConfig {
+        ChartConfig {
+            chart_type,
+            title: None,
+            category_column: "month".into(),
+            value_columns: vec!["launches".into()],
+        }
+    }
+
+    #[test]
+    fn line_chart_series_are_lines() {
+        let option = LineChartTransformer
+            .transform(&monthly_result(), &line_config(ChartType::Line))
+            .unwrap();
+
+        assert_eq!(option["xAxis"]["data"], json!(["Jan", "Feb"]));
+        let series = option["series"].as_array().unwrap();
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0]["type"], "line");
+        assert_eq!(series_values(&series[0]), json!([4, 7]));
+    }
+
+    #[test]
+    fn line_chart_wrong_chart_type() {
+        let err = LineChartTransformer
+            .transform(&monthly_result(), &line_config(ChartType::Bar))
+            .unwrap_err();
+        assert!(
+            matches!(&err, TransformError::TransformFailed(msg) if msg.contains("Bar")),
+            "expected TransformFailed error, got {err:?}"
+        );
+    }
+}
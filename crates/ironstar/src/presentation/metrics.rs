@@ -7,6 +7,13 @@
 //!
 //! - `GET /metrics` - Prometheus text exposition format
 //!
+//! # Authentication
+//!
+//! Metrics reveal cache and query statistics, so scrapes must present the
+//! token from `IRONSTAR_METRICS_TOKEN` as `Authorization: Bearer <token>`.
+//! Anything else gets 401; with no token configured, every scrape does.
+//! Health probes stay open.
+//!
 //! # Integration
 //!
 //! Configure Prometheus to scrape this endpoint:
@@ -17,35 +24,50 @@
 //!     static_configs:
 //!       - targets: ['127.0.0.1:3000']
 //!     scrape_interval: 15s
+//!     authorization:
+//!       credentials_file: /run/secrets/ironstar-metrics-token
 //! ```
 
 use axum::Router;
 use axum::extract::State;
 use axum::http::StatusCode;
-use axum::http::header;
-use axum::response::IntoResponse;
+use axum::http::{HeaderMap, header};
+use axum::response::{IntoResponse, Response};
 use axum::routing::get;
 use metrics_exporter_prometheus::PrometheusHandle;
+use std::sync::Arc;
 use tracing::instrument;
 
 use crate::state::AppState;
 
 /// Application state subset for the metrics endpoint.
 ///
-/// Contains the Prometheus handle used to render metric values on demand.
+/// Contains the Prometheus handle used to render metric values on demand
+/// and the bearer token scrapes must present.
 #[derive(Clone)]
 pub struct MetricsState {
     /// Handle to the Prometheus recorder for rendering exposition format.
     pub prometheus_handle: PrometheusHandle,
+    /// Bearer token required to scrape; `None` rejects every scrape.
+    pub token: Option<Arc<str>>,
 }
 
 /// GET /metrics - Prometheus text exposition format.
 ///
 /// Returns all registered metrics in the Prometheus text format with
 /// `Content-Type: text/plain; version=0.0.4; charset=utf-8` as required
-/// by the Prometheus exposition format specification.
-#[instrument(name = "handler.metrics", skip(state))]
-pub async fn metrics_handler(State(state): State<MetricsState>) -> impl IntoResponse {
+/// by the Prometheus exposition format specification, or 401 unless the
+/// request carries the configured bearer token.
+#[instrument(name = "handler.metrics", skip(state, headers))]
+pub async fn metrics_handler(State(state): State<MetricsState>, headers: HeaderMap) -> Response {
+    if !is_authorized(state.token.as_deref(), &headers) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+        )
+            .into_response();
+    }
+
     let body = state.prometheus_handle.render();
     (
         StatusCode::OK,
@@ -55,6 +77,25 @@ pub async fn metrics_handler(State(state): State<MetricsState>) -> impl IntoResp
         )],
         body,
     )
+        .into_response()
+}
+
+/// Whether `headers` carry `Authorization: Bearer <expected>`.
+fn is_authorized(expected: Option<&str>, headers: &HeaderMap) -> bool {
+    let Some(expected) = expected else {
+        return false;
+    };
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|presented| constant_time_eq(presented.as_bytes(), expected.as_bytes()))
+}
+
+/// Compare without short-circuiting on the first differing byte, so the
+/// response time does not reveal how much of the token was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
 }
 
 /// Creates the metrics feature router.
@@ -77,43 +118,97 @@ mod tests {
     use axum::http::Request;
     use tower::ServiceExt;
 
+    const TEST_TOKEN: &str = "scrape-secret";
+
     fn create_test_router() -> Router {
         let state = MetricsState {
             prometheus_handle: test_prometheus_handle(),
+            token: Some(Arc::from(TEST_TOKEN)),
         };
         Router::new()
             .route("/metrics", get(metrics_handler))
             .with_state(state)
     }
 
+    fn scrape(authorization: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().uri("/metrics");
+        if let Some(value) = authorization {
+            builder = builder.header(header::AUTHORIZATION, value);
+        }
+        builder.body(Body::empty()).expect("request body")
+    }
+
+    fn authorized_scrape() -> Request<Body> {
+        scrape(Some(&format!("Bearer {TEST_TOKEN}")))
+    }
+
     #[tokio::test]
     async fn metrics_returns_ok() {
         let app = create_test_router();
 
         let response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/metrics")
-                    .body(Body::empty())
-                    .expect("request body"),
-            )
+            .oneshot(authorized_scrape())
             .await
             .expect("request should succeed");
 
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn metrics_without_token_is_unauthorized() {
+        let app = create_test_router();
+
+        let response = app
+            .oneshot(scrape(None))
+            .await
+            .expect("request should succeed");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::WWW_AUTHENTICATE)
+                .expect("challenge header"),
+            "Bearer"
+        );
+    }
+
+    #[tokio::test]
+    async fn metrics_with_wrong_token_is_unauthorized() {
+        let app = create_test_router();
+
+        let response = app
+            .oneshot(scrape(Some("Bearer scrape-secreT")))
+            .await
+            .expect("request should succeed");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn metrics_without_configured_token_rejects_everything() {
+        let state = MetricsState {
+            prometheus_handle: test_prometheus_handle(),
+            token: None,
+        };
+        let app = Router::new()
+            .route("/metrics", get(metrics_handler))
+            .with_state(state);
+
+        let response = app
+            .oneshot(authorized_scrape())
+            .await
+            .expect("request should succeed");
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test]
     async fn metrics_returns_prometheus_content_type() {
         let app = create_test_router();
 
         let response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/metrics")
-                    .body(Body::empty())
-                    .expect("request body"),
-            )
+            .oneshot(authorized_scrape())
             .await
             .expect("request should succeed");
 
@@ -129,12 +224,7 @@ mod tests {
         let app = create_test_router();
 
         let response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/metrics")
-                    .body(Body::empty())
-                    .expect("request body"),
-            )
+            .oneshot(authorized_scrape())
             .await
             .expect("request should succeed");
 
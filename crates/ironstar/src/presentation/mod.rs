@@ -106,10 +106,12 @@
 
 pub mod analytics;
 pub mod bar_chart_transformer;
+pub mod body_limit;
 pub mod chart;
 pub mod chart_templates;
 pub mod chart_transformer;
 pub mod components;
+pub mod compression;
 pub mod datastar_bridge;
 pub mod error;
 pub mod extractors;
@@ -117,36 +119,57 @@ pub mod health;
 #[cfg(debug_assertions)]
 pub mod hotreload;
 pub mod layout;
+pub mod line_chart_transformer;
 pub mod metrics;
 pub mod middleware;
+pub mod results_templates;
+pub mod settings;
+pub mod settings_templates;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod todo;
 pub mod todo_templates;
 pub mod workspace;
+pub mod workspace_templates;
 
 pub use bar_chart_transformer::BarChartTransformer;
+pub use body_limit::{
+    DEFAULT_ANALYTICS_REQUEST_BODY_LIMIT, DEFAULT_REQUEST_BODY_LIMIT, RequestBodyLimits,
+};
 pub use chart::{
     astronauts_chart_page, astronauts_chart_sse, chart_feed_handler, routes as chart_routes,
 };
-pub use chart_templates::{chart_page, echarts_chart, echarts_chart_with_feedback};
+pub use chart_templates::{
+    chart_loading_signal, chart_page, chart_slot_id, echarts_chart, echarts_chart_error,
+    echarts_chart_loading, echarts_chart_with_feedback, echarts_empty_chart,
+};
 pub use chart_transformer::{
     ChartConfig, ChartTransformer, ChartType, ColumnMetadata, QueryResult, TransformError,
+    transformer_for,
 };
 pub use components::{button, checkbox, icon, loading_spinner, text_field};
+pub use compression::{MIN_COMPRESSED_SIZE, compression_layer};
 pub use datastar_bridge::{
     ToDatastarEvents, render_patch_elements, render_patch_elements_with_selector,
 };
 pub use error::{AppError, AppErrorKind, ErrorResponse};
 pub use extractors::{
-    DatastarRequest, SESSION_COOKIE_NAME, SessionExtractor, SessionRejection, clear_session_cookie,
-    session_cookie,
+    Actor, Command, CommandRejection, DatastarRequest, RequestCorrelation, SESSION_COOKIE_NAME,
+    SessionExtractor, SessionRejection, clear_session_cookie, session_cookie,
 };
 pub use health::{
     HealthChecks, HealthResponse, HealthState, HealthStatus, health_router, routes as health_routes,
 };
+pub use line_chart_transformer::LineChartTransformer;
 pub use metrics::{MetricsState, metrics_handler};
 pub use middleware::MakeRequestUuidV7;
+pub use settings::routes as settings_routes;
+pub use settings_templates::settings_page;
 pub use todo::{TodoAppState, TodoListResponse, get_todo, list_todos};
 pub use todo_templates::{todo_app, todo_footer, todo_item, todo_list, todo_page};
+pub use workspace_templates::{
+    DASHBOARD_GRID_ID, WORKSPACE_PREFERENCES_ID, dashboard_grid, workspace_preferences_panel,
+};
 
 use crate::infrastructure::create_static_router;
 use crate::state::AppState;
@@ -160,27 +183,39 @@ use tower_http::trace::TraceLayer;
 /// - Health endpoints at root (/health/*)
 /// - Todo feature at /todos
 /// - Chart feature at /charts
+/// - User settings at /settings
 /// - Static assets at /static
 ///
 /// Each feature router uses `Router<AppState>` and handlers extract
-/// domain-specific state via `FromRef`.
+/// domain-specific state via `FromRef`. Feature routers reject oversized
+/// request bodies with 413 (see [`body_limit`]).
 ///
 /// # Middleware stack (outermost first)
 ///
 /// 1. `SetRequestIdLayer` — generates UUID v7 request ID (or preserves existing)
 /// 2. `TraceLayer` — creates a tracing span per request with method, URI, and request_id
 /// 3. `PropagateRequestIdLayer` — copies request ID to response header
+/// 4. `CompressionLayer` — gzip-encodes responses except SSE streams (see
+///    [`compression`] for why `text/event-stream` is excluded)
 pub fn app_router(state: AppState) -> Router {
     let x_request_id = http::HeaderName::from_static("x-request-id");
 
-    // Compose stateful feature routers and apply state
+    let body_limits = state.request_body_limits;
+
+    // Compose stateful feature routers and apply state. `layer` only wraps
+    // routes added before it, so analytics keeps its own higher body limit.
     let stateful = Router::new()
         .merge(health::routes())
         .merge(metrics::routes())
         .nest("/todos", todo::routes())
-        .nest("/analytics", analytics::routes())
         .nest("/charts", chart::routes())
         .nest("/workspace", workspace::routes())
+        .nest("/settings", settings::routes())
+        .layer(body_limits.default_layer())
+        .nest(
+            "/analytics",
+            analytics::routes().layer(body_limits.analytics_layer()),
+        )
         .with_state(state);
 
     // Merge stateless routers after state is applied, then add
@@ -197,6 +232,7 @@ pub fn app_router(state: AppState) -> Router {
     }
 
     router
+        .layer(compression_layer())
         .layer(PropagateRequestIdLayer::new(x_request_id.clone()))
         .layer(TraceLayer::new_for_http().make_span_with(
             |request: &http::Request<axum::body::Body>| {
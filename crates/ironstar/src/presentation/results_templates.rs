@@ -0,0 +1,174 @@
+//! Query result table templates for Datastar-driven paging.
+//!
+//! A results table keeps its body and pagination in elements with stable ids,
+//! so the page handler can return both as one fragment and Datastar morphs
+//! them in place while the header stays put.
+
+use hypertext::prelude::*;
+use serde_json::Value;
+
+use crate::domain::QueryId;
+use crate::presentation::chart_transformer::QueryResult;
+
+/// Rows shown per results page.
+pub const RESULTS_PAGE_SIZE: usize = 50;
+
+/// Element id of the results table body.
+pub const RESULTS_BODY_ID: &str = "results-body";
+
+/// Element id of the results pagination controls.
+pub const RESULTS_PAGINATION_ID: &str = "results-pagination";
+
+/// One page of a query result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResultPage<'a> {
+    /// Rows on this page.
+    pub rows: &'a [Vec<Value>],
+    /// 1-based page number.
+    pub number: usize,
+    /// Number of pages; at least 1, even for an empty result.
+    pub page_count: usize,
+    /// Rows in the whole result.
+    pub total_rows: usize,
+}
+
+impl<'a> ResultPage<'a> {
+    /// Slice page `requested` (1-based) of `result`.
+    ///
+    /// Out-of-range requests are clamped: page 0 yields the first page and
+    /// pages past the end yield the last.
+    #[must_use]
+    pub fn of(result: &'a QueryResult, requested: usize, page_size: usize) -> Self {
+        let page_size = page_size.max(1);
+        let total_rows = result.rows.len();
+        let page_count = total_rows.div_ceil(page_size).max(1);
+        let number = requested.clamp(1, page_count);
+        let start = (number - 1) * page_size;
+        let end = (start + page_size).min(total_rows);
+
+        Self {
+            rows: result.rows.get(start..end).unwrap_or_default(),
+            number,
+            page_count,
+            total_rows,
+        }
+    }
+}
+
+/// Table body holding the rows of `page`.
+pub fn results_table_body(page: &ResultPage<'_>, column_count: usize) -> impl Renderable {
+    let rows: Vec<Vec<String>> = page
+        .rows
+        .iter()
+        .map(|row| row.iter().map(cell_text).collect())
+        .collect();
+
+    maud! {
+        tbody id=(RESULTS_BODY_ID) {
+            @if rows.is_empty() {
+                tr { td colspan=(column_count.max(1)) class="text-2" { "No rows" } }
+            }
+            @for row in &rows {
+                tr {
+                    @for cell in row {
+                        td { (cell) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Previous/next controls fetching neighbouring pages of `query_id`.
+pub fn results_pagination(query_id: &QueryId, page: &ResultPage<'_>) -> impl Renderable {
+    let fetch = |n: usize| format!("@get('/analytics/results/{query_id}/page/{n}')");
+    let previous = (page.number > 1).then(|| fetch(page.number - 1));
+    let next = (page.number < page.page_count).then(|| fetch(page.number + 1));
+    let summary = format!(
+        "Page {} of {} ({} rows)",
+        page.number, page.page_count, page.total_rows
+    );
+
+    maud! {
+        nav id=(RESULTS_PAGINATION_ID) class="cluster" aria-label="Result pages" {
+            @if let Some(action) = &previous {
+                button type="button" class="button outlined" "data-on:click"=(action) { "Previous" }
+            } @else {
+                button type="button" class="button outlined" disabled { "Previous" }
+            }
+            span class="text-2" { (summary) }
+            @if let Some(action) = &next {
+                button type="button" class="button outlined" "data-on:click"=(action) { "Next" }
+            } @else {
+                button type="button" class="button outlined" disabled { "Next" }
+            }
+        }
+    }
+}
+
+/// Display text of a result cell: strings unquoted, null blank.
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(rows: usize) -> QueryResult {
+        QueryResult::new(
+            Vec::new(),
+            (0..rows)
+                .map(|i| vec![Value::from(i), Value::Null])
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn slices_requested_page() {
+        let result = result(5);
+        let page = ResultPage::of(&result, 2, 2);
+
+        assert_eq!(page.number, 2);
+        assert_eq!(page.page_count, 3);
+        assert_eq!(page.rows, &result.rows[2..4]);
+    }
+
+    #[test]
+    fn out_of_range_pages_are_clamped() {
+        let result = result(5);
+
+        let past_end = ResultPage::of(&result, 9, 2);
+        assert_eq!(past_end.number, 3);
+        assert_eq!(past_end.rows, &result.rows[4..]);
+
+        assert_eq!(ResultPage::of(&result, 0, 2).number, 1);
+    }
+
+    #[test]
+    fn empty_result_has_one_empty_page() {
+        let result = result(0);
+        let page = ResultPage::of(&result, 3, 2);
+
+        assert_eq!((page.number, page.page_count), (1, 1));
+        let html = results_table_body(&page, 2).render();
+        assert!(html.as_inner().contains("No rows"));
+    }
+
+    #[test]
+    fn pagination_disables_missing_neighbours() {
+        let result = result(3);
+        let query_id = QueryId::new();
+        let page = ResultPage::of(&result, 1, 2);
+        let html = results_pagination(&query_id, &page).render();
+        let body = html.as_inner();
+
+        assert!(body.contains(&format!("/analytics/results/{query_id}/page/2")));
+        assert!(!body.contains("/page/0"));
+        assert!(body.contains("Page 1 of 2 (3 rows)"));
+    }
+}
@@ -0,0 +1,305 @@
+//! User settings page and handlers.
+//!
+//! The settings page edits the acting user's preferences. Each command
+//! handler issues one user preferences command and responds with a
+//! one-shot SSE stream carrying a `datastar-patch-signals` event with the
+//! resulting [`SettingsSignals`], so the page updates live.
+//!
+//! # Routes
+//!
+//! - `GET /` - Render the settings page (HTML)
+//! - `POST /api/theme` - Set theme
+//! - `POST /api/locale` - Set locale
+//! - `POST /api/notifications` - Set notification preferences
+//!
+//! Preferences are initialized on the first change, so a user who never
+//! saved settings sees the defaults without anything being written.
+
+use axum::Json;
+use axum::Router;
+use axum::extract::State;
+use axum::response::sse::{Event, Sse};
+use axum::response::{Html, IntoResponse};
+use axum::routing::{get, post};
+use chrono::{DateTime, Utc};
+use datastar::prelude::PatchSignals;
+use futures::stream::{self, Stream};
+use hypertext::Renderable;
+use serde::Deserialize;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tracing::{instrument, warn};
+
+use crate::application::user_preferences::handle_user_preferences_command_zenoh;
+use crate::application::workspace::query_user_preferences;
+use crate::domain::session::UserId;
+use crate::domain::signals::SettingsSignals;
+use crate::domain::user_preferences::commands::UserPreferencesCommand;
+use crate::domain::user_preferences::values::{
+    DigestFrequency, Locale, NotificationPreferences, PreferencesId, Theme,
+};
+use crate::domain::views::UserPreferencesViewState;
+use crate::infrastructure::assets::AssetManifest;
+use crate::presentation::error::AppError;
+use crate::presentation::extractors::Actor;
+use crate::presentation::settings_templates::settings_page;
+use crate::presentation::workspace::WorkspaceAppState;
+use crate::state::AppState;
+
+/// Creates the settings feature router.
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(settings_page_handler))
+        .route("/api/theme", post(set_theme))
+        .route("/api/locale", post(set_locale))
+        .route("/api/notifications", post(set_notifications))
+}
+
+/// Request body for setting the theme.
+#[derive(Debug, Deserialize)]
+pub struct SetThemeRequest {
+    /// `light`, `dark`, or `system`.
+    pub theme: String,
+}
+
+/// Request body for setting the locale.
+#[derive(Debug, Deserialize)]
+pub struct SetLocaleRequest {
+    pub locale: String,
+}
+
+/// Request body for setting notification preferences.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetNotificationsRequest {
+    pub email_enabled: bool,
+    pub in_app_enabled: bool,
+    /// `off`, `daily`, or `weekly`.
+    pub digest: String,
+}
+
+/// GET / - Render the settings page for the acting user.
+#[instrument(name = "handler.settings.page", skip(state, manifest))]
+async fn settings_page_handler(
+    State(state): State<WorkspaceAppState>,
+    State(manifest): State<AssetManifest>,
+    Actor(actor): Actor,
+) -> Result<impl IntoResponse, AppError> {
+    let preferences = query_user_preferences(&state.user_preferences_repo, &actor).await?;
+    let signals = SettingsSignals::from(&preferences);
+    let html = settings_page(&manifest, preferences.theme, &signals).render();
+
+    Ok(Html(html.into_inner()))
+}
+
+/// POST /api/theme - Set the acting user's theme.
+#[instrument(name = "handler.settings.set_theme", skip(state, request))]
+pub async fn set_theme(
+    State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+    Json(request): Json<SetThemeRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let theme = request.theme.parse::<Theme>()?;
+    let event = apply_settings_command(&state, actor, |set_at| UserPreferencesCommand::SetTheme {
+        user_id: actor,
+        theme,
+        set_at,
+    })
+    .await?;
+    Ok(Sse::new(stream::once(async move { Ok(event) })))
+}
+
+/// POST /api/locale - Set the acting user's locale.
+#[instrument(name = "handler.settings.set_locale", skip(state, request))]
+pub async fn set_locale(
+    State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+    Json(request): Json<SetLocaleRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let locale = Locale::new(request.locale)?;
+    let event = apply_settings_command(&state, actor, |set_at| UserPreferencesCommand::SetLocale {
+        user_id: actor,
+        locale,
+        set_at,
+    })
+    .await?;
+    Ok(Sse::new(stream::once(async move { Ok(event) })))
+}
+
+/// POST /api/notifications - Set the acting user's notification preferences.
+#[instrument(name = "handler.settings.set_notifications", skip(state, request))]
+pub async fn set_notifications(
+    State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+    Json(request): Json<SetNotificationsRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    let notifications = NotificationPreferences::new(
+        request.email_enabled,
+        request.in_app_enabled,
+        request.digest.parse::<DigestFrequency>()?,
+    );
+    let event = apply_settings_command(&state, actor, |set_at| {
+        UserPreferencesCommand::SetNotificationPreferences {
+            user_id: actor,
+            notifications,
+            set_at,
+        }
+    })
+    .await?;
+    Ok(Sse::new(stream::once(async move { Ok(event) })))
+}
+
+/// Issue the command built by `command` for `actor` and render the
+/// resulting signals.
+///
+/// The command is timestamped after any initialization, since the decider
+/// rejects changes dated before the preferences were initialized. Anonymous
+/// requests are forbidden: they all act as [`UserId::system`], so their
+/// settings would be shared by every anonymous visitor.
+async fn apply_settings_command(
+    state: &WorkspaceAppState,
+    actor: UserId,
+    command: impl FnOnce(DateTime<Utc>) -> UserPreferencesCommand,
+) -> Result<Event, AppError> {
+    if actor.is_system() {
+        return Err(AppError::forbidden("change settings without signing in"));
+    }
+    let preferences = query_user_preferences(&state.user_preferences_repo, &actor).await?;
+    if !preferences.initialized {
+        handle_user_preferences_command_zenoh(
+            Arc::clone(&state.user_preferences_repo),
+            state.event_bus.as_deref(),
+            UserPreferencesCommand::InitializePreferences {
+                preferences_id: PreferencesId::new(),
+                user_id: actor,
+                initialized_at: state.clock.now(),
+            },
+        )
+        .await?;
+    }
+
+    handle_user_preferences_command_zenoh(
+        Arc::clone(&state.user_preferences_repo),
+        state.event_bus.as_deref(),
+        command(state.clock.now()),
+    )
+    .await?;
+
+    let preferences = query_user_preferences(&state.user_preferences_repo, &actor).await?;
+    Ok(settings_signals_event(&preferences))
+}
+
+/// Render `preferences` as a `datastar-patch-signals` event.
+fn settings_signals_event(preferences: &UserPreferencesViewState) -> Event {
+    let signals = SettingsSignals::from(preferences);
+    let json = serde_json::to_string(&signals).unwrap_or_else(|e| {
+        warn!(error = %e, "Failed to serialize SettingsSignals");
+        "{}".to_string()
+    });
+    PatchSignals::new(json).into()
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::presentation::test_support::{sign_in, workspace_app_state};
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    /// Settings router and a session cookie signed in as a new user.
+    async fn create_settings_router() -> (Router, String) {
+        let state = workspace_app_state().await;
+        let cookie = sign_in(&state, &UserId::new()).await;
+        let router = Router::new()
+            .route("/api/theme", post(set_theme))
+            .route("/api/locale", post(set_locale))
+            .with_state(state);
+        (router, cookie)
+    }
+
+    async fn post_json(
+        app: &Router,
+        cookie: Option<&str>,
+        uri: &str,
+        body: serde_json::Value,
+    ) -> (StatusCode, String) {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json");
+        if let Some(cookie) = cookie {
+            request = request.header("cookie", cookie);
+        }
+        let response = app
+            .clone()
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+            .await
+            .expect("request should succeed");
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn theme_change_patches_theme_signal() {
+        let (app, cookie) = create_settings_router().await;
+
+        let (status, body) = post_json(
+            &app,
+            Some(&cookie),
+            "/api/theme",
+            serde_json::json!({ "theme": "dark" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("event: datastar-patch-signals"));
+        assert!(body.contains(r#""theme":"dark""#));
+
+        // A later change keeps the earlier one: preferences are initialized once.
+        let (status, body) = post_json(
+            &app,
+            Some(&cookie),
+            "/api/locale",
+            serde_json::json!({ "locale": "de-DE" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#""theme":"dark""#));
+        assert!(body.contains(r#""locale":"de-DE""#));
+    }
+
+    #[tokio::test]
+    async fn unknown_theme_is_rejected() {
+        let (app, cookie) = create_settings_router().await;
+
+        let (status, _) = post_json(
+            &app,
+            Some(&cookie),
+            "/api/theme",
+            serde_json::json!({ "theme": "sepia" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn anonymous_caller_may_not_change_settings() {
+        let (app, _) = create_settings_router().await;
+
+        let (status, _) = post_json(
+            &app,
+            None,
+            "/api/theme",
+            serde_json::json!({ "theme": "dark" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+}
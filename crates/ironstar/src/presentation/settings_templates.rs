@@ -0,0 +1,125 @@
+//! User settings hypertext templates.
+//!
+//! Every control binds to a [`SettingsSignals`] field and posts on change;
+//! the handlers answer with a signal patch, so the page never re-renders.
+//! The layout applies the `theme` signal, which makes a theme change visible
+//! as soon as its signal patch lands.
+
+use hypertext::prelude::*;
+
+use crate::domain::signals::SettingsSignals;
+use crate::domain::user_preferences::values::Theme;
+use crate::infrastructure::assets::AssetManifest;
+use crate::presentation::components::{checkbox, text_field};
+use crate::presentation::layout::themed_layout;
+
+/// Complete settings page with base layout.
+pub fn settings_page(
+    manifest: &AssetManifest,
+    theme: Theme,
+    signals: &SettingsSignals,
+) -> impl Renderable {
+    let signals_json = serde_json::to_string(signals).unwrap_or_else(|_| "{}".to_string());
+
+    let content = maud! {
+        main
+            id="settings"
+            class="center stack"
+            "data-signals"=(signals_json)
+        {
+            h1 { "Settings" }
+
+            (appearance_section())
+
+            (notifications_section(signals))
+        }
+    };
+
+    themed_layout(manifest, theme, content)
+}
+
+/// Theme and locale controls.
+fn appearance_section() -> impl Renderable {
+    maud! {
+        section class="card stack" {
+            h2 { "Appearance" }
+
+            label class="stack" {
+                "Theme"
+                select
+                    name="theme"
+                    "data-bind"="theme"
+                    "data-on:change"="@post('/settings/api/theme')"
+                {
+                    option value="system" { "System" }
+                    option value="light" { "Light" }
+                    option value="dark" { "Dark" }
+                }
+            }
+
+            (text_field(
+                "locale",
+                "Locale",
+                " ",
+                "outlined",
+                r#"data-bind:locale data-on:change="@post('/settings/api/locale')""#
+            ))
+        }
+    }
+}
+
+/// Email, in-app, and digest notification controls.
+fn notifications_section(signals: &SettingsSignals) -> impl Renderable {
+    let on_change = r#"data-on:change="@post('/settings/api/notifications')""#;
+    let email_attrs = format!("data-bind:email-enabled {on_change}");
+    let in_app_attrs = format!("data-bind:in-app-enabled {on_change}");
+
+    maud! {
+        section class="card stack" {
+            h2 { "Notifications" }
+
+            label class="cluster" {
+                (checkbox("emailEnabled", signals.email_enabled, &email_attrs))
+                "Email notifications"
+            }
+
+            label class="cluster" {
+                (checkbox("inAppEnabled", signals.in_app_enabled, &in_app_attrs))
+                "In-app notifications"
+            }
+
+            label class="stack" {
+                "Digest"
+                select
+                    name="digest"
+                    "data-bind"="digest"
+                    "data-on:change"="@post('/settings/api/notifications')"
+                {
+                    option value="off" { "Off" }
+                    option value="daily" { "Daily" }
+                    option value="weekly" { "Weekly" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::views::UserPreferencesViewState;
+
+    #[test]
+    fn settings_page_seeds_signals_and_applies_theme() {
+        let signals = SettingsSignals::from(&UserPreferencesViewState::default());
+        let html = settings_page(&AssetManifest::default(), Theme::System, &signals).render();
+        let body = html.as_inner();
+
+        assert!(body.contains("data-signals="));
+        assert!(body.contains("&quot;theme&quot;:&quot;system&quot;"));
+        assert!(body.contains("data-effect="));
+        assert!(body.contains("/settings/api/theme"));
+        assert!(body.contains("/settings/api/locale"));
+        assert!(body.contains("/settings/api/notifications"));
+    }
+}
@@ -0,0 +1,64 @@
+//! Shared fixtures for presentation handler tests.
+//!
+//! Handlers on [`WorkspaceAppState`] attribute and authorize commands by
+//! the request's [`Actor`](super::extractors::Actor), so the state built
+//! here carries a session store and [`sign_in`] returns a cookie acting as
+//! a given user. Requests without the cookie act as the anonymous user.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use sqlx::sqlite::SqlitePoolOptions;
+
+use crate::application::workspace::{ReservedWorkspaceNames, WorkspaceAdministrators};
+use crate::domain::clock::SystemClock;
+use crate::domain::session::UserId;
+use crate::infrastructure::event_store::{EVENTS_MIGRATION_SQL, SqliteEventRepository};
+use crate::infrastructure::session_store::{
+    SESSIONS_MIGRATION_SQL, SessionStore, SqliteSessionStore,
+};
+use crate::presentation::extractors::SESSION_COOKIE_NAME;
+use crate::presentation::workspace::WorkspaceAppState;
+
+/// Workspace handler state with every repository and the session store on
+/// one in-memory pool.
+#[allow(clippy::expect_used)]
+pub(crate) async fn workspace_app_state() -> WorkspaceAppState {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to create test pool");
+    for migration in [EVENTS_MIGRATION_SQL, SESSIONS_MIGRATION_SQL] {
+        sqlx::query(migration)
+            .execute(&pool)
+            .await
+            .expect("Failed to run migration");
+    }
+
+    WorkspaceAppState {
+        workspace_repo: Arc::new(SqliteEventRepository::new(pool.clone())),
+        dashboard_repo: Arc::new(SqliteEventRepository::new(pool.clone())),
+        saved_query_repo: Arc::new(SqliteEventRepository::new(pool.clone())),
+        user_preferences_repo: Arc::new(SqliteEventRepository::new(pool.clone())),
+        workspace_preferences_repo: Arc::new(SqliteEventRepository::new(pool.clone())),
+        event_bus: None,
+        clock: Arc::new(SystemClock),
+        reserved_workspace_names: Arc::new(ReservedWorkspaceNames::default()),
+        workspace_administrators: Arc::new(WorkspaceAdministrators::default()),
+        session_store: Some(Arc::new(SqliteSessionStore::new(pool, Duration::days(1)))),
+    }
+}
+
+/// A `Cookie` header value for a new session signed in as `user_id`.
+#[allow(clippy::expect_used)]
+pub(crate) async fn sign_in(state: &WorkspaceAppState, user_id: &UserId) -> String {
+    let session = state
+        .session_store
+        .as_ref()
+        .expect("test state has a session store")
+        .create(Some(&user_id.to_string()))
+        .await
+        .expect("create session");
+    format!("{SESSION_COOKIE_NAME}={}", session.id)
+}
@@ -23,7 +23,6 @@ use axum::response::{
     sse::{Event, Sse},
 };
 use axum::routing::{delete as route_delete, get, post};
-use chrono::Utc;
 use futures::Stream;
 use futures::stream::StreamExt;
 use hypertext::Renderable;
@@ -34,18 +33,22 @@ use tracing::{instrument, warn};
 use uuid::Uuid;
 
 use crate::application::todo::{handle_todo_command_zenoh, query_all_todos, query_todo_state};
+use crate::domain::clock::Clock;
 use crate::domain::signals::TodoItemView;
 use crate::domain::todo::commands::TodoCommand;
 use crate::domain::todo::events::TodoEvent;
 use crate::domain::todo::values::TodoId;
 use crate::infrastructure::assets::AssetManifest;
 use crate::infrastructure::event_bus::ZenohEventBus;
-use crate::infrastructure::event_store::SqliteEventRepository;
+use crate::infrastructure::event_store::{GlobalSequence, SqliteEventRepository};
 use crate::infrastructure::key_expr::aggregate_type_pattern;
 use crate::infrastructure::sse_stream::SseStreamBuilder;
 use crate::presentation::datastar_bridge::ToDatastarEvents;
 use crate::presentation::error::AppError;
+use crate::presentation::extractors::Actor;
+use crate::presentation::layout::actor_theme;
 use crate::presentation::todo_templates::todo_page;
+use crate::presentation::workspace::WorkspaceAppState;
 use crate::state::AppState;
 use ironstar_todo::{TodoViewState, todo_view};
 
@@ -62,6 +65,8 @@ pub struct TodoAppState {
     /// When `None`, events are persisted but not published to subscribers.
     /// Use `None` in tests that don't require event bus integration.
     pub event_bus: Option<Arc<ZenohEventBus>>,
+    /// Clock used to timestamp commands.
+    pub clock: Arc<dyn Clock>,
 }
 
 // =============================================================================
@@ -100,16 +105,19 @@ pub fn routes() -> Router<AppState> {
 }
 
 /// GET / - Render the todo page with current todos.
-#[instrument(name = "handler.todo.page", skip(state, manifest))]
+#[instrument(name = "handler.todo.page", skip(state, manifest, workspace))]
 async fn todo_page_handler(
     State(state): State<TodoAppState>,
     State(manifest): State<AssetManifest>,
+    State(workspace): State<WorkspaceAppState>,
+    Actor(actor): Actor,
 ) -> Result<impl IntoResponse, AppError> {
     // Query current todos
     let view_state = query_all_todos(&state.repo).await?;
 
     // Render page with manifest-resolved asset paths
-    let html = todo_page(&manifest, &view_state.todos).render();
+    let theme = actor_theme(&workspace, &actor).await;
+    let html = todo_page(&manifest, theme, &view_state.todos).render();
 
     Ok(Html(html.into_inner()))
 }
@@ -165,11 +173,11 @@ async fn todo_feed_handler(
     })?;
 
     // Extract Last-Event-ID for reconnection support
-    let last_event_id: i64 = headers
+    let last_event_id: GlobalSequence = headers
         .get("Last-Event-ID")
         .and_then(|h| h.to_str().ok())
         .and_then(|s| s.parse().ok())
-        .unwrap_or(0);
+        .unwrap_or_default();
 
     // CRITICAL INVARIANT: Subscribe BEFORE loading historical events.
     // This prevents race conditions where events arrive during replay and get missed.
@@ -196,7 +204,7 @@ async fn todo_feed_handler(
     // Fold historical events into view state
     let view = todo_view();
     let mut view_state = TodoViewState::default();
-    let mut latest_seq: i64 = 0;
+    let mut latest_seq = GlobalSequence::ZERO;
     for stored in &historical_events {
         view_state = (view.evolve)(&view_state, &stored.event);
         latest_seq = stored.sequence;
@@ -254,7 +262,7 @@ async fn todo_feed_handler(
 /// SSE event ID on the final event to support `Last-Event-ID` reconnection.
 fn view_state_to_sse_events(
     view_state: &TodoViewState,
-    latest_seq: i64,
+    latest_seq: GlobalSequence,
 ) -> Vec<Result<Event, Infallible>> {
     use crate::presentation::datastar_bridge::render_patch_elements_with_selector;
     use crate::presentation::todo_templates::{todo_footer, todo_list};
@@ -262,7 +270,7 @@ fn view_state_to_sse_events(
     let mut events = Vec::new();
 
     // Render the todo list targeting #todo-list
-    let id_str = if latest_seq > 0 {
+    let id_str = if latest_seq > GlobalSequence::ZERO {
         Some(latest_seq.to_string())
     } else {
         None
@@ -506,7 +514,7 @@ pub async fn create_todo(
     let command = TodoCommand::Create {
         id,
         text: request.text,
-        created_at: Utc::now(),
+        created_at: state.clock.now(),
     };
 
     // Convert Arc<ZenohEventBus> to &ZenohEventBus for the generic call
@@ -551,7 +559,7 @@ pub async fn complete_todo(
     let todo_id = TodoId::from_uuid(id);
     let command = TodoCommand::Complete {
         id: todo_id,
-        completed_at: Utc::now(),
+        completed_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
@@ -594,7 +602,7 @@ pub async fn delete_todo(
     let todo_id = TodoId::from_uuid(id);
     let command = TodoCommand::Delete {
         id: todo_id,
-        deleted_at: Utc::now(),
+        deleted_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
@@ -613,7 +621,9 @@ pub async fn delete_todo(
 mod tests {
     use super::*;
     use crate::application::todo::handle_todo_command;
+    use crate::domain::clock::SystemClock;
     use crate::infrastructure::event_bus::ZenohEventBus;
+    use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
     use axum::Router;
     use axum::body::Body;
     use axum::http::Request;
@@ -629,7 +639,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -644,6 +654,7 @@ mod tests {
             .with_state(TodoAppState {
                 repo,
                 event_bus: None,
+                clock: Arc::new(SystemClock),
             })
     }
 
@@ -6,12 +6,17 @@
 use hypertext::prelude::*;
 
 use crate::domain::signals::TodoItemView;
+use crate::domain::user_preferences::values::Theme;
 use crate::infrastructure::assets::AssetManifest;
 use crate::presentation::components::{button, checkbox, loading_spinner, text_field};
-use crate::presentation::layout::base_layout;
+use crate::presentation::layout::themed_layout;
 
 /// Complete todo page with base layout.
-pub fn todo_page(manifest: &AssetManifest, todos: &[TodoItemView]) -> impl Renderable {
+pub fn todo_page(
+    manifest: &AssetManifest,
+    theme: Theme,
+    todos: &[TodoItemView],
+) -> impl Renderable {
     let content = maud! {
         main class="center stack" {
             h1 { "Todo" }
@@ -21,7 +26,7 @@ pub fn todo_page(manifest: &AssetManifest, todos: &[TodoItemView]) -> impl Rende
         }
     };
 
-    base_layout(manifest, content)
+    themed_layout(manifest, theme, content)
 }
 
 /// Todo application container with SSE connection.
@@ -9,8 +9,11 @@
 //! Query endpoints:
 //! - `GET /api` - List all workspaces
 //! - `GET /api/{id}/dashboard/{dashboard_id}` - Get dashboard layout
+//! - `GET /api/{id}/dashboard/{dashboard_id}/grid` - Render the dashboard grid
 //! - `GET /api/{id}/queries` - List saved queries for a workspace
 //! - `GET /api/user/preferences/{user_id}` - Get user preferences
+//! - `GET /api/user/{user_id}/home` - Redirect to the user's default workspace
+//! - `GET /api/{id}/preferences` - Render the workspace preferences panel
 //!
 //! Workspace lifecycle:
 //! - `POST /api` - Create a new workspace
@@ -24,21 +27,30 @@
 //! Saved queries:
 //! - `POST /api/{id}/query` - Save a query in a workspace
 //!
-//! Workspace preferences:
+//! Workspace preferences (each returns the updated preferences panel):
 //! - `POST /api/{id}/preferences/catalog` - Set default catalog
 //! - `POST /api/{id}/preferences/catalog/clear` - Clear default catalog
+//! - `POST /api/{id}/preferences/layout` - Update layout defaults
+//!
+//! Preferences endpoints are authorized by the actor's [`WorkspaceRole`]:
+//! reading requires viewer access and changes require the owner role.
+//! Workspaces the actor cannot see at all respond as not found. Changes to a
+//! suspended workspace, its dashboards, or its saved queries are rejected.
 //!
 //! User preferences:
 //! - `POST /api/user/preferences/theme` - Set user theme
 //! - `POST /api/user/preferences/locale` - Set user locale
+//! - `POST /api/user/preferences/{user_id}/workspace` - Set default workspace
+//! - `POST /api/user/preferences/{user_id}/workspace/clear` - Clear default workspace
 
 use axum::Json;
 use axum::Router;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::response::IntoResponse;
+use axum::response::{Html, IntoResponse, Redirect};
 use axum::routing::{get, post};
 use chrono::Utc;
+use hypertext::prelude::*;
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::instrument;
@@ -46,40 +58,51 @@ use uuid::Uuid;
 
 use crate::application::dashboard::handle_dashboard_command_zenoh;
 use crate::application::error::CommandPipelineError;
-use crate::application::saved_query::handle_saved_query_command_zenoh;
+use crate::application::saved_query::{execute_saved_query, handle_saved_query_command_zenoh};
 use crate::application::user_preferences::handle_user_preferences_command_zenoh;
 use crate::application::workspace::{
-    handle_workspace_command_zenoh, query_dashboard_layout, query_saved_query_list,
-    query_user_preferences, query_workspace_list,
+    ReservedWorkspaceNames, WorkspaceAdministrators, handle_workspace_command_zenoh,
+    query_dashboard_layout, query_saved_query_list, query_user_preferences, query_workspace_list,
+    query_workspace_preferences,
 };
 use crate::application::workspace_preferences::handle_workspace_preferences_command_zenoh;
 use crate::domain::analytics::{DatasetRef, SqlQuery};
+use crate::domain::clock::Clock;
 use crate::domain::common::DashboardTitle;
 use crate::domain::dashboard::commands::DashboardCommand;
 use crate::domain::dashboard::events::DashboardEvent;
 use crate::domain::dashboard::values::{ChartPlacement, DashboardId};
+use crate::domain::error::{ValidationError, ValidationErrorKind};
 use crate::domain::saved_query::commands::SavedQueryCommand;
 use crate::domain::saved_query::events::SavedQueryEvent;
 use crate::domain::saved_query::values::{QueryName, SavedQueryId};
 use crate::domain::session::UserId;
 use crate::domain::user_preferences::commands::UserPreferencesCommand;
 use crate::domain::user_preferences::events::UserPreferencesEvent;
-use crate::domain::user_preferences::values::{Locale, PreferencesId, Theme};
+use crate::domain::user_preferences::values::{
+    Locale, NotificationPreferences, PreferencesId, Theme,
+};
+use crate::domain::views::DashboardLayoutViewState;
+use crate::domain::workspace::WorkspaceError;
 use crate::domain::workspace::commands::WorkspaceCommand;
 use crate::domain::workspace::events::WorkspaceEvent;
-use crate::domain::workspace::values::{Visibility, WorkspaceId, WorkspaceName};
+use crate::domain::workspace::values::{Visibility, WorkspaceId, WorkspaceName, WorkspaceRole};
 use crate::domain::workspace_preferences::commands::WorkspacePreferencesCommand;
 use crate::domain::workspace_preferences::events::WorkspacePreferencesEvent;
-use crate::domain::workspace_preferences::values::CatalogUri;
+use crate::domain::workspace_preferences::values::{CatalogUri, LayoutDefaults};
+use crate::infrastructure::SqliteSessionStore;
 use crate::infrastructure::event_bus::ZenohEventBus;
 use crate::infrastructure::event_store::SqliteEventRepository;
 use crate::presentation::error::AppError;
+use crate::presentation::extractors::{Actor, RequestCorrelation};
+use crate::presentation::workspace_templates::{dashboard_grid, workspace_preferences_panel};
 use crate::state::AppState;
 
 /// Application state for Workspace bounded context handlers.
 ///
-/// Contains event repositories for all five aggregate types and an optional
-/// event bus for post-persist notification.
+/// Contains event repositories for all five aggregate types, an optional
+/// event bus for post-persist notification, the clock used to timestamp
+/// commands, and the optional session store used to attribute them.
 #[derive(Clone)]
 pub struct WorkspaceAppState {
     pub workspace_repo: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>,
@@ -90,6 +113,10 @@ pub struct WorkspaceAppState {
     pub workspace_preferences_repo:
         Arc<SqliteEventRepository<WorkspacePreferencesCommand, WorkspacePreferencesEvent>>,
     pub event_bus: Option<Arc<ZenohEventBus>>,
+    pub clock: Arc<dyn Clock>,
+    pub reserved_workspace_names: Arc<ReservedWorkspaceNames>,
+    pub workspace_administrators: Arc<WorkspaceAdministrators>,
+    pub session_store: Option<Arc<SqliteSessionStore>>,
 }
 
 // =============================================================================
@@ -105,8 +132,14 @@ pub fn routes() -> Router<AppState> {
             "/api/{id}/dashboard/{dashboard_id}",
             get(get_dashboard_layout),
         )
+        .route(
+            "/api/{id}/dashboard/{dashboard_id}/grid",
+            get(get_dashboard_grid),
+        )
         .route("/api/{id}/queries", get(list_saved_queries))
         .route("/api/user/preferences/{user_id}", get(get_user_preferences))
+        .route("/api/user/{user_id}/home", get(user_home))
+        .route("/api/{id}/preferences", get(get_workspace_preferences))
         // Workspace lifecycle
         .route("/api", post(create_workspace))
         .route("/api/{id}/rename", post(rename_workspace))
@@ -116,15 +149,25 @@ pub fn routes() -> Router<AppState> {
         .route("/api/{id}/dashboard/{dashboard_id}/chart", post(add_chart))
         // Saved queries
         .route("/api/{id}/query", post(save_query))
+        .route("/api/{id}/query/{query_id}/run", post(record_query_run))
         // Workspace preferences
         .route("/api/{id}/preferences/catalog", post(set_default_catalog))
         .route(
             "/api/{id}/preferences/catalog/clear",
             post(clear_default_catalog),
         )
+        .route("/api/{id}/preferences/layout", post(update_layout_defaults))
         // User preferences
         .route("/api/user/preferences/theme", post(set_theme))
         .route("/api/user/preferences/locale", post(set_locale))
+        .route(
+            "/api/user/preferences/{user_id}/workspace",
+            post(set_default_workspace),
+        )
+        .route(
+            "/api/user/preferences/{user_id}/workspace/clear",
+            post(clear_default_workspace),
+        )
 }
 
 // =============================================================================
@@ -174,6 +217,9 @@ pub struct DashboardLayoutResponse {
     pub placements: Vec<ChartPlacement>,
     pub chart_count: usize,
     pub tab_count: usize,
+    /// CSS custom properties sizing the dashboard grid, from the workspace
+    /// layout defaults.
+    pub grid_style: String,
 }
 
 /// A single saved query entry in the list response.
@@ -186,6 +232,8 @@ pub struct SavedQueryListItem {
     pub sql: String,
     pub dataset_ref: String,
     pub saved_at: chrono::DateTime<Utc>,
+    pub run_count: u64,
+    pub last_run_at: Option<chrono::DateTime<Utc>>,
 }
 
 /// Response body for the saved query list query.
@@ -204,6 +252,9 @@ pub struct UserPreferencesResponse {
     pub user_id: Option<UserId>,
     pub theme: Theme,
     pub locale: Locale,
+    pub notifications: NotificationPreferences,
+    pub default_workspace: Option<WorkspaceId>,
+    pub recent_workspaces: Vec<WorkspaceId>,
     pub initialized: bool,
 }
 
@@ -240,15 +291,7 @@ pub async fn get_dashboard_layout(
     State(state): State<WorkspaceAppState>,
     Path((_workspace_id, dashboard_id)): Path<(Uuid, Uuid)>,
 ) -> Result<impl IntoResponse, AppError> {
-    let view_state = query_dashboard_layout::<DashboardCommand>(
-        &state.dashboard_repo,
-        &dashboard_id.to_string(),
-    )
-    .await?;
-
-    if view_state.dashboard_id.is_none() {
-        return Err(AppError::not_found("Dashboard", dashboard_id.to_string()));
-    }
+    let (view_state, layout_defaults) = load_dashboard_layout(&state, dashboard_id).await?;
 
     Ok(Json(DashboardLayoutResponse {
         dashboard_id: view_state.dashboard_id,
@@ -257,9 +300,67 @@ pub async fn get_dashboard_layout(
         placements: view_state.placements,
         chart_count: view_state.chart_count,
         tab_count: view_state.tab_count,
+        grid_style: dashboard_grid_style(&layout_defaults),
     }))
 }
 
+/// GET /api/{id}/dashboard/{dashboard_id}/grid - Render the dashboard grid.
+///
+/// The grid's style attribute carries the workspace's row height and gap.
+#[instrument(name = "handler.dashboard.get_grid", skip(state), fields(dashboard_id = %dashboard_id))]
+pub async fn get_dashboard_grid(
+    State(state): State<WorkspaceAppState>,
+    Path((_workspace_id, dashboard_id)): Path<(Uuid, Uuid)>,
+) -> Result<Html<String>, AppError> {
+    let (view_state, layout_defaults) = load_dashboard_layout(&state, dashboard_id).await?;
+    let html = dashboard_grid(
+        &view_state.placements,
+        &dashboard_grid_style(&layout_defaults),
+    )
+    .render();
+    Ok(Html(html.into_inner()))
+}
+
+/// Load a dashboard's layout with its workspace's layout defaults.
+///
+/// Dashboards without a workspace use the default layout.
+async fn load_dashboard_layout(
+    state: &WorkspaceAppState,
+    dashboard_id: Uuid,
+) -> Result<(DashboardLayoutViewState, LayoutDefaults), AppError> {
+    let view_state = query_dashboard_layout::<DashboardCommand>(
+        &state.dashboard_repo,
+        &DashboardId::from_uuid(dashboard_id),
+    )
+    .await?;
+
+    if view_state.dashboard_id.is_none() {
+        return Err(AppError::not_found("Dashboard", dashboard_id.to_string()));
+    }
+
+    let layout_defaults = match &view_state.workspace_id {
+        Some(workspace_id) => {
+            query_workspace_preferences::<WorkspacePreferencesCommand>(
+                &state.workspace_preferences_repo,
+                workspace_id,
+            )
+            .await?
+            .layout_defaults
+        }
+        None => LayoutDefaults::default(),
+    };
+    Ok((view_state, layout_defaults))
+}
+
+/// CSS custom properties read by the `.grid` composition.
+fn dashboard_grid_style(layout_defaults: &LayoutDefaults) -> String {
+    format!(
+        "--grid-row-height: {}px; --grid-gap: {}px",
+        layout_defaults.row_height_px(),
+        layout_defaults.gap_px()
+    )
+}
+
 /// GET /api/{id}/queries - List saved queries for a workspace.
 #[instrument(name = "handler.saved_query.list", skip(state), fields(workspace_id = %workspace_id))]
 pub async fn list_saved_queries(
@@ -279,6 +380,8 @@ pub async fn list_saved_queries(
             sql: q.sql.clone(),
             dataset_ref: q.dataset_ref.clone(),
             saved_at: q.saved_at,
+            run_count: q.run_count,
+            last_run_at: q.last_run_at,
         })
         .collect();
     let count = queries.len();
@@ -296,16 +399,45 @@ pub async fn get_user_preferences(
     let view_state =
         query_user_preferences::<UserPreferencesCommand>(&state.user_preferences_repo, &uid)
             .await?;
+    let recent_workspaces = view_state.recent_workspaces().to_vec();
 
     Ok(Json(UserPreferencesResponse {
         preferences_id: view_state.preferences_id,
         user_id: view_state.user_id,
         theme: view_state.theme,
         locale: view_state.locale,
+        notifications: view_state.notifications,
+        default_workspace: view_state.default_workspace,
+        recent_workspaces,
         initialized: view_state.initialized,
     }))
 }
 
+/// GET /api/user/{user_id}/home - Redirect to the user's default workspace.
+///
+/// Without a default workspace the user is sent to the workspace list.
+/// Nothing redirects here automatically; there is no login flow yet.
+#[instrument(name = "handler.user_preferences.home", skip(state), fields(user_id = %user_id))]
+pub async fn user_home(
+    State(state): State<WorkspaceAppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Redirect, AppError> {
+    let uid = UserId::from_uuid(user_id);
+    let view_state =
+        query_user_preferences::<UserPreferencesCommand>(&state.user_preferences_repo, &uid)
+            .await?;
+
+    Ok(Redirect::to(&landing_path(view_state.default_workspace)))
+}
+
+/// Path `user_home` redirects to.
+fn landing_path(default_workspace: Option<WorkspaceId>) -> String {
+    match default_workspace {
+        Some(workspace_id) => format!("/workspace/api/{workspace_id}/queries"),
+        None => "/workspace/api".to_string(),
+    }
+}
+
 // =============================================================================
 // Request payload types
 // =============================================================================
@@ -361,12 +493,27 @@ pub struct SetDefaultCatalogRequest {
     pub catalog_uri: String,
 }
 
+/// Request body for updating layout defaults.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLayoutDefaultsRequest {
+    /// Layout defaults as a JSON object.
+    pub layout_defaults: serde_json::Value,
+}
+
 /// Request body for setting user theme.
 #[derive(Debug, Deserialize)]
 pub struct SetThemeRequest {
     pub theme: Theme,
 }
 
+/// Request body for setting the default workspace.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDefaultWorkspaceRequest {
+    pub workspace_id: Uuid,
+}
+
 /// Request body for setting user locale.
 #[derive(Debug, Deserialize)]
 pub struct SetLocaleRequest {
@@ -383,6 +530,8 @@ pub struct SetLocaleRequest {
 #[instrument(name = "handler.workspace.create", skip(state, request))]
 pub async fn create_workspace(
     State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+    RequestCorrelation(correlation): RequestCorrelation,
     Json(request): Json<CreateWorkspaceRequest>,
 ) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
     let id = WorkspaceId::new();
@@ -391,13 +540,20 @@ pub async fn create_workspace(
         name: request.name,
         owner_id: UserId::from_uuid(request.owner_id),
         visibility: request.visibility,
-        created_at: Utc::now(),
+        actor,
+        created_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
-    let events =
-        handle_workspace_command_zenoh(Arc::clone(&state.workspace_repo), event_bus_ref, command)
-            .await?;
+    let events = handle_workspace_command_zenoh(
+        Arc::clone(&state.workspace_repo),
+        event_bus_ref,
+        &state.reserved_workspace_names,
+        &state.workspace_administrators,
+        command,
+        correlation,
+    )
+    .await?;
 
     Ok((
         StatusCode::ACCEPTED,
@@ -412,6 +568,8 @@ pub async fn create_workspace(
 #[instrument(name = "handler.workspace.rename", skip(state, request), fields(workspace_id = %id))]
 pub async fn rename_workspace(
     State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+    RequestCorrelation(correlation): RequestCorrelation,
     Path(id): Path<Uuid>,
     Json(request): Json<RenameWorkspaceRequest>,
 ) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
@@ -419,13 +577,20 @@ pub async fn rename_workspace(
     let command = WorkspaceCommand::Rename {
         workspace_id,
         new_name: request.new_name,
-        renamed_at: Utc::now(),
+        actor,
+        renamed_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
-    let events =
-        handle_workspace_command_zenoh(Arc::clone(&state.workspace_repo), event_bus_ref, command)
-            .await?;
+    let events = handle_workspace_command_zenoh(
+        Arc::clone(&state.workspace_repo),
+        event_bus_ref,
+        &state.reserved_workspace_names,
+        &state.workspace_administrators,
+        command,
+        correlation,
+    )
+    .await?;
 
     Ok((
         StatusCode::ACCEPTED,
@@ -440,6 +605,8 @@ pub async fn rename_workspace(
 #[instrument(name = "handler.workspace.set_visibility", skip(state, request), fields(workspace_id = %id))]
 pub async fn set_visibility(
     State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+    RequestCorrelation(correlation): RequestCorrelation,
     Path(id): Path<Uuid>,
     Json(request): Json<SetVisibilityRequest>,
 ) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
@@ -447,13 +614,20 @@ pub async fn set_visibility(
     let command = WorkspaceCommand::SetVisibility {
         workspace_id,
         visibility: request.visibility,
-        changed_at: Utc::now(),
+        actor,
+        changed_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
-    let events =
-        handle_workspace_command_zenoh(Arc::clone(&state.workspace_repo), event_bus_ref, command)
-            .await?;
+    let events = handle_workspace_command_zenoh(
+        Arc::clone(&state.workspace_repo),
+        event_bus_ref,
+        &state.reserved_workspace_names,
+        &state.workspace_administrators,
+        command,
+        correlation,
+    )
+    .await?;
 
     Ok((
         StatusCode::ACCEPTED,
@@ -472,6 +646,7 @@ pub async fn set_visibility(
 #[instrument(name = "handler.dashboard.create", skip(state, request), fields(workspace_id = %workspace_id))]
 pub async fn create_dashboard(
     State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
     Path(workspace_id): Path<Uuid>,
     Json(request): Json<CreateDashboardRequest>,
 ) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
@@ -480,7 +655,8 @@ pub async fn create_dashboard(
         dashboard_id,
         workspace_id: WorkspaceId::from_uuid(workspace_id),
         name: DashboardTitle::new(request.name)?,
-        created_at: Utc::now(),
+        actor,
+        created_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
@@ -501,6 +677,7 @@ pub async fn create_dashboard(
 #[instrument(name = "handler.dashboard.add_chart", skip(state, request), fields(dashboard_id = %dashboard_id))]
 pub async fn add_chart(
     State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
     Path((_workspace_id, dashboard_id)): Path<(Uuid, Uuid)>,
     Json(request): Json<AddChartRequest>,
 ) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
@@ -508,7 +685,8 @@ pub async fn add_chart(
     let command = DashboardCommand::AddChart {
         dashboard_id: db_id,
         placement: request.placement,
-        added_at: Utc::now(),
+        actor,
+        added_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
@@ -533,6 +711,7 @@ pub async fn add_chart(
 #[instrument(name = "handler.saved_query.save", skip(state, request), fields(workspace_id = %workspace_id))]
 pub async fn save_query(
     State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
     Path(workspace_id): Path<Uuid>,
     Json(request): Json<SaveQueryRequest>,
 ) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
@@ -545,9 +724,10 @@ pub async fn save_query(
         query_id,
         workspace_id: WorkspaceId::from_uuid(workspace_id),
         name: QueryName::new(request.name)?,
-        sql: SqlQuery::new(request.sql)?,
+        sql: SqlQuery::new_read_only(request.sql)?,
         dataset_ref,
-        saved_at: Utc::now(),
+        actor,
+        saved_at: state.clock.now(),
     };
 
     let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
@@ -567,55 +747,77 @@ pub async fn save_query(
     ))
 }
 
+/// POST /api/{id}/query/{query_id}/run - Record an execution of a saved query.
+///
+/// The actor must hold at least a viewer role in the query's workspace.
+#[instrument(name = "handler.saved_query.record_run", skip(state), fields(query_id = %query_id))]
+pub async fn record_query_run(
+    State(state): State<WorkspaceAppState>,
+    Actor(actor): Actor,
+    Path((_workspace_id, query_id)): Path<(Uuid, Uuid)>,
+) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
+    let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
+    let events = execute_saved_query(
+        Arc::clone(&state.saved_query_repo),
+        &state.workspace_repo,
+        event_bus_ref,
+        SavedQueryId::from_uuid(query_id),
+        actor,
+        state.clock.now(),
+    )
+    .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(CommandResponse {
+            id: query_id,
+            events_count: events.len(),
+        }),
+    ))
+}
+
 // =============================================================================
 // Workspace preferences command handlers
 // =============================================================================
 
+/// GET /api/{id}/preferences - Render the workspace preferences panel.
+#[instrument(name = "handler.workspace_preferences.get", skip(state), fields(workspace_id = %id))]
+pub async fn get_workspace_preferences(
+    State(state): State<WorkspaceAppState>,
+    Path(id): Path<Uuid>,
+    Actor(actor): Actor,
+) -> Result<Html<String>, AppError> {
+    let workspace_id = WorkspaceId::from_uuid(id);
+    let role = authorize_workspace(&state, &workspace_id, &actor, WorkspaceRole::Viewer).await?;
+    render_workspace_preferences(&state, &workspace_id, role).await
+}
+
 /// POST /api/{id}/preferences/catalog - Set default catalog for workspace.
 #[instrument(name = "handler.workspace_preferences.set_catalog", skip(state, request), fields(workspace_id = %id))]
 pub async fn set_default_catalog(
     State(state): State<WorkspaceAppState>,
     Path(id): Path<Uuid>,
+    Actor(actor): Actor,
     Json(request): Json<SetDefaultCatalogRequest>,
-) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
+) -> Result<Html<String>, AppError> {
     let workspace_id = WorkspaceId::from_uuid(id);
-
-    // Ensure workspace preferences are initialized first, then set catalog
-    let init_command = WorkspacePreferencesCommand::InitializeWorkspacePreferences {
-        workspace_id,
-        initialized_at: Utc::now(),
-    };
-    let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
-
-    // Initialize is idempotent — will return empty events if already initialized
-    let _ = handle_workspace_preferences_command_zenoh(
-        Arc::clone(&state.workspace_preferences_repo),
-        event_bus_ref,
-        init_command,
-    )
-    .await?;
+    let role = authorize_workspace(&state, &workspace_id, &actor, WorkspaceRole::Owner).await?;
+    initialize_workspace_preferences(&state, workspace_id).await?;
 
     let command = WorkspacePreferencesCommand::SetDefaultCatalog {
         workspace_id,
         catalog_uri: CatalogUri::new(request.catalog_uri)
             .map_err(|e| AppError::from(CommandPipelineError::from(e)))?,
-        set_at: Utc::now(),
+        set_at: state.clock.now(),
     };
-
-    let events = handle_workspace_preferences_command_zenoh(
+    handle_workspace_preferences_command_zenoh(
         Arc::clone(&state.workspace_preferences_repo),
-        event_bus_ref,
+        state.event_bus.as_deref(),
         command,
     )
     .await?;
 
-    Ok((
-        StatusCode::ACCEPTED,
-        Json(CommandResponse {
-            id,
-            events_count: events.len(),
-        }),
-    ))
+    render_workspace_preferences(&state, &workspace_id, role).await
 }
 
 /// POST /api/{id}/preferences/catalog/clear - Clear default catalog.
@@ -623,28 +825,138 @@ pub async fn set_default_catalog(
 pub async fn clear_default_catalog(
     State(state): State<WorkspaceAppState>,
     Path(id): Path<Uuid>,
-) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
+    Actor(actor): Actor,
+) -> Result<Html<String>, AppError> {
     let workspace_id = WorkspaceId::from_uuid(id);
+    let role = authorize_workspace(&state, &workspace_id, &actor, WorkspaceRole::Owner).await?;
+
     let command = WorkspacePreferencesCommand::ClearDefaultCatalog {
         workspace_id,
-        cleared_at: Utc::now(),
+        cleared_at: state.clock.now(),
     };
+    handle_workspace_preferences_command_zenoh(
+        Arc::clone(&state.workspace_preferences_repo),
+        state.event_bus.as_deref(),
+        command,
+    )
+    .await?;
 
-    let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
-    let events = handle_workspace_preferences_command_zenoh(
+    render_workspace_preferences(&state, &workspace_id, role).await
+}
+
+/// POST /api/{id}/preferences/layout - Update layout defaults.
+///
+/// The layout defaults must be a JSON object; the domain stores them as an
+/// opaque string and relies on this boundary for validity. The decider
+/// still range-checks `row_height_px` and `gap_px`.
+#[instrument(name = "handler.workspace_preferences.update_layout", skip(state, request), fields(workspace_id = %id))]
+pub async fn update_layout_defaults(
+    State(state): State<WorkspaceAppState>,
+    Path(id): Path<Uuid>,
+    Actor(actor): Actor,
+    Json(request): Json<UpdateLayoutDefaultsRequest>,
+) -> Result<Html<String>, AppError> {
+    if !request.layout_defaults.is_object() {
+        return Err(AppError::from(ValidationError::new(
+            ValidationErrorKind::InvalidFormat {
+                field: "layoutDefaults".to_string(),
+                expected: "a JSON object".to_string(),
+            },
+        )));
+    }
+
+    let workspace_id = WorkspaceId::from_uuid(id);
+    let role = authorize_workspace(&state, &workspace_id, &actor, WorkspaceRole::Owner).await?;
+    initialize_workspace_preferences(&state, workspace_id).await?;
+
+    let command = WorkspacePreferencesCommand::UpdateLayoutDefaults {
+        workspace_id,
+        layout_defaults: LayoutDefaults::new(request.layout_defaults.to_string()),
+        updated_at: state.clock.now(),
+    };
+    handle_workspace_preferences_command_zenoh(
         Arc::clone(&state.workspace_preferences_repo),
-        event_bus_ref,
+        state.event_bus.as_deref(),
         command,
     )
     .await?;
 
-    Ok((
-        StatusCode::ACCEPTED,
-        Json(CommandResponse {
-            id,
-            events_count: events.len(),
-        }),
-    ))
+    render_workspace_preferences(&state, &workspace_id, role).await
+}
+
+/// Check that `actor` holds at least `required` in the workspace.
+///
+/// Returns the actor's role. Anonymous requests are forbidden outright.
+/// Workspaces a signed-in actor has no access to are reported as not found,
+/// so private workspaces do not reveal that they exist. Nobody holds more
+/// than [`WorkspaceRole::Viewer`] in a suspended workspace, so changes to
+/// it are rejected as suspended rather than forbidden.
+async fn authorize_workspace(
+    state: &WorkspaceAppState,
+    workspace_id: &WorkspaceId,
+    actor: &UserId,
+    required: WorkspaceRole,
+) -> Result<WorkspaceRole, AppError> {
+    if actor.is_system() {
+        return Err(AppError::forbidden(format!(
+            "access workspace {workspace_id} without signing in"
+        )));
+    }
+    let workspaces = query_workspace_list::<WorkspaceCommand>(&state.workspace_repo).await?;
+    match workspaces.role_of(workspace_id, actor) {
+        None => Err(AppError::not_found("Workspace", workspace_id.to_string())),
+        Some(role) if role < required => match workspaces.suspension(workspace_id) {
+            Some(reason) => Err(AppError::from(CommandPipelineError::Workspace(
+                WorkspaceError::suspended(reason),
+            ))),
+            None => Err(AppError::forbidden(format!(
+                "change preferences of workspace {workspace_id}"
+            ))),
+        },
+        Some(role) => Ok(role),
+    }
+}
+
+/// Initialize a workspace's preferences if they do not exist yet.
+async fn initialize_workspace_preferences(
+    state: &WorkspaceAppState,
+    workspace_id: WorkspaceId,
+) -> Result<(), AppError> {
+    let preferences = query_workspace_preferences::<WorkspacePreferencesCommand>(
+        &state.workspace_preferences_repo,
+        &workspace_id,
+    )
+    .await?;
+    if preferences.initialized {
+        return Ok(());
+    }
+
+    let command = WorkspacePreferencesCommand::InitializeWorkspacePreferences {
+        workspace_id,
+        initialized_at: state.clock.now(),
+    };
+    handle_workspace_preferences_command_zenoh(
+        Arc::clone(&state.workspace_preferences_repo),
+        state.event_bus.as_deref(),
+        command,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Render the current preferences panel for `role`.
+async fn render_workspace_preferences(
+    state: &WorkspaceAppState,
+    workspace_id: &WorkspaceId,
+    role: WorkspaceRole,
+) -> Result<Html<String>, AppError> {
+    let preferences = query_workspace_preferences::<WorkspacePreferencesCommand>(
+        &state.workspace_preferences_repo,
+        workspace_id,
+    )
+    .await?;
+    let html = workspace_preferences_panel(workspace_id, &preferences, role).render();
+    Ok(Html(html.into_inner()))
 }
 
 // =============================================================================
@@ -667,7 +979,7 @@ pub async fn set_theme(
     let init_command = UserPreferencesCommand::InitializePreferences {
         preferences_id: prefs_id,
         user_id,
-        initialized_at: Utc::now(),
+        initialized_at: state.clock.now(),
     };
     let _ = handle_user_preferences_command_zenoh(
         Arc::clone(&state.user_preferences_repo),
@@ -679,7 +991,7 @@ pub async fn set_theme(
     let command = UserPreferencesCommand::SetTheme {
         user_id,
         theme: request.theme,
-        set_at: Utc::now(),
+        set_at: state.clock.now(),
     };
 
     let events = handle_user_preferences_command_zenoh(
@@ -712,7 +1024,7 @@ pub async fn set_locale(
     let init_command = UserPreferencesCommand::InitializePreferences {
         preferences_id: prefs_id,
         user_id,
-        initialized_at: Utc::now(),
+        initialized_at: state.clock.now(),
     };
     let _ = handle_user_preferences_command_zenoh(
         Arc::clone(&state.user_preferences_repo),
@@ -724,7 +1036,7 @@ pub async fn set_locale(
     let command = UserPreferencesCommand::SetLocale {
         user_id,
         locale: Locale::new(request.locale)?,
-        set_at: Utc::now(),
+        set_at: state.clock.now(),
     };
 
     let events = handle_user_preferences_command_zenoh(
@@ -743,10 +1055,79 @@ pub async fn set_locale(
     ))
 }
 
+/// POST /api/user/preferences/{user_id}/workspace - Set default workspace.
+#[instrument(
+    name = "handler.user_preferences.set_default_workspace",
+    skip(state, request),
+    fields(user_id = %user_id)
+)]
+pub async fn set_default_workspace(
+    State(state): State<WorkspaceAppState>,
+    Path(user_id): Path<Uuid>,
+    Json(request): Json<SetDefaultWorkspaceRequest>,
+) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
+    let command = UserPreferencesCommand::SetDefaultWorkspace {
+        user_id: UserId::from_uuid(user_id),
+        workspace_id: WorkspaceId::from_uuid(request.workspace_id),
+        set_at: state.clock.now(),
+    };
+
+    let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
+    let events = handle_user_preferences_command_zenoh(
+        Arc::clone(&state.user_preferences_repo),
+        event_bus_ref,
+        command,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(CommandResponse {
+            id: user_id,
+            events_count: events.len(),
+        }),
+    ))
+}
+
+/// POST /api/user/preferences/{user_id}/workspace/clear - Clear default workspace.
+#[instrument(
+    name = "handler.user_preferences.clear_default_workspace",
+    skip(state),
+    fields(user_id = %user_id)
+)]
+pub async fn clear_default_workspace(
+    State(state): State<WorkspaceAppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<CommandResponse>), AppError> {
+    let command = UserPreferencesCommand::ClearDefaultWorkspace {
+        user_id: UserId::from_uuid(user_id),
+        cleared_at: state.clock.now(),
+    };
+
+    let event_bus_ref: Option<&ZenohEventBus> = state.event_bus.as_deref();
+    let events = handle_user_preferences_command_zenoh(
+        Arc::clone(&state.user_preferences_repo),
+        event_bus_ref,
+        command,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(CommandResponse {
+            id: user_id,
+            events_count: events.len(),
+        }),
+    ))
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use crate::domain::clock::{FixedClock, SystemClock};
+    use crate::infrastructure::event_store::{Correlation, EVENTS_MIGRATION_SQL};
+    use crate::presentation::test_support::{sign_in, workspace_app_state};
     use axum::Router;
     use axum::body::Body;
     use axum::http::Request;
@@ -760,7 +1141,7 @@ mod tests {
             .await
             .expect("Failed to create test pool");
 
-        sqlx::query(include_str!("../../migrations/001_events.sql"))
+        sqlx::query(EVENTS_MIGRATION_SQL)
             .execute(&pool)
             .await
             .expect("Failed to run migration");
@@ -772,6 +1153,20 @@ mod tests {
         workspace_repo: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>,
         dashboard_repo: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
         saved_query_repo: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+    ) -> Router {
+        create_workspace_router_with_clock(
+            workspace_repo,
+            dashboard_repo,
+            saved_query_repo,
+            Arc::new(SystemClock),
+        )
+    }
+
+    fn create_workspace_router_with_clock(
+        workspace_repo: Arc<SqliteEventRepository<WorkspaceCommand, WorkspaceEvent>>,
+        dashboard_repo: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
+        saved_query_repo: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>>,
+        clock: Arc<dyn Clock>,
     ) -> Router {
         let state = WorkspaceAppState {
             workspace_repo,
@@ -790,6 +1185,10 @@ mod tests {
                     .expect("lazy pool"),
             )),
             event_bus: None,
+            clock,
+            reserved_workspace_names: Arc::new(ReservedWorkspaceNames::default()),
+            workspace_administrators: Arc::new(WorkspaceAdministrators::default()),
+            session_store: None,
         };
 
         Router::new()
@@ -837,6 +1236,51 @@ mod tests {
         assert_eq!(resp.events_count, 1);
     }
 
+    #[tokio::test]
+    async fn create_workspace_uses_injected_clock() {
+        let pool = create_test_pool().await;
+        let repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        let db_repo = Arc::new(SqliteEventRepository::new(pool.clone()));
+        let sq_repo = Arc::new(SqliteEventRepository::new(pool));
+        let fixed = chrono::DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .expect("valid timestamp")
+            .with_timezone(&Utc);
+        let app = create_workspace_router_with_clock(
+            Arc::clone(&repo),
+            db_repo,
+            sq_repo,
+            Arc::new(FixedClock::new(fixed)),
+        );
+
+        let body = serde_json::json!({
+            "name": "Clocked Workspace",
+            "ownerId": Uuid::new_v4().to_string(),
+            "visibility": "private"
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .expect("request should succeed");
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+        let stored = repo.query_all().await.expect("query events");
+        match stored.as_slice() {
+            [only] => match &only.event {
+                WorkspaceEvent::Created { created_at, .. } => assert_eq!(*created_at, fixed),
+                other => panic!("expected Created, got {other:?}"),
+            },
+            other => panic!("expected one event, got {}", other.len()),
+        }
+    }
+
     #[tokio::test]
     async fn rename_nonexistent_workspace_returns_error() {
         let pool = create_test_pool().await;
@@ -923,4 +1367,432 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::ACCEPTED);
     }
+
+    /// Router over one shared pool with every repository, and a session
+    /// cookie signed in as `user_id`.
+    async fn create_preferences_router(user_id: &UserId) -> (Router, String) {
+        let (router, state) = preferences_router().await;
+        let cookie = sign_in(&state, user_id).await;
+        (router, cookie)
+    }
+
+    /// Router over one shared pool with every repository, and its state for
+    /// signing in.
+    async fn preferences_router() -> (Router, WorkspaceAppState) {
+        let state = workspace_app_state().await;
+        let router = Router::new()
+            .route("/api", post(create_workspace))
+            .route("/api/{id}/preferences", get(get_workspace_preferences))
+            .route("/api/{id}/preferences/catalog", post(set_default_catalog))
+            .route("/api/{id}/preferences/layout", post(update_layout_defaults))
+            .route("/api/{id}/dashboard", post(create_dashboard))
+            .route(
+                "/api/{id}/dashboard/{dashboard_id}",
+                get(get_dashboard_layout),
+            )
+            .route(
+                "/api/{id}/dashboard/{dashboard_id}/grid",
+                get(get_dashboard_grid),
+            )
+            .route("/api/{id}/query", post(save_query))
+            .with_state(state.clone());
+        (router, state)
+    }
+
+    /// Create a private workspace owned by `owner`, returning its id.
+    async fn create_owned_workspace(app: &Router, cookie: &str, owner: &UserId) -> Uuid {
+        let (status, body) = send(
+            app,
+            Some(cookie),
+            "POST",
+            "/api",
+            Some(serde_json::json!({
+                "name": "Owned",
+                "ownerId": owner.to_string(),
+                "visibility": "private"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        serde_json::from_str::<CommandResponse>(&body)
+            .expect("valid JSON response")
+            .id
+    }
+
+    async fn send(
+        app: &Router,
+        cookie: Option<&str>,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, String) {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(cookie) = cookie {
+            request = request.header("cookie", cookie);
+        }
+        let body = match body {
+            Some(json) => {
+                request = request.header("content-type", "application/json");
+                Body::from(json.to_string())
+            }
+            None => Body::empty(),
+        };
+        let response = app
+            .clone()
+            .oneshot(request.body(body).unwrap())
+            .await
+            .expect("request should succeed");
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn set_default_catalog_renders_updated_preferences() {
+        let owner = UserId::new();
+        let (app, cookie) = create_preferences_router(&owner).await;
+        let (status, body) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            "/api",
+            Some(serde_json::json!({
+                "name": "Preferences",
+                "ownerId": owner.to_string(),
+                "visibility": "private"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        let created: CommandResponse = serde_json::from_str(&body).expect("valid JSON response");
+        let id = created.id;
+
+        let (status, body) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{id}/preferences"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(!body.contains("ducklake:space"));
+
+        let (status, body) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            &format!("/api/{id}/preferences/catalog"),
+            Some(serde_json::json!({ "catalogUri": "ducklake:space" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#"id="workspace-preferences""#));
+        assert!(body.contains("ducklake:space"));
+
+        let (status, body) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{id}/preferences"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("ducklake:space"));
+    }
+
+    #[tokio::test]
+    async fn layout_defaults_must_be_an_object() {
+        let owner = UserId::new();
+        let (app, cookie) = create_preferences_router(&owner).await;
+        let (_, body) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            "/api",
+            Some(serde_json::json!({
+                "name": "Layout",
+                "ownerId": owner.to_string(),
+                "visibility": "public"
+            })),
+        )
+        .await;
+        let id = serde_json::from_str::<CommandResponse>(&body)
+            .expect("valid JSON response")
+            .id;
+        let uri = format!("/api/{id}/preferences/layout");
+
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            &uri,
+            Some(serde_json::json!({ "layoutDefaults": [1] })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            &uri,
+            Some(serde_json::json!({ "layoutDefaults": { "columns": 3 } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("columns"));
+    }
+
+    #[tokio::test]
+    async fn layout_grid_spacing_is_range_checked_and_applied() {
+        let owner = UserId::new();
+        let (app, cookie) = create_preferences_router(&owner).await;
+        let (_, body) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            "/api",
+            Some(serde_json::json!({
+                "name": "Grid",
+                "ownerId": owner.to_string(),
+                "visibility": "public"
+            })),
+        )
+        .await;
+        let id = serde_json::from_str::<CommandResponse>(&body)
+            .expect("valid JSON response")
+            .id;
+        let uri = format!("/api/{id}/preferences/layout");
+
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            &uri,
+            Some(serde_json::json!({ "layoutDefaults": { "row_height_px": 10 } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            &uri,
+            Some(serde_json::json!({ "layoutDefaults": { "row_height_px": 120, "gap_px": 8 } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            &format!("/api/{id}/dashboard"),
+            Some(serde_json::json!({ "name": "Main" })),
+        )
+        .await;
+        let dashboard_id = serde_json::from_str::<CommandResponse>(&body)
+            .expect("valid JSON response")
+            .id;
+
+        let (status, body) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{id}/dashboard/{dashboard_id}"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let layout: serde_json::Value = serde_json::from_str(&body).expect("valid JSON response");
+        assert_eq!(
+            layout["gridStyle"],
+            "--grid-row-height: 120px; --grid-gap: 8px"
+        );
+
+        let (status, body) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{id}/dashboard/{dashboard_id}/grid"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#"class="grid" style="--grid-row-height: 120px; --grid-gap: 8px""#));
+    }
+
+    /// [`preferences_router`] with a workspace of `visibility` owned by a
+    /// new user, returning the workspace id.
+    async fn workspace_of_another_user(visibility: &str) -> (Router, WorkspaceAppState, Uuid) {
+        let (router, state) = preferences_router().await;
+        let owner = UserId::new();
+        let cookie = sign_in(&state, &owner).await;
+        let (_, body) = send(
+            &router,
+            Some(&cookie),
+            "POST",
+            "/api",
+            Some(serde_json::json!({
+                "name": "Shared",
+                "ownerId": owner.to_string(),
+                "visibility": visibility
+            })),
+        )
+        .await;
+        let id = serde_json::from_str::<CommandResponse>(&body)
+            .expect("valid JSON response")
+            .id;
+        (router, state, id)
+    }
+
+    #[tokio::test]
+    async fn non_member_may_view_but_not_change_public_workspace_preferences() {
+        let (app, state, id) = workspace_of_another_user("public").await;
+        let cookie = sign_in(&state, &UserId::new()).await;
+
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{id}/preferences"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "POST",
+            &format!("/api/{id}/preferences/catalog"),
+            Some(serde_json::json!({ "catalogUri": "ducklake:space" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn non_member_does_not_see_private_workspace_preferences() {
+        let (app, state, id) = workspace_of_another_user("private").await;
+        let cookie = sign_in(&state, &UserId::new()).await;
+
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{id}/preferences"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn anonymous_caller_is_forbidden_workspace_preferences() {
+        let (app, _, id) = workspace_of_another_user("public").await;
+
+        let (status, _) = send(&app, None, "GET", &format!("/api/{id}/preferences"), None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = send(
+            &app,
+            None,
+            "POST",
+            &format!("/api/{id}/preferences/layout"),
+            Some(serde_json::json!({ "layoutDefaults": { "columns": 3 } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn preferences_of_unknown_workspace_are_not_found() {
+        let owner = UserId::new();
+        let (app, cookie) = create_preferences_router(&owner).await;
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{}/preferences", Uuid::new_v4()),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn suspended_workspace_rejects_changes_but_stays_readable() {
+        let owner = UserId::new();
+        let (app, state) = preferences_router().await;
+        let cookie = sign_in(&state, &owner).await;
+        let id = create_owned_workspace(&app, &cookie, &owner).await;
+
+        let admin = UserId::new();
+        handle_workspace_command_zenoh(
+            Arc::clone(&state.workspace_repo),
+            None,
+            &state.reserved_workspace_names,
+            &WorkspaceAdministrators::new([admin]),
+            WorkspaceCommand::Suspend {
+                workspace_id: WorkspaceId::from_uuid(id),
+                reason: "pending review".to_string(),
+                actor: admin,
+                suspended_at: Utc::now(),
+            },
+            Correlation::new("suspend-request"),
+        )
+        .await
+        .expect("administrator may suspend");
+
+        let changes = [
+            ("dashboard", serde_json::json!({ "name": "Frozen" })),
+            (
+                "query",
+                serde_json::json!({ "name": "Frozen", "sql": "SELECT 1" }),
+            ),
+            (
+                "preferences/catalog",
+                serde_json::json!({ "catalogUri": "ducklake:frozen" }),
+            ),
+        ];
+        for (path, body) in changes {
+            let (status, body) = send(
+                &app,
+                Some(&cookie),
+                "POST",
+                &format!("/api/{id}/{path}"),
+                Some(body),
+            )
+            .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{path}: {body}");
+            assert!(body.contains("Suspended"), "{path}: {body}");
+        }
+
+        let (status, _) = send(
+            &app,
+            Some(&cookie),
+            "GET",
+            &format!("/api/{id}/preferences"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[test]
+    fn landing_path_prefers_default_workspace() {
+        let workspace_id = WorkspaceId::from_uuid(Uuid::nil());
+
+        assert_eq!(
+            landing_path(Some(workspace_id)),
+            format!("/workspace/api/{workspace_id}/queries")
+        );
+        assert_eq!(landing_path(None), "/workspace/api");
+    }
 }
@@ -0,0 +1,194 @@
+//! Workspace hypertext templates for Datastar-driven UI.
+//!
+//! The preferences panel is a self-contained fragment with a stable id, so
+//! the preferences handlers can return it after each change and Datastar
+//! morphs it in place. The dashboard grid lays out chart placements on the
+//! `.grid` composition, sized by the workspace layout defaults.
+
+use hypertext::prelude::*;
+
+use crate::domain::dashboard::values::ChartPlacement;
+use crate::domain::views::WorkspacePreferencesViewState;
+use crate::domain::workspace::values::{WorkspaceId, WorkspaceRole};
+use crate::presentation::components::{button, loading_spinner, text_field};
+
+/// Element id of the workspace preferences panel.
+pub const WORKSPACE_PREFERENCES_ID: &str = "workspace-preferences";
+
+/// Element id of the dashboard grid.
+pub const DASHBOARD_GRID_ID: &str = "dashboard-grid";
+
+/// Dashboard grid with one cell per chart placement.
+///
+/// `grid_style` carries the CSS custom properties the `.grid` composition
+/// reads for row height and gap; each cell spans its placement's size from
+/// its zero-indexed position.
+pub fn dashboard_grid(placements: &[ChartPlacement], grid_style: &str) -> impl Renderable {
+    let cells: Vec<(String, String)> = placements
+        .iter()
+        .map(|placement| {
+            (
+                placement.chart_id.to_string(),
+                format!(
+                    "grid-column: {} / span {}; grid-row: {} / span {}",
+                    placement.position.col + 1,
+                    placement.size.width(),
+                    placement.position.row + 1,
+                    placement.size.height()
+                ),
+            )
+        })
+        .collect();
+
+    maud! {
+        div id=(DASHBOARD_GRID_ID) class="grid" style=(grid_style) {
+            @for (chart_id, cell_style) in &cells {
+                div class="card" data-chart-id=(chart_id) style=(cell_style) {}
+            }
+        }
+    }
+}
+
+/// Workspace preferences panel.
+///
+/// Owners get controls to change the default catalog; viewers see the
+/// current values only.
+pub fn workspace_preferences_panel(
+    workspace_id: &WorkspaceId,
+    preferences: &WorkspacePreferencesViewState,
+    role: WorkspaceRole,
+) -> impl Renderable {
+    let base = format!("/workspace/api/{workspace_id}/preferences");
+    let default_catalog = preferences
+        .default_catalog
+        .as_ref()
+        .map(|c| c.as_str().to_string());
+    let layout_defaults = preferences
+        .initialized
+        .then(|| preferences.layout_defaults.as_str().to_string());
+    let editable = role == WorkspaceRole::Owner;
+
+    let set_attrs = format!(
+        r#"type="submit" data-on:click="@post('{base}/catalog', {{payload: {{catalogUri: $catalogUri}}}})" data-indicator="savingPreferences""#
+    );
+    let clear_attrs = format!(
+        r#"data-on:click="@post('{base}/catalog/clear')" data-indicator="savingPreferences""#
+    );
+
+    maud! {
+        section id=(WORKSPACE_PREFERENCES_ID) class="card stack" {
+            h2 { "Workspace preferences" }
+
+            dl class="stack" {
+                dt { "Default catalog" }
+                dd class="default-catalog" {
+                    @if let Some(catalog) = &default_catalog {
+                        code { (catalog) }
+                    } @else {
+                        span class="text-2" { "None" }
+                    }
+                }
+
+                dt { "Layout defaults" }
+                dd class="layout-defaults" {
+                    @if let Some(layout) = &layout_defaults {
+                        code { (layout) }
+                    } @else {
+                        span class="text-2" { "Not initialized" }
+                    }
+                }
+            }
+
+            @if editable {
+                div class="cluster" {
+                    (text_field(
+                        "catalogUri",
+                        "Catalog URI",
+                        " ",
+                        "outlined",
+                        r#"data-bind:catalog-uri"#
+                    ))
+                    (button("Set default", "filled", None, &set_attrs))
+                    @if default_catalog.is_some() {
+                        (button("Clear", "outlined", None, &clear_attrs))
+                    }
+                    (loading_spinner("savingPreferences"))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::domain::workspace_preferences::values::{CatalogUri, LayoutDefaults};
+
+    fn initialized(catalog: Option<&str>) -> WorkspacePreferencesViewState {
+        WorkspacePreferencesViewState {
+            workspace_id: Some(WorkspaceId::new()),
+            default_catalog: catalog.map(|c| CatalogUri::new(c).expect("valid uri")),
+            layout_defaults: LayoutDefaults::default(),
+            updated_at: None,
+            initialized: true,
+        }
+    }
+
+    #[test]
+    fn dashboard_grid_applies_grid_style_and_placement_spans() {
+        use crate::domain::GridSize;
+        use crate::domain::dashboard::values::{
+            ChartDefinitionRef, ChartId, ChartSource, GridPosition,
+        };
+
+        let placement = ChartPlacement {
+            chart_id: ChartId::new(),
+            source: ChartSource::Reference(ChartDefinitionRef {
+                ref_id: "chart-1".to_string(),
+                chart_type_hint: None,
+            }),
+            position: GridPosition { row: 1, col: 2 },
+            size: GridSize::new(4, 3).expect("valid size"),
+            tab_id: None,
+        };
+        let html = dashboard_grid(
+            std::slice::from_ref(&placement),
+            "--grid-row-height: 120px; --grid-gap: 8px",
+        )
+        .render();
+        let body = html.as_inner();
+
+        assert!(body.contains(
+            r#"id="dashboard-grid" class="grid" style="--grid-row-height: 120px; --grid-gap: 8px""#
+        ));
+        assert!(body.contains(&format!(r#"data-chart-id="{}""#, placement.chart_id)));
+        assert!(body.contains(r#"style="grid-column: 3 / span 4; grid-row: 2 / span 3""#));
+    }
+
+    #[test]
+    fn viewer_sees_values_without_controls() {
+        let prefs = initialized(Some("ducklake:space"));
+        let html = workspace_preferences_panel(&WorkspaceId::new(), &prefs, WorkspaceRole::Viewer)
+            .render();
+        let body = html.as_inner();
+
+        assert!(body.contains(r#"id="workspace-preferences""#));
+        assert!(body.contains("ducklake:space"));
+        assert!(!body.contains("@post("));
+    }
+
+    #[test]
+    fn owner_gets_controls_posting_to_workspace() {
+        let workspace_id = WorkspaceId::new();
+        let html =
+            workspace_preferences_panel(&workspace_id, &initialized(None), WorkspaceRole::Owner)
+                .render();
+        let body = html.as_inner();
+
+        assert!(body.contains(&format!(
+            "/workspace/api/{workspace_id}/preferences/catalog"
+        )));
+        assert!(!body.contains("catalog/clear"));
+    }
+}
@@ -25,6 +25,8 @@
 //! }
 //! ```
 
+use crate::application::{ReservedWorkspaceNames, WorkspaceAdministrators};
+use crate::domain::clock::{Clock, SystemClock};
 use crate::domain::dashboard::{DashboardCommand, DashboardEvent};
 use crate::domain::saved_query::{SavedQueryCommand, SavedQueryEvent};
 use crate::domain::todo::commands::TodoCommand;
@@ -35,11 +37,13 @@ use crate::domain::workspace_preferences::{
     WorkspacePreferencesCommand, WorkspacePreferencesEvent,
 };
 use crate::domain::{CatalogCommand, CatalogEvent, QuerySessionCommand, QuerySessionEvent};
+use crate::infrastructure::event_store::PayloadCompression;
 use crate::infrastructure::{
     AnalyticsState, AssetManifest, CachedAnalyticsService, DuckDBService, SqliteEventRepository,
     SqliteSessionStore, ZenohEventBus,
 };
 use crate::presentation::analytics::AnalyticsAppState;
+use crate::presentation::body_limit::RequestBodyLimits;
 use crate::presentation::health::HealthState;
 use crate::presentation::metrics::MetricsState;
 use crate::presentation::todo::TodoAppState;
@@ -98,6 +102,25 @@ pub struct AppState {
     /// Used by the `/metrics` endpoint to render accumulated metrics on demand.
     pub prometheus_handle: PrometheusHandle,
 
+    /// Bearer token required by the `/metrics` endpoint.
+    ///
+    /// When `None`, every scrape is rejected.
+    pub metrics_token: Option<Arc<str>>,
+
+    /// Clock used by handlers to timestamp commands.
+    ///
+    /// Defaults to the system clock; tests inject a fixed clock.
+    pub clock: Arc<dyn Clock>,
+
+    /// Workspace names rejected on create and rename.
+    pub reserved_workspace_names: Arc<ReservedWorkspaceNames>,
+
+    /// Users who may suspend and unsuspend workspaces.
+    pub workspace_administrators: Arc<WorkspaceAdministrators>,
+
+    /// Maximum request body sizes enforced by `app_router`.
+    pub request_body_limits: RequestBodyLimits,
+
     /// Shared Todo event repository.
     ///
     /// Cached here to avoid recreating for each request.
@@ -154,6 +177,11 @@ impl AppState {
             analytics: None,
             cached_analytics: None,
             prometheus_handle,
+            metrics_token: None,
+            clock: Arc::new(SystemClock),
+            reserved_workspace_names: Arc::new(ReservedWorkspaceNames::default()),
+            workspace_administrators: Arc::new(WorkspaceAdministrators::default()),
+            request_body_limits: RequestBodyLimits::default(),
             todo_repo,
             catalog_repo,
             query_session_repo,
@@ -165,6 +193,32 @@ impl AppState {
         }
     }
 
+    /// Store large event payloads compressed according to `compression`.
+    ///
+    /// Rebuilds the event repositories over the same pool; reads decompress
+    /// flagged rows either way.
+    #[must_use]
+    pub fn with_event_compression(mut self, compression: PayloadCompression) -> Self {
+        let pool = &self.db_pool;
+        self.todo_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self.catalog_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self.query_session_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self.workspace_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self.dashboard_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self.saved_query_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self.user_preferences_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self.workspace_preferences_repo =
+            Arc::new(SqliteEventRepository::new(pool.clone()).with_compression(compression));
+        self
+    }
+
     /// Set the Zenoh event bus.
     #[must_use]
     pub fn with_event_bus(mut self, event_bus: Arc<ZenohEventBus>) -> Self {
@@ -193,6 +247,44 @@ impl AppState {
         self
     }
 
+    /// Set the clock used to timestamp commands.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set the workspace names rejected on create and rename.
+    #[must_use]
+    pub fn with_reserved_workspace_names(mut self, names: ReservedWorkspaceNames) -> Self {
+        self.reserved_workspace_names = Arc::new(names);
+        self
+    }
+
+    /// Set the users who may suspend and unsuspend workspaces.
+    #[must_use]
+    pub fn with_workspace_administrators(
+        mut self,
+        administrators: WorkspaceAdministrators,
+    ) -> Self {
+        self.workspace_administrators = Arc::new(administrators);
+        self
+    }
+
+    /// Set the maximum request body sizes.
+    #[must_use]
+    pub fn with_request_body_limits(mut self, limits: RequestBodyLimits) -> Self {
+        self.request_body_limits = limits;
+        self
+    }
+
+    /// Set the bearer token required by `/metrics`.
+    #[must_use]
+    pub fn with_metrics_token(mut self, token: impl Into<Arc<str>>) -> Self {
+        self.metrics_token = Some(token.into());
+        self
+    }
+
     /// Check if the event bus is available.
     #[must_use]
     pub fn has_event_bus(&self) -> bool {
@@ -221,6 +313,7 @@ impl FromRef<AppState> for TodoAppState {
         Self {
             repo: Arc::clone(&app_state.todo_repo),
             event_bus: app_state.event_bus.clone(),
+            clock: Arc::clone(&app_state.clock),
         }
     }
 }
@@ -231,6 +324,7 @@ impl FromRef<AppState> for AnalyticsAppState {
             catalog_repo: Arc::clone(&app_state.catalog_repo),
             query_session_repo: Arc::clone(&app_state.query_session_repo),
             event_bus: app_state.event_bus.clone(),
+            clock: Arc::clone(&app_state.clock),
         }
     }
 }
@@ -244,6 +338,10 @@ impl FromRef<AppState> for WorkspaceAppState {
             user_preferences_repo: Arc::clone(&app_state.user_preferences_repo),
             workspace_preferences_repo: Arc::clone(&app_state.workspace_preferences_repo),
             event_bus: app_state.event_bus.clone(),
+            clock: Arc::clone(&app_state.clock),
+            reserved_workspace_names: Arc::clone(&app_state.reserved_workspace_names),
+            workspace_administrators: Arc::clone(&app_state.workspace_administrators),
+            session_store: app_state.session_store.clone(),
         }
     }
 }
@@ -260,6 +358,7 @@ impl FromRef<AppState> for MetricsState {
     fn from_ref(app_state: &AppState) -> Self {
         Self {
             prometheus_handle: app_state.prometheus_handle.clone(),
+            token: app_state.metrics_token.clone(),
         }
     }
 }
@@ -314,6 +413,43 @@ mod tests {
         assert!(!state.has_analytics());
     }
 
+    #[tokio::test]
+    async fn event_compression_applies_to_repositories() {
+        use crate::application::todo::handle_todo_command_zenoh;
+        use crate::domain::todo::values::TodoId;
+        use crate::infrastructure::event_store::EVENTS_MIGRATION_SQL;
+
+        let pool = create_test_pool().await;
+        sqlx::query(EVENTS_MIGRATION_SQL)
+            .execute(&pool)
+            .await
+            .expect("migration");
+        let state = AppState::new(
+            pool.clone(),
+            AssetManifest::default(),
+            test_prometheus_handle(),
+        )
+        .with_event_compression(PayloadCompression::with_threshold(1));
+
+        handle_todo_command_zenoh(
+            Arc::clone(&state.todo_repo),
+            None::<&ZenohEventBus>,
+            TodoCommand::Create {
+                id: TodoId::new(),
+                text: "compressed".to_string(),
+                created_at: chrono::Utc::now(),
+            },
+        )
+        .await
+        .expect("create todo");
+
+        let compressed: i64 = sqlx::query_scalar("SELECT compressed FROM events")
+            .fetch_one(&pool)
+            .await
+            .expect("event row");
+        assert_eq!(compressed, 1);
+    }
+
     #[tokio::test]
     async fn from_ref_todo_app_state() {
         let pool = create_test_pool().await;
@@ -19,14 +19,8 @@ use ironstar::presentation::chart_transformer::{
 fn bar_chart_produces_valid_echarts_structure() {
     let result = QueryResult {
         columns: vec![
-            ColumnMetadata {
-                name: "nationality".to_string(),
-                data_type: "VARCHAR".to_string(),
-            },
-            ColumnMetadata {
-                name: "count".to_string(),
-                data_type: "BIGINT".to_string(),
-            },
+            ColumnMetadata::new("nationality", "VARCHAR"),
+            ColumnMetadata::new("count", "BIGINT"),
         ],
         rows: vec![
             vec![serde_json::json!("USA"), serde_json::json!(337)],
@@ -162,14 +156,8 @@ async fn e2e_huggingface_astronauts_chart() {
 
             Ok(QueryResult::new(
                 vec![
-                    ColumnMetadata {
-                        name: "nationality".to_string(),
-                        data_type: "VARCHAR".to_string(),
-                    },
-                    ColumnMetadata {
-                        name: "count".to_string(),
-                        data_type: "BIGINT".to_string(),
-                    },
+                    ColumnMetadata::new("nationality", "VARCHAR"),
+                    ColumnMetadata::new("count", "BIGINT"),
                 ],
                 rows,
             ))
@@ -0,0 +1,224 @@
+//! Integration tests for pinning a saved query's chart to a dashboard.
+//!
+//! These tests drive `add_query_result_to_dashboard` against a SQLite event
+//! store and read the placements back by replaying the dashboard stream.
+
+#![expect(
+    clippy::expect_used,
+    reason = "test file with standard test assertions"
+)]
+
+use chrono::Utc;
+use fmodel_rust::Identifier;
+use ironstar::application::dashboard::{add_query_result_to_dashboard, handle_dashboard_command};
+use ironstar::application::saved_query::handle_saved_query_command;
+use ironstar::domain::UserId;
+use ironstar::domain::analytics::{ChartType, DatasetRef, SqlQuery};
+use ironstar::domain::common::DashboardTitle;
+use ironstar::domain::dashboard::{
+    ChartDefinitionRef, ChartSource, DashboardCommand, DashboardEvent, DashboardId, DashboardState,
+    GridPosition, dashboard_decider,
+};
+use ironstar::domain::saved_query::{QueryName, SavedQueryCommand, SavedQueryEvent, SavedQueryId};
+use ironstar::domain::workspace::WorkspaceId;
+use ironstar::infrastructure::EVENTS_MIGRATION_SQL;
+use ironstar::infrastructure::ZenohEventBus;
+use ironstar::infrastructure::event_store::SqliteEventRepository;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+
+const NO_EVENT_BUS: Option<&ZenohEventBus> = None;
+
+/// Create an in-memory SQLite pool with event store migrations applied.
+async fn create_test_pool() -> sqlx::SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to create test pool");
+
+    sqlx::query(EVENTS_MIGRATION_SQL)
+        .execute(&pool)
+        .await
+        .expect("Failed to run migration");
+
+    pool
+}
+
+struct Fixture {
+    dashboards: Arc<SqliteEventRepository<DashboardCommand, DashboardEvent>>,
+    queries: SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>,
+    dashboard_id: DashboardId,
+    query_id: SavedQueryId,
+}
+
+/// Create a dashboard and a saved query named "Daily totals", each in its
+/// own workspace unless `same_workspace`.
+async fn fixture(same_workspace: bool) -> Fixture {
+    let pool = create_test_pool().await;
+    let dashboards = Arc::new(SqliteEventRepository::new(pool.clone()));
+    let queries = Arc::new(SqliteEventRepository::new(pool.clone()));
+    let workspace_id = WorkspaceId::new();
+    let dashboard_id = DashboardId::new();
+    let query_id = SavedQueryId::new();
+
+    handle_dashboard_command(
+        Arc::clone(&dashboards),
+        NO_EVENT_BUS,
+        DashboardCommand::CreateDashboard {
+            dashboard_id,
+            workspace_id,
+            name: DashboardTitle::new("Overview").expect("valid title"),
+            actor: UserId::system(),
+            created_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("create dashboard");
+
+    handle_saved_query_command(
+        queries,
+        NO_EVENT_BUS,
+        SavedQueryCommand::SaveQuery {
+            query_id,
+            workspace_id: if same_workspace {
+                workspace_id
+            } else {
+                WorkspaceId::new()
+            },
+            name: QueryName::new("Daily totals").expect("valid name"),
+            sql: SqlQuery::new("SELECT day, sum(n) AS total FROM t GROUP BY day")
+                .expect("valid sql"),
+            dataset_ref: DatasetRef::new("hf://test/dataset").expect("valid ref"),
+            actor: UserId::system(),
+            saved_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("save query");
+
+    Fixture {
+        dashboards,
+        queries: SqliteEventRepository::new(pool),
+        dashboard_id,
+        query_id,
+    }
+}
+
+async fn dashboard_state(fixture: &Fixture) -> DashboardState {
+    let decider = dashboard_decider();
+    fixture
+        .dashboards
+        .fetch_events_by_aggregate("Dashboard", &fixture.dashboard_id.identifier())
+        .await
+        .expect("load dashboard")
+        .iter()
+        .fold((decider.initial_state)(), |state, (event, _version)| {
+            (decider.evolve)(&state, event)
+        })
+}
+
+fn reference(source: &ChartSource) -> Option<&ChartDefinitionRef> {
+    match source {
+        ChartSource::Reference(def_ref) => Some(def_ref),
+        ChartSource::Inline(_) => None,
+    }
+}
+
+#[tokio::test]
+async fn saved_query_chart_is_pinned_to_dashboard() {
+    let fixture = fixture(true).await;
+
+    let first = add_query_result_to_dashboard(
+        Arc::clone(&fixture.dashboards),
+        &fixture.queries,
+        NO_EVENT_BUS,
+        fixture.dashboard_id,
+        fixture.query_id,
+        ChartType::Line,
+        None,
+        UserId::system(),
+    )
+    .await
+    .expect("pin chart");
+    let second = add_query_result_to_dashboard(
+        Arc::clone(&fixture.dashboards),
+        &fixture.queries,
+        NO_EVENT_BUS,
+        fixture.dashboard_id,
+        fixture.query_id,
+        ChartType::Bar,
+        None,
+        UserId::system(),
+    )
+    .await
+    .expect("pin second chart");
+
+    let state = dashboard_state(&fixture).await;
+    let first = state.chart_placement(&first).expect("first chart placed");
+    let second = state.chart_placement(&second).expect("second chart placed");
+
+    let def_ref = reference(&first.source).expect("chart definition reference");
+    assert_eq!(def_ref.ref_id, fixture.query_id.to_string());
+    assert_eq!(def_ref.chart_type_hint, Some(ChartType::Line));
+    assert_eq!(first.position, GridPosition { row: 0, col: 0 });
+    assert_eq!(first.tab_id, None);
+
+    // Auto-placed below the first chart, with its own chart type.
+    assert_eq!(
+        second.position,
+        GridPosition {
+            row: first.size.height(),
+            col: 0
+        }
+    );
+    assert_eq!(second.source.chart_type(), Some(ChartType::Bar));
+    assert_eq!(
+        reference(&second.source).map(|def_ref| def_ref.ref_id.as_str()),
+        Some(fixture.query_id.to_string().as_str())
+    );
+}
+
+#[tokio::test]
+async fn explicit_position_is_kept() {
+    let fixture = fixture(true).await;
+    let position = GridPosition { row: 2, col: 6 };
+
+    let chart_id = add_query_result_to_dashboard(
+        Arc::clone(&fixture.dashboards),
+        &fixture.queries,
+        NO_EVENT_BUS,
+        fixture.dashboard_id,
+        fixture.query_id,
+        ChartType::Line,
+        Some(position),
+        UserId::system(),
+    )
+    .await
+    .expect("pin chart");
+
+    let state = dashboard_state(&fixture).await;
+    let placement = state.chart_placement(&chart_id).expect("chart placed");
+    assert_eq!(placement.position, position);
+}
+
+#[tokio::test]
+async fn query_from_another_workspace_is_refused() {
+    let fixture = fixture(false).await;
+
+    let result = add_query_result_to_dashboard(
+        Arc::clone(&fixture.dashboards),
+        &fixture.queries,
+        NO_EVENT_BUS,
+        fixture.dashboard_id,
+        fixture.query_id,
+        ChartType::Line,
+        None,
+        UserId::system(),
+    )
+    .await;
+
+    assert!(result.is_err());
+    let state = dashboard_state(&fixture).await;
+    assert_eq!(state.placements().map(<[_]>::len), Some(0));
+}
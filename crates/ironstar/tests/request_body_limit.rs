@@ -0,0 +1,86 @@
+//! Integration tests for request body size limits in `app_router`.
+//!
+//! These tests verify that:
+//! - Bodies over the default limit are rejected with 413
+//! - Analytics routes accept bodies up to their higher limit
+//! - Limits are configurable through `AppState`
+
+#![expect(
+    clippy::expect_used,
+    reason = "test file with standard test assertions"
+)]
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Request, StatusCode, header};
+use ironstar::infrastructure::{AssetManifest, test_prometheus_handle};
+use ironstar::presentation::{DEFAULT_REQUEST_BODY_LIMIT, RequestBodyLimits, app_router};
+use ironstar::state::AppState;
+use sqlx::sqlite::SqlitePoolOptions;
+use tower::ServiceExt;
+
+async fn create_test_state() -> AppState {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to create test pool");
+
+    AppState::new(pool, AssetManifest::default(), test_prometheus_handle())
+}
+
+/// POST a JSON-typed body of `len` bytes to `uri`.
+async fn post_body(app: Router, uri: &str, len: usize) -> StatusCode {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::CONTENT_LENGTH, len)
+        .body(Body::from(vec![b' '; len]))
+        .expect("test request");
+
+    app.oneshot(request)
+        .await
+        .expect("request should succeed")
+        .status()
+}
+
+#[tokio::test]
+async fn over_limit_body_is_rejected_with_413() {
+    let app = app_router(create_test_state().await);
+
+    let status = post_body(app, "/workspace/api", DEFAULT_REQUEST_BODY_LIMIT + 1).await;
+
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn analytics_routes_accept_larger_bodies() {
+    let app = app_router(create_test_state().await);
+
+    let status = post_body(
+        app,
+        "/analytics/api/queries",
+        DEFAULT_REQUEST_BODY_LIMIT + 1,
+    )
+    .await;
+
+    assert_ne!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn configured_limit_replaces_default() {
+    let state = create_test_state()
+        .await
+        .with_request_body_limits(RequestBodyLimits {
+            default: 16,
+            analytics: 16,
+        });
+    let app = app_router(state);
+
+    let status = post_body(app.clone(), "/analytics/api/queries", 17).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+    let status = post_body(app, "/workspace/api", 16).await;
+    assert_ne!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
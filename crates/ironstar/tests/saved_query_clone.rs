@@ -0,0 +1,136 @@
+//! Integration tests for cloning saved queries.
+//!
+//! These tests drive `clone_saved_query` against a SQLite event store and
+//! read the results back through the saved query list view.
+
+#![expect(
+    clippy::expect_used,
+    reason = "test file with standard test assertions"
+)]
+
+use chrono::Utc;
+use ironstar::application::saved_query::{clone_saved_query, handle_saved_query_command};
+use ironstar::application::workspace::query_saved_query_list;
+use ironstar::domain::UserId;
+use ironstar::domain::analytics::{DatasetRef, SqlQuery};
+use ironstar::domain::clock::SystemClock;
+use ironstar::domain::saved_query::{QueryName, SavedQueryCommand, SavedQueryEvent, SavedQueryId};
+use ironstar::domain::workspace::WorkspaceId;
+use ironstar::infrastructure::EVENTS_MIGRATION_SQL;
+use ironstar::infrastructure::ZenohEventBus;
+use ironstar::infrastructure::event_store::SqliteEventRepository;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+
+const NO_EVENT_BUS: Option<&ZenohEventBus> = None;
+
+/// Create an in-memory SQLite pool with event store migrations applied.
+async fn create_test_pool() -> sqlx::SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to create test pool");
+
+    sqlx::query(EVENTS_MIGRATION_SQL)
+        .execute(&pool)
+        .await
+        .expect("Failed to run migration");
+
+    pool
+}
+
+fn query_name(name: &str) -> QueryName {
+    QueryName::try_from(name.to_string()).expect("valid name")
+}
+
+#[tokio::test]
+async fn cloned_query_is_independent_copy() {
+    let pool = create_test_pool().await;
+    let repo: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>> =
+        Arc::new(SqliteEventRepository::new(pool));
+    let workspace_id = WorkspaceId::new();
+    let source_id = SavedQueryId::new();
+
+    handle_saved_query_command(
+        Arc::clone(&repo),
+        NO_EVENT_BUS,
+        SavedQueryCommand::SaveQuery {
+            query_id: source_id,
+            workspace_id,
+            name: query_name("Daily totals"),
+            sql: SqlQuery::try_from("SELECT day, sum(n) FROM t GROUP BY day".to_string())
+                .expect("valid sql"),
+            dataset_ref: DatasetRef::try_from("hf://test/dataset".to_string()).expect("valid ref"),
+            actor: UserId::system(),
+            saved_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("save should succeed");
+
+    let copy_id = clone_saved_query(
+        Arc::clone(&repo),
+        NO_EVENT_BUS,
+        source_id,
+        query_name("Daily totals (copy)"),
+        UserId::system(),
+        &SystemClock,
+    )
+    .await
+    .expect("clone should succeed");
+    assert_ne!(copy_id, source_id);
+
+    handle_saved_query_command(
+        Arc::clone(&repo),
+        NO_EVENT_BUS,
+        SavedQueryCommand::UpdateQuerySql {
+            query_id: copy_id,
+            sql: SqlQuery::try_from("SELECT 1".to_string()).expect("valid sql"),
+            actor: UserId::system(),
+            updated_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("editing the copy should succeed");
+
+    let list = query_saved_query_list(&repo).await.expect("list queries");
+    let queries = list.queries_for_workspace(&workspace_id);
+    assert_eq!(queries.len(), 2);
+
+    let source = queries
+        .iter()
+        .find(|q| q.query_id == source_id)
+        .expect("source still listed");
+    let copy = queries
+        .iter()
+        .find(|q| q.query_id == copy_id)
+        .expect("copy listed");
+    assert_eq!(source.name.as_str(), "Daily totals");
+    assert_eq!(copy.name.as_str(), "Daily totals (copy)");
+    assert_eq!(copy.dataset_ref, source.dataset_ref);
+    assert_eq!(
+        source.sql.as_str(),
+        "SELECT day, sum(n) FROM t GROUP BY day"
+    );
+    assert_eq!(copy.sql.as_str(), "SELECT 1");
+}
+
+#[tokio::test]
+async fn cloning_missing_query_fails() {
+    let pool = create_test_pool().await;
+    let repo: Arc<SqliteEventRepository<SavedQueryCommand, SavedQueryEvent>> =
+        Arc::new(SqliteEventRepository::new(pool));
+
+    let result = clone_saved_query(
+        repo,
+        NO_EVENT_BUS,
+        SavedQueryId::new(),
+        query_name("Copy"),
+        UserId::system(),
+        &SystemClock,
+    )
+    .await;
+
+    assert!(result.is_err());
+}
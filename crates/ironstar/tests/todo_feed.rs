@@ -20,6 +20,7 @@ use ironstar::application::todo::handle_todo_command;
 use ironstar::domain::todo::commands::TodoCommand;
 use ironstar::domain::todo::events::TodoEvent;
 use ironstar::domain::todo::values::TodoId;
+use ironstar::infrastructure::EVENTS_MIGRATION_SQL;
 use ironstar::infrastructure::event_store::SqliteEventRepository;
 use ironstar::infrastructure::{
     AssetManifest, ZenohEventBus, open_embedded_session, test_prometheus_handle,
@@ -39,7 +40,7 @@ async fn create_test_pool() -> sqlx::SqlitePool {
         .await
         .expect("Failed to create test pool");
 
-    sqlx::query(include_str!("../migrations/001_events.sql"))
+    sqlx::query(EVENTS_MIGRATION_SQL)
         .execute(&pool)
         .await
         .expect("Failed to run migration");
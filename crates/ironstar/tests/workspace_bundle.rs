@@ -0,0 +1,346 @@
+//! Integration tests for workspace export and import bundles.
+//!
+//! These tests build a workspace in one SQLite event store, export it,
+//! pass the bundle through JSON, and import it into a fresh store.
+
+#![expect(
+    clippy::expect_used,
+    clippy::panic,
+    reason = "test file with standard test assertions"
+)]
+
+use chrono::Utc;
+use fmodel_rust::Identifier;
+use ironstar::application::CommandPipelineError;
+use ironstar::application::dashboard::handle_dashboard_command;
+use ironstar::application::saved_query::handle_saved_query_command;
+use ironstar::application::workspace::{
+    ReservedWorkspaceNames, WorkspaceAdministrators, WorkspaceBundle, WorkspaceRepositories,
+    export_workspace_bundle, handle_workspace_command, import_workspace_bundle,
+};
+use ironstar::domain::UserId;
+use ironstar::domain::analytics::{ChartConfig, ChartType, DatasetRef, SqlQuery};
+use ironstar::domain::clock::SystemClock;
+use ironstar::domain::common::{DashboardTitle, GridSize, TabTitle};
+use ironstar::domain::dashboard::{
+    ChartDefinitionRef, ChartId, ChartPlacement, ChartSource, DashboardCommand, DashboardId,
+    GridPosition, TabId, TabInfo,
+};
+use ironstar::domain::saved_query::{QueryName, SavedQueryCommand, SavedQueryId};
+use ironstar::domain::workspace::{Visibility, WorkspaceCommand, WorkspaceId};
+use ironstar::infrastructure::EVENTS_MIGRATION_SQL;
+use ironstar::infrastructure::ZenohEventBus;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+
+const NO_EVENT_BUS: Option<&ZenohEventBus> = None;
+
+/// Create an in-memory SQLite pool with event store migrations applied.
+async fn create_test_pool() -> sqlx::SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to create test pool");
+
+    sqlx::query(EVENTS_MIGRATION_SQL)
+        .execute(&pool)
+        .await
+        .expect("Failed to run migration");
+
+    pool
+}
+
+/// Build a workspace holding one saved query and one dashboard with a tab,
+/// a chart on that tab referencing the query, and an inline chart.
+async fn seed_workspace(repos: &WorkspaceRepositories) -> (WorkspaceId, SavedQueryId) {
+    let owner = UserId::new();
+    let workspace_id = WorkspaceId::new();
+    handle_workspace_command(
+        Arc::clone(&repos.workspaces),
+        NO_EVENT_BUS,
+        &ReservedWorkspaceNames::default(),
+        &WorkspaceAdministrators::default(),
+        WorkspaceCommand::Create {
+            workspace_id,
+            name: "Research".to_string(),
+            owner_id: owner,
+            visibility: Visibility::Public,
+            actor: owner,
+            created_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("create workspace");
+
+    let query_id = SavedQueryId::new();
+    handle_saved_query_command(
+        Arc::clone(&repos.saved_queries),
+        NO_EVENT_BUS,
+        SavedQueryCommand::SaveQuery {
+            query_id,
+            workspace_id,
+            name: QueryName::new("Daily totals").expect("valid name"),
+            sql: SqlQuery::new("SELECT day, sum(n) AS total FROM t GROUP BY day")
+                .expect("valid sql"),
+            dataset_ref: DatasetRef::new("hf://test/dataset").expect("valid ref"),
+            actor: owner,
+            saved_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("save query");
+
+    let dashboard_id = DashboardId::new();
+    let tab_id = TabId::new();
+    let size = GridSize::new(4, 3).expect("valid size");
+    for command in [
+        DashboardCommand::CreateDashboard {
+            dashboard_id,
+            workspace_id,
+            name: DashboardTitle::new("Overview").expect("valid title"),
+            actor: owner,
+            created_at: Utc::now(),
+        },
+        DashboardCommand::AddTab {
+            dashboard_id,
+            tab_info: TabInfo {
+                tab_id,
+                name: TabTitle::new("Trends").expect("valid tab title"),
+            },
+            actor: owner,
+            added_at: Utc::now(),
+        },
+        DashboardCommand::AddChart {
+            dashboard_id,
+            placement: ChartPlacement {
+                chart_id: ChartId::new(),
+                source: ChartSource::Reference(ChartDefinitionRef {
+                    ref_id: query_id.identifier(),
+                    chart_type_hint: Some(ChartType::Line),
+                }),
+                position: GridPosition { row: 0, col: 0 },
+                size,
+                tab_id: Some(tab_id),
+            },
+            actor: owner,
+            added_at: Utc::now(),
+        },
+        DashboardCommand::AddChart {
+            dashboard_id,
+            placement: ChartPlacement {
+                chart_id: ChartId::new(),
+                source: ChartSource::Inline(ChartConfig::new(ChartType::Bar)),
+                position: GridPosition { row: 3, col: 0 },
+                size,
+                tab_id: None,
+            },
+            actor: owner,
+            added_at: Utc::now(),
+        },
+    ] {
+        handle_dashboard_command(Arc::clone(&repos.dashboards), NO_EVENT_BUS, command)
+            .await
+            .expect("dashboard command");
+    }
+
+    (workspace_id, query_id)
+}
+
+#[tokio::test]
+async fn exported_workspace_imports_into_fresh_store() {
+    let source = WorkspaceRepositories::new(create_test_pool().await);
+    let (source_workspace, source_query) = seed_workspace(&source).await;
+
+    let exported = export_workspace_bundle(&source, source_workspace)
+        .await
+        .expect("export workspace");
+    assert_eq!(exported.saved_queries.len(), 1);
+    assert_eq!(exported.dashboards.len(), 1);
+
+    let json = serde_json::to_string(&exported).expect("serialize bundle");
+    let bundle: WorkspaceBundle = serde_json::from_str(&json).expect("deserialize bundle");
+    assert_eq!(bundle, exported);
+
+    let target = WorkspaceRepositories::new(create_test_pool().await);
+    let new_owner = UserId::new();
+    let imported_workspace = import_workspace_bundle(
+        &target,
+        NO_EVENT_BUS,
+        &ReservedWorkspaceNames::default(),
+        &SystemClock,
+        &bundle,
+        new_owner,
+    )
+    .await
+    .expect("import workspace");
+    assert_ne!(imported_workspace, source_workspace);
+
+    let imported = export_workspace_bundle(&target, imported_workspace)
+        .await
+        .expect("export imported workspace");
+    assert_eq!(imported.name, exported.name);
+    assert_eq!(imported.visibility, exported.visibility);
+
+    let [query] = imported.saved_queries.as_slice() else {
+        panic!("expected one saved query, got {:?}", imported.saved_queries);
+    };
+    assert_ne!(query.query_id, source_query);
+    assert_eq!(query.name, exported.saved_queries[0].name);
+    assert_eq!(query.sql, exported.saved_queries[0].sql);
+    assert_eq!(query.dataset_ref, exported.saved_queries[0].dataset_ref);
+
+    let [dashboard] = imported.dashboards.as_slice() else {
+        panic!("expected one dashboard, got {:?}", imported.dashboards);
+    };
+    let source_dashboard = &exported.dashboards[0];
+    assert_ne!(dashboard.dashboard_id, source_dashboard.dashboard_id);
+    assert_eq!(dashboard.name, source_dashboard.name);
+
+    let [tab] = dashboard.tabs.as_slice() else {
+        panic!("expected one tab, got {:?}", dashboard.tabs);
+    };
+    assert_ne!(tab.tab_id, source_dashboard.tabs[0].tab_id);
+    assert_eq!(tab.name, source_dashboard.tabs[0].name);
+    assert_eq!(
+        source_dashboard.default_tab_id,
+        Some(source_dashboard.tabs[0].tab_id)
+    );
+    assert_eq!(dashboard.default_tab_id, Some(tab.tab_id));
+
+    let [on_tab, inline] = dashboard.placements.as_slice() else {
+        panic!("expected two placements, got {:?}", dashboard.placements);
+    };
+    // The tabbed chart follows its tab and references the imported query.
+    assert_eq!(on_tab.tab_id, Some(tab.tab_id));
+    assert_eq!(
+        on_tab.source,
+        ChartSource::Reference(ChartDefinitionRef {
+            ref_id: query.query_id.identifier(),
+            chart_type_hint: Some(ChartType::Line),
+        })
+    );
+    assert_eq!(inline.tab_id, None);
+    assert_eq!(inline.source, source_dashboard.placements[1].source);
+
+    for (placement, original) in dashboard
+        .placements
+        .iter()
+        .zip(&source_dashboard.placements)
+    {
+        assert_ne!(placement.chart_id, original.chart_id);
+        assert_eq!(placement.position, original.position);
+        assert_eq!(placement.size, original.size);
+    }
+}
+
+#[tokio::test]
+async fn exporting_missing_workspace_fails() {
+    let repos = WorkspaceRepositories::new(create_test_pool().await);
+
+    let result = export_workspace_bundle(&repos, WorkspaceId::new()).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn export_leaves_out_other_workspaces() {
+    let repos = WorkspaceRepositories::new(create_test_pool().await);
+    let (first, first_query) = seed_workspace(&repos).await;
+    let (second, second_query) = seed_workspace(&repos).await;
+
+    let exported = export_workspace_bundle(&repos, first)
+        .await
+        .expect("export workspace");
+    let [query] = exported.saved_queries.as_slice() else {
+        panic!("expected one saved query, got {:?}", exported.saved_queries);
+    };
+    assert_eq!(query.query_id, first_query);
+    assert_eq!(exported.dashboards.len(), 1);
+
+    let exported = export_workspace_bundle(&repos, second)
+        .await
+        .expect("export workspace");
+    assert_eq!(exported.saved_queries[0].query_id, second_query);
+    assert_eq!(exported.dashboards.len(), 1);
+}
+
+#[tokio::test]
+async fn failed_import_persists_nothing() {
+    let source = WorkspaceRepositories::new(create_test_pool().await);
+    let (source_workspace, _) = seed_workspace(&source).await;
+    let bundle = export_workspace_bundle(&source, source_workspace)
+        .await
+        .expect("export workspace");
+
+    let pool = create_test_pool().await;
+    // Fail the last aggregate the import writes, after the workspace and
+    // saved query are inserted.
+    sqlx::query(
+        r#"
+        CREATE TRIGGER fail_dashboards BEFORE INSERT ON events
+        WHEN NEW.event_type = 'DashboardCreated'
+        BEGIN
+            SELECT RAISE(ABORT, 'forced failure');
+        END;
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("create trigger");
+    let target = WorkspaceRepositories::new(pool);
+
+    let result = import_workspace_bundle(
+        &target,
+        NO_EVENT_BUS,
+        &ReservedWorkspaceNames::default(),
+        &SystemClock,
+        &bundle,
+        UserId::new(),
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        target
+            .workspaces
+            .latest_sequence()
+            .await
+            .expect("read sequence"),
+        None
+    );
+}
+
+#[tokio::test]
+async fn import_rejects_a_saved_query_that_writes() {
+    let source = WorkspaceRepositories::new(create_test_pool().await);
+    let (source_workspace, _) = seed_workspace(&source).await;
+    let mut bundle = export_workspace_bundle(&source, source_workspace)
+        .await
+        .expect("export workspace");
+    bundle.saved_queries[0].sql = SqlQuery::new("DELETE FROM sales").expect("valid sql");
+
+    let target = WorkspaceRepositories::new(create_test_pool().await);
+    let result = import_workspace_bundle(
+        &target,
+        NO_EVENT_BUS,
+        &ReservedWorkspaceNames::default(),
+        &SystemClock,
+        &bundle,
+        UserId::new(),
+    )
+    .await;
+
+    assert!(
+        matches!(result, Err(CommandPipelineError::InvalidSql(_))),
+        "expected InvalidSql, got {result:?}"
+    );
+    assert_eq!(
+        target
+            .workspaces
+            .latest_sequence()
+            .await
+            .expect("read sequence"),
+        None
+    );
+}
@@ -0,0 +1,291 @@
+//! Integration tests for suspension of a workspace's dashboards and queries.
+//!
+//! These tests suspend a workspace in a SQLite event store and drive the
+//! dashboard and saved query application services against it, checking that
+//! every change inside the workspace is rejected until it is unsuspended.
+
+#![expect(
+    clippy::expect_used,
+    clippy::panic,
+    reason = "test file with standard test assertions"
+)]
+
+use chrono::Utc;
+use ironstar::application::CommandPipelineError;
+use ironstar::application::dashboard::{add_query_result_to_dashboard, handle_dashboard_command};
+use ironstar::application::saved_query::{
+    clone_saved_query, delete_saved_queries, handle_saved_query_command,
+};
+use ironstar::application::workspace::{
+    ReservedWorkspaceNames, WorkspaceAdministrators, WorkspaceRepositories,
+    handle_workspace_command,
+};
+use ironstar::domain::UserId;
+use ironstar::domain::analytics::{ChartType, DatasetRef, SqlQuery};
+use ironstar::domain::clock::SystemClock;
+use ironstar::domain::common::DashboardTitle;
+use ironstar::domain::dashboard::{DashboardCommand, DashboardId};
+use ironstar::domain::saved_query::{QueryName, SavedQueryCommand, SavedQueryId};
+use ironstar::domain::workspace::{Visibility, WorkspaceCommand, WorkspaceErrorKind, WorkspaceId};
+use ironstar::infrastructure::EVENTS_MIGRATION_SQL;
+use ironstar::infrastructure::ZenohEventBus;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+
+const NO_EVENT_BUS: Option<&ZenohEventBus> = None;
+
+/// Create an in-memory SQLite pool with event store migrations applied.
+async fn create_test_pool() -> sqlx::SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to create test pool");
+
+    sqlx::query(EVENTS_MIGRATION_SQL)
+        .execute(&pool)
+        .await
+        .expect("Failed to run migration");
+
+    pool
+}
+
+struct Fixture {
+    repos: WorkspaceRepositories,
+    administrators: WorkspaceAdministrators,
+    admin: UserId,
+    owner: UserId,
+    suspended: WorkspaceId,
+    active: WorkspaceId,
+    dashboard_id: DashboardId,
+    suspended_query: SavedQueryId,
+    active_query: SavedQueryId,
+}
+
+async fn workspace_command(fixture: &Fixture, command: WorkspaceCommand) {
+    handle_workspace_command(
+        Arc::clone(&fixture.repos.workspaces),
+        NO_EVENT_BUS,
+        &ReservedWorkspaceNames::default(),
+        &fixture.administrators,
+        command,
+    )
+    .await
+    .expect("workspace command");
+}
+
+async fn save_query(fixture: &Fixture, query_id: SavedQueryId, workspace_id: WorkspaceId) {
+    handle_saved_query_command(
+        Arc::clone(&fixture.repos.saved_queries),
+        NO_EVENT_BUS,
+        SavedQueryCommand::SaveQuery {
+            query_id,
+            workspace_id,
+            name: QueryName::new("Daily totals").expect("valid name"),
+            sql: SqlQuery::new("SELECT 1").expect("valid sql"),
+            dataset_ref: DatasetRef::new("hf://test/dataset").expect("valid ref"),
+            actor: fixture.owner,
+            saved_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("save query");
+}
+
+/// Two workspaces of one owner, each with a saved query, and a dashboard in
+/// the first, which an administrator then suspends.
+async fn fixture() -> Fixture {
+    let admin = UserId::new();
+    let fixture = Fixture {
+        repos: WorkspaceRepositories::new(create_test_pool().await),
+        administrators: WorkspaceAdministrators::new([admin]),
+        admin,
+        owner: UserId::new(),
+        suspended: WorkspaceId::new(),
+        active: WorkspaceId::new(),
+        dashboard_id: DashboardId::new(),
+        suspended_query: SavedQueryId::new(),
+        active_query: SavedQueryId::new(),
+    };
+
+    for (workspace_id, name) in [(fixture.suspended, "Frozen"), (fixture.active, "Open")] {
+        workspace_command(
+            &fixture,
+            WorkspaceCommand::Create {
+                workspace_id,
+                name: name.to_string(),
+                owner_id: fixture.owner,
+                visibility: Visibility::Private,
+                actor: fixture.owner,
+                created_at: Utc::now(),
+            },
+        )
+        .await;
+    }
+    save_query(&fixture, fixture.suspended_query, fixture.suspended).await;
+    save_query(&fixture, fixture.active_query, fixture.active).await;
+    handle_dashboard_command(
+        Arc::clone(&fixture.repos.dashboards),
+        NO_EVENT_BUS,
+        DashboardCommand::CreateDashboard {
+            dashboard_id: fixture.dashboard_id,
+            workspace_id: fixture.suspended,
+            name: DashboardTitle::new("Overview").expect("valid title"),
+            actor: fixture.owner,
+            created_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("create dashboard");
+
+    workspace_command(
+        &fixture,
+        WorkspaceCommand::Suspend {
+            workspace_id: fixture.suspended,
+            reason: "pending review".to_string(),
+            actor: admin,
+            suspended_at: Utc::now(),
+        },
+    )
+    .await;
+    fixture
+}
+
+fn assert_suspended<T: std::fmt::Debug>(result: Result<T, CommandPipelineError>) {
+    match result {
+        Err(CommandPipelineError::Workspace(ref e))
+            if *e.kind() == WorkspaceErrorKind::WorkspaceSuspended("pending review".into()) => {}
+        other => panic!("Expected WorkspaceSuspended, got: {other:?}"),
+    }
+}
+
+fn move_query(fixture: &Fixture, query_id: SavedQueryId, target: WorkspaceId) -> SavedQueryCommand {
+    SavedQueryCommand::MoveToWorkspace {
+        query_id,
+        target_workspace_id: target,
+        actor: fixture.owner,
+        moved_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn saved_queries_of_a_suspended_workspace_cannot_change() {
+    let fixture = fixture().await;
+    let queries = &fixture.repos.saved_queries;
+
+    assert_suspended(
+        clone_saved_query(
+            Arc::clone(queries),
+            NO_EVENT_BUS,
+            fixture.suspended_query,
+            QueryName::new("Copy").expect("valid name"),
+            fixture.owner,
+            &SystemClock,
+        )
+        .await,
+    );
+    for command in [
+        move_query(&fixture, fixture.suspended_query, fixture.active),
+        move_query(&fixture, fixture.active_query, fixture.suspended),
+    ] {
+        assert_suspended(
+            handle_saved_query_command(Arc::clone(queries), NO_EVENT_BUS, command).await,
+        );
+    }
+
+    let summary = delete_saved_queries(
+        Arc::clone(queries),
+        NO_EVENT_BUS,
+        vec![fixture.suspended_query],
+        fixture.owner,
+        &SystemClock,
+    )
+    .await;
+    let [(query_id, error)] = summary.failed.as_slice() else {
+        panic!("expected one failed deletion, got {summary:?}");
+    };
+    assert_eq!(*query_id, fixture.suspended_query);
+    assert!(matches!(error, CommandPipelineError::Workspace(_)));
+
+    // Running a query only reads the workspace.
+    handle_saved_query_command(
+        Arc::clone(queries),
+        NO_EVENT_BUS,
+        SavedQueryCommand::RecordRun {
+            query_id: fixture.suspended_query,
+            run_at: Utc::now(),
+        },
+    )
+    .await
+    .expect("runs are recorded while suspended");
+}
+
+#[tokio::test]
+async fn dashboards_of_a_suspended_workspace_cannot_change() {
+    let fixture = fixture().await;
+
+    assert_suspended(
+        handle_dashboard_command(
+            Arc::clone(&fixture.repos.dashboards),
+            NO_EVENT_BUS,
+            DashboardCommand::CreateDashboard {
+                dashboard_id: DashboardId::new(),
+                workspace_id: fixture.suspended,
+                name: DashboardTitle::new("Another").expect("valid title"),
+                actor: fixture.owner,
+                created_at: Utc::now(),
+            },
+        )
+        .await,
+    );
+    assert_suspended(
+        add_query_result_to_dashboard(
+            Arc::clone(&fixture.repos.dashboards),
+            &fixture.repos.saved_queries,
+            NO_EVENT_BUS,
+            fixture.dashboard_id,
+            fixture.suspended_query,
+            ChartType::Line,
+            None,
+            fixture.owner,
+        )
+        .await,
+    );
+}
+
+#[tokio::test]
+async fn unsuspended_workspace_accepts_changes_again() {
+    let fixture = fixture().await;
+    workspace_command(
+        &fixture,
+        WorkspaceCommand::Unsuspend {
+            workspace_id: fixture.suspended,
+            actor: fixture.admin,
+            unsuspended_at: Utc::now(),
+        },
+    )
+    .await;
+
+    clone_saved_query(
+        Arc::clone(&fixture.repos.saved_queries),
+        NO_EVENT_BUS,
+        fixture.suspended_query,
+        QueryName::new("Copy").expect("valid name"),
+        fixture.owner,
+        &SystemClock,
+    )
+    .await
+    .expect("clone after unsuspend");
+    add_query_result_to_dashboard(
+        Arc::clone(&fixture.repos.dashboards),
+        &fixture.repos.saved_queries,
+        NO_EVENT_BUS,
+        fixture.dashboard_id,
+        fixture.suspended_query,
+        ChartType::Line,
+        None,
+        fixture.owner,
+    )
+    .await
+    .expect("pin after unsuspend");
+}
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChartId } from "../domain/ChartId";
+import type { ChartPlacement } from "../domain/ChartPlacement";
+import type { DashboardId } from "../domain/DashboardId";
+import type { DashboardTitle } from "../domain/DashboardTitle";
+import type { TabId } from "../domain/TabId";
+import type { TabInfo } from "../domain/TabInfo";
+import type { UserId } from "../domain/UserId";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Commands that can be sent to the Dashboard aggregate.
+ *
+ * The aggregate ID follows the pattern `dashboard_{dashboard_id}`,
+ * supporting multiple dashboards per workspace.
+ */
+export type DashboardCommand = { "type": "CreateDashboard", dashboard_id: DashboardId, workspace_id: WorkspaceId, name: DashboardTitle, actor: UserId, created_at: string, } | { "type": "RenameDashboard", dashboard_id: DashboardId, name: DashboardTitle, actor: UserId, renamed_at: string, } | { "type": "AddChart", dashboard_id: DashboardId, placement: ChartPlacement, actor: UserId, added_at: string, } | { "type": "RemoveChart", dashboard_id: DashboardId, chart_id: ChartId, actor: UserId, removed_at: string, } | { "type": "AddTab", dashboard_id: DashboardId, tab_info: TabInfo, actor: UserId, added_at: string, } | { "type": "RemoveTab", dashboard_id: DashboardId, tab_id: TabId, actor: UserId, removed_at: string, } | { "type": "MoveChartToTab", dashboard_id: DashboardId, chart_id: ChartId, tab_id: TabId, actor: UserId, moved_at: string, } | { "type": "SetDefaultTab", dashboard_id: DashboardId, tab_id: TabId, actor: UserId, set_at: string, } | { "type": "ArchiveDashboard", dashboard_id: DashboardId, actor: UserId, archived_at: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DatasetRef } from "../domain/DatasetRef";
+import type { QueryName } from "../domain/QueryName";
+import type { SavedQueryId } from "../domain/SavedQueryId";
+import type { SqlQuery } from "../domain/SqlQuery";
+import type { UserId } from "../domain/UserId";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Commands that can be sent to the SavedQuery aggregate.
+ *
+ * The aggregate ID follows the pattern `saved_query_{query_id}`.
+ */
+export type SavedQueryCommand = { "type": "SaveQuery", query_id: SavedQueryId, workspace_id: WorkspaceId, name: QueryName, sql: SqlQuery, dataset_ref: DatasetRef, actor: UserId, saved_at: string, } | { "type": "DeleteQuery", query_id: SavedQueryId, actor: UserId, deleted_at: string, } | { "type": "RenameQuery", query_id: SavedQueryId, name: QueryName, actor: UserId, renamed_at: string, } | { "type": "UpdateQuerySql", query_id: SavedQueryId, sql: SqlQuery, actor: UserId, updated_at: string, } | { "type": "UpdateDatasetRef", query_id: SavedQueryId, dataset_ref: DatasetRef, actor: UserId, updated_at: string, } | { "type": "MoveToWorkspace", query_id: SavedQueryId, target_workspace_id: WorkspaceId, actor: UserId, moved_at: string, } | { "type": "RecordRun", query_id: SavedQueryId, run_at: string, };
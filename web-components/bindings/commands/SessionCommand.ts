@@ -0,0 +1,63 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OAuthProvider } from "../domain/OAuthProvider";
+import type { SessionId } from "../domain/SessionId";
+import type { SessionMetadata } from "../domain/SessionMetadata";
+import type { UserId } from "../domain/UserId";
+
+/**
+ * Commands that can be sent to the Session aggregate.
+ *
+ * Each command represents an authentication-related intention. The aggregate
+ * validates the command against its current state and either:
+ *
+ * - Emits events (success)
+ * - Returns an error (validation failure)
+ *
+ * Commands are constructed at the boundary layer after OAuth callbacks
+ * or session management operations.
+ */
+export type SessionCommand = { "type": "Create", 
+/**
+ * Fresh session identifier.
+ */
+session_id: SessionId, 
+/**
+ * Canonical user identifier.
+ */
+user_id: UserId, 
+/**
+ * OAuth provider that authenticated this session.
+ */
+provider: OAuthProvider, 
+/**
+ * When the session was created.
+ */
+created_at: string, 
+/**
+ * When the session expires.
+ */
+expires_at: string, 
+/**
+ * Audit trail metadata.
+ */
+metadata: SessionMetadata, } | { "type": "Refresh", 
+/**
+ * Session to refresh.
+ */
+session_id: SessionId, 
+/**
+ * When the refresh occurred.
+ */
+refreshed_at: string, 
+/**
+ * New expiration timestamp.
+ */
+new_expires_at: string, } | { "type": "Invalidate", 
+/**
+ * Session to invalidate.
+ */
+session_id: SessionId, 
+/**
+ * When invalidation occurred.
+ */
+invalidated_at: string, };
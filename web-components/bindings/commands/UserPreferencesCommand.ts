@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Locale } from "../domain/Locale";
+import type { NotificationPreferences } from "../domain/NotificationPreferences";
+import type { PreferencesId } from "../domain/PreferencesId";
+import type { Theme } from "../domain/Theme";
+import type { UiState } from "../domain/UiState";
+import type { UserId } from "../domain/UserId";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Commands that can be sent to the UserPreferences aggregate.
+ *
+ * The aggregate ID follows the pattern `user_{user_id}/preferences`,
+ * making this a per-user singleton.
+ */
+export type UserPreferencesCommand = { "type": "InitializePreferences", preferences_id: PreferencesId, user_id: UserId, initialized_at: string, } | { "type": "SetTheme", user_id: UserId, theme: Theme, set_at: string, } | { "type": "SetLocale", user_id: UserId, locale: Locale, set_at: string, } | { "type": "UpdateUiState", user_id: UserId, ui_state: UiState, updated_at: string, } | { "type": "SetDefaultWorkspace", user_id: UserId, workspace_id: WorkspaceId, set_at: string, } | { "type": "ClearDefaultWorkspace", user_id: UserId, cleared_at: string, } | { "type": "RecordWorkspaceView", user_id: UserId, workspace_id: WorkspaceId, viewed_at: string, } | { "type": "SetNotificationPreferences", user_id: UserId, notifications: NotificationPreferences, set_at: string, };
@@ -0,0 +1,114 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UserId } from "../domain/UserId";
+import type { Visibility } from "../domain/Visibility";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Commands that can be sent to the Workspace aggregate.
+ *
+ * Each command represents a user intention. The aggregate validates
+ * the command against its current state and either:
+ *
+ * - Emits events (success)
+ * - Returns an error (validation failure)
+ *
+ * Commands are deserialized from HTTP requests (via datastar signals)
+ * and routed to the appropriate aggregate.
+ */
+export type WorkspaceCommand = { "type": "Create", 
+/**
+ * Client-generated unique identifier.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * Raw name input (will be validated and trimmed).
+ */
+name: string, 
+/**
+ * Owner of the workspace.
+ */
+owner_id: UserId, 
+/**
+ * Visibility setting.
+ */
+visibility: Visibility, 
+/**
+ * Who issued the command (injected at boundary).
+ */
+actor: UserId, 
+/**
+ * When the command was issued (injected at boundary).
+ */
+created_at: string, } | { "type": "Rename", 
+/**
+ * Which workspace to rename.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * New name (raw, will be validated).
+ */
+new_name: string, 
+/**
+ * Who issued the command (injected at boundary).
+ */
+actor: UserId, 
+/**
+ * When the rename was issued (injected at boundary).
+ */
+renamed_at: string, } | { "type": "SetVisibility", 
+/**
+ * Which workspace to modify.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * New visibility setting.
+ */
+visibility: Visibility, 
+/**
+ * Who issued the command (injected at boundary).
+ */
+actor: UserId, 
+/**
+ * When the change was issued (injected at boundary).
+ */
+changed_at: string, } | { "type": "Suspend", 
+/**
+ * Which workspace to suspend.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * Why the workspace is being suspended.
+ */
+reason: string, 
+/**
+ * Who issued the command (injected at boundary).
+ */
+actor: UserId, 
+/**
+ * When the suspension was issued (injected at boundary).
+ */
+suspended_at: string, } | { "type": "Unsuspend", 
+/**
+ * Which workspace to unsuspend.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * Who issued the command (injected at boundary).
+ */
+actor: UserId, 
+/**
+ * When the suspension was lifted (injected at boundary).
+ */
+unsuspended_at: string, } | { "type": "Archive", 
+/**
+ * Which workspace to archive.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * Who issued the command (injected at boundary).
+ */
+actor: UserId, 
+/**
+ * When the archive was issued (injected at boundary).
+ */
+archived_at: string, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CatalogUri } from "../domain/CatalogUri";
+import type { LayoutDefaults } from "../domain/LayoutDefaults";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Commands that can be sent to the WorkspacePreferences aggregate.
+ *
+ * The aggregate ID follows the pattern `workspace_{workspace_id}/preferences`,
+ * making this a per-workspace singleton.
+ */
+export type WorkspacePreferencesCommand = { "type": "InitializeWorkspacePreferences", workspace_id: WorkspaceId, initialized_at: string, } | { "type": "SetDefaultCatalog", workspace_id: WorkspaceId, catalog_uri: CatalogUri, set_at: string, } | { "type": "ClearDefaultCatalog", workspace_id: WorkspaceId, cleared_at: string, } | { "type": "UpdateLayoutDefaults", workspace_id: WorkspaceId, layout_defaults: LayoutDefaults, updated_at: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Why a query was cancelled.
+ *
+ * Serialized as a plain string: the known reasons use the snake_case codes
+ * below, and any other string is [`Other`](Self::Other). Cancellations
+ * recorded before reasons were classified stored free text, so those read
+ * back as `Other` with the original text.
+ */
+export type CancellationReason = string;
@@ -0,0 +1,33 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CatalogMetadata } from "./CatalogMetadata";
+import type { CatalogRef } from "./CatalogRef";
+
+/**
+ * Commands for the Catalog aggregate.
+ *
+ * Commands represent requests to change state. All commands include
+ * timestamp fields for pure decision-making. Timestamps are injected
+ * by the application layer; the decider never calls `Utc::now()`.
+ *
+ * For `RefreshCatalogMetadata`, the application layer introspects the
+ * DuckLake catalog via DuckDB and provides the metadata in the command.
+ * The typed holes from the Idris spec (`?metadata`, `?timestamp_refresh`)
+ * resolve at the effect boundary, not in the Decider.
+ */
+export type CatalogCommand = { "type": "SelectCatalog", 
+/**
+ * Reference to the catalog to select.
+ */
+catalog_ref: CatalogRef, 
+/**
+ * Timestamp when the catalog was selected (injected by application layer).
+ */
+selected_at: string, } | { "type": "RefreshCatalogMetadata", 
+/**
+ * Fresh metadata from DuckDB catalog introspection.
+ */
+metadata: CatalogMetadata, 
+/**
+ * Timestamp when the refresh was performed (injected by application layer).
+ */
+refreshed_at: string, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CatalogMetadata } from "./CatalogMetadata";
+import type { CatalogMetadataDiff } from "./CatalogMetadataDiff";
+import type { CatalogRef } from "./CatalogRef";
+
+/**
+ * Events emitted by the Catalog aggregate.
+ *
+ * Events represent facts that have occurred. They are immutable records
+ * of state changes, persisted to the event store.
+ */
+export type CatalogEvent = { "type": "CatalogSelected", catalog_ref: CatalogRef, selected_at: string, } | { "type": "CatalogMetadataRefreshed", metadata: CatalogMetadata, diff: CatalogMetadataDiff, refreshed_at: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DatasetInfo } from "./DatasetInfo";
+
+/**
+ * Metadata about a DuckLake catalog.
+ *
+ * Contains the list of datasets and the timestamp of the last refresh.
+ * This is populated by the application layer after introspecting the catalog
+ * via DuckDB and injected into the `RefreshCatalogMetadata` command.
+ */
+export type CatalogMetadata = { 
+/**
+ * Datasets available in the catalog.
+ */
+datasets: Array<DatasetInfo>, 
+/**
+ * When the metadata was last refreshed.
+ */
+last_refreshed: string, };
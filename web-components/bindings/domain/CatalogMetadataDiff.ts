@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DatasetInfo } from "./DatasetInfo";
+
+/**
+ * Dataset changes between two catalog metadata snapshots.
+ *
+ * Datasets are matched by name. A dataset present in both snapshots whose
+ * table count, schema version, or row count differs is reported as changed, carrying its
+ * new details. The refresh timestamp is not compared.
+ */
+export type CatalogMetadataDiff = { 
+/**
+ * Datasets present only in the new metadata.
+ */
+added: Array<DatasetInfo>, 
+/**
+ * Datasets present only in the old metadata.
+ */
+removed: Array<DatasetInfo>, 
+/**
+ * Datasets present in both whose details changed, as they are now.
+ */
+changed: Array<DatasetInfo>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Reference to a DuckLake catalog.
+ *
+ * Guarantees:
+ * - Non-empty (at least one non-whitespace character)
+ * - At most [`CATALOG_REF_MAX_LENGTH`] characters
+ * - Trimmed of leading/trailing whitespace
+ * - Either a `ducklake:` URI with a non-empty target, or a file path to a
+ *   catalog database (no `scheme://` prefix)
+ * - No control characters
+ *
+ * Example values: `"ducklake://hf/sciexp/fixtures"`,
+ * `"ducklake:hf://datasets/sciexp/fixtures/lakes/frozen/space.db"`,
+ * `"./data/catalog.ducklake"`
+ */
+export type CatalogRef = string;
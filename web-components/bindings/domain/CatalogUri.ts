@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * URI referencing a DuckDB catalog.
+ *
+ * Structural guarantees:
+ * - Non-empty (at least one non-whitespace character)
+ * - At most [`CATALOG_URI_MAX_LENGTH`] characters
+ * - Trimmed of leading/trailing whitespace
+ *
+ * Semantic guarantee (enforced at boundary):
+ * - References a valid DuckDB catalog (e.g., `"ducklake:hf://datasets/sciexp"`)
+ */
+export type CatalogUri = string;
@@ -0,0 +1,52 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChartType } from "./ChartType";
+
+/**
+ * Configuration for ECharts visualization.
+ *
+ * This is a product type containing all configuration options for rendering
+ * a chart. Optional fields use `Option` to allow partial configuration
+ * with sensible defaults applied at render time.
+ *
+ * # Example
+ *
+ * ```rust,ignore
+ * let config = ChartConfig::new(ChartType::Line)
+ *     .with_x_axis("date")
+ *     .with_y_axis("value")
+ *     .with_title("Sales Over Time");
+ * ```
+ */
+export type ChartConfig = { 
+/**
+ * Type of chart to render.
+ */
+chart_type: ChartType, 
+/**
+ * Column name for x-axis data.
+ */
+x_axis: string | null, 
+/**
+ * Column name for y-axis data.
+ */
+y_axis: string | null, 
+/**
+ * Chart title.
+ */
+title: string | null, 
+/**
+ * Column name for series grouping (multiple lines/bars).
+ */
+series_column: string | null, 
+/**
+ * Maximum number of data points to display.
+ */
+limit: number | null, 
+/**
+ * Whether to enable data zoom (scrollable x-axis).
+ */
+enable_zoom: boolean, 
+/**
+ * Whether to show legend.
+ */
+show_legend: boolean, };
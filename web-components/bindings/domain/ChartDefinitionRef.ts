@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChartType } from "./ChartType";
+
+/**
+ * Reference to an Analytics domain ChartDefinition.
+ *
+ * This is a cross-aggregate reference following the Customer-Supplier
+ * pattern: Dashboard (Workspace context) references ChartDefinition
+ * (Analytics context) by ID.
+ */
+export type ChartDefinitionRef = { 
+/**
+ * The reference identifier for the chart definition.
+ */
+ref_id: string, 
+/**
+ * Optional hint about the chart type for rendering purposes.
+ */
+chart_type_hint: ChartType | null, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChartId } from "./ChartId";
+import type { ChartSource } from "./ChartSource";
+import type { GridPosition } from "./GridPosition";
+import type { GridSize } from "./GridSize";
+import type { TabId } from "./TabId";
+
+/**
+ * A chart placed on a dashboard with position, size, and optional tab assignment.
+ */
+export type ChartPlacement = { 
+/**
+ * Unique identifier for this chart placement.
+ */
+chart_id: ChartId, 
+/**
+ * Chart configuration, referenced or inline.
+ *
+ * Placements recorded before inline configuration existed stored a
+ * bare reference under `chart_def_ref`.
+ */
+source: ChartSource, 
+/**
+ * Grid position of the chart.
+ */
+position: GridPosition, 
+/**
+ * Grid size of the chart.
+ */
+size: GridSize, 
+/**
+ * Optional tab assignment. None means the chart is on the default view.
+ */
+tab_id: TabId | null, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChartConfig } from "./ChartConfig";
+import type { ChartDefinitionRef } from "./ChartDefinitionRef";
+
+/**
+ * Configuration source of a placed chart.
+ *
+ * Most placements reference a shared Analytics ChartDefinition; small
+ * dashboards can embed a one-off configuration instead, which needs no
+ * resolution. Serialized untagged: a reference has `ref_id`, an inline
+ * configuration has `chart_type`.
+ */
+export type ChartSource = ChartDefinitionRef | ChartConfig;
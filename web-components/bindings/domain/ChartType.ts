@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Type of chart visualization.
+ *
+ * Maps to Apache ECharts chart types. This is a sum type (enum) representing
+ * the discrete choices available for chart visualization.
+ */
+export type ChartType = "line" | "bar" | "scatter" | "pie" | "area" | "heatmap" | "boxplot" | "candlestick";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unique identifier for a dashboard instance.
+ *
+ * Wraps a UUID with domain semantics. Each dashboard has a unique ID
+ * independent of the workspace it belongs to (multiple dashboards per
+ * workspace are supported).
+ */
+export type DashboardId = string;
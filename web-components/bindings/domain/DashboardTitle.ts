@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated dashboard title.
+ *
+ * Guarantees:
+ * - Non-empty (at least 1 character)
+ * - At most 200 characters
+ * - Trimmed of leading/trailing whitespace
+ */
+export type DashboardTitle = string;
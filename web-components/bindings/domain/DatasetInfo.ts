@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Information about a dataset in the catalog.
+ */
+export type DatasetInfo = { 
+/**
+ * Dataset name.
+ */
+name: string, 
+/**
+ * Number of tables in the dataset.
+ */
+table_count: number, 
+/**
+ * Schema version string.
+ */
+schema_version: string, 
+/**
+ * Number of rows, when counted during refresh.
+ *
+ * Counting scans every dataset, so it only happens when a refresh opts
+ * in. `None` means the count was not requested or could not be taken.
+ */
+row_count?: bigint, };
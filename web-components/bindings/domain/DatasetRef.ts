@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated reference to a dataset.
+ *
+ * Supports multiple URI schemes for different data sources:
+ * - `hf://datasets/user/repo` - HuggingFace Hub dataset
+ * - `s3://bucket/path` - S3-compatible object storage
+ * - `gs://bucket/path` - Google Cloud Storage
+ * - `file:///path/to/data` - Local file path (absolute)
+ * - `./relative/path` - Local relative path (development)
+ *
+ * The reference is validated at construction time to ensure it's non-empty,
+ * within length limits, and follows a recognized format.
+ *
+ * # Example
+ *
+ * ```rust,ignore
+ * let hf_dataset = DatasetRef::new("hf://datasets/user/repo")?;
+ * let s3_dataset = DatasetRef::new("s3://my-bucket/data/file.parquet")?;
+ * let local_file = DatasetRef::new("./fixtures/sample.csv")?;
+ * ```
+ */
+export type DatasetRef = string;
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How often a notification digest email is sent.
+ *
+ * Defaults to `Off`. Parsing from a string accepts only `off`, `daily`, or
+ * `weekly` (case-insensitive).
+ */
+export type DigestFrequency = "off" | "daily" | "weekly";
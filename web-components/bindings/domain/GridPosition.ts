@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Zero-indexed grid position for chart placement.
+ *
+ * Both row and col start at 0. No validation is needed since zero is a
+ * valid position.
+ */
+export type GridPosition = { 
+/**
+ * Zero-indexed row.
+ */
+row: number, 
+/**
+ * Zero-indexed column.
+ */
+col: number, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated grid dimensions.
+ *
+ * Guarantees:
+ * - Width >= 1
+ * - Height >= 1
+ *
+ * Represents the size of a grid layout in cells. Both dimensions must be
+ * at least 1 to ensure a valid, displayable grid.
+ */
+export type GridSize = { width: number, height: number, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * JSON string representing workspace layout defaults.
+ *
+ * The domain layer treats this as an opaque string. JSON validation
+ * is deferred to the boundary layer per Hoffman's Law 7 (work is a
+ * side effect).
+ *
+ * Two keys are understood by the domain: `row_height_px` and `gap_px`
+ * size the dashboard grid. [`LayoutDefaults::validate`] range-checks them
+ * when present; other keys pass through untouched.
+ *
+ * Default value is `"{}"` (empty JSON object).
+ */
+export type LayoutDefaults = string;
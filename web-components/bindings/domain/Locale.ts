@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated BCP-47 language tag.
+ *
+ * Structural guarantees:
+ * - Non-empty (at least one non-whitespace character)
+ * - At most [`LOCALE_MAX_LENGTH`] characters
+ * - Trimmed of leading/trailing whitespace
+ *
+ * Semantic validation (e.g., whether the tag references a real locale)
+ * is deferred to the boundary layer.
+ */
+export type Locale = string;
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DigestFrequency } from "./DigestFrequency";
+
+/**
+ * Notification settings for a user.
+ *
+ * Defaults to in-app notifications only, with no email and no digest.
+ */
+export type NotificationPreferences = { 
+/**
+ * Send notifications by email.
+ */
+email_enabled: boolean, 
+/**
+ * Show notifications in the application.
+ */
+in_app_enabled: boolean, 
+/**
+ * Digest email frequency.
+ */
+digest: DigestFrequency, };
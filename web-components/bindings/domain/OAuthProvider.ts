@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * OAuth authentication provider.
+ *
+ * GitHub is the primary provider; Google is planned for future extension.
+ */
+export type OAuthProvider = "github" | "google";
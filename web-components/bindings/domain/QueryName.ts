@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated query name.
+ *
+ * Guarantees:
+ * - Non-empty (at least 1 character)
+ * - At most 200 characters
+ * - Trimmed of leading/trailing whitespace
+ *
+ * # Example
+ *
+ * ```rust,ignore
+ * let name = QueryName::new("Monthly Revenue by Region")?;
+ * assert!(!name.as_str().is_empty());
+ * ```
+ */
+export type QueryName = string;
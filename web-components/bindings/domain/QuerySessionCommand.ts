@@ -0,0 +1,99 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CancellationReason } from "./CancellationReason";
+import type { ChartConfig } from "./ChartConfig";
+import type { DatasetRef } from "./DatasetRef";
+import type { QueryId } from "./QueryId";
+import type { SqlQuery } from "./SqlQuery";
+import type { UserId } from "./UserId";
+
+/**
+ * Commands for the QuerySession aggregate.
+ *
+ * Commands represent requests to change state. They carry the data needed
+ * for validation and event emission. The aggregate validates commands
+ * against current state and either rejects them or emits events.
+ *
+ * All commands include timestamp fields for pure decision-making. Timestamps
+ * are injected by the application layer; the decider never calls `Utc::now()`.
+ */
+export type QuerySessionCommand = { "type": "StartQuery", 
+/**
+ * Unique identifier for this query.
+ */
+query_id: QueryId, 
+/**
+ * The SQL query to execute.
+ */
+sql: SqlQuery, 
+/**
+ * Optional dataset reference (for remote data sources).
+ */
+dataset_ref: DatasetRef | null, 
+/**
+ * Optional chart configuration for visualization.
+ */
+chart_config: ChartConfig | null, 
+/**
+ * Timestamp when the query was started (injected by application layer).
+ */
+started_at: string, 
+/**
+ * User starting the query.
+ */
+actor: UserId, } | { "type": "BeginExecution", 
+/**
+ * Must match the pending query ID.
+ */
+query_id: QueryId, 
+/**
+ * Timestamp when execution began (injected by application layer).
+ */
+began_at: string, } | { "type": "CompleteQuery", 
+/**
+ * Must match the executing query ID.
+ */
+query_id: QueryId, 
+/**
+ * Number of rows returned.
+ */
+row_count: number, 
+/**
+ * Cache key under which the query result is stored.
+ */
+result_ref: string, 
+/**
+ * Execution duration in milliseconds.
+ */
+duration_ms: bigint, 
+/**
+ * Timestamp when the query completed (injected by application layer).
+ */
+completed_at: string, } | { "type": "FailQuery", 
+/**
+ * Must match the executing query ID.
+ */
+query_id: QueryId, 
+/**
+ * Error message describing the failure.
+ */
+error: string, 
+/**
+ * Timestamp when the query failed (injected by application layer).
+ */
+failed_at: string, } | { "type": "CancelQuery", 
+/**
+ * Must match the current query ID.
+ */
+query_id: QueryId, 
+/**
+ * Why the query is being cancelled.
+ */
+reason: CancellationReason, 
+/**
+ * Timestamp when the query was cancelled (injected by application layer).
+ */
+cancelled_at: string, } | { "type": "ResetSession", 
+/**
+ * Timestamp when the session was reset (injected by application layer).
+ */
+reset_at: string, };
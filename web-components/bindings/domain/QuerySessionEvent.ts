@@ -0,0 +1,35 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CancellationReason } from "./CancellationReason";
+import type { ChartConfig } from "./ChartConfig";
+import type { DatasetRef } from "./DatasetRef";
+import type { QueryId } from "./QueryId";
+import type { SqlQuery } from "./SqlQuery";
+import type { UserId } from "./UserId";
+
+/**
+ * Events emitted by the QuerySession aggregate.
+ *
+ * Events represent facts that have occurred in the domain. They are
+ * immutable records of state changes, persisted to the event store.
+ *
+ * Note: PartialEq is derived for testing convenience but comparing events
+ * with timestamps should use pattern matching, not assert_eq!, to avoid
+ * timestamp comparison issues.
+ */
+export type QuerySessionEvent = { "type": "QueryStarted", query_id: QueryId, sql: SqlQuery, dataset_ref: DatasetRef | null, chart_config: ChartConfig | null, started_at: string, 
+/**
+ * Absent in events recorded before queries were attributed, which
+ * read as started by the anonymous user.
+ */
+actor: UserId, } | { "type": "ExecutionBegan", query_id: QueryId, began_at: string, } | { "type": "QueryCompleted", query_id: QueryId, row_count: number, 
+/**
+ * Cache key under which the query result is stored.
+ *
+ * Empty for queries completed before results were cached.
+ */
+result_ref: string, duration_ms: bigint, completed_at: string, } | { "type": "QueryFailed", query_id: QueryId, error: string, failed_at: string, } | { "type": "QueryCancelled", query_id: QueryId, 
+/**
+ * Absent in events recorded before reasons were required, which
+ * were all user cancellations.
+ */
+reason: CancellationReason, cancelled_at: string, } | { "type": "SessionReset", reset_at: string, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unique identifier for a Session.
+ *
+ * Wraps a UUID v4, providing type safety to prevent mixing up different
+ * ID types (e.g., passing a `UserId` where a `SessionId` is expected).
+ *
+ * # Construction
+ *
+ * - `SessionId::new()` - Generate a new random ID
+ * - `SessionId::from_uuid(uuid)` - Wrap an existing UUID (for deserialization)
+ */
+export type SessionId = string;
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Metadata captured at session creation for security audit trail.
+ *
+ * All fields are optional as they depend on boundary layer context.
+ * Populated from HTTP request headers at the boundary.
+ */
+export type SessionMetadata = { 
+/**
+ * Client IP address (X-Forwarded-For or direct).
+ */
+ip_address: string | null, 
+/**
+ * User-Agent header value.
+ */
+user_agent: string | null, };
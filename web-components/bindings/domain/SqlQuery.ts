@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated SQL query string.
+ *
+ * Guarantees:
+ * - Non-empty (at least one non-whitespace character)
+ * - At most [`SQL_QUERY_MAX_LENGTH`] characters
+ * - Trimmed of leading/trailing whitespace
+ *
+ * User input goes through [`SqlQuery::new_read_only`], which also requires
+ * a single statement that starts with a read keyword such as `SELECT` or
+ * `WITH` and contains no data-modifying statement. [`SqlQuery::new`] and
+ * deserialization skip that check, so stored events written before it
+ * still replay.
+ *
+ * Note: The statement check is lexical, not a full SQL parse. DuckDB's
+ * read-only access mode still backs it at the execution layer.
+ *
+ * # Example
+ *
+ * ```rust,ignore
+ * let query = SqlQuery::new("SELECT * FROM dataset LIMIT 10")?;
+ * assert!(!query.as_str().is_empty());
+ * ```
+ */
+export type SqlQuery = string;
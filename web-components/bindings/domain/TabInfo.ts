@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TabId } from "./TabId";
+import type { TabTitle } from "./TabTitle";
+
+/**
+ * Tab metadata for dashboard tab organization.
+ */
+export type TabInfo = { 
+/**
+ * Unique identifier for this tab.
+ */
+tab_id: TabId, 
+/**
+ * Display name of the tab.
+ */
+name: TabTitle, };
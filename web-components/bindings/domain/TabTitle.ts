@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated tab title.
+ *
+ * Guarantees:
+ * - Non-empty (at least 1 character)
+ * - At most 100 characters
+ * - Trimmed of leading/trailing whitespace
+ */
+export type TabTitle = string;
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Visual theme selection for the user interface.
+ *
+ * Defaults to `System`, which defers to the operating system preference.
+ * Parsing from a string accepts only `light`, `dark`, or `system`
+ * (case-insensitive), matching the `Display` form.
+ */
+export type Theme = "Light" | "Dark" | "System";
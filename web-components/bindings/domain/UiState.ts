@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * JSON string representing arbitrary UI state.
+ *
+ * The domain layer treats this as an opaque string.
+ * JSON validation is deferred to the boundary layer per Hoffman's Law 7
+ * (work is a side effect).
+ *
+ * Default value is `"{}"` (empty JSON object).
+ */
+export type UiState = string;
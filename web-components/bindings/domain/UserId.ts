@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unique identifier for a User (Shared Kernel type).
+ *
+ * Wraps a UUID v4, providing type safety. This is the canonical user identity
+ * referenced across bounded contexts (Session, Workspace).
+ *
+ * # Design note
+ *
+ * The provider+externalId lookup (OAuth identity mapping) is an infrastructure
+ * concern handled by the user_identities table. This domain type uses UUID as
+ * the canonical identity reference.
+ *
+ * # Construction
+ *
+ * - `UserId::new()` - Generate a new random ID
+ * - `UserId::from_uuid(uuid)` - Wrap an existing UUID (for deserialization)
+ * - `UserId::system()` - The system actor (nil UUID)
+ */
+export type UserId = string;
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Workspace visibility controls access permissions.
+ *
+ * - `Private`: Visible only to owner (default)
+ * - `Public`: Visible to all authenticated users
+ */
+export type Visibility = "private" | "public";
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unique identifier for a Workspace.
+ *
+ * Wraps a UUID v4, providing type safety to prevent mixing up different
+ * ID types (e.g., passing a `UserId` where a `WorkspaceId` is expected).
+ *
+ * # Construction
+ *
+ * - `WorkspaceId::new()` - Generate a new ID from the current
+ *   [`IdSource`](ironstar_core::IdSource) (random unless a test installs one)
+ * - `WorkspaceId::from_uuid(uuid)` - Wrap an existing UUID (for deserialization)
+ */
+export type WorkspaceId = string;
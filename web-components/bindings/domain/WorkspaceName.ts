@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Validated workspace name.
+ *
+ * Guarantees:
+ * - Non-empty (at least one non-whitespace character)
+ * - At most [`WORKSPACE_NAME_MAX_LENGTH`] characters
+ * - Trimmed of leading/trailing whitespace
+ *
+ * # Example
+ *
+ * ```rust,ignore
+ * let name = WorkspaceName::new("  My Workspace  ")?;
+ * assert_eq!(name.as_str(), "My Workspace"); // Trimmed
+ * ```
+ */
+export type WorkspaceName = string;
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DatasetRef } from "../domain/DatasetRef";
+import type { QueryName } from "../domain/QueryName";
+import type { SavedQueryId } from "../domain/SavedQueryId";
+import type { SqlQuery } from "../domain/SqlQuery";
+import type { UserId } from "../domain/UserId";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Events emitted by the SavedQuery aggregate.
+ */
+export type SavedQueryEvent = { "type": "QuerySaved", query_id: SavedQueryId, workspace_id: WorkspaceId, name: QueryName, sql: SqlQuery, dataset_ref: DatasetRef, actor: UserId, saved_at: string, } | { "type": "QueryDeleted", query_id: SavedQueryId, actor: UserId, deleted_at: string, } | { "type": "QueryRenamed", query_id: SavedQueryId, name: QueryName, actor: UserId, renamed_at: string, } | { "type": "QuerySqlUpdated", query_id: SavedQueryId, sql: SqlQuery, actor: UserId, updated_at: string, } | { "type": "DatasetRefUpdated", query_id: SavedQueryId, dataset_ref: DatasetRef, actor: UserId, updated_at: string, } | { "type": "QueryMovedToWorkspace", query_id: SavedQueryId, from_workspace_id: WorkspaceId, to_workspace_id: WorkspaceId, actor: UserId, moved_at: string, } | { "type": "QueryRun", query_id: SavedQueryId, run_at: string, };
@@ -0,0 +1,70 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OAuthProvider } from "../domain/OAuthProvider";
+import type { SessionId } from "../domain/SessionId";
+import type { SessionMetadata } from "../domain/SessionMetadata";
+import type { UserId } from "../domain/UserId";
+
+/**
+ * Events emitted by the Session aggregate.
+ *
+ * Each variant represents a state change that occurred. The aggregate's
+ * current state is the result of applying all its events in order.
+ *
+ * # Versioning
+ *
+ * Events have an implicit version (currently v1). Schema evolution is
+ * handled by upcasters that transform old event formats during loading.
+ */
+export type SessionEvent = { "type": "Created", 
+/**
+ * Unique session identifier.
+ */
+session_id: SessionId, 
+/**
+ * Canonical user identifier.
+ */
+user_id: UserId, 
+/**
+ * OAuth provider that authenticated this session.
+ */
+provider: OAuthProvider, 
+/**
+ * When the session was created.
+ */
+created_at: string, 
+/**
+ * When the session expires.
+ */
+expires_at: string, 
+/**
+ * Audit trail metadata (IP, user agent).
+ */
+metadata: SessionMetadata, } | { "type": "Refreshed", 
+/**
+ * Which session was refreshed.
+ */
+session_id: SessionId, 
+/**
+ * When the refresh occurred.
+ */
+refreshed_at: string, 
+/**
+ * New expiration timestamp.
+ */
+new_expires_at: string, } | { "type": "Invalidated", 
+/**
+ * Which session was invalidated.
+ */
+session_id: SessionId, 
+/**
+ * When invalidation occurred.
+ */
+invalidated_at: string, } | { "type": "Expired", 
+/**
+ * Which session expired.
+ */
+session_id: SessionId, 
+/**
+ * When expiration was detected.
+ */
+expired_at: string, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Locale } from "../domain/Locale";
+import type { NotificationPreferences } from "../domain/NotificationPreferences";
+import type { PreferencesId } from "../domain/PreferencesId";
+import type { Theme } from "../domain/Theme";
+import type { UiState } from "../domain/UiState";
+import type { UserId } from "../domain/UserId";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Events emitted by the UserPreferences aggregate.
+ */
+export type UserPreferencesEvent = { "type": "PreferencesInitialized", preferences_id: PreferencesId, user_id: UserId, initialized_at: string, } | { "type": "ThemeSet", user_id: UserId, theme: Theme, set_at: string, } | { "type": "LocaleSet", user_id: UserId, locale: Locale, set_at: string, } | { "type": "UiStateUpdated", user_id: UserId, ui_state: UiState, updated_at: string, } | { "type": "DefaultWorkspaceSet", user_id: UserId, workspace_id: WorkspaceId, set_at: string, } | { "type": "DefaultWorkspaceCleared", user_id: UserId, cleared_at: string, } | { "type": "WorkspaceViewed", user_id: UserId, workspace_id: WorkspaceId, viewed_at: string, } | { "type": "NotificationPreferencesSet", user_id: UserId, notifications: NotificationPreferences, set_at: string, };
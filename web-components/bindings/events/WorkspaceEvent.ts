@@ -0,0 +1,131 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UserId } from "../domain/UserId";
+import type { Visibility } from "../domain/Visibility";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+import type { WorkspaceName } from "../domain/WorkspaceName";
+
+/**
+ * Events emitted by the Workspace aggregate.
+ *
+ * Each variant represents a state change that occurred. The aggregate's
+ * current state is the result of applying all its events in order.
+ *
+ * # Versioning
+ *
+ * Events have an implicit version (currently v1). Schema evolution is
+ * handled by upcasters that transform old event formats during loading.
+ * See `EventUpcaster` trait in the infrastructure layer.
+ */
+export type WorkspaceEvent = { "type": "Created", 
+/**
+ * Unique identifier for the workspace.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * The workspace's name (validated).
+ */
+name: WorkspaceName, 
+/**
+ * Trimmed, lowercased name for uniqueness checks in projections.
+ */
+normalized_name: string, 
+/**
+ * Owner of the workspace.
+ */
+owner_id: UserId, 
+/**
+ * Visibility setting.
+ */
+visibility: Visibility, 
+/**
+ * Who performed the action.
+ */
+actor: UserId, 
+/**
+ * When the workspace was created.
+ */
+created_at: string, } | { "type": "Renamed", 
+/**
+ * Which workspace was renamed.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * The previous name (for audit trail).
+ */
+old_name: WorkspaceName, 
+/**
+ * The new name.
+ */
+new_name: WorkspaceName, 
+/**
+ * Trimmed, lowercased new name for uniqueness checks in projections.
+ */
+normalized_name: string, 
+/**
+ * Who performed the action.
+ */
+actor: UserId, 
+/**
+ * When the rename occurred.
+ */
+renamed_at: string, } | { "type": "VisibilityChanged", 
+/**
+ * Which workspace was modified.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * The previous visibility (for audit trail).
+ */
+old_visibility: Visibility, 
+/**
+ * The new visibility.
+ */
+new_visibility: Visibility, 
+/**
+ * Who performed the action.
+ */
+actor: UserId, 
+/**
+ * When the change occurred.
+ */
+changed_at: string, } | { "type": "Suspended", 
+/**
+ * Which workspace was suspended.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * Why the workspace was suspended.
+ */
+reason: string, 
+/**
+ * Who performed the action.
+ */
+actor: UserId, 
+/**
+ * When the workspace was suspended.
+ */
+suspended_at: string, } | { "type": "Unsuspended", 
+/**
+ * Which workspace was unsuspended.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * Who performed the action.
+ */
+actor: UserId, 
+/**
+ * When the suspension was lifted.
+ */
+unsuspended_at: string, } | { "type": "Archived", 
+/**
+ * Which workspace was archived.
+ */
+workspace_id: WorkspaceId, 
+/**
+ * Who performed the action.
+ */
+actor: UserId, 
+/**
+ * When the workspace was archived.
+ */
+archived_at: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CatalogUri } from "../domain/CatalogUri";
+import type { LayoutDefaults } from "../domain/LayoutDefaults";
+import type { WorkspaceId } from "../domain/WorkspaceId";
+
+/**
+ * Events emitted by the WorkspacePreferences aggregate.
+ */
+export type WorkspacePreferencesEvent = { "type": "WorkspacePreferencesInitialized", workspace_id: WorkspaceId, initialized_at: string, } | { "type": "DefaultCatalogSet", workspace_id: WorkspaceId, catalog_uri: CatalogUri, set_at: string, } | { "type": "DefaultCatalogCleared", workspace_id: WorkspaceId, cleared_at: string, } | { "type": "LayoutDefaultsUpdated", workspace_id: WorkspaceId, layout_defaults: LayoutDefaults, updated_at: string, };
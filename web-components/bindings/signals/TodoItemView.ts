@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * View projection of a single todo item for list rendering.
+ *
+ * This is a read-only projection derived from [`crate::state::TodoState`],
+ * shaped for efficient list rendering via SSE updates.
+ */
+export type TodoItemView = { 
+/**
+ * Unique identifier for this todo.
+ */
+id: string, 
+/**
+ * The todo text content.
+ */
+text: string, 
+/**
+ * Whether the todo is completed.
+ */
+completed: boolean, };